@@ -0,0 +1,67 @@
+-- Track every reaction type separately so the API can apply configurable
+-- per-reaction weights when computing the effective engagement score.
+
+ALTER TABLE engagement_counts ADD COLUMN IF NOT EXISTS heart_count INTEGER NOT NULL DEFAULT 0;
+ALTER TABLE engagement_counts ADD COLUMN IF NOT EXISTS eyes_count INTEGER NOT NULL DEFAULT 0;
+ALTER TABLE engagement_counts ADD COLUMN IF NOT EXISTS review_count INTEGER NOT NULL DEFAULT 0;
+
+-- Backfill from existing engagements
+UPDATE engagement_counts ec SET
+    heart_count = sub.heart_count,
+    eyes_count = sub.eyes_count,
+    review_count = sub.review_count
+FROM (
+    SELECT
+        target_type,
+        target_id,
+        COUNT(*) FILTER (WHERE reaction = 'heart') AS heart_count,
+        COUNT(*) FILTER (WHERE reaction = 'eyes') AS eyes_count,
+        COUNT(*) FILTER (WHERE engagement_type = 'review') AS review_count
+    FROM engagements
+    GROUP BY target_type, target_id
+) sub
+WHERE ec.target_type = sub.target_type AND ec.target_id = sub.target_id;
+
+CREATE OR REPLACE FUNCTION update_engagement_counts()
+RETURNS TRIGGER AS $$
+BEGIN
+    INSERT INTO engagement_counts (target_type, target_id, laugh_count, fire_count, skull_count, heart_count, eyes_count, comment_count, review_count, total_score, updated_at)
+    VALUES (
+        NEW.target_type,
+        NEW.target_id,
+        CASE WHEN NEW.reaction = 'laugh' THEN 1 ELSE 0 END,
+        CASE WHEN NEW.reaction = 'fire' THEN 1 ELSE 0 END,
+        CASE WHEN NEW.reaction = 'skull' THEN 1 ELSE 0 END,
+        CASE WHEN NEW.reaction = 'heart' THEN 1 ELSE 0 END,
+        CASE WHEN NEW.reaction = 'eyes' THEN 1 ELSE 0 END,
+        CASE WHEN NEW.engagement_type = 'comment' THEN 1 ELSE 0 END,
+        CASE WHEN NEW.engagement_type = 'review' THEN 1 ELSE 0 END,
+        CASE
+            WHEN NEW.reaction = 'laugh' THEN 2
+            WHEN NEW.reaction = 'fire' THEN 3
+            WHEN NEW.reaction = 'skull' THEN 2
+            WHEN NEW.engagement_type = 'comment' THEN 5
+            ELSE 1
+        END,
+        NOW()
+    )
+    ON CONFLICT (target_type, target_id) DO UPDATE SET
+        laugh_count = engagement_counts.laugh_count + CASE WHEN NEW.reaction = 'laugh' THEN 1 ELSE 0 END,
+        fire_count = engagement_counts.fire_count + CASE WHEN NEW.reaction = 'fire' THEN 1 ELSE 0 END,
+        skull_count = engagement_counts.skull_count + CASE WHEN NEW.reaction = 'skull' THEN 1 ELSE 0 END,
+        heart_count = engagement_counts.heart_count + CASE WHEN NEW.reaction = 'heart' THEN 1 ELSE 0 END,
+        eyes_count = engagement_counts.eyes_count + CASE WHEN NEW.reaction = 'eyes' THEN 1 ELSE 0 END,
+        comment_count = engagement_counts.comment_count + CASE WHEN NEW.engagement_type = 'comment' THEN 1 ELSE 0 END,
+        review_count = engagement_counts.review_count + CASE WHEN NEW.engagement_type = 'review' THEN 1 ELSE 0 END,
+        total_score = engagement_counts.total_score + CASE
+            WHEN NEW.reaction = 'laugh' THEN 2
+            WHEN NEW.reaction = 'fire' THEN 3
+            WHEN NEW.reaction = 'skull' THEN 2
+            WHEN NEW.engagement_type = 'comment' THEN 5
+            ELSE 1
+        END,
+        updated_at = NOW();
+
+    RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
@@ -0,0 +1,5 @@
+-- Read-only mirrors of projects whose source lives on an external Gitea/GitHub instance.
+
+ALTER TABLE projects ADD COLUMN IF NOT EXISTS mirror_source_url TEXT;
+ALTER TABLE projects ADD COLUMN IF NOT EXISTS mirror_repo VARCHAR(255);
+ALTER TABLE projects ADD COLUMN IF NOT EXISTS mirrored_at TIMESTAMPTZ;
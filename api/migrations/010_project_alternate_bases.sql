@@ -0,0 +1,3 @@
+-- Projects may opt in to merging PRs whose base is not the repository's default branch.
+
+ALTER TABLE projects ADD COLUMN IF NOT EXISTS allow_alternate_bases BOOLEAN NOT NULL DEFAULT FALSE;
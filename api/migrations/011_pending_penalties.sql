@@ -0,0 +1,26 @@
+-- PR-rejection penalties are deferred for a grace period so that a PR reopened
+-- shortly after being closed does not cost its author ELO.
+
+CREATE TABLE pending_penalties (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    agent_id UUID NOT NULL REFERENCES agents(id) ON DELETE CASCADE,
+    project_id UUID NOT NULL REFERENCES projects(id) ON DELETE CASCADE,
+    gitea_org VARCHAR(255) NOT NULL,
+    gitea_repo VARCHAR(255) NOT NULL,
+    pr_number BIGINT NOT NULL,
+    status VARCHAR(20) NOT NULL DEFAULT 'pending',
+    apply_at TIMESTAMPTZ NOT NULL,
+    resolved_at TIMESTAMPTZ,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+
+    CONSTRAINT valid_pending_penalty_status CHECK (status IN ('pending', 'applied', 'canceled'))
+);
+
+-- Index for the sweep that applies penalties whose grace period has elapsed
+CREATE INDEX idx_pending_penalties_due ON pending_penalties(apply_at) WHERE status = 'pending';
+
+-- Index for canceling a penalty when its PR is reopened
+CREATE INDEX idx_pending_penalties_pr ON pending_penalties(project_id, pr_number) WHERE status = 'pending';
+
+-- Index for listing an agent's outstanding penalties
+CREATE INDEX idx_pending_penalties_agent ON pending_penalties(agent_id) WHERE status = 'pending';
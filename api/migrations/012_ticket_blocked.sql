@@ -0,0 +1,8 @@
+-- Tickets can be marked blocked (e.g. waiting on a dependency) with a reason.
+-- Blocked tickets are left out of the actionable feed until unblocked.
+
+ALTER TABLE tickets DROP CONSTRAINT IF EXISTS tickets_status_check;
+ALTER TABLE tickets ADD CONSTRAINT tickets_status_check
+    CHECK (status IN ('open', 'in_progress', 'blocked', 'closed'));
+
+ALTER TABLE tickets ADD COLUMN IF NOT EXISTS blocked_reason TEXT;
@@ -0,0 +1,3 @@
+-- Projects may require a green combined CI status before PRs can be merged.
+
+ALTER TABLE projects ADD COLUMN IF NOT EXISTS require_green_ci BOOLEAN NOT NULL DEFAULT FALSE;
@@ -0,0 +1,16 @@
+-- Reactions added directly on Gitea by users who aren't SynStack agents.
+-- Reconciliation replaces a target's row with the latest tally from Gitea;
+-- these counts are added on top of engagement_counts when reading.
+
+CREATE TABLE IF NOT EXISTS engagement_external_counts (
+    target_type VARCHAR(30) NOT NULL,
+    target_id UUID NOT NULL,
+    laugh_count INTEGER NOT NULL DEFAULT 0,
+    fire_count INTEGER NOT NULL DEFAULT 0,
+    skull_count INTEGER NOT NULL DEFAULT 0,
+    heart_count INTEGER NOT NULL DEFAULT 0,
+    eyes_count INTEGER NOT NULL DEFAULT 0,
+    synced_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+
+    PRIMARY KEY (target_type, target_id)
+);
@@ -0,0 +1,15 @@
+-- Agents may explicitly abandon a claimed ticket for a small ELO penalty.
+
+ALTER TABLE elo_events DROP CONSTRAINT IF EXISTS valid_event_type;
+ALTER TABLE elo_events ADD CONSTRAINT valid_event_type CHECK (event_type IN (
+    'pr_merged',
+    'high_elo_approval',
+    'longevity_bonus',
+    'dependent_pr',
+    'commit_reverted',
+    'bug_referenced',
+    'pr_rejected',
+    'low_peer_review_score',
+    'code_replaced',
+    'ticket_abandoned'
+));
@@ -0,0 +1,6 @@
+-- Tickets carry a difficulty that weights the merge ELO award and feed ordering.
+
+ALTER TABLE tickets ADD COLUMN IF NOT EXISTS difficulty VARCHAR(10) NOT NULL DEFAULT 'medium';
+ALTER TABLE tickets DROP CONSTRAINT IF EXISTS tickets_difficulty_check;
+ALTER TABLE tickets ADD CONSTRAINT tickets_difficulty_check
+    CHECK (difficulty IN ('easy', 'medium', 'hard'));
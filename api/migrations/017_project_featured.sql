@@ -0,0 +1,6 @@
+-- Operators can curate a featured project list, ordered by rank.
+
+ALTER TABLE projects ADD COLUMN IF NOT EXISTS featured BOOLEAN NOT NULL DEFAULT FALSE;
+ALTER TABLE projects ADD COLUMN IF NOT EXISTS featured_rank INTEGER NOT NULL DEFAULT 0;
+
+CREATE INDEX IF NOT EXISTS idx_projects_featured ON projects(featured_rank) WHERE featured;
@@ -0,0 +1,7 @@
+-- Operators can weight ELO earned on flagship projects more heavily.
+
+ALTER TABLE projects ADD COLUMN IF NOT EXISTS elo_multiplier REAL NOT NULL DEFAULT 1.0;
+
+ALTER TABLE projects DROP CONSTRAINT IF EXISTS valid_elo_multiplier;
+ALTER TABLE projects ADD CONSTRAINT valid_elo_multiplier
+    CHECK (elo_multiplier BETWEEN 0.5 AND 3.0);
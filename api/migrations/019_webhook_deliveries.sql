@@ -0,0 +1,22 @@
+-- Every received Gitea webhook is logged with its outcome so operators can see
+-- why an expected ELO change never happened.
+
+CREATE TABLE webhook_deliveries (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    delivery_id VARCHAR(255),
+    event_type VARCHAR(64) NOT NULL,
+    repo VARCHAR(255),
+    action VARCHAR(64),
+    outcome VARCHAR(20) NOT NULL,
+    error TEXT,
+    received_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+
+    CONSTRAINT valid_webhook_delivery_outcome
+        CHECK (outcome IN ('success', 'duplicate', 'signature_failed', 'invalid_payload', 'failed'))
+);
+
+-- Index for the admin "recent deliveries" listing
+CREATE INDEX idx_webhook_deliveries_received ON webhook_deliveries(received_at DESC);
+
+-- Index for skipping redeliveries that were already processed
+CREATE INDEX idx_webhook_deliveries_delivery ON webhook_deliveries(delivery_id) WHERE outcome = 'success';
@@ -0,0 +1,16 @@
+-- Reviewers lose a little ELO when a PR they approved is reverted or found buggy.
+
+ALTER TABLE elo_events DROP CONSTRAINT IF EXISTS valid_event_type;
+ALTER TABLE elo_events ADD CONSTRAINT valid_event_type CHECK (event_type IN (
+    'pr_merged',
+    'high_elo_approval',
+    'longevity_bonus',
+    'dependent_pr',
+    'commit_reverted',
+    'bug_referenced',
+    'pr_rejected',
+    'low_peer_review_score',
+    'code_replaced',
+    'ticket_abandoned',
+    'bad_approval'
+));
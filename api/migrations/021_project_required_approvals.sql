@@ -0,0 +1,7 @@
+-- Projects may require a number of approving reviews before PRs can be merged.
+
+ALTER TABLE projects ADD COLUMN IF NOT EXISTS required_approvals INTEGER NOT NULL DEFAULT 0;
+
+ALTER TABLE projects DROP CONSTRAINT IF EXISTS valid_required_approvals;
+ALTER TABLE projects ADD CONSTRAINT valid_required_approvals
+    CHECK (required_approvals >= 0);
@@ -0,0 +1,8 @@
+-- Projects can be hidden from public listings (unlisted) or from non-members
+-- entirely (private).
+
+ALTER TABLE projects ADD COLUMN IF NOT EXISTS visibility VARCHAR(20) NOT NULL DEFAULT 'public';
+
+ALTER TABLE projects DROP CONSTRAINT IF EXISTS valid_project_visibility;
+ALTER TABLE projects ADD CONSTRAINT valid_project_visibility
+    CHECK (visibility IN ('public', 'unlisted', 'private'));
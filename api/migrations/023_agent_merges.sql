@@ -0,0 +1,4 @@
+-- Duplicate agents can be merged into another agent. The merged (source) agent
+-- keeps its row for history, but drops out of listings and can't authenticate.
+
+ALTER TABLE agents ADD COLUMN IF NOT EXISTS merged_into UUID REFERENCES agents(id);
@@ -0,0 +1,8 @@
+-- Old, unpromoted viral moments have their snapshot compacted to a small
+-- summary to free storage. The timestamp marks moments already compacted.
+
+ALTER TABLE viral_moments ADD COLUMN IF NOT EXISTS snapshot_compacted_at TIMESTAMPTZ;
+
+CREATE INDEX IF NOT EXISTS idx_viral_moments_compactable
+    ON viral_moments (created_at)
+    WHERE snapshot_compacted_at IS NULL AND promoted = FALSE;
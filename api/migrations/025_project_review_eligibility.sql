@@ -0,0 +1,7 @@
+-- Projects can limit peer reviews to their own members.
+
+ALTER TABLE projects ADD COLUMN IF NOT EXISTS review_eligibility VARCHAR(20) NOT NULL DEFAULT 'open';
+
+ALTER TABLE projects DROP CONSTRAINT IF EXISTS valid_project_review_eligibility;
+ALTER TABLE projects ADD CONSTRAINT valid_project_review_eligibility
+    CHECK (review_eligibility IN ('open', 'members'));
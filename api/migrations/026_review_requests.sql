@@ -0,0 +1,43 @@
+-- Reviews requested of specific agents. A request nobody answers before
+-- due_at is either reassigned to another member or costs the reviewer ELO.
+
+CREATE TABLE review_requests (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    project_id UUID NOT NULL REFERENCES projects(id) ON DELETE CASCADE,
+    gitea_org VARCHAR(255) NOT NULL,
+    gitea_repo VARCHAR(255) NOT NULL,
+    pr_number BIGINT NOT NULL,
+    reviewer_agent_id UUID NOT NULL REFERENCES agents(id) ON DELETE CASCADE,
+    requested_by UUID REFERENCES agents(id) ON DELETE SET NULL,
+    status VARCHAR(20) NOT NULL DEFAULT 'pending',
+    due_at TIMESTAMPTZ NOT NULL,
+    resolved_at TIMESTAMPTZ,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+
+    CONSTRAINT valid_review_request_status CHECK (
+        status IN ('pending', 'completed', 'reassigned', 'penalized', 'canceled')
+    )
+);
+
+-- Index for the sweep that handles requests past their deadline
+CREATE INDEX idx_review_requests_due ON review_requests(due_at) WHERE status = 'pending';
+
+-- Index for completing requests when a review is submitted
+CREATE INDEX idx_review_requests_pr ON review_requests(project_id, pr_number);
+
+-- No-show reviewers lose a little ELO
+ALTER TABLE elo_events DROP CONSTRAINT IF EXISTS valid_event_type;
+ALTER TABLE elo_events ADD CONSTRAINT valid_event_type CHECK (event_type IN (
+    'pr_merged',
+    'high_elo_approval',
+    'longevity_bonus',
+    'dependent_pr',
+    'commit_reverted',
+    'bug_referenced',
+    'pr_rejected',
+    'low_peer_review_score',
+    'code_replaced',
+    'ticket_abandoned',
+    'bad_approval',
+    'review_abandoned'
+));
@@ -0,0 +1,12 @@
+-- Retry engagements whose Gitea sync failed. The PR's repository is kept on
+-- the engagement so a retry needs no other lookup; engagements that keep
+-- failing are flagged and left alone.
+
+ALTER TABLE engagements ADD COLUMN gitea_org VARCHAR(255);
+ALTER TABLE engagements ADD COLUMN gitea_repo VARCHAR(255);
+ALTER TABLE engagements ADD COLUMN sync_attempts INTEGER NOT NULL DEFAULT 0;
+ALTER TABLE engagements ADD COLUMN sync_failed BOOLEAN NOT NULL DEFAULT FALSE;
+
+-- Index for the job that retries unsynced engagements
+CREATE INDEX idx_engagements_unsynced ON engagements(created_at)
+    WHERE gitea_synced = FALSE AND sync_failed = FALSE AND gitea_org IS NOT NULL;
@@ -0,0 +1,9 @@
+-- Private projects only accept agents an owner or maintainer invited.
+
+CREATE TABLE IF NOT EXISTS project_invites (
+    project_id UUID NOT NULL REFERENCES projects(id) ON DELETE CASCADE,
+    agent_id UUID NOT NULL REFERENCES agents(id) ON DELETE CASCADE,
+    invited_by UUID REFERENCES agents(id) ON DELETE SET NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (project_id, agent_id)
+);
@@ -0,0 +1,13 @@
+-- Accepted webhooks are written here before they're acknowledged, so a
+-- restart doesn't lose deliveries that were still waiting for a worker.
+-- The raw body is kept until the delivery has been processed.
+
+ALTER TABLE webhook_deliveries ADD COLUMN IF NOT EXISTS payload TEXT;
+
+ALTER TABLE webhook_deliveries DROP CONSTRAINT IF EXISTS valid_webhook_delivery_outcome;
+ALTER TABLE webhook_deliveries ADD CONSTRAINT valid_webhook_delivery_outcome
+    CHECK (outcome IN ('queued', 'success', 'duplicate', 'signature_failed', 'invalid_payload', 'failed'));
+
+-- Index for re-queueing unprocessed deliveries on startup
+CREATE INDEX IF NOT EXISTS idx_webhook_deliveries_queued
+    ON webhook_deliveries(received_at) WHERE outcome = 'queued';
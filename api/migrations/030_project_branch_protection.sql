@@ -0,0 +1,4 @@
+-- Projects may opt in to mirroring their merge rules onto the default
+-- branch's Gitea protection.
+
+ALTER TABLE projects ADD COLUMN IF NOT EXISTS protect_default_branch BOOLEAN NOT NULL DEFAULT FALSE;
@@ -0,0 +1,13 @@
+-- Audit trail of merges where a project owner waived the project's required
+-- approvals with `force`.
+
+CREATE TABLE IF NOT EXISTS force_merges (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    project_id UUID NOT NULL REFERENCES projects(id) ON DELETE CASCADE,
+    agent_id UUID NOT NULL REFERENCES agents(id) ON DELETE CASCADE,
+    pr_number BIGINT NOT NULL,
+    waived_approvals INTEGER NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_force_merges_project ON force_merges(project_id, created_at DESC);
@@ -0,0 +1,46 @@
+-- PR engagement targets used to be derived from the PR number alone, so PR #N
+-- of every project shared one target. They now carry the project too: the
+-- first 8 bytes hold the PR number and the last 8 the tail of the project ID.
+
+-- Move engagements that know their repository onto their project's target
+UPDATE engagements e SET
+    target_id = encode(
+        overlay(uuid_send(e.target_id) placing substring(uuid_send(p.id) from 9 for 8) from 9),
+        'hex'
+    )::uuid
+FROM projects p
+WHERE e.target_type = 'pr'
+  AND e.gitea_org = p.gitea_org
+  AND e.gitea_repo = p.gitea_repo
+  AND substring(uuid_send(e.target_id) from 9 for 8) = '\x0000000000000000'::bytea;
+
+-- Rebuild the PR counts from the moved engagements
+DELETE FROM engagement_counts WHERE target_type = 'pr';
+
+INSERT INTO engagement_counts (target_type, target_id, laugh_count, fire_count, skull_count, heart_count, eyes_count, comment_count, review_count, total_score, updated_at)
+SELECT
+    target_type,
+    target_id,
+    COUNT(*) FILTER (WHERE reaction = 'laugh'),
+    COUNT(*) FILTER (WHERE reaction = 'fire'),
+    COUNT(*) FILTER (WHERE reaction = 'skull'),
+    COUNT(*) FILTER (WHERE reaction = 'heart'),
+    COUNT(*) FILTER (WHERE reaction = 'eyes'),
+    COUNT(*) FILTER (WHERE engagement_type = 'comment'),
+    COUNT(*) FILTER (WHERE engagement_type = 'review'),
+    SUM(CASE
+        WHEN reaction = 'laugh' THEN 2
+        WHEN reaction = 'fire' THEN 3
+        WHEN reaction = 'skull' THEN 2
+        WHEN engagement_type = 'comment' THEN 5
+        ELSE 1
+    END),
+    NOW()
+FROM engagements
+WHERE target_type = 'pr'
+GROUP BY target_type, target_id;
+
+-- External tallies are re-pulled from Gitea under the new targets
+DELETE FROM engagement_external_counts
+WHERE target_type = 'pr'
+  AND substring(uuid_send(target_id) from 9 for 8) = '\x0000000000000000'::bytea;
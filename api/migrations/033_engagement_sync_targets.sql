@@ -0,0 +1,20 @@
+-- Reactions recorded before their PR's repository was known never got a sync
+-- target, so the re-sync job skipped them for good. PR targets carry the tail
+-- of their project's ID, which is enough to find the repository now.
+UPDATE engagements e SET
+    gitea_org = p.gitea_org,
+    gitea_repo = p.gitea_repo
+FROM projects p
+WHERE e.target_type = 'pr'
+  AND e.engagement_type = 'reaction'
+  AND e.gitea_synced = FALSE
+  AND e.sync_failed = FALSE
+  AND e.gitea_org IS NULL
+  AND substring(uuid_send(e.target_id) from 9 for 8) = substring(uuid_send(p.id) from 9 for 8);
+
+-- Comments are no longer mirrored to Gitea; stop retrying the unsynced ones
+UPDATE engagements SET
+    gitea_org = NULL,
+    gitea_repo = NULL
+WHERE engagement_type = 'comment'
+  AND gitea_synced = FALSE;
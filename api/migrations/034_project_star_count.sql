@@ -0,0 +1,4 @@
+-- Star count of each project's repository, refreshed from Gitea so project
+-- listings don't have to ask Gitea on every request.
+
+ALTER TABLE projects ADD COLUMN IF NOT EXISTS star_count INTEGER NOT NULL DEFAULT 0;
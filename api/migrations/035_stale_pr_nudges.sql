@@ -0,0 +1,8 @@
+-- PRs the stale PR sweep has already nudged, so each is nudged at most once.
+
+CREATE TABLE IF NOT EXISTS stale_pr_nudges (
+    project_id UUID NOT NULL REFERENCES projects(id) ON DELETE CASCADE,
+    pr_number BIGINT NOT NULL,
+    nudged_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (project_id, pr_number)
+);
@@ -1,22 +1,35 @@
 //! Gitea API client implementation
 
+use std::time::Instant;
+
 use async_trait::async_trait;
-use reqwest::Client;
+use reqwest::{Client, RequestBuilder, Response};
 use serde::{Deserialize, Serialize};
+use tokio::sync::Semaphore;
+use tracing::Instrument;
 use urlencoding::encode;
 
 use crate::domain::ports::{
-    GiteaBranch, GiteaClient, GiteaCombinedStatus, GiteaComment, GiteaCommit, GiteaIssue,
-    GiteaIssueComment, GiteaLabel, GiteaOrg, GiteaPRBranch, GiteaPRReview, GiteaPullRequest,
-    GiteaReaction, GiteaRepo, GiteaStatus, GiteaUser,
+    BranchProtection, GiteaBranch, GiteaClient, GiteaCombinedStatus, GiteaComment, GiteaCommit,
+    GiteaCommitDetails, GiteaComparison, GiteaHeatmapEntry, GiteaIssue, GiteaIssueComment,
+    GiteaLabel, GiteaMergeStatus, GiteaNotification, GiteaOrg, GiteaPRBranch, GiteaPRReview,
+    GiteaPullRequest, GiteaReaction, GiteaRelease, GiteaRepo, GiteaStatus, GiteaUser, RepoSettings,
 };
 use crate::error::GiteaError;
 
+/// Page size used when walking paginated Gitea list endpoints
+const PAGE_LIMIT: usize = 50;
+
+/// Default cap on concurrent outbound Gitea requests
+pub const DEFAULT_MAX_CONCURRENT_REQUESTS: usize = 32;
+
 /// Implementation of the Gitea API client
 pub struct GiteaClientImpl {
     http: Client,
     base_url: String,
     admin_token: String,
+    /// Bounds how many requests are in flight to Gitea at once
+    limiter: Semaphore,
 }
 
 impl GiteaClientImpl {
@@ -25,9 +38,16 @@ impl GiteaClientImpl {
             http: Client::new(),
             base_url: base_url.trim_end_matches('/').to_string(),
             admin_token,
+            limiter: Semaphore::new(DEFAULT_MAX_CONCURRENT_REQUESTS),
         }
     }
 
+    /// Cap the number of concurrent outbound requests (at least 1)
+    pub fn with_max_concurrent_requests(mut self, max: usize) -> Self {
+        self.limiter = Semaphore::new(max.max(1));
+        self
+    }
+
     fn api_url(&self, path: &str) -> String {
         format!("{}/api/v1{}", self.base_url, path)
     }
@@ -73,6 +93,107 @@ impl GiteaClientImpl {
             })
         }
     }
+
+    /// Commits reachable from `head` but not from `base`
+    async fn compare_refs(
+        &self,
+        owner: &str,
+        repo: &str,
+        base: &str,
+        head: &str,
+    ) -> Result<GiteaCompareResponse, GiteaError> {
+        let resp = self
+            .http
+            .get(self.api_url(&format!(
+                "/repos/{}/{}/compare/{}...{}",
+                owner,
+                repo,
+                encode(base),
+                encode(head)
+            )))
+            .header("Authorization", format!("token {}", self.admin_token))
+            .send_logged(&self.limiter, "/repos/{owner}/{repo}/compare/{basehead}")
+            .await?;
+
+        if resp.status().as_u16() == 404 {
+            return Err(GiteaError::RepoNotFound {
+                owner: owner.to_string(),
+                repo: repo.to_string(),
+            });
+        }
+
+        self.handle_response(resp).await
+    }
+}
+
+/// Sends Gitea requests with structured logging.
+///
+/// `endpoint` is the path template (e.g. `/repos/{owner}/{repo}/pulls/{number}`)
+/// rather than the concrete path, which keeps log cardinality bounded.
+/// A permit from `limiter` is held while the request is in flight.
+trait SendLogged {
+    async fn send_logged(
+        self,
+        limiter: &Semaphore,
+        endpoint: &'static str,
+    ) -> Result<Response, GiteaError>;
+}
+
+impl SendLogged for RequestBuilder {
+    async fn send_logged(
+        self,
+        limiter: &Semaphore,
+        endpoint: &'static str,
+    ) -> Result<Response, GiteaError> {
+        let (client, request) = self.build_split();
+        let request = request?;
+        let method = request.method().clone();
+        let span = tracing::debug_span!("gitea_api", method = %method, endpoint);
+
+        async move {
+            let _permit = limiter
+                .acquire()
+                .await
+                .expect("Gitea request limiter is never closed");
+            let start = Instant::now();
+            let result = client.execute(request).await;
+            let elapsed_ms = start.elapsed().as_millis() as u64;
+
+            match &result {
+                Ok(resp) if resp.status().is_client_error() || resp.status().is_server_error() => {
+                    tracing::warn!(
+                        method = %method,
+                        endpoint,
+                        status = resp.status().as_u16(),
+                        elapsed_ms,
+                        "Gitea API call failed"
+                    );
+                }
+                Ok(resp) => {
+                    tracing::debug!(
+                        method = %method,
+                        endpoint,
+                        status = resp.status().as_u16(),
+                        elapsed_ms,
+                        "Gitea API call"
+                    );
+                }
+                Err(e) => {
+                    tracing::warn!(
+                        method = %method,
+                        endpoint,
+                        elapsed_ms,
+                        error = %e,
+                        "Gitea API request error"
+                    );
+                }
+            }
+
+            Ok(result?)
+        }
+        .instrument(span)
+        .await
+    }
 }
 
 /// Request types for Gitea API
@@ -84,6 +205,14 @@ struct CreateUserRequest<'a> {
     must_change_password: bool,
 }
 
+#[derive(Serialize)]
+struct EditUserPasswordRequest<'a> {
+    login_name: &'a str,
+    source_id: i64,
+    password: &'a str,
+    must_change_password: bool,
+}
+
 #[derive(Serialize)]
 struct CreateTokenRequest<'a> {
     name: &'a str,
@@ -102,6 +231,13 @@ struct CreateOrgRequest<'a> {
     description: Option<&'a str>,
 }
 
+#[derive(Serialize)]
+struct CreateBranchRequest<'a> {
+    new_branch_name: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    old_branch_name: Option<&'a str>,
+}
+
 #[derive(Serialize)]
 struct CreateRepoRequest<'a> {
     name: &'a str,
@@ -111,12 +247,33 @@ struct CreateRepoRequest<'a> {
     auto_init: bool,
 }
 
+#[derive(Serialize)]
+struct GenerateRepoRequest<'a> {
+    owner: &'a str,
+    name: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<&'a str>,
+    private: bool,
+    git_content: bool,
+    topics: bool,
+    labels: bool,
+}
+
 #[derive(Serialize)]
 struct ForkRepoRequest<'a> {
     #[serde(skip_serializing_if = "Option::is_none")]
     organization: Option<&'a str>,
 }
 
+#[derive(Serialize)]
+struct MigrateRepoRequest<'a> {
+    clone_addr: &'a str,
+    repo_owner: &'a str,
+    repo_name: &'a str,
+    mirror: bool,
+    service: &'a str,
+}
+
 #[derive(Serialize)]
 struct CreatePRRequest<'a> {
     title: &'a str,
@@ -130,6 +287,7 @@ struct CreatePRRequest<'a> {
 struct MergePRRequest<'a> {
     #[serde(rename = "Do")]
     do_merge: &'a str,
+    force_merge: bool,
 }
 
 #[derive(Serialize)]
@@ -166,6 +324,17 @@ struct AddCollaboratorRequest<'a> {
     permission: &'a str,
 }
 
+#[derive(Serialize)]
+struct BranchProtectionRequest<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    rule_name: Option<&'a str>,
+    enable_push: bool,
+    required_approvals: i64,
+    block_on_rejected_reviews: bool,
+    enable_status_check: bool,
+    status_check_contexts: &'a [String],
+}
+
 /// Response types from Gitea API
 #[derive(Deserialize)]
 struct GiteaUserResponse {
@@ -216,6 +385,10 @@ struct GiteaRepoResponse {
     html_url: String,
     default_branch: String,
     private: bool,
+    #[serde(default)]
+    open_issues_count: i64,
+    #[serde(default)]
+    stars_count: i64,
 }
 
 impl From<GiteaRepoResponse> for GiteaRepo {
@@ -230,6 +403,8 @@ impl From<GiteaRepoResponse> for GiteaRepo {
             html_url: r.html_url,
             default_branch: r.default_branch,
             private: r.private,
+            open_issues_count: r.open_issues_count,
+            stars_count: r.stars_count,
         }
     }
 }
@@ -258,6 +433,47 @@ impl From<GiteaBranchResponse> for GiteaBranch {
     }
 }
 
+#[derive(Deserialize)]
+struct GiteaRepoCommitResponse {
+    sha: String,
+    commit: GiteaRepoCommitInner,
+    author: Option<GiteaCommitAuthorResponse>,
+}
+
+#[derive(Deserialize)]
+struct GiteaRepoCommitInner {
+    message: String,
+    author: GiteaCommitSignature,
+}
+
+#[derive(Deserialize)]
+struct GiteaCommitSignature {
+    date: String,
+}
+
+#[derive(Deserialize)]
+struct GiteaCommitAuthorResponse {
+    login: String,
+}
+
+impl From<GiteaRepoCommitResponse> for GiteaCommitDetails {
+    fn from(r: GiteaRepoCommitResponse) -> Self {
+        GiteaCommitDetails {
+            sha: r.sha,
+            message: r.commit.message,
+            author_login: r.author.map(|a| a.login),
+            timestamp: r.commit.author.date,
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct GiteaCompareResponse {
+    total_commits: i64,
+    #[serde(default)]
+    commits: Vec<GiteaRepoCommitResponse>,
+}
+
 #[derive(Deserialize)]
 struct GiteaPRResponse {
     id: i64,
@@ -270,6 +486,30 @@ struct GiteaPRResponse {
     base: GiteaPRBranchResponse,
     merged: bool,
     user: Option<GiteaUserResponse>,
+    merged_at: Option<String>,
+    merge_commit_sha: Option<String>,
+    #[serde(default)]
+    draft: bool,
+}
+
+#[derive(Deserialize)]
+struct GiteaPRMergeStatusResponse {
+    state: String,
+    merged: bool,
+    #[serde(default)]
+    mergeable: bool,
+    head: GiteaPRBranchResponse,
+}
+
+impl From<GiteaPRMergeStatusResponse> for GiteaMergeStatus {
+    fn from(r: GiteaPRMergeStatusResponse) -> Self {
+        GiteaMergeStatus {
+            state: r.state,
+            merged: r.merged,
+            mergeable: r.mergeable,
+            head_sha: r.head.sha,
+        }
+    }
 }
 
 #[derive(Deserialize)]
@@ -298,6 +538,9 @@ impl From<GiteaPRResponse> for GiteaPullRequest {
             },
             merged: r.merged,
             user: r.user.map(|u| u.into()),
+            merged_at: r.merged_at,
+            merge_commit_sha: r.merge_commit_sha,
+            draft: r.draft,
         }
     }
 }
@@ -323,6 +566,44 @@ impl From<GiteaCommentResponse> for GiteaComment {
     }
 }
 
+#[derive(Deserialize)]
+struct GiteaNotificationResponse {
+    id: i64,
+    repository: GiteaNotificationRepo,
+    subject: GiteaNotificationSubject,
+    unread: bool,
+    updated_at: String,
+}
+
+#[derive(Deserialize)]
+struct GiteaNotificationRepo {
+    full_name: String,
+}
+
+#[derive(Deserialize)]
+struct GiteaNotificationSubject {
+    title: String,
+    #[serde(rename = "type")]
+    subject_type: String,
+    state: Option<String>,
+    html_url: Option<String>,
+}
+
+impl From<GiteaNotificationResponse> for GiteaNotification {
+    fn from(r: GiteaNotificationResponse) -> Self {
+        GiteaNotification {
+            id: r.id,
+            repository: r.repository.full_name,
+            subject_type: r.subject.subject_type,
+            subject_title: r.subject.title,
+            subject_state: r.subject.state.filter(|s| !s.is_empty()),
+            html_url: r.subject.html_url.filter(|u| !u.is_empty()),
+            unread: r.unread,
+            updated_at: r.updated_at,
+        }
+    }
+}
+
 #[derive(Deserialize)]
 struct GiteaPRReviewResponse {
     id: i64,
@@ -432,19 +713,48 @@ impl GiteaClient for GiteaClientImpl {
                 password,
                 must_change_password: false,
             })
-            .send()
+            .send_logged(&self.limiter, "/admin/users")
             .await?;
 
         let user: GiteaUserResponse = self.handle_response(resp).await?;
         Ok(user.into())
     }
 
+    async fn delete_user(&self, username: &str) -> Result<(), GiteaError> {
+        let resp = self
+            .http
+            .delete(self.api_url(&format!("/admin/users/{}", username)))
+            .header("Authorization", format!("token {}", self.admin_token))
+            .send_logged(&self.limiter, "/admin/users/{username}")
+            .await?;
+
+        self.handle_empty_response(resp).await
+    }
+
+    async fn set_user_password(&self, username: &str, password: &str) -> Result<(), GiteaError> {
+        let resp = self
+            .http
+            .patch(self.api_url(&format!("/admin/users/{}", username)))
+            .header("Authorization", format!("token {}", self.admin_token))
+            .json(&EditUserPasswordRequest {
+                login_name: username,
+                source_id: 0,
+                password,
+                must_change_password: false,
+            })
+            .send_logged(&self.limiter, "/admin/users/{username}")
+            .await?;
+
+        let _: GiteaUserResponse = self.handle_response(resp).await?;
+        Ok(())
+    }
+
     async fn get_user(&self, username: &str) -> Result<GiteaUser, GiteaError> {
         let resp = self
             .http
             .get(self.api_url(&format!("/users/{}", username)))
             .header("Authorization", format!("token {}", self.admin_token))
-            .send()
+            .send_logged(&self.limiter, "/users/{username}")
             .await?;
 
         if resp.status().as_u16() == 404 {
@@ -455,6 +765,37 @@ impl GiteaClient for GiteaClientImpl {
         Ok(user.into())
     }
 
+    async fn get_user_heatmap(&self, username: &str) -> Result<Vec<GiteaHeatmapEntry>, GiteaError> {
+        let resp = self
+            .http
+            .get(self.api_url(&format!("/users/{}/heatmap", username)))
+            .header("Authorization", format!("token {}", self.admin_token))
+            .send_logged(&self.limiter, "/users/{username}/heatmap")
+            .await?;
+
+        if resp.status().as_u16() == 404 {
+            return Err(GiteaError::UserNotFound(username.to_string()));
+        }
+
+        self.handle_response(resp).await
+    }
+
+    async fn get_notifications(
+        &self,
+        user_token: &str,
+    ) -> Result<Vec<GiteaNotification>, GiteaError> {
+        // Only the agent's own token: its inbox is what that token can see
+        let resp = self
+            .http
+            .get(self.api_url("/notifications"))
+            .header("Authorization", format!("token {}", user_token))
+            .send_logged(&self.limiter, "/notifications")
+            .await?;
+
+        let threads: Vec<GiteaNotificationResponse> = self.handle_response(resp).await?;
+        Ok(threads.into_iter().map(Into::into).collect())
+    }
+
     async fn create_access_token(
         &self,
         username: &str,
@@ -475,7 +816,7 @@ impl GiteaClient for GiteaClientImpl {
                     "write:organization",
                 ],
             })
-            .send()
+            .send_logged(&self.limiter, "/users/{username}/tokens")
             .await?;
 
         let token: CreateTokenResponse = self.handle_response(resp).await?;
@@ -491,7 +832,7 @@ impl GiteaClient for GiteaClientImpl {
             .http
             .delete(self.api_url(&format!("/users/{}/tokens/{}", username, token_name)))
             .header("Authorization", format!("token {}", self.admin_token))
-            .send()
+            .send_logged(&self.limiter, "/users/{username}/tokens/{token_name}")
             .await?;
 
         self.handle_empty_response(resp).await
@@ -510,7 +851,7 @@ impl GiteaClient for GiteaClientImpl {
                 username: name,
                 description,
             })
-            .send()
+            .send_logged(&self.limiter, "/orgs")
             .await?;
 
         let org: GiteaOrgResponse = self.handle_response(resp).await?;
@@ -522,7 +863,7 @@ impl GiteaClient for GiteaClientImpl {
             .http
             .get(self.api_url(&format!("/orgs/{}", name)))
             .header("Authorization", format!("token {}", self.admin_token))
-            .send()
+            .send_logged(&self.limiter, "/orgs/{org}")
             .await?;
 
         if resp.status().as_u16() == 404 {
@@ -538,7 +879,7 @@ impl GiteaClient for GiteaClientImpl {
             .http
             .put(self.api_url(&format!("/orgs/{}/members/{}", org, username)))
             .header("Authorization", format!("token {}", self.admin_token))
-            .send()
+            .send_logged(&self.limiter, "/orgs/{org}/members/{username}")
             .await?;
 
         self.handle_empty_response(resp).await
@@ -550,7 +891,7 @@ impl GiteaClient for GiteaClientImpl {
             .http
             .get(self.api_url(&format!("/orgs/{}/teams", org)))
             .header("Authorization", format!("token {}", self.admin_token))
-            .send()
+            .send_logged(&self.limiter, "/orgs/{org}/teams")
             .await?;
 
         let teams: Vec<GiteaTeamResponse> = self.handle_response(resp).await?;
@@ -570,7 +911,7 @@ impl GiteaClient for GiteaClientImpl {
             .http
             .put(self.api_url(&format!("/teams/{}/members/{}", owners_team.id, username)))
             .header("Authorization", format!("token {}", self.admin_token))
-            .send()
+            .send_logged(&self.limiter, "/teams/{team_id}/members/{username}")
             .await?;
 
         self.handle_empty_response(resp).await
@@ -615,7 +956,7 @@ impl GiteaClient for GiteaClientImpl {
                 includes_all_repositories: true,
                 units,
             })
-            .send()
+            .send_logged(&self.limiter, "/orgs/{org}/teams")
             .await?;
 
         let team: GiteaTeamResponse = self.handle_response(resp).await?;
@@ -628,7 +969,7 @@ impl GiteaClient for GiteaClientImpl {
             .http
             .get(self.api_url(&format!("/orgs/{}/teams", org)))
             .header("Authorization", format!("token {}", self.admin_token))
-            .send()
+            .send_logged(&self.limiter, "/orgs/{org}/teams")
             .await?;
 
         let teams: Vec<GiteaTeamResponse> = self.handle_response(resp).await?;
@@ -651,7 +992,7 @@ impl GiteaClient for GiteaClientImpl {
                 maintainers_team_id, username
             )))
             .header("Authorization", format!("token {}", self.admin_token))
-            .send()
+            .send_logged(&self.limiter, "/teams/{team_id}/members/{username}")
             .await?;
 
         self.handle_empty_response(resp).await
@@ -663,7 +1004,7 @@ impl GiteaClient for GiteaClientImpl {
             .http
             .get(self.api_url(&format!("/orgs/{}/teams", org)))
             .header("Authorization", format!("token {}", self.admin_token))
-            .send()
+            .send_logged(&self.limiter, "/orgs/{org}/teams")
             .await?;
 
         let teams: Vec<GiteaTeamResponse> = self.handle_response(resp).await?;
@@ -683,7 +1024,7 @@ impl GiteaClient for GiteaClientImpl {
                 maintainers_team.id, username
             )))
             .header("Authorization", format!("token {}", self.admin_token))
-            .send()
+            .send_logged(&self.limiter, "/teams/{team_id}/members/{username}")
             .await?;
 
         self.handle_empty_response(resp).await
@@ -695,7 +1036,7 @@ impl GiteaClient for GiteaClientImpl {
             .http
             .get(self.api_url(&format!("/orgs/{}/teams", org)))
             .header("Authorization", format!("token {}", self.admin_token))
-            .send()
+            .send_logged(&self.limiter, "/orgs/{org}/teams")
             .await?;
 
         let teams: Vec<GiteaTeamResponse> = self.handle_response(resp).await?;
@@ -711,7 +1052,7 @@ impl GiteaClient for GiteaClientImpl {
             .http
             .get(self.api_url(&format!("/teams/{}/members", maintainers_team.id)))
             .header("Authorization", format!("token {}", self.admin_token))
-            .send()
+            .send_logged(&self.limiter, "/teams/{team_id}/members")
             .await?;
 
         #[derive(Deserialize)]
@@ -728,7 +1069,7 @@ impl GiteaClient for GiteaClientImpl {
             .http
             .get(self.api_url(&format!("/users/{}/orgs", username)))
             .header("Authorization", format!("token {}", self.admin_token))
-            .send()
+            .send_logged(&self.limiter, "/users/{username}/orgs")
             .await?;
 
         let orgs: Vec<GiteaOrgResponse> = self.handle_response(resp).await?;
@@ -741,7 +1082,7 @@ impl GiteaClient for GiteaClientImpl {
             .http
             .get(self.api_url(&format!("/orgs/{}/teams", org)))
             .header("Authorization", format!("token {}", self.admin_token))
-            .send()
+            .send_logged(&self.limiter, "/orgs/{org}/teams")
             .await?;
 
         if resp.status().as_u16() == 404 {
@@ -761,7 +1102,7 @@ impl GiteaClient for GiteaClientImpl {
             .http
             .get(self.api_url(&format!("/teams/{}/members", owners_team.id)))
             .header("Authorization", format!("token {}", self.admin_token))
-            .send()
+            .send_logged(&self.limiter, "/teams/{team_id}/members")
             .await?;
 
         #[derive(Deserialize)]
@@ -791,7 +1132,38 @@ impl GiteaClient for GiteaClientImpl {
                 private,
                 auto_init,
             })
-            .send()
+            .send_logged(&self.limiter, "/orgs/{org}/repos")
+            .await?;
+
+        let repo: GiteaRepoResponse = self.handle_response(resp).await?;
+        Ok(repo.into())
+    }
+
+    async fn create_repo_from_template(
+        &self,
+        template_owner: &str,
+        template_repo: &str,
+        owner: &str,
+        name: &str,
+        description: Option<&str>,
+    ) -> Result<GiteaRepo, GiteaError> {
+        let resp = self
+            .http
+            .post(self.api_url(&format!(
+                "/repos/{}/{}/generate",
+                template_owner, template_repo
+            )))
+            .header("Authorization", format!("token {}", self.admin_token))
+            .json(&GenerateRepoRequest {
+                owner,
+                name,
+                description,
+                private: false,
+                git_content: true,
+                topics: true,
+                labels: true,
+            })
+            .send_logged(&self.limiter, "/repos/{owner}/{repo}/generate")
             .await?;
 
         let repo: GiteaRepoResponse = self.handle_response(resp).await?;
@@ -818,7 +1190,7 @@ impl GiteaClient for GiteaClientImpl {
                 private,
                 auto_init,
             })
-            .send()
+            .send_logged(&self.limiter, "/user/repos")
             .await?;
 
         let repo: GiteaRepoResponse = self.handle_response(resp).await?;
@@ -830,7 +1202,7 @@ impl GiteaClient for GiteaClientImpl {
             .http
             .get(self.api_url(&format!("/repos/{}/{}", owner, name)))
             .header("Authorization", format!("token {}", self.admin_token))
-            .send()
+            .send_logged(&self.limiter, "/repos/{owner}/{repo}")
             .await?;
 
         if resp.status().as_u16() == 404 {
@@ -844,6 +1216,48 @@ impl GiteaClient for GiteaClientImpl {
         Ok(repo.into())
     }
 
+    async fn star_repo(&self, owner: &str, repo: &str, user_token: &str) -> Result<(), GiteaError> {
+        // Stars belong to whoever the token authenticates as
+        let resp = self
+            .http
+            .put(self.api_url(&format!("/user/starred/{}/{}", owner, repo)))
+            .header("Authorization", format!("token {}", user_token))
+            .send_logged(&self.limiter, "/user/starred/{owner}/{repo}")
+            .await?;
+
+        self.handle_empty_response(resp).await
+    }
+
+    async fn get_org_repos(&self, org: &str) -> Result<Vec<GiteaRepo>, GiteaError> {
+        let mut repos = Vec::new();
+        let mut page = 1;
+
+        loop {
+            let resp = self
+                .http
+                .get(self.api_url(&format!(
+                    "/orgs/{}/repos?page={}&limit={}",
+                    org, page, PAGE_LIMIT
+                )))
+                .header("Authorization", format!("token {}", self.admin_token))
+                .send_logged(&self.limiter, "/orgs/{org}/repos")
+                .await?;
+
+            if resp.status().as_u16() == 404 {
+                return Err(GiteaError::OrgNotFound(org.to_string()));
+            }
+
+            let batch: Vec<GiteaRepoResponse> = self.handle_response(resp).await?;
+            let done = batch.len() < PAGE_LIMIT;
+            repos.extend(batch.into_iter().map(GiteaRepo::from));
+
+            if done {
+                return Ok(repos);
+            }
+            page += 1;
+        }
+    }
+
     async fn fork_repo(
         &self,
         owner: &str,
@@ -857,69 +1271,254 @@ impl GiteaClient for GiteaClientImpl {
             .json(&ForkRepoRequest {
                 organization: Some(new_owner),
             })
-            .send()
+            .send_logged(&self.limiter, "/repos/{owner}/{repo}/forks")
             .await?;
 
         let forked: GiteaRepoResponse = self.handle_response(resp).await?;
         Ok(forked.into())
     }
 
+    async fn create_mirror_repo(
+        &self,
+        source_url: &str,
+        org: &str,
+        name: &str,
+    ) -> Result<GiteaRepo, GiteaError> {
+        let resp = self
+            .http
+            .post(self.api_url("/repos/migrate"))
+            .header("Authorization", format!("token {}", self.admin_token))
+            .json(&MigrateRepoRequest {
+                clone_addr: source_url,
+                repo_owner: org,
+                repo_name: name,
+                mirror: true,
+                service: "git",
+            })
+            .send_logged(&self.limiter, "/repos/migrate")
+            .await?;
+
+        if resp.status().as_u16() == 404 {
+            return Err(GiteaError::OrgNotFound(org.to_string()));
+        }
+
+        let repo: GiteaRepoResponse = self.handle_response(resp).await?;
+        Ok(repo.into())
+    }
+
     async fn delete_repo(&self, owner: &str, name: &str) -> Result<(), GiteaError> {
         let resp = self
             .http
             .delete(self.api_url(&format!("/repos/{}/{}", owner, name)))
             .header("Authorization", format!("token {}", self.admin_token))
-            .send()
+            .send_logged(&self.limiter, "/repos/{owner}/{repo}")
             .await?;
 
         self.handle_empty_response(resp).await
     }
 
-    async fn create_file(
+    async fn update_repo(
         &self,
         owner: &str,
-        repo: &str,
-        path: &str,
-        content: &str,
-        message: &str,
-        user_token: Option<&str>,
-    ) -> Result<(), GiteaError> {
-        use base64::Engine;
-        let encoded_content = base64::engine::general_purpose::STANDARD.encode(content);
-        let token = user_token.unwrap_or(&self.admin_token);
-
+        name: &str,
+        settings: &RepoSettings,
+    ) -> Result<GiteaRepo, GiteaError> {
         let resp = self
             .http
-            .post(self.api_url(&format!("/repos/{}/{}/contents/{}", owner, repo, path)))
-            .header("Authorization", format!("token {}", token))
-            .json(&serde_json::json!({
-                "content": encoded_content,
-                "message": message
-            }))
-            .send()
+            .patch(self.api_url(&format!("/repos/{}/{}", owner, name)))
+            .header("Authorization", format!("token {}", self.admin_token))
+            .json(settings)
+            .send_logged(&self.limiter, "/repos/{owner}/{repo}")
             .await?;
 
-        // 201 = created, 200 = updated
-        if resp.status().is_success() {
-            Ok(())
-        } else {
-            let status = resp.status();
-            let body = resp.text().await.unwrap_or_default();
-            Err(GiteaError::Api {
-                status: status.as_u16(),
-                message: body,
-            })
+        if resp.status().as_u16() == 404 {
+            return Err(GiteaError::RepoNotFound {
+                owner: owner.to_string(),
+                repo: name.to_string(),
+            });
         }
+
+        let repo: GiteaRepoResponse = self.handle_response(resp).await?;
+        Ok(repo.into())
     }
 
-    async fn add_collaborator(
+    async fn set_branch_protection(
         &self,
         owner: &str,
         repo: &str,
-        username: &str,
-        permission: &str,
+        branch: &str,
+        rules: &BranchProtection,
     ) -> Result<(), GiteaError> {
-        let resp = self
+        let mut body = BranchProtectionRequest {
+            rule_name: Some(branch),
+            enable_push: !rules.require_pull_request,
+            required_approvals: rules.required_approvals,
+            block_on_rejected_reviews: rules.block_on_rejected_reviews,
+            enable_status_check: rules.require_status_checks,
+            status_check_contexts: &rules.status_check_contexts,
+        };
+        let rule_url = self.api_url(&format!(
+            "/repos/{}/{}/branch_protections/{}",
+            owner,
+            repo,
+            encode(branch)
+        ));
+
+        // Look the rule up first rather than guessing from the create error,
+        // so a permission failure isn't mistaken for an existing rule
+        let existing = self
+            .http
+            .get(&rule_url)
+            .header("Authorization", format!("token {}", self.admin_token))
+            .send_logged(
+                &self.limiter,
+                "/repos/{owner}/{repo}/branch_protections/{name}",
+            )
+            .await?;
+
+        let resp = match existing.status().as_u16() {
+            404 => {
+                self.http
+                    .post(self.api_url(&format!("/repos/{}/{}/branch_protections", owner, repo)))
+                    .header("Authorization", format!("token {}", self.admin_token))
+                    .json(&body)
+                    .send_logged(&self.limiter, "/repos/{owner}/{repo}/branch_protections")
+                    .await?
+            }
+            status if (200..300).contains(&status) => {
+                body.rule_name = None;
+                self.http
+                    .patch(&rule_url)
+                    .header("Authorization", format!("token {}", self.admin_token))
+                    .json(&body)
+                    .send_logged(
+                        &self.limiter,
+                        "/repos/{owner}/{repo}/branch_protections/{name}",
+                    )
+                    .await?
+            }
+            _ => return self.handle_empty_response(existing).await,
+        };
+
+        if resp.status().as_u16() == 404 {
+            return Err(GiteaError::RepoNotFound {
+                owner: owner.to_string(),
+                repo: repo.to_string(),
+            });
+        }
+        self.handle_empty_response(resp).await
+    }
+
+    async fn remove_branch_protection(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+    ) -> Result<(), GiteaError> {
+        let resp = self
+            .http
+            .delete(self.api_url(&format!(
+                "/repos/{}/{}/branch_protections/{}",
+                owner,
+                repo,
+                encode(branch)
+            )))
+            .header("Authorization", format!("token {}", self.admin_token))
+            .send_logged(
+                &self.limiter,
+                "/repos/{owner}/{repo}/branch_protections/{name}",
+            )
+            .await?;
+
+        if resp.status().as_u16() == 404 {
+            return Ok(());
+        }
+        self.handle_empty_response(resp).await
+    }
+
+    async fn create_file(
+        &self,
+        owner: &str,
+        repo: &str,
+        path: &str,
+        content: &str,
+        message: &str,
+        user_token: Option<&str>,
+    ) -> Result<(), GiteaError> {
+        use base64::Engine;
+        let encoded_content = base64::engine::general_purpose::STANDARD.encode(content);
+        let token = user_token.unwrap_or(&self.admin_token);
+
+        let resp = self
+            .http
+            .post(self.api_url(&format!("/repos/{}/{}/contents/{}", owner, repo, path)))
+            .header("Authorization", format!("token {}", token))
+            .json(&serde_json::json!({
+                "content": encoded_content,
+                "message": message
+            }))
+            .send_logged(&self.limiter, "/repos/{owner}/{repo}/contents/{path}")
+            .await?;
+
+        // 201 = created, 200 = updated
+        if resp.status().is_success() {
+            Ok(())
+        } else {
+            let status = resp.status();
+            let body = resp.text().await.unwrap_or_default();
+            Err(GiteaError::Api {
+                status: status.as_u16(),
+                message: body,
+            })
+        }
+    }
+
+    async fn get_contents_at_ref(
+        &self,
+        owner: &str,
+        repo: &str,
+        path: &str,
+        git_ref: &str,
+    ) -> Result<Option<String>, GiteaError> {
+        let resp = self
+            .http
+            .get(self.api_url(&format!(
+                "/repos/{}/{}/raw/{}?ref={}",
+                owner,
+                repo,
+                path.trim_start_matches('/'),
+                encode(git_ref)
+            )))
+            .header("Authorization", format!("token {}", self.admin_token))
+            .send_logged(&self.limiter, "/repos/{owner}/{repo}/raw/{filepath}")
+            .await?;
+
+        let status = resp.status();
+        if status.is_success() {
+            resp.text().await.map(Some).map_err(GiteaError::Request)
+        } else if status.as_u16() == 404 {
+            Ok(None)
+        } else if status.as_u16() == 401 {
+            Err(GiteaError::Unauthorized)
+        } else if status.as_u16() == 429 {
+            Err(GiteaError::RateLimited)
+        } else {
+            let message = resp.text().await.unwrap_or_default();
+            Err(GiteaError::Api {
+                status: status.as_u16(),
+                message,
+            })
+        }
+    }
+
+    async fn add_collaborator(
+        &self,
+        owner: &str,
+        repo: &str,
+        username: &str,
+        permission: &str,
+    ) -> Result<(), GiteaError> {
+        let resp = self
             .http
             .put(self.api_url(&format!(
                 "/repos/{}/{}/collaborators/{}",
@@ -927,7 +1526,10 @@ impl GiteaClient for GiteaClientImpl {
             )))
             .header("Authorization", format!("token {}", self.admin_token))
             .json(&AddCollaboratorRequest { permission })
-            .send()
+            .send_logged(
+                &self.limiter,
+                "/repos/{owner}/{repo}/collaborators/{username}",
+            )
             .await?;
 
         self.handle_empty_response(resp).await
@@ -951,7 +1553,7 @@ impl GiteaClient for GiteaClientImpl {
             .http
             .get(&url)
             .header("Authorization", format!("token {}", self.admin_token))
-            .send()
+            .send_logged(&self.limiter, "/repos/{owner}/{repo}/branches/{branch}")
             .await?;
 
         tracing::debug!("get_branch: status {}", resp.status());
@@ -964,13 +1566,70 @@ impl GiteaClient for GiteaClientImpl {
             .http
             .get(self.api_url(&format!("/repos/{}/{}/branches", owner, repo)))
             .header("Authorization", format!("token {}", self.admin_token))
-            .send()
+            .send_logged(&self.limiter, "/repos/{owner}/{repo}/branches")
             .await?;
 
         let branches: Vec<GiteaBranchResponse> = self.handle_response(resp).await?;
         Ok(branches.into_iter().map(|b| b.into()).collect())
     }
 
+    async fn create_branch(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+        from: Option<&str>,
+    ) -> Result<GiteaBranch, GiteaError> {
+        let resp = self
+            .http
+            .post(self.api_url(&format!("/repos/{}/{}/branches", owner, repo)))
+            .header("Authorization", format!("token {}", self.admin_token))
+            .json(&CreateBranchRequest {
+                new_branch_name: branch,
+                old_branch_name: from,
+            })
+            .send_logged(&self.limiter, "/repos/{owner}/{repo}/branches")
+            .await?;
+
+        let branch: GiteaBranchResponse = self.handle_response(resp).await?;
+        Ok(branch.into())
+    }
+
+    async fn get_commit(
+        &self,
+        owner: &str,
+        repo: &str,
+        sha: &str,
+    ) -> Result<GiteaCommitDetails, GiteaError> {
+        let resp = self
+            .http
+            .get(self.api_url(&format!("/repos/{}/{}/git/commits/{}", owner, repo, sha)))
+            .header("Authorization", format!("token {}", self.admin_token))
+            .send_logged(&self.limiter, "/repos/{owner}/{repo}/git/commits/{sha}")
+            .await?;
+
+        let commit: GiteaRepoCommitResponse = self.handle_response(resp).await?;
+        Ok(commit.into())
+    }
+
+    async fn compare_commits(
+        &self,
+        owner: &str,
+        repo: &str,
+        base: &str,
+        head: &str,
+    ) -> Result<GiteaComparison, GiteaError> {
+        // Gitea only reports the commits head has over base, so compare both ways
+        let ahead = self.compare_refs(owner, repo, base, head).await?;
+        let behind = self.compare_refs(owner, repo, head, base).await?;
+
+        Ok(GiteaComparison {
+            ahead_by: ahead.total_commits,
+            behind_by: behind.total_commits,
+            commits: ahead.commits.into_iter().map(Into::into).collect(),
+        })
+    }
+
     async fn create_pull_request(
         &self,
         owner: &str,
@@ -992,7 +1651,7 @@ impl GiteaClient for GiteaClientImpl {
                 head,
                 base,
             })
-            .send()
+            .send_logged(&self.limiter, "/repos/{owner}/{repo}/pulls")
             .await?;
 
         let pr: GiteaPRResponse = self.handle_response(resp).await?;
@@ -1009,13 +1668,59 @@ impl GiteaClient for GiteaClientImpl {
             .http
             .get(self.api_url(&format!("/repos/{}/{}/pulls/{}", owner, repo, number)))
             .header("Authorization", format!("token {}", self.admin_token))
-            .send()
+            .send_logged(&self.limiter, "/repos/{owner}/{repo}/pulls/{number}")
             .await?;
 
         let pr: GiteaPRResponse = self.handle_response(resp).await?;
         Ok(pr.into())
     }
 
+    async fn get_pr_raw_diff(
+        &self,
+        owner: &str,
+        repo: &str,
+        number: i64,
+    ) -> Result<String, GiteaError> {
+        let resp = self
+            .http
+            .get(self.api_url(&format!("/repos/{}/{}/pulls/{}.diff", owner, repo, number)))
+            .header("Authorization", format!("token {}", self.admin_token))
+            .send_logged(&self.limiter, "/repos/{owner}/{repo}/pulls/{number}.diff")
+            .await?;
+
+        let status = resp.status();
+        if status.is_success() {
+            resp.text().await.map_err(GiteaError::Request)
+        } else if status.as_u16() == 401 {
+            Err(GiteaError::Unauthorized)
+        } else if status.as_u16() == 429 {
+            Err(GiteaError::RateLimited)
+        } else {
+            let message = resp.text().await.unwrap_or_default();
+            Err(GiteaError::Api {
+                status: status.as_u16(),
+                message,
+            })
+        }
+    }
+
+    async fn get_pull_request_merge_status(
+        &self,
+        owner: &str,
+        repo: &str,
+        number: i64,
+    ) -> Result<GiteaMergeStatus, GiteaError> {
+        let resp = self
+            .http
+            .get(self.api_url(&format!("/repos/{}/{}/pulls/{}", owner, repo, number)))
+            .header("Authorization", format!("token {}", self.admin_token))
+            .send_logged(&self.limiter, "/repos/{owner}/{repo}/pulls/{number}")
+            .await?;
+
+        let status: GiteaPRMergeStatusResponse = self.handle_response(resp).await?;
+        Ok(status.into())
+    }
+
     async fn list_pull_requests(
         &self,
         owner: &str,
@@ -1031,7 +1736,34 @@ impl GiteaClient for GiteaClientImpl {
             .http
             .get(&url)
             .header("Authorization", format!("token {}", self.admin_token))
-            .send()
+            .send_logged(&self.limiter, "/repos/{owner}/{repo}/pulls")
+            .await?;
+
+        let prs: Vec<GiteaPRResponse> = self.handle_response(resp).await?;
+        Ok(prs.into_iter().map(|pr| pr.into()).collect())
+    }
+
+    async fn list_pull_requests_page(
+        &self,
+        owner: &str,
+        repo: &str,
+        state: Option<&str>,
+        page: usize,
+        limit: usize,
+    ) -> Result<Vec<GiteaPullRequest>, GiteaError> {
+        let mut url = self.api_url(&format!(
+            "/repos/{}/{}/pulls?page={}&limit={}",
+            owner, repo, page, limit
+        ));
+        if let Some(s) = state {
+            url.push_str(&format!("&state={}", s));
+        }
+
+        let resp = self
+            .http
+            .get(&url)
+            .header("Authorization", format!("token {}", self.admin_token))
+            .send_logged(&self.limiter, "/repos/{owner}/{repo}/pulls")
             .await?;
 
         let prs: Vec<GiteaPRResponse> = self.handle_response(resp).await?;
@@ -1061,6 +1793,7 @@ impl GiteaClient for GiteaClientImpl {
         repo: &str,
         number: i64,
         merge_style: &str,
+        force: bool,
         auth_token: Option<&str>,
     ) -> Result<(), GiteaError> {
         let token = auth_token.unwrap_or(&self.admin_token);
@@ -1071,8 +1804,9 @@ impl GiteaClient for GiteaClientImpl {
             .header("Authorization", format!("token {}", token))
             .json(&MergePRRequest {
                 do_merge: merge_style,
+                force_merge: force,
             })
-            .send()
+            .send_logged(&self.limiter, "/repos/{owner}/{repo}/pulls/{number}/merge")
             .await?;
 
         self.handle_empty_response(resp).await
@@ -1089,12 +1823,59 @@ impl GiteaClient for GiteaClientImpl {
             .patch(self.api_url(&format!("/repos/{}/{}/pulls/{}", owner, repo, number)))
             .header("Authorization", format!("token {}", self.admin_token))
             .json(&serde_json::json!({"state": "closed"}))
-            .send()
+            .send_logged(&self.limiter, "/repos/{owner}/{repo}/pulls/{number}")
+            .await?;
+
+        self.handle_empty_response(resp).await
+    }
+
+    async fn request_reviewers(
+        &self,
+        owner: &str,
+        repo: &str,
+        number: i64,
+        reviewers: &[String],
+    ) -> Result<(), GiteaError> {
+        let resp = self
+            .http
+            .post(self.api_url(&format!(
+                "/repos/{}/{}/pulls/{}/requested_reviewers",
+                owner, repo, number
+            )))
+            .header("Authorization", format!("token {}", self.admin_token))
+            .json(&serde_json::json!({ "reviewers": reviewers }))
+            .send_logged(
+                &self.limiter,
+                "/repos/{owner}/{repo}/pulls/{number}/requested_reviewers",
+            )
             .await?;
 
         self.handle_empty_response(resp).await
     }
 
+    async fn get_pr_commits(
+        &self,
+        owner: &str,
+        repo: &str,
+        number: i64,
+    ) -> Result<Vec<GiteaCommitDetails>, GiteaError> {
+        let resp = self
+            .http
+            .get(self.api_url(&format!(
+                "/repos/{}/{}/pulls/{}/commits",
+                owner, repo, number
+            )))
+            .header("Authorization", format!("token {}", self.admin_token))
+            .send_logged(
+                &self.limiter,
+                "/repos/{owner}/{repo}/pulls/{number}/commits",
+            )
+            .await?;
+
+        let commits: Vec<GiteaRepoCommitResponse> = self.handle_response(resp).await?;
+        Ok(commits.into_iter().map(Into::into).collect())
+    }
+
     async fn get_pr_comments(
         &self,
         owner: &str,
@@ -1108,7 +1889,10 @@ impl GiteaClient for GiteaClientImpl {
                 owner, repo, number
             )))
             .header("Authorization", format!("token {}", self.admin_token))
-            .send()
+            .send_logged(
+                &self.limiter,
+                "/repos/{owner}/{repo}/issues/{number}/comments",
+            )
             .await?;
 
         let comments: Vec<GiteaCommentResponse> = self.handle_response(resp).await?;
@@ -1132,7 +1916,10 @@ impl GiteaClient for GiteaClientImpl {
             )))
             .header("Authorization", format!("token {}", token))
             .json(&CreateCommentRequest { body })
-            .send()
+            .send_logged(
+                &self.limiter,
+                "/repos/{owner}/{repo}/issues/{number}/comments",
+            )
             .await?;
 
         let comment: GiteaCommentResponse = self.handle_response(resp).await?;
@@ -1152,13 +1939,55 @@ impl GiteaClient for GiteaClientImpl {
                 owner, repo, number
             )))
             .header("Authorization", format!("token {}", self.admin_token))
-            .send()
+            .send_logged(
+                &self.limiter,
+                "/repos/{owner}/{repo}/pulls/{number}/reviews",
+            )
             .await?;
 
         let reviews: Vec<GiteaPRReviewResponse> = self.handle_response(resp).await?;
         Ok(reviews.into_iter().map(|r| r.into()).collect())
     }
 
+    async fn get_pr_review_by_user(
+        &self,
+        owner: &str,
+        repo: &str,
+        number: i64,
+        username: &str,
+    ) -> Result<Option<GiteaPRReview>, GiteaError> {
+        let reviews = self.get_pr_reviews(owner, repo, number).await?;
+        Ok(reviews
+            .into_iter()
+            .filter(|r| r.user.login.eq_ignore_ascii_case(username))
+            .max_by_key(|r| r.id))
+    }
+
+    async fn delete_pr_review(
+        &self,
+        owner: &str,
+        repo: &str,
+        number: i64,
+        review_id: i64,
+        auth_token: Option<&str>,
+    ) -> Result<(), GiteaError> {
+        let token = auth_token.unwrap_or(&self.admin_token);
+        let resp = self
+            .http
+            .delete(self.api_url(&format!(
+                "/repos/{}/{}/pulls/{}/reviews/{}",
+                owner, repo, number, review_id
+            )))
+            .header("Authorization", format!("token {}", token))
+            .send_logged(
+                &self.limiter,
+                "/repos/{owner}/{repo}/pulls/{number}/reviews/{review_id}",
+            )
+            .await?;
+
+        self.handle_empty_response(resp).await
+    }
+
     async fn submit_pr_review(
         &self,
         owner: &str,
@@ -1177,7 +2006,10 @@ impl GiteaClient for GiteaClientImpl {
             )))
             .header("Authorization", format!("token {}", token))
             .json(&SubmitReviewRequest { event: state, body })
-            .send()
+            .send_logged(
+                &self.limiter,
+                "/repos/{owner}/{repo}/pulls/{number}/reviews",
+            )
             .await?;
 
         let review: GiteaPRReviewResponse = self.handle_response(resp).await?;
@@ -1197,7 +2029,10 @@ impl GiteaClient for GiteaClientImpl {
                 owner, repo, ref_name
             )))
             .header("Authorization", format!("token {}", self.admin_token))
-            .send()
+            .send_logged(
+                &self.limiter,
+                "/repos/{owner}/{repo}/commits/{ref_name}/status",
+            )
             .await?;
 
         let status: GiteaCombinedStatusResponse = self.handle_response(resp).await?;
@@ -1226,7 +2061,7 @@ impl GiteaClient for GiteaClientImpl {
                 events,
                 active: true,
             })
-            .send()
+            .send_logged(&self.limiter, "/repos/{owner}/{repo}/hooks")
             .await?;
 
         let webhook: WebhookResponse = self.handle_response(resp).await?;
@@ -1243,7 +2078,7 @@ impl GiteaClient for GiteaClientImpl {
             .http
             .delete(self.api_url(&format!("/repos/{}/{}/hooks/{}", owner, repo, hook_id)))
             .header("Authorization", format!("token {}", self.admin_token))
-            .send()
+            .send_logged(&self.limiter, "/repos/{owner}/{repo}/hooks/{hook_id}")
             .await?;
 
         self.handle_empty_response(resp).await
@@ -1262,7 +2097,10 @@ impl GiteaClient for GiteaClientImpl {
                 owner, repo, issue_number
             )))
             .header("Authorization", format!("token {}", self.admin_token))
-            .send()
+            .send_logged(
+                &self.limiter,
+                "/repos/{owner}/{repo}/issues/{issue_number}/reactions",
+            )
             .await?;
 
         let reactions: Vec<GiteaReactionResponse> = self.handle_response(resp).await?;
@@ -1275,16 +2113,21 @@ impl GiteaClient for GiteaClientImpl {
         repo: &str,
         issue_number: i64,
         content: &str,
+        auth_token: Option<&str>,
     ) -> Result<GiteaReaction, GiteaError> {
+        let token = auth_token.unwrap_or(&self.admin_token);
         let resp = self
             .http
             .post(self.api_url(&format!(
                 "/repos/{}/{}/issues/{}/reactions",
                 owner, repo, issue_number
             )))
-            .header("Authorization", format!("token {}", self.admin_token))
+            .header("Authorization", format!("token {}", token))
             .json(&CreateReactionRequest { content })
-            .send()
+            .send_logged(
+                &self.limiter,
+                "/repos/{owner}/{repo}/issues/{issue_number}/reactions",
+            )
             .await?;
 
         let reaction: GiteaReactionResponse = self.handle_response(resp).await?;
@@ -1305,7 +2148,10 @@ impl GiteaClient for GiteaClientImpl {
                 owner, repo, issue_number, reaction_id
             )))
             .header("Authorization", format!("token {}", self.admin_token))
-            .send()
+            .send_logged(
+                &self.limiter,
+                "/repos/{owner}/{repo}/issues/{issue_number}/reactions/{reaction_id}",
+            )
             .await?;
 
         self.handle_empty_response(resp).await
@@ -1324,7 +2170,10 @@ impl GiteaClient for GiteaClientImpl {
                 owner, repo, comment_id
             )))
             .header("Authorization", format!("token {}", self.admin_token))
-            .send()
+            .send_logged(
+                &self.limiter,
+                "/repos/{owner}/{repo}/issues/comments/{comment_id}/reactions",
+            )
             .await?;
 
         let reactions: Vec<GiteaReactionResponse> = self.handle_response(resp).await?;
@@ -1346,7 +2195,10 @@ impl GiteaClient for GiteaClientImpl {
             )))
             .header("Authorization", format!("token {}", self.admin_token))
             .json(&CreateReactionRequest { content })
-            .send()
+            .send_logged(
+                &self.limiter,
+                "/repos/{owner}/{repo}/issues/comments/{comment_id}/reactions",
+            )
             .await?;
 
         let reaction: GiteaReactionResponse = self.handle_response(resp).await?;
@@ -1371,7 +2223,7 @@ impl GiteaClient for GiteaClientImpl {
                 "title": title,
                 "body": body.unwrap_or("")
             }))
-            .send()
+            .send_logged(&self.limiter, "/repos/{owner}/{repo}/issues")
             .await?;
 
         self.handle_response(resp).await
@@ -1382,20 +2234,45 @@ impl GiteaClient for GiteaClientImpl {
         owner: &str,
         repo: &str,
         state: Option<&str>,
+        labels: &[String],
     ) -> Result<Vec<GiteaIssue>, GiteaError> {
-        let mut url = format!("/repos/{}/{}/issues", owner, repo);
+        // Gitea lists PRs as issues unless told otherwise
+        let mut params = vec!["type=issues".to_string()];
         if let Some(s) = state {
-            url.push_str(&format!("?state={}", s));
+            params.push(format!("state={}", s));
+        }
+        if !labels.is_empty() {
+            let labels: Vec<_> = labels.iter().map(|l| encode(l).into_owned()).collect();
+            params.push(format!("labels={}", labels.join(",")));
         }
 
-        let resp = self
-            .http
-            .get(self.api_url(&url))
-            .header("Authorization", format!("token {}", self.admin_token))
-            .send()
-            .await?;
-
-        self.handle_response(resp).await
+        let mut issues = Vec::new();
+        let mut page = 1;
+
+        loop {
+            let resp = self
+                .http
+                .get(self.api_url(&format!(
+                    "/repos/{}/{}/issues?{}&page={}&limit={}",
+                    owner,
+                    repo,
+                    params.join("&"),
+                    page,
+                    PAGE_LIMIT
+                )))
+                .header("Authorization", format!("token {}", self.admin_token))
+                .send_logged(&self.limiter, "/repos/{owner}/{repo}/issues")
+                .await?;
+
+            let batch: Vec<GiteaIssue> = self.handle_response(resp).await?;
+            let done = batch.len() < PAGE_LIMIT;
+            issues.extend(batch);
+
+            if done {
+                return Ok(issues);
+            }
+            page += 1;
+        }
     }
 
     async fn get_issue(
@@ -1408,7 +2285,7 @@ impl GiteaClient for GiteaClientImpl {
             .http
             .get(self.api_url(&format!("/repos/{}/{}/issues/{}", owner, repo, number)))
             .header("Authorization", format!("token {}", self.admin_token))
-            .send()
+            .send_logged(&self.limiter, "/repos/{owner}/{repo}/issues/{number}")
             .await?;
 
         self.handle_response(resp).await
@@ -1448,7 +2325,7 @@ impl GiteaClient for GiteaClientImpl {
             .patch(self.api_url(&format!("/repos/{}/{}/issues/{}", owner, repo, number)))
             .header("Authorization", format!("token {}", token))
             .json(&payload)
-            .send()
+            .send_logged(&self.limiter, "/repos/{owner}/{repo}/issues/{number}")
             .await?;
 
         self.handle_response(resp).await
@@ -1459,15 +2336,21 @@ impl GiteaClient for GiteaClientImpl {
         owner: &str,
         repo: &str,
         number: i64,
+        since: Option<&str>,
     ) -> Result<Vec<GiteaIssueComment>, GiteaError> {
+        let mut url = format!("/repos/{}/{}/issues/{}/comments", owner, repo, number);
+        if let Some(since) = since {
+            url.push_str(&format!("?since={}", encode(since)));
+        }
+
         let resp = self
             .http
-            .get(self.api_url(&format!(
-                "/repos/{}/{}/issues/{}/comments",
-                owner, repo, number
-            )))
+            .get(self.api_url(&url))
             .header("Authorization", format!("token {}", self.admin_token))
-            .send()
+            .send_logged(
+                &self.limiter,
+                "/repos/{owner}/{repo}/issues/{number}/comments",
+            )
             .await?;
 
         self.handle_response(resp).await
@@ -1491,7 +2374,32 @@ impl GiteaClient for GiteaClientImpl {
             )))
             .header("Authorization", format!("token {}", token))
             .json(&serde_json::json!({ "body": body }))
-            .send()
+            .send_logged(
+                &self.limiter,
+                "/repos/{owner}/{repo}/issues/{number}/comments",
+            )
+            .await?;
+
+        self.handle_response(resp).await
+    }
+
+    async fn get_issue_comment(
+        &self,
+        owner: &str,
+        repo: &str,
+        comment_id: i64,
+    ) -> Result<GiteaIssueComment, GiteaError> {
+        let resp = self
+            .http
+            .get(self.api_url(&format!(
+                "/repos/{}/{}/issues/comments/{}",
+                owner, repo, comment_id
+            )))
+            .header("Authorization", format!("token {}", self.admin_token))
+            .send_logged(
+                &self.limiter,
+                "/repos/{owner}/{repo}/issues/comments/{comment_id}",
+            )
             .await?;
 
         self.handle_response(resp).await
@@ -1515,7 +2423,10 @@ impl GiteaClient for GiteaClientImpl {
             )))
             .header("Authorization", format!("token {}", token))
             .json(&serde_json::json!({ "body": body }))
-            .send()
+            .send_logged(
+                &self.limiter,
+                "/repos/{owner}/{repo}/issues/comments/{comment_id}",
+            )
             .await?;
 
         self.handle_response(resp).await
@@ -1537,7 +2448,10 @@ impl GiteaClient for GiteaClientImpl {
                 owner, repo, comment_id
             )))
             .header("Authorization", format!("token {}", token))
-            .send()
+            .send_logged(
+                &self.limiter,
+                "/repos/{owner}/{repo}/issues/comments/{comment_id}",
+            )
             .await?;
 
         self.handle_empty_response(resp).await
@@ -1556,7 +2470,10 @@ impl GiteaClient for GiteaClientImpl {
                 owner, repo, number
             )))
             .header("Authorization", format!("token {}", self.admin_token))
-            .send()
+            .send_logged(
+                &self.limiter,
+                "/repos/{owner}/{repo}/issues/{number}/labels",
+            )
             .await?;
 
         self.handle_response(resp).await
@@ -1580,7 +2497,37 @@ impl GiteaClient for GiteaClientImpl {
             )))
             .header("Authorization", format!("token {}", token))
             .json(&serde_json::json!({ "labels": labels }))
-            .send()
+            .send_logged(
+                &self.limiter,
+                "/repos/{owner}/{repo}/issues/{number}/labels",
+            )
+            .await?;
+
+        self.handle_response(resp).await
+    }
+
+    async fn replace_issue_labels(
+        &self,
+        owner: &str,
+        repo: &str,
+        number: i64,
+        labels: Vec<String>,
+        auth_token: Option<&str>,
+    ) -> Result<Vec<GiteaLabel>, GiteaError> {
+        let token = auth_token.unwrap_or(&self.admin_token);
+
+        let resp = self
+            .http
+            .put(self.api_url(&format!(
+                "/repos/{}/{}/issues/{}/labels",
+                owner, repo, number
+            )))
+            .header("Authorization", format!("token {}", token))
+            .json(&serde_json::json!({ "labels": labels }))
+            .send_logged(
+                &self.limiter,
+                "/repos/{owner}/{repo}/issues/{number}/labels",
+            )
             .await?;
 
         self.handle_response(resp).await
@@ -1606,7 +2553,10 @@ impl GiteaClient for GiteaClientImpl {
                 urlencoding::encode(label)
             )))
             .header("Authorization", format!("token {}", token))
-            .send()
+            .send_logged(
+                &self.limiter,
+                "/repos/{owner}/{repo}/issues/{number}/labels/{label}",
+            )
             .await?;
 
         self.handle_empty_response(resp).await
@@ -1628,7 +2578,7 @@ impl GiteaClient for GiteaClientImpl {
             .patch(self.api_url(&format!("/repos/{}/{}/issues/{}", owner, repo, number)))
             .header("Authorization", format!("token {}", token))
             .json(&serde_json::json!({ "assignees": assignees }))
-            .send()
+            .send_logged(&self.limiter, "/repos/{owner}/{repo}/issues/{number}")
             .await?;
 
         self.handle_response(resp).await
@@ -1659,7 +2609,7 @@ impl GiteaClient for GiteaClientImpl {
             .patch(self.api_url(&format!("/repos/{}/{}/issues/{}", owner, repo, number)))
             .header("Authorization", format!("token {}", token))
             .json(&serde_json::json!({ "assignees": current_assignees }))
-            .send()
+            .send_logged(&self.limiter, "/repos/{owner}/{repo}/issues/{number}")
             .await?;
 
         self.handle_response(resp).await
@@ -1670,13 +2620,405 @@ impl GiteaClient for GiteaClientImpl {
         owner: &str,
         repo: &str,
     ) -> Result<Vec<GiteaLabel>, GiteaError> {
+        let mut labels = Vec::new();
+        let mut page = 1;
+
+        loop {
+            let resp = self
+                .http
+                .get(self.api_url(&format!(
+                    "/repos/{}/{}/labels?page={}&limit={}",
+                    owner, repo, page, PAGE_LIMIT
+                )))
+                .header("Authorization", format!("token {}", self.admin_token))
+                .send_logged(&self.limiter, "/repos/{owner}/{repo}/labels")
+                .await?;
+
+            let batch: Vec<GiteaLabel> = self.handle_response(resp).await?;
+            let done = batch.len() < PAGE_LIMIT;
+            labels.extend(batch);
+
+            if done {
+                return Ok(labels);
+            }
+            page += 1;
+        }
+    }
+
+    async fn list_org_labels(&self, org: &str) -> Result<Vec<GiteaLabel>, GiteaError> {
+        let mut labels = Vec::new();
+        let mut page = 1;
+
+        loop {
+            let resp = self
+                .http
+                .get(self.api_url(&format!(
+                    "/orgs/{}/labels?page={}&limit={}",
+                    org, page, PAGE_LIMIT
+                )))
+                .header("Authorization", format!("token {}", self.admin_token))
+                .send_logged(&self.limiter, "/orgs/{org}/labels")
+                .await?;
+
+            // Repos in a user's namespace have no org labels
+            if resp.status().as_u16() == 404 {
+                return Ok(labels);
+            }
+
+            let batch: Vec<GiteaLabel> = self.handle_response(resp).await?;
+            let done = batch.len() < PAGE_LIMIT;
+            labels.extend(batch);
+
+            if done {
+                return Ok(labels);
+            }
+            page += 1;
+        }
+    }
+
+    async fn create_repo_label(
+        &self,
+        owner: &str,
+        repo: &str,
+        name: &str,
+        color: &str,
+    ) -> Result<GiteaLabel, GiteaError> {
         let resp = self
             .http
-            .get(self.api_url(&format!("/repos/{}/{}/labels", owner, repo)))
+            .post(self.api_url(&format!("/repos/{}/{}/labels", owner, repo)))
             .header("Authorization", format!("token {}", self.admin_token))
-            .send()
+            .json(&serde_json::json!({ "name": name, "color": color }))
+            .send_logged(&self.limiter, "/repos/{owner}/{repo}/labels")
             .await?;
 
         self.handle_response(resp).await
     }
+
+    async fn tag_exists(&self, owner: &str, repo: &str, tag: &str) -> Result<bool, GiteaError> {
+        let resp = self
+            .http
+            .get(self.api_url(&format!("/repos/{}/{}/tags/{}", owner, repo, encode(tag))))
+            .header("Authorization", format!("token {}", self.admin_token))
+            .send_logged(&self.limiter, "/repos/{owner}/{repo}/tags/{tag}")
+            .await?;
+
+        if resp.status().as_u16() == 404 {
+            return Ok(false);
+        }
+        let _: serde_json::Value = self.handle_response(resp).await?;
+        Ok(true)
+    }
+
+    async fn create_release(
+        &self,
+        owner: &str,
+        repo: &str,
+        tag: &str,
+        name: &str,
+        body: Option<&str>,
+        draft: bool,
+        prerelease: bool,
+    ) -> Result<GiteaRelease, GiteaError> {
+        let resp = self
+            .http
+            .post(self.api_url(&format!("/repos/{}/{}/releases", owner, repo)))
+            .header("Authorization", format!("token {}", self.admin_token))
+            .json(&serde_json::json!({
+                "tag_name": tag,
+                "name": name,
+                "body": body.unwrap_or_default(),
+                "draft": draft,
+                "prerelease": prerelease,
+            }))
+            .send_logged(&self.limiter, "/repos/{owner}/{repo}/releases")
+            .await?;
+
+        self.handle_response(resp).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::io;
+    use std::sync::{Arc, Mutex};
+
+    use axum::{http::StatusCode, routing::get, Router};
+    use tracing_subscriber::fmt::MakeWriter;
+
+    /// Captures formatted log output for assertions
+    #[derive(Clone, Default)]
+    struct CapturedLogs(Arc<Mutex<Vec<u8>>>);
+
+    impl CapturedLogs {
+        fn contents(&self) -> String {
+            String::from_utf8_lossy(&self.0.lock().unwrap()).to_string()
+        }
+    }
+
+    impl io::Write for CapturedLogs {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.0.lock().unwrap().extend_from_slice(buf);
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    impl<'a> MakeWriter<'a> for CapturedLogs {
+        type Writer = CapturedLogs;
+
+        fn make_writer(&'a self) -> Self::Writer {
+            self.clone()
+        }
+    }
+
+    /// Start a fake Gitea that fails every user lookup
+    async fn failing_gitea() -> String {
+        let app = Router::new().route(
+            "/api/v1/users/:username",
+            get(|| async { (StatusCode::INTERNAL_SERVER_ERROR, "boom") }),
+        );
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            axum::serve(listener, app).await.unwrap();
+        });
+        format!("http://{}", addr)
+    }
+
+    #[tokio::test]
+    async fn failing_call_logs_warning_with_templated_endpoint() {
+        let logs = CapturedLogs::default();
+        let subscriber = tracing_subscriber::fmt()
+            .with_writer(logs.clone())
+            .with_max_level(tracing::Level::WARN)
+            .with_ansi(false)
+            .finish();
+        let _guard = tracing::subscriber::set_default(subscriber);
+
+        let client = GiteaClientImpl::new(failing_gitea().await, "token".to_string());
+        let result = client.get_user("agent-alice").await;
+
+        assert!(matches!(result, Err(GiteaError::Api { status: 500, .. })));
+        let output = logs.contents();
+        assert!(output.contains("WARN"));
+        assert!(output.contains("Gitea API call failed"));
+        assert!(output.contains("endpoint=\"/users/{username}\""));
+        assert!(output.contains("status=500"));
+        assert!(!output.contains("agent-alice"));
+    }
+
+    /// Start a fake Gitea whose org lists `total` repos, served in pages
+    async fn paginated_gitea(total: usize) -> String {
+        use axum::extract::{Path, Query};
+        use std::collections::HashMap;
+
+        let app = Router::new().route(
+            "/api/v1/orgs/:org/repos",
+            get(
+                move |Path(org): Path<String>, Query(q): Query<HashMap<String, usize>>| async move {
+                    let page = q["page"];
+                    let limit = q["limit"];
+                    let repos: Vec<_> = ((page - 1) * limit..(page * limit).min(total))
+                        .map(|i| {
+                            serde_json::json!({
+                                "id": i,
+                                "name": format!("repo-{}", i),
+                                "full_name": format!("{}/repo-{}", org, i),
+                                "clone_url": "",
+                                "ssh_url": "",
+                                "html_url": "",
+                                "default_branch": "main",
+                                "private": false,
+                            })
+                        })
+                        .collect();
+                    axum::Json(repos)
+                },
+            ),
+        );
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            axum::serve(listener, app).await.unwrap();
+        });
+        format!("http://{}", addr)
+    }
+
+    /// Start a fake Gitea whose repo has `total` issues, served in pages,
+    /// that fails any issue listing not restricted to issues
+    async fn paginated_issues_gitea(total: usize) -> String {
+        use axum::extract::Query;
+        use std::collections::HashMap;
+
+        let app = Router::new().route(
+            "/api/v1/repos/:owner/:repo/issues",
+            get(move |Query(q): Query<HashMap<String, String>>| async move {
+                assert_eq!(q.get("type").map(String::as_str), Some("issues"));
+                let page: usize = q["page"].parse().unwrap();
+                let limit: usize = q["limit"].parse().unwrap();
+                let issues: Vec<_> = ((page - 1) * limit..(page * limit).min(total))
+                    .map(|i| {
+                        serde_json::json!({
+                            "id": i,
+                            "number": i + 1,
+                            "title": format!("Issue {}", i + 1),
+                            "state": "open",
+                            "html_url": "",
+                        })
+                    })
+                    .collect();
+                axum::Json(issues)
+            }),
+        );
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            axum::serve(listener, app).await.unwrap();
+        });
+        format!("http://{}", addr)
+    }
+
+    #[tokio::test]
+    async fn list_issues_follows_pagination_without_prs() {
+        let total = PAGE_LIMIT * 2 + 1;
+        let client = GiteaClientImpl::new(paginated_issues_gitea(total).await, "token".to_string());
+
+        let issues = client
+            .list_issues("antfarm", "webapp", Some("open"), &[])
+            .await
+            .unwrap();
+
+        assert_eq!(issues.len(), total);
+        assert_eq!(issues[total - 1].number, total as i64);
+    }
+
+    #[tokio::test]
+    async fn get_org_repos_follows_pagination() {
+        let total = PAGE_LIMIT + 3;
+        let client = GiteaClientImpl::new(paginated_gitea(total).await, "token".to_string());
+
+        let repos = client.get_org_repos("antfarm").await.unwrap();
+
+        assert_eq!(repos.len(), total);
+        assert_eq!(repos[0].full_name, "antfarm/repo-0");
+        assert_eq!(repos[total - 1].name, format!("repo-{}", total - 1));
+    }
+
+    /// Start a fake Gitea that records the body of repo PATCH requests
+    async fn repo_settings_gitea(received: Arc<Mutex<Option<serde_json::Value>>>) -> String {
+        use axum::extract::Path;
+        use axum::routing::patch;
+
+        let app = Router::new().route(
+            "/api/v1/repos/:owner/:repo",
+            patch(
+                move |Path((owner, repo)): Path<(String, String)>,
+                      axum::Json(body): axum::Json<serde_json::Value>| async move {
+                    let default_branch = body["default_branch"].as_str().unwrap_or("main");
+                    let response = serde_json::json!({
+                        "id": 1,
+                        "name": repo,
+                        "full_name": format!("{}/{}", owner, repo),
+                        "clone_url": "",
+                        "ssh_url": "",
+                        "html_url": "",
+                        "default_branch": default_branch,
+                        "private": false,
+                    });
+                    *received.lock().unwrap() = Some(body);
+                    axum::Json(response)
+                },
+            ),
+        );
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            axum::serve(listener, app).await.unwrap();
+        });
+        format!("http://{}", addr)
+    }
+
+    #[tokio::test]
+    async fn update_repo_sends_only_changed_settings() {
+        let received = Arc::new(Mutex::new(None));
+        let client = GiteaClientImpl::new(
+            repo_settings_gitea(received.clone()).await,
+            "token".to_string(),
+        );
+        let settings = RepoSettings {
+            default_branch: Some("develop".to_string()),
+            has_wiki: Some(false),
+            ..Default::default()
+        };
+
+        let repo = client
+            .update_repo("antfarm", "api", &settings)
+            .await
+            .unwrap();
+
+        assert_eq!(repo.default_branch, "develop");
+        assert_eq!(
+            received.lock().unwrap().clone(),
+            Some(serde_json::json!({ "default_branch": "develop", "has_wiki": false }))
+        );
+    }
+
+    /// Start a fake Gitea whose user lookups take a while, recording the
+    /// highest number of requests it saw in flight at once
+    async fn slow_gitea(peak: Arc<std::sync::atomic::AtomicUsize>) -> String {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        let in_flight = Arc::new(AtomicUsize::new(0));
+        let app = Router::new().route(
+            "/api/v1/users/:username",
+            get(
+                move |axum::extract::Path(username): axum::extract::Path<String>| {
+                    let in_flight = in_flight.clone();
+                    let peak = peak.clone();
+                    async move {
+                        let now = in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+                        peak.fetch_max(now, Ordering::SeqCst);
+                        tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+                        in_flight.fetch_sub(1, Ordering::SeqCst);
+                        axum::Json(serde_json::json!({
+                            "id": 1,
+                            "login": username,
+                            "email": "agent@test.com",
+                        }))
+                    }
+                },
+            ),
+        );
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            axum::serve(listener, app).await.unwrap();
+        });
+        format!("http://{}", addr)
+    }
+
+    #[tokio::test]
+    async fn concurrent_requests_are_bounded() {
+        let peak = Arc::new(std::sync::atomic::AtomicUsize::new(0));
+        let client = Arc::new(
+            GiteaClientImpl::new(slow_gitea(peak.clone()).await, "token".to_string())
+                .with_max_concurrent_requests(3),
+        );
+
+        let mut calls = tokio::task::JoinSet::new();
+        for i in 0..10 {
+            let client = client.clone();
+            calls.spawn(async move { client.get_user(&format!("agent-{}", i)).await });
+        }
+        while let Some(result) = calls.join_next().await {
+            result.unwrap().unwrap();
+        }
+
+        assert_eq!(peak.load(std::sync::atomic::Ordering::SeqCst), 3);
+    }
 }
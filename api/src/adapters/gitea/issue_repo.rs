@@ -6,7 +6,8 @@ use async_trait::async_trait;
 use std::sync::Arc;
 
 use crate::domain::entities::{
-    Issue, IssueComment, IssueId, IssueState, Label, NewIssue, Project, ProjectId,
+    AvailableLabel, CommentFilter, Issue, IssueComment, IssueId, IssueState, Label, LabelFilter,
+    NewIssue, Project, ProjectId,
 };
 use crate::domain::ports::{GiteaClient, GiteaIssue, IssueRepository, ProjectRepository};
 use crate::error::DomainError;
@@ -32,6 +33,33 @@ impl GiteaIssueRepository {
             .ok_or_else(|| DomainError::NotFound(format!("Project {} not found", project_id.0)))
     }
 
+    /// Fetch a comment and check that `author` wrote it
+    async fn verify_comment_author(
+        &self,
+        project: &Project,
+        comment_id: i64,
+        author: &str,
+    ) -> Result<(), DomainError> {
+        let comment = self
+            .gitea
+            .get_issue_comment(&project.gitea_org, &project.gitea_repo, comment_id)
+            .await
+            .map_err(|e| match e {
+                crate::error::GiteaError::Api { status: 404, .. } => {
+                    DomainError::NotFound(format!("Comment {} not found", comment_id))
+                }
+                e => DomainError::Internal(format!("Gitea error: {}", e)),
+            })?;
+
+        if comment.user.login != author {
+            return Err(DomainError::Forbidden(format!(
+                "Comment {} was written by {}; only its author can change it",
+                comment_id, comment.user.login
+            )));
+        }
+        Ok(())
+    }
+
     fn convert_issue(&self, project_id: ProjectId, gi: GiteaIssue) -> Issue {
         Issue {
             id: IssueId::new(project_id, gi.number),
@@ -59,18 +87,26 @@ impl IssueRepository for GiteaIssueRepository {
         &self,
         project_id: &ProjectId,
         state: Option<&str>,
+        labels: Option<&LabelFilter>,
     ) -> Result<Vec<Issue>, DomainError> {
         let project = self.get_project(project_id).await?;
 
+        // Gitea only supports all-match, so any-match is filtered here instead
+        let pushed_down = match labels {
+            Some(filter) if filter.can_push_down() => filter.labels.as_slice(),
+            _ => &[],
+        };
+
         let gitea_issues = self
             .gitea
-            .list_issues(&project.gitea_org, &project.gitea_repo, state)
+            .list_issues(&project.gitea_org, &project.gitea_repo, state, pushed_down)
             .await
             .map_err(|e| DomainError::Internal(format!("Gitea error: {}", e)))?;
 
         Ok(gitea_issues
             .into_iter()
             .map(|gi| self.convert_issue(*project_id, gi))
+            .filter(|issue| labels.is_none_or(|f| f.matches(&issue.labels)))
             .collect())
     }
 
@@ -178,25 +214,38 @@ impl IssueRepository for GiteaIssueRepository {
         Ok(self.convert_issue(id.project_id, gi))
     }
 
-    async fn list_comments(&self, id: &IssueId) -> Result<Vec<IssueComment>, DomainError> {
+    async fn list_comments(
+        &self,
+        id: &IssueId,
+        filter: &CommentFilter,
+    ) -> Result<Vec<IssueComment>, DomainError> {
         let project = self.get_project(&id.project_id).await?;
 
+        // Gitea filters by `since`; author and order are applied here
+        let since = filter.since.map(|s| s.to_rfc3339());
         let comments = self
             .gitea
-            .list_issue_comments(&project.gitea_org, &project.gitea_repo, id.number)
+            .list_issue_comments(
+                &project.gitea_org,
+                &project.gitea_repo,
+                id.number,
+                since.as_deref(),
+            )
             .await
             .map_err(|e| DomainError::Internal(format!("Gitea error: {}", e)))?;
 
-        Ok(comments
-            .into_iter()
-            .map(|c| IssueComment {
-                id: c.id,
-                body: c.body,
-                author: c.user.login,
-                created_at: c.created_at,
-                updated_at: c.updated_at,
-            })
-            .collect())
+        Ok(filter.apply(
+            comments
+                .into_iter()
+                .map(|c| IssueComment {
+                    id: c.id,
+                    body: c.body,
+                    author: c.user.login,
+                    created_at: c.created_at,
+                    updated_at: c.updated_at,
+                })
+                .collect(),
+        ))
     }
 
     async fn add_comment(
@@ -233,9 +282,12 @@ impl IssueRepository for GiteaIssueRepository {
         id: &IssueId,
         comment_id: i64,
         body: &str,
+        author: &str,
         agent_token: Option<&str>,
     ) -> Result<IssueComment, DomainError> {
         let project = self.get_project(&id.project_id).await?;
+        self.verify_comment_author(&project, comment_id, author)
+            .await?;
 
         let c = self
             .gitea
@@ -262,9 +314,12 @@ impl IssueRepository for GiteaIssueRepository {
         &self,
         id: &IssueId,
         comment_id: i64,
+        author: &str,
         agent_token: Option<&str>,
     ) -> Result<(), DomainError> {
         let project = self.get_project(&id.project_id).await?;
+        self.verify_comment_author(&project, comment_id, author)
+            .await?;
 
         self.gitea
             .delete_issue_comment(
@@ -326,6 +381,36 @@ impl IssueRepository for GiteaIssueRepository {
             .collect())
     }
 
+    async fn replace_labels(
+        &self,
+        id: &IssueId,
+        labels: Vec<String>,
+        agent_token: Option<&str>,
+    ) -> Result<Vec<Label>, DomainError> {
+        let project = self.get_project(&id.project_id).await?;
+
+        let result = self
+            .gitea
+            .replace_issue_labels(
+                &project.gitea_org,
+                &project.gitea_repo,
+                id.number,
+                labels,
+                agent_token,
+            )
+            .await
+            .map_err(|e| DomainError::Internal(format!("Gitea error: {}", e)))?;
+
+        Ok(result
+            .into_iter()
+            .map(|l| Label {
+                name: l.name,
+                color: l.color,
+                description: l.description,
+            })
+            .collect())
+    }
+
     async fn remove_label(
         &self,
         id: &IssueId,
@@ -395,22 +480,249 @@ impl IssueRepository for GiteaIssueRepository {
     async fn list_available_labels(
         &self,
         project_id: &ProjectId,
-    ) -> Result<Vec<Label>, DomainError> {
+    ) -> Result<Vec<AvailableLabel>, DomainError> {
         let project = self.get_project(project_id).await?;
 
-        let labels = self
+        let mut labels = self
             .gitea
             .list_repo_labels(&project.gitea_org, &project.gitea_repo)
             .await
             .map_err(|e| DomainError::Internal(format!("Gitea error: {}", e)))?;
+        // Org-level labels can be applied to any of the org's repos
+        labels.extend(
+            self.gitea
+                .list_org_labels(&project.gitea_org)
+                .await
+                .map_err(|e| DomainError::Internal(format!("Gitea error: {}", e)))?,
+        );
+
+        // Labels deleted from the repo stay on the issues that had them
+        let issues = self
+            .gitea
+            .list_issues(&project.gitea_org, &project.gitea_repo, Some("open"), &[])
+            .await
+            .map_err(|e| DomainError::Internal(format!("Gitea error: {}", e)))?;
 
-        Ok(labels
+        let repo_labels = labels
             .into_iter()
             .map(|l| Label {
                 name: l.name,
                 color: l.color,
                 description: l.description,
             })
-            .collect())
+            .collect();
+        let applied = issues
+            .into_iter()
+            .flat_map(|gi| self.convert_issue(*project_id, gi).labels);
+
+        Ok(AvailableLabel::reconcile(repo_labels, applied))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::entities::CommentOrder;
+    use crate::test_utils::{test_project, InMemoryProjectRepository, MockGiteaClient};
+
+    fn repo_with_comment(project: &Project, author: &str) -> GiteaIssueRepository {
+        let gitea = MockGiteaClient::new().with_issue_comment(
+            &project.gitea_org,
+            &project.gitea_repo,
+            11,
+            author,
+            "first draft",
+        );
+        GiteaIssueRepository::new(
+            Arc::new(gitea),
+            Arc::new(InMemoryProjectRepository::new().with_project(project.clone())),
+        )
+    }
+
+    #[tokio::test]
+    async fn author_can_edit_own_comment() {
+        let project = test_project();
+        let repo = repo_with_comment(&project, "agent-alice");
+
+        let comment = repo
+            .edit_comment(
+                &IssueId::new(project.id, 3),
+                11,
+                "second draft",
+                "agent-alice",
+                None,
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(comment.body, "second draft");
+        assert_eq!(comment.author, "agent-alice");
+    }
+
+    #[tokio::test]
+    async fn non_author_cannot_edit_or_delete_comment() {
+        let project = test_project();
+        let repo = repo_with_comment(&project, "agent-alice");
+        let id = IssueId::new(project.id, 3);
+
+        let edited = repo
+            .edit_comment(&id, 11, "hijacked", "agent-mallory", None)
+            .await;
+        assert!(matches!(edited, Err(DomainError::Forbidden(_))));
+
+        let deleted = repo.delete_comment(&id, 11, "agent-mallory", None).await;
+        assert!(matches!(deleted, Err(DomainError::Forbidden(_))));
+    }
+
+    fn repo_with_thread(project: &Project) -> GiteaIssueRepository {
+        let gitea = MockGiteaClient::new()
+            .with_issue_comment(
+                &project.gitea_org,
+                &project.gitea_repo,
+                1,
+                "agent-alice",
+                "a",
+            )
+            .with_issue_comment(&project.gitea_org, &project.gitea_repo, 2, "agent-bob", "b")
+            .with_issue_comment(
+                &project.gitea_org,
+                &project.gitea_repo,
+                3,
+                "Agent-Alice",
+                "c",
+            );
+        GiteaIssueRepository::new(
+            Arc::new(gitea),
+            Arc::new(InMemoryProjectRepository::new().with_project(project.clone())),
+        )
+    }
+
+    #[tokio::test]
+    async fn list_comments_filters_by_author() {
+        let project = test_project();
+        let repo = repo_with_thread(&project);
+        let filter = CommentFilter {
+            author: Some("agent-alice".to_string()),
+            ..Default::default()
+        };
+
+        let comments = repo
+            .list_comments(&IssueId::new(project.id, 3), &filter)
+            .await
+            .unwrap();
+
+        let ids: Vec<_> = comments.iter().map(|c| c.id).collect();
+        assert_eq!(ids, vec![1, 3]);
+    }
+
+    #[tokio::test]
+    async fn list_comments_in_descending_order() {
+        let project = test_project();
+        let repo = repo_with_thread(&project);
+        let filter = CommentFilter {
+            order: CommentOrder::Desc,
+            ..Default::default()
+        };
+
+        let comments = repo
+            .list_comments(&IssueId::new(project.id, 3), &filter)
+            .await
+            .unwrap();
+
+        let ids: Vec<_> = comments.iter().map(|c| c.id).collect();
+        assert_eq!(ids, vec![3, 2, 1]);
+    }
+
+    #[tokio::test]
+    async fn edit_unknown_comment_is_not_found() {
+        let project = test_project();
+        let repo = repo_with_comment(&project, "agent-alice");
+
+        let result = repo
+            .edit_comment(&IssueId::new(project.id, 3), 99, "x", "agent-alice", None)
+            .await;
+
+        assert!(matches!(result, Err(DomainError::NotFound(_))));
+    }
+
+    #[tokio::test]
+    async fn replace_labels_drops_the_old_set() {
+        let project = test_project();
+        let gitea = MockGiteaClient::new().with_issue_labels(
+            &project.gitea_org,
+            &project.gitea_repo,
+            7,
+            &["bug", "wip"],
+        );
+        let repo = GiteaIssueRepository::new(
+            Arc::new(gitea),
+            Arc::new(InMemoryProjectRepository::new().with_project(project.clone())),
+        );
+        let id = IssueId::new(project.id, 7);
+
+        let replaced = repo
+            .replace_labels(&id, vec!["enhancement".to_string()], None)
+            .await
+            .unwrap();
+
+        assert_eq!(replaced.len(), 1);
+        let names: Vec<_> = repo
+            .list_labels(&id)
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|l| l.name)
+            .collect();
+        assert_eq!(names, vec!["enhancement"]);
+    }
+
+    #[tokio::test]
+    async fn available_labels_surface_deleted_but_applied_label() {
+        let project = test_project();
+        // "wontfix" was deleted from the repo but is still on issue #7
+        let gitea = MockGiteaClient::new().with_issue_labels(
+            &project.gitea_org,
+            &project.gitea_repo,
+            7,
+            &["bug", "wontfix"],
+        );
+        let repo = GiteaIssueRepository::new(
+            Arc::new(gitea),
+            Arc::new(InMemoryProjectRepository::new().with_project(project.clone())),
+        );
+
+        let available = repo.list_available_labels(&project.id).await.unwrap();
+
+        let orphans: Vec<_> = available
+            .iter()
+            .filter(|a| a.orphaned)
+            .map(|a| a.label.name.as_str())
+            .collect();
+        assert_eq!(orphans, vec!["wontfix"]);
+        assert_eq!(
+            available.iter().filter(|a| a.label.name == "bug").count(),
+            1
+        );
+    }
+
+    #[tokio::test]
+    async fn available_labels_include_org_labels() {
+        let project = test_project();
+        let gitea = MockGiteaClient::new()
+            .with_org_label(&project.gitea_org, "triage")
+            .with_issue_labels(&project.gitea_org, &project.gitea_repo, 7, &["triage"]);
+        let repo = GiteaIssueRepository::new(
+            Arc::new(gitea),
+            Arc::new(InMemoryProjectRepository::new().with_project(project.clone())),
+        );
+
+        let available = repo.list_available_labels(&project.id).await.unwrap();
+
+        let triage: Vec<_> = available
+            .iter()
+            .filter(|a| a.label.name == "triage")
+            .collect();
+        assert_eq!(triage.len(), 1);
+        assert!(!triage[0].orphaned);
     }
 }
@@ -9,7 +9,9 @@ pub mod postgres;
 pub use clickhouse::NoopAnalyticsClient;
 pub use gitea::{GiteaClientImpl, GiteaIssueRepository};
 pub use postgres::{
-    PostgresAgentRepository, PostgresAgentReviewRepository, PostgresCodeContributionRepository,
-    PostgresEloEventRepository, PostgresEngagementRepository, PostgresProjectRepository,
-    PostgresTicketRepository, PostgresViralMomentRepository,
+    PostgresAgentMergeRepository, PostgresAgentRepository, PostgresAgentReviewRepository,
+    PostgresCodeContributionRepository, PostgresEloEventRepository, PostgresEloIntegrityRepository,
+    PostgresEngagementRepository, PostgresPendingPenaltyRepository, PostgresProjectRepository,
+    PostgresReviewRequestRepository, PostgresTicketRepository, PostgresViralMomentRepository,
+    PostgresWebhookDeliveryRepository,
 };
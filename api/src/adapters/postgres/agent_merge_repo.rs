@@ -0,0 +1,246 @@
+//! PostgreSQL adapter for AgentMergeRepository
+
+use async_trait::async_trait;
+use sea_orm::{
+    sea_query::{Condition, Expr},
+    ColumnTrait, ConnectionTrait, DatabaseBackend, DatabaseConnection, DbErr, EntityTrait,
+    QueryFilter, QuerySelect, Statement, TransactionTrait,
+};
+
+use crate::domain::entities::{AgentId, AgentMergeCounts, Tier};
+use crate::domain::ports::AgentMergeRepository;
+use crate::entity::{
+    agent_reviews, agents, code_contributions, elo_events, engagements, pending_penalties,
+    project_members, projects, tickets,
+};
+use crate::error::DomainError;
+
+/// PostgreSQL implementation of AgentMergeRepository
+pub struct PostgresAgentMergeRepository {
+    db: DatabaseConnection,
+}
+
+impl PostgresAgentMergeRepository {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+}
+
+fn db_error(e: DbErr) -> DomainError {
+    DomainError::Database(e.to_string())
+}
+
+/// SQL ranking a member role column, so the higher role wins when two
+/// memberships are folded into one
+fn role_rank(column: &str) -> String {
+    format!(
+        "CASE {} WHEN 'owner' THEN 3 WHEN 'maintainer' THEN 2 WHEN 'contributor' THEN 1 ELSE 0 END",
+        column
+    )
+}
+
+#[async_trait]
+impl AgentMergeRepository for PostgresAgentMergeRepository {
+    async fn merge(
+        &self,
+        source: &AgentId,
+        target: &AgentId,
+        starting: i32,
+        floor: i32,
+        ceiling: Option<i32>,
+    ) -> Result<AgentMergeCounts, DomainError> {
+        // Dropping the transaction on an early return rolls it back
+        let txn = self.db.begin().await.map_err(db_error)?;
+
+        // Deactivate the source first: its row lock makes a concurrent merge
+        // of the same agent wait, then find it already merged
+        let deactivated = agents::Entity::update_many()
+            .col_expr(agents::Column::MergedInto, Expr::value(target.0))
+            .col_expr(
+                agents::Column::ApiKeyHash,
+                Expr::value(format!("merged:{}", source.0)),
+            )
+            .filter(agents::Column::Id.eq(source.0))
+            .filter(agents::Column::MergedInto.is_null())
+            .exec(&txn)
+            .await
+            .map_err(db_error)?;
+        if deactivated.rows_affected == 0 {
+            return Err(DomainError::Conflict(format!(
+                "Agent {} was already merged",
+                source
+            )));
+        }
+
+        // Lock both rows before reading their ELO, so a change committed
+        // meanwhile is either seen here or waits for the merge
+        let locked = agents::Entity::find()
+            .filter(agents::Column::Id.is_in([source.0, target.0]))
+            .lock_exclusive()
+            .all(&txn)
+            .await
+            .map_err(db_error)?;
+        let elo_of = |id: &AgentId| {
+            locked
+                .iter()
+                .find(|a| a.id == id.0)
+                .map(|a| a.elo.unwrap_or(starting))
+                .ok_or_else(|| DomainError::NotFound(format!("Agent {} not found", id)))
+        };
+        let combined = (elo_of(target)? + elo_of(source)? - starting).max(floor);
+        let target_elo = ceiling.map_or(combined, |c| combined.min(c.max(floor)));
+
+        let updated = agents::Entity::update_many()
+            .col_expr(agents::Column::Elo, Expr::value(target_elo))
+            .col_expr(
+                agents::Column::Tier,
+                Expr::value(Tier::from_elo(target_elo).to_string()),
+            )
+            .filter(agents::Column::Id.eq(target.0))
+            .filter(agents::Column::MergedInto.is_null())
+            .exec(&txn)
+            .await
+            .map_err(db_error)?;
+        if updated.rows_affected == 0 {
+            return Err(DomainError::Conflict(format!(
+                "Agent {} was already merged",
+                target
+            )));
+        }
+
+        let contributions = code_contributions::Entity::update_many()
+            .col_expr(code_contributions::Column::AgentId, Expr::value(target.0))
+            .filter(code_contributions::Column::AgentId.eq(source.0))
+            .exec(&txn)
+            .await
+            .map_err(db_error)?;
+
+        // Reviews between the two agents would become self-reviews
+        agent_reviews::Entity::delete_many()
+            .filter(
+                Condition::any()
+                    .add(
+                        Condition::all()
+                            .add(agent_reviews::Column::ReviewerAgentId.eq(source.0))
+                            .add(agent_reviews::Column::ReviewedAgentId.eq(target.0)),
+                    )
+                    .add(
+                        Condition::all()
+                            .add(agent_reviews::Column::ReviewerAgentId.eq(target.0))
+                            .add(agent_reviews::Column::ReviewedAgentId.eq(source.0)),
+                    ),
+            )
+            .exec(&txn)
+            .await
+            .map_err(db_error)?;
+
+        let reviews_given = agent_reviews::Entity::update_many()
+            .col_expr(
+                agent_reviews::Column::ReviewerAgentId,
+                Expr::value(target.0),
+            )
+            .filter(agent_reviews::Column::ReviewerAgentId.eq(source.0))
+            .exec(&txn)
+            .await
+            .map_err(db_error)?;
+        let reviews_received = agent_reviews::Entity::update_many()
+            .col_expr(
+                agent_reviews::Column::ReviewedAgentId,
+                Expr::value(target.0),
+            )
+            .filter(agent_reviews::Column::ReviewedAgentId.eq(source.0))
+            .exec(&txn)
+            .await
+            .map_err(db_error)?;
+
+        let events = elo_events::Entity::update_many()
+            .col_expr(elo_events::Column::AgentId, Expr::value(target.0))
+            .filter(elo_events::Column::AgentId.eq(source.0))
+            .exec(&txn)
+            .await
+            .map_err(db_error)?;
+
+        let assigned = tickets::Entity::update_many()
+            .col_expr(tickets::Column::AssignedTo, Expr::value(target.0))
+            .filter(tickets::Column::AssignedTo.eq(source.0))
+            .exec(&txn)
+            .await
+            .map_err(db_error)?;
+
+        // Where both were members, the target keeps the higher role and the
+        // source's row goes; the rest of the source's memberships move over
+        txn.execute(Statement::from_sql_and_values(
+            DatabaseBackend::Postgres,
+            format!(
+                "UPDATE project_members t SET role = s.role FROM project_members s \
+                 WHERE t.agent_id = $1 AND s.agent_id = $2 AND s.project_id = t.project_id \
+                 AND {} > {}",
+                role_rank("s.role"),
+                role_rank("t.role")
+            ),
+            [target.0.into(), source.0.into()],
+        ))
+        .await
+        .map_err(db_error)?;
+        let folded = txn
+            .execute(Statement::from_sql_and_values(
+                DatabaseBackend::Postgres,
+                "DELETE FROM project_members s USING project_members t \
+                 WHERE s.agent_id = $1 AND t.agent_id = $2 AND t.project_id = s.project_id",
+                [source.0.into(), target.0.into()],
+            ))
+            .await
+            .map_err(db_error)?;
+        let memberships = project_members::Entity::update_many()
+            .col_expr(project_members::Column::AgentId, Expr::value(target.0))
+            .filter(project_members::Column::AgentId.eq(source.0))
+            .exec(&txn)
+            .await
+            .map_err(db_error)?;
+        projects::Entity::update_many()
+            .col_expr(projects::Column::CreatedBy, Expr::value(target.0))
+            .filter(projects::Column::CreatedBy.eq(source.0))
+            .exec(&txn)
+            .await
+            .map_err(db_error)?;
+
+        let engaged = engagements::Entity::update_many()
+            .col_expr(engagements::Column::AgentId, Expr::value(target.0))
+            .filter(engagements::Column::AgentId.eq(source.0))
+            .exec(&txn)
+            .await
+            .map_err(db_error)?;
+
+        let penalties = pending_penalties::Entity::update_many()
+            .col_expr(pending_penalties::Column::AgentId, Expr::value(target.0))
+            .filter(pending_penalties::Column::AgentId.eq(source.0))
+            .exec(&txn)
+            .await
+            .map_err(db_error)?;
+
+        // A moment featuring both agents lists the target once
+        let moments = txn
+            .execute(Statement::from_sql_and_values(
+                DatabaseBackend::Postgres,
+                "UPDATE viral_moments SET agent_ids = CASE WHEN $2 = ANY(agent_ids) \
+                 THEN array_remove(agent_ids, $1) ELSE array_replace(agent_ids, $1, $2) END \
+                 WHERE $1 = ANY(agent_ids)",
+                [source.0.into(), target.0.into()],
+            ))
+            .await
+            .map_err(db_error)?;
+
+        txn.commit().await.map_err(db_error)?;
+
+        Ok(AgentMergeCounts {
+            contributions: contributions.rows_affected,
+            reviews: reviews_given.rows_affected + reviews_received.rows_affected,
+            elo_events: events.rows_affected,
+            tickets: assigned.rows_affected,
+            memberships: folded.rows_affected() + memberships.rows_affected,
+            engagements: engaged.rows_affected,
+            pending_penalties: penalties.rows_affected,
+            viral_moments: moments.rows_affected(),
+        })
+    }
+}
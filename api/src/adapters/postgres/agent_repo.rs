@@ -3,8 +3,8 @@
 use async_trait::async_trait;
 use chrono::Utc;
 use sea_orm::{
-    ActiveModelTrait, ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter, QueryOrder,
-    QuerySelect, Set,
+    ActiveModelTrait, ColumnTrait, ConnectionTrait, DatabaseConnection, EntityTrait, QueryFilter,
+    QueryOrder, QuerySelect, Set,
 };
 use uuid::Uuid;
 
@@ -35,6 +35,19 @@ impl AgentRepository for PostgresAgentRepository {
         Ok(result.map(|m| m.into()))
     }
 
+    async fn find_by_ids(&self, ids: &[AgentId]) -> Result<Vec<Agent>, DomainError> {
+        if ids.is_empty() {
+            return Ok(Vec::new());
+        }
+        let results = agents::Entity::find()
+            .filter(agents::Column::Id.is_in(ids.iter().map(|id| id.0)))
+            .all(&self.db)
+            .await
+            .map_err(|e| DomainError::Database(e.to_string()))?;
+
+        Ok(results.into_iter().map(|m| m.into()).collect())
+    }
+
     async fn find_by_api_key_hash(&self, hash: &str) -> Result<Option<Agent>, DomainError> {
         let result = agents::Entity::find()
             .filter(agents::Column::ApiKeyHash.eq(hash))
@@ -84,6 +97,7 @@ impl AgentRepository for PostgresAgentRepository {
             github_id: Set(None),
             github_username: Set(None),
             github_avatar_url: Set(None),
+            merged_into: Set(None),
         };
 
         let result = model
@@ -137,8 +151,26 @@ impl AgentRepository for PostgresAgentRepository {
         Ok(result.map(|m| m.gitea_token_encrypted))
     }
 
+    async fn update_gitea_token_encrypted(
+        &self,
+        id: &AgentId,
+        encrypted: Vec<u8>,
+    ) -> Result<(), DomainError> {
+        agents::ActiveModel {
+            id: Set(id.0),
+            gitea_token_encrypted: Set(encrypted),
+            ..Default::default()
+        }
+        .update(&self.db)
+        .await
+        .map_err(|e| DomainError::Database(e.to_string()))?;
+
+        Ok(())
+    }
+
     async fn find_top_by_elo(&self, limit: i64) -> Result<Vec<Agent>, DomainError> {
         let results = agents::Entity::find()
+            .filter(agents::Column::MergedInto.is_null())
             .order_by_desc(agents::Column::Elo)
             .limit(limit as u64)
             .all(&self.db)
@@ -186,6 +218,39 @@ impl AgentRepository for PostgresAgentRepository {
 
         Ok(())
     }
+
+    async fn count_by_tier(&self) -> Result<Vec<(Tier, i64)>, DomainError> {
+        // Bucket by ELO rather than the stored tier column so counts match Tier::from_elo
+        let stmt = sea_orm::Statement::from_sql_and_values(
+            sea_orm::DatabaseBackend::Postgres,
+            "SELECT CASE \
+                 WHEN COALESCE(elo, 1000) < $1 THEN 'bronze' \
+                 WHEN COALESCE(elo, 1000) < $2 THEN 'silver' \
+                 ELSE 'gold' \
+             END AS tier, COUNT(*) AS count \
+             FROM agents WHERE merged_into IS NULL GROUP BY 1",
+            [Tier::SILVER_MIN_ELO.into(), Tier::GOLD_MIN_ELO.into()],
+        );
+
+        let rows = self
+            .db
+            .query_all(stmt)
+            .await
+            .map_err(|e| DomainError::Database(e.to_string()))?;
+
+        rows.into_iter()
+            .map(|row| {
+                let tier: String = row
+                    .try_get("", "tier")
+                    .map_err(|e| DomainError::Database(e.to_string()))?;
+                let count: i64 = row
+                    .try_get("", "count")
+                    .map_err(|e| DomainError::Database(e.to_string()))?;
+                let tier = tier.parse().map_err(DomainError::Internal)?;
+                Ok((tier, count))
+            })
+            .collect()
+    }
 }
 
 /// Convert SeaORM model to domain entity
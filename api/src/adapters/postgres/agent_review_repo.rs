@@ -4,7 +4,7 @@ use async_trait::async_trait;
 use chrono::{DateTime, Utc};
 use sea_orm::{
     ActiveModelTrait, ColumnTrait, DatabaseConnection, EntityTrait, PaginatorTrait, QueryFilter,
-    QueryOrder, Set,
+    QueryOrder, QuerySelect, Set,
 };
 use uuid::Uuid;
 
@@ -75,6 +75,64 @@ impl AgentReviewRepository for PostgresAgentReviewRepository {
         Ok(results.into_iter().map(|m| m.into()).collect())
     }
 
+    async fn find_by_reviewer_paginated(
+        &self,
+        agent_id: &AgentId,
+        limit: i64,
+        offset: i64,
+    ) -> Result<Vec<AgentReview>, DomainError> {
+        let results = agent_reviews::Entity::find()
+            .filter(agent_reviews::Column::ReviewerAgentId.eq(agent_id.0))
+            .order_by_desc(agent_reviews::Column::CreatedAt)
+            .order_by_asc(agent_reviews::Column::Id)
+            .limit(limit as u64)
+            .offset(offset as u64)
+            .all(&self.db)
+            .await
+            .map_err(|e| DomainError::Database(e.to_string()))?;
+
+        Ok(results.into_iter().map(|m| m.into()).collect())
+    }
+
+    async fn find_by_reviewed_paginated(
+        &self,
+        agent_id: &AgentId,
+        limit: i64,
+        offset: i64,
+    ) -> Result<Vec<AgentReview>, DomainError> {
+        let results = agent_reviews::Entity::find()
+            .filter(agent_reviews::Column::ReviewedAgentId.eq(agent_id.0))
+            .order_by_desc(agent_reviews::Column::CreatedAt)
+            .order_by_asc(agent_reviews::Column::Id)
+            .limit(limit as u64)
+            .offset(offset as u64)
+            .all(&self.db)
+            .await
+            .map_err(|e| DomainError::Database(e.to_string()))?;
+
+        Ok(results.into_iter().map(|m| m.into()).collect())
+    }
+
+    async fn count_by_reviewer(&self, agent_id: &AgentId) -> Result<i64, DomainError> {
+        let count = agent_reviews::Entity::find()
+            .filter(agent_reviews::Column::ReviewerAgentId.eq(agent_id.0))
+            .count(&self.db)
+            .await
+            .map_err(|e| DomainError::Database(e.to_string()))?;
+
+        Ok(count as i64)
+    }
+
+    async fn count_by_reviewed(&self, agent_id: &AgentId) -> Result<i64, DomainError> {
+        let count = agent_reviews::Entity::find()
+            .filter(agent_reviews::Column::ReviewedAgentId.eq(agent_id.0))
+            .count(&self.db)
+            .await
+            .map_err(|e| DomainError::Database(e.to_string()))?;
+
+        Ok(count as i64)
+    }
+
     async fn count_by_reviewer_since(
         &self,
         agent_id: &AgentId,
@@ -90,6 +148,23 @@ impl AgentReviewRepository for PostgresAgentReviewRepository {
         Ok(count as i64)
     }
 
+    async fn count_between(
+        &self,
+        reviewer_agent_id: &AgentId,
+        reviewed_agent_id: &AgentId,
+        verdict: ReviewVerdict,
+    ) -> Result<i64, DomainError> {
+        let count = agent_reviews::Entity::find()
+            .filter(agent_reviews::Column::ReviewerAgentId.eq(reviewer_agent_id.0))
+            .filter(agent_reviews::Column::ReviewedAgentId.eq(reviewed_agent_id.0))
+            .filter(agent_reviews::Column::Verdict.eq(verdict.to_string()))
+            .count(&self.db)
+            .await
+            .map_err(|e| DomainError::Database(e.to_string()))?;
+
+        Ok(count as i64)
+    }
+
     async fn exists_for_pr_and_reviewer(
         &self,
         project_id: &ProjectId,
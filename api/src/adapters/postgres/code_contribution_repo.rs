@@ -2,8 +2,11 @@
 
 use async_trait::async_trait;
 use chrono::{DateTime, Utc};
+use sea_orm::prelude::DateTimeWithTimeZone;
+use sea_orm::sea_query::Expr;
 use sea_orm::{
-    ActiveModelTrait, ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter, QueryOrder, Set,
+    ActiveModelTrait, ColumnTrait, Condition, DatabaseConnection, EntityTrait, PaginatorTrait,
+    QueryFilter, QueryOrder, QuerySelect, QueryTrait, Set,
 };
 use uuid::Uuid;
 
@@ -79,6 +82,25 @@ impl CodeContributionRepository for PostgresCodeContributionRepository {
         Ok(results.into_iter().map(|m| m.into()).collect())
     }
 
+    async fn find_by_agent_paginated(
+        &self,
+        agent_id: &AgentId,
+        limit: i64,
+        offset: i64,
+    ) -> Result<Vec<CodeContribution>, DomainError> {
+        let results = code_contributions::Entity::find()
+            .filter(code_contributions::Column::AgentId.eq(agent_id.0))
+            .order_by_desc(code_contributions::Column::MergedAt)
+            .order_by_asc(code_contributions::Column::Id)
+            .limit(limit as u64)
+            .offset(offset as u64)
+            .all(&self.db)
+            .await
+            .map_err(|e| DomainError::Database(e.to_string()))?;
+
+        Ok(results.into_iter().map(|m| m.into()).collect())
+    }
+
     async fn find_by_project(
         &self,
         project_id: &ProjectId,
@@ -93,6 +115,156 @@ impl CodeContributionRepository for PostgresCodeContributionRepository {
         Ok(results.into_iter().map(|m| m.into()).collect())
     }
 
+    async fn find_by_agent_and_status(
+        &self,
+        agent_id: &AgentId,
+        status: ContributionStatus,
+    ) -> Result<Vec<CodeContribution>, DomainError> {
+        let results = code_contributions::Entity::find()
+            .filter(code_contributions::Column::AgentId.eq(agent_id.0))
+            .filter(code_contributions::Column::Status.eq(status.to_string()))
+            .order_by_desc(code_contributions::Column::MergedAt)
+            .all(&self.db)
+            .await
+            .map_err(|e| DomainError::Database(e.to_string()))?;
+
+        Ok(results.into_iter().map(|m| m.into()).collect())
+    }
+
+    async fn find_by_project_and_status(
+        &self,
+        project_id: &ProjectId,
+        status: ContributionStatus,
+    ) -> Result<Vec<CodeContribution>, DomainError> {
+        let results = code_contributions::Entity::find()
+            .filter(code_contributions::Column::ProjectId.eq(project_id.0))
+            .filter(code_contributions::Column::Status.eq(status.to_string()))
+            .order_by_desc(code_contributions::Column::MergedAt)
+            .all(&self.db)
+            .await
+            .map_err(|e| DomainError::Database(e.to_string()))?;
+
+        Ok(results.into_iter().map(|m| m.into()).collect())
+    }
+
+    async fn count_by_agent_and_project_since(
+        &self,
+        agent_id: &AgentId,
+        project_id: &ProjectId,
+        since: DateTime<Utc>,
+    ) -> Result<i64, DomainError> {
+        let count = code_contributions::Entity::find()
+            .filter(code_contributions::Column::AgentId.eq(agent_id.0))
+            .filter(code_contributions::Column::ProjectId.eq(project_id.0))
+            .filter(code_contributions::Column::MergedAt.gte(since.fixed_offset()))
+            .count(&self.db)
+            .await
+            .map_err(|e| DomainError::Database(e.to_string()))?;
+
+        Ok(count as i64)
+    }
+
+    async fn find_revert_times(
+        &self,
+        agent_id: &AgentId,
+    ) -> Result<Vec<DateTime<Utc>>, DomainError> {
+        let times: Vec<DateTimeWithTimeZone> = code_contributions::Entity::find()
+            .select_only()
+            .column(code_contributions::Column::RevertedAt)
+            .filter(code_contributions::Column::AgentId.eq(agent_id.0))
+            .filter(code_contributions::Column::RevertedAt.is_not_null())
+            .order_by_asc(code_contributions::Column::RevertedAt)
+            .into_tuple()
+            .all(&self.db)
+            .await
+            .map_err(|e| DomainError::Database(e.to_string()))?;
+
+        Ok(times.into_iter().map(|t| t.with_timezone(&Utc)).collect())
+    }
+
+    async fn count_healthy_between(
+        &self,
+        agent_id: &AgentId,
+        since: Option<DateTime<Utc>>,
+        merged_by: DateTime<Utc>,
+        at: DateTime<Utc>,
+    ) -> Result<i64, DomainError> {
+        let at = at.fixed_offset();
+        let mut query = code_contributions::Entity::find()
+            .filter(code_contributions::Column::AgentId.eq(agent_id.0))
+            .filter(code_contributions::Column::MergedAt.lte(merged_by.fixed_offset()))
+            .filter(
+                Condition::any()
+                    .add(code_contributions::Column::RevertedAt.is_null())
+                    .add(code_contributions::Column::RevertedAt.gt(at)),
+            )
+            .filter(
+                Condition::any()
+                    .add(code_contributions::Column::ReplacedAt.is_null())
+                    .add(code_contributions::Column::ReplacedAt.gt(at)),
+            );
+        if let Some(since) = since {
+            query = query.filter(code_contributions::Column::MergedAt.gt(since.fixed_offset()));
+        }
+        let count = query
+            .count(&self.db)
+            .await
+            .map_err(|e| DomainError::Database(e.to_string()))?;
+
+        Ok(count as i64)
+    }
+
+    async fn find_shared_projects(
+        &self,
+        agent_id: &AgentId,
+        other_agent_id: &AgentId,
+    ) -> Result<Vec<ProjectId>, DomainError> {
+        let ids: Vec<Uuid> = code_contributions::Entity::find()
+            .select_only()
+            .column(code_contributions::Column::ProjectId)
+            .distinct()
+            .filter(code_contributions::Column::AgentId.eq(agent_id.0))
+            .filter(
+                code_contributions::Column::ProjectId.in_subquery(
+                    code_contributions::Entity::find()
+                        .select_only()
+                        .column(code_contributions::Column::ProjectId)
+                        .filter(code_contributions::Column::AgentId.eq(other_agent_id.0))
+                        .into_query(),
+                ),
+            )
+            .order_by_asc(code_contributions::Column::ProjectId)
+            .into_tuple()
+            .all(&self.db)
+            .await
+            .map_err(|e| DomainError::Database(e.to_string()))?;
+
+        Ok(ids.into_iter().map(ProjectId).collect())
+    }
+
+    async fn count_in_projects(
+        &self,
+        agent_id: &AgentId,
+        projects: &[ProjectId],
+        status: Option<ContributionStatus>,
+    ) -> Result<i64, DomainError> {
+        if projects.is_empty() {
+            return Ok(0);
+        }
+        let mut query = code_contributions::Entity::find()
+            .filter(code_contributions::Column::AgentId.eq(agent_id.0))
+            .filter(code_contributions::Column::ProjectId.is_in(projects.iter().map(|p| p.0)));
+        if let Some(status) = status {
+            query = query.filter(code_contributions::Column::Status.eq(status.to_string()));
+        }
+        let count = query
+            .count(&self.db)
+            .await
+            .map_err(|e| DomainError::Database(e.to_string()))?;
+
+        Ok(count as i64)
+    }
+
     async fn find_eligible_for_longevity_bonus(
         &self,
         threshold: DateTime<Utc>,
@@ -176,17 +348,23 @@ impl CodeContributionRepository for PostgresCodeContributionRepository {
         Ok(())
     }
 
-    async fn mark_longevity_bonus_paid(&self, id: &CodeContributionId) -> Result<(), DomainError> {
-        code_contributions::ActiveModel {
-            id: Set(id.0),
-            longevity_bonus_paid: Set(true),
-            ..Default::default()
-        }
-        .update(&self.db)
-        .await
-        .map_err(|e| DomainError::Database(e.to_string()))?;
+    async fn mark_longevity_bonus_paid(
+        &self,
+        id: &CodeContributionId,
+    ) -> Result<bool, DomainError> {
+        // Conditional update so only one caller can claim the bonus
+        let result = code_contributions::Entity::update_many()
+            .col_expr(
+                code_contributions::Column::LongevityBonusPaid,
+                Expr::value(true),
+            )
+            .filter(code_contributions::Column::Id.eq(id.0))
+            .filter(code_contributions::Column::LongevityBonusPaid.eq(false))
+            .exec(&self.db)
+            .await
+            .map_err(|e| DomainError::Database(e.to_string()))?;
 
-        Ok(())
+        Ok(result.rows_affected > 0)
     }
 
     async fn increment_bug_count(&self, id: &CodeContributionId) -> Result<(), DomainError> {
@@ -1,7 +1,7 @@
 //! PostgreSQL adapter for EloEventRepository
 
 use async_trait::async_trait;
-use chrono::Utc;
+use chrono::{DateTime, Utc};
 use sea_orm::{
     ActiveModelTrait, ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter, QueryOrder,
     QuerySelect, Set,
@@ -114,6 +114,50 @@ impl EloEventRepository for PostgresEloEventRepository {
 
         Ok(result.unwrap_or(0))
     }
+
+    async fn sum_delta_by_agent_since(
+        &self,
+        agent_id: &AgentId,
+        since: DateTime<Utc>,
+    ) -> Result<i64, DomainError> {
+        use sea_orm::sea_query::Expr;
+
+        // SUM is NULL when the agent has no events in the window
+        let result: Option<Option<i64>> = elo_events::Entity::find()
+            .filter(elo_events::Column::AgentId.eq(agent_id.0))
+            .filter(elo_events::Column::CreatedAt.gte(since.fixed_offset()))
+            .select_only()
+            .column_as(Expr::col(elo_events::Column::Delta).sum(), "sum")
+            .into_tuple()
+            .one(&self.db)
+            .await
+            .map_err(|e| DomainError::Database(e.to_string()))?;
+
+        Ok(result.flatten().unwrap_or(0))
+    }
+
+    async fn net_deltas_since(
+        &self,
+        since: DateTime<Utc>,
+    ) -> Result<Vec<(AgentId, i64)>, DomainError> {
+        use sea_orm::sea_query::Expr;
+
+        let rows: Vec<(Uuid, Option<i64>)> = elo_events::Entity::find()
+            .filter(elo_events::Column::CreatedAt.gte(since.fixed_offset()))
+            .select_only()
+            .column(elo_events::Column::AgentId)
+            .column_as(Expr::col(elo_events::Column::Delta).sum(), "sum")
+            .group_by(elo_events::Column::AgentId)
+            .into_tuple()
+            .all(&self.db)
+            .await
+            .map_err(|e| DomainError::Database(e.to_string()))?;
+
+        Ok(rows
+            .into_iter()
+            .map(|(agent_id, sum)| (AgentId(agent_id), sum.unwrap_or(0)))
+            .collect())
+    }
 }
 
 /// Convert SeaORM model to domain entity
@@ -0,0 +1,74 @@
+//! PostgreSQL adapter for EloIntegrityRepository
+
+use async_trait::async_trait;
+use sea_orm::{ConnectionTrait, DatabaseBackend, DatabaseConnection, Statement};
+use uuid::Uuid;
+
+use crate::domain::entities::{AgentId, EloDrift};
+use crate::domain::ports::EloIntegrityRepository;
+use crate::error::DomainError;
+
+/// PostgreSQL implementation of EloIntegrityRepository
+pub struct PostgresEloIntegrityRepository {
+    db: DatabaseConnection,
+}
+
+impl PostgresEloIntegrityRepository {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl EloIntegrityRepository for PostgresEloIntegrityRepository {
+    async fn find_elo_drift(
+        &self,
+        starting: i32,
+        limit: i64,
+    ) -> Result<Vec<EloDrift>, DomainError> {
+        // One pass: sum the change each agent's events made, then compare
+        // against the stored ELO. `delta` is recorded before the floor clamp,
+        // so `new_elo - old_elo` is what actually moved the rating.
+        let stmt = Statement::from_sql_and_values(
+            DatabaseBackend::Postgres,
+            "WITH totals AS ( \
+                 SELECT a.id, a.name, COALESCE(a.elo, $1) AS stored_elo, \
+                        COALESCE(s.event_sum, 0) AS event_sum \
+                 FROM agents a \
+                 LEFT JOIN ( \
+                     SELECT agent_id, SUM(new_elo - old_elo)::BIGINT AS event_sum \
+                     FROM elo_events GROUP BY agent_id \
+                 ) s ON s.agent_id = a.id \
+                 WHERE a.merged_into IS NULL \
+             ), expected AS ( \
+                 SELECT *, ($1 + event_sum)::INT AS expected_elo \
+                 FROM totals \
+             ) \
+             SELECT id, name, stored_elo, expected_elo, event_sum FROM expected \
+             WHERE stored_elo <> expected_elo \
+             ORDER BY ABS(stored_elo - expected_elo) DESC, name \
+             LIMIT $2",
+            [starting.into(), limit.into()],
+        );
+
+        let rows = self
+            .db
+            .query_all(stmt)
+            .await
+            .map_err(|e| DomainError::Database(e.to_string()))?;
+
+        rows.into_iter()
+            .map(|row| {
+                let get_err = |e: sea_orm::DbErr| DomainError::Database(e.to_string());
+                let id: Uuid = row.try_get("", "id").map_err(get_err)?;
+                Ok(EloDrift {
+                    agent_id: AgentId(id),
+                    agent_name: row.try_get("", "name").map_err(get_err)?,
+                    stored_elo: row.try_get("", "stored_elo").map_err(get_err)?,
+                    expected_elo: row.try_get("", "expected_elo").map_err(get_err)?,
+                    event_sum: row.try_get("", "event_sum").map_err(get_err)?,
+                })
+            })
+            .collect()
+    }
+}
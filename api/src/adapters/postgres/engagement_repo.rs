@@ -3,11 +3,13 @@
 //! NOTE: This file requires running `make db-migrate && make entities` to generate
 //! the SeaORM entity files before it will compile.
 
+use std::collections::HashMap;
+
 use async_trait::async_trait;
-use chrono::Utc;
+use chrono::{DateTime, Utc};
 use sea_orm::{
-    ActiveModelTrait, ColumnTrait, DatabaseConnection, EntityTrait, PaginatorTrait, QueryFilter,
-    QueryOrder, QuerySelect, Set,
+    sea_query::OnConflict, ActiveModelTrait, ColumnTrait, Condition, ConnectionTrait,
+    DatabaseConnection, EntityTrait, PaginatorTrait, QueryFilter, QueryOrder, QuerySelect, Set,
 };
 use uuid::Uuid;
 
@@ -15,7 +17,7 @@ use crate::domain::entities::{
     AgentId, Engagement, EngagementCounts, EngagementId, EngagementType, NewEngagement, TargetType,
 };
 use crate::domain::ports::EngagementRepository;
-use crate::entity::{engagement_counts, engagements};
+use crate::entity::{engagement_counts, engagement_external_counts, engagements};
 use crate::error::DomainError;
 
 /// PostgreSQL implementation of EngagementRepository
@@ -60,6 +62,65 @@ impl EngagementRepository for PostgresEngagementRepository {
         Ok(results.into_iter().map(|m| m.into()).collect())
     }
 
+    async fn find_recent_by_targets(
+        &self,
+        target_type: &str,
+        target_ids: &[Uuid],
+        per_target: i64,
+    ) -> Result<HashMap<Uuid, Vec<Engagement>>, DomainError> {
+        if target_ids.is_empty() {
+            return Ok(HashMap::new());
+        }
+
+        // One round trip for every target, newest `per_target` rows each
+        let placeholders = (0..target_ids.len())
+            .map(|i| format!("${}", i + 3))
+            .collect::<Vec<_>>()
+            .join(", ");
+        let sql = format!(
+            "SELECT * FROM ( \
+               SELECT *, ROW_NUMBER() OVER (PARTITION BY target_id ORDER BY created_at DESC) AS rn \
+               FROM engagements WHERE target_type = $1 AND target_id IN ({}) \
+             ) ranked WHERE rn <= $2",
+            placeholders
+        );
+        let values = [target_type.into(), per_target.into()]
+            .into_iter()
+            .chain(target_ids.iter().map(|id| (*id).into()));
+        let stmt = sea_orm::Statement::from_sql_and_values(
+            sea_orm::DatabaseBackend::Postgres,
+            sql,
+            values,
+        );
+
+        let rows = engagements::Entity::find()
+            .from_raw_sql(stmt)
+            .all(&self.db)
+            .await
+            .map_err(|e| DomainError::Database(e.to_string()))?;
+
+        let mut by_target: HashMap<Uuid, Vec<Engagement>> = HashMap::new();
+        for row in rows {
+            by_target.entry(row.target_id).or_default().push(row.into());
+        }
+        Ok(by_target)
+    }
+
+    async fn count_by_target(
+        &self,
+        target_type: &str,
+        target_id: Uuid,
+    ) -> Result<i64, DomainError> {
+        let count = engagements::Entity::find()
+            .filter(engagements::Column::TargetType.eq(target_type))
+            .filter(engagements::Column::TargetId.eq(target_id))
+            .count(&self.db)
+            .await
+            .map_err(|e| DomainError::Database(e.to_string()))?;
+
+        Ok(count as i64)
+    }
+
     async fn find_by_agent(
         &self,
         agent_id: &AgentId,
@@ -90,7 +151,117 @@ impl EngagementRepository for PostgresEngagementRepository {
             .await
             .map_err(|e| DomainError::Database(e.to_string()))?;
 
-        Ok(result.map(|m| m.into()).unwrap_or_default())
+        let external = engagement_external_counts::Entity::find()
+            .filter(engagement_external_counts::Column::TargetType.eq(target_type))
+            .filter(engagement_external_counts::Column::TargetId.eq(target_id))
+            .one(&self.db)
+            .await
+            .map_err(|e| DomainError::Database(e.to_string()))?;
+
+        let mut counts: EngagementCounts = result.map(|m| m.into()).unwrap_or_default();
+        if let Some(external) = external {
+            counts.add_reactions(&external.into());
+        }
+        Ok(counts)
+    }
+
+    async fn get_counts_batch(
+        &self,
+        targets: &[(String, Uuid)],
+    ) -> Result<HashMap<(String, Uuid), EngagementCounts>, DomainError> {
+        let mut counts: HashMap<(String, Uuid), EngagementCounts> = targets
+            .iter()
+            .map(|key| (key.clone(), EngagementCounts::default()))
+            .collect();
+        if targets.is_empty() {
+            return Ok(counts);
+        }
+
+        let condition = targets
+            .iter()
+            .fold(Condition::any(), |cond, (target_type, target_id)| {
+                cond.add(
+                    Condition::all()
+                        .add(engagement_counts::Column::TargetType.eq(target_type.as_str()))
+                        .add(engagement_counts::Column::TargetId.eq(*target_id)),
+                )
+            });
+
+        let results = engagement_counts::Entity::find()
+            .filter(condition)
+            .all(&self.db)
+            .await
+            .map_err(|e| DomainError::Database(e.to_string()))?;
+
+        let external = engagement_external_counts::Entity::find()
+            .filter(
+                targets
+                    .iter()
+                    .fold(Condition::any(), |cond, (target_type, target_id)| {
+                        cond.add(
+                            Condition::all()
+                                .add(
+                                    engagement_external_counts::Column::TargetType
+                                        .eq(target_type.as_str()),
+                                )
+                                .add(engagement_external_counts::Column::TargetId.eq(*target_id)),
+                        )
+                    }),
+            )
+            .all(&self.db)
+            .await
+            .map_err(|e| DomainError::Database(e.to_string()))?;
+
+        for model in results {
+            counts.insert((model.target_type.clone(), model.target_id), model.into());
+        }
+        for model in external {
+            if let Some(c) = counts.get_mut(&(model.target_type.clone(), model.target_id)) {
+                c.add_reactions(&model.into());
+            }
+        }
+
+        Ok(counts)
+    }
+
+    async fn set_external_counts(
+        &self,
+        target_type: &str,
+        target_id: Uuid,
+        counts: &EngagementCounts,
+    ) -> Result<(), DomainError> {
+        let model = engagement_external_counts::ActiveModel {
+            target_type: Set(target_type.to_string()),
+            target_id: Set(target_id),
+            laugh_count: Set(counts.laugh_count),
+            fire_count: Set(counts.fire_count),
+            skull_count: Set(counts.skull_count),
+            heart_count: Set(counts.heart_count),
+            eyes_count: Set(counts.eyes_count),
+            synced_at: Set(Utc::now().fixed_offset()),
+        };
+
+        engagement_external_counts::Entity::insert(model)
+            .on_conflict(
+                OnConflict::columns([
+                    engagement_external_counts::Column::TargetType,
+                    engagement_external_counts::Column::TargetId,
+                ])
+                .update_columns([
+                    engagement_external_counts::Column::LaughCount,
+                    engagement_external_counts::Column::FireCount,
+                    engagement_external_counts::Column::SkullCount,
+                    engagement_external_counts::Column::HeartCount,
+                    engagement_external_counts::Column::EyesCount,
+                    engagement_external_counts::Column::SyncedAt,
+                ])
+                .to_owned(),
+            )
+            .exec(&self.db)
+            .await
+            .map_err(|e| DomainError::Database(e.to_string()))?;
+
+        Ok(())
     }
 
     async fn create(&self, engagement: &NewEngagement) -> Result<Engagement, DomainError> {
@@ -108,6 +279,10 @@ impl EngagementRepository for PostgresEngagementRepository {
             gitea_synced: Set(false),
             gitea_id: Set(None),
             created_at: Set(now),
+            gitea_org: Set(engagement.gitea_org.clone()),
+            gitea_repo: Set(engagement.gitea_repo.clone()),
+            sync_attempts: Set(0),
+            sync_failed: Set(false),
         };
 
         let result = model
@@ -132,6 +307,66 @@ impl EngagementRepository for PostgresEngagementRepository {
         Ok(())
     }
 
+    async fn find_unsynced(
+        &self,
+        created_before: DateTime<Utc>,
+        limit: i64,
+    ) -> Result<Vec<Engagement>, DomainError> {
+        let results = engagements::Entity::find()
+            .filter(engagements::Column::GiteaSynced.eq(false))
+            .filter(engagements::Column::CreatedAt.lt(created_before))
+            .filter(engagements::Column::SyncFailed.eq(false))
+            .filter(engagements::Column::GiteaOrg.is_not_null())
+            .filter(engagements::Column::GiteaRepo.is_not_null())
+            .order_by_asc(engagements::Column::CreatedAt)
+            .limit(limit as u64)
+            .all(&self.db)
+            .await
+            .map_err(|e| DomainError::Database(e.to_string()))?;
+
+        Ok(results.into_iter().map(|m| m.into()).collect())
+    }
+
+    async fn record_sync_failure(
+        &self,
+        id: &EngagementId,
+        give_up: bool,
+    ) -> Result<(), DomainError> {
+        // Use raw SQL for atomic increment
+        let stmt = sea_orm::Statement::from_sql_and_values(
+            sea_orm::DatabaseBackend::Postgres,
+            "UPDATE engagements SET sync_attempts = sync_attempts + 1, \
+             sync_failed = sync_failed OR $1 WHERE id = $2",
+            [give_up.into(), id.0.into()],
+        );
+
+        self.db
+            .execute(stmt)
+            .await
+            .map_err(|e| DomainError::Database(e.to_string()))?;
+
+        Ok(())
+    }
+
+    async fn find_synced_gitea_ids(
+        &self,
+        target_type: &str,
+        target_id: Uuid,
+    ) -> Result<Vec<i64>, DomainError> {
+        let ids: Vec<Option<i64>> = engagements::Entity::find()
+            .select_only()
+            .column(engagements::Column::GiteaId)
+            .filter(engagements::Column::TargetType.eq(target_type))
+            .filter(engagements::Column::TargetId.eq(target_id))
+            .filter(engagements::Column::GiteaId.is_not_null())
+            .into_tuple()
+            .all(&self.db)
+            .await
+            .map_err(|e| DomainError::Database(e.to_string()))?;
+
+        Ok(ids.into_iter().flatten().collect())
+    }
+
     async fn has_reaction(
         &self,
         agent_id: &AgentId,
@@ -150,6 +385,26 @@ impl EngagementRepository for PostgresEngagementRepository {
 
         Ok(count > 0)
     }
+
+    async fn last_engagement_at(
+        &self,
+        agent_id: &AgentId,
+        target_type: &str,
+        target_id: Uuid,
+        engagement_type: &str,
+    ) -> Result<Option<DateTime<Utc>>, DomainError> {
+        let latest = engagements::Entity::find()
+            .filter(engagements::Column::AgentId.eq(agent_id.0))
+            .filter(engagements::Column::TargetType.eq(target_type))
+            .filter(engagements::Column::TargetId.eq(target_id))
+            .filter(engagements::Column::EngagementType.eq(engagement_type))
+            .order_by_desc(engagements::Column::CreatedAt)
+            .one(&self.db)
+            .await
+            .map_err(|e| DomainError::Database(e.to_string()))?;
+
+        Ok(latest.map(|m| m.created_at.with_timezone(&Utc)))
+    }
 }
 
 /// Convert SeaORM model to domain entity
@@ -169,6 +424,10 @@ impl From<engagements::Model> for Engagement {
             gitea_synced: model.gitea_synced,
             gitea_id: model.gitea_id,
             created_at: model.created_at.with_timezone(&Utc),
+            gitea_org: model.gitea_org,
+            gitea_repo: model.gitea_repo,
+            sync_attempts: model.sync_attempts,
+            sync_failed: model.sync_failed,
         }
     }
 }
@@ -180,8 +439,24 @@ impl From<engagement_counts::Model> for EngagementCounts {
             laugh_count: model.laugh_count,
             fire_count: model.fire_count,
             skull_count: model.skull_count,
+            heart_count: model.heart_count,
+            eyes_count: model.eyes_count,
             comment_count: model.comment_count,
+            review_count: model.review_count,
             total_score: model.total_score,
         }
     }
 }
+
+impl From<engagement_external_counts::Model> for EngagementCounts {
+    fn from(model: engagement_external_counts::Model) -> Self {
+        EngagementCounts {
+            laugh_count: model.laugh_count,
+            fire_count: model.fire_count,
+            skull_count: model.skull_count,
+            heart_count: model.heart_count,
+            eyes_count: model.eyes_count,
+            ..Default::default()
+        }
+    }
+}
@@ -440,9 +440,15 @@ mod code_contribution_repo_tests {
 
         assert!(!contrib.longevity_bonus_paid);
 
-        repo.mark_longevity_bonus_paid(&contrib.id)
+        assert!(repo
+            .mark_longevity_bonus_paid(&contrib.id)
             .await
-            .expect("Failed to mark");
+            .expect("Failed to mark"));
+        // A second claim finds the bonus already paid
+        assert!(!repo
+            .mark_longevity_bonus_paid(&contrib.id)
+            .await
+            .expect("Failed to mark"));
 
         let updated = repo
             .find_by_id(&contrib.id)
@@ -950,6 +956,31 @@ mod elo_event_repo_tests {
         assert_eq!(sum, 5);
     }
 
+    #[tokio::test]
+    #[ignore]
+    async fn sum_delta_since_is_zero_without_events() {
+        let db = get_test_db().await;
+        let agent_repo = PostgresAgentRepository::new(db.clone());
+        let elo_repo = PostgresEloEventRepository::new(db);
+
+        let agent = agent_repo
+            .create(&NewAgent {
+                name: unique_name("quiet-agent"),
+                api_key_hash: format!("hash-{}", Uuid::new_v4()),
+                gitea_username: unique_name("gitea"),
+                gitea_token_encrypted: vec![],
+                claim_code: format!("claim-{}", Uuid::new_v4()),
+            })
+            .await
+            .expect("Failed to create agent");
+
+        let sum = elo_repo
+            .sum_delta_by_agent_since(&agent.id, chrono::Utc::now() - chrono::Duration::days(1))
+            .await
+            .expect("Failed to sum");
+        assert_eq!(sum, 0);
+    }
+
     #[tokio::test]
     #[ignore]
     async fn all_event_types_persist_correctly() {
@@ -1042,6 +1073,8 @@ mod engagement_repo_tests {
             engagement_type: EngagementType::Reaction,
             reaction: Some(ReactionType::Laugh),
             body: None,
+            gitea_org: None,
+            gitea_repo: None,
         };
 
         // Create
@@ -1081,6 +1114,8 @@ mod engagement_repo_tests {
                 engagement_type: EngagementType::Reaction,
                 reaction: Some(reaction),
                 body: None,
+                gitea_org: None,
+                gitea_repo: None,
             })
             .await
             .expect("Failed to create");
@@ -1110,6 +1145,8 @@ mod engagement_repo_tests {
                 engagement_type: EngagementType::Reaction,
                 reaction: Some(ReactionType::Fire),
                 body: None,
+                gitea_org: None,
+                gitea_repo: None,
             })
             .await
             .expect("Failed to create");
@@ -1140,6 +1177,8 @@ mod engagement_repo_tests {
             engagement_type: EngagementType::Reaction,
             reaction: Some(ReactionType::Skull),
             body: None,
+            gitea_org: None,
+            gitea_repo: None,
         })
         .await
         .expect("Failed to create");
@@ -1174,6 +1213,8 @@ mod engagement_repo_tests {
                 engagement_type: EngagementType::Reaction,
                 reaction: Some(ReactionType::Heart),
                 body: None,
+                gitea_org: None,
+                gitea_repo: None,
             })
             .await
             .expect("Failed to create");
@@ -1210,6 +1251,8 @@ mod engagement_repo_tests {
                 engagement_type: EngagementType::Comment,
                 reaction: None,
                 body: Some("This is a great solution!".to_string()),
+                gitea_org: None,
+                gitea_repo: None,
             })
             .await
             .expect("Failed to create");
@@ -1239,6 +1282,8 @@ mod engagement_repo_tests {
             engagement_type: EngagementType::Reaction,
             reaction: Some(ReactionType::Laugh),
             body: None,
+            gitea_org: None,
+            gitea_repo: None,
         })
         .await
         .expect("Failed to create");
@@ -1250,6 +1295,8 @@ mod engagement_repo_tests {
             engagement_type: EngagementType::Reaction,
             reaction: Some(ReactionType::Fire),
             body: None,
+            gitea_org: None,
+            gitea_repo: None,
         })
         .await
         .expect("Failed to create");
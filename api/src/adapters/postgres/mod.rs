@@ -2,23 +2,33 @@
 //!
 //! Implementations of repository traits using SeaORM and PostgreSQL.
 
+pub mod agent_merge_repo;
 pub mod agent_repo;
 pub mod agent_review_repo;
 pub mod code_contribution_repo;
 pub mod elo_event_repo;
+pub mod elo_integrity_repo;
 pub mod engagement_repo;
+pub mod pending_penalty_repo;
 pub mod project_repo;
+pub mod review_request_repo;
 pub mod ticket_repo;
 pub mod viral_moment_repo;
+pub mod webhook_delivery_repo;
 
 #[cfg(test)]
 mod integration_tests;
 
+pub use agent_merge_repo::PostgresAgentMergeRepository;
 pub use agent_repo::PostgresAgentRepository;
 pub use agent_review_repo::PostgresAgentReviewRepository;
 pub use code_contribution_repo::PostgresCodeContributionRepository;
 pub use elo_event_repo::PostgresEloEventRepository;
+pub use elo_integrity_repo::PostgresEloIntegrityRepository;
 pub use engagement_repo::PostgresEngagementRepository;
+pub use pending_penalty_repo::PostgresPendingPenaltyRepository;
 pub use project_repo::PostgresProjectRepository;
+pub use review_request_repo::PostgresReviewRequestRepository;
 pub use ticket_repo::PostgresTicketRepository;
 pub use viral_moment_repo::PostgresViralMomentRepository;
+pub use webhook_delivery_repo::PostgresWebhookDeliveryRepository;
@@ -0,0 +1,157 @@
+//! PostgreSQL adapter for PendingPenaltyRepository
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter, QueryOrder,
+    QuerySelect, Set,
+};
+use uuid::Uuid;
+
+use crate::domain::entities::{
+    AgentId, NewPendingPenalty, PenaltyStatus, PendingPenalty, PendingPenaltyId, ProjectId,
+};
+use crate::domain::ports::PendingPenaltyRepository;
+use crate::entity::pending_penalties;
+use crate::error::DomainError;
+
+/// PostgreSQL implementation of PendingPenaltyRepository
+pub struct PostgresPendingPenaltyRepository {
+    db: DatabaseConnection,
+}
+
+impl PostgresPendingPenaltyRepository {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl PendingPenaltyRepository for PostgresPendingPenaltyRepository {
+    async fn create(&self, penalty: &NewPendingPenalty) -> Result<PendingPenalty, DomainError> {
+        let id = Uuid::new_v4();
+        let now = Utc::now().fixed_offset();
+
+        let model = pending_penalties::ActiveModel {
+            id: Set(id),
+            agent_id: Set(penalty.agent_id.0),
+            project_id: Set(penalty.project_id.0),
+            gitea_org: Set(penalty.gitea_org.clone()),
+            gitea_repo: Set(penalty.gitea_repo.clone()),
+            pr_number: Set(penalty.pr_number),
+            status: Set(PenaltyStatus::Pending.to_string()),
+            apply_at: Set(penalty.apply_at.fixed_offset()),
+            resolved_at: Set(None),
+            created_at: Set(now),
+        };
+
+        let result = model
+            .insert(&self.db)
+            .await
+            .map_err(|e| DomainError::Database(e.to_string()))?;
+
+        Ok(result.into())
+    }
+
+    async fn find_due(
+        &self,
+        now: DateTime<Utc>,
+        limit: i64,
+    ) -> Result<Vec<PendingPenalty>, DomainError> {
+        let results = pending_penalties::Entity::find()
+            .filter(pending_penalties::Column::Status.eq(PenaltyStatus::Pending.to_string()))
+            .filter(pending_penalties::Column::ApplyAt.lte(now.fixed_offset()))
+            .order_by_asc(pending_penalties::Column::ApplyAt)
+            .limit(limit as u64)
+            .all(&self.db)
+            .await
+            .map_err(|e| DomainError::Database(e.to_string()))?;
+
+        Ok(results.into_iter().map(|m| m.into()).collect())
+    }
+
+    async fn find_pending_by_pr(
+        &self,
+        project_id: &ProjectId,
+        pr_number: i64,
+    ) -> Result<Vec<PendingPenalty>, DomainError> {
+        let results = pending_penalties::Entity::find()
+            .filter(pending_penalties::Column::ProjectId.eq(project_id.0))
+            .filter(pending_penalties::Column::PrNumber.eq(pr_number))
+            .filter(pending_penalties::Column::Status.eq(PenaltyStatus::Pending.to_string()))
+            .all(&self.db)
+            .await
+            .map_err(|e| DomainError::Database(e.to_string()))?;
+
+        Ok(results.into_iter().map(|m| m.into()).collect())
+    }
+
+    async fn find_pending_by_agent(
+        &self,
+        agent_id: &AgentId,
+    ) -> Result<Vec<PendingPenalty>, DomainError> {
+        let results = pending_penalties::Entity::find()
+            .filter(pending_penalties::Column::AgentId.eq(agent_id.0))
+            .filter(pending_penalties::Column::Status.eq(PenaltyStatus::Pending.to_string()))
+            .order_by_asc(pending_penalties::Column::ApplyAt)
+            .all(&self.db)
+            .await
+            .map_err(|e| DomainError::Database(e.to_string()))?;
+
+        Ok(results.into_iter().map(|m| m.into()).collect())
+    }
+
+    async fn reschedule(
+        &self,
+        id: &PendingPenaltyId,
+        apply_at: DateTime<Utc>,
+    ) -> Result<(), DomainError> {
+        pending_penalties::ActiveModel {
+            id: Set(id.0),
+            apply_at: Set(apply_at.fixed_offset()),
+            ..Default::default()
+        }
+        .update(&self.db)
+        .await
+        .map_err(|e| DomainError::Database(e.to_string()))?;
+
+        Ok(())
+    }
+
+    async fn resolve(
+        &self,
+        id: &PendingPenaltyId,
+        status: PenaltyStatus,
+        resolved_at: DateTime<Utc>,
+    ) -> Result<(), DomainError> {
+        pending_penalties::ActiveModel {
+            id: Set(id.0),
+            status: Set(status.to_string()),
+            resolved_at: Set(Some(resolved_at.fixed_offset())),
+            ..Default::default()
+        }
+        .update(&self.db)
+        .await
+        .map_err(|e| DomainError::Database(e.to_string()))?;
+
+        Ok(())
+    }
+}
+
+/// Convert SeaORM model to domain entity
+impl From<pending_penalties::Model> for PendingPenalty {
+    fn from(model: pending_penalties::Model) -> Self {
+        PendingPenalty {
+            id: PendingPenaltyId(model.id),
+            agent_id: AgentId(model.agent_id),
+            project_id: ProjectId(model.project_id),
+            gitea_org: model.gitea_org,
+            gitea_repo: model.gitea_repo,
+            pr_number: model.pr_number,
+            status: model.status.parse().unwrap_or(PenaltyStatus::Pending),
+            apply_at: model.apply_at.with_timezone(&Utc),
+            resolved_at: model.resolved_at.map(|t| t.with_timezone(&Utc)),
+            created_at: model.created_at.with_timezone(&Utc),
+        }
+    }
+}
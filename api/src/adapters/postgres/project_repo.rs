@@ -1,18 +1,20 @@
 //! PostgreSQL adapter for ProjectRepository
 
 use async_trait::async_trait;
-use chrono::Utc;
+use chrono::{DateTime, Utc};
+use sea_orm::sea_query::{Expr, OnConflict};
 use sea_orm::{
-    ActiveModelTrait, ColumnTrait, ConnectionTrait, DatabaseConnection, EntityTrait, QueryFilter,
-    QueryOrder, QuerySelect, Set,
+    ActiveModelTrait, ColumnTrait, ConnectionTrait, DatabaseConnection, EntityTrait,
+    PaginatorTrait, QueryFilter, QueryOrder, QuerySelect, Set,
 };
 use uuid::Uuid;
 
 use crate::domain::entities::{
-    AgentId, BuildStatus, MemberRole, NewProject, Project, ProjectId, ProjectMember, ProjectStatus,
+    AgentId, BuildStatus, MemberRole, NewProject, Project, ProjectId, ProjectMember, ProjectMirror,
+    ProjectStatus, ProjectVisibility, ReviewEligibility,
 };
 use crate::domain::ports::ProjectRepository;
-use crate::entity::{project_members, projects};
+use crate::entity::{force_merges, project_invites, project_members, projects, stale_pr_nudges};
 use crate::error::DomainError;
 
 /// PostgreSQL implementation of ProjectRepository
@@ -37,6 +39,19 @@ impl ProjectRepository for PostgresProjectRepository {
         Ok(result.map(|m| m.into()))
     }
 
+    async fn find_by_ids(&self, ids: &[ProjectId]) -> Result<Vec<Project>, DomainError> {
+        if ids.is_empty() {
+            return Ok(Vec::new());
+        }
+        let results = projects::Entity::find()
+            .filter(projects::Column::Id.is_in(ids.iter().map(|id| id.0)))
+            .all(&self.db)
+            .await
+            .map_err(|e| DomainError::Database(e.to_string()))?;
+
+        Ok(results.into_iter().map(|m| m.into()).collect())
+    }
+
     async fn find_by_name(&self, name: &str) -> Result<Option<Project>, DomainError> {
         let result = projects::Entity::find()
             .filter(projects::Column::Name.eq(name))
@@ -47,9 +62,56 @@ impl ProjectRepository for PostgresProjectRepository {
         Ok(result.map(|m| m.into()))
     }
 
+    async fn find_by_gitea_repo(
+        &self,
+        gitea_org: &str,
+        gitea_repo: &str,
+    ) -> Result<Option<Project>, DomainError> {
+        let result = projects::Entity::find()
+            .filter(projects::Column::GiteaOrg.eq(gitea_org))
+            .filter(projects::Column::GiteaRepo.eq(gitea_repo))
+            .one(&self.db)
+            .await
+            .map_err(|e| DomainError::Database(e.to_string()))?;
+
+        Ok(result.map(|m| m.into()))
+    }
+
     async fn find_active(&self, limit: i64, offset: i64) -> Result<Vec<Project>, DomainError> {
         let results = projects::Entity::find()
             .filter(projects::Column::Status.eq("active"))
+            .filter(projects::Column::Visibility.eq(ProjectVisibility::Public.to_string()))
+            .order_by_desc(projects::Column::CreatedAt)
+            .offset(offset as u64)
+            .limit(limit as u64)
+            .all(&self.db)
+            .await
+            .map_err(|e| DomainError::Database(e.to_string()))?;
+
+        Ok(results.into_iter().map(|m| m.into()).collect())
+    }
+
+    async fn count_active(&self) -> Result<i64, DomainError> {
+        let count = projects::Entity::find()
+            .filter(projects::Column::Status.eq("active"))
+            .filter(projects::Column::Visibility.eq(ProjectVisibility::Public.to_string()))
+            .count(&self.db)
+            .await
+            .map_err(|e| DomainError::Database(e.to_string()))?;
+
+        Ok(count as i64)
+    }
+
+    async fn find_active_by_language(
+        &self,
+        languages: &[String],
+        limit: i64,
+        offset: i64,
+    ) -> Result<Vec<Project>, DomainError> {
+        let results = projects::Entity::find()
+            .filter(projects::Column::Status.eq("active"))
+            .filter(projects::Column::Visibility.eq(ProjectVisibility::Public.to_string()))
+            .filter(Expr::expr(Expr::cust("LOWER(TRIM(language))")).is_in(languages.to_vec()))
             .order_by_desc(projects::Column::CreatedAt)
             .offset(offset as u64)
             .limit(limit as u64)
@@ -60,6 +122,46 @@ impl ProjectRepository for PostgresProjectRepository {
         Ok(results.into_iter().map(|m| m.into()).collect())
     }
 
+    async fn count_active_by_language(&self, languages: &[String]) -> Result<i64, DomainError> {
+        let count = projects::Entity::find()
+            .filter(projects::Column::Status.eq("active"))
+            .filter(projects::Column::Visibility.eq(ProjectVisibility::Public.to_string()))
+            .filter(Expr::expr(Expr::cust("LOWER(TRIM(language))")).is_in(languages.to_vec()))
+            .count(&self.db)
+            .await
+            .map_err(|e| DomainError::Database(e.to_string()))?;
+
+        Ok(count as i64)
+    }
+
+    async fn count_active_languages(&self) -> Result<Vec<(String, i64)>, DomainError> {
+        let stmt = sea_orm::Statement::from_sql_and_values(
+            sea_orm::DatabaseBackend::Postgres,
+            "SELECT LOWER(TRIM(language)) AS language, COUNT(*) AS count \
+             FROM projects \
+             WHERE status = 'active' AND visibility = $1 \
+               AND language IS NOT NULL AND TRIM(language) <> '' \
+             GROUP BY LOWER(TRIM(language))",
+            [ProjectVisibility::Public.to_string().into()],
+        );
+
+        let rows = self
+            .db
+            .query_all(stmt)
+            .await
+            .map_err(|e| DomainError::Database(e.to_string()))?;
+
+        rows.into_iter()
+            .map(|row| {
+                let get_err = |e: sea_orm::DbErr| DomainError::Database(e.to_string());
+                Ok((
+                    row.try_get("", "language").map_err(get_err)?,
+                    row.try_get("", "count").map_err(get_err)?,
+                ))
+            })
+            .collect()
+    }
+
     async fn find_all(&self, limit: i64, offset: i64) -> Result<Vec<Project>, DomainError> {
         let results = projects::Entity::find()
             .order_by_desc(projects::Column::CreatedAt)
@@ -89,6 +191,19 @@ impl ProjectRepository for PostgresProjectRepository {
             build_status: Set(Some("unknown".to_string())),
             created_by: Set(project.created_by.map(|id| id.0)),
             created_at: Set(Some(now)),
+            mirror_source_url: Set(None),
+            mirror_repo: Set(None),
+            mirrored_at: Set(None),
+            allow_alternate_bases: Set(false),
+            require_green_ci: Set(false),
+            required_approvals: Set(0),
+            featured: Set(false),
+            featured_rank: Set(0),
+            elo_multiplier: Set(1.0),
+            visibility: Set(ProjectVisibility::Public.to_string()),
+            review_eligibility: Set(ReviewEligibility::Open.to_string()),
+            protect_default_branch: Set(false),
+            star_count: Set(0),
         };
 
         let result = model
@@ -116,16 +231,195 @@ impl ProjectRepository for PostgresProjectRepository {
         Ok(())
     }
 
-    async fn update_stats(
+    async fn set_mirror(&self, id: &ProjectId, mirror: &ProjectMirror) -> Result<(), DomainError> {
+        projects::ActiveModel {
+            id: Set(id.0),
+            mirror_source_url: Set(Some(mirror.source_url.clone())),
+            mirror_repo: Set(Some(mirror.repo.clone())),
+            mirrored_at: Set(Some(mirror.mirrored_at.fixed_offset())),
+            ..Default::default()
+        }
+        .update(&self.db)
+        .await
+        .map_err(|e| DomainError::Database(e.to_string()))?;
+
+        Ok(())
+    }
+
+    async fn set_allow_alternate_bases(
+        &self,
+        id: &ProjectId,
+        allowed: bool,
+    ) -> Result<(), DomainError> {
+        projects::ActiveModel {
+            id: Set(id.0),
+            allow_alternate_bases: Set(allowed),
+            ..Default::default()
+        }
+        .update(&self.db)
+        .await
+        .map_err(|e| DomainError::Database(e.to_string()))?;
+
+        Ok(())
+    }
+
+    async fn set_protect_default_branch(
+        &self,
+        id: &ProjectId,
+        enabled: bool,
+    ) -> Result<(), DomainError> {
+        projects::ActiveModel {
+            id: Set(id.0),
+            protect_default_branch: Set(enabled),
+            ..Default::default()
+        }
+        .update(&self.db)
+        .await
+        .map_err(|e| DomainError::Database(e.to_string()))?;
+
+        Ok(())
+    }
+
+    async fn set_require_green_ci(
+        &self,
+        id: &ProjectId,
+        required: bool,
+    ) -> Result<(), DomainError> {
+        projects::ActiveModel {
+            id: Set(id.0),
+            require_green_ci: Set(required),
+            ..Default::default()
+        }
+        .update(&self.db)
+        .await
+        .map_err(|e| DomainError::Database(e.to_string()))?;
+
+        Ok(())
+    }
+
+    async fn set_featured(
+        &self,
+        id: &ProjectId,
+        featured: bool,
+        rank: i32,
+    ) -> Result<(), DomainError> {
+        projects::ActiveModel {
+            id: Set(id.0),
+            featured: Set(featured),
+            featured_rank: Set(rank),
+            ..Default::default()
+        }
+        .update(&self.db)
+        .await
+        .map_err(|e| DomainError::Database(e.to_string()))?;
+
+        Ok(())
+    }
+
+    async fn set_required_approvals(&self, id: &ProjectId, count: i32) -> Result<(), DomainError> {
+        projects::ActiveModel {
+            id: Set(id.0),
+            required_approvals: Set(count),
+            ..Default::default()
+        }
+        .update(&self.db)
+        .await
+        .map_err(|e| DomainError::Database(e.to_string()))?;
+
+        Ok(())
+    }
+
+    async fn set_visibility(
+        &self,
+        id: &ProjectId,
+        visibility: ProjectVisibility,
+    ) -> Result<(), DomainError> {
+        projects::ActiveModel {
+            id: Set(id.0),
+            visibility: Set(visibility.to_string()),
+            ..Default::default()
+        }
+        .update(&self.db)
+        .await
+        .map_err(|e| DomainError::Database(e.to_string()))?;
+
+        Ok(())
+    }
+
+    async fn set_review_eligibility(
         &self,
         id: &ProjectId,
-        contributor_count: i32,
-        open_ticket_count: i32,
+        eligibility: ReviewEligibility,
     ) -> Result<(), DomainError> {
         projects::ActiveModel {
             id: Set(id.0),
-            contributor_count: Set(Some(contributor_count)),
-            open_ticket_count: Set(Some(open_ticket_count)),
+            review_eligibility: Set(eligibility.to_string()),
+            ..Default::default()
+        }
+        .update(&self.db)
+        .await
+        .map_err(|e| DomainError::Database(e.to_string()))?;
+
+        Ok(())
+    }
+
+    async fn set_elo_multiplier(&self, id: &ProjectId, multiplier: f32) -> Result<(), DomainError> {
+        projects::ActiveModel {
+            id: Set(id.0),
+            elo_multiplier: Set(multiplier),
+            ..Default::default()
+        }
+        .update(&self.db)
+        .await
+        .map_err(|e| DomainError::Database(e.to_string()))?;
+
+        Ok(())
+    }
+
+    async fn find_featured(&self, limit: i64) -> Result<Vec<Project>, DomainError> {
+        let results = projects::Entity::find()
+            .filter(projects::Column::Featured.eq(true))
+            .filter(projects::Column::Visibility.eq(ProjectVisibility::Public.to_string()))
+            .order_by_asc(projects::Column::FeaturedRank)
+            .order_by_asc(projects::Column::Name)
+            .limit(limit as u64)
+            .all(&self.db)
+            .await
+            .map_err(|e| DomainError::Database(e.to_string()))?;
+
+        Ok(results.into_iter().map(|m| m.into()).collect())
+    }
+
+    async fn set_contributor_count(&self, id: &ProjectId, count: i32) -> Result<(), DomainError> {
+        projects::ActiveModel {
+            id: Set(id.0),
+            contributor_count: Set(Some(count)),
+            ..Default::default()
+        }
+        .update(&self.db)
+        .await
+        .map_err(|e| DomainError::Database(e.to_string()))?;
+
+        Ok(())
+    }
+
+    async fn set_open_ticket_count(&self, id: &ProjectId, count: i32) -> Result<(), DomainError> {
+        projects::ActiveModel {
+            id: Set(id.0),
+            open_ticket_count: Set(Some(count)),
+            ..Default::default()
+        }
+        .update(&self.db)
+        .await
+        .map_err(|e| DomainError::Database(e.to_string()))?;
+
+        Ok(())
+    }
+
+    async fn set_star_count(&self, id: &ProjectId, count: i32) -> Result<(), DomainError> {
+        projects::ActiveModel {
+            id: Set(id.0),
+            star_count: Set(count),
             ..Default::default()
         }
         .update(&self.db)
@@ -199,6 +493,108 @@ impl ProjectRepository for PostgresProjectRepository {
         Ok(result.is_some())
     }
 
+    async fn add_invite(
+        &self,
+        project_id: &ProjectId,
+        agent_id: &AgentId,
+        invited_by: &AgentId,
+    ) -> Result<(), DomainError> {
+        let model = project_invites::ActiveModel {
+            project_id: Set(project_id.0),
+            agent_id: Set(agent_id.0),
+            invited_by: Set(Some(invited_by.0)),
+            created_at: Set(Utc::now().fixed_offset()),
+        };
+
+        project_invites::Entity::insert(model)
+            .on_conflict(
+                OnConflict::columns([
+                    project_invites::Column::ProjectId,
+                    project_invites::Column::AgentId,
+                ])
+                .do_nothing()
+                .to_owned(),
+            )
+            .exec_without_returning(&self.db)
+            .await
+            .map_err(|e| DomainError::Database(e.to_string()))?;
+
+        Ok(())
+    }
+
+    async fn is_invited(
+        &self,
+        project_id: &ProjectId,
+        agent_id: &AgentId,
+    ) -> Result<bool, DomainError> {
+        let count = project_invites::Entity::find()
+            .filter(project_invites::Column::ProjectId.eq(project_id.0))
+            .filter(project_invites::Column::AgentId.eq(agent_id.0))
+            .count(&self.db)
+            .await
+            .map_err(|e| DomainError::Database(e.to_string()))?;
+
+        Ok(count > 0)
+    }
+
+    async fn record_force_merge(
+        &self,
+        project_id: &ProjectId,
+        agent_id: &AgentId,
+        pr_number: i64,
+        waived_approvals: i32,
+    ) -> Result<(), DomainError> {
+        force_merges::ActiveModel {
+            id: Set(Uuid::new_v4()),
+            project_id: Set(project_id.0),
+            agent_id: Set(agent_id.0),
+            pr_number: Set(pr_number),
+            waived_approvals: Set(waived_approvals),
+            created_at: Set(Utc::now().fixed_offset()),
+        }
+        .insert(&self.db)
+        .await
+        .map_err(|e| DomainError::Database(e.to_string()))?;
+
+        Ok(())
+    }
+
+    async fn find_nudged_prs(&self, project_id: &ProjectId) -> Result<Vec<i64>, DomainError> {
+        let nudges = stale_pr_nudges::Entity::find()
+            .filter(stale_pr_nudges::Column::ProjectId.eq(project_id.0))
+            .all(&self.db)
+            .await
+            .map_err(|e| DomainError::Database(e.to_string()))?;
+
+        Ok(nudges.into_iter().map(|n| n.pr_number).collect())
+    }
+
+    async fn record_pr_nudge(
+        &self,
+        project_id: &ProjectId,
+        pr_number: i64,
+        nudged_at: DateTime<Utc>,
+    ) -> Result<(), DomainError> {
+        stale_pr_nudges::Entity::insert(stale_pr_nudges::ActiveModel {
+            project_id: Set(project_id.0),
+            pr_number: Set(pr_number),
+            nudged_at: Set(nudged_at.fixed_offset()),
+        })
+        .on_conflict(
+            OnConflict::columns([
+                stale_pr_nudges::Column::ProjectId,
+                stale_pr_nudges::Column::PrNumber,
+            ])
+            .update_column(stale_pr_nudges::Column::NudgedAt)
+            .to_owned(),
+        )
+        .exec(&self.db)
+        .await
+        .map_err(|e| DomainError::Database(e.to_string()))?;
+
+        Ok(())
+    }
+
     async fn find_by_agent(&self, agent_id: &AgentId) -> Result<Vec<Project>, DomainError> {
         use sea_orm::JoinType;
         use sea_orm::QuerySelect;
@@ -217,6 +613,29 @@ impl ProjectRepository for PostgresProjectRepository {
         Ok(results.into_iter().map(|m| m.into()).collect())
     }
 
+    async fn find_by_agent_with_role(
+        &self,
+        agent_id: &AgentId,
+        role: MemberRole,
+    ) -> Result<Vec<Project>, DomainError> {
+        use sea_orm::JoinType;
+        use sea_orm::QuerySelect;
+        use sea_orm::RelationTrait;
+
+        let results = projects::Entity::find()
+            .join(
+                JoinType::InnerJoin,
+                projects::Relation::ProjectMembers.def(),
+            )
+            .filter(project_members::Column::AgentId.eq(agent_id.0))
+            .filter(project_members::Column::Role.eq(role.to_string()))
+            .all(&self.db)
+            .await
+            .map_err(|e| DomainError::Database(e.to_string()))?;
+
+        Ok(results.into_iter().map(|m| m.into()).collect())
+    }
+
     async fn get_member_role(
         &self,
         project_id: &ProjectId,
@@ -309,6 +728,28 @@ impl From<projects::Model> for Project {
                 .created_at
                 .map(|dt| dt.with_timezone(&Utc))
                 .unwrap_or_else(Utc::now),
+            mirror: match (
+                model.mirror_source_url,
+                model.mirror_repo,
+                model.mirrored_at,
+            ) {
+                (Some(source_url), Some(repo), Some(at)) => Some(ProjectMirror {
+                    source_url,
+                    repo,
+                    mirrored_at: at.with_timezone(&Utc),
+                }),
+                _ => None,
+            },
+            allow_alternate_bases: model.allow_alternate_bases,
+            require_green_ci: model.require_green_ci,
+            required_approvals: model.required_approvals,
+            featured: model.featured,
+            featured_rank: model.featured_rank,
+            elo_multiplier: model.elo_multiplier,
+            visibility: model.visibility.parse().unwrap_or_default(),
+            review_eligibility: model.review_eligibility.parse().unwrap_or_default(),
+            protect_default_branch: model.protect_default_branch,
+            star_count: model.star_count,
         }
     }
 }
@@ -0,0 +1,144 @@
+//! PostgreSQL adapter for ReviewRequestRepository
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter, QueryOrder,
+    QuerySelect, Set,
+};
+use uuid::Uuid;
+
+use crate::domain::entities::{
+    AgentId, NewReviewRequest, ProjectId, ReviewRequest, ReviewRequestId, ReviewRequestStatus,
+};
+use crate::domain::ports::ReviewRequestRepository;
+use crate::entity::review_requests;
+use crate::error::DomainError;
+
+/// PostgreSQL implementation of ReviewRequestRepository
+pub struct PostgresReviewRequestRepository {
+    db: DatabaseConnection,
+}
+
+impl PostgresReviewRequestRepository {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl ReviewRequestRepository for PostgresReviewRequestRepository {
+    async fn create(&self, request: &NewReviewRequest) -> Result<ReviewRequest, DomainError> {
+        let id = Uuid::new_v4();
+        let now = Utc::now().fixed_offset();
+
+        let model = review_requests::ActiveModel {
+            id: Set(id),
+            project_id: Set(request.project_id.0),
+            gitea_org: Set(request.gitea_org.clone()),
+            gitea_repo: Set(request.gitea_repo.clone()),
+            pr_number: Set(request.pr_number),
+            reviewer_agent_id: Set(request.reviewer_agent_id.0),
+            requested_by: Set(request.requested_by.map(|a| a.0)),
+            status: Set(ReviewRequestStatus::Pending.to_string()),
+            due_at: Set(request.due_at.fixed_offset()),
+            resolved_at: Set(None),
+            created_at: Set(now),
+        };
+
+        let result = model
+            .insert(&self.db)
+            .await
+            .map_err(|e| DomainError::Database(e.to_string()))?;
+
+        Ok(result.into())
+    }
+
+    async fn find_overdue(
+        &self,
+        now: DateTime<Utc>,
+        limit: i64,
+    ) -> Result<Vec<ReviewRequest>, DomainError> {
+        let results = review_requests::Entity::find()
+            .filter(review_requests::Column::Status.eq(ReviewRequestStatus::Pending.to_string()))
+            .filter(review_requests::Column::DueAt.lte(now.fixed_offset()))
+            .order_by_asc(review_requests::Column::DueAt)
+            .limit(limit as u64)
+            .all(&self.db)
+            .await
+            .map_err(|e| DomainError::Database(e.to_string()))?;
+
+        Ok(results.into_iter().map(|m| m.into()).collect())
+    }
+
+    async fn find_by_pr(
+        &self,
+        project_id: &ProjectId,
+        pr_number: i64,
+    ) -> Result<Vec<ReviewRequest>, DomainError> {
+        let results = review_requests::Entity::find()
+            .filter(review_requests::Column::ProjectId.eq(project_id.0))
+            .filter(review_requests::Column::PrNumber.eq(pr_number))
+            .order_by_asc(review_requests::Column::CreatedAt)
+            .all(&self.db)
+            .await
+            .map_err(|e| DomainError::Database(e.to_string()))?;
+
+        Ok(results.into_iter().map(|m| m.into()).collect())
+    }
+
+    async fn reschedule(
+        &self,
+        id: &ReviewRequestId,
+        due_at: DateTime<Utc>,
+    ) -> Result<(), DomainError> {
+        review_requests::ActiveModel {
+            id: Set(id.0),
+            due_at: Set(due_at.fixed_offset()),
+            ..Default::default()
+        }
+        .update(&self.db)
+        .await
+        .map_err(|e| DomainError::Database(e.to_string()))?;
+
+        Ok(())
+    }
+
+    async fn resolve(
+        &self,
+        id: &ReviewRequestId,
+        status: ReviewRequestStatus,
+        resolved_at: DateTime<Utc>,
+    ) -> Result<(), DomainError> {
+        review_requests::ActiveModel {
+            id: Set(id.0),
+            status: Set(status.to_string()),
+            resolved_at: Set(Some(resolved_at.fixed_offset())),
+            ..Default::default()
+        }
+        .update(&self.db)
+        .await
+        .map_err(|e| DomainError::Database(e.to_string()))?;
+
+        Ok(())
+    }
+}
+
+/// Convert SeaORM model to domain entity
+impl From<review_requests::Model> for ReviewRequest {
+    fn from(model: review_requests::Model) -> Self {
+        ReviewRequest {
+            id: ReviewRequestId(model.id),
+            project_id: ProjectId(model.project_id),
+            gitea_org: model.gitea_org,
+            gitea_repo: model.gitea_repo,
+            pr_number: model.pr_number,
+            reviewer_agent_id: AgentId(model.reviewer_agent_id),
+            requested_by: model.requested_by.map(AgentId),
+            status: model.status.parse().unwrap_or(ReviewRequestStatus::Pending),
+            due_at: model.due_at.with_timezone(&Utc),
+            resolved_at: model.resolved_at.map(|t| t.with_timezone(&Utc)),
+            created_at: model.created_at.with_timezone(&Utc),
+        }
+    }
+}
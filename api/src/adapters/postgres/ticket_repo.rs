@@ -48,6 +48,24 @@ impl TicketRepository for PostgresTicketRepository {
         Ok(results.into_iter().map(|m| m.into()).collect())
     }
 
+    async fn find_by_issue_number(
+        &self,
+        project_id: &ProjectId,
+        issue_number: i64,
+    ) -> Result<Option<Ticket>, DomainError> {
+        let Ok(issue_number) = i32::try_from(issue_number) else {
+            return Ok(None);
+        };
+        let result = tickets::Entity::find()
+            .filter(tickets::Column::ProjectId.eq(project_id.0))
+            .filter(tickets::Column::GiteaIssueNumber.eq(issue_number))
+            .one(&self.db)
+            .await
+            .map_err(|e| DomainError::Database(e.to_string()))?;
+
+        Ok(result.map(|m| m.into()))
+    }
+
     async fn find_open_by_project(
         &self,
         project_id: &ProjectId,
@@ -99,10 +117,12 @@ impl TicketRepository for PostgresTicketRepository {
             gitea_issue_url: Set(ticket.gitea_issue_url.clone()),
             status: Set(Some("open".to_string())),
             priority: Set(Some(ticket.priority.to_string())),
+            difficulty: Set(ticket.resolved_difficulty().to_string()),
             assigned_to: Set(None),
             created_by: Set(ticket.created_by.map(|a| a.0)),
             created_at: Set(Some(now)),
             closed_at: Set(None),
+            blocked_reason: Set(None),
         };
 
         let result = model
@@ -168,6 +188,44 @@ impl TicketRepository for PostgresTicketRepository {
         Ok(())
     }
 
+    async fn block(&self, id: &TicketId, reason: &str) -> Result<(), DomainError> {
+        tickets::ActiveModel {
+            id: Set(id.0),
+            status: Set(Some(TicketStatus::Blocked.to_string())),
+            blocked_reason: Set(Some(reason.to_string())),
+            ..Default::default()
+        }
+        .update(&self.db)
+        .await
+        .map_err(|e| DomainError::Database(e.to_string()))?;
+
+        Ok(())
+    }
+
+    async fn unblock(&self, id: &TicketId) -> Result<(), DomainError> {
+        let ticket = self
+            .find_by_id(id)
+            .await?
+            .ok_or_else(|| DomainError::NotFound(format!("Ticket {} not found", id)))?;
+        let status = if ticket.assigned_to.is_some() {
+            TicketStatus::InProgress
+        } else {
+            TicketStatus::Open
+        };
+
+        tickets::ActiveModel {
+            id: Set(id.0),
+            status: Set(Some(status.to_string())),
+            blocked_reason: Set(None),
+            ..Default::default()
+        }
+        .update(&self.db)
+        .await
+        .map_err(|e| DomainError::Database(e.to_string()))?;
+
+        Ok(())
+    }
+
     async fn count_open_by_project(&self, project_id: &ProjectId) -> Result<i64, DomainError> {
         let count = tickets::Entity::find()
             .filter(tickets::Column::ProjectId.eq(project_id.0))
@@ -198,6 +256,7 @@ impl From<tickets::Model> for Ticket {
                 .priority
                 .and_then(|p| p.parse().ok())
                 .unwrap_or(TicketPriority::Medium),
+            difficulty: model.difficulty.parse().unwrap_or_default(),
             assigned_to: model.assigned_to.map(AgentId),
             created_by: model.created_by.map(AgentId),
             created_at: model
@@ -205,6 +264,7 @@ impl From<tickets::Model> for Ticket {
                 .map(|dt| dt.with_timezone(&Utc))
                 .unwrap_or_else(Utc::now),
             closed_at: model.closed_at.map(|dt| dt.with_timezone(&Utc)),
+            blocked_reason: model.blocked_reason,
         }
     }
 }
@@ -4,16 +4,17 @@
 //! the SeaORM entity files before it will compile.
 
 use async_trait::async_trait;
-use chrono::Utc;
+use chrono::{DateTime, Utc};
 use sea_orm::{
     sea_query::{Expr, SimpleExpr},
     ActiveModelTrait, ColumnTrait, DatabaseConnection, EntityTrait, PaginatorTrait, QueryFilter,
-    QueryOrder, QuerySelect, Set,
+    QueryOrder, QuerySelect, Select, Set,
 };
 use uuid::Uuid;
 
 use crate::domain::entities::{
-    AgentId, MomentType, NewViralMoment, ReferenceType, ViralMoment, ViralMomentId,
+    AgentId, MomentFilter, MomentSort, MomentType, NewViralMoment, ReferenceType, ViralMoment,
+    ViralMomentId,
 };
 use crate::domain::ports::ViralMomentRepository;
 use crate::entity::viral_moments;
@@ -28,6 +29,20 @@ impl PostgresViralMomentRepository {
     pub fn new(db: DatabaseConnection) -> Self {
         Self { db }
     }
+
+    fn filtered(filter: &MomentFilter) -> Select<viral_moments::Entity> {
+        let mut query = viral_moments::Entity::find();
+        if let Some(moment_type) = filter.moment_type {
+            query = query.filter(viral_moments::Column::MomentType.eq(moment_type.to_string()));
+        }
+        if let Some(promoted) = filter.promoted {
+            query = query.filter(viral_moments::Column::Promoted.eq(promoted));
+        }
+        if let Some(hidden) = filter.hidden {
+            query = query.filter(viral_moments::Column::Hidden.eq(hidden));
+        }
+        query
+    }
 }
 
 #[async_trait]
@@ -88,6 +103,49 @@ impl ViralMomentRepository for PostgresViralMomentRepository {
         Ok(results.into_iter().map(|m| m.into()).collect())
     }
 
+    async fn count_promoted(&self) -> Result<i64, DomainError> {
+        let count = viral_moments::Entity::find()
+            .filter(viral_moments::Column::Hidden.eq(false))
+            .filter(viral_moments::Column::Promoted.eq(true))
+            .count(&self.db)
+            .await
+            .map_err(|e| DomainError::Database(e.to_string()))?;
+
+        Ok(count as i64)
+    }
+
+    async fn find_filtered(
+        &self,
+        filter: &MomentFilter,
+        limit: i64,
+        offset: i64,
+    ) -> Result<Vec<ViralMoment>, DomainError> {
+        let query = Self::filtered(filter);
+        let query = match filter.sort {
+            MomentSort::Score => query
+                .order_by_desc(viral_moments::Column::Score)
+                .order_by_desc(viral_moments::Column::CreatedAt),
+            MomentSort::Recent => query.order_by_desc(viral_moments::Column::CreatedAt),
+        };
+        let results = query
+            .offset(offset as u64)
+            .limit(limit as u64)
+            .all(&self.db)
+            .await
+            .map_err(|e| DomainError::Database(e.to_string()))?;
+
+        Ok(results.into_iter().map(|m| m.into()).collect())
+    }
+
+    async fn count_filtered(&self, filter: &MomentFilter) -> Result<i64, DomainError> {
+        let count = Self::filtered(filter)
+            .count(&self.db)
+            .await
+            .map_err(|e| DomainError::Database(e.to_string()))?;
+
+        Ok(count as i64)
+    }
+
     async fn exists_for_reference(
         &self,
         reference_type: &str,
@@ -125,6 +183,7 @@ impl ViralMomentRepository for PostgresViralMomentRepository {
             llm_classified: Set(false),
             llm_classification: Set(None),
             created_at: Set(now),
+            snapshot_compacted_at: Set(None),
         };
 
         let result = model
@@ -215,6 +274,49 @@ impl ViralMomentRepository for PostgresViralMomentRepository {
 
         Ok(results.into_iter().map(|m| m.into()).collect())
     }
+
+    async fn find_compactable(
+        &self,
+        before: DateTime<Utc>,
+        limit: i64,
+    ) -> Result<Vec<ViralMoment>, DomainError> {
+        let results = viral_moments::Entity::find()
+            .filter(viral_moments::Column::Promoted.eq(false))
+            .filter(viral_moments::Column::SnapshotCompactedAt.is_null())
+            .filter(viral_moments::Column::CreatedAt.lt(before.fixed_offset()))
+            .order_by_asc(viral_moments::Column::CreatedAt)
+            .limit(limit.max(0) as u64)
+            .all(&self.db)
+            .await
+            .map_err(|e| DomainError::Database(e.to_string()))?;
+
+        Ok(results.into_iter().map(|m| m.into()).collect())
+    }
+
+    async fn compact_snapshot(
+        &self,
+        id: &ViralMomentId,
+        summary: &serde_json::Value,
+    ) -> Result<bool, DomainError> {
+        // Re-check promotion in the update itself so a moment promoted since
+        // it was selected keeps its full snapshot
+        let result = viral_moments::Entity::update_many()
+            .col_expr(
+                viral_moments::Column::Snapshot,
+                Expr::value(summary.clone()),
+            )
+            .col_expr(
+                viral_moments::Column::SnapshotCompactedAt,
+                Expr::value(Utc::now().fixed_offset()),
+            )
+            .filter(viral_moments::Column::Id.eq(id.0))
+            .filter(viral_moments::Column::Promoted.eq(false))
+            .exec(&self.db)
+            .await
+            .map_err(|e| DomainError::Database(e.to_string()))?;
+
+        Ok(result.rows_affected > 0)
+    }
 }
 
 /// Convert SeaORM model to domain entity
@@ -240,6 +342,7 @@ impl From<viral_moments::Model> for ViralMoment {
                 .llm_classification
                 .and_then(|v| serde_json::from_value(v).ok()),
             created_at: model.created_at.with_timezone(&Utc),
+            snapshot_compacted_at: model.snapshot_compacted_at.map(|t| t.with_timezone(&Utc)),
         }
     }
 }
@@ -0,0 +1,133 @@
+//! PostgreSQL adapter for WebhookDeliveryRepository
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, DatabaseConnection, EntityTrait, PaginatorTrait, QueryFilter,
+    QueryOrder, QuerySelect, Set,
+};
+use uuid::Uuid;
+
+use crate::domain::entities::{
+    DeliveryOutcome, NewWebhookDelivery, WebhookDelivery, WebhookDeliveryId,
+};
+use crate::domain::ports::WebhookDeliveryRepository;
+use crate::entity::webhook_deliveries;
+use crate::error::DomainError;
+
+/// PostgreSQL implementation of WebhookDeliveryRepository
+pub struct PostgresWebhookDeliveryRepository {
+    db: DatabaseConnection,
+}
+
+impl PostgresWebhookDeliveryRepository {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl WebhookDeliveryRepository for PostgresWebhookDeliveryRepository {
+    async fn create(&self, delivery: &NewWebhookDelivery) -> Result<WebhookDelivery, DomainError> {
+        let model = webhook_deliveries::ActiveModel {
+            id: Set(Uuid::new_v4()),
+            delivery_id: Set(delivery.delivery_id.clone()),
+            event_type: Set(delivery.event_type.clone()),
+            repo: Set(delivery.repo.clone()),
+            action: Set(delivery.action.clone()),
+            outcome: Set(delivery.outcome.to_string()),
+            error: Set(delivery.error.clone()),
+            received_at: Set(Utc::now().fixed_offset()),
+            payload: Set(delivery.payload.clone()),
+        };
+
+        let result = model
+            .insert(&self.db)
+            .await
+            .map_err(|e| DomainError::Database(e.to_string()))?;
+
+        Ok(result.into())
+    }
+
+    async fn find_recent(&self, limit: i64) -> Result<Vec<WebhookDelivery>, DomainError> {
+        let results = webhook_deliveries::Entity::find()
+            .order_by_desc(webhook_deliveries::Column::ReceivedAt)
+            .limit(limit as u64)
+            .all(&self.db)
+            .await
+            .map_err(|e| DomainError::Database(e.to_string()))?;
+
+        Ok(results.into_iter().map(|m| m.into()).collect())
+    }
+
+    async fn is_processed(&self, delivery_id: &str) -> Result<bool, DomainError> {
+        let count = webhook_deliveries::Entity::find()
+            .filter(webhook_deliveries::Column::DeliveryId.eq(delivery_id))
+            .filter(webhook_deliveries::Column::Outcome.eq(DeliveryOutcome::Success.to_string()))
+            .count(&self.db)
+            .await
+            .map_err(|e| DomainError::Database(e.to_string()))?;
+
+        Ok(count > 0)
+    }
+
+    async fn complete(
+        &self,
+        id: &WebhookDeliveryId,
+        outcome: DeliveryOutcome,
+        error: Option<String>,
+    ) -> Result<(), DomainError> {
+        webhook_deliveries::ActiveModel {
+            id: Set(id.0),
+            outcome: Set(outcome.to_string()),
+            error: Set(error),
+            payload: Set(None),
+            ..Default::default()
+        }
+        .update(&self.db)
+        .await
+        .map_err(|e| DomainError::Database(e.to_string()))?;
+
+        Ok(())
+    }
+
+    async fn find_queued(&self, limit: i64) -> Result<Vec<WebhookDelivery>, DomainError> {
+        let results = webhook_deliveries::Entity::find()
+            .filter(webhook_deliveries::Column::Outcome.eq(DeliveryOutcome::Queued.to_string()))
+            .order_by_asc(webhook_deliveries::Column::ReceivedAt)
+            .limit(limit as u64)
+            .all(&self.db)
+            .await
+            .map_err(|e| DomainError::Database(e.to_string()))?;
+
+        Ok(results.into_iter().map(|m| m.into()).collect())
+    }
+
+    async fn delete_received_before(&self, before: DateTime<Utc>) -> Result<u64, DomainError> {
+        let result = webhook_deliveries::Entity::delete_many()
+            .filter(webhook_deliveries::Column::ReceivedAt.lt(before.fixed_offset()))
+            .filter(webhook_deliveries::Column::Outcome.ne(DeliveryOutcome::Queued.to_string()))
+            .exec(&self.db)
+            .await
+            .map_err(|e| DomainError::Database(e.to_string()))?;
+
+        Ok(result.rows_affected)
+    }
+}
+
+/// Convert SeaORM model to domain entity
+impl From<webhook_deliveries::Model> for WebhookDelivery {
+    fn from(model: webhook_deliveries::Model) -> Self {
+        WebhookDelivery {
+            id: WebhookDeliveryId(model.id),
+            delivery_id: model.delivery_id,
+            event_type: model.event_type,
+            repo: model.repo,
+            action: model.action,
+            outcome: model.outcome.parse().unwrap_or(DeliveryOutcome::Failed),
+            error: model.error,
+            received_at: model.received_at.with_timezone(&Utc),
+            payload: model.payload,
+        }
+    }
+}
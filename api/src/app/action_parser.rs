@@ -2,7 +2,7 @@
 //!
 //! Parses text commands from agents like "join 1", "details 2", etc.
 
-use crate::error::ParseError;
+use crate::error::ActionParseError;
 
 /// Review action types
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -83,12 +83,12 @@ pub enum AgentAction {
 }
 
 /// Parse an agent action from text input
-pub fn parse_action(input: &str) -> Result<AgentAction, ParseError> {
+pub fn parse_action(input: &str) -> Result<AgentAction, ActionParseError> {
     let input = input.trim();
 
     // Handle empty input
     if input.is_empty() {
-        return Err(ParseError::UnknownCommand("empty input".to_string()));
+        return Err(ActionParseError::Empty);
     }
 
     // Split into parts
@@ -99,61 +99,24 @@ pub fn parse_action(input: &str) -> Result<AgentAction, ParseError> {
 
     match command.as_str() {
         "details" | "detail" | "info" => {
-            if parts.len() < 2 {
-                return Err(ParseError::MissingArgument("details".to_string()));
-            }
-            let index: usize = parts[1].parse().map_err(|_| {
-                ParseError::InvalidArgument(format!("'{}' is not a valid number", parts[1]))
-            })?;
-            if index == 0 {
-                return Err(ParseError::InvalidArgument(
-                    "index must be 1 or greater".to_string(),
-                ));
-            }
-            Ok(AgentAction::Details {
-                item_index: index - 1,
-            })
+            let index = parse_index(&parts, "details", "details <N>")?;
+            Ok(AgentAction::Details { item_index: index })
         }
 
         "join" => {
-            if parts.len() < 2 {
-                return Err(ParseError::MissingArgument("join".to_string()));
-            }
-            let index: usize = parts[1].parse().map_err(|_| {
-                ParseError::InvalidArgument(format!("'{}' is not a valid number", parts[1]))
-            })?;
-            if index == 0 {
-                return Err(ParseError::InvalidArgument(
-                    "index must be 1 or greater".to_string(),
-                ));
-            }
+            let index = parse_index(&parts, "join", "join <N>")?;
             Ok(AgentAction::Join {
-                project_index: index - 1,
+                project_index: index,
             })
         }
 
         "work-on" | "workon" | "start" | "claim" => {
-            if parts.len() < 2 {
-                return Err(ParseError::MissingArgument("work-on".to_string()));
-            }
-            let index: usize = parts[1].parse().map_err(|_| {
-                ParseError::InvalidArgument(format!("'{}' is not a valid number", parts[1]))
-            })?;
-            if index == 0 {
-                return Err(ParseError::InvalidArgument(
-                    "index must be 1 or greater".to_string(),
-                ));
-            }
-            Ok(AgentAction::WorkOn {
-                item_index: index - 1,
-            })
+            let index = parse_index(&parts, "work-on", "work-on <N>")?;
+            Ok(AgentAction::WorkOn { item_index: index })
         }
 
         "submit" | "pr" => {
-            if parts.len() < 2 {
-                return Err(ParseError::MissingArgument("submit".to_string()));
-            }
-            let branch = parts[1].to_string();
+            let branch = required_arg(&parts, 1, "submit", "branch", SUBMIT_USAGE)?.to_string();
 
             // Parse optional title (everything after branch in quotes or until end)
             // Format: submit <branch> "title" "body"
@@ -168,25 +131,26 @@ pub fn parse_action(input: &str) -> Result<AgentAction, ParseError> {
         }
 
         "review" => {
-            if parts.len() < 3 {
-                return Err(ParseError::MissingArgument(
-                    "review (usage: review <action> <pr-number> [comment])".to_string(),
-                ));
-            }
-
-            let action: ReviewAction = parts[1].parse().map_err(|e: String| {
-                ParseError::InvalidArgument(format!(
-                    "{} (valid: approve, request-changes, comment)",
-                    e
-                ))
-            })?;
-
-            let pr_number: i64 = parts[2]
+            let action_arg = required_arg(&parts, 1, "review", "action", REVIEW_USAGE)?;
+            let pr_arg = required_arg(&parts, 2, "review", "pr-number", REVIEW_USAGE)?;
+
+            let action: ReviewAction =
+                action_arg
+                    .parse()
+                    .map_err(|_| ActionParseError::InvalidArgument {
+                        name: "action".to_string(),
+                        value: action_arg.to_string(),
+                        reason: "expected one of approve, request-changes, comment".to_string(),
+                    })?;
+
+            let pr_number: i64 = pr_arg
                 .trim_start_matches("pr-")
                 .trim_start_matches('#')
                 .parse()
-                .map_err(|_| {
-                    ParseError::InvalidArgument(format!("'{}' is not a valid PR number", parts[2]))
+                .map_err(|_| ActionParseError::InvalidArgument {
+                    name: "pr-number".to_string(),
+                    value: pr_arg.to_string(),
+                    reason: "expected a PR number such as 42, #42 or pr-42".to_string(),
                 })?;
 
             // Rest is the comment
@@ -224,11 +188,47 @@ pub fn parse_action(input: &str) -> Result<AgentAction, ParseError> {
                     });
                 }
             }
-            Err(ParseError::UnknownCommand(command))
+            Err(ActionParseError::UnknownAction { action: command })
         }
     }
 }
 
+const SUBMIT_USAGE: &str = "submit <branch> [\"title\"] [\"body\"]";
+const REVIEW_USAGE: &str = "review <action> <pr-number> [comment]";
+
+/// Get the positional argument at `position`, or report it missing
+fn required_arg<'a>(
+    parts: &[&'a str],
+    position: usize,
+    action: &str,
+    name: &str,
+    usage: &str,
+) -> Result<&'a str, ActionParseError> {
+    parts
+        .get(position)
+        .copied()
+        .ok_or_else(|| ActionParseError::MissingArgument {
+            action: action.to_string(),
+            name: name.to_string(),
+            usage: usage.to_string(),
+        })
+}
+
+/// Parse the 1-based feed index argument into a 0-based index
+fn parse_index(parts: &[&str], action: &str, usage: &str) -> Result<usize, ActionParseError> {
+    let value = required_arg(parts, 1, action, "N", usage)?;
+    let invalid = |reason: &str| ActionParseError::InvalidArgument {
+        name: "N".to_string(),
+        value: value.to_string(),
+        reason: reason.to_string(),
+    };
+    match value.parse::<usize>() {
+        Ok(0) => Err(invalid("index must be 1 or greater")),
+        Ok(index) => Ok(index - 1),
+        Err(_) => Err(invalid("not a valid number")),
+    }
+}
+
 /// Parse title and body from submit command arguments
 fn parse_submit_args(parts: &[&str]) -> (Option<String>, Option<String>) {
     if parts.is_empty() {
@@ -450,6 +450,75 @@ mod tests {
         assert!(parse_action("review badaction 1").is_err()); // Invalid action
     }
 
+    #[test]
+    fn test_parse_error_empty() {
+        assert_eq!(parse_action("   ").unwrap_err(), ActionParseError::Empty);
+    }
+
+    #[test]
+    fn test_parse_error_unknown_action() {
+        assert_eq!(
+            parse_action("Foobar 1").unwrap_err(),
+            ActionParseError::UnknownAction {
+                action: "foobar".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_error_missing_argument() {
+        assert_eq!(
+            parse_action("review approve").unwrap_err(),
+            ActionParseError::MissingArgument {
+                action: "review".to_string(),
+                name: "pr-number".to_string(),
+                usage: REVIEW_USAGE.to_string(),
+            }
+        );
+        assert_eq!(
+            parse_action("join").unwrap_err(),
+            ActionParseError::MissingArgument {
+                action: "join".to_string(),
+                name: "N".to_string(),
+                usage: "join <N>".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_error_invalid_argument() {
+        assert_eq!(
+            parse_action("details 0").unwrap_err(),
+            ActionParseError::InvalidArgument {
+                name: "N".to_string(),
+                value: "0".to_string(),
+                reason: "index must be 1 or greater".to_string(),
+            }
+        );
+        let err = parse_action("review lgtm pr-x").unwrap_err();
+        assert!(matches!(
+            err,
+            ActionParseError::InvalidArgument { ref name, ref value, .. }
+                if name == "pr-number" && value == "pr-x"
+        ));
+        let err = parse_action("review badaction 1").unwrap_err();
+        assert!(matches!(
+            err,
+            ActionParseError::InvalidArgument { ref name, .. } if name == "action"
+        ));
+    }
+
+    #[test]
+    fn test_parse_error_is_unprocessable() {
+        use axum::response::IntoResponse;
+
+        let err = crate::error::AppError::from(parse_action("join x").unwrap_err());
+        assert_eq!(
+            err.into_response().status(),
+            axum::http::StatusCode::UNPROCESSABLE_ENTITY
+        );
+    }
+
     #[test]
     fn test_review_action_display() {
         assert_eq!(ReviewAction::Approve.to_string(), "approve");
@@ -2,15 +2,76 @@
 //!
 //! Handles agent registration, authentication, and profile management.
 
+use std::collections::BTreeMap;
 use std::sync::Arc;
+use std::time::Duration;
 
+use async_trait::async_trait;
+use chrono::{DateTime, NaiveDate};
 use rand::Rng;
 use sha2::{Digest, Sha256};
 
-use crate::domain::entities::{Agent, AgentId, NewAgent};
-use crate::domain::ports::{AgentRepository, GiteaClient};
+use crate::app::elo_config::ELO_STARTING;
+use crate::app::reactive_elo_service::EloBounds;
+use crate::app::token_cache::TokenCache;
+use crate::domain::entities::{Agent, AgentId, AgentMergeCounts, NamePolicy, NewAgent, Tier};
+use crate::domain::ports::{AgentMergeRepository, AgentRepository, GiteaClient};
 use crate::error::{AppError, DomainError, GiteaError};
 
+/// Name of the Gitea access token issued to each agent
+const GITEA_TOKEN_NAME: &str = "synstack-api";
+
+/// What a batch user creation does when some users can't be created
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum BatchFailurePolicy {
+    /// Keep the users that were created and report per-agent results
+    #[default]
+    Report,
+    /// Delete the users that were created so the batch is all-or-nothing
+    Rollback,
+}
+
+/// Outcome of creating one agent's Gitea user in a batch
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum UserCreationOutcome {
+    Created,
+    Failed(String),
+    /// Created, then deleted because another user in the batch failed
+    RolledBack,
+}
+
+/// Contributions an agent made on one UTC day
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ActivityDay {
+    pub date: NaiveDate,
+    pub count: i64,
+}
+
+/// Per-agent result of a batch user creation
+#[derive(Debug, Clone)]
+pub struct BatchUserResult {
+    pub agent_id: AgentId,
+    pub gitea_username: String,
+    pub outcome: UserCreationOutcome,
+}
+
+/// Outcome of merging a duplicate agent into another
+#[derive(Debug, Clone)]
+pub struct AgentMergeResult {
+    /// The deactivated duplicate, as it was before the merge
+    pub source: Agent,
+    /// The surviving agent with its combined ELO
+    pub target: Agent,
+    pub moved: AgentMergeCounts,
+}
+
+/// Looks up the Gitea token an agent acts under, for work done on an
+/// agent's behalf outside of its own request
+#[async_trait]
+pub trait AgentTokens: Send + Sync {
+    async fn gitea_token(&self, id: &AgentId) -> Result<Option<String>, AppError>;
+}
+
 /// Service for managing agents
 pub struct AgentService<AR, GC>
 where
@@ -20,6 +81,9 @@ where
     agents: Arc<AR>,
     gitea: Arc<GC>,
     encryption_key: String,
+    name_policy: NamePolicy,
+    token_cache: TokenCache,
+    elo_bounds: EloBounds,
 }
 
 impl<AR, GC> AgentService<AR, GC>
@@ -32,9 +96,30 @@ where
             agents,
             gitea,
             encryption_key,
+            name_policy: NamePolicy::default(),
+            token_cache: TokenCache::default(),
+            elo_bounds: EloBounds::default(),
         }
     }
 
+    pub fn with_name_policy(mut self, policy: NamePolicy) -> Self {
+        self.name_policy = policy;
+        self
+    }
+
+    /// ELO floor and ceiling a merged agent's combined ELO is held within
+    pub fn with_elo_bounds(mut self, bounds: EloBounds) -> Self {
+        self.elo_bounds = bounds;
+        self
+    }
+
+    /// Cache decrypted Gitea tokens in memory for `ttl`, holding at most
+    /// `max_entries` (a zero TTL disables the cache)
+    pub fn with_token_cache(mut self, ttl: Duration, max_entries: usize) -> Self {
+        self.token_cache = TokenCache::new(ttl, max_entries);
+        self
+    }
+
     /// Register a new agent
     ///
     /// Creates:
@@ -51,6 +136,10 @@ where
             ));
         }
 
+        if let Some(reason) = self.name_policy.violation(name) {
+            return Err(AppError::Domain(DomainError::Validation(reason)));
+        }
+
         // Check if name is already taken
         if self.agents.find_by_name(name).await?.is_some() {
             return Err(AppError::Domain(DomainError::AlreadyExists(format!(
@@ -59,29 +148,43 @@ where
             ))));
         }
 
+        let gitea_username = format!("agent-{}", name.to_lowercase().replace(' ', "-"));
+        let leftover_user = self
+            .ensure_no_foreign_gitea_user(name, &gitea_username)
+            .await?;
+
         // Generate credentials
         let api_key = generate_api_key();
         let api_key_hash = hash_api_key(&api_key);
         let claim_code = generate_claim_code();
-        let gitea_username = format!("agent-{}", name.to_lowercase().replace(' ', "-"));
-        let gitea_email = format!("{}@agents.synstack.local", gitea_username);
+        let gitea_email = agent_email(&gitea_username);
         let gitea_password = generate_password();
 
-        // Create Gitea user
-        self.gitea
-            .create_user(&gitea_username, &gitea_email, &gitea_password)
-            .await
-            .map_err(|e| match e {
-                GiteaError::Api { status: 422, .. } => AppError::Domain(
-                    DomainError::AlreadyExists("Gitea user already exists".to_string()),
-                ),
-                e => AppError::Gitea(e),
-            })?;
+        if leftover_user {
+            // An earlier attempt created the user but never stored the agent;
+            // take the user over with a fresh password rather than failing
+            tracing::warn!(
+                username = %gitea_username,
+                "Reusing Gitea user left by an earlier registration attempt"
+            );
+            self.gitea
+                .set_user_password(&gitea_username, &gitea_password)
+                .await?;
+        } else {
+            self.gitea
+                .create_user(&gitea_username, &gitea_email, &gitea_password)
+                .await
+                .map_err(|e| match e {
+                    GiteaError::Api { status: 422, .. } => AppError::Domain(
+                        DomainError::AlreadyExists("Gitea user already exists".to_string()),
+                    ),
+                    e => AppError::Gitea(e),
+                })?;
+        }
 
         // Create Gitea access token (requires user's password)
         let gitea_token = self
-            .gitea
-            .create_access_token(&gitea_username, &gitea_password, "synstack-api")
+            .create_access_token(&gitea_username, &gitea_password)
             .await?;
 
         // Encrypt the token for storage
@@ -101,6 +204,184 @@ where
         Ok((agent, api_key, gitea_token, claim_code))
     }
 
+    /// Create Gitea users for existing agents, by ID
+    ///
+    /// Used to re-provision agents whose Gitea users are missing, e.g.
+    /// after the Gitea instance was reset. Every ID must name an agent.
+    pub async fn provision_gitea_users(
+        &self,
+        agent_ids: &[AgentId],
+        policy: BatchFailurePolicy,
+    ) -> Result<Vec<BatchUserResult>, AppError>
+    where
+        GC: 'static,
+    {
+        let mut agents = Vec::with_capacity(agent_ids.len());
+        for id in agent_ids {
+            let agent = self
+                .agents
+                .find_by_id(id)
+                .await?
+                .ok_or_else(|| AppError::NotFound(format!("Agent {} not found", id)))?;
+            agents.push(agent);
+        }
+
+        Ok(self.create_gitea_users(&agents, policy).await)
+    }
+
+    /// Create Gitea users for many agents concurrently
+    ///
+    /// Results are returned in the same order as `agents`. With
+    /// `BatchFailurePolicy::Rollback`, any failure deletes the users this
+    /// batch created; a user that can't be deleted stays `Created`.
+    pub async fn create_gitea_users(
+        &self,
+        agents: &[Agent],
+        policy: BatchFailurePolicy,
+    ) -> Vec<BatchUserResult>
+    where
+        GC: 'static,
+    {
+        let mut calls = tokio::task::JoinSet::new();
+        for (index, agent) in agents.iter().enumerate() {
+            let gitea = self.gitea.clone();
+            let username = agent.gitea_username.clone();
+            calls.spawn(async move {
+                let email = agent_email(&username);
+                let result = gitea
+                    .create_user(&username, &email, &generate_password())
+                    .await;
+                (index, result)
+            });
+        }
+
+        let mut outcomes = vec![None; agents.len()];
+        while let Some(joined) = calls.join_next().await {
+            match joined {
+                Ok((index, Ok(_))) => outcomes[index] = Some(UserCreationOutcome::Created),
+                Ok((index, Err(e))) => {
+                    outcomes[index] = Some(UserCreationOutcome::Failed(e.to_string()))
+                }
+                Err(e) => tracing::warn!("Gitea user creation task failed: {}", e),
+            }
+        }
+
+        let mut results: Vec<BatchUserResult> = agents
+            .iter()
+            .zip(outcomes)
+            .map(|(agent, outcome)| BatchUserResult {
+                agent_id: agent.id,
+                gitea_username: agent.gitea_username.clone(),
+                outcome: outcome.unwrap_or_else(|| {
+                    UserCreationOutcome::Failed("User creation did not complete".to_string())
+                }),
+            })
+            .collect();
+
+        let any_failed = results
+            .iter()
+            .any(|r| matches!(r.outcome, UserCreationOutcome::Failed(_)));
+        if policy == BatchFailurePolicy::Rollback && any_failed {
+            for result in results
+                .iter_mut()
+                .filter(|r| r.outcome == UserCreationOutcome::Created)
+            {
+                match self.gitea.delete_user(&result.gitea_username).await {
+                    Ok(()) => result.outcome = UserCreationOutcome::RolledBack,
+                    Err(e) => tracing::warn!(
+                        "Failed to roll back Gitea user {}: {}",
+                        result.gitea_username,
+                        e
+                    ),
+                }
+            }
+        }
+
+        results
+    }
+
+    /// Create the agent's Gitea access token, recovering from a name collision
+    ///
+    /// A retried registration can find a token left over from an earlier
+    /// attempt. The stale token is deleted and recreated; if it can't be
+    /// deleted, a uniquely suffixed name is used instead.
+    async fn create_access_token(
+        &self,
+        username: &str,
+        password: &str,
+    ) -> Result<String, AppError> {
+        match self
+            .gitea
+            .create_access_token(username, password, GITEA_TOKEN_NAME)
+            .await
+        {
+            Err(e) if is_token_name_collision(&e) => {}
+            result => return Ok(result?),
+        }
+
+        tracing::warn!(
+            username,
+            "Gitea token '{}' already exists; replacing it",
+            GITEA_TOKEN_NAME
+        );
+        let token_name = match self
+            .gitea
+            .delete_access_token(username, GITEA_TOKEN_NAME)
+            .await
+        {
+            Ok(()) => GITEA_TOKEN_NAME.to_string(),
+            Err(e) => {
+                tracing::warn!(username, "Failed to delete stale Gitea token: {}", e);
+                let suffix: u32 = rand::thread_rng().gen();
+                format!("{}-{:08x}", GITEA_TOKEN_NAME, suffix)
+            }
+        };
+
+        Ok(self
+            .gitea
+            .create_access_token(username, password, &token_name)
+            .await?)
+    }
+
+    /// Reject names that match a Gitea user the platform didn't create
+    ///
+    /// Checks both the bare name (so agents can't pose as existing Gitea
+    /// users such as maintainers) and the username we'd create for the agent.
+    /// Returns true when the agent's username belongs to a user the platform
+    /// created in an earlier attempt that never stored the agent.
+    async fn ensure_no_foreign_gitea_user(
+        &self,
+        name: &str,
+        gitea_username: &str,
+    ) -> Result<bool, AppError> {
+        let bare = name.to_lowercase().replace(' ', "-");
+        let mut leftover = false;
+        for login in [bare.as_str(), gitea_username] {
+            let user = match self.gitea.get_user(login).await {
+                Ok(user) => user,
+                Err(GiteaError::UserNotFound(_)) => continue,
+                Err(e) => return Err(AppError::Gitea(e)),
+            };
+            if self
+                .agents
+                .find_by_gitea_username(&user.login)
+                .await?
+                .is_some()
+            {
+                continue;
+            }
+            if login == gitea_username && user.email == agent_email(gitea_username) {
+                leftover = true;
+                continue;
+            }
+            return Err(AppError::Domain(DomainError::Validation(format!(
+                "The name '{}' collides with an existing Gitea user",
+                name
+            ))));
+        }
+        Ok(leftover)
+    }
+
     /// Find an agent by their API key hash
     pub async fn find_by_api_key(&self, api_key_hash: &str) -> Result<Option<Agent>, AppError> {
         Ok(self.agents.find_by_api_key_hash(api_key_hash).await?)
@@ -124,9 +405,40 @@ where
     }
 
     /// Get the decrypted Gitea token for an agent
+    ///
+    /// Served from the in-memory token cache when a fresh entry exists.
     pub async fn get_gitea_token(&self, id: &AgentId) -> Result<Option<String>, AppError> {
+        if let Some(token) = self.token_cache.get(id) {
+            return Ok(Some(token));
+        }
+
         let encrypted = self.agents.get_gitea_token_encrypted(id).await?;
-        Ok(encrypted.map(|e| decrypt_token(&e, &self.encryption_key)))
+        let token = encrypted.map(|e| decrypt_token(&e, &self.encryption_key));
+        if let Some(token) = &token {
+            self.token_cache.insert(*id, token.clone());
+        }
+        Ok(token)
+    }
+
+    /// Issue the agent a new Gitea token, revoking the old one
+    ///
+    /// The agent's Gitea password is reset (tokens need it to be created),
+    /// the old token is replaced, and any cached copy is dropped.
+    pub async fn rotate_gitea_token(&self, agent: &Agent) -> Result<String, AppError> {
+        let password = generate_password();
+        self.gitea
+            .set_user_password(&agent.gitea_username, &password)
+            .await?;
+        let token = self
+            .create_access_token(&agent.gitea_username, &password)
+            .await?;
+
+        let encrypted = encrypt_token(&token, &self.encryption_key);
+        self.agents
+            .update_gitea_token_encrypted(&agent.id, encrypted)
+            .await?;
+        self.token_cache.invalidate(&agent.id);
+        Ok(token)
     }
 
     /// Get leaderboard (top agents by ELO)
@@ -134,6 +446,43 @@ where
         Ok(self.agents.find_top_by_elo(limit).await?)
     }
 
+    /// Number of agents in each tier, lowest tier first (empty tiers count as 0)
+    pub async fn get_tier_distribution(&self) -> Result<Vec<(Tier, i64)>, AppError> {
+        let counts = self.agents.count_by_tier().await?;
+        Ok(Tier::ALL
+            .into_iter()
+            .map(|tier| {
+                let count = counts
+                    .iter()
+                    .filter(|(t, _)| *t == tier)
+                    .map(|(_, c)| c)
+                    .sum();
+                (tier, count)
+            })
+            .collect())
+    }
+
+    /// Daily contribution counts from the agent's Gitea heatmap, oldest first.
+    /// Gitea reports sub-day buckets; they're summed per UTC day and days
+    /// without activity are omitted.
+    pub async fn get_activity(&self, agent: &Agent) -> Result<Vec<ActivityDay>, AppError> {
+        let heatmap = self.gitea.get_user_heatmap(&agent.gitea_username).await?;
+
+        let mut days: BTreeMap<NaiveDate, i64> = BTreeMap::new();
+        for entry in heatmap {
+            let Some(at) = DateTime::from_timestamp(entry.timestamp, 0) else {
+                continue;
+            };
+            *days.entry(at.date_naive()).or_default() += entry.contributions;
+        }
+
+        Ok(days
+            .into_iter()
+            .filter(|(_, count)| *count > 0)
+            .map(|(date, count)| ActivityDay { date, count })
+            .collect())
+    }
+
     /// Find an agent by their claim code
     pub async fn find_by_claim_code(&self, code: &str) -> Result<Option<Agent>, AppError> {
         Ok(self.agents.find_by_claim_code(code).await?)
@@ -158,6 +507,63 @@ where
         self.agents.claim(id, claim).await?;
         Ok(())
     }
+
+    /// Merge a duplicate agent into another. The source's history, project
+    /// memberships and pending penalties move to the target, the target
+    /// keeps the ELO both agents earned above the starting rating (within
+    /// the configured bounds), and the source is deactivated.
+    pub async fn merge_agents<AMR: AgentMergeRepository>(
+        &self,
+        merger: &AMR,
+        source_id: &AgentId,
+        target_id: &AgentId,
+    ) -> Result<AgentMergeResult, AppError> {
+        if source_id == target_id {
+            return Err(AppError::BadRequest(
+                "Cannot merge an agent into itself".to_string(),
+            ));
+        }
+
+        let source = self
+            .agents
+            .find_by_id(source_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound(format!("Agent {} not found", source_id)))?;
+        if self.agents.find_by_id(target_id).await?.is_none() {
+            return Err(AppError::NotFound(format!("Agent {} not found", target_id)));
+        }
+
+        // Both agents started from the same rating; count it once. The sum
+        // is taken inside the merge so ELO changes made meanwhile aren't lost
+        let moved = merger
+            .merge(
+                source_id,
+                target_id,
+                ELO_STARTING,
+                self.elo_bounds.floor,
+                self.elo_bounds.ceiling,
+            )
+            .await?;
+        let target = self
+            .agents
+            .find_by_id(target_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound(format!("Agent {} not found", target_id)))?;
+
+        tracing::info!(
+            source = %source_id,
+            target = %target_id,
+            elo = target.elo,
+            ?moved,
+            "Merged agents"
+        );
+
+        Ok(AgentMergeResult {
+            source,
+            target,
+            moved,
+        })
+    }
 }
 
 /// Generate a random API key
@@ -167,6 +573,25 @@ fn generate_api_key() -> String {
     format!("sk-{}", hex::encode(bytes))
 }
 
+/// Whether Gitea rejected a token because one with that name already exists
+fn is_token_name_collision(error: &GiteaError) -> bool {
+    match error {
+        GiteaError::Api {
+            status: 400 | 409 | 422,
+            message,
+        } => {
+            let message = message.to_lowercase();
+            message.contains("already") || message.contains("exists")
+        }
+        _ => false,
+    }
+}
+
+/// Email of the Gitea user the platform creates for an agent
+fn agent_email(gitea_username: &str) -> String {
+    format!("{}@agents.synstack.local", gitea_username)
+}
+
 /// Generate a random password for Gitea
 fn generate_password() -> String {
     let mut rng = rand::thread_rng();
@@ -181,6 +606,17 @@ fn generate_claim_code() -> String {
     hex::encode(bytes)
 }
 
+#[async_trait]
+impl<AR, GC> AgentTokens for AgentService<AR, GC>
+where
+    AR: AgentRepository,
+    GC: GiteaClient,
+{
+    async fn gitea_token(&self, id: &AgentId) -> Result<Option<String>, AppError> {
+        self.get_gitea_token(id).await
+    }
+}
+
 /// Hash an API key for storage
 pub fn hash_api_key(api_key: &str) -> String {
     let mut hasher = Sha256::new();
@@ -214,7 +650,23 @@ fn decrypt_token(encrypted: &[u8], key: &str) -> String {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::test_utils::{test_agent, InMemoryAgentRepository, MockGiteaClient};
+    use crate::domain::entities::{
+        EloEventType, Engagement, EngagementId, EngagementType, MemberRole, MomentType,
+        NewPendingPenalty, ProjectId, ReactionType, ReferenceType, ReviewVerdict, TargetType,
+        ViralMoment, ViralMomentId,
+    };
+    use crate::domain::ports::{
+        AgentReviewRepository, CodeContributionRepository, EloEventRepository,
+        EngagementRepository, PendingPenaltyRepository, ProjectRepository, ViralMomentRepository,
+    };
+    use crate::test_utils::{
+        test_agent, test_agent_review, test_agent_with_elo, test_code_contribution, test_elo_event,
+        test_project, test_ticket_assigned, InMemoryAgentMergeRepository, InMemoryAgentRepository,
+        InMemoryAgentReviewRepository, InMemoryCodeContributionRepository,
+        InMemoryEloEventRepository, InMemoryEngagementRepository, InMemoryPendingPenaltyRepository,
+        InMemoryProjectRepository, InMemoryTicketRepository, InMemoryViralMomentRepository,
+        MockGiteaClient,
+    };
 
     fn create_service(
         agent_repo: InMemoryAgentRepository,
@@ -227,6 +679,171 @@ mod tests {
         )
     }
 
+    fn batch_agents() -> Vec<Agent> {
+        ["one", "two", "three"]
+            .into_iter()
+            .map(|name| {
+                let mut agent = test_agent();
+                agent.name = name.to_string();
+                agent.gitea_username = format!("agent-{}", name);
+                agent
+            })
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn activity_sums_heatmap_buckets_per_day() {
+        let agent = test_agent();
+        // Two buckets on 2026-01-15, one on 01-17, and an empty one on 01-18 (UTC)
+        let gitea = MockGiteaClient::new().with_heatmap(
+            &agent.gitea_username,
+            &[
+                (1_768_435_200, 2),
+                (1_768_489_200, 3),
+                (1_768_640_400, 1),
+                (1_768_694_400, 0),
+            ],
+        );
+        let service = create_service(InMemoryAgentRepository::new(), gitea);
+
+        let activity = service.get_activity(&agent).await.unwrap();
+
+        assert_eq!(
+            activity,
+            vec![
+                ActivityDay {
+                    date: NaiveDate::from_ymd_opt(2026, 1, 15).unwrap(),
+                    count: 5,
+                },
+                ActivityDay {
+                    date: NaiveDate::from_ymd_opt(2026, 1, 17).unwrap(),
+                    count: 1,
+                },
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn batch_user_creation_reports_partial_failure() {
+        let agents = batch_agents();
+        let gitea = Arc::new(MockGiteaClient::new().with_failing_user("agent-two"));
+        let service = AgentService::new(
+            Arc::new(InMemoryAgentRepository::new()),
+            gitea.clone(),
+            "test-encryption-key".to_string(),
+        );
+
+        let results = service
+            .create_gitea_users(&agents, BatchFailurePolicy::Report)
+            .await;
+
+        assert_eq!(results.len(), 3);
+        assert_eq!(results[0].agent_id, agents[0].id);
+        assert_eq!(results[0].outcome, UserCreationOutcome::Created);
+        assert!(matches!(results[1].outcome, UserCreationOutcome::Failed(_)));
+        assert_eq!(results[1].gitea_username, "agent-two");
+        assert_eq!(results[2].outcome, UserCreationOutcome::Created);
+
+        let mut created = gitea.users_created.read().unwrap().clone();
+        created.sort();
+        assert_eq!(created, vec!["agent-one", "agent-three"]);
+        assert!(gitea.users_deleted.read().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn batch_user_creation_rolls_back_on_failure() {
+        let agents = batch_agents();
+        let gitea = Arc::new(MockGiteaClient::new().with_failing_user("agent-two"));
+        let service = AgentService::new(
+            Arc::new(InMemoryAgentRepository::new()),
+            gitea.clone(),
+            "test-encryption-key".to_string(),
+        );
+
+        let results = service
+            .create_gitea_users(&agents, BatchFailurePolicy::Rollback)
+            .await;
+
+        assert_eq!(results[0].outcome, UserCreationOutcome::RolledBack);
+        assert!(matches!(results[1].outcome, UserCreationOutcome::Failed(_)));
+        assert_eq!(results[2].outcome, UserCreationOutcome::RolledBack);
+        assert!(gitea.users_created.read().unwrap().is_empty());
+        assert_eq!(gitea.users_deleted.read().unwrap().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn provision_gitea_users_looks_up_agents_by_id() {
+        let agents = batch_agents();
+        let repo = agents
+            .iter()
+            .cloned()
+            .fold(InMemoryAgentRepository::new(), |repo, agent| {
+                repo.with_agent(agent)
+            });
+        let gitea = Arc::new(MockGiteaClient::new());
+        let service = AgentService::new(
+            Arc::new(repo),
+            gitea.clone(),
+            "test-encryption-key".to_string(),
+        );
+
+        let ids: Vec<AgentId> = agents.iter().map(|a| a.id).collect();
+        let results = service
+            .provision_gitea_users(&ids, BatchFailurePolicy::Report)
+            .await
+            .unwrap();
+
+        assert_eq!(results.len(), 3);
+        assert!(results
+            .iter()
+            .all(|r| r.outcome == UserCreationOutcome::Created));
+        assert_eq!(gitea.users_created.read().unwrap().len(), 3);
+
+        let missing = service
+            .provision_gitea_users(&[AgentId::new()], BatchFailurePolicy::Report)
+            .await;
+        assert!(matches!(missing, Err(AppError::NotFound(_))));
+        assert_eq!(gitea.users_created.read().unwrap().len(), 3);
+    }
+
+    #[tokio::test]
+    async fn gitea_token_is_cached_until_rotated() {
+        let agent = test_agent();
+        let repo = InMemoryAgentRepository::new();
+        let id = repo
+            .create(&NewAgent {
+                name: agent.name.clone(),
+                api_key_hash: agent.api_key_hash.clone(),
+                gitea_username: agent.gitea_username.clone(),
+                gitea_token_encrypted: encrypt_token("first-token", "test-encryption-key"),
+                claim_code: "claim".to_string(),
+            })
+            .await
+            .unwrap()
+            .id;
+        let reads = repo.token_reads.clone();
+        let gitea =
+            MockGiteaClient::new().with_access_token(&agent.gitea_username, GITEA_TOKEN_NAME);
+        let tokens_deleted = gitea.tokens_deleted.clone();
+        let service = create_service(repo, gitea).with_token_cache(Duration::from_secs(60), 10);
+
+        let first = service.get_gitea_token(&id).await.unwrap();
+        let second = service.get_gitea_token(&id).await.unwrap();
+
+        assert_eq!(first.as_deref(), Some("first-token"));
+        assert_eq!(second, first);
+        assert_eq!(*reads.read().unwrap(), 1);
+
+        let registered = service.find_by_id(&id).await.unwrap().unwrap();
+        let rotated = service.rotate_gitea_token(&registered).await.unwrap();
+        let after = service.get_gitea_token(&id).await.unwrap();
+
+        assert_eq!(after, Some(rotated));
+        assert_ne!(after, first);
+        assert_eq!(*reads.read().unwrap(), 2);
+        assert_eq!(tokens_deleted.read().unwrap().len(), 1);
+    }
+
     #[test]
     fn test_api_key_generation() {
         let key = generate_api_key();
@@ -269,6 +886,56 @@ mod tests {
         assert_eq!(claim_code.len(), 64); // 32 bytes hex encoded
     }
 
+    #[tokio::test]
+    async fn register_replaces_token_left_by_earlier_attempt() {
+        let gitea = MockGiteaClient::new().with_access_token("agent-retry", GITEA_TOKEN_NAME);
+        let tokens_deleted = gitea.tokens_deleted.clone();
+        let service = create_service(InMemoryAgentRepository::new(), gitea);
+
+        let (agent, _, gitea_token, _) = service.register("retry").await.unwrap();
+
+        assert_eq!(agent.gitea_username, "agent-retry");
+        assert!(!gitea_token.is_empty());
+        assert_eq!(
+            *tokens_deleted.read().unwrap(),
+            vec![("agent-retry".to_string(), GITEA_TOKEN_NAME.to_string())]
+        );
+    }
+
+    #[tokio::test]
+    async fn register_retry_reuses_gitea_user_from_failed_attempt() {
+        // The first attempt created the user and its token, then failed
+        // before the agent was stored
+        let gitea = MockGiteaClient::new()
+            .with_user_email("agent-retry", "agent-retry@agents.synstack.local")
+            .with_access_token("agent-retry", GITEA_TOKEN_NAME);
+        let users_created = gitea.users_created.clone();
+        let passwords_set = gitea.passwords_set.clone();
+        let tokens_deleted = gitea.tokens_deleted.clone();
+        let service = create_service(InMemoryAgentRepository::new(), gitea);
+
+        let (agent, _, gitea_token, _) = service.register("retry").await.unwrap();
+
+        assert_eq!(agent.gitea_username, "agent-retry");
+        assert!(!gitea_token.is_empty());
+        assert!(users_created.read().unwrap().is_empty());
+        assert_eq!(*passwords_set.read().unwrap(), vec!["agent-retry"]);
+        assert_eq!(tokens_deleted.read().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn token_name_collision_is_detected_from_gitea_message() {
+        assert!(is_token_name_collision(&GiteaError::Api {
+            status: 400,
+            message: "access token name has been used already".to_string(),
+        }));
+        assert!(!is_token_name_collision(&GiteaError::Api {
+            status: 400,
+            message: "invalid scope".to_string(),
+        }));
+        assert!(!is_token_name_collision(&GiteaError::Unauthorized));
+    }
+
     #[tokio::test]
     async fn register_fails_with_empty_name() {
         let service = create_service(InMemoryAgentRepository::new(), MockGiteaClient::new());
@@ -307,6 +974,65 @@ mod tests {
         assert!(err.contains("already exists"));
     }
 
+    #[tokio::test]
+    async fn register_fails_with_blocked_name() {
+        let service = create_service(InMemoryAgentRepository::new(), MockGiteaClient::new());
+
+        let result = service.register("Admin").await;
+
+        assert!(matches!(
+            result,
+            Err(AppError::Domain(DomainError::Validation(msg))) if msg.contains("not allowed")
+        ));
+    }
+
+    #[tokio::test]
+    async fn register_fails_with_reserved_prefix() {
+        let service = create_service(InMemoryAgentRepository::new(), MockGiteaClient::new());
+
+        let result = service.register("synstack-helper").await;
+
+        assert!(matches!(
+            result,
+            Err(AppError::Domain(DomainError::Validation(msg))) if msg.contains("reserved")
+        ));
+    }
+
+    #[tokio::test]
+    async fn register_respects_custom_name_policy() {
+        let service = create_service(InMemoryAgentRepository::new(), MockGiteaClient::new())
+            .with_name_policy(NamePolicy::from_settings(Some("crab"), Some("")));
+
+        assert!(service.register("Crab").await.is_err());
+        assert!(service.register("synstack-helper").await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn register_fails_when_name_matches_foreign_gitea_user() {
+        let gitea = MockGiteaClient::new().with_user("jviguy");
+        let service = create_service(InMemoryAgentRepository::new(), gitea);
+
+        let result = service.register("jviguy").await;
+
+        assert!(matches!(
+            result,
+            Err(AppError::Domain(DomainError::Validation(msg))) if msg.contains("Gitea user")
+        ));
+    }
+
+    #[tokio::test]
+    async fn register_fails_when_gitea_username_taken_outside_platform() {
+        let gitea = MockGiteaClient::new().with_user("agent-squatter");
+        let service = create_service(InMemoryAgentRepository::new(), gitea);
+
+        let result = service.register("squatter").await;
+
+        assert!(matches!(
+            result,
+            Err(AppError::Domain(DomainError::Validation(_)))
+        ));
+    }
+
     #[tokio::test]
     async fn register_fails_when_gitea_fails() {
         let service = create_service(InMemoryAgentRepository::new(), MockGiteaClient::failing());
@@ -354,4 +1080,364 @@ mod tests {
 
         assert!(result.is_ok());
     }
+
+    #[tokio::test]
+    async fn tier_distribution_counts_agents_per_tier() {
+        let repo = InMemoryAgentRepository::new()
+            .with_agent(test_agent_with_elo(900))
+            .with_agent(test_agent_with_elo(1199))
+            .with_agent(test_agent_with_elo(1200))
+            .with_agent(test_agent_with_elo(1450))
+            .with_agent(test_agent_with_elo(1599))
+            .with_agent(test_agent_with_elo(1600));
+        let service = create_service(repo, MockGiteaClient::new());
+
+        let distribution = service.get_tier_distribution().await.unwrap();
+
+        assert_eq!(
+            distribution,
+            vec![(Tier::Bronze, 2), (Tier::Silver, 3), (Tier::Gold, 1)]
+        );
+    }
+
+    #[tokio::test]
+    async fn tier_distribution_includes_empty_tiers() {
+        let service = create_service(InMemoryAgentRepository::new(), MockGiteaClient::new());
+
+        let distribution = service.get_tier_distribution().await.unwrap();
+
+        assert_eq!(
+            distribution,
+            vec![(Tier::Bronze, 0), (Tier::Silver, 0), (Tier::Gold, 0)]
+        );
+    }
+
+    #[tokio::test]
+    async fn merge_agents_moves_history_to_target() {
+        let mut source = test_agent_with_elo(1150);
+        source.name = "duplicate".to_string();
+        source.api_key_hash = "duplicate-key-hash".to_string();
+        let mut target = test_agent_with_elo(1300);
+        target.name = "original".to_string();
+        target.api_key_hash = "original-key-hash".to_string();
+        let project_id = ProjectId::new();
+
+        let agents = Arc::new(
+            InMemoryAgentRepository::new()
+                .with_agent(source.clone())
+                .with_agent(target.clone()),
+        );
+        let contributions = Arc::new(
+            InMemoryCodeContributionRepository::new()
+                .with_contribution(test_code_contribution(source.id, project_id))
+                .with_contribution(test_code_contribution(source.id, project_id))
+                .with_contribution(test_code_contribution(target.id, project_id)),
+        );
+        let elo_events = Arc::new(
+            InMemoryEloEventRepository::new()
+                .with_event(test_elo_event(source.id, EloEventType::PrMerged, 15))
+                .with_event(test_elo_event(target.id, EloEventType::PrMerged, 15)),
+        );
+        let tickets = Arc::new(
+            InMemoryTicketRepository::new()
+                .with_ticket(test_ticket_assigned(project_id, source.id)),
+        );
+        let merger = InMemoryAgentMergeRepository::new(
+            agents.clone(),
+            contributions.clone(),
+            Arc::new(InMemoryAgentReviewRepository::new()),
+            elo_events.clone(),
+            tickets,
+        );
+        let service = AgentService::new(
+            agents.clone(),
+            Arc::new(MockGiteaClient::new()),
+            "test-encryption-key".to_string(),
+        );
+
+        let result = service
+            .merge_agents(&merger, &source.id, &target.id)
+            .await
+            .unwrap();
+
+        assert_eq!(result.moved.contributions, 2);
+        assert_eq!(result.moved.elo_events, 1);
+        assert_eq!(result.moved.tickets, 1);
+        // 1300 + 1150 - 1000 starting ELO
+        assert_eq!(result.target.elo, 1450);
+        assert_eq!(result.target.tier, Tier::Silver);
+        assert_eq!(
+            agents.find_by_id(&target.id).await.unwrap().unwrap().elo,
+            1450
+        );
+
+        assert!(contributions
+            .find_by_agent(&source.id)
+            .await
+            .unwrap()
+            .is_empty());
+        assert_eq!(
+            contributions.find_by_agent(&target.id).await.unwrap().len(),
+            3
+        );
+        assert!(elo_events
+            .find_by_agent(&source.id)
+            .await
+            .unwrap()
+            .is_empty());
+        assert_eq!(elo_events.find_by_agent(&target.id).await.unwrap().len(), 2);
+
+        // The duplicate can no longer authenticate or be merged again
+        assert!(service
+            .find_by_api_key("duplicate-key-hash")
+            .await
+            .unwrap()
+            .is_none());
+        let again = service.merge_agents(&merger, &source.id, &target.id).await;
+        assert!(matches!(
+            again,
+            Err(AppError::Domain(DomainError::Conflict(_)))
+        ));
+    }
+
+    #[tokio::test]
+    async fn merge_agents_moves_memberships_and_drops_reviews_between_them() {
+        let source = test_agent_with_elo(1200);
+        let target = test_agent_with_elo(1300);
+        let other = test_agent();
+        let mut owned = test_project();
+        owned.created_by = Some(source.id);
+        let maintained = test_project();
+
+        let agents = Arc::new(
+            InMemoryAgentRepository::new()
+                .with_agent(source.clone())
+                .with_agent(target.clone()),
+        );
+        let projects = Arc::new(
+            InMemoryProjectRepository::new()
+                .with_project_and_role(owned.clone(), source.id, MemberRole::Owner)
+                .with_project_and_role(owned.clone(), target.id, MemberRole::Contributor)
+                .with_project_and_role(maintained.clone(), source.id, MemberRole::Maintainer),
+        );
+        let reviews = Arc::new(
+            InMemoryAgentReviewRepository::new()
+                .with_review(test_agent_review(
+                    source.id,
+                    target.id,
+                    owned.id,
+                    ReviewVerdict::Approved,
+                ))
+                .with_review(test_agent_review(
+                    target.id,
+                    source.id,
+                    owned.id,
+                    ReviewVerdict::Approved,
+                ))
+                .with_review(test_agent_review(
+                    other.id,
+                    source.id,
+                    owned.id,
+                    ReviewVerdict::Approved,
+                )),
+        );
+        let penalties = Arc::new(InMemoryPendingPenaltyRepository::new());
+        penalties
+            .create(&NewPendingPenalty {
+                agent_id: source.id,
+                project_id: owned.id,
+                gitea_org: "org".to_string(),
+                gitea_repo: "repo".to_string(),
+                pr_number: 7,
+                apply_at: chrono::Utc::now(),
+            })
+            .await
+            .unwrap();
+        let merger = InMemoryAgentMergeRepository::new(
+            agents.clone(),
+            Arc::new(InMemoryCodeContributionRepository::new()),
+            reviews.clone(),
+            Arc::new(InMemoryEloEventRepository::new()),
+            Arc::new(InMemoryTicketRepository::new()),
+        )
+        .with_projects(projects.clone())
+        .with_penalties(penalties.clone());
+        let service = AgentService::new(
+            agents,
+            Arc::new(MockGiteaClient::new()),
+            "test-encryption-key".to_string(),
+        )
+        .with_elo_bounds(EloBounds {
+            ceiling: Some(1400),
+            ..EloBounds::default()
+        });
+
+        let result = service
+            .merge_agents(&merger, &source.id, &target.id)
+            .await
+            .unwrap();
+
+        // 1300 + 1200 - 1000 is held to the configured ceiling
+        assert_eq!(result.target.elo, 1400);
+        assert_eq!(result.moved.memberships, 2);
+        assert_eq!(result.moved.pending_penalties, 1);
+
+        // The target keeps the higher role and takes over the project
+        assert_eq!(
+            projects
+                .get_member_role(&owned.id, &target.id)
+                .await
+                .unwrap(),
+            Some(MemberRole::Owner)
+        );
+        assert_eq!(
+            projects
+                .get_member_role(&maintained.id, &target.id)
+                .await
+                .unwrap(),
+            Some(MemberRole::Maintainer)
+        );
+        assert_eq!(projects.get_members(&owned.id).await.unwrap().len(), 1);
+        assert_eq!(
+            projects
+                .find_by_id(&owned.id)
+                .await
+                .unwrap()
+                .unwrap()
+                .created_by,
+            Some(target.id)
+        );
+        assert!(penalties
+            .get_all_penalties()
+            .iter()
+            .all(|p| p.agent_id == target.id));
+
+        // Only the review from a third agent survives; none are self-reviews
+        let received = reviews.find_by_reviewed(&target.id).await.unwrap();
+        assert_eq!(received.len(), 1);
+        assert_eq!(received[0].reviewer_agent_id, other.id);
+        assert!(reviews
+            .find_by_reviewer(&target.id)
+            .await
+            .unwrap()
+            .is_empty());
+    }
+
+    #[tokio::test]
+    async fn merge_agents_moves_engagements_and_viral_moments() {
+        let source = test_agent();
+        let target = test_agent();
+        let other = test_agent();
+        let engagement = Engagement {
+            id: EngagementId::new(),
+            agent_id: source.id,
+            target_type: TargetType::ViralMoment,
+            target_id: uuid::Uuid::new_v4(),
+            engagement_type: EngagementType::Reaction,
+            reaction: Some(ReactionType::Fire),
+            body: None,
+            gitea_synced: false,
+            gitea_id: None,
+            created_at: chrono::Utc::now(),
+            gitea_org: None,
+            gitea_repo: None,
+            sync_attempts: 0,
+            sync_failed: false,
+        };
+        let moment = |agent_ids: Vec<AgentId>| ViralMoment {
+            id: ViralMomentId::new(),
+            moment_type: MomentType::LiveBattle,
+            title: "Battle".to_string(),
+            subtitle: None,
+            score: 10,
+            agent_ids,
+            reference_type: ReferenceType::PullRequest,
+            reference_id: uuid::Uuid::new_v4(),
+            snapshot: serde_json::json!({}),
+            promoted: false,
+            hidden: false,
+            llm_classified: false,
+            llm_classification: None,
+            created_at: chrono::Utc::now(),
+            snapshot_compacted_at: None,
+        };
+        let with_source = moment(vec![source.id, other.id]);
+        let with_both = moment(vec![target.id, source.id]);
+
+        let agents = Arc::new(
+            InMemoryAgentRepository::new()
+                .with_agent(source.clone())
+                .with_agent(target.clone()),
+        );
+        let engagements = Arc::new(InMemoryEngagementRepository::new().with_engagement(engagement));
+        let moments = Arc::new(
+            InMemoryViralMomentRepository::new()
+                .with_moment(with_source.clone())
+                .with_moment(with_both.clone()),
+        );
+        let merger = InMemoryAgentMergeRepository::new(
+            agents.clone(),
+            Arc::new(InMemoryCodeContributionRepository::new()),
+            Arc::new(InMemoryAgentReviewRepository::new()),
+            Arc::new(InMemoryEloEventRepository::new()),
+            Arc::new(InMemoryTicketRepository::new()),
+        )
+        .with_engagements(engagements.clone())
+        .with_moments(moments.clone());
+        let service = AgentService::new(
+            agents,
+            Arc::new(MockGiteaClient::new()),
+            "test-encryption-key".to_string(),
+        );
+
+        let result = service
+            .merge_agents(&merger, &source.id, &target.id)
+            .await
+            .unwrap();
+
+        assert_eq!(result.moved.engagements, 1);
+        assert_eq!(result.moved.viral_moments, 2);
+        assert!(engagements
+            .find_by_agent(&source.id, 10, 0)
+            .await
+            .unwrap()
+            .is_empty());
+        assert_eq!(
+            engagements
+                .find_by_agent(&target.id, 10, 0)
+                .await
+                .unwrap()
+                .len(),
+            1
+        );
+        let agent_ids = |id| {
+            let moments = moments.clone();
+            async move { moments.find_by_id(&id).await.unwrap().unwrap().agent_ids }
+        };
+        assert_eq!(agent_ids(with_source.id).await, vec![target.id, other.id]);
+        // A moment listing both agents names the target once
+        assert_eq!(agent_ids(with_both.id).await, vec![target.id]);
+    }
+
+    #[tokio::test]
+    async fn merge_agents_rejects_merging_into_itself() {
+        let agent = test_agent();
+        let agents = Arc::new(InMemoryAgentRepository::new().with_agent(agent.clone()));
+        let merger = InMemoryAgentMergeRepository::new(
+            agents.clone(),
+            Arc::new(InMemoryCodeContributionRepository::new()),
+            Arc::new(InMemoryAgentReviewRepository::new()),
+            Arc::new(InMemoryEloEventRepository::new()),
+            Arc::new(InMemoryTicketRepository::new()),
+        );
+        let service = AgentService::new(
+            agents,
+            Arc::new(MockGiteaClient::new()),
+            "test-encryption-key".to_string(),
+        );
+
+        let result = service.merge_agents(&merger, &agent.id, &agent.id).await;
+
+        assert!(matches!(result, Err(AppError::BadRequest(_))));
+    }
 }
@@ -68,9 +68,26 @@ curl -X POST "https://api.synstack.org/tickets/abandon" \
 
 use chrono::Utc;
 
-use crate::domain::entities::{Agent, MemberRole, NewProject, Project};
-use crate::domain::ports::{AnalyticsClient, AnalyticsEvent, GiteaClient, ProjectRepository};
-use crate::error::{AppError, DomainError};
+use crate::app::elo_config::{ELO_MULTIPLIER_MAX, ELO_MULTIPLIER_MIN};
+use crate::domain::entities::{
+    Agent, AutoLabelRules, IssueHookAction, IssueHooks, IssueMetadata, IssueState, LanguageAliases,
+    MemberRole, NewProject, NonMemberMergePolicy, Project, ProjectId, ProjectMirror,
+    ProjectVisibility, ReviewEligibility, Succession, MAX_REQUIRED_APPROVALS,
+};
+use crate::domain::ports::{
+    AgentRepository, AnalyticsClient, AnalyticsEvent, BranchProtection, GiteaClient, GiteaLabel,
+    GiteaRelease, GiteaRepo, ProjectRepository, RepoSettings, RepoTemplate,
+};
+use crate::error::{AppError, DomainError, GiteaError};
+
+/// Longest Gitea user/organization name
+const MAX_OWNER_SLUG_LEN: usize = 40;
+
+/// Longest repository name accepted on project creation
+const MAX_REPO_SLUG_LEN: usize = 100;
+
+/// Gitea events registered on imported repositories (matches the webhook handler)
+const IMPORT_WEBHOOK_EVENTS: &[&str] = &["push", "pull_request", "pull_request_review", "issues"];
 
 /// Result of joining a project
 #[derive(Debug)]
@@ -80,6 +97,16 @@ pub struct JoinResult {
     pub message: String,
 }
 
+/// An abandoned leadership role an agent is eligible to claim
+#[derive(Debug)]
+pub struct ClaimableRole {
+    pub project: Project,
+    /// Role the agent can claim (owner or maintainer)
+    pub role: MemberRole,
+    /// Days since the role's current holder was last active
+    pub inactive_days: Option<i64>,
+}
+
 /// Result of creating a project
 #[derive(Debug)]
 pub struct CreateProjectResult {
@@ -87,6 +114,36 @@ pub struct CreateProjectResult {
     pub message: String,
 }
 
+/// Result of importing an existing Gitea repository as a project
+#[derive(Debug)]
+pub struct ImportProjectResult {
+    pub project: Project,
+    /// Gitea webhook ID, if registration succeeded
+    pub webhook_id: Option<i64>,
+    /// Other repositories in the same organization
+    pub sibling_repos: Vec<String>,
+    pub message: String,
+}
+
+/// Result of mirroring a project's external source repository
+#[derive(Debug)]
+pub struct MirrorProjectResult {
+    pub project: Project,
+    pub repo: GiteaRepo,
+    pub message: String,
+}
+
+/// A release to cut on a project's repository
+#[derive(Debug, Clone, Default)]
+pub struct NewRelease {
+    pub tag: String,
+    /// Release title (defaults to the tag)
+    pub name: Option<String>,
+    pub body: Option<String>,
+    pub draft: bool,
+    pub prerelease: bool,
+}
+
 /// Service for Ant Farm operations
 pub struct AntfarmService<PR, GC, AC>
 where
@@ -97,6 +154,11 @@ where
     projects: Arc<PR>,
     gitea: Arc<GC>,
     analytics: Arc<AC>,
+    auto_labels: AutoLabelRules,
+    issue_hooks: IssueHooks,
+    language_aliases: LanguageAliases,
+    repo_template: Option<RepoTemplate>,
+    non_member_merges: NonMemberMergePolicy,
 }
 
 impl<PR, GC, AC> AntfarmService<PR, GC, AC>
@@ -110,9 +172,46 @@ where
             projects,
             gitea,
             analytics,
+            auto_labels: AutoLabelRules::default(),
+            issue_hooks: IssueHooks::default(),
+            language_aliases: LanguageAliases::default(),
+            repo_template: None,
+            non_member_merges: NonMemberMergePolicy::default(),
         }
     }
 
+    /// Override the metadata-to-label mapping used for generated issues
+    pub fn with_auto_labels(mut self, rules: AutoLabelRules) -> Self {
+        self.auto_labels = rules;
+        self
+    }
+
+    /// Run these actions when issues are closed or reopened
+    pub fn with_issue_hooks(mut self, hooks: IssueHooks) -> Self {
+        self.issue_hooks = hooks;
+        self
+    }
+
+    /// Override the alternate language spellings folded together when
+    /// filtering and counting projects by language
+    pub fn with_language_aliases(mut self, aliases: LanguageAliases) -> Self {
+        self.language_aliases = aliases;
+        self
+    }
+
+    /// Generate new project repos from this template instead of creating
+    /// them empty
+    pub fn with_repo_template(mut self, template: Option<RepoTemplate>) -> Self {
+        self.repo_template = template;
+        self
+    }
+
+    /// Set what happens when a merged PR's author isn't a project member
+    pub fn with_non_member_merges(mut self, policy: NonMemberMergePolicy) -> Self {
+        self.non_member_merges = policy;
+        self
+    }
+
     /// Create a new Ant Farm project
     ///
     /// Supports three modes:
@@ -142,21 +241,14 @@ where
             ));
         }
 
-        // Validate repo name
-        if repo_name.is_empty() || repo_name.len() > 100 {
-            return Err(AppError::BadRequest(
-                "Repository name must be between 1 and 100 characters".to_string(),
-            ));
-        }
-        if !repo_name
-            .chars()
-            .all(|c| c.is_alphanumeric() || c == '-' || c == '_' || c == '.')
-        {
-            return Err(AppError::BadRequest(
-                "Repository name can only contain letters, numbers, hyphens, underscores, and dots"
-                    .to_string(),
-            ));
-        }
+        // Normalize the Gitea owner and repo so invalid slugs fail here
+        // rather than as opaque Gitea errors
+        let repo_name = &normalize_slug("Repository", repo_name, MAX_REPO_SLUG_LEN, true)?;
+        let own_username = agent.gitea_username.to_lowercase();
+        let gitea_owner = &match owner {
+            Some(owner) => normalize_slug("Owner", owner, MAX_OWNER_SLUG_LEN, false)?,
+            None => own_username.clone(),
+        };
 
         // Check if project name is taken
         if self.projects.find_by_name(name).await?.is_some() {
@@ -166,24 +258,45 @@ where
             ))));
         }
 
-        // Determine the owner (org or user)
-        let gitea_owner = owner.unwrap_or(&agent.gitea_username);
-
-        // Validate owner name
-        if !gitea_owner
-            .chars()
-            .all(|c| c.is_alphanumeric() || c == '-' || c == '_')
+        // Check if another project already uses this repository
+        if self
+            .projects
+            .find_by_gitea_repo(gitea_owner, repo_name)
+            .await?
+            .is_some()
         {
-            return Err(AppError::BadRequest(
-                "Owner name can only contain letters, numbers, hyphens, and underscores"
-                    .to_string(),
-            ));
+            return Err(AppError::Domain(DomainError::AlreadyExists(format!(
+                "A project for {}/{} already exists",
+                gitea_owner, repo_name
+            ))));
         }
 
         // Determine if this is personal repo or org repo
-        let is_personal = owner.is_none() || gitea_owner == agent.gitea_username;
+        let is_personal = *gitea_owner == own_username;
+
+        if !is_personal {
+            self.ensure_org(agent, gitea_owner, description, create_org)
+                .await?;
+        }
 
-        let repo = if is_personal {
+        let repo = if let Some(template) = &self.repo_template {
+            // Generated with the admin token, which may create repos in any namespace
+            self.gitea
+                .create_repo_from_template(
+                    &template.owner,
+                    &template.repo,
+                    gitea_owner,
+                    repo_name,
+                    description,
+                )
+                .await
+                .map_err(|e| {
+                    AppError::Internal(format!(
+                        "Failed to create repo from template {}/{}: {}",
+                        template.owner, template.repo, e
+                    ))
+                })?
+        } else if is_personal {
             // Create repo in agent's personal namespace
             let token = agent_token.ok_or_else(|| {
                 AppError::BadRequest("Agent token required for personal repo creation".to_string())
@@ -201,51 +314,6 @@ where
                 .await
                 .map_err(|e| AppError::Internal(format!("Failed to create repo: {}", e)))?
         } else {
-            // Creating in an organization
-            // Check if org exists
-            let org_exists = self.gitea.get_org(gitea_owner).await.is_ok();
-
-            if !org_exists {
-                if create_org {
-                    // Create the org first
-                    self.gitea
-                        .create_org(gitea_owner, description)
-                        .await
-                        .map_err(|e| {
-                            AppError::Internal(format!("Failed to create organization: {}", e))
-                        })?;
-
-                    // Add agent as owner
-                    self.gitea
-                        .add_org_owner(gitea_owner, &agent.gitea_username)
-                        .await
-                        .map_err(|e| {
-                            AppError::Internal(format!("Failed to add agent as org owner: {}", e))
-                        })?;
-                } else {
-                    return Err(AppError::NotFound(format!(
-                        "Organization '{}' not found. Set create_org=true to create it.",
-                        gitea_owner
-                    )));
-                }
-            } else {
-                // Org exists - verify agent has access
-                let is_owner = self
-                    .gitea
-                    .is_org_owner(gitea_owner, &agent.gitea_username)
-                    .await
-                    .map_err(|e| {
-                        AppError::Internal(format!("Failed to check org ownership: {}", e))
-                    })?;
-
-                if !is_owner {
-                    return Err(AppError::Domain(DomainError::Forbidden(format!(
-                        "You don't have permission to create repos in organization '{}'",
-                        gitea_owner
-                    ))));
-                }
-            }
-
             // Create the repo in the org
             self.gitea
                 .create_org_repo(gitea_owner, repo_name, description, false, false)
@@ -293,7 +361,7 @@ where
             .await?;
 
         // Update contributor count
-        self.projects.update_stats(&project.id, 1, 0).await?;
+        self.projects.set_contributor_count(&project.id, 1).await?;
 
         let message = format!(
             "Project '{}' created successfully!\n\nGitea repository: {}\nClone URL: {}",
@@ -303,422 +371,2636 @@ where
         Ok(CreateProjectResult { project, message })
     }
 
-    /// Create a new organization for the agent
-    pub async fn create_org(
+    /// Make sure `org` exists and the agent may create repos in it, creating
+    /// the org (with the agent as owner) when `create_org` is set
+    async fn ensure_org(
         &self,
         agent: &Agent,
-        name: &str,
+        org: &str,
         description: Option<&str>,
-    ) -> Result<String, AppError> {
-        // Validate name
-        if name.is_empty() || name.len() > 100 {
-            return Err(AppError::BadRequest(
-                "Organization name must be between 1 and 100 characters".to_string(),
-            ));
-        }
-        if !name
-            .chars()
-            .all(|c| c.is_alphanumeric() || c == '-' || c == '_')
-        {
-            return Err(AppError::BadRequest(
-                "Organization name can only contain letters, numbers, hyphens, and underscores"
-                    .to_string(),
-            ));
-        }
+        create_org: bool,
+    ) -> Result<(), AppError> {
+        let org_exists = self.gitea.get_org(org).await.is_ok();
+
+        if !org_exists {
+            if create_org {
+                // Create the org first
+                self.gitea.create_org(org, description).await.map_err(|e| {
+                    AppError::Internal(format!("Failed to create organization: {}", e))
+                })?;
+
+                // Add agent as owner
+                self.gitea
+                    .add_org_owner(org, &agent.gitea_username)
+                    .await
+                    .map_err(|e| {
+                        AppError::Internal(format!("Failed to add agent as org owner: {}", e))
+                    })?;
+            } else {
+                return Err(AppError::NotFound(format!(
+                    "Organization '{}' not found. Set create_org=true to create it.",
+                    org
+                )));
+            }
+        } else {
+            // Org exists - verify agent has access
+            let is_owner = self
+                .gitea
+                .is_org_owner(org, &agent.gitea_username)
+                .await
+                .map_err(|e| AppError::Internal(format!("Failed to check org ownership: {}", e)))?;
 
-        // Check if org already exists
-        if self.gitea.get_org(name).await.is_ok() {
-            return Err(AppError::Domain(DomainError::AlreadyExists(format!(
-                "Organization '{}' already exists",
-                name
-            ))));
+            if !is_owner {
+                return Err(AppError::Domain(DomainError::Forbidden(format!(
+                    "You don't have permission to create repos in organization '{}'",
+                    org
+                ))));
+            }
         }
 
-        // Create the org
-        let org = self
-            .gitea
-            .create_org(name, description)
-            .await
-            .map_err(|e| AppError::Internal(format!("Failed to create organization: {}", e)))?;
-
-        // Add agent as owner
-        self.gitea
-            .add_org_owner(name, &agent.gitea_username)
-            .await
-            .map_err(|e| AppError::Internal(format!("Failed to add agent as org owner: {}", e)))?;
-
-        Ok(format!("Organization '{}' created successfully!", org.name))
+        Ok(())
     }
 
-    /// List organizations the agent owns
-    pub async fn list_my_orgs(&self, agent: &Agent) -> Result<Vec<String>, AppError> {
-        let orgs = self
+    /// Mirror a project's merge settings onto its default branch's Gitea
+    /// protection, or remove the protection when the project has it off
+    async fn sync_branch_protection(&self, project: &Project) -> Result<(), AppError> {
+        let (owner, repo) = (&project.gitea_org, &project.gitea_repo);
+        let branch = self
             .gitea
-            .list_user_orgs(&agent.gitea_username)
+            .get_repo(owner, repo)
             .await
-            .map_err(|e| AppError::Internal(format!("Failed to list organizations: {}", e)))?;
+            .map_err(|e| AppError::Internal(format!("Failed to look up repository: {}", e)))?
+            .default_branch;
 
-        Ok(orgs.into_iter().map(|o| o.name).collect())
+        let result = if project.protect_default_branch {
+            self.gitea
+                .set_branch_protection(
+                    owner,
+                    repo,
+                    &branch,
+                    &BranchProtection::for_project(project),
+                )
+                .await
+        } else {
+            self.gitea
+                .remove_branch_protection(owner, repo, &branch)
+                .await
+        };
+        result.map_err(|e| {
+            AppError::Internal(format!(
+                "Failed to update protection of {} on {}/{}: {}",
+                branch, owner, repo, e
+            ))
+        })
     }
 
-    /// Join an existing project
-    pub async fn join_project(
+    /// Import an existing Gitea repository as an Ant Farm project
+    ///
+    /// The importing agent becomes the project owner and must own the repository
+    /// (personal repo) or the organization. A webhook pointing at `webhook_url` is
+    /// registered so merges, reviews, and issues flow back into SynStack.
+    /// With `protect_default_branch`, the project's merge settings are mirrored
+    /// onto the default branch's protection; otherwise it is left alone.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn import_project(
         &self,
         agent: &Agent,
-        project: &Project,
-    ) -> Result<JoinResult, AppError> {
-        // Check if project is joinable
-        if !project.is_joinable() {
-            return Err(AppError::Domain(DomainError::Conflict(format!(
-                "Project '{}' is not accepting new contributors (status: {})",
-                project.name, project.status
+        gitea_org: &str,
+        gitea_repo: &str,
+        name: Option<&str>,
+        language: Option<&str>,
+        webhook_url: &str,
+        webhook_secret: Option<&str>,
+        protect_default_branch: bool,
+    ) -> Result<ImportProjectResult, AppError> {
+        // Reject if already imported
+        if let Some(existing) = self
+            .projects
+            .find_by_gitea_repo(gitea_org, gitea_repo)
+            .await?
+        {
+            return Err(AppError::Domain(DomainError::AlreadyExists(format!(
+                "{}/{} is already imported as project '{}'",
+                gitea_org, gitea_repo, existing.name
             ))));
         }
 
-        // Check if already a member
-        if self.projects.is_member(&project.id, &agent.id).await? {
+        // Verify the repository exists
+        let repo = self
+            .gitea
+            .get_repo(gitea_org, gitea_repo)
+            .await
+            .map_err(|e| match e {
+                GiteaError::RepoNotFound { .. } => {
+                    AppError::NotFound(format!("Repository {}/{} not found", gitea_org, gitea_repo))
+                }
+                e => AppError::Internal(format!("Failed to look up repository: {}", e)),
+            })?;
+
+        // Only the repo owner (or an owner of the org) may import it
+        if gitea_org != agent.gitea_username {
+            let is_owner = self
+                .gitea
+                .is_org_owner(gitea_org, &agent.gitea_username)
+                .await
+                .map_err(|e| AppError::Internal(format!("Failed to check org ownership: {}", e)))?;
+
+            if !is_owner {
+                return Err(AppError::Domain(DomainError::Forbidden(format!(
+                    "You don't have permission to import repos from '{}'",
+                    gitea_org
+                ))));
+            }
+        }
+
+        let name = name.unwrap_or(&repo.name);
+        if name.is_empty() || name.len() > 100 {
+            return Err(AppError::BadRequest(
+                "Project name must be between 1 and 100 characters".to_string(),
+            ));
+        }
+        if self.projects.find_by_name(name).await?.is_some() {
             return Err(AppError::Domain(DomainError::AlreadyExists(format!(
-                "You are already a member of '{}'",
-                project.name
+                "Project '{}' already exists",
+                name
             ))));
         }
 
-        // Add to Gitea as collaborator
-        self.gitea
-            .add_collaborator(
-                &project.gitea_org,
-                &project.gitea_repo,
-                &agent.gitea_username,
-                "write",
-            )
-            .await
-            .map_err(|e| AppError::Internal(format!("Failed to add collaborator: {}", e)))?;
+        // Create project record
+        let new_project = NewProject {
+            name: name.to_string(),
+            description: repo.description.clone(),
+            gitea_org: gitea_org.to_string(),
+            gitea_repo: gitea_repo.to_string(),
+            language: language.map(String::from),
+            created_by: Some(agent.id),
+        };
 
-        // Add membership
-        let member = self
-            .projects
-            .add_member(&project.id, &agent.id, MemberRole::Contributor)
-            .await?;
+        let mut project = self.projects.create(&new_project).await?;
 
-        // Update contributor count
-        let members = self.projects.get_members(&project.id).await?;
+        // Add agent as owner
         self.projects
-            .update_stats(&project.id, members.len() as i32, project.open_ticket_count)
+            .add_member(&project.id, &agent.id, MemberRole::Owner)
             .await?;
+        self.projects.set_contributor_count(&project.id, 1).await?;
+
+        // Register webhook (don't fail the import if this fails)
+        let events = IMPORT_WEBHOOK_EVENTS
+            .iter()
+            .map(|e| e.to_string())
+            .collect();
+        let webhook_id = match self
+            .gitea
+            .create_webhook(gitea_org, gitea_repo, webhook_url, events, webhook_secret)
+            .await
+        {
+            Ok(id) => Some(id),
+            Err(e) => {
+                tracing::warn!(
+                    "Failed to register webhook on {}/{}: {}",
+                    gitea_org,
+                    gitea_repo,
+                    e
+                );
+                None
+            }
+        };
 
-        // Track analytics (non-blocking, log errors)
+        // Enforce the settings projects rely on (don't fail the import if this fails)
         if let Err(e) = self
-            .analytics
-            .track(AnalyticsEvent::ProjectJoined {
-                agent_id: agent.id,
-                project_id: project.id,
-                timestamp: Utc::now(),
-            })
+            .gitea
+            .update_repo(gitea_org, gitea_repo, &RepoSettings::standard())
             .await
         {
-            tracing::warn!(error = %e, "Failed to track project joined analytics");
+            tracing::warn!(
+                "Failed to apply standard settings to {}/{}: {}",
+                gitea_org,
+                gitea_repo,
+                e
+            );
         }
 
-        let message = format!(
-            "Welcome to '{}'!\n\nYou are now a {} of this project.\nClone the repository to get started:\n  git clone {}@gitea:{}/{}.git",
-            project.name,
-            member.role,
-            agent.gitea_username,
-            project.gitea_org,
-            project.gitea_repo
+        // Protection is opt-in (don't fail the import if this fails)
+        if protect_default_branch {
+            let protected = Project {
+                protect_default_branch: true,
+                ..project.clone()
+            };
+            match self.sync_branch_protection(&protected).await {
+                Ok(()) => {
+                    self.projects
+                        .set_protect_default_branch(&project.id, true)
+                        .await?;
+                    project = protected;
+                }
+                Err(e) => tracing::warn!("Failed to protect {}/{}: {}", gitea_org, gitea_repo, e),
+            }
+        }
+
+        // Personal namespaces aren't orgs, so there is nothing else to scope
+        let sibling_repos = if gitea_org != agent.gitea_username {
+            match self.gitea.get_org_repos(gitea_org).await {
+                Ok(repos) => repos
+                    .into_iter()
+                    .map(|r| r.name)
+                    .filter(|n| n != gitea_repo)
+                    .collect(),
+                Err(e) => {
+                    tracing::warn!("Failed to list repos in {}: {}", gitea_org, e);
+                    Vec::new()
+                }
+            }
+        } else {
+            Vec::new()
+        };
+
+        let mut message = format!(
+            "Imported {} as project '{}'.\n\nClone URL: {}",
+            repo.full_name, project.name, repo.clone_url
         );
+        if !sibling_repos.is_empty() {
+            message.push_str(&format!(
+                "\n\nOther repos in {}: {}",
+                gitea_org,
+                sibling_repos.join(", ")
+            ));
+        }
 
-        Ok(JoinResult {
-            project: project.clone(),
-            role: member.role,
+        Ok(ImportProjectResult {
+            project,
+            webhook_id,
+            sibling_repos,
             message,
         })
     }
 
-    /// Get project by ID
-    pub async fn get_project(
+    /// Create a read-only Gitea mirror of a project's external source repository
+    ///
+    /// The mirror lives in the project's org (default name `<repo>-mirror`) and is
+    /// recorded on the project. Only owners and maintainers may create it.
+    pub async fn mirror_project(
         &self,
-        id: &crate::domain::entities::ProjectId,
-    ) -> Result<Option<Project>, AppError> {
-        Ok(self.projects.find_by_id(id).await?)
-    }
+        agent: &Agent,
+        project: &Project,
+        source_url: &str,
+        name: Option<&str>,
+    ) -> Result<MirrorProjectResult, AppError> {
+        let role = self
+            .projects
+            .get_member_role(&project.id, &agent.id)
+            .await?;
+        if !matches!(role, Some(MemberRole::Owner) | Some(MemberRole::Maintainer)) {
+            return Err(AppError::Domain(DomainError::Forbidden(
+                "Only project owners and maintainers can create mirrors".to_string(),
+            )));
+        }
 
-    /// Get projects an agent is a member of
-    pub async fn get_my_projects(&self, agent: &Agent) -> Result<Vec<Project>, AppError> {
-        Ok(self.projects.find_by_agent(&agent.id).await?)
-    }
+        if let Some(existing) = &project.mirror {
+            return Err(AppError::Domain(DomainError::Conflict(format!(
+                "Project '{}' is already mirrored from {}",
+                project.name, existing.source_url
+            ))));
+        }
 
-    /// List active projects
-    pub async fn list_active_projects(
-        &self,
-        limit: i64,
-        offset: i64,
+        if !(source_url.starts_with("https://") || source_url.starts_with("http://")) {
+            return Err(AppError::BadRequest(
+                "Mirror source must be an http(s) clone URL".to_string(),
+            ));
+        }
+
+        let default_name = format!("{}-mirror", project.gitea_repo);
+        let name = name.unwrap_or(&default_name);
+
+        let repo = self
+            .gitea
+            .create_mirror_repo(source_url, &project.gitea_org, name)
+            .await
+            .map_err(|e| AppError::Internal(format!("Failed to create mirror: {}", e)))?;
+
+        let mirror = ProjectMirror {
+            source_url: source_url.to_string(),
+            repo: repo.name.clone(),
+            mirrored_at: Utc::now(),
+        };
+        self.projects.set_mirror(&project.id, &mirror).await?;
+
+        let project = Project {
+            mirror: Some(mirror),
+            ..project.clone()
+        };
+        let message = format!(
+            "Mirroring {} into {}.\n\nClone URL: {}",
+            source_url, repo.full_name, repo.clone_url
+        );
+
+        Ok(MirrorProjectResult {
+            project,
+            repo,
+            message,
+        })
+    }
+
+    /// Cut a release of the project's repository
+    ///
+    /// Only owners and maintainers may release. The tag must be new: releasing
+    /// an existing tag is rejected rather than attaching to old history.
+    pub async fn create_release(
+        &self,
+        agent: &Agent,
+        project: &Project,
+        release: &NewRelease,
+    ) -> Result<GiteaRelease, AppError> {
+        let role = self
+            .projects
+            .get_member_role(&project.id, &agent.id)
+            .await?;
+        if !matches!(role, Some(MemberRole::Owner) | Some(MemberRole::Maintainer)) {
+            return Err(AppError::Domain(DomainError::Forbidden(
+                "Only project owners and maintainers can create releases".to_string(),
+            )));
+        }
+
+        let tag = release.tag.trim();
+        if tag.is_empty() || tag.chars().any(char::is_whitespace) {
+            return Err(AppError::BadRequest(
+                "Release tag must be non-empty and contain no whitespace".to_string(),
+            ));
+        }
+
+        if self
+            .gitea
+            .tag_exists(&project.gitea_org, &project.gitea_repo, tag)
+            .await?
+        {
+            return Err(AppError::Domain(DomainError::AlreadyExists(format!(
+                "Tag '{}' already exists in {}/{}",
+                tag, project.gitea_org, project.gitea_repo
+            ))));
+        }
+
+        let name = release
+            .name
+            .as_deref()
+            .map(str::trim)
+            .filter(|n| !n.is_empty())
+            .unwrap_or(tag);
+
+        Ok(self
+            .gitea
+            .create_release(
+                &project.gitea_org,
+                &project.gitea_repo,
+                tag,
+                name,
+                release.body.as_deref(),
+                release.draft,
+                release.prerelease,
+            )
+            .await?)
+    }
+
+    /// Label a freshly generated issue from its metadata
+    ///
+    /// Labels missing from the repository are created first so Gitea accepts
+    /// them. Returns the issue's labels after labeling (empty when no rule matched).
+    pub async fn auto_label_issue(
+        &self,
+        project: &Project,
+        number: i64,
+        metadata: &IssueMetadata,
+    ) -> Result<Vec<GiteaLabel>, AppError> {
+        let labels = self.auto_labels.labels_for(metadata);
+        if labels.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        self.ensure_repo_labels(project, &labels).await?;
+
+        self.gitea
+            .add_issue_labels(
+                &project.gitea_org,
+                &project.gitea_repo,
+                number,
+                labels,
+                None,
+            )
+            .await
+            .map_err(|e| AppError::Internal(format!("Failed to label issue: {}", e)))
+    }
+
+    /// Create any of `labels` the project's repository doesn't have yet
+    async fn ensure_repo_labels(
+        &self,
+        project: &Project,
+        labels: &[String],
+    ) -> Result<(), AppError> {
+        let existing = self
+            .gitea
+            .list_repo_labels(&project.gitea_org, &project.gitea_repo)
+            .await
+            .map_err(|e| AppError::Internal(format!("Failed to list labels: {}", e)))?;
+        for label in labels {
+            if !existing.iter().any(|l| l.name.eq_ignore_ascii_case(label)) {
+                self.gitea
+                    .create_repo_label(
+                        &project.gitea_org,
+                        &project.gitea_repo,
+                        label,
+                        auto_label_color(label),
+                    )
+                    .await
+                    .map_err(|e| {
+                        AppError::Internal(format!("Failed to create label '{}': {}", label, e))
+                    })?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Run the configured hooks for an issue that just moved into `state`
+    ///
+    /// Actions run in configuration order as the server account and stop at
+    /// the first failure. Returns how many actions ran.
+    pub async fn run_issue_hooks(
+        &self,
+        project: &Project,
+        number: i64,
+        state: IssueState,
+    ) -> Result<usize, AppError> {
+        let mut ran = 0;
+        for action in self.issue_hooks.actions_for(state) {
+            match action {
+                IssueHookAction::Comment(body) => {
+                    self.gitea
+                        .create_issue_comment(
+                            &project.gitea_org,
+                            &project.gitea_repo,
+                            number,
+                            body,
+                            None,
+                        )
+                        .await
+                        .map_err(|e| {
+                            AppError::Internal(format!("Failed to post hook comment: {}", e))
+                        })?;
+                }
+                IssueHookAction::Label(label) => {
+                    let labels = vec![label.clone()];
+                    self.ensure_repo_labels(project, &labels).await?;
+                    self.gitea
+                        .add_issue_labels(
+                            &project.gitea_org,
+                            &project.gitea_repo,
+                            number,
+                            labels,
+                            None,
+                        )
+                        .await
+                        .map_err(|e| {
+                            AppError::Internal(format!("Failed to apply hook label: {}", e))
+                        })?;
+                }
+            }
+            ran += 1;
+        }
+        Ok(ran)
+    }
+
+    /// Allow or forbid PRs against branches other than the default branch
+    ///
+    /// Requires owner or maintainer role.
+    pub async fn set_allow_alternate_bases(
+        &self,
+        agent: &Agent,
+        project: &Project,
+        allowed: bool,
+    ) -> Result<Project, AppError> {
+        let role = self
+            .projects
+            .get_member_role(&project.id, &agent.id)
+            .await?;
+        if !matches!(role, Some(MemberRole::Owner) | Some(MemberRole::Maintainer)) {
+            return Err(AppError::Domain(DomainError::Forbidden(
+                "Only project owners and maintainers can change project settings".to_string(),
+            )));
+        }
+
+        self.projects
+            .set_allow_alternate_bases(&project.id, allowed)
+            .await?;
+
+        Ok(Project {
+            allow_alternate_bases: allowed,
+            ..project.clone()
+        })
+    }
+
+    /// Require (or stop requiring) a green combined CI status before merging PRs
+    ///
+    /// Requires owner or maintainer role.
+    pub async fn set_require_green_ci(
+        &self,
+        agent: &Agent,
+        project: &Project,
+        required: bool,
+    ) -> Result<Project, AppError> {
+        let role = self
+            .projects
+            .get_member_role(&project.id, &agent.id)
+            .await?;
+        if !matches!(role, Some(MemberRole::Owner) | Some(MemberRole::Maintainer)) {
+            return Err(AppError::Domain(DomainError::Forbidden(
+                "Only project owners and maintainers can change project settings".to_string(),
+            )));
+        }
+
+        let updated = Project {
+            require_green_ci: required,
+            ..project.clone()
+        };
+        if project.protect_default_branch {
+            self.sync_branch_protection(&updated).await?;
+        }
+        self.projects
+            .set_require_green_ci(&project.id, required)
+            .await?;
+
+        Ok(updated)
+    }
+
+    /// Set how many distinct approving reviews a PR needs before it can be merged
+    ///
+    /// Requires owner or maintainer role.
+    pub async fn set_required_approvals(
+        &self,
+        agent: &Agent,
+        project: &Project,
+        count: i32,
+    ) -> Result<Project, AppError> {
+        let role = self
+            .projects
+            .get_member_role(&project.id, &agent.id)
+            .await?;
+        if !matches!(role, Some(MemberRole::Owner) | Some(MemberRole::Maintainer)) {
+            return Err(AppError::Domain(DomainError::Forbidden(
+                "Only project owners and maintainers can change project settings".to_string(),
+            )));
+        }
+        if !(0..=MAX_REQUIRED_APPROVALS).contains(&count) {
+            return Err(AppError::BadRequest(format!(
+                "required_approvals must be between 0 and {}",
+                MAX_REQUIRED_APPROVALS
+            )));
+        }
+
+        let updated = Project {
+            required_approvals: count,
+            ..project.clone()
+        };
+        if project.protect_default_branch {
+            self.sync_branch_protection(&updated).await?;
+        }
+        self.projects
+            .set_required_approvals(&project.id, count)
+            .await?;
+
+        Ok(updated)
+    }
+
+    /// Mirror the project's merge settings onto its default branch's Gitea
+    /// protection, or stop doing so and remove the protection
+    ///
+    /// Requires owner or maintainer role.
+    pub async fn set_protect_default_branch(
+        &self,
+        agent: &Agent,
+        project: &Project,
+        enabled: bool,
+    ) -> Result<Project, AppError> {
+        let role = self
+            .projects
+            .get_member_role(&project.id, &agent.id)
+            .await?;
+        if !matches!(role, Some(MemberRole::Owner) | Some(MemberRole::Maintainer)) {
+            return Err(AppError::Domain(DomainError::Forbidden(
+                "Only project owners and maintainers can change project settings".to_string(),
+            )));
+        }
+
+        let updated = Project {
+            protect_default_branch: enabled,
+            ..project.clone()
+        };
+        self.sync_branch_protection(&updated).await?;
+        self.projects
+            .set_protect_default_branch(&project.id, enabled)
+            .await?;
+
+        Ok(updated)
+    }
+
+    /// Set who can see the project
+    ///
+    /// Requires owner or maintainer role.
+    pub async fn set_visibility(
+        &self,
+        agent: &Agent,
+        project: &Project,
+        visibility: ProjectVisibility,
+    ) -> Result<Project, AppError> {
+        let role = self
+            .projects
+            .get_member_role(&project.id, &agent.id)
+            .await?;
+        if !matches!(role, Some(MemberRole::Owner) | Some(MemberRole::Maintainer)) {
+            return Err(AppError::Domain(DomainError::Forbidden(
+                "Only project owners and maintainers can change project settings".to_string(),
+            )));
+        }
+
+        self.projects
+            .set_visibility(&project.id, visibility)
+            .await?;
+
+        Ok(Project {
+            visibility,
+            ..project.clone()
+        })
+    }
+
+    /// Set who may peer-review the project's PRs
+    ///
+    /// Requires owner or maintainer role.
+    pub async fn set_review_eligibility(
+        &self,
+        agent: &Agent,
+        project: &Project,
+        eligibility: ReviewEligibility,
+    ) -> Result<Project, AppError> {
+        let role = self
+            .projects
+            .get_member_role(&project.id, &agent.id)
+            .await?;
+        if !matches!(role, Some(MemberRole::Owner) | Some(MemberRole::Maintainer)) {
+            return Err(AppError::Domain(DomainError::Forbidden(
+                "Only project owners and maintainers can change project settings".to_string(),
+            )));
+        }
+
+        self.projects
+            .set_review_eligibility(&project.id, eligibility)
+            .await?;
+
+        Ok(Project {
+            review_eligibility: eligibility,
+            ..project.clone()
+        })
+    }
+
+    /// Create a new organization for the agent
+    pub async fn create_org(
+        &self,
+        agent: &Agent,
+        name: &str,
+        description: Option<&str>,
+    ) -> Result<String, AppError> {
+        // Validate name
+        if name.is_empty() || name.len() > 100 {
+            return Err(AppError::BadRequest(
+                "Organization name must be between 1 and 100 characters".to_string(),
+            ));
+        }
+        if !name
+            .chars()
+            .all(|c| c.is_alphanumeric() || c == '-' || c == '_')
+        {
+            return Err(AppError::BadRequest(
+                "Organization name can only contain letters, numbers, hyphens, and underscores"
+                    .to_string(),
+            ));
+        }
+
+        // Check if org already exists
+        if self.gitea.get_org(name).await.is_ok() {
+            return Err(AppError::Domain(DomainError::AlreadyExists(format!(
+                "Organization '{}' already exists",
+                name
+            ))));
+        }
+
+        // Create the org
+        let org = self
+            .gitea
+            .create_org(name, description)
+            .await
+            .map_err(|e| AppError::Internal(format!("Failed to create organization: {}", e)))?;
+
+        // Add agent as owner
+        self.gitea
+            .add_org_owner(name, &agent.gitea_username)
+            .await
+            .map_err(|e| AppError::Internal(format!("Failed to add agent as org owner: {}", e)))?;
+
+        Ok(format!("Organization '{}' created successfully!", org.name))
+    }
+
+    /// List organizations the agent owns
+    pub async fn list_my_orgs(&self, agent: &Agent) -> Result<Vec<String>, AppError> {
+        let orgs = self
+            .gitea
+            .list_user_orgs(&agent.gitea_username)
+            .await
+            .map_err(|e| AppError::Internal(format!("Failed to list organizations: {}", e)))?;
+
+        Ok(orgs.into_iter().map(|o| o.name).collect())
+    }
+
+    /// List every repository in an organization
+    ///
+    /// Restricted to owners and maintainers of the organization.
+    pub async fn list_org_repos(
+        &self,
+        agent: &Agent,
+        org: &str,
+    ) -> Result<Vec<GiteaRepo>, AppError> {
+        let is_owner = self
+            .gitea
+            .is_org_owner(org, &agent.gitea_username)
+            .await
+            .map_err(|e| match e {
+                GiteaError::OrgNotFound(_) => {
+                    AppError::NotFound(format!("Organization '{}' not found", org))
+                }
+                e => AppError::Internal(format!("Failed to check org ownership: {}", e)),
+            })?;
+
+        if !is_owner {
+            let maintainers =
+                self.gitea.list_maintainers(org).await.map_err(|e| {
+                    AppError::Internal(format!("Failed to list maintainers: {}", e))
+                })?;
+
+            if !maintainers.contains(&agent.gitea_username) {
+                return Err(AppError::Domain(DomainError::Forbidden(format!(
+                    "Only owners and maintainers of '{}' can list its repositories",
+                    org
+                ))));
+            }
+        }
+
+        self.gitea.get_org_repos(org).await.map_err(|e| match e {
+            GiteaError::OrgNotFound(_) => {
+                AppError::NotFound(format!("Organization '{}' not found", org))
+            }
+            e => AppError::Internal(format!("Failed to list organization repos: {}", e)),
+        })
+    }
+
+    /// Whether a merged PR's author may be credited for it. Members always
+    /// are; non-members are skipped or, per policy, join the project the same
+    /// way as through `join_project`. An author the project won't take (it
+    /// isn't active, or it's private and they weren't invited) is skipped.
+    pub async fn admit_merge_author(
+        &self,
+        agent: &Agent,
+        project: &Project,
+    ) -> Result<bool, AppError> {
+        if self.projects.is_member(&project.id, &agent.id).await? {
+            return Ok(true);
+        }
+
+        match self.non_member_merges {
+            NonMemberMergePolicy::Skip => Ok(false),
+            NonMemberMergePolicy::Enroll => match self.join_project(agent, project).await {
+                Ok(_) => Ok(true),
+                Err(AppError::Domain(DomainError::Conflict(_) | DomainError::Forbidden(_))) => {
+                    Ok(false)
+                }
+                Err(e) => Err(e),
+            },
+        }
+    }
+
+    /// Join an existing project
+    pub async fn join_project(
+        &self,
+        agent: &Agent,
+        project: &Project,
+    ) -> Result<JoinResult, AppError> {
+        // Check if project is joinable
+        if !project.is_joinable() {
+            return Err(AppError::Domain(DomainError::Conflict(format!(
+                "Project '{}' is not accepting new contributors (status: {})",
+                project.name, project.status
+            ))));
+        }
+
+        // Check if already a member
+        if self.projects.is_member(&project.id, &agent.id).await? {
+            return Err(AppError::Domain(DomainError::AlreadyExists(format!(
+                "You are already a member of '{}'",
+                project.name
+            ))));
+        }
+
+        // Private projects only take agents who were invited
+        if project.visibility == ProjectVisibility::Private
+            && !self.projects.is_invited(&project.id, &agent.id).await?
+        {
+            return Err(AppError::Domain(DomainError::Forbidden(format!(
+                "'{}' is private. Ask an owner or maintainer for an invite.",
+                project.name
+            ))));
+        }
+
+        // Add to Gitea as collaborator
+        self.gitea
+            .add_collaborator(
+                &project.gitea_org,
+                &project.gitea_repo,
+                &agent.gitea_username,
+                "write",
+            )
+            .await
+            .map_err(|e| AppError::Internal(format!("Failed to add collaborator: {}", e)))?;
+
+        // Add membership
+        let member = self
+            .projects
+            .add_member(&project.id, &agent.id, MemberRole::Contributor)
+            .await?;
+
+        // Update contributor count
+        let members = self.projects.get_members(&project.id).await?;
+        self.projects
+            .set_contributor_count(&project.id, members.len() as i32)
+            .await?;
+
+        // Track analytics (non-blocking, log errors)
+        if let Err(e) = self
+            .analytics
+            .track(AnalyticsEvent::ProjectJoined {
+                agent_id: agent.id,
+                project_id: project.id,
+                timestamp: Utc::now(),
+            })
+            .await
+        {
+            tracing::warn!(error = %e, "Failed to track project joined analytics");
+        }
+
+        let message = format!(
+            "Welcome to '{}'!\n\nYou are now a {} of this project.\nClone the repository to get started:\n  git clone {}@gitea:{}/{}.git",
+            project.name,
+            member.role,
+            agent.gitea_username,
+            project.gitea_org,
+            project.gitea_repo
+        );
+
+        Ok(JoinResult {
+            project: project.clone(),
+            role: member.role,
+            message,
+        })
+    }
+
+    /// Assess a project's succession: which leadership roles are abandoned.
+    ///
+    /// Leaders whose agent record can't be found are skipped.
+    pub async fn get_succession<AR: AgentRepository>(
+        &self,
+        project: &Project,
+        agents: &AR,
+    ) -> Result<Succession, AppError> {
+        let members = self.projects.get_members(&project.id).await?;
+        let mut leaders = Vec::new();
+        for member in members {
+            if member.role == MemberRole::Contributor {
+                continue;
+            }
+            if let Some(leader) = agents.find_by_id(&member.agent_id).await? {
+                leaders.push((
+                    member.role,
+                    leader.last_seen_at.unwrap_or(leader.created_at),
+                ));
+            }
+        }
+        Ok(Succession::assess(leaders, Utc::now()))
+    }
+
+    /// List every abandoned role the agent could claim across its projects.
+    ///
+    /// Applies the same eligibility rules as a single project's succession
+    /// check, so each entry can be claimed through `POST /projects/:id/claim`.
+    pub async fn list_claimable_roles<AR: AgentRepository>(
+        &self,
+        agent: &Agent,
+        agents: &AR,
+    ) -> Result<Vec<ClaimableRole>, AppError> {
+        let mut claimable = Vec::new();
+        for project in self.projects.find_by_agent(&agent.id).await? {
+            let role = self
+                .projects
+                .get_member_role(&project.id, &agent.id)
+                .await?;
+            let succession = self.get_succession(&project, agents).await?;
+            if let Some(claim) = succession.claimable_by(role) {
+                let inactive_days = match claim {
+                    MemberRole::Owner => succession.owner_inactive_days,
+                    _ => succession.maintainer_inactive_days,
+                };
+                claimable.push(ClaimableRole {
+                    project,
+                    role: claim,
+                    inactive_days,
+                });
+            }
+        }
+        Ok(claimable)
+    }
+
+    /// Get project by ID
+    pub async fn get_project(&self, id: &ProjectId) -> Result<Option<Project>, AppError> {
+        Ok(self.projects.find_by_id(id).await?)
+    }
+
+    /// Get a project as `viewer` sees it (None for anonymous requests).
+    ///
+    /// Private projects are hidden from everyone but their members and
+    /// invitees, so they read as not found rather than revealing that they
+    /// exist.
+    pub async fn get_visible_project(
+        &self,
+        id: &ProjectId,
+        viewer: Option<&Agent>,
+    ) -> Result<Option<Project>, AppError> {
+        let Some(project) = self.projects.find_by_id(id).await? else {
+            return Ok(None);
+        };
+        if project.visibility != ProjectVisibility::Private {
+            return Ok(Some(project));
+        }
+        let can_see = match viewer {
+            Some(agent) => {
+                self.projects.is_member(&project.id, &agent.id).await?
+                    || self.projects.is_invited(&project.id, &agent.id).await?
+            }
+            None => false,
+        };
+        Ok(can_see.then_some(project))
+    }
+
+    /// Invite an agent to join a private project
+    ///
+    /// Requires owner or maintainer role.
+    pub async fn invite_member(
+        &self,
+        agent: &Agent,
+        project: &Project,
+        invitee: &Agent,
+    ) -> Result<(), AppError> {
+        let role = self
+            .projects
+            .get_member_role(&project.id, &agent.id)
+            .await?;
+        if !matches!(role, Some(MemberRole::Owner) | Some(MemberRole::Maintainer)) {
+            return Err(AppError::Domain(DomainError::Forbidden(
+                "Only project owners and maintainers can invite agents".to_string(),
+            )));
+        }
+
+        self.projects
+            .add_invite(&project.id, &invitee.id, &agent.id)
+            .await?;
+        Ok(())
+    }
+
+    /// Star a project's repository as the agent the token belongs to
+    ///
+    /// Returns the repository's star count afterwards, which is also stored
+    /// on the project for listings.
+    pub async fn star_project(&self, project: &Project, user_token: &str) -> Result<i64, AppError> {
+        self.gitea
+            .star_repo(&project.gitea_org, &project.gitea_repo, user_token)
+            .await?;
+        let repo = self
+            .gitea
+            .get_repo(&project.gitea_org, &project.gitea_repo)
+            .await?;
+        self.projects
+            .set_star_count(&project.id, repo.stars_count as i32)
+            .await?;
+        Ok(repo.stars_count)
+    }
+
+    /// Get projects an agent is a member of
+    pub async fn get_my_projects(&self, agent: &Agent) -> Result<Vec<Project>, AppError> {
+        Ok(self.projects.find_by_agent(&agent.id).await?)
+    }
+
+    /// Get projects where the agent holds a role.
+    ///
+    /// `Maintainer` also includes projects the agent owns, since owners have
+    /// every maintainer permission.
+    pub async fn get_my_projects_with_role(
+        &self,
+        agent: &Agent,
+        role: MemberRole,
+    ) -> Result<Vec<Project>, AppError> {
+        let mut projects = self
+            .projects
+            .find_by_agent_with_role(&agent.id, role)
+            .await?;
+        if role == MemberRole::Maintainer {
+            projects.extend(
+                self.projects
+                    .find_by_agent_with_role(&agent.id, MemberRole::Owner)
+                    .await?,
+            );
+        }
+        Ok(projects)
+    }
+
+    /// List active projects
+    pub async fn list_active_projects(
+        &self,
+        limit: i64,
+        offset: i64,
     ) -> Result<Vec<Project>, AppError> {
         Ok(self.projects.find_active(limit, offset).await?)
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::domain::entities::ProjectStatus;
-    use crate::test_utils::{
-        test_agent, test_project, test_project_with_status, InMemoryProjectRepository,
-        MockAnalyticsClient, MockGiteaClient,
-    };
+    /// Count all active projects (the total behind `list_active_projects`)
+    pub async fn count_active_projects(&self) -> Result<i64, AppError> {
+        Ok(self.projects.count_active().await?)
+    }
+
+    /// List active projects written in `language` (case-insensitive, with
+    /// aliases folded in)
+    pub async fn list_active_projects_by_language(
+        &self,
+        language: &str,
+        limit: i64,
+        offset: i64,
+    ) -> Result<Vec<Project>, AppError> {
+        let spellings = self.language_aliases.spellings(language);
+        if spellings.is_empty() {
+            return Ok(Vec::new());
+        }
+        Ok(self
+            .projects
+            .find_active_by_language(&spellings, limit, offset)
+            .await?)
+    }
+
+    /// Count active projects written in `language` (the total behind
+    /// `list_active_projects_by_language`)
+    pub async fn count_active_projects_by_language(&self, language: &str) -> Result<i64, AppError> {
+        let spellings = self.language_aliases.spellings(language);
+        if spellings.is_empty() {
+            return Ok(0);
+        }
+        Ok(self.projects.count_active_by_language(&spellings).await?)
+    }
+
+    /// Distinct languages of active projects with their project counts,
+    /// most common first
+    pub async fn language_facets(&self) -> Result<Vec<(String, i64)>, AppError> {
+        let mut facets: Vec<(String, i64)> = Vec::new();
+        for (language, count) in self.projects.count_active_languages().await? {
+            let Some(canonical) = self.language_aliases.canonical(&language) else {
+                continue;
+            };
+            match facets.iter_mut().find(|(l, _)| *l == canonical) {
+                Some((_, total)) => *total += count,
+                None => facets.push((canonical, count)),
+            }
+        }
+        facets.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+        Ok(facets)
+    }
+
+    /// List the operator-curated featured projects, in curation order
+    pub async fn list_featured_projects(&self, limit: i64) -> Result<Vec<Project>, AppError> {
+        Ok(self.projects.find_featured(limit).await?)
+    }
+
+    /// Feature (or unfeature) a project at a curation rank
+    ///
+    /// Callers are responsible for checking operator authorization.
+    pub async fn set_featured(
+        &self,
+        project: &Project,
+        featured: bool,
+        rank: i32,
+    ) -> Result<Project, AppError> {
+        self.projects
+            .set_featured(&project.id, featured, rank)
+            .await?;
+
+        Ok(Project {
+            featured,
+            featured_rank: rank,
+            ..project.clone()
+        })
+    }
+
+    /// Set the multiplier applied to ELO earned on a project, clamped to
+    /// `[ELO_MULTIPLIER_MIN, ELO_MULTIPLIER_MAX]`
+    ///
+    /// Callers are responsible for checking operator authorization.
+    pub async fn set_elo_multiplier(
+        &self,
+        project: &Project,
+        multiplier: f32,
+    ) -> Result<Project, AppError> {
+        if !multiplier.is_finite() {
+            return Err(AppError::BadRequest(
+                "ELO multiplier must be a finite number".to_string(),
+            ));
+        }
+        let multiplier = multiplier.clamp(ELO_MULTIPLIER_MIN, ELO_MULTIPLIER_MAX);
+        self.projects
+            .set_elo_multiplier(&project.id, multiplier)
+            .await?;
+
+        Ok(Project {
+            elo_multiplier: multiplier,
+            ..project.clone()
+        })
+    }
+}
+
+/// Normalize a Gitea owner or repository slug: trimmed and lowercased, made
+/// of ASCII letters, digits, hyphens and underscores (and dots when
+/// `allow_dots`), starting with a letter or digit.
+fn normalize_slug(
+    kind: &str,
+    value: &str,
+    max_len: usize,
+    allow_dots: bool,
+) -> Result<String, AppError> {
+    let slug = value.trim().to_lowercase();
+    if slug.is_empty() || slug.len() > max_len {
+        return Err(AppError::Unprocessable(format!(
+            "{} name must be between 1 and {} characters",
+            kind, max_len
+        )));
+    }
+    let allowed =
+        |c: char| c.is_ascii_alphanumeric() || c == '-' || c == '_' || (allow_dots && c == '.');
+    if !slug.chars().all(allowed) {
+        let allowed_desc = if allow_dots {
+            "letters, numbers, hyphens, underscores, and dots"
+        } else {
+            "letters, numbers, hyphens, and underscores"
+        };
+        return Err(AppError::Unprocessable(format!(
+            "{} name '{}' can only contain {}",
+            kind, value, allowed_desc
+        )));
+    }
+    if !slug.starts_with(|c: char| c.is_ascii_alphanumeric()) {
+        return Err(AppError::Unprocessable(format!(
+            "{} name '{}' must start with a letter or number",
+            kind, value
+        )));
+    }
+    if slug.ends_with(".git") {
+        return Err(AppError::Unprocessable(format!(
+            "{} name '{}' must not end in .git",
+            kind, value
+        )));
+    }
+    Ok(slug)
+}
+
+/// Color for a label created by auto-labeling
+fn auto_label_color(label: &str) -> &'static str {
+    match label {
+        "good-first-issue" => "#7057ff",
+        "bug" => "#d73a4a",
+        "enhancement" => "#a2eeef",
+        "documentation" => "#0075ca",
+        _ => "#ededed",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::entities::ProjectStatus;
+    use crate::test_utils::{
+        test_agent, test_agent_named, test_project, test_project_with_status,
+        InMemoryAgentRepository, InMemoryProjectRepository, MockAnalyticsClient, MockGiteaClient,
+    };
+    use axum::response::IntoResponse;
+
+    fn create_service(
+        project_repo: InMemoryProjectRepository,
+        gitea: MockGiteaClient,
+    ) -> AntfarmService<InMemoryProjectRepository, MockGiteaClient, MockAnalyticsClient> {
+        AntfarmService::new(
+            Arc::new(project_repo),
+            Arc::new(gitea),
+            Arc::new(MockAnalyticsClient::new()),
+        )
+    }
+
+    #[tokio::test]
+    async fn starring_a_project_increments_its_star_count() {
+        let project = test_project();
+        let service = create_service(
+            InMemoryProjectRepository::new().with_project(project.clone()),
+            MockGiteaClient::new(),
+        );
+        // Project detail serves the project as looked up here
+        let detail = || async {
+            service
+                .get_visible_project(&project.id, None)
+                .await
+                .unwrap()
+                .unwrap()
+                .star_count
+        };
+        assert_eq!(detail().await, 0);
+
+        assert_eq!(service.star_project(&project, "token-a").await.unwrap(), 1);
+        assert_eq!(detail().await, 1);
+
+        // A second star from the same agent doesn't count twice
+        assert_eq!(service.star_project(&project, "token-a").await.unwrap(), 1);
+        assert_eq!(service.star_project(&project, "token-b").await.unwrap(), 2);
+        assert_eq!(detail().await, 2);
+    }
+
+    #[tokio::test]
+    async fn create_project_in_personal_namespace() {
+        let agent = test_agent();
+        let service = create_service(InMemoryProjectRepository::new(), MockGiteaClient::new());
+
+        // Create project in personal namespace (no owner specified)
+        let result = service
+            .create_project(
+                &agent,
+                "my-project",
+                Some("A cool project"),
+                Some("rust"),
+                None,                     // personal repo
+                "my-repo",                // repo name
+                false,                    // don't create org
+                Some("mock-agent-token"), // agent token for personal repos
+            )
+            .await;
+
+        assert!(result.is_ok());
+        let result = result.unwrap();
+        assert_eq!(result.project.name, "my-project");
+        assert_eq!(result.project.gitea_org, agent.gitea_username);
+        assert_eq!(result.project.gitea_repo, "my-repo");
+    }
+
+    #[tokio::test]
+    async fn create_project_stores_language_as_given() {
+        let agent = test_agent();
+        let service = create_service(InMemoryProjectRepository::new(), MockGiteaClient::new());
+
+        let result = service
+            .create_project(
+                &agent,
+                "gopher",
+                None,
+                Some("Golang"),
+                None,
+                "gopher",
+                false,
+                Some("mock-agent-token"),
+            )
+            .await
+            .unwrap();
+
+        // Only queries are normalized; the alias still finds it
+        assert_eq!(result.project.language.as_deref(), Some("Golang"));
+        let found = service
+            .list_active_projects_by_language("go", 10, 0)
+            .await
+            .unwrap();
+        assert_eq!(found.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn create_project_in_new_org() {
+        let agent = test_agent();
+        let service = create_service(InMemoryProjectRepository::new(), MockGiteaClient::new());
+
+        // Create project in a new org
+        let result = service
+            .create_project(
+                &agent,
+                "my-project",
+                Some("A cool project"),
+                Some("rust"),
+                Some("my-new-org"), // org name
+                "main",             // repo name
+                true,               // create the org
+                None,               // no agent token needed for org repos
+            )
+            .await;
+
+        assert!(result.is_ok());
+        let result = result.unwrap();
+        assert_eq!(result.project.name, "my-project");
+        assert_eq!(result.project.gitea_org, "my-new-org");
+        assert_eq!(result.project.gitea_repo, "main");
+    }
+
+    #[tokio::test]
+    async fn create_project_uses_configured_template() {
+        let agent = test_agent();
+        let template: RepoTemplate = "synstack/project-template".parse().unwrap();
+        let service = create_service(InMemoryProjectRepository::new(), MockGiteaClient::new())
+            .with_repo_template(Some(template));
+
+        service
+            .create_project(
+                &agent,
+                "templated",
+                None,
+                Some("rust"),
+                Some("my-new-org"),
+                "templated",
+                true,
+                None,
+            )
+            .await
+            .unwrap();
+
+        let generated = service.gitea.template_repos.read().unwrap().clone();
+        assert_eq!(
+            generated,
+            vec![(
+                ("my-new-org".to_string(), "templated".to_string()),
+                ("synstack".to_string(), "project-template".to_string()),
+            )]
+        );
+    }
+
+    #[tokio::test]
+    async fn create_project_without_template_creates_bare_repo() {
+        let agent = test_agent();
+        let service = create_service(InMemoryProjectRepository::new(), MockGiteaClient::new());
+
+        let result = service
+            .create_project(
+                &agent,
+                "bare",
+                None,
+                None,
+                Some("my-new-org"),
+                "bare",
+                true,
+                None,
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(result.project.gitea_repo, "bare");
+        assert!(service.gitea.template_repos.read().unwrap().is_empty());
+    }
+
+    #[test]
+    fn parse_repo_template() {
+        let template: RepoTemplate = "synstack/project-template".parse().unwrap();
+        assert_eq!(template.owner, "synstack");
+        assert_eq!(template.repo, "project-template");
+        assert!("project-template".parse::<RepoTemplate>().is_err());
+        assert!("a/b/c".parse::<RepoTemplate>().is_err());
+        assert!("/repo".parse::<RepoTemplate>().is_err());
+    }
+
+    #[tokio::test]
+    async fn create_project_fails_with_empty_name() {
+        let agent = test_agent();
+        let service = create_service(InMemoryProjectRepository::new(), MockGiteaClient::new());
+
+        let result = service
+            .create_project(&agent, "", None, None, None, "repo", false, Some("token"))
+            .await;
+
+        assert!(result.is_err());
+        let err = result.unwrap_err().to_string();
+        assert!(err.contains("between 1 and 100"));
+    }
+
+    #[tokio::test]
+    async fn create_project_fails_with_long_name() {
+        let agent = test_agent();
+        let service = create_service(InMemoryProjectRepository::new(), MockGiteaClient::new());
+        let long_name = "a".repeat(101);
+
+        let result = service
+            .create_project(
+                &agent,
+                &long_name,
+                None,
+                None,
+                None,
+                "repo",
+                false,
+                Some("token"),
+            )
+            .await;
+
+        assert!(result.is_err());
+        let err = result.unwrap_err().to_string();
+        assert!(err.contains("between 1 and 100"));
+    }
+
+    #[tokio::test]
+    async fn create_project_fails_when_name_exists() {
+        let agent = test_agent();
+        let existing = test_project();
+        let service = create_service(
+            InMemoryProjectRepository::new().with_project(existing.clone()),
+            MockGiteaClient::new(),
+        );
+
+        let result = service
+            .create_project(
+                &agent,
+                &existing.name,
+                None,
+                None,
+                None,
+                "repo",
+                false,
+                Some("token"),
+            )
+            .await;
+
+        assert!(result.is_err());
+        let err = result.unwrap_err().to_string();
+        assert!(err.contains("already exists"));
+    }
+
+    #[tokio::test]
+    async fn create_project_fails_when_gitea_fails() {
+        let agent = test_agent();
+        let service = create_service(InMemoryProjectRepository::new(), MockGiteaClient::failing());
+
+        let result = service
+            .create_project(
+                &agent,
+                "new-project",
+                None,
+                None,
+                None,
+                "repo",
+                false,
+                Some("token"),
+            )
+            .await;
+
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn create_project_requires_token_for_personal_repo() {
+        let agent = test_agent();
+        let service = create_service(InMemoryProjectRepository::new(), MockGiteaClient::new());
+
+        // Try to create personal repo without token
+        let result = service
+            .create_project(
+                &agent,
+                "my-project",
+                None,
+                None,
+                None, // personal repo
+                "repo",
+                false,
+                None, // no token!
+            )
+            .await;
+
+        assert!(result.is_err());
+        let err = result.unwrap_err().to_string();
+        assert!(err.contains("token required"));
+    }
+
+    #[tokio::test]
+    async fn create_project_normalizes_slugs() {
+        let agent = test_agent();
+        let service = create_service(InMemoryProjectRepository::new(), MockGiteaClient::new());
+
+        let result = service
+            .create_project(
+                &agent,
+                "my-project",
+                None,
+                None,
+                Some(" My-Org "),
+                "My_Repo.rs",
+                true,
+                None,
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(result.project.gitea_org, "my-org");
+        assert_eq!(result.project.gitea_repo, "my_repo.rs");
+    }
+
+    #[tokio::test]
+    async fn create_project_rejects_slug_with_spaces() {
+        let agent = test_agent();
+        let service = create_service(InMemoryProjectRepository::new(), MockGiteaClient::new());
+
+        let err = service
+            .create_project(
+                &agent,
+                "my-project",
+                None,
+                None,
+                None,
+                "my repo",
+                false,
+                Some("token"),
+            )
+            .await
+            .unwrap_err();
+
+        assert!(matches!(err, AppError::Unprocessable(_)));
+        assert_eq!(
+            err.into_response().status(),
+            axum::http::StatusCode::UNPROCESSABLE_ENTITY
+        );
+    }
+
+    #[tokio::test]
+    async fn create_project_rejects_duplicate_repo() {
+        let agent = test_agent();
+        let existing = test_project();
+        let service = create_service(
+            InMemoryProjectRepository::new().with_project(existing.clone()),
+            MockGiteaClient::new(),
+        );
+
+        let err = service
+            .create_project(
+                &agent,
+                "another-project",
+                None,
+                None,
+                Some(&existing.gitea_org.to_uppercase()),
+                &existing.gitea_repo,
+                false,
+                None,
+            )
+            .await
+            .unwrap_err();
+
+        assert_eq!(
+            err.into_response().status(),
+            axum::http::StatusCode::CONFLICT
+        );
+    }
+
+    #[tokio::test]
+    async fn join_project_success() {
+        let agent = test_agent();
+        let project = test_project();
+        let service = create_service(
+            InMemoryProjectRepository::new().with_project(project.clone()),
+            MockGiteaClient::new(),
+        );
 
-    fn create_service(
-        project_repo: InMemoryProjectRepository,
-        gitea: MockGiteaClient,
-    ) -> AntfarmService<InMemoryProjectRepository, MockGiteaClient, MockAnalyticsClient> {
-        AntfarmService::new(
-            Arc::new(project_repo),
-            Arc::new(gitea),
+        let result = service.join_project(&agent, &project).await;
+
+        assert!(result.is_ok());
+        let result = result.unwrap();
+        assert_eq!(result.role, MemberRole::Contributor);
+    }
+
+    #[tokio::test]
+    async fn member_merge_is_admitted() {
+        let agent = test_agent();
+        let project = test_project();
+        let service = create_service(
+            InMemoryProjectRepository::new().with_project_and_member(project.clone(), agent.id),
+            MockGiteaClient::new(),
+        );
+
+        assert!(service.admit_merge_author(&agent, &project).await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn non_member_merge_skipped_or_enrolled_per_policy() {
+        let agent = test_agent();
+        let project = test_project();
+        let projects = Arc::new(InMemoryProjectRepository::new().with_project(project.clone()));
+        let gitea = Arc::new(MockGiteaClient::new());
+        let service = |policy| {
+            AntfarmService::new(
+                projects.clone(),
+                gitea.clone(),
+                Arc::new(MockAnalyticsClient::new()),
+            )
+            .with_non_member_merges(policy)
+        };
+
+        let skip = service(NonMemberMergePolicy::Skip);
+        assert!(!skip.admit_merge_author(&agent, &project).await.unwrap());
+        assert!(!projects.is_member(&project.id, &agent.id).await.unwrap());
+
+        let enroll = service(NonMemberMergePolicy::Enroll);
+        assert!(enroll.admit_merge_author(&agent, &project).await.unwrap());
+        let members = projects.get_members(&project.id).await.unwrap();
+        assert_eq!(members.len(), 1);
+        assert_eq!(members[0].role, MemberRole::Contributor);
+        let updated = projects.find_by_id(&project.id).await.unwrap().unwrap();
+        assert_eq!(updated.contributor_count, 1);
+    }
+
+    #[tokio::test]
+    async fn non_member_merge_enrolls_by_default() {
+        let agent = test_agent();
+        let project = test_project();
+        let service = create_service(
+            InMemoryProjectRepository::new().with_project(project.clone()),
+            MockGiteaClient::new(),
+        );
+
+        assert!(service.admit_merge_author(&agent, &project).await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn enroll_skips_authors_the_project_wont_take() {
+        let agent = test_agent();
+        let mut project = test_project();
+        project.visibility = ProjectVisibility::Private;
+        let service = create_service(
+            InMemoryProjectRepository::new().with_project(project.clone()),
+            MockGiteaClient::new(),
+        )
+        .with_non_member_merges(NonMemberMergePolicy::Enroll);
+
+        assert!(!service.admit_merge_author(&agent, &project).await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn join_project_fails_when_not_active() {
+        let agent = test_agent();
+        let project = test_project_with_status(ProjectStatus::Paused);
+        let service = create_service(
+            InMemoryProjectRepository::new().with_project(project.clone()),
+            MockGiteaClient::new(),
+        );
+
+        let result = service.join_project(&agent, &project).await;
+
+        assert!(result.is_err());
+        let err = result.unwrap_err().to_string();
+        assert!(err.contains("not accepting new contributors"));
+    }
+
+    #[tokio::test]
+    async fn join_project_fails_when_already_member() {
+        let agent = test_agent();
+        let project = test_project();
+        let repo = InMemoryProjectRepository::new().with_project(project.clone());
+
+        // First join
+        let service = create_service(repo, MockGiteaClient::new());
+        let first_join = service.join_project(&agent, &project).await;
+        assert!(first_join.is_ok());
+
+        // Second join should fail
+        let second_join = service.join_project(&agent, &project).await;
+        assert!(second_join.is_err());
+        let err = second_join.unwrap_err().to_string();
+        assert!(err.contains("already a member"));
+    }
+
+    #[tokio::test]
+    async fn list_active_projects() {
+        let project1 = test_project();
+        let project2 = test_project_with_status(ProjectStatus::Paused);
+        let project3 = test_project();
+        let service = create_service(
+            InMemoryProjectRepository::new()
+                .with_project(project1)
+                .with_project(project2)
+                .with_project(project3),
+            MockGiteaClient::new(),
+        );
+
+        let result = service.list_active_projects(10, 0).await;
+
+        assert!(result.is_ok());
+        let projects = result.unwrap();
+        // Only active projects should be returned
+        assert!(projects.iter().all(|p| p.status == ProjectStatus::Active));
+    }
+
+    #[tokio::test]
+    async fn closing_an_issue_runs_its_configured_hooks() {
+        let project = test_project();
+        let gitea = Arc::new(MockGiteaClient::new());
+        let service = AntfarmService::new(
+            Arc::new(InMemoryProjectRepository::new()),
+            gitea.clone(),
             Arc::new(MockAnalyticsClient::new()),
         )
+        .with_issue_hooks(
+            "closed:comment=Thanks for closing this out!;closed:label=done;open:label=triage"
+                .parse()
+                .unwrap(),
+        );
+
+        let ran = service
+            .run_issue_hooks(&project, 12, IssueState::Closed)
+            .await
+            .unwrap();
+
+        assert_eq!(ran, 2);
+        assert_eq!(
+            *gitea.posted_comments.read().unwrap(),
+            vec![(
+                (project.gitea_org.clone(), project.gitea_repo.clone(), 12),
+                "Thanks for closing this out!".to_string()
+            )]
+        );
+        assert_eq!(
+            *gitea.issue_labels_added.read().unwrap(),
+            vec![(12, vec!["done".to_string()])]
+        );
+    }
+
+    #[tokio::test]
+    async fn issue_hooks_do_nothing_when_unconfigured() {
+        let gitea = Arc::new(MockGiteaClient::new());
+        let service = AntfarmService::new(
+            Arc::new(InMemoryProjectRepository::new()),
+            gitea.clone(),
+            Arc::new(MockAnalyticsClient::new()),
+        );
+
+        let ran = service
+            .run_issue_hooks(&test_project(), 12, IssueState::Closed)
+            .await
+            .unwrap();
+
+        assert_eq!(ran, 0);
+        assert!(gitea.posted_comments.read().unwrap().is_empty());
+    }
+
+    fn project_in(language: Option<&str>) -> Project {
+        Project {
+            language: language.map(String::from),
+            ..test_project()
+        }
+    }
+
+    #[tokio::test]
+    async fn list_active_projects_by_language_normalizes_and_folds_aliases() {
+        let service = create_service(
+            InMemoryProjectRepository::new()
+                .with_project(project_in(Some("Rust")))
+                .with_project(project_in(Some(" rust ")))
+                .with_project(project_in(Some("rs")))
+                .with_project(project_in(Some("go")))
+                .with_project(project_in(None))
+                .with_project(Project {
+                    status: ProjectStatus::Paused,
+                    ..project_in(Some("rust"))
+                }),
+            MockGiteaClient::new(),
+        );
+
+        let projects = service
+            .list_active_projects_by_language("RUST", 10, 0)
+            .await
+            .unwrap();
+        assert_eq!(projects.len(), 3);
+        assert_eq!(
+            service
+                .count_active_projects_by_language("rust")
+                .await
+                .unwrap(),
+            3
+        );
+        assert_eq!(
+            service
+                .count_active_projects_by_language("golang")
+                .await
+                .unwrap(),
+            1
+        );
+        assert_eq!(
+            service
+                .count_active_projects_by_language("  ")
+                .await
+                .unwrap(),
+            0
+        );
+    }
+
+    #[tokio::test]
+    async fn language_facets_count_each_language_once() {
+        let service = create_service(
+            InMemoryProjectRepository::new()
+                .with_project(project_in(Some("Rust")))
+                .with_project(project_in(Some("rs")))
+                .with_project(project_in(Some("golang")))
+                .with_project(project_in(Some("Python")))
+                .with_project(project_in(Some("")))
+                .with_project(project_in(None))
+                .with_project(Project {
+                    visibility: ProjectVisibility::Private,
+                    ..project_in(Some("go"))
+                }),
+            MockGiteaClient::new(),
+        )
+        .with_language_aliases("rs=rust".parse().unwrap());
+
+        let facets = service.language_facets().await.unwrap();
+
+        assert_eq!(
+            facets,
+            vec![
+                ("rust".to_string(), 2),
+                ("golang".to_string(), 1),
+                ("python".to_string(), 1),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn active_project_count_covers_all_pages() {
+        let service = create_service(
+            InMemoryProjectRepository::new()
+                .with_project(test_project())
+                .with_project(test_project_with_status(ProjectStatus::Paused))
+                .with_project(test_project())
+                .with_project(test_project()),
+            MockGiteaClient::new(),
+        );
+
+        let page = service.list_active_projects(1, 0).await.unwrap();
+        let total = service.count_active_projects().await.unwrap();
+
+        assert_eq!(page.len(), 1);
+        assert_eq!(total, 3);
+    }
+
+    #[tokio::test]
+    async fn get_my_projects_empty() {
+        let agent = test_agent();
+        let service = create_service(InMemoryProjectRepository::new(), MockGiteaClient::new());
+
+        let result = service.get_my_projects(&agent).await;
+
+        assert!(result.is_ok());
+        assert!(result.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn get_my_projects_with_role_filters_by_role() {
+        let agent = test_agent();
+        let mut contributed = test_project();
+        contributed.name = "contributed".to_string();
+        let mut maintained = test_project();
+        maintained.name = "maintained".to_string();
+        let project_repo = InMemoryProjectRepository::new()
+            .with_project_and_role(contributed, agent.id, MemberRole::Contributor)
+            .with_project_and_role(maintained.clone(), agent.id, MemberRole::Maintainer);
+        let service = create_service(project_repo, MockGiteaClient::new());
+
+        let projects = service
+            .get_my_projects_with_role(&agent, MemberRole::Maintainer)
+            .await
+            .unwrap();
+
+        assert_eq!(projects.len(), 1);
+        assert_eq!(projects[0].id, maintained.id);
+        assert_eq!(service.get_my_projects(&agent).await.unwrap().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn get_my_projects_with_maintainer_role_includes_owned() {
+        let agent = test_agent();
+        let owned = test_project();
+        let project_repo = InMemoryProjectRepository::new().with_project_and_role(
+            owned.clone(),
+            agent.id,
+            MemberRole::Owner,
+        );
+        let service = create_service(project_repo, MockGiteaClient::new());
+
+        let maintained = service
+            .get_my_projects_with_role(&agent, MemberRole::Maintainer)
+            .await
+            .unwrap();
+        let contributed = service
+            .get_my_projects_with_role(&agent, MemberRole::Contributor)
+            .await
+            .unwrap();
+
+        assert_eq!(maintained.len(), 1);
+        assert_eq!(maintained[0].id, owned.id);
+        assert!(contributed.is_empty());
+    }
+
+    #[tokio::test]
+    async fn private_project_is_hidden_from_anonymous_viewers() {
+        let member = test_agent();
+        let mut project = test_project();
+        project.visibility = ProjectVisibility::Private;
+        let project_repo =
+            InMemoryProjectRepository::new().with_project_and_member(project.clone(), member.id);
+        let service = create_service(project_repo, MockGiteaClient::new());
+
+        let anonymous = service.get_visible_project(&project.id, None).await;
+        let outsider = service
+            .get_visible_project(&project.id, Some(&test_agent_named("outsider")))
+            .await;
+        let as_member = service
+            .get_visible_project(&project.id, Some(&member))
+            .await;
+
+        assert!(anonymous.unwrap().is_none());
+        assert!(outsider.unwrap().is_none());
+        assert_eq!(as_member.unwrap().unwrap().id, project.id);
+    }
+
+    #[tokio::test]
+    async fn invited_agent_can_see_and_join_private_project() {
+        let owner = test_agent_named("owner");
+        let invitee = test_agent_named("invitee");
+        let mut project = test_project();
+        project.visibility = ProjectVisibility::Private;
+        let project_repo = InMemoryProjectRepository::new().with_project_and_role(
+            project.clone(),
+            owner.id,
+            MemberRole::Owner,
+        );
+        let service = create_service(project_repo, MockGiteaClient::new());
+
+        assert!(service
+            .get_visible_project(&project.id, Some(&invitee))
+            .await
+            .unwrap()
+            .is_none());
+        let uninvited = service.join_project(&invitee, &project).await;
+        assert!(matches!(
+            uninvited,
+            Err(AppError::Domain(DomainError::Forbidden(_)))
+        ));
+
+        service
+            .invite_member(&owner, &project, &invitee)
+            .await
+            .unwrap();
+
+        assert!(service
+            .get_visible_project(&project.id, Some(&invitee))
+            .await
+            .unwrap()
+            .is_some());
+        let joined = service.join_project(&invitee, &project).await.unwrap();
+        assert_eq!(joined.role, MemberRole::Contributor);
+    }
+
+    #[tokio::test]
+    async fn contributors_cannot_invite() {
+        let contributor = test_agent_named("contributor");
+        let mut project = test_project();
+        project.visibility = ProjectVisibility::Private;
+        let project_repo = InMemoryProjectRepository::new()
+            .with_project_and_member(project.clone(), contributor.id);
+        let service = create_service(project_repo, MockGiteaClient::new());
+
+        let result = service
+            .invite_member(&contributor, &project, &test_agent_named("friend"))
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(AppError::Domain(DomainError::Forbidden(_)))
+        ));
+    }
+
+    #[tokio::test]
+    async fn featured_listing_excludes_hidden_projects() {
+        let mut private = test_project();
+        private.featured = true;
+        private.visibility = ProjectVisibility::Private;
+        let mut unlisted = test_project();
+        unlisted.featured = true;
+        unlisted.visibility = ProjectVisibility::Unlisted;
+        let mut public = test_project();
+        public.featured = true;
+        let project_repo = InMemoryProjectRepository::new()
+            .with_project(private)
+            .with_project(unlisted)
+            .with_project(public.clone());
+        let service = create_service(project_repo, MockGiteaClient::new());
+
+        let featured = service.list_featured_projects(10).await.unwrap();
+
+        assert_eq!(featured.len(), 1);
+        assert_eq!(featured[0].id, public.id);
+    }
+
+    #[tokio::test]
+    async fn active_listing_only_includes_public_projects() {
+        let mut unlisted = test_project();
+        unlisted.visibility = ProjectVisibility::Unlisted;
+        let mut private = test_project();
+        private.visibility = ProjectVisibility::Private;
+        let public = test_project();
+        let project_repo = InMemoryProjectRepository::new()
+            .with_project(unlisted.clone())
+            .with_project(private)
+            .with_project(public.clone());
+        let service = create_service(project_repo, MockGiteaClient::new());
+
+        let listed = service.list_active_projects(20, 0).await.unwrap();
+
+        assert_eq!(listed.len(), 1);
+        assert_eq!(listed[0].id, public.id);
+        assert_eq!(service.count_active_projects().await.unwrap(), 1);
+        // Unlisted projects are still reachable by ID
+        assert!(service
+            .get_visible_project(&unlisted.id, None)
+            .await
+            .unwrap()
+            .is_some());
+    }
+
+    #[tokio::test]
+    async fn list_claimable_roles_only_includes_eligible_projects() {
+        let agent = test_agent();
+        let mut idle_owner = test_agent_named("idle-owner");
+        idle_owner.last_seen_at = Some(Utc::now() - chrono::Duration::days(45));
+        let mut active_owner = test_agent_named("active-owner");
+        active_owner.last_seen_at = Some(Utc::now());
+
+        let mut abandoned = test_project();
+        abandoned.name = "abandoned".to_string();
+        let mut thriving = test_project();
+        thriving.name = "thriving".to_string();
+        let project_repo = InMemoryProjectRepository::new()
+            .with_project_and_role(abandoned.clone(), idle_owner.id, MemberRole::Owner)
+            .with_project_and_role(abandoned.clone(), agent.id, MemberRole::Contributor)
+            .with_project_and_role(thriving.clone(), active_owner.id, MemberRole::Owner)
+            .with_project_and_role(thriving, agent.id, MemberRole::Contributor);
+        let agents = InMemoryAgentRepository::new()
+            .with_agent(idle_owner)
+            .with_agent(active_owner);
+        let service = create_service(project_repo, MockGiteaClient::new());
+
+        let claimable = service.list_claimable_roles(&agent, &agents).await.unwrap();
+
+        assert_eq!(claimable.len(), 1);
+        assert_eq!(claimable[0].project.id, abandoned.id);
+        assert_eq!(claimable[0].role, MemberRole::Owner);
+        assert_eq!(claimable[0].inactive_days, Some(45));
+    }
+
+    // ===== mirror_project tests =====
+
+    #[tokio::test]
+    async fn mirror_project_creates_mirror_from_source_url() {
+        let agent = test_agent();
+        let project = test_project();
+        let project_repo = InMemoryProjectRepository::new().with_project(project.clone());
+        project_repo
+            .add_member(&project.id, &agent.id, MemberRole::Owner)
+            .await
+            .unwrap();
+        let service = create_service(project_repo, MockGiteaClient::new());
+
+        let result = service
+            .mirror_project(
+                &agent,
+                &project,
+                "https://github.com/example/upstream.git",
+                None,
+            )
+            .await
+            .unwrap();
+
+        let mirrors = service.gitea.mirrors.read().unwrap().clone();
+        assert_eq!(
+            mirrors,
+            vec![(
+                "https://github.com/example/upstream.git".to_string(),
+                project.gitea_org.clone(),
+                format!("{}-mirror", project.gitea_repo),
+            )]
+        );
+
+        let mirror = result.project.mirror.unwrap();
+        assert_eq!(mirror.source_url, "https://github.com/example/upstream.git");
+        assert_eq!(mirror.repo, format!("{}-mirror", project.gitea_repo));
+
+        let stored = service
+            .projects
+            .find_by_id(&project.id)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(stored.mirror.unwrap().repo, mirror.repo);
     }
 
     #[tokio::test]
-    async fn create_project_in_personal_namespace() {
+    async fn mirror_project_requires_owner_or_maintainer() {
         let agent = test_agent();
-        let service = create_service(InMemoryProjectRepository::new(), MockGiteaClient::new());
+        let project = test_project();
+        let project_repo = InMemoryProjectRepository::new().with_project(project.clone());
+        project_repo
+            .add_member(&project.id, &agent.id, MemberRole::Contributor)
+            .await
+            .unwrap();
+        let service = create_service(project_repo, MockGiteaClient::new());
 
-        // Create project in personal namespace (no owner specified)
         let result = service
-            .create_project(
+            .mirror_project(
                 &agent,
-                "my-project",
-                Some("A cool project"),
-                Some("rust"),
-                None,                     // personal repo
-                "my-repo",                // repo name
-                false,                    // don't create org
-                Some("mock-agent-token"), // agent token for personal repos
+                &project,
+                "https://github.com/example/upstream.git",
+                None,
             )
             .await;
 
-        assert!(result.is_ok());
-        let result = result.unwrap();
-        assert_eq!(result.project.name, "my-project");
-        assert_eq!(result.project.gitea_org, agent.gitea_username);
-        assert_eq!(result.project.gitea_repo, "my-repo");
+        assert!(matches!(
+            result,
+            Err(AppError::Domain(DomainError::Forbidden(_)))
+        ));
+        assert!(service.gitea.mirrors.read().unwrap().is_empty());
     }
 
     #[tokio::test]
-    async fn create_project_in_new_org() {
+    async fn create_release_records_tag_and_name() {
         let agent = test_agent();
-        let service = create_service(InMemoryProjectRepository::new(), MockGiteaClient::new());
+        let project = test_project();
+        let project_repo = InMemoryProjectRepository::new().with_project_and_role(
+            project.clone(),
+            agent.id,
+            MemberRole::Maintainer,
+        );
+        let service = create_service(project_repo, MockGiteaClient::new());
+        let release = NewRelease {
+            tag: "v1.0.0".to_string(),
+            name: Some("First milestone".to_string()),
+            body: Some("100 merged PRs".to_string()),
+            ..Default::default()
+        };
 
-        // Create project in a new org
-        let result = service
-            .create_project(
-                &agent,
-                "my-project",
-                Some("A cool project"),
-                Some("rust"),
-                Some("my-new-org"), // org name
-                "main",             // repo name
-                true,               // create the org
-                None,               // no agent token needed for org repos
-            )
-            .await;
+        let created = service
+            .create_release(&agent, &project, &release)
+            .await
+            .unwrap();
+
+        assert_eq!(created.tag_name, "v1.0.0");
+        assert_eq!(created.name, "First milestone");
+        let recorded = service.gitea.releases.read().unwrap().clone();
+        assert_eq!(recorded.len(), 1);
+        assert_eq!(recorded[0].0, project.gitea_org);
+        assert_eq!(recorded[0].1, project.gitea_repo);
+        assert_eq!(recorded[0].2.tag_name, "v1.0.0");
+        assert_eq!(recorded[0].2.name, "First milestone");
+
+        let again = service.create_release(&agent, &project, &release).await;
+        assert!(matches!(
+            again,
+            Err(AppError::Domain(DomainError::AlreadyExists(_)))
+        ));
+    }
 
-        assert!(result.is_ok());
-        let result = result.unwrap();
-        assert_eq!(result.project.name, "my-project");
-        assert_eq!(result.project.gitea_org, "my-new-org");
-        assert_eq!(result.project.gitea_repo, "main");
+    #[tokio::test]
+    async fn create_release_requires_owner_or_maintainer() {
+        let agent = test_agent();
+        let project = test_project();
+        let project_repo =
+            InMemoryProjectRepository::new().with_project_and_member(project.clone(), agent.id);
+        let service = create_service(project_repo, MockGiteaClient::new());
+        let release = NewRelease {
+            tag: "v1.0.0".to_string(),
+            ..Default::default()
+        };
+
+        let result = service.create_release(&agent, &project, &release).await;
+
+        assert!(matches!(
+            result,
+            Err(AppError::Domain(DomainError::Forbidden(_)))
+        ));
+        assert!(service.gitea.releases.read().unwrap().is_empty());
     }
 
+    // ===== auto_label_issue tests =====
+
     #[tokio::test]
-    async fn create_project_fails_with_empty_name() {
+    async fn auto_label_issue_marks_easy_issue_as_good_first_issue() {
+        let project = test_project();
+        let service = create_service(
+            InMemoryProjectRepository::new().with_project(project.clone()),
+            MockGiteaClient::new(),
+        );
+        let metadata = IssueMetadata {
+            difficulty: Some("easy".to_string()),
+            kind: None,
+        };
+
+        let labels = service
+            .auto_label_issue(&project, 7, &metadata)
+            .await
+            .unwrap();
+
+        assert_eq!(labels.len(), 1);
+        assert_eq!(labels[0].name, "good-first-issue");
+        let added = service.gitea.issue_labels_added.read().unwrap().clone();
+        assert_eq!(added, vec![(7, vec!["good-first-issue".to_string()])]);
+        // The label didn't exist in the repo yet, so it was created first
+        let created = service.gitea.labels_created.read().unwrap().clone();
+        assert_eq!(created.len(), 1);
+        assert_eq!(created[0].name, "good-first-issue");
+    }
+
+    #[tokio::test]
+    async fn auto_label_issue_reuses_existing_labels_and_custom_rules() {
+        let project = test_project();
+        let rules: AutoLabelRules = "bug=bug".parse().unwrap();
+        let service = create_service(
+            InMemoryProjectRepository::new().with_project(project.clone()),
+            MockGiteaClient::new(),
+        )
+        .with_auto_labels(rules);
+        let metadata = IssueMetadata {
+            difficulty: Some("easy".to_string()),
+            kind: Some("bug".to_string()),
+        };
+
+        let labels = service
+            .auto_label_issue(&project, 3, &metadata)
+            .await
+            .unwrap();
+
+        assert_eq!(labels.len(), 1);
+        assert_eq!(labels[0].name, "bug");
+        assert!(service.gitea.labels_created.read().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn auto_label_issue_without_matching_rule_is_noop() {
+        let project = test_project();
+        let service = create_service(
+            InMemoryProjectRepository::new().with_project(project.clone()),
+            MockGiteaClient::new(),
+        );
+
+        let labels = service
+            .auto_label_issue(&project, 1, &IssueMetadata::default())
+            .await
+            .unwrap();
+
+        assert!(labels.is_empty());
+        assert!(service.gitea.issue_labels_added.read().unwrap().is_empty());
+    }
+
+    // ===== import_project tests =====
+
+    #[tokio::test]
+    async fn import_project_registers_webhook_and_owner() {
         let agent = test_agent();
-        let service = create_service(InMemoryProjectRepository::new(), MockGiteaClient::new());
+        let gitea =
+            MockGiteaClient::new().with_org_repos("existing-org", &["existing-repo", "docs"]);
+        let service = create_service(InMemoryProjectRepository::new(), gitea);
 
         let result = service
-            .create_project(&agent, "", None, None, None, "repo", false, Some("token"))
-            .await;
+            .import_project(
+                &agent,
+                "existing-org",
+                "existing-repo",
+                None,
+                Some("rust"),
+                "http://api.test/webhooks/gitea",
+                Some("secret"),
+                false,
+            )
+            .await
+            .unwrap();
 
-        assert!(result.is_err());
-        let err = result.unwrap_err().to_string();
-        assert!(err.contains("between 1 and 100"));
+        assert_eq!(result.project.name, "existing-repo");
+        assert_eq!(result.project.gitea_org, "existing-org");
+        assert_eq!(result.project.gitea_repo, "existing-repo");
+        assert_eq!(result.project.created_by, Some(agent.id));
+        assert!(result.webhook_id.is_some());
+        assert_eq!(result.sibling_repos, vec!["docs".to_string()]);
+
+        let role = service
+            .projects
+            .get_member_role(&result.project.id, &agent.id)
+            .await
+            .unwrap();
+        assert_eq!(role, Some(MemberRole::Owner));
+
+        let webhooks = service.gitea.webhooks.read().unwrap().clone();
+        assert_eq!(
+            webhooks,
+            vec![(
+                "existing-org".to_string(),
+                "existing-repo".to_string(),
+                "http://api.test/webhooks/gitea".to_string()
+            )]
+        );
     }
 
     #[tokio::test]
-    async fn create_project_fails_with_long_name() {
+    async fn import_project_applies_standard_repo_settings() {
         let agent = test_agent();
-        let service = create_service(InMemoryProjectRepository::new(), MockGiteaClient::new());
-        let long_name = "a".repeat(101);
+        let gitea = MockGiteaClient::new().with_org_repos("existing-org", &["existing-repo"]);
+        let service = create_service(InMemoryProjectRepository::new(), gitea);
 
-        let result = service
-            .create_project(
+        service
+            .import_project(
                 &agent,
-                &long_name,
+                "existing-org",
+                "existing-repo",
                 None,
                 None,
+                "http://api.test/webhooks/gitea",
                 None,
-                "repo",
                 false,
-                Some("token"),
             )
-            .await;
-
-        assert!(result.is_err());
-        let err = result.unwrap_err().to_string();
-        assert!(err.contains("between 1 and 100"));
+            .await
+            .unwrap();
+
+        let updates = service.gitea.repo_updates.read().unwrap().clone();
+        assert_eq!(
+            updates,
+            vec![(
+                "existing-org".to_string(),
+                "existing-repo".to_string(),
+                RepoSettings::standard()
+            )]
+        );
     }
 
     #[tokio::test]
-    async fn create_project_fails_when_name_exists() {
+    async fn import_project_protects_default_branch_when_asked() {
         let agent = test_agent();
-        let existing = test_project();
-        let service = create_service(
-            InMemoryProjectRepository::new().with_project(existing.clone()),
-            MockGiteaClient::new(),
-        );
+        let gitea = MockGiteaClient::new().with_org_repos("existing-org", &["existing-repo"]);
+        let service = create_service(InMemoryProjectRepository::new(), gitea);
 
         let result = service
-            .create_project(
+            .import_project(
                 &agent,
-                &existing.name,
+                "existing-org",
+                "existing-repo",
                 None,
                 None,
+                "http://api.test/webhooks/gitea",
                 None,
-                "repo",
-                false,
-                Some("token"),
+                true,
             )
-            .await;
-
-        assert!(result.is_err());
-        let err = result.unwrap_err().to_string();
-        assert!(err.contains("already exists"));
+            .await
+            .unwrap();
+
+        assert!(result.project.protect_default_branch);
+        let protections = service.gitea.branch_protections.read().unwrap().clone();
+        assert_eq!(protections.len(), 1);
+        let ((owner, repo, branch), rules) = &protections[0];
+        assert_eq!(
+            (owner.as_str(), repo.as_str(), branch.as_str()),
+            ("existing-org", "existing-repo", "main")
+        );
+        // A fresh project requires no approvals or CI, so neither does Gitea
+        assert!(rules.require_pull_request);
+        assert_eq!(rules.required_approvals, 0);
+        assert!(!rules.require_status_checks);
     }
 
     #[tokio::test]
-    async fn create_project_fails_when_gitea_fails() {
+    async fn import_project_leaves_branch_unprotected_by_default() {
         let agent = test_agent();
-        let service = create_service(InMemoryProjectRepository::new(), MockGiteaClient::failing());
+        let gitea = MockGiteaClient::new().with_org_repos("existing-org", &["existing-repo"]);
+        let service = create_service(InMemoryProjectRepository::new(), gitea);
 
         let result = service
-            .create_project(
+            .import_project(
                 &agent,
-                "new-project",
+                "existing-org",
+                "existing-repo",
                 None,
                 None,
+                "http://api.test/webhooks/gitea",
                 None,
-                "repo",
                 false,
-                Some("token"),
             )
+            .await
+            .unwrap();
+
+        assert!(!result.project.protect_default_branch);
+        assert!(service.gitea.branch_protections.read().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn branch_protection_follows_project_merge_settings() {
+        let owner = test_agent();
+        let project = test_project();
+        let projects = InMemoryProjectRepository::new().with_project_and_role(
+            project.clone(),
+            owner.id,
+            MemberRole::Owner,
+        );
+        let service = create_service(projects, MockGiteaClient::new());
+
+        let project = service
+            .set_protect_default_branch(&owner, &project, true)
+            .await
+            .unwrap();
+        let project = service
+            .set_required_approvals(&owner, &project, 2)
+            .await
+            .unwrap();
+        let project = service
+            .set_require_green_ci(&owner, &project, true)
+            .await
+            .unwrap();
+
+        let protections = service.gitea.branch_protections.read().unwrap().clone();
+        assert_eq!(protections.len(), 1);
+        assert_eq!(protections[0].1, BranchProtection::for_project(&project));
+        assert_eq!(protections[0].1.required_approvals, 2);
+        assert_eq!(
+            protections[0].1.status_check_contexts,
+            vec!["*".to_string()]
+        );
+
+        service
+            .set_protect_default_branch(&owner, &project, false)
+            .await
+            .unwrap();
+        assert!(service.gitea.branch_protections.read().unwrap().is_empty());
+        let stored = service
+            .projects
+            .find_by_id(&project.id)
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(!stored.protect_default_branch);
+    }
+
+    #[tokio::test]
+    async fn required_approvals_are_capped() {
+        let owner = test_agent();
+        let project = test_project();
+        let projects = InMemoryProjectRepository::new().with_project_and_role(
+            project.clone(),
+            owner.id,
+            MemberRole::Owner,
+        );
+        let service = create_service(projects, MockGiteaClient::new());
+
+        let too_many = service
+            .set_required_approvals(&owner, &project, MAX_REQUIRED_APPROVALS + 1)
+            .await;
+        let at_cap = service
+            .set_required_approvals(&owner, &project, MAX_REQUIRED_APPROVALS)
+            .await;
+
+        assert!(matches!(too_many, Err(AppError::BadRequest(_))));
+        assert_eq!(at_cap.unwrap().required_approvals, MAX_REQUIRED_APPROVALS);
+    }
+
+    #[tokio::test]
+    async fn failed_protection_update_keeps_the_setting() {
+        let owner = test_agent();
+        let project = test_project();
+        let projects = InMemoryProjectRepository::new().with_project_and_role(
+            project.clone(),
+            owner.id,
+            MemberRole::Owner,
+        );
+        let service = create_service(projects, MockGiteaClient::failing());
+
+        let result = service
+            .set_protect_default_branch(&owner, &project, true)
             .await;
 
         assert!(result.is_err());
+        let stored = service
+            .projects
+            .find_by_id(&project.id)
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(!stored.protect_default_branch);
     }
 
     #[tokio::test]
-    async fn create_project_requires_token_for_personal_repo() {
+    async fn import_project_rejects_reimport() {
         let agent = test_agent();
         let service = create_service(InMemoryProjectRepository::new(), MockGiteaClient::new());
 
-        // Try to create personal repo without token
+        service
+            .import_project(
+                &agent,
+                "existing-org",
+                "existing-repo",
+                None,
+                None,
+                "http://api.test/webhooks/gitea",
+                None,
+                false,
+            )
+            .await
+            .unwrap();
+
+        // Same org/repo under a different display name is still a re-import
         let result = service
-            .create_project(
+            .import_project(
                 &agent,
-                "my-project",
+                "existing-org",
+                "existing-repo",
+                Some("another-name"),
                 None,
+                "http://api.test/webhooks/gitea",
                 None,
-                None, // personal repo
-                "repo",
                 false,
-                None, // no token!
             )
             .await;
 
-        assert!(result.is_err());
-        let err = result.unwrap_err().to_string();
-        assert!(err.contains("token required"));
+        assert!(matches!(
+            result,
+            Err(AppError::Domain(DomainError::AlreadyExists(_)))
+        ));
+        assert_eq!(service.gitea.webhooks.read().unwrap().len(), 1);
     }
 
+    // ===== featured project tests =====
+
     #[tokio::test]
-    async fn join_project_success() {
-        let agent = test_agent();
-        let project = test_project();
+    async fn featured_projects_follow_curation_rank() {
+        let first = test_project();
+        let mut second = test_project();
+        second.name = "second-project".to_string();
+        let unfeatured = test_project_with_status(ProjectStatus::Active);
         let service = create_service(
-            InMemoryProjectRepository::new().with_project(project.clone()),
+            InMemoryProjectRepository::new()
+                .with_project(first.clone())
+                .with_project(second.clone())
+                .with_project(unfeatured),
             MockGiteaClient::new(),
         );
 
-        let result = service.join_project(&agent, &project).await;
+        service.set_featured(&second, true, 2).await.unwrap();
+        let updated = service.set_featured(&first, true, 1).await.unwrap();
+        assert!(updated.featured);
 
-        assert!(result.is_ok());
-        let result = result.unwrap();
-        assert_eq!(result.role, MemberRole::Contributor);
+        let featured = service.list_featured_projects(10).await.unwrap();
+        let ids: Vec<_> = featured.iter().map(|p| p.id).collect();
+        assert_eq!(ids, vec![first.id, second.id]);
     }
 
     #[tokio::test]
-    async fn join_project_fails_when_not_active() {
-        let agent = test_agent();
-        let project = test_project_with_status(ProjectStatus::Paused);
+    async fn unfeatured_project_leaves_featured_list() {
+        let project = test_project();
         let service = create_service(
             InMemoryProjectRepository::new().with_project(project.clone()),
             MockGiteaClient::new(),
         );
 
-        let result = service.join_project(&agent, &project).await;
+        service.set_featured(&project, true, 1).await.unwrap();
+        assert_eq!(service.list_featured_projects(10).await.unwrap().len(), 1);
 
-        assert!(result.is_err());
-        let err = result.unwrap_err().to_string();
-        assert!(err.contains("not accepting new contributors"));
+        service.set_featured(&project, false, 0).await.unwrap();
+        assert!(service.list_featured_projects(10).await.unwrap().is_empty());
     }
 
     #[tokio::test]
-    async fn join_project_fails_when_already_member() {
-        let agent = test_agent();
+    async fn elo_multiplier_is_clamped_to_range() {
         let project = test_project();
-        let repo = InMemoryProjectRepository::new().with_project(project.clone());
-
-        // First join
-        let service = create_service(repo, MockGiteaClient::new());
-        let first_join = service.join_project(&agent, &project).await;
-        assert!(first_join.is_ok());
-
-        // Second join should fail
-        let second_join = service.join_project(&agent, &project).await;
-        assert!(second_join.is_err());
-        let err = second_join.unwrap_err().to_string();
-        assert!(err.contains("already a member"));
-    }
-
-    #[tokio::test]
-    async fn list_active_projects() {
-        let project1 = test_project();
-        let project2 = test_project_with_status(ProjectStatus::Paused);
-        let project3 = test_project();
         let service = create_service(
-            InMemoryProjectRepository::new()
-                .with_project(project1)
-                .with_project(project2)
-                .with_project(project3),
+            InMemoryProjectRepository::new().with_project(project.clone()),
             MockGiteaClient::new(),
         );
 
-        let result = service.list_active_projects(10, 0).await;
-
-        assert!(result.is_ok());
-        let projects = result.unwrap();
-        // Only active projects should be returned
-        assert!(projects.iter().all(|p| p.status == ProjectStatus::Active));
-    }
-
-    #[tokio::test]
-    async fn get_my_projects_empty() {
-        let agent = test_agent();
-        let service = create_service(InMemoryProjectRepository::new(), MockGiteaClient::new());
+        let updated = service.set_elo_multiplier(&project, 10.0).await.unwrap();
+        assert_eq!(updated.elo_multiplier, ELO_MULTIPLIER_MAX);
 
-        let result = service.get_my_projects(&agent).await;
+        service.set_elo_multiplier(&project, 0.0).await.unwrap();
+        let stored = service.get_project(&project.id).await.unwrap().unwrap();
+        assert_eq!(stored.elo_multiplier, ELO_MULTIPLIER_MIN);
 
-        assert!(result.is_ok());
-        assert!(result.unwrap().is_empty());
+        assert!(service
+            .set_elo_multiplier(&project, f32::NAN)
+            .await
+            .is_err());
     }
 }
@@ -0,0 +1,193 @@
+//! Gitea-backed bug attribution
+//!
+//! Confirms a PR changed the code a bug report points at. File references
+//! are checked against the PR's diff; for line references, the line is read
+//! from the file on the default branch, where the reporter saw it, and must be
+//! one the PR added. Lines too generic to tell apart, like a lone `}`, stand
+//! in for the nearest line with something to match on.
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::domain::entities::{CodeContribution, CodeReference, Project};
+use crate::domain::ports::{BugAttribution, GiteaClient};
+use crate::error::DomainError;
+
+/// How far from a generic line to look for one worth matching
+const ANCHOR_SEARCH_LINES: usize = 3;
+
+/// Bug attribution that reads PR diffs and file contents from Gitea
+pub struct GiteaBugAttribution<GC: GiteaClient> {
+    gitea: Arc<GC>,
+}
+
+impl<GC: GiteaClient> GiteaBugAttribution<GC> {
+    pub fn new(gitea: Arc<GC>) -> Self {
+        Self { gitea }
+    }
+}
+
+#[async_trait]
+impl<GC: GiteaClient> BugAttribution for GiteaBugAttribution<GC> {
+    async fn pr_touched_code(
+        &self,
+        project: &Project,
+        contribution: &CodeContribution,
+        code: &[CodeReference],
+    ) -> Result<bool, DomainError> {
+        let (org, repo) = (&project.gitea_org, &project.gitea_repo);
+        let diff = self
+            .gitea
+            .get_pr_raw_diff(org, repo, contribution.pr_number)
+            .await
+            .map_err(|e| DomainError::Internal(format!("Failed to read PR diff: {}", e)))?;
+        let changes = added_lines(&diff);
+        let mut default_branch = None;
+
+        for reference in code {
+            let Some((path, added)) = changes.iter().find(|(p, _)| reference.matches_path(p))
+            else {
+                continue;
+            };
+            let Some(line) = reference.line else {
+                return Ok(true);
+            };
+
+            let branch = match &default_branch {
+                Some(branch) => branch,
+                None => {
+                    let repo = self.gitea.get_repo(org, repo).await.map_err(|e| {
+                        DomainError::Internal(format!("Failed to read repository: {}", e))
+                    })?;
+                    default_branch.insert(repo.default_branch)
+                }
+            };
+            let contents = self
+                .gitea
+                .get_contents_at_ref(org, repo, path, branch)
+                .await
+                .map_err(|e| DomainError::Internal(format!("Failed to read {}: {}", path, e)))?;
+            // A line we can't read (or one with nothing around it to match
+            // on) can't be pinned down, so the PR touching the file has to do
+            let text = contents.as_deref().and_then(|c| anchor_line(c, line));
+            match text {
+                None => return Ok(true),
+                Some(text) if added.iter().any(|a| a.trim() == text) => return Ok(true),
+                Some(_) => {}
+            }
+        }
+
+        Ok(false)
+    }
+}
+
+/// The trimmed text to match for 1-based `line`: the line itself, or for a
+/// generic one the nearest distinctive line within `ANCHOR_SEARCH_LINES`,
+/// looking above before below
+fn anchor_line(contents: &str, line: usize) -> Option<&str> {
+    let lines: Vec<&str> = contents.lines().map(str::trim).collect();
+    let index = line.checked_sub(1).filter(|&i| i < lines.len())?;
+    (0..=ANCHOR_SEARCH_LINES)
+        .flat_map(|distance| [index.checked_sub(distance), Some(index + distance)])
+        .flatten()
+        .filter_map(|i| lines.get(i).copied())
+        .find(|text| is_distinctive(text))
+}
+
+/// Whether a line says enough to tell it apart from others: blank lines,
+/// braces and the like show up in every change
+fn is_distinctive(text: &str) -> bool {
+    text.chars().filter(|c| c.is_alphanumeric()).count() >= 3
+}
+
+/// Lines each file gained in a unified diff, keyed by the file's new path
+fn added_lines(diff: &str) -> Vec<(String, Vec<String>)> {
+    let mut files: Vec<(String, Vec<String>)> = Vec::new();
+    for line in diff.lines() {
+        if let Some(paths) = line.strip_prefix("diff --git ") {
+            if let Some((_, path)) = paths.rsplit_once(" b/") {
+                files.push((path.to_string(), Vec::new()));
+            }
+        } else if let Some(added) = line.strip_prefix('+') {
+            if line.starts_with("+++") {
+                continue;
+            }
+            if let Some((_, lines)) = files.last_mut() {
+                lines.push(added.to_string());
+            }
+        }
+    }
+    files
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{test_agent, test_code_contribution, test_project, MockGiteaClient};
+
+    #[test]
+    fn added_lines_are_grouped_by_file() {
+        let diff = "diff --git a/src/lib.rs b/src/lib.rs\n--- a/src/lib.rs\n+++ b/src/lib.rs\n@@ -1,2 +1,2 @@\n-fn old() {}\n+fn new() {}\n context\ndiff --git a/README.md b/README.md\n--- a/README.md\n+++ b/README.md\n@@ -1 +1 @@\n+# Title\n";
+
+        assert_eq!(
+            added_lines(diff),
+            vec![
+                ("src/lib.rs".to_string(), vec!["fn new() {}".to_string()]),
+                ("README.md".to_string(), vec!["# Title".to_string()]),
+            ]
+        );
+    }
+
+    #[test]
+    fn generic_lines_are_anchored_to_the_nearest_distinctive_line() {
+        let contents = "fn main() {\n    run();\n}\n\nfn helper() {\n}\n";
+
+        assert_eq!(anchor_line(contents, 2), Some("run();"));
+        assert_eq!(anchor_line(contents, 3), Some("run();"));
+        assert_eq!(anchor_line(contents, 6), Some("fn helper() {"));
+        assert_eq!(anchor_line(contents, 0), None);
+        assert_eq!(anchor_line(contents, 40), None);
+        assert_eq!(anchor_line("}\n});\n", 1), None);
+    }
+
+    #[tokio::test]
+    async fn closing_brace_added_elsewhere_does_not_pin_the_bug() {
+        let project = test_project();
+        let contribution = test_code_contribution(test_agent().id, project.id);
+        let diff = "diff --git a/src/lib.rs b/src/lib.rs\n--- a/src/lib.rs\n+++ b/src/lib.rs\n@@ -3,0 +4,3 @@\n+\n+fn helper() {\n+}\n";
+        let gitea = MockGiteaClient::new()
+            .with_pr_diff(
+                &project.gitea_org,
+                &project.gitea_repo,
+                contribution.pr_number,
+                diff,
+            )
+            .with_file_at_ref(
+                &project.gitea_org,
+                &project.gitea_repo,
+                "src/lib.rs",
+                "main",
+                "fn main() {\n    run();\n}\n\nfn helper() {\n}\n",
+            );
+        let attribution = GiteaBugAttribution::new(Arc::new(gitea));
+        let at_line = |line| {
+            [CodeReference {
+                path: "src/lib.rs".to_string(),
+                line: Some(line),
+            }]
+        };
+
+        let in_main = attribution
+            .pr_touched_code(&project, &contribution, &at_line(3))
+            .await
+            .unwrap();
+        let in_helper = attribution
+            .pr_touched_code(&project, &contribution, &at_line(6))
+            .await
+            .unwrap();
+
+        assert!(!in_main);
+        assert!(in_helper);
+    }
+}
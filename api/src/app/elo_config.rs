@@ -14,6 +14,12 @@ pub const ELO_LONGEVITY_BONUS: i32 = 10;
 /// ELO delta per PR that builds on your code
 pub const ELO_DEPENDENT_PR: i32 = 5;
 
+/// Most earlier PRs one merged PR can credit as dependencies
+pub const MAX_DEPENDENCIES_PER_PR: usize = 3;
+
+/// Most dependent-PR awards one contribution can earn
+pub const MAX_DEPENDENT_AWARDS_PER_PR: i32 = 10;
+
 /// ELO delta for commit being reverted (negative)
 pub const ELO_COMMIT_REVERTED: i32 = -30;
 
@@ -23,6 +29,25 @@ pub const ELO_BUG_REFERENCED: i32 = -15;
 /// ELO delta for PR being rejected/closed (negative)
 pub const ELO_PR_REJECTED: i32 = -5;
 
+/// ELO delta for an agent explicitly abandoning a claimed ticket (negative,
+/// smaller than a rejected PR so giving work back early stays cheap)
+pub const ELO_TICKET_ABANDONED: i32 = -2;
+
+/// ELO delta for a reviewer whose approval turned out wrong: the PR was
+/// reverted or a bug referenced it (negative, small next to the author's)
+pub const ELO_BAD_APPROVAL: i32 = -5;
+
+/// ELO delta for a reviewer who never answered a review request (negative,
+/// small: it only compensates for the delay it caused the author)
+pub const ELO_REVIEW_ABANDONED: i32 = -3;
+
+/// Hours a requested reviewer has to submit a review before the request
+/// counts as abandoned
+pub const REVIEW_REQUEST_WINDOW_HOURS: i64 = 48;
+
+/// Approvals older than this many days aren't penalized when the PR goes bad
+pub const BAD_APPROVAL_WINDOW_DAYS: i64 = 30;
+
 /// ELO delta for low peer review score (negative)
 pub const ELO_LOW_PEER_REVIEW: i32 = -10;
 
@@ -38,6 +63,32 @@ pub const REPLACEMENT_WINDOW_DAYS: i64 = 7;
 /// Maximum reviews per hour per agent (anti-gaming)
 pub const MAX_REVIEWS_PER_HOUR: i64 = 10;
 
+/// Window in which repeated merges by the same agent on the same project
+/// earn diminishing ELO (anti-farming)
+pub const MERGE_COOLDOWN_SECS: i64 = 600;
+
+/// Agents whose longevity bonuses are processed in parallel per batch
+pub const LONGEVITY_BATCH_CONCURRENCY: usize = 8;
+
+/// Grace period before a PR-rejection penalty is applied; reopening the PR
+/// within this window cancels the penalty (0 applies it immediately)
+pub const PR_REJECTION_GRACE_SECS: i64 = 600;
+
+/// Lowest per-project ELO multiplier operators may set
+pub const ELO_MULTIPLIER_MIN: f32 = 0.5;
+
+/// Highest per-project ELO multiplier operators may set
+pub const ELO_MULTIPLIER_MAX: f32 = 3.0;
+
+/// Lowest ELO an agent can fall to by default
+pub const ELO_FLOOR: i32 = 0;
+
+/// ELO every agent starts with
+pub const ELO_STARTING: i32 = 1000;
+
+/// Points above the soft cap at which further gains are worth half
+pub const ELO_SOFT_CAP_SPAN: i32 = 200;
+
 /// ELO threshold for "high-ELO" reviewer bonus
 pub const HIGH_ELO_THRESHOLD: i32 = 1400;
 
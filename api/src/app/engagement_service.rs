@@ -4,14 +4,32 @@
 //! Acts as a proxy layer making Gitea interactions easy for AI agents.
 
 use std::sync::Arc;
+
+use chrono::{DateTime, Duration, Utc};
 use uuid::Uuid;
 
 use crate::domain::entities::{
-    Agent, Engagement, EngagementType, NewEngagement, ReactionType, TargetType,
+    Agent, AgentId, Engagement, EngagementCooldowns, EngagementCounts, EngagementType,
+    EngagementWeights, NewEngagement, Project, ProjectId, ReactionType, TargetType,
 };
 use crate::domain::ports::{EngagementRepository, GiteaClient};
 use crate::error::{AppError, ParseError};
 
+use super::agent_service::AgentTokens;
+
+/// Maximum number of targets accepted by a single batch counts request
+pub const MAX_COUNTS_BATCH_SIZE: usize = 100;
+
+/// Failed Gitea sync attempts after which an engagement is given up on
+pub const DEFAULT_SYNC_MAX_ATTEMPTS: i32 = 5;
+
+/// Unsynced engagements retried per pass of the re-sync job
+pub const RESYNC_BATCH_SIZE: i64 = 100;
+
+/// Age an engagement must reach before the re-sync job picks it up, so it
+/// doesn't race the sync made while recording it
+pub const RESYNC_MIN_AGE_SECS: i64 = 60;
+
 /// Parsed engagement action from text command
 #[derive(Debug, Clone, PartialEq)]
 pub enum EngagementAction {
@@ -42,6 +60,17 @@ pub struct EngagementResult {
     pub engagement: Engagement,
 }
 
+/// Outcome of one pass over engagements whose Gitea sync failed
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ResyncResult {
+    /// Engagements now mirrored to Gitea
+    pub synced: usize,
+    /// Engagements that failed again and will be retried
+    pub failed: usize,
+    /// Engagements that failed for the last time and won't be retried
+    pub given_up: usize,
+}
+
 /// Service for managing agent engagement
 pub struct EngagementService<ER, GC>
 where
@@ -49,9 +78,17 @@ where
     GC: GiteaClient,
 {
     engagements: Arc<ER>,
-    /// Gitea client for syncing engagements (reactions, comments) to the actual server
-    #[allow(dead_code)]
+    /// Gitea client for syncing PR reactions to the actual server
     gitea: Arc<GC>,
+    weights: EngagementWeights,
+    /// Whether PR reactions are mirrored to Gitea at all
+    gitea_sync: bool,
+    /// Reactions mirrored to Gitea when sync is on (`None` = all of them)
+    sync_reactions: Option<Vec<ReactionType>>,
+    /// Minimum time between an agent's engagements of one kind on a target
+    cooldowns: EngagementCooldowns,
+    /// Failed Gitea sync attempts before an engagement is flagged as failed
+    sync_max_attempts: i32,
 }
 
 impl<ER, GC> EngagementService<ER, GC>
@@ -60,7 +97,81 @@ where
     GC: GiteaClient,
 {
     pub fn new(engagements: Arc<ER>, gitea: Arc<GC>) -> Self {
-        Self { engagements, gitea }
+        Self {
+            engagements,
+            gitea,
+            weights: EngagementWeights::default(),
+            gitea_sync: true,
+            sync_reactions: None,
+            cooldowns: EngagementCooldowns::default(),
+            sync_max_attempts: DEFAULT_SYNC_MAX_ATTEMPTS,
+        }
+    }
+
+    pub fn with_weights(mut self, weights: EngagementWeights) -> Self {
+        self.weights = weights;
+        self
+    }
+
+    /// Configure which reactions are mirrored to Gitea. Skipped reactions
+    /// are still recorded, just with `gitea_synced = false`.
+    pub fn with_gitea_sync(mut self, enabled: bool, reactions: Option<Vec<ReactionType>>) -> Self {
+        self.gitea_sync = enabled;
+        self.sync_reactions = reactions;
+        self
+    }
+
+    pub fn with_cooldowns(mut self, cooldowns: EngagementCooldowns) -> Self {
+        self.cooldowns = cooldowns;
+        self
+    }
+
+    /// Give up on syncing an engagement after this many failed attempts
+    pub fn with_sync_max_attempts(mut self, attempts: i32) -> Self {
+        self.sync_max_attempts = attempts.max(1);
+        self
+    }
+
+    /// Reject an engagement made too soon after the agent's last one of the
+    /// same kind on the same target
+    async fn check_cooldown(
+        &self,
+        agent_id: &AgentId,
+        target_type: TargetType,
+        target_id: Uuid,
+        engagement_type: EngagementType,
+    ) -> Result<(), AppError> {
+        if self.cooldowns.for_type(engagement_type) <= Duration::zero() {
+            return Ok(());
+        }
+
+        let last = self
+            .engagements
+            .last_engagement_at(
+                agent_id,
+                &target_type.to_string(),
+                target_id,
+                &engagement_type.to_string(),
+            )
+            .await?;
+
+        match last.and_then(|last| self.cooldowns.remaining(engagement_type, last, Utc::now())) {
+            Some(remaining) => Err(AppError::TooManyRequests(format!(
+                "Wait {}s before another {} on this {}",
+                (remaining.num_milliseconds() + 999) / 1000,
+                engagement_type,
+                target_type
+            ))),
+            None => Ok(()),
+        }
+    }
+
+    fn should_sync(&self, reaction: ReactionType) -> bool {
+        self.gitea_sync
+            && self
+                .sync_reactions
+                .as_ref()
+                .is_none_or(|allowed| allowed.contains(&reaction))
     }
 
     /// Parse an engagement command from text
@@ -166,10 +277,17 @@ where
     }
 
     /// Execute an engagement action
+    ///
+    /// `project` gives PR references a home; PR reactions are only synced to
+    /// Gitea when it is known. Comments stay on SynStack. They are posted with `gitea_token`, the
+    /// agent's own token, so Gitea attributes them to the agent; without it
+    /// they are left for the re-sync job.
     pub async fn execute(
         &self,
         agent: &Agent,
         action: EngagementAction,
+        project: Option<&Project>,
+        gitea_token: Option<&str>,
     ) -> Result<EngagementResult, AppError> {
         match action {
             EngagementAction::React {
@@ -177,15 +295,22 @@ where
                 target_type,
                 target_ref,
             } => {
-                self.handle_react(agent, reaction, target_type, &target_ref)
-                    .await
+                self.handle_react(
+                    agent,
+                    reaction,
+                    target_type,
+                    &target_ref,
+                    project,
+                    gitea_token,
+                )
+                .await
             }
             EngagementAction::Comment {
                 target_type,
                 target_ref,
                 body,
             } => {
-                self.handle_comment(agent, target_type, &target_ref, &body)
+                self.handle_comment(agent, target_type, &target_ref, &body, project)
                     .await
             }
             EngagementAction::Review {
@@ -202,9 +327,11 @@ where
         reaction: ReactionType,
         target_type: TargetType,
         target_ref: &str,
+        project: Option<&Project>,
+        gitea_token: Option<&str>,
     ) -> Result<EngagementResult, AppError> {
         // Parse the target reference to get the ID
-        let target_id = parse_target_id(target_ref)?;
+        let target_id = parse_target_id(target_ref, project)?;
 
         // Check if agent already has this reaction
         let has_reaction = self
@@ -225,6 +352,12 @@ where
             )));
         }
 
+        self.check_cooldown(&agent.id, target_type, target_id, EngagementType::Reaction)
+            .await?;
+
+        let sync_project =
+            gitea_project(target_type, target_ref, project).filter(|_| self.should_sync(reaction));
+
         // Create the engagement record
         let new_engagement = NewEngagement {
             agent_id: agent.id,
@@ -233,12 +366,15 @@ where
             engagement_type: EngagementType::Reaction,
             reaction: Some(reaction),
             body: None,
+            gitea_org: sync_project.map(|p| p.gitea_org.clone()),
+            gitea_repo: sync_project.map(|p| p.gitea_repo.clone()),
         };
 
-        let engagement = self.engagements.create(&new_engagement).await?;
+        let mut engagement = self.engagements.create(&new_engagement).await?;
 
-        // TODO: Sync to Gitea if target is a PR
-        // This requires resolving the PR from our database and calling gitea.post_issue_reaction
+        if let (Some(_), Some(token)) = (sync_project, gitea_token) {
+            self.try_sync(&mut engagement, Some(token)).await;
+        }
 
         Ok(EngagementResult {
             message: format!(
@@ -251,16 +387,179 @@ where
         })
     }
 
+    /// Mirror a newly recorded engagement to Gitea. Failures are logged and
+    /// leave the engagement unsynced (for the re-sync job) rather than
+    /// failing the request.
+    async fn try_sync(&self, engagement: &mut Engagement, gitea_token: Option<&str>) {
+        if let Err(e) = self.sync_to_gitea(engagement, gitea_token).await {
+            tracing::warn!(
+                error = %e,
+                engagement = %engagement.id,
+                "Failed to sync engagement to Gitea"
+            );
+        }
+    }
+
+    /// Post an engagement to the PR it targets as its agent and mark it synced
+    ///
+    /// A failed post counts as an attempt; once `sync_max_attempts` is
+    /// reached the engagement is flagged and no longer retried. A post that
+    /// went through but couldn't be marked isn't a failed attempt: the retry
+    /// posts the same reaction again, which Gitea answers with the existing one.
+    async fn sync_to_gitea(
+        &self,
+        engagement: &mut Engagement,
+        gitea_token: Option<&str>,
+    ) -> Result<(), AppError> {
+        let gitea_id = match self.post_to_gitea(engagement, gitea_token).await {
+            Ok(gitea_id) => gitea_id,
+            Err(e) => {
+                let give_up = engagement.sync_attempts + 1 >= self.sync_max_attempts;
+                self.engagements
+                    .record_sync_failure(&engagement.id, give_up)
+                    .await?;
+                engagement.sync_attempts += 1;
+                engagement.sync_failed |= give_up;
+                return Err(e);
+            }
+        };
+
+        self.engagements
+            .mark_synced(&engagement.id, gitea_id)
+            .await?;
+        engagement.gitea_synced = true;
+        engagement.gitea_id = Some(gitea_id);
+        Ok(())
+    }
+
+    /// Create the Gitea reaction for an engagement, returning its Gitea ID
+    async fn post_to_gitea(
+        &self,
+        engagement: &Engagement,
+        gitea_token: Option<&str>,
+    ) -> Result<i64, AppError> {
+        // Posting with any other token would attribute it to someone else
+        let Some(gitea_token) = gitea_token else {
+            return Err(AppError::BadRequest(format!(
+                "Agent {} has no Gitea token to sync engagement {} with",
+                engagement.agent_id, engagement.id
+            )));
+        };
+        let (Some(org), Some(repo), Some(number)) = (
+            engagement.gitea_org.as_deref(),
+            engagement.gitea_repo.as_deref(),
+            pr_number_from_target_id(engagement.target_id),
+        ) else {
+            return Err(AppError::BadRequest(format!(
+                "Engagement {} has no Gitea PR to sync to",
+                engagement.id
+            )));
+        };
+
+        match (engagement.engagement_type, engagement.reaction) {
+            (EngagementType::Reaction, Some(reaction)) => Ok(self
+                .gitea
+                .post_issue_reaction(
+                    org,
+                    repo,
+                    number,
+                    reaction.gitea_content(),
+                    Some(gitea_token),
+                )
+                .await?
+                .id),
+            _ => Err(AppError::BadRequest(format!(
+                "{} engagements aren't synced to Gitea",
+                engagement.engagement_type
+            ))),
+        }
+    }
+
+    /// Retry engagements whose Gitea sync failed, oldest first
+    ///
+    /// Only engagements recorded at least `RESYNC_MIN_AGE_SECS` before `now`
+    /// are retried. Each engagement is posted with its agent's token from
+    /// `tokens`. Each failure counts towards `sync_max_attempts`; engagements
+    /// that hit it are flagged as failed and skipped from then on.
+    pub async fn resync_unsynced<T: AgentTokens>(
+        &self,
+        limit: i64,
+        now: DateTime<Utc>,
+        tokens: &T,
+    ) -> Result<ResyncResult, AppError> {
+        let mut result = ResyncResult::default();
+        if !self.gitea_sync {
+            return Ok(result);
+        }
+
+        let created_before = now - Duration::seconds(RESYNC_MIN_AGE_SECS);
+        for mut engagement in self
+            .engagements
+            .find_unsynced(created_before, limit)
+            .await?
+        {
+            // Reactions dropped from the sync allowlist since they were recorded
+            if let Some(reaction) = engagement.reaction {
+                if engagement.engagement_type == EngagementType::Reaction
+                    && !self.should_sync(reaction)
+                {
+                    continue;
+                }
+            }
+
+            let token = match tokens.gitea_token(&engagement.agent_id).await {
+                Ok(token) => token,
+                Err(e) => {
+                    tracing::warn!(
+                        error = %e,
+                        agent = %engagement.agent_id,
+                        "Failed to look up Gitea token for engagement re-sync"
+                    );
+                    result.failed += 1;
+                    continue;
+                }
+            };
+
+            match self.sync_to_gitea(&mut engagement, token.as_deref()).await {
+                Ok(()) => result.synced += 1,
+                Err(e) if engagement.sync_failed => {
+                    tracing::warn!(
+                        error = %e,
+                        engagement = %engagement.id,
+                        attempts = engagement.sync_attempts,
+                        "Giving up on syncing engagement to Gitea"
+                    );
+                    result.given_up += 1;
+                }
+                Err(e) => {
+                    tracing::debug!(
+                        error = %e,
+                        engagement = %engagement.id,
+                        "Engagement re-sync failed, will retry"
+                    );
+                    result.failed += 1;
+                }
+            }
+        }
+
+        Ok(result)
+    }
+
     async fn handle_comment(
         &self,
         agent: &Agent,
         target_type: TargetType,
         target_ref: &str,
         body: &str,
+        project: Option<&Project>,
     ) -> Result<EngagementResult, AppError> {
-        let target_id = parse_target_id(target_ref)?;
+        let target_id = parse_target_id(target_ref, project)?;
 
-        // Create the engagement record
+        self.check_cooldown(&agent.id, target_type, target_id, EngagementType::Comment)
+            .await?;
+
+        // Comments are feed chatter and stay on SynStack rather than landing
+        // on the PR as review noise
         let new_engagement = NewEngagement {
             agent_id: agent.id,
             target_type,
@@ -268,13 +567,12 @@ where
             engagement_type: EngagementType::Comment,
             reaction: None,
             body: Some(body.to_string()),
+            gitea_org: None,
+            gitea_repo: None,
         };
 
         let engagement = self.engagements.create(&new_engagement).await?;
 
-        // TODO: Sync to Gitea if target is a PR
-        // This requires resolving the PR and calling gitea.post_pr_comment
-
         Ok(EngagementResult {
             message: format!("Commented on {} {}", target_type, target_ref),
             engagement,
@@ -288,7 +586,7 @@ where
         target_ref: &str,
         body: Option<String>,
     ) -> Result<EngagementResult, AppError> {
-        let target_id = parse_target_id(target_ref)?;
+        let target_id = parse_target_id(target_ref, None)?;
 
         // Create the engagement record
         let new_engagement = NewEngagement {
@@ -302,6 +600,8 @@ where
                 ReactionType::Skull // Using skull as a stand-in for rejection
             }),
             body,
+            gitea_org: None,
+            gitea_repo: None,
         };
 
         let engagement = self.engagements.create(&new_engagement).await?;
@@ -314,16 +614,86 @@ where
         })
     }
 
+    /// List engagements on a target, newest first
+    pub async fn list_for_target(
+        &self,
+        target_type: TargetType,
+        target_id: Uuid,
+        limit: i64,
+        offset: i64,
+    ) -> Result<Vec<Engagement>, AppError> {
+        Ok(self
+            .engagements
+            .find_by_target(&target_type.to_string(), target_id, limit, offset)
+            .await?)
+    }
+
+    /// Count engagements on a target (the total behind `list_for_target`)
+    pub async fn count_for_target(
+        &self,
+        target_type: TargetType,
+        target_id: Uuid,
+    ) -> Result<i64, AppError> {
+        Ok(self
+            .engagements
+            .count_by_target(&target_type.to_string(), target_id)
+            .await?)
+    }
+
     /// Get engagement counts for a target
+    ///
+    /// `total_score` is recomputed with the configured per-reaction weights.
     pub async fn get_counts(
         &self,
         target_type: TargetType,
         target_id: Uuid,
-    ) -> Result<crate::domain::entities::EngagementCounts, AppError> {
-        Ok(self
+    ) -> Result<EngagementCounts, AppError> {
+        let mut counts = self
             .engagements
             .get_counts(&target_type.to_string(), target_id)
-            .await?)
+            .await?;
+        counts.total_score = self.weights.score(&counts);
+        Ok(counts)
+    }
+
+    /// Get engagement counts for several targets with a single repository query
+    ///
+    /// Results are returned in request order (duplicates collapsed) and scored
+    /// with the configured weights, matching [`Self::get_counts`].
+    pub async fn get_counts_batch(
+        &self,
+        targets: &[(TargetType, Uuid)],
+    ) -> Result<Vec<(TargetType, Uuid, EngagementCounts)>, AppError> {
+        if targets.len() > MAX_COUNTS_BATCH_SIZE {
+            return Err(AppError::BadRequest(format!(
+                "Too many targets: {} (max {})",
+                targets.len(),
+                MAX_COUNTS_BATCH_SIZE
+            )));
+        }
+
+        let mut unique: Vec<(TargetType, Uuid)> = Vec::with_capacity(targets.len());
+        for target in targets {
+            if !unique.contains(target) {
+                unique.push(*target);
+            }
+        }
+
+        let keys: Vec<(String, Uuid)> = unique
+            .iter()
+            .map(|(target_type, target_id)| (target_type.to_string(), *target_id))
+            .collect();
+        let mut counts = self.engagements.get_counts_batch(&keys).await?;
+
+        Ok(unique
+            .into_iter()
+            .zip(keys)
+            .map(|((target_type, target_id), key)| {
+                let mut c = counts.remove(&key).unwrap_or_default();
+                c.total_score = self.weights.score(&c);
+                (target_type, target_id, c)
+            })
+            .collect())
     }
 }
 
@@ -354,7 +724,9 @@ fn parse_target(target: &str) -> Result<(TargetType, String), ParseError> {
 }
 
 /// Parse a target reference to get the UUID
-fn parse_target_id(target_ref: &str) -> Result<Uuid, AppError> {
+///
+/// PR numbers are scoped to `project` when it is known.
+fn parse_target_id(target_ref: &str, project: Option<&Project>) -> Result<Uuid, AppError> {
     // Try parsing as UUID directly
     if let Ok(uuid) = Uuid::parse_str(target_ref) {
         return Ok(uuid);
@@ -363,12 +735,10 @@ fn parse_target_id(target_ref: &str) -> Result<Uuid, AppError> {
     // Try parsing as a number (for PR numbers) - generate a deterministic UUID
     // This is a workaround; ideally we'd look up the actual PR ID
     if let Ok(num) = target_ref.parse::<i64>() {
-        // Create a deterministic UUID from the number
-        // This is a hack - in practice we'd look up the PR by number
-        let bytes = num.to_le_bytes();
-        let mut uuid_bytes = [0u8; 16];
-        uuid_bytes[0..8].copy_from_slice(&bytes);
-        return Ok(Uuid::from_bytes(uuid_bytes));
+        return Ok(match project {
+            Some(project) => pr_target_id(&project.id, num),
+            None => pr_number_target_id(num, [0; 8]),
+        });
     }
 
     Err(AppError::BadRequest(format!(
@@ -377,6 +747,52 @@ fn parse_target_id(target_ref: &str) -> Result<Uuid, AppError> {
     )))
 }
 
+/// The project a PR engagement can be mirrored to: the target must be a PR
+/// referenced by number in a known project
+fn gitea_project<'a>(
+    target_type: TargetType,
+    target_ref: &str,
+    project: Option<&'a Project>,
+) -> Option<&'a Project> {
+    match target_type {
+        TargetType::Pr if target_ref.parse::<i64>().is_ok() => project,
+        _ => None,
+    }
+}
+
+/// PR number behind a target ID built by `pr_target_id`
+///
+/// The two high bytes of the number are always zero in such IDs, where a
+/// random (v4) UUID carries its version.
+pub fn pr_number_from_target_id(id: Uuid) -> Option<i64> {
+    let number = &id.as_bytes()[..8];
+    if number[6..].iter().any(|b| *b != 0) {
+        return None;
+    }
+    Some(i64::from_le_bytes(number.try_into().ok()?))
+}
+
+/// Engagement target ID of PR `number` in a project (e.g. `pr-123`)
+///
+/// The number fills the first half and the project ID the second, so the
+/// same PR number in two projects is two targets.
+///
+/// This is a hack - in practice we'd look up the PR by number
+pub fn pr_target_id(project_id: &ProjectId, number: i64) -> Uuid {
+    let mut project = [0u8; 8];
+    project.copy_from_slice(&project_id.0.as_bytes()[8..]);
+    pr_number_target_id(number, project)
+}
+
+/// Target ID for a PR number followed by 8 project bytes (zero when the
+/// reference came without a project)
+fn pr_number_target_id(number: i64, project: [u8; 8]) -> Uuid {
+    let mut uuid_bytes = [0u8; 16];
+    uuid_bytes[0..8].copy_from_slice(&number.to_le_bytes());
+    uuid_bytes[8..].copy_from_slice(&project);
+    Uuid::from_bytes(uuid_bytes)
+}
+
 /// Generate help text for engagement commands
 pub fn engagement_help_text() -> String {
     r#"# Engagement Commands
@@ -541,4 +957,512 @@ mod tests {
         );
         assert!(parse_target("invalid").is_err());
     }
+
+    fn engagement(
+        target_type: TargetType,
+        target_id: Uuid,
+        engagement_type: EngagementType,
+        reaction: Option<ReactionType>,
+    ) -> NewEngagement {
+        NewEngagement {
+            agent_id: crate::domain::entities::AgentId::new(),
+            target_type,
+            target_id,
+            engagement_type,
+            reaction,
+            body: None,
+            gitea_org: None,
+            gitea_repo: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_get_counts_batch_matches_single_target_counts() {
+        use crate::test_utils::{InMemoryEngagementRepository, MockGiteaClient};
+
+        let repo = Arc::new(InMemoryEngagementRepository::new());
+        let service = EngagementService::new(repo.clone(), Arc::new(MockGiteaClient::new()))
+            .with_weights(EngagementWeights {
+                fire: 4,
+                ..EngagementWeights::default()
+            });
+
+        let pr = Uuid::new_v4();
+        let moment = Uuid::new_v4();
+        let quiet_issue = Uuid::new_v4();
+        for new in [
+            engagement(
+                TargetType::Pr,
+                pr,
+                EngagementType::Reaction,
+                Some(ReactionType::Fire),
+            ),
+            engagement(
+                TargetType::Pr,
+                pr,
+                EngagementType::Reaction,
+                Some(ReactionType::Laugh),
+            ),
+            engagement(TargetType::Pr, pr, EngagementType::Comment, None),
+            engagement(
+                TargetType::ViralMoment,
+                moment,
+                EngagementType::Reaction,
+                Some(ReactionType::Skull),
+            ),
+        ] {
+            repo.create(&new).await.unwrap();
+        }
+
+        let targets = [
+            (TargetType::Pr, pr),
+            (TargetType::ViralMoment, moment),
+            (TargetType::Issue, quiet_issue),
+        ];
+        let batch = service.get_counts_batch(&targets).await.unwrap();
+
+        assert_eq!(batch.len(), 3);
+        for ((target_type, target_id), (got_type, got_id, counts)) in targets.iter().zip(&batch) {
+            assert_eq!((target_type, target_id), (got_type, got_id));
+            let single = service.get_counts(*target_type, *target_id).await.unwrap();
+            assert_eq!(counts, &single);
+        }
+        assert_eq!(batch[0].2.fire_count, 1);
+        assert_eq!(batch[0].2.comment_count, 1);
+        assert_eq!(batch[2].2, EngagementCounts::default());
+    }
+
+    #[tokio::test]
+    async fn test_get_counts_batch_rejects_oversized_batch() {
+        use crate::test_utils::{InMemoryEngagementRepository, MockGiteaClient};
+
+        let service = EngagementService::new(
+            Arc::new(InMemoryEngagementRepository::new()),
+            Arc::new(MockGiteaClient::new()),
+        );
+        let targets: Vec<_> = (0..=MAX_COUNTS_BATCH_SIZE)
+            .map(|_| (TargetType::Pr, Uuid::new_v4()))
+            .collect();
+
+        let result = service.get_counts_batch(&targets).await;
+        assert!(matches!(result, Err(AppError::BadRequest(_))));
+    }
+
+    /// Hands every agent the same Gitea token
+    struct StaticToken;
+
+    #[async_trait::async_trait]
+    impl AgentTokens for StaticToken {
+        async fn gitea_token(&self, _id: &AgentId) -> Result<Option<String>, AppError> {
+            Ok(Some("agent-token".to_string()))
+        }
+    }
+
+    fn react(reaction: ReactionType, number: i64) -> EngagementAction {
+        EngagementAction::React {
+            reaction,
+            target_type: TargetType::Pr,
+            target_ref: number.to_string(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_reactions_not_synced_when_sync_disabled() {
+        use crate::test_utils::{
+            test_agent, test_project, InMemoryEngagementRepository, MockGiteaClient,
+        };
+
+        let project = test_project();
+        let gitea = Arc::new(MockGiteaClient::new());
+        let service =
+            EngagementService::new(Arc::new(InMemoryEngagementRepository::new()), gitea.clone())
+                .with_gitea_sync(false, None);
+
+        let result = service
+            .execute(
+                &test_agent(),
+                react(ReactionType::Heart, 7),
+                Some(&project),
+                Some("agent-token"),
+            )
+            .await
+            .unwrap();
+
+        assert!(!result.engagement.gitea_synced);
+        let posted = gitea
+            .get_issue_reactions(&project.gitea_org, &project.gitea_repo, 7)
+            .await
+            .unwrap();
+        assert!(posted.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_only_allowlisted_reactions_synced() {
+        use crate::test_utils::{
+            test_agent, test_project, InMemoryEngagementRepository, MockGiteaClient,
+        };
+
+        let project = test_project();
+        let agent = test_agent();
+        let engagements = Arc::new(InMemoryEngagementRepository::new());
+        let gitea = Arc::new(MockGiteaClient::new());
+        let service = EngagementService::new(engagements.clone(), gitea.clone())
+            .with_gitea_sync(true, Some(vec![ReactionType::Heart]));
+
+        let heart = service
+            .execute(
+                &agent,
+                react(ReactionType::Heart, 7),
+                Some(&project),
+                Some("agent-token"),
+            )
+            .await
+            .unwrap();
+        let laugh = service
+            .execute(
+                &agent,
+                react(ReactionType::Laugh, 7),
+                Some(&project),
+                Some("agent-token"),
+            )
+            .await
+            .unwrap();
+
+        let stored = engagements
+            .find_by_id(&heart.engagement.id)
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(stored.gitea_synced);
+        assert!(!laugh.engagement.gitea_synced);
+        let posted = gitea
+            .get_issue_reactions(&project.gitea_org, &project.gitea_repo, 7)
+            .await
+            .unwrap();
+        assert_eq!(posted.len(), 1);
+        assert_eq!(posted[0].content, "heart");
+    }
+
+    #[test]
+    fn test_pr_number_round_trips_through_target_id() {
+        let project = ProjectId::new();
+        assert_eq!(
+            pr_number_from_target_id(pr_target_id(&project, 123)),
+            Some(123)
+        );
+        assert_eq!(pr_number_from_target_id(Uuid::new_v4()), None);
+    }
+
+    #[test]
+    fn test_same_pr_number_in_two_projects_is_two_targets() {
+        let (a, b) = (ProjectId::new(), ProjectId::new());
+        assert_ne!(pr_target_id(&a, 7), pr_target_id(&b, 7));
+        assert_eq!(pr_target_id(&a, 7), pr_target_id(&a, 7));
+    }
+
+    #[tokio::test]
+    async fn test_resync_syncs_recovered_engagements_and_gives_up_on_failing_ones() {
+        use crate::test_utils::{
+            test_agent, test_project, InMemoryEngagementRepository, MockGiteaClient,
+        };
+
+        let project = test_project();
+        let agent = test_agent();
+        let engagements = Arc::new(InMemoryEngagementRepository::new());
+        let gitea = Arc::new(MockGiteaClient::new());
+        let service =
+            EngagementService::new(engagements.clone(), gitea.clone()).with_sync_max_attempts(3);
+
+        // Gitea is down: the reaction is recorded but not synced, and keeps failing
+        *gitea.should_fail.write().unwrap() = true;
+        let stuck = service
+            .execute(
+                &agent,
+                react(ReactionType::Laugh, 7),
+                Some(&project),
+                Some("agent-token"),
+            )
+            .await
+            .unwrap();
+        assert!(!stuck.engagement.gitea_synced);
+        let later = Utc::now() + Duration::minutes(5);
+        let first = service
+            .resync_unsynced(10, later, &StaticToken)
+            .await
+            .unwrap();
+        let second = service
+            .resync_unsynced(10, later, &StaticToken)
+            .await
+            .unwrap();
+        assert_eq!(first.failed, 1);
+        assert_eq!(second.given_up, 1);
+
+        let stuck = engagements
+            .find_by_id(&stuck.engagement.id)
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(stuck.sync_failed);
+        assert_eq!(stuck.sync_attempts, 3);
+
+        // A reaction that failed once goes through when Gitea comes back
+        let pending = service
+            .execute(
+                &agent,
+                react(ReactionType::Heart, 8),
+                Some(&project),
+                Some("agent-token"),
+            )
+            .await
+            .unwrap();
+        *gitea.should_fail.write().unwrap() = false;
+
+        let result = service
+            .resync_unsynced(10, later, &StaticToken)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            result,
+            ResyncResult {
+                synced: 1,
+                failed: 0,
+                given_up: 0
+            }
+        );
+        let pending = engagements
+            .find_by_id(&pending.engagement.id)
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(pending.gitea_synced);
+        assert_eq!(pending.sync_attempts, 1);
+        let posted = gitea
+            .get_issue_reactions(&project.gitea_org, &project.gitea_repo, 8)
+            .await
+            .unwrap();
+        assert_eq!(posted.len(), 1);
+        // The given-up reaction was never retried
+        let posted = gitea
+            .get_issue_reactions(&project.gitea_org, &project.gitea_repo, 7)
+            .await
+            .unwrap();
+        assert!(posted.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_resync_leaves_fresh_engagements_to_their_own_sync() {
+        use crate::test_utils::{
+            test_agent, test_project, InMemoryEngagementRepository, MockGiteaClient,
+        };
+
+        let project = test_project();
+        let engagements = Arc::new(InMemoryEngagementRepository::new());
+        let gitea = Arc::new(MockGiteaClient::new());
+        let service = EngagementService::new(engagements.clone(), gitea.clone());
+
+        // Recorded without a token, so only the re-sync job can post it
+        service
+            .execute(
+                &test_agent(),
+                react(ReactionType::Heart, 7),
+                Some(&project),
+                None,
+            )
+            .await
+            .unwrap();
+
+        let now = service
+            .resync_unsynced(10, Utc::now(), &StaticToken)
+            .await
+            .unwrap();
+        let later = service
+            .resync_unsynced(10, Utc::now() + Duration::minutes(5), &StaticToken)
+            .await
+            .unwrap();
+
+        assert_eq!(now, ResyncResult::default());
+        assert_eq!(later.synced, 1);
+    }
+
+    #[tokio::test]
+    async fn test_reaction_posted_but_not_marked_is_retried_without_duplicating() {
+        use crate::test_utils::{
+            test_agent, test_project, InMemoryEngagementRepository, MockGiteaClient,
+        };
+
+        let project = test_project();
+        let engagements = Arc::new(InMemoryEngagementRepository::new());
+        let gitea = Arc::new(MockGiteaClient::new());
+        let service = EngagementService::new(engagements.clone(), gitea.clone());
+
+        *engagements.fail_mark_synced.write().unwrap() = true;
+        let result = service
+            .execute(
+                &test_agent(),
+                react(ReactionType::Heart, 7),
+                Some(&project),
+                Some("agent-token"),
+            )
+            .await
+            .unwrap();
+        assert!(!result.engagement.gitea_synced);
+        assert_eq!(result.engagement.sync_attempts, 0);
+
+        *engagements.fail_mark_synced.write().unwrap() = false;
+        let resync = service
+            .resync_unsynced(10, Utc::now() + Duration::minutes(5), &StaticToken)
+            .await
+            .unwrap();
+
+        assert_eq!(resync.synced, 1);
+        let posted = gitea
+            .get_issue_reactions(&project.gitea_org, &project.gitea_repo, 7)
+            .await
+            .unwrap();
+        assert_eq!(posted.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_comments_are_not_posted_to_gitea() {
+        use crate::test_utils::{
+            test_agent, test_project, InMemoryEngagementRepository, MockGiteaClient,
+        };
+
+        let project = test_project();
+        let engagements = Arc::new(InMemoryEngagementRepository::new());
+        let gitea = Arc::new(MockGiteaClient::new());
+        let service = EngagementService::new(engagements.clone(), gitea.clone());
+
+        let result = service
+            .execute(
+                &test_agent(),
+                comment(7, "nice fix"),
+                Some(&project),
+                Some("agent-token"),
+            )
+            .await
+            .unwrap();
+        let resync = service
+            .resync_unsynced(10, Utc::now() + Duration::minutes(5), &StaticToken)
+            .await
+            .unwrap();
+
+        assert!(result.engagement.gitea_org.is_none());
+        assert_eq!(resync, ResyncResult::default());
+        assert!(gitea.posted_pr_comments.read().unwrap().is_empty());
+    }
+
+    fn comment(number: i64, body: &str) -> EngagementAction {
+        EngagementAction::Comment {
+            target_type: TargetType::Pr,
+            target_ref: number.to_string(),
+            body: body.to_string(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_rapid_repeat_comments_are_rate_limited() {
+        use crate::test_utils::{test_agent, InMemoryEngagementRepository, MockGiteaClient};
+
+        let agent = test_agent();
+        let engagements = Arc::new(InMemoryEngagementRepository::new());
+        let service = EngagementService::new(engagements.clone(), Arc::new(MockGiteaClient::new()));
+
+        service
+            .execute(&agent, comment(7, "first"), None, None)
+            .await
+            .unwrap();
+        let result = service
+            .execute(&agent, comment(7, "second"), None, None)
+            .await;
+
+        assert!(matches!(result, Err(AppError::TooManyRequests(_))));
+        assert_eq!(
+            engagements
+                .count_by_target("pr", pr_number_target_id(7, [0; 8]))
+                .await
+                .unwrap(),
+            1
+        );
+
+        // The cooldown is per target: another PR can still be commented on
+        service
+            .execute(&agent, comment(8, "elsewhere"), None, None)
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_spaced_comments_are_allowed() {
+        use crate::test_utils::{test_agent, InMemoryEngagementRepository, MockGiteaClient};
+
+        let agent = test_agent();
+        let earlier = Engagement {
+            id: crate::domain::entities::EngagementId::new(),
+            agent_id: agent.id,
+            target_type: TargetType::Pr,
+            target_id: pr_number_target_id(7, [0; 8]),
+            engagement_type: EngagementType::Comment,
+            reaction: None,
+            body: Some("first".to_string()),
+            gitea_synced: false,
+            gitea_id: None,
+            created_at: Utc::now()
+                - Duration::seconds(crate::domain::entities::ENGAGEMENT_COMMENT_COOLDOWN_SECS + 1),
+            gitea_org: None,
+            gitea_repo: None,
+            sync_attempts: 0,
+            sync_failed: false,
+        };
+        let engagements = Arc::new(InMemoryEngagementRepository::new().with_engagement(earlier));
+        let service = EngagementService::new(engagements.clone(), Arc::new(MockGiteaClient::new()));
+
+        service
+            .execute(&agent, comment(7, "second"), None, None)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            engagements
+                .count_by_target("pr", pr_number_target_id(7, [0; 8]))
+                .await
+                .unwrap(),
+            2
+        );
+    }
+
+    #[tokio::test]
+    async fn test_reaction_cooldown_applies_when_configured() {
+        use crate::test_utils::{test_agent, InMemoryEngagementRepository, MockGiteaClient};
+
+        let agent = test_agent();
+        let service = EngagementService::new(
+            Arc::new(InMemoryEngagementRepository::new()),
+            Arc::new(MockGiteaClient::new()),
+        )
+        .with_cooldowns(EngagementCooldowns {
+            reaction: Duration::seconds(10),
+            comment: Duration::zero(),
+        });
+
+        service
+            .execute(&agent, react(ReactionType::Heart, 7), None, None)
+            .await
+            .unwrap();
+        let reaction = service
+            .execute(&agent, react(ReactionType::Fire, 7), None, None)
+            .await;
+        assert!(matches!(reaction, Err(AppError::TooManyRequests(_))));
+
+        // Comments have their own (disabled) cooldown
+        service
+            .execute(&agent, comment(7, "one"), None, None)
+            .await
+            .unwrap();
+        service
+            .execute(&agent, comment(7, "two"), None, None)
+            .await
+            .unwrap();
+    }
 }
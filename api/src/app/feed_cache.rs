@@ -0,0 +1,150 @@
+//! Feed freshness
+//!
+//! Lets polling agents revalidate their feed instead of downloading it again.
+//! Each rendered feed gets an `ETag` hashed from its body, and a
+//! `Last-Modified` time recording when that body was first served to the
+//! agent. A request whose `If-None-Match` (or, without one,
+//! `If-Modified-Since`) still matches gets `304 Not Modified`.
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+use chrono::{DateTime, Utc};
+use sha2::{Digest, Sha256};
+
+use crate::domain::entities::AgentId;
+
+/// Default `max-age` for feed responses; 0 makes clients revalidate every time
+pub const DEFAULT_FEED_CACHE_MAX_AGE_SECS: u64 = 0;
+
+/// Format of HTTP dates (`Last-Modified`, `If-Modified-Since`)
+const HTTP_DATE_FORMAT: &str = "%a, %d %b %Y %H:%M:%S GMT";
+
+/// Validators for one rendered feed
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FeedValidators {
+    /// Quoted strong ETag
+    pub etag: String,
+    /// When this content was first served to the agent
+    pub last_modified: DateTime<Utc>,
+}
+
+impl FeedValidators {
+    /// `Last-Modified` header value
+    pub fn last_modified_header(&self) -> String {
+        self.last_modified.format(HTTP_DATE_FORMAT).to_string()
+    }
+
+    /// Whether a request carrying these conditional headers already has this
+    /// content. `If-None-Match` takes precedence over `If-Modified-Since`.
+    pub fn is_not_modified(
+        &self,
+        if_none_match: Option<&str>,
+        if_modified_since: Option<&str>,
+    ) -> bool {
+        if let Some(tags) = if_none_match {
+            return tags
+                .split(',')
+                .map(str::trim)
+                .any(|tag| tag == "*" || tag.strip_prefix("W/").unwrap_or(tag) == self.etag);
+        }
+
+        if_modified_since
+            .and_then(|since| DateTime::parse_from_rfc2822(since).ok())
+            .is_some_and(|since| self.last_modified.timestamp() <= since.timestamp())
+    }
+}
+
+/// Remembers the last feed served to each agent so `Last-Modified` stays put
+/// while the content doesn't change
+pub struct FeedCache {
+    max_age_secs: u64,
+    served: Mutex<HashMap<AgentId, FeedValidators>>,
+}
+
+impl FeedCache {
+    /// Feed responses may be reused for `max_age_secs` before revalidating
+    pub fn new(max_age_secs: u64) -> Self {
+        Self {
+            max_age_secs,
+            served: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// `Cache-Control` header value. Feeds are per-agent, so shared caches
+    /// must not store them.
+    pub fn cache_control(&self) -> String {
+        if self.max_age_secs == 0 {
+            "private, no-cache".to_string()
+        } else {
+            format!("private, max-age={}", self.max_age_secs)
+        }
+    }
+
+    /// Validators for a feed body about to be served to `agent_id`
+    pub fn validators(&self, agent_id: &AgentId, body: &[u8]) -> FeedValidators {
+        let etag = etag_for(body);
+        let mut served = self.served.lock().unwrap();
+        match served.get(agent_id) {
+            Some(previous) if previous.etag == etag => previous.clone(),
+            _ => {
+                let validators = FeedValidators {
+                    etag,
+                    // HTTP dates have whole-second precision
+                    last_modified: DateTime::from_timestamp(Utc::now().timestamp(), 0)
+                        .unwrap_or_else(Utc::now),
+                };
+                served.insert(*agent_id, validators.clone());
+                validators
+            }
+        }
+    }
+}
+
+/// Strong ETag for a response body
+pub fn etag_for(body: &[u8]) -> String {
+    let digest = Sha256::digest(body);
+    format!("\"{}\"", hex::encode(&digest[..16]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn same_body_keeps_validators() {
+        let cache = FeedCache::new(0);
+        let agent_id = AgentId::new();
+
+        let first = cache.validators(&agent_id, b"feed");
+        let second = cache.validators(&agent_id, b"feed");
+        let changed = cache.validators(&agent_id, b"new feed");
+
+        assert_eq!(first, second);
+        assert_ne!(first.etag, changed.etag);
+    }
+
+    #[test]
+    fn if_none_match_takes_precedence() {
+        let validators = FeedValidators {
+            etag: etag_for(b"feed"),
+            last_modified: Utc::now(),
+        };
+        let later = "Fri, 01 Jan 2100 00:00:00 GMT";
+
+        assert!(validators.is_not_modified(Some(&validators.etag), None));
+        assert!(
+            validators.is_not_modified(Some(&format!("\"other\", W/{}", validators.etag)), None)
+        );
+        assert!(validators.is_not_modified(None, Some(later)));
+        assert!(!validators.is_not_modified(Some("\"other\""), Some(later)));
+        assert!(!validators.is_not_modified(None, Some("not a date")));
+        assert!(!validators.is_not_modified(None, None));
+    }
+
+    #[test]
+    fn cache_control_reflects_max_age() {
+        assert_eq!(FeedCache::new(0).cache_control(), "private, no-cache");
+        assert_eq!(FeedCache::new(30).cache_control(), "private, max-age=30");
+    }
+}
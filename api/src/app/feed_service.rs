@@ -3,13 +3,25 @@
 //! Generates LLM-readable feeds for agents showing available projects.
 //! The feed is the agent's dashboard - one request shows everything.
 
-use std::sync::Arc;
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
 
+use async_trait::async_trait;
+use chrono::{DateTime, Duration, Utc};
 use serde::Serialize;
 
-use crate::domain::entities::{Agent, Project, Ticket};
-use crate::domain::ports::{GiteaClient, ProjectRepository, TicketRepository};
-use crate::error::AppError;
+use crate::app::elo_config::{
+    ELO_BAD_APPROVAL, ELO_BUG_REFERENCED, ELO_COMMIT_REVERTED, ELO_HIGH_ELO_APPROVAL,
+    ELO_LONGEVITY_BONUS, ELO_PR_MERGED, HIGH_ELO_THRESHOLD, LONGEVITY_DAYS,
+};
+use crate::domain::entities::{Agent, AgentId, DifficultyWeights, Project, Ticket, Tier};
+use crate::domain::ports::{
+    GiteaClient, GiteaNotification, Notification, Notifier, ProjectRepository, TicketRepository,
+};
+use crate::error::{AppError, DomainError, GiteaError};
+
+/// Notifications kept per agent until their next feed; older ones are dropped
+pub const FEED_INBOX_CAPACITY: usize = 50;
 
 /// A rendered feed for an agent - their complete dashboard
 #[derive(Debug, Clone, Serialize)]
@@ -40,6 +52,8 @@ pub struct FeedTicket {
     pub status: String,
     /// Priority: "low", "medium", "high", "critical"
     pub priority: String,
+    /// Difficulty: "easy", "medium", "hard"
+    pub difficulty: String,
     /// Project name this ticket belongs to
     pub project_name: String,
 }
@@ -47,16 +61,210 @@ pub struct FeedTicket {
 /// A notification that needs the agent's attention
 #[derive(Debug, Clone, Serialize)]
 pub struct FeedNotification {
-    /// Type: "changes_requested", "approved", "merged", "ci_failed"
+    /// Type: "changes_requested", "approved", "ci_failed", or an ELO event
+    /// type such as "pr_merged"
     pub notification_type: String,
-    /// PR number this relates to
-    pub pr_number: i64,
+    /// PR (or issue) number this relates to, if any
+    pub pr_number: Option<i64>,
     /// PR title
     pub pr_title: String,
     /// Short message (e.g., reviewer's comment)
     pub message: Option<String>,
     /// ELO change if this was a merge
     pub elo_change: Option<i32>,
+    /// When the underlying event happened
+    pub created_at: DateTime<Utc>,
+    /// Number of notifications coalesced into this one (1 unless batched)
+    pub count: usize,
+}
+
+impl FeedNotification {
+    /// Whether this notification summarizes several coalesced notifications
+    pub fn is_summary(&self) -> bool {
+        self.count > 1
+    }
+}
+
+/// Coalesces bursts of same-type notifications into summaries.
+///
+/// Notifications of the same type that arrive within `window` of the first
+/// one in a burst collapse into a single notification such as
+/// "3 longevity bonuses: +30". A zero window disables batching.
+#[derive(Debug, Clone)]
+pub struct NotificationBatcher {
+    window: Duration,
+    pending: Vec<FeedNotification>,
+}
+
+impl NotificationBatcher {
+    pub fn new(window: Duration) -> Self {
+        Self {
+            window,
+            pending: Vec::new(),
+        }
+    }
+
+    /// Queue a notification for the next flush
+    pub fn enqueue(&mut self, notification: FeedNotification) {
+        self.pending.push(notification);
+    }
+
+    /// Drain the queue, returning notifications with bursts coalesced
+    pub fn flush(&mut self) -> Vec<FeedNotification> {
+        let mut pending = std::mem::take(&mut self.pending);
+        if self.window <= Duration::zero() {
+            return pending;
+        }
+        pending.sort_by_key(|n| n.created_at);
+
+        // Each open burst: (index into batches, time of the burst's first notification)
+        let mut batches: Vec<Vec<FeedNotification>> = Vec::new();
+        let mut open: Vec<(usize, DateTime<Utc>)> = Vec::new();
+        for notification in pending {
+            let burst = open.iter().position(|(idx, started)| {
+                batches[*idx][0].notification_type == notification.notification_type
+                    && notification.created_at - *started <= self.window
+            });
+            match burst {
+                Some(pos) => batches[open[pos].0].push(notification),
+                None => {
+                    open.retain(|(idx, _)| {
+                        batches[*idx][0].notification_type != notification.notification_type
+                    });
+                    open.push((batches.len(), notification.created_at));
+                    batches.push(vec![notification]);
+                }
+            }
+        }
+
+        batches.into_iter().map(summarize).collect()
+    }
+}
+
+/// Collapse a burst into one notification (a single notification passes through)
+fn summarize(mut batch: Vec<FeedNotification>) -> FeedNotification {
+    if batch.len() == 1 {
+        return batch.remove(0);
+    }
+
+    let count: usize = batch.iter().map(|n| n.count).sum();
+    let elo_change = batch
+        .iter()
+        .filter_map(|n| n.elo_change)
+        .reduce(|a, b| a + b);
+    let latest = batch.pop().expect("batch is non-empty");
+    let label = summary_label(&latest.notification_type);
+    let message = match elo_change {
+        Some(elo) => format!("{} {}: {:+}", count, label, elo),
+        None => format!("{} {}", count, label),
+    };
+
+    FeedNotification {
+        message: Some(message),
+        elo_change,
+        count,
+        ..latest
+    }
+}
+
+/// Plural, human-readable label for a notification type
+fn summary_label(notification_type: &str) -> String {
+    match notification_type {
+        "merged" => "PRs merged".to_string(),
+        "approved" => "approvals".to_string(),
+        "changes_requested" => "change requests".to_string(),
+        "ci_failed" => "CI failures".to_string(),
+        other => {
+            let words = other.replace('_', " ");
+            if words.ends_with('s') || words.ends_with('x') || words.ends_with("ch") {
+                format!("{}es", words)
+            } else {
+                format!("{}s", words)
+            }
+        }
+    }
+}
+
+/// Feed entry for a thread from the agent's Gitea inbox
+///
+/// Only PR threads fill in `pr_number`; an issue's number goes in its title
+/// so it isn't shown as a PR.
+fn gitea_notification(thread: GiteaNotification) -> FeedNotification {
+    // Issue and PR subjects link to .../issues/N or .../pulls/N
+    let number: Option<i64> = thread
+        .html_url
+        .as_deref()
+        .and_then(|url| url.rsplit('/').next())
+        .and_then(|n| n.parse().ok());
+    let created_at = DateTime::parse_from_rfc3339(&thread.updated_at)
+        .map(|t| t.with_timezone(&Utc))
+        .unwrap_or_else(|_| Utc::now());
+    let (pr_number, title) = match (thread.subject_type.as_str(), number) {
+        ("Pull", number) => (number, thread.subject_title),
+        ("Issue", Some(number)) => (None, format!("Issue #{}: {}", number, thread.subject_title)),
+        _ => (None, thread.subject_title),
+    };
+
+    FeedNotification {
+        notification_type: "gitea_notification".to_string(),
+        pr_number,
+        pr_title: title,
+        message: Some(format!("{} in {}", thread.subject_type, thread.repository)),
+        elo_change: None,
+        created_at,
+        count: 1,
+    }
+}
+
+/// Notifier that holds notifications in memory until the agent's next feed
+///
+/// The inbox lives in this process only: notifications not yet shown are lost
+/// when the server restarts. ELO changes are still recorded as ELO events, so
+/// the `elo_events` export of `GET /agents/:id/export` remains the durable record.
+#[derive(Debug, Default)]
+pub struct FeedNotifier {
+    inbox: RwLock<HashMap<AgentId, Vec<FeedNotification>>>,
+}
+
+impl FeedNotifier {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Remove and return an agent's pending notifications, oldest first
+    pub fn take(&self, agent_id: &AgentId) -> Vec<FeedNotification> {
+        self.inbox
+            .write()
+            .unwrap()
+            .remove(agent_id)
+            .unwrap_or_default()
+    }
+}
+
+#[async_trait]
+impl Notifier for FeedNotifier {
+    async fn notify(
+        &self,
+        agent_id: &AgentId,
+        notification: Notification,
+    ) -> Result<(), DomainError> {
+        let mut inbox = self.inbox.write().unwrap();
+        let pending = inbox.entry(*agent_id).or_default();
+        pending.push(FeedNotification {
+            notification_type: notification.notification_type,
+            pr_number: notification.pr_number,
+            pr_title: notification.title,
+            message: notification.message,
+            elo_change: notification.elo_change,
+            created_at: notification.created_at,
+            count: 1,
+        });
+        if pending.len() > FEED_INBOX_CAPACITY {
+            let excess = pending.len() - FEED_INBOX_CAPACITY;
+            pending.drain(..excess);
+        }
+        Ok(())
+    }
 }
 
 /// An open PR belonging to the agent
@@ -93,6 +301,74 @@ pub struct FeedProject {
     pub contributors: i32,
 }
 
+/// Onboarding notification shown in an agent's first feed
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum WelcomeMessage {
+    /// No welcome notification
+    Disabled,
+    /// Built-in guidance for the agent's tier
+    #[default]
+    TierDefault,
+    /// Custom text; `{tier}` and `{elo}` are filled in
+    Custom(String),
+}
+
+impl WelcomeMessage {
+    /// The welcome text for `agent`, if welcomes are enabled
+    pub fn render(&self, agent: &Agent) -> Option<String> {
+        let tier = Tier::from_elo(agent.elo);
+        let template = match self {
+            WelcomeMessage::Disabled => return None,
+            WelcomeMessage::TierDefault => default_welcome(tier),
+            WelcomeMessage::Custom(text) => text.clone(),
+        };
+        Some(
+            template
+                .replace("{tier}", &tier.to_string())
+                .replace("{elo}", &agent.elo.to_string()),
+        )
+    }
+}
+
+impl std::str::FromStr for WelcomeMessage {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim() {
+            "" | "off" | "none" => Ok(WelcomeMessage::Disabled),
+            "default" => Ok(WelcomeMessage::TierDefault),
+            text => Ok(WelcomeMessage::Custom(text.to_string())),
+        }
+    }
+}
+
+/// Built-in onboarding guidance for each tier, with `{tier}` and `{elo}` left to fill in
+fn default_welcome(tier: Tier) -> String {
+    let intro = "Welcome to SynStack! You start in {tier} with {elo} ELO.";
+    match tier {
+        Tier::Bronze => format!(
+            "{intro} Join a project, `work-on` a ticket and submit a PR: each merged PR \
+             earns +{ELO_PR_MERGED} ELO and code that survives {LONGEVITY_DAYS} days earns \
+             +{ELO_LONGEVITY_BONUS} more. Reverts and bug reports against your PRs cost \
+             ELO, so test before you submit. Reach {} ELO for Silver.",
+            Tier::SILVER_MIN_ELO
+        ),
+        Tier::Silver => format!(
+            "{intro} Merged PRs (+{ELO_PR_MERGED}) and code that lasts {LONGEVITY_DAYS} \
+             days (+{ELO_LONGEVITY_BONUS}) keep you climbing, and reviewing other agents' \
+             PRs helps the whole project. Reach {} ELO for Gold.",
+            Tier::GOLD_MIN_ELO
+        ),
+        Tier::Gold => format!(
+            "{intro} Your reviews carry weight: while your ELO is {HIGH_ELO_THRESHOLD} or \
+             more, each PR you approve earns its author +{ELO_HIGH_ELO_APPROVAL}, and an \
+             approval of a PR later reverted or traced to a bug costs you \
+             {ELO_BAD_APPROVAL}. Reverts ({ELO_COMMIT_REVERTED}) and bugs traced to your \
+             PRs ({ELO_BUG_REFERENCED}) cost the most, so keep your code durable."
+        ),
+    }
+}
+
 /// Service for generating agent feeds
 pub struct FeedService<PR, TR, GC>
 where
@@ -103,6 +379,10 @@ where
     projects: Arc<PR>,
     tickets: Arc<TR>,
     gitea: Arc<GC>,
+    notification_batch_window: Duration,
+    difficulty_weights: DifficultyWeights,
+    welcome: WelcomeMessage,
+    inbox: Option<Arc<FeedNotifier>>,
 }
 
 impl<PR, TR, GC> FeedService<PR, TR, GC>
@@ -116,16 +396,58 @@ where
             projects,
             tickets,
             gitea,
+            notification_batch_window: Duration::zero(),
+            difficulty_weights: DifficultyWeights::default(),
+            welcome: WelcomeMessage::Disabled,
+            inbox: None,
         }
     }
 
-    /// Generate a feed for an agent
-    pub async fn generate_feed(&self, agent: &Agent) -> Result<Feed, AppError> {
+    /// Include notifications delivered through this notifier in the feed
+    pub fn with_inbox(mut self, inbox: Arc<FeedNotifier>) -> Self {
+        self.inbox = Some(inbox);
+        self
+    }
+
+    /// Greet agents on their first feed (before they've ever been seen)
+    pub fn with_welcome_message(mut self, welcome: WelcomeMessage) -> Self {
+        self.welcome = welcome;
+        self
+    }
+
+    /// Order tickets by these difficulty weights (heaviest first)
+    pub fn with_difficulty_weights(mut self, weights: DifficultyWeights) -> Self {
+        self.difficulty_weights = weights;
+        self
+    }
+
+    /// Coalesce same-type notifications that land within `window` of each other
+    pub fn with_notification_batch_window(mut self, window: Duration) -> Self {
+        self.notification_batch_window = window;
+        self
+    }
+
+    /// Generate a feed for an agent. With the agent's Gitea token, its Gitea
+    /// notifications are included too.
+    pub async fn generate_feed(
+        &self,
+        agent: &Agent,
+        gitea_token: Option<&str>,
+    ) -> Result<Feed, AppError> {
         // Get active projects
         let projects = self.projects.find_active(20, 0).await?;
 
-        // Get agent's assigned tickets
-        let assigned_tickets = self.tickets.find_open_by_agent(&agent.id).await?;
+        // Get agent's assigned tickets (blocked ones aren't actionable),
+        // harder tickets first
+        let mut assigned_tickets: Vec<Ticket> = self
+            .tickets
+            .find_open_by_agent(&agent.id)
+            .await?
+            .into_iter()
+            .filter(|t| !t.is_blocked())
+            .collect();
+        assigned_tickets
+            .sort_by_key(|t| std::cmp::Reverse(self.difficulty_weights.weight(t.difficulty)));
 
         // Build ticket index lookup for project names
         let agent_projects = self.projects.find_by_agent(&agent.id).await?;
@@ -144,7 +466,7 @@ where
 
         // Get agent's PRs
         let mut my_prs = Vec::new();
-        let mut notifications = Vec::new();
+        let mut notifications = NotificationBatcher::new(self.notification_batch_window);
 
         for project in &agent_projects {
             let repo_name = &project.gitea_repo;
@@ -174,17 +496,6 @@ where
                             Err(_) => "unknown".to_string(),
                         };
 
-                        // Add merged PR notification
-                        if pr.merged {
-                            notifications.push(FeedNotification {
-                                notification_type: "merged".to_string(),
-                                pr_number: pr.number,
-                                pr_title: pr.title.clone(),
-                                message: Some("Your PR was merged!".to_string()),
-                                elo_change: Some(25), // Placeholder
-                            });
-                        }
-
                         my_prs.push(FeedPR {
                             number: pr.number,
                             title: pr.title,
@@ -210,6 +521,46 @@ where
             .map(|(i, project)| self.project_to_feed_project(i + 1, &project))
             .collect();
 
+        if let Some(inbox) = &self.inbox {
+            for notification in inbox.take(&agent.id) {
+                notifications.enqueue(notification);
+            }
+        }
+
+        if let Some(token) = gitea_token {
+            match self.gitea.get_notifications(token).await {
+                Ok(threads) => {
+                    for thread in threads {
+                        notifications.enqueue(gitea_notification(thread));
+                    }
+                }
+                Err(GiteaError::Api { status: 403, .. }) => {
+                    tracing::debug!(agent_id = %agent.id, "Gitea token lacks the notification scope");
+                }
+                Err(e) => {
+                    tracing::warn!(agent_id = %agent.id, error = %e, "Failed to fetch Gitea notifications");
+                }
+            }
+        }
+
+        let mut notifications = notifications.flush();
+        if agent.last_seen_at.is_none() {
+            if let Some(message) = self.welcome.render(agent) {
+                notifications.insert(
+                    0,
+                    FeedNotification {
+                        notification_type: "welcome".to_string(),
+                        pr_number: None,
+                        pr_title: "Welcome to SynStack".to_string(),
+                        message: Some(message),
+                        elo_change: None,
+                        created_at: Utc::now(),
+                        count: 1,
+                    },
+                );
+            }
+        }
+
         Ok(Feed {
             notifications,
             my_tickets,
@@ -249,6 +600,7 @@ where
             title: ticket.title.clone(),
             status: ticket.status.to_string(),
             priority: ticket.priority.to_string(),
+            difficulty: ticket.difficulty.to_string(),
             project_name: project_name.to_string(),
         }
     }
@@ -257,9 +609,10 @@ where
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::domain::entities::Difficulty;
     use crate::test_utils::{
-        test_agent, test_project, InMemoryProjectRepository, InMemoryTicketRepository,
-        MockGiteaClient,
+        test_agent, test_project, test_ticket_assigned, InMemoryProjectRepository,
+        InMemoryTicketRepository, MockGiteaClient,
     };
 
     fn create_service(
@@ -283,7 +636,7 @@ mod tests {
             MockGiteaClient::new(),
         );
 
-        let result = service.generate_feed(&agent).await;
+        let result = service.generate_feed(&agent, None).await;
 
         assert!(result.is_ok());
         let feed = result.unwrap();
@@ -293,6 +646,170 @@ mod tests {
         assert!(feed.my_tickets.is_empty());
     }
 
+    #[tokio::test]
+    async fn first_feed_welcomes_new_agent_once() {
+        use crate::domain::ports::AgentRepository;
+        use crate::test_utils::InMemoryAgentRepository;
+
+        let agent = test_agent();
+        let agents = InMemoryAgentRepository::new().with_agent(agent.clone());
+        let service = create_service(
+            InMemoryProjectRepository::new(),
+            InMemoryTicketRepository::new(),
+            MockGiteaClient::new(),
+        )
+        .with_welcome_message(WelcomeMessage::TierDefault);
+
+        let first = service.generate_feed(&agent, None).await.unwrap();
+        assert_eq!(first.notifications.len(), 1);
+        assert_eq!(first.notifications[0].notification_type, "welcome");
+        let message = first.notifications[0].message.as_deref().unwrap();
+        assert!(message.contains("bronze"));
+        assert!(message.contains(&format!("earns +{} ELO", ELO_PR_MERGED)));
+        assert!(message.contains(&format!("Reach {} ELO for Silver", Tier::SILVER_MIN_ELO)));
+
+        // The auth middleware records the visit, so the next poll isn't a first one
+        agents.update_last_seen(&agent.id).await.unwrap();
+        let agent = agents.find_by_id(&agent.id).await.unwrap().unwrap();
+        let second = service.generate_feed(&agent, None).await.unwrap();
+        assert!(second.notifications.is_empty());
+    }
+
+    #[test]
+    fn gold_welcome_credits_approvals_to_the_author() {
+        let mut agent = test_agent();
+        agent.elo = Tier::GOLD_MIN_ELO;
+
+        let message = WelcomeMessage::TierDefault.render(&agent).unwrap();
+
+        assert!(message.contains(&format!(
+            "while your ELO is {} or more, each PR you approve earns its author +{}",
+            HIGH_ELO_THRESHOLD, ELO_HIGH_ELO_APPROVAL
+        )));
+        assert!(message.contains(&format!("with {} ELO", Tier::GOLD_MIN_ELO)));
+    }
+
+    #[tokio::test]
+    async fn feed_notifier_delivers_to_next_feed_once() {
+        let agent = test_agent();
+        let inbox = Arc::new(FeedNotifier::new());
+        let service = create_service(
+            InMemoryProjectRepository::new(),
+            InMemoryTicketRepository::new(),
+            MockGiteaClient::new(),
+        )
+        .with_inbox(inbox.clone());
+
+        inbox
+            .notify(
+                &agent.id,
+                Notification {
+                    notification_type: "pr_merged".to_string(),
+                    pr_number: Some(3),
+                    title: "ELO +15 (1000 -> 1015)".to_string(),
+                    message: None,
+                    elo_change: Some(15),
+                    created_at: Utc::now(),
+                },
+            )
+            .await
+            .unwrap();
+
+        let first = service.generate_feed(&agent, None).await.unwrap();
+        assert_eq!(first.notifications.len(), 1);
+        assert_eq!(first.notifications[0].pr_number, Some(3));
+        assert_eq!(first.notifications[0].elo_change, Some(15));
+
+        let second = service.generate_feed(&agent, None).await.unwrap();
+        assert!(second.notifications.is_empty());
+    }
+
+    #[tokio::test]
+    async fn gitea_notifications_join_the_feed() {
+        let agent = test_agent();
+        let inbox = Arc::new(FeedNotifier::new());
+        let gitea = MockGiteaClient::new()
+            .with_notifications(
+                "agent-token",
+                vec![GiteaNotification {
+                    id: 7,
+                    repository: "org/repo".to_string(),
+                    subject_type: "Pull".to_string(),
+                    subject_title: "Fix flaky test".to_string(),
+                    subject_state: Some("open".to_string()),
+                    html_url: Some("http://gitea/org/repo/pulls/12".to_string()),
+                    unread: true,
+                    updated_at: "2026-01-15T12:00:00Z".to_string(),
+                }],
+            )
+            .with_notifications_denied("unscoped-token");
+        let service = create_service(
+            InMemoryProjectRepository::new(),
+            InMemoryTicketRepository::new(),
+            gitea,
+        )
+        .with_inbox(inbox.clone());
+
+        inbox
+            .notify(
+                &agent.id,
+                Notification {
+                    notification_type: "pr_merged".to_string(),
+                    pr_number: Some(3),
+                    title: "ELO +15 (1000 -> 1015)".to_string(),
+                    message: None,
+                    elo_change: Some(15),
+                    created_at: Utc::now(),
+                },
+            )
+            .await
+            .unwrap();
+
+        let feed = service
+            .generate_feed(&agent, Some("agent-token"))
+            .await
+            .unwrap();
+        assert_eq!(feed.notifications.len(), 2);
+        assert_eq!(feed.notifications[0].notification_type, "pr_merged");
+        let gitea = &feed.notifications[1];
+        assert_eq!(gitea.notification_type, "gitea_notification");
+        assert_eq!(gitea.pr_number, Some(12));
+        assert_eq!(gitea.pr_title, "Fix flaky test");
+        assert_eq!(gitea.message.as_deref(), Some("Pull in org/repo"));
+
+        // A token without the notification scope still gets a feed
+        let feed = service
+            .generate_feed(&agent, Some("unscoped-token"))
+            .await
+            .unwrap();
+        assert!(feed.notifications.is_empty());
+    }
+
+    #[test]
+    fn gitea_notifications_are_labelled_by_subject() {
+        let thread = |subject_type: &str, url: &str| GiteaNotification {
+            id: 1,
+            repository: "org/repo".to_string(),
+            subject_type: subject_type.to_string(),
+            subject_title: "Flaky test".to_string(),
+            subject_state: None,
+            html_url: Some(url.to_string()),
+            unread: true,
+            updated_at: "2026-01-15T12:00:00Z".to_string(),
+        };
+
+        let issue = gitea_notification(thread("Issue", "http://gitea/org/repo/issues/5"));
+        let commit = gitea_notification(thread(
+            "Commit",
+            "http://gitea/org/repo/commit/0123456789abcdef0123456789abcdef01234567",
+        ));
+
+        assert_eq!(issue.pr_number, None);
+        assert_eq!(issue.pr_title, "Issue #5: Flaky test");
+        assert_eq!(commit.pr_number, None);
+        assert_eq!(commit.pr_title, "Flaky test");
+    }
+
     #[tokio::test]
     async fn generate_feed_with_projects() {
         let agent = test_agent();
@@ -303,7 +820,7 @@ mod tests {
             MockGiteaClient::new(),
         );
 
-        let result = service.generate_feed(&agent).await;
+        let result = service.generate_feed(&agent, None).await;
 
         assert!(result.is_ok());
         let feed = result.unwrap();
@@ -311,6 +828,102 @@ mod tests {
         assert_eq!(feed.projects[0].name, project.name);
     }
 
+    #[tokio::test]
+    async fn generate_feed_orders_hard_tickets_first() {
+        let agent = test_agent();
+        let project = test_project();
+        let mut easy = test_ticket_assigned(project.id, agent.id);
+        easy.title = "Easy ticket".to_string();
+        easy.difficulty = Difficulty::Easy;
+        let mut hard = test_ticket_assigned(project.id, agent.id);
+        hard.title = "Hard ticket".to_string();
+        hard.difficulty = Difficulty::Hard;
+
+        let service = create_service(
+            InMemoryProjectRepository::new().with_project_and_member(project, agent.id),
+            InMemoryTicketRepository::new()
+                .with_ticket(easy)
+                .with_ticket(hard),
+            MockGiteaClient::new(),
+        )
+        .with_difficulty_weights("easy=50,hard=200".parse().unwrap());
+
+        let feed = service.generate_feed(&agent, None).await.unwrap();
+
+        assert_eq!(feed.my_tickets.len(), 2);
+        assert_eq!(feed.my_tickets[0].title, "Hard ticket");
+        assert_eq!(feed.my_tickets[0].difficulty, "hard");
+        assert_eq!(feed.my_tickets[1].title, "Easy ticket");
+    }
+
+    fn notification(notification_type: &str, elo: i32, at: DateTime<Utc>) -> FeedNotification {
+        FeedNotification {
+            notification_type: notification_type.to_string(),
+            pr_number: Some(1),
+            pr_title: "Add feature".to_string(),
+            message: None,
+            elo_change: Some(elo),
+            created_at: at,
+            count: 1,
+        }
+    }
+
+    #[test]
+    fn batcher_summarizes_longevity_burst() {
+        let now = Utc::now();
+        let mut batcher = NotificationBatcher::new(Duration::minutes(5));
+        batcher.enqueue(notification("longevity_bonus", 10, now));
+        batcher.enqueue(notification(
+            "longevity_bonus",
+            10,
+            now + Duration::seconds(1),
+        ));
+        batcher.enqueue(notification(
+            "longevity_bonus",
+            10,
+            now + Duration::seconds(2),
+        ));
+
+        let flushed = batcher.flush();
+
+        assert_eq!(flushed.len(), 1);
+        assert!(flushed[0].is_summary());
+        assert_eq!(flushed[0].count, 3);
+        assert_eq!(flushed[0].elo_change, Some(30));
+        assert_eq!(
+            flushed[0].message.as_deref(),
+            Some("3 longevity bonuses: +30")
+        );
+    }
+
+    #[test]
+    fn batcher_keeps_types_and_windows_apart() {
+        let now = Utc::now();
+        let mut batcher = NotificationBatcher::new(Duration::minutes(5));
+        batcher.enqueue(notification("longevity_bonus", 10, now));
+        batcher.enqueue(notification("merged", 15, now + Duration::seconds(1)));
+        batcher.enqueue(notification(
+            "longevity_bonus",
+            10,
+            now + Duration::minutes(10),
+        ));
+
+        let flushed = batcher.flush();
+
+        assert_eq!(flushed.len(), 3);
+        assert!(flushed.iter().all(|n| !n.is_summary()));
+    }
+
+    #[test]
+    fn batcher_with_zero_window_passes_through() {
+        let now = Utc::now();
+        let mut batcher = NotificationBatcher::new(Duration::zero());
+        batcher.enqueue(notification("longevity_bonus", 10, now));
+        batcher.enqueue(notification("longevity_bonus", 10, now));
+
+        assert_eq!(batcher.flush().len(), 2);
+    }
+
     #[tokio::test]
     async fn get_project_by_index_found() {
         let project = test_project();
@@ -341,4 +954,47 @@ mod tests {
         assert!(result.is_ok());
         assert!(result.unwrap().is_none());
     }
+
+    #[tokio::test]
+    async fn blocked_ticket_leaves_feed_until_unblocked() {
+        let agent = test_agent();
+        let project = test_project();
+        let ticket = test_ticket_assigned(project.id, agent.id);
+        let tickets = Arc::new(InMemoryTicketRepository::new().with_ticket(ticket.clone()));
+        let service = FeedService::new(
+            Arc::new(InMemoryProjectRepository::new().with_project(project)),
+            tickets.clone(),
+            Arc::new(MockGiteaClient::new()),
+        );
+
+        let feed = service.generate_feed(&agent, None).await.unwrap();
+        assert_eq!(feed.my_tickets.len(), 1);
+
+        tickets
+            .block(&ticket.id, "waiting on upstream API")
+            .await
+            .unwrap();
+        let blocked = tickets.find_by_id(&ticket.id).await.unwrap().unwrap();
+        assert_eq!(
+            blocked.status,
+            crate::domain::entities::TicketStatus::Blocked
+        );
+        assert_eq!(
+            blocked.blocked_reason.as_deref(),
+            Some("waiting on upstream API")
+        );
+        let feed = service.generate_feed(&agent, None).await.unwrap();
+        assert!(feed.my_tickets.is_empty());
+
+        tickets.unblock(&ticket.id).await.unwrap();
+        let unblocked = tickets.find_by_id(&ticket.id).await.unwrap().unwrap();
+        assert_eq!(
+            unblocked.status,
+            crate::domain::entities::TicketStatus::InProgress
+        );
+        assert!(unblocked.blocked_reason.is_none());
+        let feed = service.generate_feed(&agent, None).await.unwrap();
+        assert_eq!(feed.my_tickets.len(), 1);
+        assert_eq!(feed.my_tickets[0].id, ticket.id.to_string());
+    }
 }
@@ -0,0 +1,334 @@
+//! Contribution history export
+//!
+//! Renders an agent's record as CSV. Rows are produced lazily as a stream,
+//! a page at a time, so large histories can be sent to the client without
+//! building the whole file in memory.
+
+use std::collections::hash_map::Entry;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use futures_util::stream::{self, BoxStream, StreamExt};
+
+use crate::domain::entities::{AgentId, CodeContribution, EloEvent, ProjectId};
+use crate::domain::ports::{CodeContributionRepository, EloEventRepository, ProjectRepository};
+use crate::error::AppError;
+
+/// Header row of the contributions export
+pub const CONTRIBUTIONS_CSV_HEADER: &str = "project,pr_number,status,merged_at,bug_count\n";
+
+/// Header row of the ELO events export
+pub const ELO_EVENTS_CSV_HEADER: &str = "event_type,delta,old_elo,new_elo,details,created_at\n";
+
+/// Contributions fetched per database round trip while streaming
+const CONTRIBUTION_PAGE_SIZE: i64 = 500;
+
+/// ELO events fetched per database round trip while streaming
+const ELO_EVENT_PAGE_SIZE: i64 = 500;
+
+/// Which part of an agent's history to export
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ExportRecords {
+    #[default]
+    Contributions,
+    EloEvents,
+}
+
+impl ExportRecords {
+    /// File name stem used in the download's Content-Disposition
+    pub fn file_stem(&self) -> &'static str {
+        match self {
+            ExportRecords::Contributions => "contributions",
+            ExportRecords::EloEvents => "elo-events",
+        }
+    }
+}
+
+impl std::str::FromStr for ExportRecords {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "contributions" => Ok(ExportRecords::Contributions),
+            "elo_events" | "elo-events" => Ok(ExportRecords::EloEvents),
+            _ => Err(format!(
+                "Unknown export records '{}'. Use: contributions, elo_events",
+                s
+            )),
+        }
+    }
+}
+
+/// Service for exporting an agent's contribution history
+pub struct HistoryExportService<CCR, EER, PR>
+where
+    CCR: CodeContributionRepository,
+    EER: EloEventRepository,
+    PR: ProjectRepository,
+{
+    contributions: Arc<CCR>,
+    elo_events: Arc<EER>,
+    projects: Arc<PR>,
+}
+
+impl<CCR, EER, PR> HistoryExportService<CCR, EER, PR>
+where
+    CCR: CodeContributionRepository + 'static,
+    EER: EloEventRepository + 'static,
+    PR: ProjectRepository + 'static,
+{
+    pub fn new(contributions: Arc<CCR>, elo_events: Arc<EER>, projects: Arc<PR>) -> Self {
+        Self {
+            contributions,
+            elo_events,
+            projects,
+        }
+    }
+
+    /// Stream an agent's history as CSV chunks, header row first
+    pub fn csv_stream(
+        self: &Arc<Self>,
+        agent_id: AgentId,
+        records: ExportRecords,
+    ) -> BoxStream<'static, Result<String, AppError>> {
+        match records {
+            ExportRecords::Contributions => self.contributions_csv(agent_id),
+            ExportRecords::EloEvents => self.elo_events_csv(agent_id),
+        }
+    }
+
+    fn contributions_csv(
+        self: &Arc<Self>,
+        agent_id: AgentId,
+    ) -> BoxStream<'static, Result<String, AppError>> {
+        let this = self.clone();
+        // Project names are resolved once and carried from page to page
+        let pages = stream::unfold(Some((0i64, HashMap::new())), move |state| {
+            let this = this.clone();
+            async move {
+                let (offset, mut names) = state?;
+                match this.contributions_page(&agent_id, offset, &mut names).await {
+                    Ok((chunk, more)) => {
+                        let next = more.then_some((offset + CONTRIBUTION_PAGE_SIZE, names));
+                        Some((Ok(chunk), next))
+                    }
+                    Err(e) => Some((Err(e), None)),
+                }
+            }
+        });
+
+        stream::once(async { Ok(CONTRIBUTIONS_CSV_HEADER.to_string()) })
+            .chain(pages)
+            .boxed()
+    }
+
+    /// CSV rows for one page of contributions, and whether more may follow
+    async fn contributions_page(
+        &self,
+        agent_id: &AgentId,
+        offset: i64,
+        names: &mut HashMap<ProjectId, String>,
+    ) -> Result<(String, bool), AppError> {
+        let page = self
+            .contributions
+            .find_by_agent_paginated(agent_id, CONTRIBUTION_PAGE_SIZE, offset)
+            .await?;
+
+        let mut chunk = String::new();
+        for contribution in &page {
+            let name = match names.entry(contribution.project_id) {
+                Entry::Occupied(slot) => slot.into_mut(),
+                Entry::Vacant(slot) => {
+                    let name = self
+                        .projects
+                        .find_by_id(&contribution.project_id)
+                        .await?
+                        .map(|p| p.name)
+                        .unwrap_or_else(|| contribution.project_id.to_string());
+                    slot.insert(name)
+                }
+            };
+            chunk.push_str(&contribution_csv_row(contribution, name));
+        }
+        Ok((chunk, page.len() as i64 == CONTRIBUTION_PAGE_SIZE))
+    }
+
+    fn elo_events_csv(
+        self: &Arc<Self>,
+        agent_id: AgentId,
+    ) -> BoxStream<'static, Result<String, AppError>> {
+        let this = self.clone();
+        let pages = stream::unfold(Some(0i64), move |offset| {
+            let this = this.clone();
+            async move {
+                let offset = offset?;
+                match this
+                    .elo_events
+                    .find_by_agent_paginated(&agent_id, ELO_EVENT_PAGE_SIZE, offset)
+                    .await
+                {
+                    Ok(page) => {
+                        let next = (page.len() as i64 == ELO_EVENT_PAGE_SIZE)
+                            .then_some(offset + ELO_EVENT_PAGE_SIZE);
+                        let chunk: String = page.iter().map(elo_event_csv_row).collect();
+                        Some((Ok(chunk), next))
+                    }
+                    Err(e) => Some((Err(e.into()), None)),
+                }
+            }
+        });
+
+        stream::once(async { Ok(ELO_EVENTS_CSV_HEADER.to_string()) })
+            .chain(pages)
+            .boxed()
+    }
+}
+
+/// One contributions CSV row (newline-terminated)
+fn contribution_csv_row(c: &CodeContribution, project: &str) -> String {
+    format!(
+        "{},{},{},{},{}\n",
+        csv_field(project),
+        c.pr_number,
+        c.status,
+        c.merged_at.to_rfc3339(),
+        c.bug_count
+    )
+}
+
+/// One ELO events CSV row (newline-terminated)
+fn elo_event_csv_row(e: &EloEvent) -> String {
+    format!(
+        "{},{},{},{},{},{}\n",
+        e.event_type,
+        e.delta,
+        e.old_elo,
+        e.new_elo,
+        csv_field(e.details.as_deref().unwrap_or_default()),
+        e.created_at.to_rfc3339()
+    )
+}
+
+/// Quote a field if it contains a delimiter, quote, or line break
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::entities::EloEventType;
+    use crate::test_utils::{
+        test_agent, test_code_contribution, test_elo_event, test_project,
+        InMemoryCodeContributionRepository, InMemoryEloEventRepository, InMemoryProjectRepository,
+    };
+
+    async fn collect(stream: BoxStream<'static, Result<String, AppError>>) -> String {
+        stream
+            .map(|chunk| chunk.unwrap())
+            .collect::<Vec<_>>()
+            .await
+            .concat()
+    }
+
+    #[tokio::test]
+    async fn contributions_export_has_header_and_row() {
+        let agent = test_agent();
+        let project = test_project();
+        let contribution = test_code_contribution(agent.id, project.id);
+
+        let service = Arc::new(HistoryExportService::new(
+            Arc::new(
+                InMemoryCodeContributionRepository::new().with_contribution(contribution.clone()),
+            ),
+            Arc::new(InMemoryEloEventRepository::new()),
+            Arc::new(InMemoryProjectRepository::new().with_project(project.clone())),
+        ));
+
+        let csv = collect(service.csv_stream(agent.id, ExportRecords::Contributions)).await;
+        let lines: Vec<&str> = csv.lines().collect();
+
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[0], "project,pr_number,status,merged_at,bug_count");
+        assert_eq!(
+            lines[1],
+            format!(
+                "{},{},healthy,{},0",
+                project.name,
+                contribution.pr_number,
+                contribution.merged_at.to_rfc3339()
+            )
+        );
+    }
+
+    #[tokio::test]
+    async fn elo_events_export_quotes_details() {
+        let agent = test_agent();
+        let mut event = test_elo_event(agent.id, EloEventType::PrMerged, 15);
+        event.details = Some("PR #1 merged, \"nice\"".to_string());
+
+        let service = Arc::new(HistoryExportService::new(
+            Arc::new(InMemoryCodeContributionRepository::new()),
+            Arc::new(InMemoryEloEventRepository::new().with_event(event)),
+            Arc::new(InMemoryProjectRepository::new()),
+        ));
+
+        let csv = collect(service.csv_stream(agent.id, ExportRecords::EloEvents)).await;
+        let lines: Vec<&str> = csv.lines().collect();
+
+        assert_eq!(lines[0], ELO_EVENTS_CSV_HEADER.trim_end());
+        assert_eq!(lines.len(), 2);
+        assert!(lines[1].contains("\"PR #1 merged, \"\"nice\"\"\""));
+    }
+
+    #[tokio::test]
+    async fn contributions_export_pages_through_long_histories() {
+        let agent = test_agent();
+        let project = test_project();
+        let total = CONTRIBUTION_PAGE_SIZE as usize + 3;
+        let mut contributions = InMemoryCodeContributionRepository::new();
+        for pr_number in 0..total {
+            let mut contribution = test_code_contribution(agent.id, project.id);
+            contribution.pr_number = pr_number as i64;
+            contribution.merged_at -= chrono::Duration::minutes(pr_number as i64);
+            contributions = contributions.with_contribution(contribution);
+        }
+
+        let service = Arc::new(HistoryExportService::new(
+            Arc::new(contributions),
+            Arc::new(InMemoryEloEventRepository::new()),
+            Arc::new(InMemoryProjectRepository::new().with_project(project.clone())),
+        ));
+
+        let chunks: Vec<String> = service
+            .csv_stream(agent.id, ExportRecords::Contributions)
+            .map(|chunk| chunk.unwrap())
+            .collect()
+            .await;
+        let csv = chunks.concat();
+        let lines: Vec<&str> = csv.lines().collect();
+
+        // Header, then one chunk per page
+        assert_eq!(chunks.len(), 3);
+        assert_eq!(lines.len(), total + 1);
+        assert!(lines[1].starts_with(&format!("{},0,", project.name)));
+        assert!(lines[total].starts_with(&format!("{},{},", project.name, total - 1)));
+    }
+
+    #[test]
+    fn export_records_parse() {
+        assert_eq!(
+            "contributions".parse::<ExportRecords>().unwrap(),
+            ExportRecords::Contributions
+        );
+        assert_eq!(
+            "elo_events".parse::<ExportRecords>().unwrap(),
+            ExportRecords::EloEvents
+        );
+        assert!("reviews".parse::<ExportRecords>().is_err());
+    }
+}
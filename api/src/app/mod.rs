@@ -6,29 +6,62 @@
 pub mod action_parser;
 pub mod agent_service;
 pub mod antfarm_service;
+pub mod bug_attribution;
 pub mod elo_config;
 pub mod engagement_service;
+pub mod feed_cache;
 pub mod feed_service;
+pub mod history_export;
+pub mod pending_penalty_service;
+pub mod reaction_sync_service;
 pub mod reactive_elo_service;
+pub mod related_issues;
+pub mod review_request_service;
+pub mod stale_pr_service;
+pub mod ticket_count_batcher;
+pub mod token_cache;
 pub mod viral_moment_service;
+pub mod webhook_delivery_service;
+pub mod webhook_queue;
 pub mod work_loop_service;
 
 pub use action_parser::{help_text, parse_action, AgentAction, ReviewAction};
-pub use agent_service::{hash_api_key, AgentService};
+pub use agent_service::{
+    hash_api_key, ActivityDay, AgentService, BatchFailurePolicy, BatchUserResult,
+    UserCreationOutcome,
+};
 pub use antfarm_service::AntfarmService;
+pub use bug_attribution::GiteaBugAttribution;
 pub use work_loop_service::{WorkLoopService, WorkStatus};
 // Re-export ELO config for public API (constants used by consumers)
 #[allow(unused_imports)]
 pub use elo_config::*;
 #[allow(unused_imports)]
 pub use engagement_service::{
-    engagement_help_text, EngagementAction, EngagementResult, EngagementService,
+    engagement_help_text, pr_target_id, EngagementAction, EngagementResult, EngagementService,
+    ResyncResult, RESYNC_BATCH_SIZE,
+};
+pub use feed_cache::FeedCache;
+pub use feed_service::{
+    Feed, FeedNotification, FeedNotifier, FeedPR, FeedProject, FeedService, FeedTicket,
 };
-pub use feed_service::{Feed, FeedNotification, FeedPR, FeedProject, FeedService, FeedTicket};
+pub use history_export::{ExportRecords, HistoryExportService};
+pub use pending_penalty_service::PendingPenaltyService;
+pub use reaction_sync_service::ReactionSyncService;
+pub use related_issues::RelatedIssuesService;
+pub use review_request_service::ReviewRequestService;
+pub use stale_pr_service::StalePrService;
+pub use ticket_count_batcher::TicketCountBatcher;
 // Re-export reactive ELO types for public API
 #[allow(unused_imports)]
 pub use reactive_elo_service::{
-    parse_bug_references, parse_revert_commit, EloChangeResult, ReactiveEloService,
+    parse_bug_references, parse_code_references, parse_dependency_references, parse_revert_commit,
+    BackfillReport, EloBounds, EloChangeResult, EloSimulation, ReactiveEloService, ReviewDirection,
+    ReviewRecord,
 };
 #[allow(unused_imports)]
-pub use viral_moment_service::{ViralMomentService, ViralThresholds};
+pub use viral_moment_service::{
+    PromotionOverflow, PromotionQuota, ViralMomentService, ViralThresholds,
+};
+pub use webhook_delivery_service::WebhookDeliveryService;
+pub use webhook_queue::{WebhookEventHandler, WebhookJob, WebhookQueue};
@@ -0,0 +1,525 @@
+//! Pending penalty service
+//!
+//! Defers PR-rejection penalties for a grace period. A closed PR records a
+//! pending penalty; a periodic sweep re-checks the PR in Gitea once the grace
+//! period has elapsed and only then deducts ELO. Reopening the PR within the
+//! window cancels the penalty.
+
+use std::sync::Arc;
+
+use chrono::{DateTime, Duration, Utc};
+
+use crate::app::elo_config::PR_REJECTION_GRACE_SECS;
+use crate::app::reactive_elo_service::{EloChangeResult, ReactiveEloService};
+use crate::domain::entities::{AgentId, NewPendingPenalty, PenaltyStatus, PendingPenalty, Project};
+use crate::domain::ports::{
+    AgentRepository, AgentReviewRepository, CodeContributionRepository, EloEventRepository,
+    GiteaClient, PendingPenaltyRepository, ProjectRepository,
+};
+use crate::error::{AppError, GiteaError};
+
+/// Maximum number of due penalties handled in one sweep
+const SWEEP_BATCH_SIZE: i64 = 100;
+
+/// How long a penalty whose PR couldn't be fetched waits before it's re-checked
+const RECHECK_BACKOFF_MINUTES: i64 = 30;
+
+/// What cancels a pending rejection penalty before its grace period elapses
+pub const CANCEL_CONDITIONS: [&str; 2] = [
+    "The PR is reopened before apply_at",
+    "The PR is merged before apply_at",
+];
+
+/// Service for scheduling and applying deferred PR-rejection penalties
+pub struct PendingPenaltyService<PPR, GC>
+where
+    PPR: PendingPenaltyRepository,
+    GC: GiteaClient,
+{
+    penalties: Arc<PPR>,
+    gitea: Arc<GC>,
+    grace_period: Duration,
+}
+
+impl<PPR, GC> PendingPenaltyService<PPR, GC>
+where
+    PPR: PendingPenaltyRepository,
+    GC: GiteaClient,
+{
+    pub fn new(penalties: Arc<PPR>, gitea: Arc<GC>) -> Self {
+        Self {
+            penalties,
+            gitea,
+            grace_period: Duration::seconds(PR_REJECTION_GRACE_SECS),
+        }
+    }
+
+    /// Set how long a PR must stay closed before its author is penalized
+    pub fn with_grace_period(mut self, grace_period: Duration) -> Self {
+        self.grace_period = grace_period;
+        self
+    }
+
+    /// Whether rejections are deferred at all (a zero grace period applies them immediately)
+    pub fn is_deferred(&self) -> bool {
+        self.grace_period > Duration::zero()
+    }
+
+    /// Record a rejection whose penalty applies once the grace period elapses
+    pub async fn schedule_rejection(
+        &self,
+        agent_id: &AgentId,
+        project: &Project,
+        pr_number: i64,
+    ) -> Result<PendingPenalty, AppError> {
+        let penalty = self
+            .penalties
+            .create(&NewPendingPenalty {
+                agent_id: *agent_id,
+                project_id: project.id,
+                gitea_org: project.gitea_org.clone(),
+                gitea_repo: project.gitea_repo.clone(),
+                pr_number,
+                apply_at: Utc::now() + self.grace_period,
+            })
+            .await?;
+
+        Ok(penalty)
+    }
+
+    /// Penalties scheduled against an agent that haven't been applied or canceled yet
+    pub async fn list_pending_for_agent(
+        &self,
+        agent_id: &AgentId,
+    ) -> Result<Vec<PendingPenalty>, AppError> {
+        Ok(self.penalties.find_pending_by_agent(agent_id).await?)
+    }
+
+    /// Cancel any pending penalty for a PR (it was reopened).
+    /// Returns the number of penalties canceled.
+    pub async fn cancel_for_pr(
+        &self,
+        project: &Project,
+        pr_number: i64,
+    ) -> Result<usize, AppError> {
+        let pending = self
+            .penalties
+            .find_pending_by_pr(&project.id, pr_number)
+            .await?;
+
+        let now = Utc::now();
+        for penalty in &pending {
+            self.penalties
+                .resolve(&penalty.id, PenaltyStatus::Canceled, now)
+                .await?;
+        }
+
+        Ok(pending.len())
+    }
+
+    /// Apply every penalty whose grace period has elapsed by `now`.
+    ///
+    /// Each PR is re-checked in Gitea first: if it has been reopened or merged
+    /// in the meantime the penalty is canceled instead, and if it no longer
+    /// exists the penalty is dropped. Penalties whose PR can't be fetched are
+    /// pushed back by [`RECHECK_BACKOFF_MINUTES`] so they don't crowd out the
+    /// rest of the batch. A failure on one penalty is logged and the sweep
+    /// moves on to the next.
+    pub async fn process_due<AR, CCR, ARR, EER, PR>(
+        &self,
+        elo: &ReactiveEloService<AR, CCR, ARR, EER, PR>,
+        now: DateTime<Utc>,
+    ) -> Result<Vec<EloChangeResult>, AppError>
+    where
+        AR: AgentRepository,
+        CCR: CodeContributionRepository,
+        ARR: AgentReviewRepository,
+        EER: EloEventRepository,
+        PR: ProjectRepository,
+    {
+        let due = self.penalties.find_due(now, SWEEP_BATCH_SIZE).await?;
+        let mut applied = Vec::new();
+
+        for penalty in due {
+            match self.process_penalty(elo, &penalty, now).await {
+                Ok(Some(result)) => applied.push(result),
+                Ok(None) => {}
+                Err(e) => {
+                    tracing::error!(
+                        error = %e,
+                        penalty_id = %penalty.id,
+                        pr_number = penalty.pr_number,
+                        "Failed to process pending rejection penalty"
+                    );
+                }
+            }
+        }
+
+        Ok(applied)
+    }
+
+    /// Re-check one due penalty's PR and apply, cancel or postpone it.
+    ///
+    /// The penalty is marked applied before ELO is deducted, so a failure in
+    /// between can never charge the agent twice.
+    async fn process_penalty<AR, CCR, ARR, EER, PR>(
+        &self,
+        elo: &ReactiveEloService<AR, CCR, ARR, EER, PR>,
+        penalty: &PendingPenalty,
+        now: DateTime<Utc>,
+    ) -> Result<Option<EloChangeResult>, AppError>
+    where
+        AR: AgentRepository,
+        CCR: CodeContributionRepository,
+        ARR: AgentReviewRepository,
+        EER: EloEventRepository,
+        PR: ProjectRepository,
+    {
+        let pr = match self
+            .gitea
+            .get_pull_request(&penalty.gitea_org, &penalty.gitea_repo, penalty.pr_number)
+            .await
+        {
+            Ok(pr) => pr,
+            Err(GiteaError::Api { status: 404, .. } | GiteaError::RepoNotFound { .. }) => {
+                self.penalties
+                    .resolve(&penalty.id, PenaltyStatus::Canceled, now)
+                    .await?;
+                tracing::info!(
+                    penalty_id = %penalty.id,
+                    pr_number = penalty.pr_number,
+                    "PR no longer exists, rejection penalty canceled"
+                );
+                return Ok(None);
+            }
+            Err(e) => {
+                tracing::warn!(
+                    error = %e,
+                    penalty_id = %penalty.id,
+                    pr_number = penalty.pr_number,
+                    "Failed to re-check PR for pending rejection penalty, retrying later"
+                );
+                self.penalties
+                    .reschedule(
+                        &penalty.id,
+                        now + Duration::minutes(RECHECK_BACKOFF_MINUTES),
+                    )
+                    .await?;
+                return Ok(None);
+            }
+        };
+
+        if pr.merged || pr.state == "open" {
+            self.penalties
+                .resolve(&penalty.id, PenaltyStatus::Canceled, now)
+                .await?;
+            tracing::info!(
+                penalty_id = %penalty.id,
+                pr_number = penalty.pr_number,
+                "PR reopened during grace period, rejection penalty canceled"
+            );
+            return Ok(None);
+        }
+
+        self.penalties
+            .resolve(&penalty.id, PenaltyStatus::Applied, now)
+            .await?;
+        let result = elo
+            .on_pr_rejected(&penalty.agent_id, &penalty.project_id, penalty.pr_number)
+            .await?;
+        Ok(Some(result))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app::elo_config::ELO_PR_REJECTED;
+    use crate::domain::ports::AgentRepository;
+    use crate::test_utils::{
+        test_agent_with_elo, test_project, InMemoryAgentRepository, InMemoryAgentReviewRepository,
+        InMemoryCodeContributionRepository, InMemoryEloEventRepository,
+        InMemoryPendingPenaltyRepository, InMemoryProjectRepository, MockGiteaClient,
+    };
+
+    type TestEloService = ReactiveEloService<
+        InMemoryAgentRepository,
+        InMemoryCodeContributionRepository,
+        InMemoryAgentReviewRepository,
+        InMemoryEloEventRepository,
+        InMemoryProjectRepository,
+    >;
+
+    fn create_elo_service(agent_repo: Arc<InMemoryAgentRepository>) -> TestEloService {
+        ReactiveEloService::new(
+            agent_repo,
+            Arc::new(InMemoryCodeContributionRepository::new()),
+            Arc::new(InMemoryAgentReviewRepository::new()),
+            Arc::new(InMemoryEloEventRepository::new()),
+            Arc::new(InMemoryProjectRepository::new()),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_penalty_applied_after_grace_when_pr_stays_closed() {
+        let agent = test_agent_with_elo(1000);
+        let agent_repo = Arc::new(InMemoryAgentRepository::new().with_agent(agent.clone()));
+        let elo = create_elo_service(agent_repo.clone());
+        let project = test_project();
+        let gitea = Arc::new(MockGiteaClient::new().with_closed_pr(
+            &project.gitea_org,
+            &project.gitea_repo,
+            7,
+        ));
+        let penalties = Arc::new(InMemoryPendingPenaltyRepository::new());
+        let service = PendingPenaltyService::new(penalties.clone(), gitea)
+            .with_grace_period(Duration::minutes(10));
+
+        service
+            .schedule_rejection(&agent.id, &project, 7)
+            .await
+            .unwrap();
+
+        // Still inside the grace period: nothing happens
+        let applied = service.process_due(&elo, Utc::now()).await.unwrap();
+        assert!(applied.is_empty());
+        let stored = agent_repo.find_by_id(&agent.id).await.unwrap().unwrap();
+        assert_eq!(stored.elo, 1000);
+
+        // After the grace period the PR is still closed: penalty applies
+        let later = Utc::now() + Duration::minutes(11);
+        let applied = service.process_due(&elo, later).await.unwrap();
+        assert_eq!(applied.len(), 1);
+        assert_eq!(applied[0].delta, ELO_PR_REJECTED);
+        let stored = agent_repo.find_by_id(&agent.id).await.unwrap().unwrap();
+        assert_eq!(stored.elo, 1000 + ELO_PR_REJECTED);
+
+        let all = penalties.get_all_penalties();
+        assert_eq!(all.len(), 1);
+        assert_eq!(all[0].status, PenaltyStatus::Applied);
+
+        // Applied penalties are not picked up again
+        let applied = service.process_due(&elo, later).await.unwrap();
+        assert!(applied.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_penalty_canceled_when_pr_reopened_within_grace() {
+        let agent = test_agent_with_elo(1000);
+        let agent_repo = Arc::new(InMemoryAgentRepository::new().with_agent(agent.clone()));
+        let elo = create_elo_service(agent_repo.clone());
+        let project = test_project();
+        let gitea = Arc::new(MockGiteaClient::new().with_closed_pr(
+            &project.gitea_org,
+            &project.gitea_repo,
+            7,
+        ));
+        let penalties = Arc::new(InMemoryPendingPenaltyRepository::new());
+        let service = PendingPenaltyService::new(penalties.clone(), gitea)
+            .with_grace_period(Duration::minutes(10));
+
+        service
+            .schedule_rejection(&agent.id, &project, 7)
+            .await
+            .unwrap();
+
+        // PR reopened (webhook) before the grace period elapses
+        let canceled = service.cancel_for_pr(&project, 7).await.unwrap();
+        assert_eq!(canceled, 1);
+
+        let later = Utc::now() + Duration::minutes(11);
+        let applied = service.process_due(&elo, later).await.unwrap();
+        assert!(applied.is_empty());
+        let stored = agent_repo.find_by_id(&agent.id).await.unwrap().unwrap();
+        assert_eq!(stored.elo, 1000);
+        assert_eq!(
+            penalties.get_all_penalties()[0].status,
+            PenaltyStatus::Canceled
+        );
+    }
+
+    #[tokio::test]
+    async fn test_penalty_canceled_when_pr_found_open_at_sweep() {
+        let agent = test_agent_with_elo(1000);
+        let agent_repo = Arc::new(InMemoryAgentRepository::new().with_agent(agent.clone()));
+        let elo = create_elo_service(agent_repo.clone());
+        let project = test_project();
+        // The reopen webhook was missed, but Gitea reports the PR as open
+        let gitea =
+            Arc::new(MockGiteaClient::new().with_pr(&project.gitea_org, &project.gitea_repo, 7));
+        let penalties = Arc::new(InMemoryPendingPenaltyRepository::new());
+        let service = PendingPenaltyService::new(penalties.clone(), gitea)
+            .with_grace_period(Duration::minutes(10));
+
+        service
+            .schedule_rejection(&agent.id, &project, 7)
+            .await
+            .unwrap();
+
+        let later = Utc::now() + Duration::minutes(11);
+        let applied = service.process_due(&elo, later).await.unwrap();
+        assert!(applied.is_empty());
+        let stored = agent_repo.find_by_id(&agent.id).await.unwrap().unwrap();
+        assert_eq!(stored.elo, 1000);
+        assert_eq!(
+            penalties.get_all_penalties()[0].status,
+            PenaltyStatus::Canceled
+        );
+    }
+
+    #[tokio::test]
+    async fn test_list_pending_for_agent_excludes_applied_penalties() {
+        let agent = test_agent_with_elo(1000);
+        let agent_repo = Arc::new(InMemoryAgentRepository::new().with_agent(agent.clone()));
+        let elo = create_elo_service(agent_repo);
+        let project = test_project();
+        let gitea = Arc::new(
+            MockGiteaClient::new()
+                .with_closed_pr(&project.gitea_org, &project.gitea_repo, 7)
+                .with_closed_pr(&project.gitea_org, &project.gitea_repo, 8),
+        );
+        let penalties = Arc::new(InMemoryPendingPenaltyRepository::new());
+        let service = PendingPenaltyService::new(penalties.clone(), gitea)
+            .with_grace_period(Duration::minutes(10));
+
+        // PR 7's penalty is applied by a sweep; PR 8's is scheduled afterwards
+        service
+            .schedule_rejection(&agent.id, &project, 7)
+            .await
+            .unwrap();
+        let later = Utc::now() + Duration::minutes(11);
+        assert_eq!(service.process_due(&elo, later).await.unwrap().len(), 1);
+        let scheduled = service
+            .schedule_rejection(&agent.id, &project, 8)
+            .await
+            .unwrap();
+
+        let pending = service.list_pending_for_agent(&agent.id).await.unwrap();
+        assert_eq!(pending.len(), 1);
+        assert_eq!(pending[0].id, scheduled.id);
+        assert_eq!(pending[0].pr_number, 8);
+
+        let other = service
+            .list_pending_for_agent(&AgentId::new())
+            .await
+            .unwrap();
+        assert!(other.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_unreachable_pr_is_postponed_without_blocking_the_sweep() {
+        let agent = test_agent_with_elo(1000);
+        let agent_repo = Arc::new(InMemoryAgentRepository::new().with_agent(agent.clone()));
+        let elo = create_elo_service(agent_repo.clone());
+        let project = test_project();
+        let gitea = Arc::new(
+            MockGiteaClient::new()
+                .with_unreachable_pr(&project.gitea_org, &project.gitea_repo, 7)
+                .with_closed_pr(&project.gitea_org, &project.gitea_repo, 8),
+        );
+        let penalties = Arc::new(InMemoryPendingPenaltyRepository::new());
+        let service = PendingPenaltyService::new(penalties.clone(), gitea)
+            .with_grace_period(Duration::minutes(10));
+
+        let unreachable = service
+            .schedule_rejection(&agent.id, &project, 7)
+            .await
+            .unwrap();
+        service
+            .schedule_rejection(&agent.id, &project, 8)
+            .await
+            .unwrap();
+
+        let later = Utc::now() + Duration::minutes(11);
+        let applied = service.process_due(&elo, later).await.unwrap();
+
+        // PR 8 is still penalized even though PR 7 couldn't be fetched
+        assert_eq!(applied.len(), 1);
+        let stored = agent_repo.find_by_id(&agent.id).await.unwrap().unwrap();
+        assert_eq!(stored.elo, 1000 + ELO_PR_REJECTED);
+
+        // PR 7 stays pending but is no longer due
+        let postponed = penalties
+            .get_all_penalties()
+            .into_iter()
+            .find(|p| p.id == unreachable.id)
+            .unwrap();
+        assert_eq!(postponed.status, PenaltyStatus::Pending);
+        assert!(!postponed.is_due(later));
+        assert!(postponed.is_due(later + Duration::minutes(RECHECK_BACKOFF_MINUTES)));
+    }
+
+    #[tokio::test]
+    async fn test_penalty_for_deleted_pr_is_canceled() {
+        let agent = test_agent_with_elo(1000);
+        let agent_repo = Arc::new(InMemoryAgentRepository::new().with_agent(agent.clone()));
+        let elo = create_elo_service(agent_repo.clone());
+        let project = test_project();
+        let penalties = Arc::new(InMemoryPendingPenaltyRepository::new());
+        let service =
+            PendingPenaltyService::new(penalties.clone(), Arc::new(MockGiteaClient::new()))
+                .with_grace_period(Duration::minutes(10));
+
+        service
+            .schedule_rejection(&agent.id, &project, 7)
+            .await
+            .unwrap();
+
+        let later = Utc::now() + Duration::minutes(11);
+        assert!(service.process_due(&elo, later).await.unwrap().is_empty());
+        assert_eq!(
+            penalties.get_all_penalties()[0].status,
+            PenaltyStatus::Canceled
+        );
+        let stored = agent_repo.find_by_id(&agent.id).await.unwrap().unwrap();
+        assert_eq!(stored.elo, 1000);
+    }
+
+    #[tokio::test]
+    async fn test_failed_penalty_does_not_abort_sweep() {
+        let agent = test_agent_with_elo(1000);
+        let agent_repo = Arc::new(InMemoryAgentRepository::new().with_agent(agent.clone()));
+        let elo = create_elo_service(agent_repo.clone());
+        let project = test_project();
+        let gitea = Arc::new(
+            MockGiteaClient::new()
+                .with_closed_pr(&project.gitea_org, &project.gitea_repo, 7)
+                .with_closed_pr(&project.gitea_org, &project.gitea_repo, 8),
+        );
+        let penalties = Arc::new(InMemoryPendingPenaltyRepository::new());
+        let service = PendingPenaltyService::new(penalties.clone(), gitea)
+            .with_grace_period(Duration::minutes(10));
+
+        // The first penalty belongs to an agent that no longer exists
+        service
+            .schedule_rejection(&AgentId::new(), &project, 7)
+            .await
+            .unwrap();
+        service
+            .schedule_rejection(&agent.id, &project, 8)
+            .await
+            .unwrap();
+
+        let later = Utc::now() + Duration::minutes(11);
+        let applied = service.process_due(&elo, later).await.unwrap();
+
+        assert_eq!(applied.len(), 1);
+        let stored = agent_repo.find_by_id(&agent.id).await.unwrap().unwrap();
+        assert_eq!(stored.elo, 1000 + ELO_PR_REJECTED);
+        // Resolved before applying, so the failed one is never retried
+        assert!(penalties
+            .get_all_penalties()
+            .iter()
+            .all(|p| p.status == PenaltyStatus::Applied));
+    }
+
+    #[test]
+    fn test_zero_grace_period_is_not_deferred() {
+        let service = PendingPenaltyService::new(
+            Arc::new(InMemoryPendingPenaltyRepository::new()),
+            Arc::new(MockGiteaClient::new()),
+        );
+        assert!(service.is_deferred());
+        assert!(!service.with_grace_period(Duration::zero()).is_deferred());
+    }
+}
@@ -0,0 +1,389 @@
+//! Reaction sync service
+//!
+//! Reactions left directly on Gitea never pass through `/engage`, so they
+//! would otherwise be missing from our engagement counts. This service pulls a
+//! PR's Gitea reactions and folds them in:
+//! - reactions by agents become engagements attributed to that agent
+//! - reactions by other Gitea users are tallied as external counts
+//!
+//! Engagement rows are the single source of truth: a Gitea reaction that an
+//! engagement was mirrored to (its `gitea_id`) is never counted again, even
+//! when it was posted under another account.
+//!
+//! Gitea sends no webhook for reactions, so the open PRs of active projects
+//! are reconciled periodically by a background job rather than on read.
+
+use std::sync::Arc;
+
+use crate::domain::entities::{
+    EngagementCounts, EngagementType, NewEngagement, Project, ProjectStatus, ReactionType,
+    TargetType,
+};
+use crate::domain::ports::{AgentRepository, EngagementRepository, GiteaClient, ProjectRepository};
+use crate::error::AppError;
+
+use super::engagement_service::pr_target_id;
+
+/// Projects fetched per page while sweeping
+const PROJECT_PAGE: i64 = 100;
+
+/// Outcome of reconciling one PR's reactions
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ReactionSyncResult {
+    /// Agent reactions newly recorded as engagements
+    pub attributed: usize,
+    /// Current tally of reactions from non-agent Gitea users
+    pub external: EngagementCounts,
+}
+
+/// Service for pulling Gitea-side reactions into engagement counts
+pub struct ReactionSyncService<ER, AR, GC>
+where
+    ER: EngagementRepository,
+    AR: AgentRepository,
+    GC: GiteaClient,
+{
+    engagements: Arc<ER>,
+    agents: Arc<AR>,
+    gitea: Arc<GC>,
+}
+
+impl<ER, AR, GC> ReactionSyncService<ER, AR, GC>
+where
+    ER: EngagementRepository,
+    AR: AgentRepository,
+    GC: GiteaClient,
+{
+    pub fn new(engagements: Arc<ER>, agents: Arc<AR>, gitea: Arc<GC>) -> Self {
+        Self {
+            engagements,
+            agents,
+            gitea,
+        }
+    }
+
+    /// Reconcile the reactions of every open PR across active projects
+    ///
+    /// Returns the number of PRs reconciled. A project or PR that can't be
+    /// reconciled is logged and skipped so one bad repo doesn't stall the
+    /// sweep.
+    pub async fn reconcile_open_prs<PR: ProjectRepository>(
+        &self,
+        projects: &PR,
+    ) -> Result<usize, AppError> {
+        let mut reconciled = 0;
+        let mut offset = 0;
+        loop {
+            let page = projects.find_all(PROJECT_PAGE, offset).await?;
+            for project in page.iter().filter(|p| p.status == ProjectStatus::Active) {
+                let prs = match self
+                    .gitea
+                    .list_pull_requests(&project.gitea_org, &project.gitea_repo, Some("open"))
+                    .await
+                {
+                    Ok(prs) => prs,
+                    Err(e) => {
+                        tracing::warn!(
+                            project = %project.name,
+                            error = %e,
+                            "Failed to list PRs to reconcile reactions"
+                        );
+                        continue;
+                    }
+                };
+                for pr in prs {
+                    match self.reconcile_pr(project, pr.number).await {
+                        Ok(_) => reconciled += 1,
+                        Err(e) => {
+                            tracing::warn!(
+                                project = %project.name,
+                                pr = pr.number,
+                                error = %e,
+                                "Failed to reconcile PR reactions"
+                            );
+                        }
+                    }
+                }
+            }
+            if (page.len() as i64) < PROJECT_PAGE {
+                return Ok(reconciled);
+            }
+            offset += PROJECT_PAGE;
+        }
+    }
+
+    /// Reconcile a PR's Gitea reactions with its engagement counts
+    ///
+    /// Safe to run repeatedly: agent reactions are only recorded once, and
+    /// the external tally is replaced rather than added to.
+    pub async fn reconcile_pr(
+        &self,
+        project: &Project,
+        pr_number: i64,
+    ) -> Result<ReactionSyncResult, AppError> {
+        let reactions = self
+            .gitea
+            .get_issue_reactions(&project.gitea_org, &project.gitea_repo, pr_number)
+            .await?;
+
+        let target_type = TargetType::Pr.to_string();
+        let target_id = pr_target_id(&project.id, pr_number);
+        let recorded = self
+            .engagements
+            .find_synced_gitea_ids(&target_type, target_id)
+            .await?;
+        let mut result = ReactionSyncResult::default();
+
+        for reaction in reactions {
+            if recorded.contains(&reaction.id) {
+                continue;
+            }
+            let Some(kind) = ReactionType::from_gitea_content(&reaction.content) else {
+                continue;
+            };
+
+            let Some(agent) = self
+                .agents
+                .find_by_gitea_username(&reaction.user.login)
+                .await?
+            else {
+                result.external.record_reaction(kind);
+                continue;
+            };
+
+            if self
+                .engagements
+                .has_reaction(&agent.id, &target_type, target_id, &kind.to_string())
+                .await?
+            {
+                continue;
+            }
+
+            let engagement = self
+                .engagements
+                .create(&NewEngagement {
+                    agent_id: agent.id,
+                    target_type: TargetType::Pr,
+                    target_id,
+                    engagement_type: EngagementType::Reaction,
+                    reaction: Some(kind),
+                    body: None,
+                    gitea_org: None,
+                    gitea_repo: None,
+                })
+                .await?;
+            self.engagements
+                .mark_synced(&engagement.id, reaction.id)
+                .await?;
+            result.attributed += 1;
+        }
+
+        self.engagements
+            .set_external_counts(&target_type, target_id, &result.external)
+            .await?;
+
+        Ok(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{
+        test_agent, test_project, InMemoryAgentRepository, InMemoryEngagementRepository,
+        InMemoryProjectRepository, MockGiteaClient,
+    };
+
+    fn service(
+        engagements: Arc<InMemoryEngagementRepository>,
+        agents: InMemoryAgentRepository,
+        gitea: MockGiteaClient,
+    ) -> ReactionSyncService<InMemoryEngagementRepository, InMemoryAgentRepository, MockGiteaClient>
+    {
+        ReactionSyncService::new(engagements, Arc::new(agents), Arc::new(gitea))
+    }
+
+    #[tokio::test]
+    async fn gitea_reaction_from_outside_user_shows_up_in_counts() {
+        let project = test_project();
+        let engagements = Arc::new(InMemoryEngagementRepository::new());
+        let gitea = MockGiteaClient::new().with_issue_reaction(
+            &project.gitea_org,
+            &project.gitea_repo,
+            42,
+            "human-reviewer",
+            "hooray",
+        );
+        let service = service(engagements.clone(), InMemoryAgentRepository::new(), gitea);
+
+        let before = engagements
+            .get_counts("pr", pr_target_id(&project.id, 42))
+            .await
+            .unwrap();
+        assert_eq!(before.fire_count, 0);
+
+        let result = service.reconcile_pr(&project, 42).await.unwrap();
+
+        assert_eq!(result.attributed, 0);
+        assert_eq!(result.external.fire_count, 1);
+        let after = engagements
+            .get_counts("pr", pr_target_id(&project.id, 42))
+            .await
+            .unwrap();
+        assert_eq!(after.fire_count, 1);
+    }
+
+    #[tokio::test]
+    async fn agent_reaction_on_gitea_is_attributed_once() {
+        let project = test_project();
+        let agent = test_agent();
+        let engagements = Arc::new(InMemoryEngagementRepository::new());
+        let gitea = MockGiteaClient::new().with_issue_reaction(
+            &project.gitea_org,
+            &project.gitea_repo,
+            42,
+            &agent.gitea_username,
+            "heart",
+        );
+        let service = service(
+            engagements.clone(),
+            InMemoryAgentRepository::new().with_agent(agent.clone()),
+            gitea,
+        );
+
+        let first = service.reconcile_pr(&project, 42).await.unwrap();
+        let second = service.reconcile_pr(&project, 42).await.unwrap();
+
+        assert_eq!(first.attributed, 1);
+        assert_eq!(second.attributed, 0);
+        assert_eq!(second.external, EngagementCounts::default());
+        let counts = engagements
+            .get_counts("pr", pr_target_id(&project.id, 42))
+            .await
+            .unwrap();
+        assert_eq!(counts.heart_count, 1);
+        assert!(engagements
+            .has_reaction(&agent.id, "pr", pr_target_id(&project.id, 42), "heart")
+            .await
+            .unwrap());
+    }
+
+    #[tokio::test]
+    async fn reaction_mirrored_from_engage_is_not_counted_twice() {
+        use crate::app::{EngagementAction, EngagementService};
+
+        let project = test_project();
+        let agent = test_agent();
+        let engagements = Arc::new(InMemoryEngagementRepository::new());
+        let gitea = Arc::new(MockGiteaClient::new());
+        // An older reaction mirrored under the admin account before reactions
+        // were posted as the agent
+        let legacy = engagements
+            .create(&NewEngagement {
+                agent_id: agent.id,
+                target_type: TargetType::Pr,
+                target_id: pr_target_id(&project.id, 42),
+                engagement_type: EngagementType::Reaction,
+                reaction: Some(ReactionType::Fire),
+                body: None,
+                gitea_org: Some(project.gitea_org.clone()),
+                gitea_repo: Some(project.gitea_repo.clone()),
+            })
+            .await
+            .unwrap();
+        let admin_reaction = gitea
+            .post_issue_reaction(&project.gitea_org, &project.gitea_repo, 42, "hooray", None)
+            .await
+            .unwrap();
+        engagements
+            .mark_synced(&legacy.id, admin_reaction.id)
+            .await
+            .unwrap();
+
+        let engage = EngagementService::new(engagements.clone(), gitea.clone());
+        let action = EngagementAction::React {
+            reaction: ReactionType::Heart,
+            target_type: TargetType::Pr,
+            target_ref: "42".to_string(),
+        };
+        engage
+            .execute(&agent, action, Some(&project), Some(&agent.gitea_username))
+            .await
+            .unwrap();
+
+        let posted = gitea
+            .get_issue_reactions(&project.gitea_org, &project.gitea_repo, 42)
+            .await
+            .unwrap();
+        assert_eq!(posted.last().unwrap().user.login, agent.gitea_username);
+
+        let reconcile = ReactionSyncService::new(
+            engagements.clone(),
+            Arc::new(InMemoryAgentRepository::new().with_agent(agent.clone())),
+            gitea,
+        );
+        let result = reconcile.reconcile_pr(&project, 42).await.unwrap();
+
+        assert_eq!(result.attributed, 0);
+        assert_eq!(result.external, EngagementCounts::default());
+        let counts = engagements
+            .get_counts("pr", pr_target_id(&project.id, 42))
+            .await
+            .unwrap();
+        assert_eq!(counts.fire_count, 1);
+        assert_eq!(counts.heart_count, 1);
+    }
+
+    #[tokio::test]
+    async fn same_pr_number_in_two_projects_keeps_separate_counts() {
+        let first = test_project();
+        let mut second = test_project();
+        second.gitea_repo = "other-repo".to_string();
+        let engagements = Arc::new(InMemoryEngagementRepository::new());
+        let gitea = MockGiteaClient::new().with_issue_reaction(
+            &first.gitea_org,
+            &first.gitea_repo,
+            42,
+            "human-reviewer",
+            "hooray",
+        );
+        let service = service(engagements.clone(), InMemoryAgentRepository::new(), gitea);
+
+        service.reconcile_pr(&first, 42).await.unwrap();
+        service.reconcile_pr(&second, 42).await.unwrap();
+
+        let counts = |project: &Project| {
+            let engagements = engagements.clone();
+            let target_id = pr_target_id(&project.id, 42);
+            async move { engagements.get_counts("pr", target_id).await.unwrap() }
+        };
+        assert_eq!(counts(&first).await.fire_count, 1);
+        assert_eq!(counts(&second).await.fire_count, 0);
+    }
+
+    #[tokio::test]
+    async fn sweep_reconciles_open_prs_of_active_projects() {
+        let project = test_project();
+        let engagements = Arc::new(InMemoryEngagementRepository::new());
+        let gitea = MockGiteaClient::new()
+            .with_pr(&project.gitea_org, &project.gitea_repo, 42)
+            .with_issue_reaction(
+                &project.gitea_org,
+                &project.gitea_repo,
+                42,
+                "human-reviewer",
+                "hooray",
+            );
+        let service = service(engagements.clone(), InMemoryAgentRepository::new(), gitea);
+        let projects = InMemoryProjectRepository::new().with_project(project.clone());
+
+        let reconciled = service.reconcile_open_prs(&projects).await.unwrap();
+
+        assert_eq!(reconciled, 1);
+        let counts = engagements
+            .get_counts("pr", pr_target_id(&project.id, 42))
+            .await
+            .unwrap();
+        assert_eq!(counts.fire_count, 1);
+    }
+}
@@ -3,25 +3,47 @@
 //! Handles dynamic ELO adjustments based on code contribution outcomes over time.
 //! All ELO changes go through this service to ensure audit logging and consistency.
 
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 
-use chrono::{Duration, Utc};
+use chrono::{DateTime, Duration, Utc};
+use futures_util::stream::{self, StreamExt};
+use serde::Serialize;
 
 use crate::app::elo_config::{
-    ELO_BUG_REFERENCED, ELO_CODE_REPLACED, ELO_COMMIT_REVERTED, ELO_DEPENDENT_PR,
-    ELO_HIGH_ELO_APPROVAL, ELO_LONGEVITY_BONUS, ELO_LOW_PEER_REVIEW, ELO_PR_MERGED,
-    ELO_PR_REJECTED, HIGH_ELO_THRESHOLD, LONGEVITY_DAYS, MAX_REVIEWS_PER_HOUR,
+    BAD_APPROVAL_WINDOW_DAYS, ELO_BAD_APPROVAL, ELO_BUG_REFERENCED, ELO_CODE_REPLACED,
+    ELO_COMMIT_REVERTED, ELO_DEPENDENT_PR, ELO_FLOOR, ELO_HIGH_ELO_APPROVAL, ELO_LONGEVITY_BONUS,
+    ELO_LOW_PEER_REVIEW, ELO_MULTIPLIER_MAX, ELO_MULTIPLIER_MIN, ELO_PR_MERGED, ELO_PR_REJECTED,
+    ELO_REVIEW_ABANDONED, ELO_SOFT_CAP_SPAN, ELO_STARTING, ELO_TICKET_ABANDONED,
+    HIGH_ELO_THRESHOLD, LONGEVITY_BATCH_CONCURRENCY, LONGEVITY_DAYS, MAX_DEPENDENCIES_PER_PR,
+    MAX_DEPENDENT_AWARDS_PER_PR, MAX_REVIEWS_PER_HOUR, MERGE_COOLDOWN_SECS,
     REPLACEMENT_WINDOW_DAYS,
 };
 use crate::domain::entities::{
-    AgentId, CodeContribution, CodeContributionId, ContributionStatus, EloEventType,
-    NewAgentReview, NewCodeContribution, NewEloEvent, ProjectId, ReviewVerdict,
+    Agent, AgentId, AgentReview, CodeContribution, CodeContributionId, CodeReference,
+    ContributionStatus, Difficulty, DifficultyWeights, EloDrift, EloEventType, NewAgentReview,
+    NewCodeContribution, NewEloEvent, ProbationPolicy, Project, ProjectId, ReviewRequest,
+    ReviewVerdict, SelfReviewPolicy, Ticket, Tier,
 };
 use crate::domain::ports::{
-    AgentRepository, AgentReviewRepository, CodeContributionRepository, EloEventRepository,
+    AgentRepository, AgentReviewRepository, BugAttribution, CodeContributionRepository,
+    EloEventRepository, EloIntegrityRepository, GiteaClient, GiteaPullRequest, NoopNotifier,
+    Notification, Notifier, ProjectRepository,
 };
 use crate::error::{AppError, DomainError};
 
+/// Pull requests fetched per page when backfilling an imported project
+pub const BACKFILL_PAGE_SIZE: usize = 50;
+
+/// Outcome of backfilling an imported project's merged PRs
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct BackfillReport {
+    /// Contributions created
+    pub created: usize,
+    /// Merged PRs whose contribution couldn't be recorded
+    pub failed: usize,
+}
+
 /// Result of an ELO change operation
 #[derive(Debug, Clone)]
 pub struct EloChangeResult {
@@ -33,41 +55,336 @@ pub struct EloChangeResult {
     pub message: String,
 }
 
+/// Outcome an ELO change would have, computed without applying it
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct EloSimulation {
+    pub agent_id: AgentId,
+    pub event_type: EloEventType,
+    /// Delta after any project multiplier, before the daily cap and ELO bounds
+    pub requested_delta: i32,
+    /// Delta that would be applied
+    pub delta: i32,
+    pub old_elo: i32,
+    pub new_elo: i32,
+    pub old_tier: Tier,
+    pub new_tier: Tier,
+    /// Why the delta was scaled or clamped, if it was
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub details: Option<String>,
+}
+
+/// Limits on an agent's ELO: a hard floor and ceiling, plus a soft cap above
+/// which gains are dampened so leaders can't run away with the board
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EloBounds {
+    pub floor: i32,
+    /// Hard maximum (unbounded when unset)
+    pub ceiling: Option<i32>,
+    /// ELO above which gains shrink (disabled when unset)
+    pub soft_cap: Option<i32>,
+    /// Points past the soft cap at which a gain is worth half
+    pub soft_cap_span: i32,
+}
+
+impl Default for EloBounds {
+    fn default() -> Self {
+        Self {
+            floor: ELO_FLOOR,
+            ceiling: None,
+            soft_cap: None,
+            soft_cap_span: ELO_SOFT_CAP_SPAN,
+        }
+    }
+}
+
+impl EloBounds {
+    /// The part of a gain earned past the soft cap is scaled by
+    /// `span / (span + points past the cap)`. Losses are never dampened.
+    pub fn dampen(&self, elo: i32, delta: i32) -> i32 {
+        let Some(cap) = self.soft_cap else {
+            return delta;
+        };
+        if delta <= 0 {
+            return delta;
+        }
+        let undamped = (cap - elo).clamp(0, delta);
+        let above = delta - undamped;
+        let span = f64::from(self.soft_cap_span.max(1));
+        let past_cap = f64::from((elo - cap).max(0));
+        let factor = span / (span + past_cap);
+        undamped + (f64::from(above) * factor).floor() as i32
+    }
+
+    /// Largest gain allowed from `elo` without passing the ceiling
+    fn limit_gain(&self, elo: i32, delta: i32) -> i32 {
+        match self.ceiling {
+            Some(ceiling) if delta > 0 => delta.min((ceiling - elo).max(0)),
+            _ => delta,
+        }
+    }
+}
+
+/// Window over which leaderboard movers are measured
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum MoversRange {
+    Day,
+    #[default]
+    Week,
+    Month,
+}
+
+impl MoversRange {
+    /// Start of the window ending at `now`
+    pub fn since(&self, now: DateTime<Utc>) -> DateTime<Utc> {
+        match self {
+            MoversRange::Day => now - Duration::days(1),
+            MoversRange::Week => now - Duration::weeks(1),
+            MoversRange::Month => now - Duration::days(30),
+        }
+    }
+}
+
+impl std::fmt::Display for MoversRange {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MoversRange::Day => write!(f, "day"),
+            MoversRange::Week => write!(f, "week"),
+            MoversRange::Month => write!(f, "month"),
+        }
+    }
+}
+
+impl std::str::FromStr for MoversRange {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "day" => Ok(MoversRange::Day),
+            "week" => Ok(MoversRange::Week),
+            "month" => Ok(MoversRange::Month),
+            _ => Err(format!("Unknown range '{}'. Use: day, week, month", s)),
+        }
+    }
+}
+
+/// An agent's net ELO change over a window
+#[derive(Debug, Clone)]
+pub struct EloMover {
+    pub agent: Agent,
+    pub delta: i64,
+}
+
+/// Biggest net ELO gains and losses over a window
+#[derive(Debug, Clone)]
+pub struct LeaderboardMovers {
+    pub since: DateTime<Utc>,
+    /// Largest gain first
+    pub gainers: Vec<EloMover>,
+    /// Largest loss first
+    pub losers: Vec<EloMover>,
+}
+
+/// Verdicts one agent gave another in peer reviews
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ReviewTally {
+    pub approved: usize,
+    pub changes_requested: usize,
+}
+
+/// One agent's side of a head-to-head record
+#[derive(Debug, Clone)]
+pub struct RivalRecord {
+    pub agent: Agent,
+    /// Verdicts this agent gave the rival
+    pub reviews_given: ReviewTally,
+    /// Contributions merged into projects both agents contributed to
+    pub contested_contributions: usize,
+    /// Contested contributions still healthy (not reverted or replaced)
+    pub contested_surviving: usize,
+}
+
+/// Head-to-head record between two agents
+#[derive(Debug, Clone)]
+pub struct HeadToHead {
+    pub a: RivalRecord,
+    pub b: RivalRecord,
+    /// Projects both agents have merged contributions in
+    pub shared_projects: Vec<ProjectId>,
+    /// Agent with more surviving contested contributions; None when tied
+    pub leader: Option<AgentId>,
+}
+
+/// Which side of a peer review an agent was on
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ReviewDirection {
+    /// Reviews the agent wrote
+    #[default]
+    Given,
+    /// Reviews of the agent's PRs
+    Received,
+}
+
+impl std::fmt::Display for ReviewDirection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ReviewDirection::Given => write!(f, "given"),
+            ReviewDirection::Received => write!(f, "received"),
+        }
+    }
+}
+
+impl std::str::FromStr for ReviewDirection {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "given" => Ok(ReviewDirection::Given),
+            "received" => Ok(ReviewDirection::Received),
+            _ => Err(format!("Unknown direction '{}'. Use: given, received", s)),
+        }
+    }
+}
+
+/// A peer review joined with the other agent and the project
+#[derive(Debug, Clone)]
+pub struct ReviewRecord {
+    pub review: AgentReview,
+    /// The reviewed agent for given reviews, the reviewer for received ones;
+    /// None if that agent no longer exists
+    pub counterpart: Option<Agent>,
+    /// None if the project no longer exists
+    pub project_name: Option<String>,
+}
+
 /// Service for reactive ELO calculations
-pub struct ReactiveEloService<AR, CCR, ARR, EER>
+pub struct ReactiveEloService<AR, CCR, ARR, EER, PR>
 where
     AR: AgentRepository,
     CCR: CodeContributionRepository,
     ARR: AgentReviewRepository,
     EER: EloEventRepository,
+    PR: ProjectRepository,
 {
     agents: Arc<AR>,
     contributions: Arc<CCR>,
     reviews: Arc<ARR>,
     elo_events: Arc<EER>,
+    projects: Arc<PR>,
+    merge_cooldown: Duration,
+    daily_cap: Option<i32>,
+    ticket_abandon_penalty: i32,
+    review_abandon_penalty: i32,
+    difficulty_weights: DifficultyWeights,
+    longevity_concurrency: usize,
+    self_review_policy: SelfReviewPolicy,
+    notifier: Arc<dyn Notifier>,
+    elo_bounds: EloBounds,
+    probation: Option<ProbationPolicy>,
+    bug_attribution: Option<Arc<dyn BugAttribution>>,
 }
 
-impl<AR, CCR, ARR, EER> ReactiveEloService<AR, CCR, ARR, EER>
+impl<AR, CCR, ARR, EER, PR> ReactiveEloService<AR, CCR, ARR, EER, PR>
 where
     AR: AgentRepository,
     CCR: CodeContributionRepository,
     ARR: AgentReviewRepository,
     EER: EloEventRepository,
+    PR: ProjectRepository,
 {
     pub fn new(
         agents: Arc<AR>,
         contributions: Arc<CCR>,
         reviews: Arc<ARR>,
         elo_events: Arc<EER>,
+        projects: Arc<PR>,
     ) -> Self {
         Self {
             agents,
             contributions,
             reviews,
             elo_events,
+            projects,
+            merge_cooldown: Duration::seconds(MERGE_COOLDOWN_SECS),
+            daily_cap: None,
+            ticket_abandon_penalty: ELO_TICKET_ABANDONED,
+            review_abandon_penalty: ELO_REVIEW_ABANDONED,
+            difficulty_weights: DifficultyWeights::default(),
+            longevity_concurrency: LONGEVITY_BATCH_CONCURRENCY,
+            self_review_policy: SelfReviewPolicy::default(),
+            notifier: Arc::new(NoopNotifier),
+            elo_bounds: EloBounds::default(),
+            probation: None,
+            bug_attribution: None,
         }
     }
 
+    /// Limit an agent's net ELO change per UTC day to `[-cap, +cap]`
+    pub fn with_daily_cap(mut self, cap: Option<i32>) -> Self {
+        self.daily_cap = cap;
+        self
+    }
+
+    /// Set the window in which repeated merges earn diminishing ELO
+    pub fn with_merge_cooldown(mut self, cooldown: Duration) -> Self {
+        self.merge_cooldown = cooldown;
+        self
+    }
+
+    /// Set the ELO delta applied when an agent abandons a claimed ticket
+    pub fn with_ticket_abandon_penalty(mut self, delta: i32) -> Self {
+        self.ticket_abandon_penalty = delta;
+        self
+    }
+
+    /// Set the ELO delta applied when a requested reviewer never answers
+    pub fn with_review_abandon_penalty(mut self, delta: i32) -> Self {
+        self.review_abandon_penalty = delta;
+        self
+    }
+
+    /// Set how much the merge award is scaled for each ticket difficulty
+    pub fn with_difficulty_weights(mut self, weights: DifficultyWeights) -> Self {
+        self.difficulty_weights = weights;
+        self
+    }
+
+    /// Set which reviewer/author pairs count as self-reviews
+    pub fn with_self_review_policy(mut self, policy: SelfReviewPolicy) -> Self {
+        self.self_review_policy = policy;
+        self
+    }
+
+    /// Set the ELO floor, ceiling and soft cap
+    pub fn with_elo_bounds(mut self, bounds: EloBounds) -> Self {
+        self.elo_bounds = bounds;
+        self
+    }
+
+    /// Make reverts steeper for agents still on probation
+    pub fn with_probation(mut self, policy: Option<ProbationPolicy>) -> Self {
+        self.probation = policy;
+        self
+    }
+
+    /// Only penalize bug references after confirming the PR changed the code
+    /// the bug report points at (`None` penalizes every reference)
+    pub fn with_bug_attribution(mut self, attribution: Option<Arc<dyn BugAttribution>>) -> Self {
+        self.bug_attribution = attribution;
+        self
+    }
+
+    /// Tell agents about their ELO changes through this notifier
+    pub fn with_notifier(mut self, notifier: Arc<dyn Notifier>) -> Self {
+        self.notifier = notifier;
+        self
+    }
+
+    /// Set how many agents' longevity bonuses are processed at once (at least 1)
+    pub fn with_longevity_concurrency(mut self, limit: usize) -> Self {
+        self.longevity_concurrency = limit.max(1);
+        self
+    }
+
     /// Apply an ELO change to an agent's elo with full audit logging.
     /// This is the single point through which all ELO modifications flow.
     pub async fn apply_elo_change(
@@ -77,6 +394,21 @@ where
         event_type: EloEventType,
         reference_id: Option<uuid::Uuid>,
         details: Option<String>,
+    ) -> Result<EloChangeResult, AppError> {
+        self.apply_pr_elo_change(agent_id, delta, event_type, reference_id, details, None)
+            .await
+    }
+
+    /// [`Self::apply_elo_change`] for a change caused by a PR, which the
+    /// agent's notification then points at
+    async fn apply_pr_elo_change(
+        &self,
+        agent_id: &AgentId,
+        delta: i32,
+        event_type: EloEventType,
+        reference_id: Option<uuid::Uuid>,
+        details: Option<String>,
+        pr_number: Option<i64>,
     ) -> Result<EloChangeResult, AppError> {
         let agent = self
             .agents
@@ -85,7 +417,7 @@ where
             .ok_or_else(|| DomainError::NotFound(format!("Agent not found: {}", agent_id)))?;
 
         let old_elo = agent.elo;
-        let new_elo = (old_elo + delta).max(0); // ELO can't go below 0
+        let (delta, new_elo, details) = self.bounded_change(&agent, delta, details).await?;
 
         // Update agent ELO
         self.agents.update_elo(agent_id, new_elo).await?;
@@ -111,6 +443,20 @@ where
             "ELO change applied"
         );
 
+        if delta != 0 {
+            let notification = Notification {
+                notification_type: event_type.to_string(),
+                pr_number,
+                title: format!("ELO {:+} ({} -> {})", delta, old_elo, new_elo),
+                message: details,
+                elo_change: Some(delta),
+                created_at: Utc::now(),
+            };
+            if let Err(e) = self.notifier.notify(agent_id, notification).await {
+                tracing::warn!(agent_id = %agent_id, error = %e, "Failed to send ELO notification");
+            }
+        }
+
         Ok(EloChangeResult {
             agent_id: *agent_id,
             old_elo,
@@ -124,63 +470,603 @@ where
         })
     }
 
+    /// Agents whose stored ELO has drifted from what their events add up to,
+    /// largest drift first
+    pub async fn find_elo_drift<EIR: EloIntegrityRepository>(
+        &self,
+        integrity: &EIR,
+        limit: i64,
+    ) -> Result<Vec<EloDrift>, AppError> {
+        Ok(integrity.find_elo_drift(ELO_STARTING, limit).await?)
+    }
+
+    /// What `apply_elo_change` would do for this event, without writing
+    /// anything or notifying the agent. `delta` defaults to the event type's
+    /// base delta, and is scaled by `project`'s ELO multiplier when given.
+    pub async fn simulate_elo_change(
+        &self,
+        agent_id: &AgentId,
+        event_type: EloEventType,
+        delta: Option<i32>,
+        project: Option<&ProjectId>,
+    ) -> Result<EloSimulation, AppError> {
+        let agent = self
+            .agents
+            .find_by_id(agent_id)
+            .await?
+            .ok_or_else(|| DomainError::NotFound(format!("Agent not found: {}", agent_id)))?;
+
+        let delta = delta.unwrap_or_else(|| self.base_delta(event_type));
+        let (requested_delta, details) = match project {
+            Some(project_id) => {
+                self.apply_project_multiplier(project_id, delta, String::new())
+                    .await?
+            }
+            None => (delta, String::new()),
+        };
+        let details = Some(details.trim_start().to_string()).filter(|d| !d.is_empty());
+        let (delta, new_elo, details) = self
+            .bounded_change(&agent, requested_delta, details)
+            .await?;
+
+        Ok(EloSimulation {
+            agent_id: *agent_id,
+            event_type,
+            requested_delta,
+            delta,
+            old_elo: agent.elo,
+            new_elo,
+            old_tier: Tier::from_elo(agent.elo),
+            new_tier: Tier::from_elo(new_elo),
+            details,
+        })
+    }
+
+    /// The ELO delta an event of this type awards before scaling and clamping
+    pub fn base_delta(&self, event_type: EloEventType) -> i32 {
+        match event_type {
+            EloEventType::PrMerged => ELO_PR_MERGED,
+            EloEventType::HighEloApproval => ELO_HIGH_ELO_APPROVAL,
+            EloEventType::LongevityBonus => ELO_LONGEVITY_BONUS,
+            EloEventType::DependentPr => ELO_DEPENDENT_PR,
+            EloEventType::CommitReverted => ELO_COMMIT_REVERTED,
+            EloEventType::BugReferenced => ELO_BUG_REFERENCED,
+            EloEventType::PrRejected => ELO_PR_REJECTED,
+            EloEventType::LowPeerReviewScore => ELO_LOW_PEER_REVIEW,
+            EloEventType::CodeReplaced => ELO_CODE_REPLACED,
+            EloEventType::TicketAbandoned => self.ticket_abandon_penalty,
+            EloEventType::BadApproval => ELO_BAD_APPROVAL,
+            EloEventType::ReviewAbandoned => self.review_abandon_penalty,
+        }
+    }
+
+    /// The delta an agent actually gets for `delta` after the daily cap and
+    /// ELO bounds, and the resulting ELO. Any clamping is noted in `details`.
+    async fn bounded_change(
+        &self,
+        agent: &Agent,
+        delta: i32,
+        details: Option<String>,
+    ) -> Result<(i32, i32, Option<String>), AppError> {
+        let (delta, details) = match self.daily_cap {
+            Some(cap) => {
+                let start_of_day = Utc::now()
+                    .date_naive()
+                    .and_hms_opt(0, 0, 0)
+                    .expect("midnight is a valid time")
+                    .and_utc();
+                let today = self
+                    .elo_events
+                    .sum_delta_by_agent_since(&agent.id, start_of_day)
+                    .await?;
+                let clamped = clamp_to_daily_cap(delta, today, cap);
+                if clamped == delta {
+                    (delta, details)
+                } else {
+                    let note = format!(
+                        "daily ELO cap: {:+} clamped to {:+} (today {:+}, cap ±{})",
+                        delta, clamped, today, cap
+                    );
+                    let details = match details {
+                        Some(d) => format!("{} [{}]", d, note),
+                        None => note,
+                    };
+                    (clamped, Some(details))
+                }
+            }
+            None => (delta, details),
+        };
+
+        let old_elo = agent.elo;
+        let bounded = self
+            .elo_bounds
+            .limit_gain(old_elo, self.elo_bounds.dampen(old_elo, delta));
+        let details = if bounded == delta {
+            details
+        } else {
+            let note = format!(
+                "ELO soft cap/ceiling: {:+} dampened to {:+} (ELO {})",
+                delta, bounded, old_elo
+            );
+            Some(match details {
+                Some(d) => format!("{} [{}]", d, note),
+                None => note,
+            })
+        };
+        let delta = bounded;
+        let new_elo = (old_elo + delta).max(self.elo_bounds.floor);
+
+        Ok((delta, new_elo, details))
+    }
+
+    /// Scale a delta earned on a project by that project's ELO multiplier,
+    /// noting the multiplier in `details` when it isn't 1.0
+    async fn apply_project_multiplier(
+        &self,
+        project_id: &ProjectId,
+        delta: i32,
+        details: String,
+    ) -> Result<(i32, String), AppError> {
+        let multiplier = self
+            .projects
+            .find_by_id(project_id)
+            .await?
+            .map_or(1.0, |p| p.elo_multiplier);
+        Ok(scale_by_multiplier(delta, multiplier, details))
+    }
+
+    /// List an agent's contributions, optionally only those with `status`
+    pub async fn list_contributions(
+        &self,
+        agent_id: &AgentId,
+        status: Option<ContributionStatus>,
+    ) -> Result<Vec<CodeContribution>, AppError> {
+        let contributions = match status {
+            Some(status) => {
+                self.contributions
+                    .find_by_agent_and_status(agent_id, status)
+                    .await?
+            }
+            None => self.contributions.find_by_agent(agent_id).await?,
+        };
+        Ok(contributions)
+    }
+
+    /// The contribution recorded for a PR when it merged
+    ///
+    /// Fails with `NotFound` for PRs that aren't tracked, e.g. ones that
+    /// haven't merged yet.
+    pub async fn get_pr_contribution(
+        &self,
+        project_id: &ProjectId,
+        pr_number: i64,
+    ) -> Result<CodeContribution, AppError> {
+        self.contributions
+            .find_by_pr(project_id, pr_number)
+            .await?
+            .ok_or_else(|| {
+                AppError::NotFound(format!("No contribution recorded for PR #{}", pr_number))
+            })
+    }
+
+    /// Agents with the biggest net ELO gains and losses over `range`,
+    /// at most `limit` of each
+    pub async fn leaderboard_movers(
+        &self,
+        range: MoversRange,
+        limit: usize,
+    ) -> Result<LeaderboardMovers, AppError> {
+        let since = range.since(Utc::now());
+        let mut deltas = self.elo_events.net_deltas_since(since).await?;
+        // Biggest gain first; ties broken by agent id so results are stable
+        deltas.sort_by(|a, b| b.1.cmp(&a.1).then(a.0 .0.cmp(&b.0 .0)));
+
+        let gainers: Vec<(AgentId, i64)> = deltas
+            .iter()
+            .filter(|(_, delta)| *delta > 0)
+            .take(limit)
+            .copied()
+            .collect();
+        let losers: Vec<(AgentId, i64)> = deltas
+            .iter()
+            .rev()
+            .filter(|(_, delta)| *delta < 0)
+            .take(limit)
+            .copied()
+            .collect();
+
+        Ok(LeaderboardMovers {
+            since,
+            gainers: self.resolve_movers(gainers).await?,
+            losers: self.resolve_movers(losers).await?,
+        })
+    }
+
+    /// Head-to-head record between two agents: the review verdicts each gave
+    /// the other, and how their contributions to shared projects held up.
+    pub async fn get_head_to_head(
+        &self,
+        agent_a: &AgentId,
+        agent_b: &AgentId,
+    ) -> Result<HeadToHead, AppError> {
+        if agent_a == agent_b {
+            return Err(AppError::BadRequest(
+                "An agent has no head-to-head record with itself".to_string(),
+            ));
+        }
+        let a = self.find_agent(agent_a).await?;
+        let b = self.find_agent(agent_b).await?;
+
+        let a_reviews = self.review_tally(agent_a, agent_b).await?;
+        let b_reviews = self.review_tally(agent_b, agent_a).await?;
+
+        let shared_projects = self
+            .contributions
+            .find_shared_projects(agent_a, agent_b)
+            .await?;
+        let (a_contested, a_surviving) = self.contested(agent_a, &shared_projects).await?;
+        let (b_contested, b_surviving) = self.contested(agent_b, &shared_projects).await?;
+
+        let leader = match a_surviving.cmp(&b_surviving) {
+            std::cmp::Ordering::Greater => Some(*agent_a),
+            std::cmp::Ordering::Less => Some(*agent_b),
+            std::cmp::Ordering::Equal => None,
+        };
+
+        Ok(HeadToHead {
+            a: RivalRecord {
+                agent: a,
+                reviews_given: a_reviews,
+                contested_contributions: a_contested,
+                contested_surviving: a_surviving,
+            },
+            b: RivalRecord {
+                agent: b,
+                reviews_given: b_reviews,
+                contested_contributions: b_contested,
+                contested_surviving: b_surviving,
+            },
+            shared_projects,
+            leader,
+        })
+    }
+
+    /// Verdicts `reviewer` gave `reviewed`
+    async fn review_tally(
+        &self,
+        reviewer: &AgentId,
+        reviewed: &AgentId,
+    ) -> Result<ReviewTally, AppError> {
+        let count = |verdict| self.reviews.count_between(reviewer, reviewed, verdict);
+        Ok(ReviewTally {
+            approved: count(ReviewVerdict::Approved).await? as usize,
+            changes_requested: count(ReviewVerdict::ChangesRequested).await? as usize,
+        })
+    }
+
+    /// An agent's contributions to `projects` and how many are still healthy
+    async fn contested(
+        &self,
+        agent: &AgentId,
+        projects: &[ProjectId],
+    ) -> Result<(usize, usize), AppError> {
+        let total = self
+            .contributions
+            .count_in_projects(agent, projects, None)
+            .await?;
+        let surviving = self
+            .contributions
+            .count_in_projects(agent, projects, Some(ContributionStatus::Healthy))
+            .await?;
+        Ok((total as usize, surviving as usize))
+    }
+
+    /// One page of the reviews an agent gave or received, newest first,
+    /// with the total across all pages
+    pub async fn list_reviews(
+        &self,
+        agent_id: &AgentId,
+        direction: ReviewDirection,
+        limit: usize,
+        offset: usize,
+    ) -> Result<(Vec<ReviewRecord>, usize), AppError> {
+        let (limit, offset) = (limit as i64, offset as i64);
+        let (reviews, total) = match direction {
+            ReviewDirection::Given => (
+                self.reviews
+                    .find_by_reviewer_paginated(agent_id, limit, offset)
+                    .await?,
+                self.reviews.count_by_reviewer(agent_id).await?,
+            ),
+            ReviewDirection::Received => (
+                self.reviews
+                    .find_by_reviewed_paginated(agent_id, limit, offset)
+                    .await?,
+                self.reviews.count_by_reviewed(agent_id).await?,
+            ),
+        };
+        let counterpart_of = |review: &AgentReview| match direction {
+            ReviewDirection::Given => review.reviewed_agent_id,
+            ReviewDirection::Received => review.reviewer_agent_id,
+        };
+
+        let mut agent_ids: Vec<AgentId> = reviews.iter().map(counterpart_of).collect();
+        agent_ids.sort_by_key(|id| id.0);
+        agent_ids.dedup();
+        let mut project_ids: Vec<ProjectId> = reviews.iter().map(|r| r.project_id).collect();
+        project_ids.sort_by_key(|id| id.0);
+        project_ids.dedup();
+        let agents: HashMap<AgentId, Agent> = self
+            .agents
+            .find_by_ids(&agent_ids)
+            .await?
+            .into_iter()
+            .map(|a| (a.id, a))
+            .collect();
+        let project_names: HashMap<ProjectId, String> = self
+            .projects
+            .find_by_ids(&project_ids)
+            .await?
+            .into_iter()
+            .map(|p| (p.id, p.name))
+            .collect();
+
+        let records = reviews
+            .into_iter()
+            .map(|review| ReviewRecord {
+                counterpart: agents.get(&counterpart_of(&review)).cloned(),
+                project_name: project_names.get(&review.project_id).cloned(),
+                review,
+            })
+            .collect();
+
+        Ok((records, total as usize))
+    }
+
+    async fn find_agent(&self, id: &AgentId) -> Result<Agent, AppError> {
+        self.agents
+            .find_by_id(id)
+            .await?
+            .ok_or_else(|| AppError::NotFound(format!("Agent {} not found", id)))
+    }
+
+    /// Attach agent records to (agent, delta) pairs, skipping deleted agents
+    async fn resolve_movers(&self, deltas: Vec<(AgentId, i64)>) -> Result<Vec<EloMover>, AppError> {
+        let mut movers = Vec::with_capacity(deltas.len());
+        for (agent_id, delta) in deltas {
+            if let Some(agent) = self.agents.find_by_id(&agent_id).await? {
+                movers.push(EloMover { agent, delta });
+            }
+        }
+        Ok(movers)
+    }
+
     /// Handle a PR being merged in Ant Farm mode.
     /// Creates a CodeContribution record and awards +15 ELO.
+    /// The award halves for each earlier merge by the same agent on the same
+    /// project within the merge cooldown, so rapid merge/revert cycles can't farm ELO.
+    /// The award is then scaled by the difficulty of the ticket the PR resolves.
+    /// Returns None when the PR's contribution is already recorded (e.g. a
+    /// redelivered webhook), without awarding ELO again.
     pub async fn on_pr_merged(
         &self,
         agent_id: &AgentId,
         project_id: &ProjectId,
         pr_number: i64,
         commit_sha: &str,
-    ) -> Result<EloChangeResult, AppError> {
-        // Create contribution record
-        let contribution = NewCodeContribution {
-            agent_id: *agent_id,
-            project_id: *project_id,
-            pr_number,
-            commit_sha: commit_sha.to_string(),
-            merged_at: Utc::now(),
+        difficulty: Difficulty,
+    ) -> Result<Option<EloChangeResult>, AppError> {
+        // A redelivered merge webhook must not record or award the PR twice.
+        // A contribution whose award never landed (the ELO write failed after
+        // it was recorded) is paid on the retry; backfilled contributions are
+        // settled from the start and never earn a merge award.
+        let existing = self.contributions.find_by_pr(project_id, pr_number).await?;
+        if let Some(contribution) = &existing {
+            if contribution.longevity_bonus_paid || self.merge_award_paid(contribution).await? {
+                return Ok(None);
+            }
+        }
+
+        let merged_at = existing.as_ref().map_or_else(Utc::now, |c| c.merged_at);
+        let mut recent_merges = self
+            .contributions
+            .count_by_agent_and_project_since(agent_id, project_id, merged_at - self.merge_cooldown)
+            .await?;
+
+        let created = match existing {
+            // Don't count the retried merge against itself
+            Some(contribution) => {
+                recent_merges = (recent_merges - 1).max(0);
+                contribution
+            }
+            None => {
+                let contribution = NewCodeContribution {
+                    agent_id: *agent_id,
+                    project_id: *project_id,
+                    pr_number,
+                    commit_sha: commit_sha.to_string(),
+                    merged_at,
+                };
+                self.contributions.create(&contribution).await?
+            }
         };
 
-        let created = self.contributions.create(&contribution).await?;
+        let award = self
+            .difficulty_weights
+            .scale(diminished_merge_award(recent_merges), difficulty);
+        let (delta, mut details) = self
+            .apply_project_multiplier(
+                project_id,
+                award,
+                format!(
+                    "PR #{} merged in project {} ({} difficulty)",
+                    pr_number, project_id, difficulty
+                ),
+            )
+            .await?;
+        if recent_merges > 0 {
+            details.push_str(&format!(
+                " (cooldown: {} recent merges, award reduced to {:+})",
+                recent_merges, delta
+            ));
+        }
 
         // Award ELO
-        self.apply_elo_change(
+        self.apply_pr_elo_change(
             agent_id,
-            ELO_PR_MERGED,
+            delta,
             EloEventType::PrMerged,
             Some(created.id.0),
-            Some(format!(
-                "PR #{} merged in project {}",
-                pr_number, project_id
-            )),
+            Some(details),
+            Some(pr_number),
         )
         .await
+        .map(Some)
     }
 
-    /// Handle a peer review submission.
-    /// Awards +5 ELO if reviewer is high-ELO and approved.
-    pub async fn on_peer_review(
+    /// Whether the merge award for `contribution` was already recorded
+    async fn merge_award_paid(&self, contribution: &CodeContribution) -> Result<bool, AppError> {
+        Ok(self
+            .elo_events
+            .find_by_reference(contribution.id.0)
+            .await?
+            .iter()
+            .any(|e| e.event_type == EloEventType::PrMerged))
+    }
+
+    /// Record contributions for PRs merged before a project was imported,
+    /// paging through the repo's closed PRs. No ELO is awarded; this only seeds
+    /// history so later reverts and bug reports can reference the original
+    /// work. Backfilled contributions never earn a longevity bonus, since they
+    /// weren't merged through SynStack.
+    ///
+    /// A PR that fails to record is logged and counted in the report; failing
+    /// to list a page stops the backfill with an error.
+    pub async fn backfill_contributions<G: GiteaClient>(
         &self,
-        pr_id: i64,
-        project_id: &ProjectId,
-        reviewer_agent_id: &AgentId,
-        reviewed_agent_id: &AgentId,
-        verdict: ReviewVerdict,
-    ) -> Result<Option<EloChangeResult>, AppError> {
-        // Validate: no self-reviews
-        if reviewer_agent_id == reviewed_agent_id {
-            return Err(AppError::Domain(DomainError::Validation(
-                "Cannot review your own PR".to_string(),
-            )));
+        gitea: &G,
+        project: &Project,
+    ) -> Result<BackfillReport, AppError> {
+        let mut report = BackfillReport::default();
+
+        for page in 1.. {
+            let prs = gitea
+                .list_pull_requests_page(
+                    &project.gitea_org,
+                    &project.gitea_repo,
+                    Some("closed"),
+                    page,
+                    BACKFILL_PAGE_SIZE,
+                )
+                .await?;
+
+            for pr in prs.iter().filter(|pr| pr.merged) {
+                match self.backfill_pr(&project.id, pr).await {
+                    Ok(true) => report.created += 1,
+                    Ok(false) => {}
+                    Err(e) => {
+                        tracing::warn!(
+                            error = %e,
+                            project = %project.name,
+                            pr_number = pr.number,
+                            "Failed to backfill contribution"
+                        );
+                        report.failed += 1;
+                    }
+                }
+            }
+
+            if prs.len() < BACKFILL_PAGE_SIZE {
+                break;
+            }
         }
 
-        // Check rate limit: max 10 reviews per hour
-        let one_hour_ago = Utc::now() - Duration::hours(1);
-        let recent_count = self
-            .reviews
-            .count_by_reviewer_since(reviewer_agent_id, one_hour_ago)
-            .await?;
+        Ok(report)
+    }
+
+    /// Record one merged PR's contribution; false if its author isn't an
+    /// agent or it was already recorded
+    async fn backfill_pr(
+        &self,
+        project_id: &ProjectId,
+        pr: &GiteaPullRequest,
+    ) -> Result<bool, AppError> {
+        let Some(user) = &pr.user else {
+            return Ok(false);
+        };
+        let Some(agent) = self.agents.find_by_gitea_username(&user.login).await? else {
+            return Ok(false);
+        };
+        if self
+            .contributions
+            .find_by_pr(project_id, pr.number)
+            .await?
+            .is_some()
+        {
+            return Ok(false);
+        }
+
+        let merged_at = pr
+            .merged_at
+            .as_deref()
+            .and_then(|t| DateTime::parse_from_rfc3339(t).ok())
+            .map(|t| t.with_timezone(&Utc))
+            .unwrap_or_else(Utc::now);
+
+        let contribution = NewCodeContribution {
+            agent_id: agent.id,
+            project_id: *project_id,
+            pr_number: pr.number,
+            commit_sha: pr
+                .merge_commit_sha
+                .clone()
+                .unwrap_or_else(|| pr.head.sha.clone()),
+            merged_at,
+        };
+        let created = self.contributions.create(&contribution).await?;
+        // Flag the bonus as settled so the longevity sweep skips it
+        self.contributions
+            .mark_longevity_bonus_paid(&created.id)
+            .await?;
+        Ok(true)
+    }
+
+    /// Handle a peer review submission.
+    /// Awards +5 ELO if reviewer is high-ELO and approved.
+    pub async fn on_peer_review(
+        &self,
+        pr_id: i64,
+        project_id: &ProjectId,
+        reviewer_agent_id: &AgentId,
+        reviewed_agent_id: &AgentId,
+        verdict: ReviewVerdict,
+    ) -> Result<Option<EloChangeResult>, AppError> {
+        // Validate: no self-reviews
+        if reviewer_agent_id == reviewed_agent_id {
+            return Err(AppError::Domain(DomainError::Validation(
+                "Cannot review your own PR".to_string(),
+            )));
+        }
+
+        // Member-only projects take reviews from their own members alone
+        if let Some(project) = self.projects.find_by_id(project_id).await? {
+            if !project.review_eligibility.permits(
+                self.projects
+                    .is_member(project_id, reviewer_agent_id)
+                    .await?,
+            ) {
+                return Err(AppError::Domain(DomainError::Validation(
+                    "Only members of this project can review its PRs".to_string(),
+                )));
+            }
+        }
+
+        // Check rate limit: max 10 reviews per hour
+        let one_hour_ago = Utc::now() - Duration::hours(1);
+        let recent_count = self
+            .reviews
+            .count_by_reviewer_since(reviewer_agent_id, one_hour_ago)
+            .await?;
 
         if recent_count >= MAX_REVIEWS_PER_HOUR {
             return Err(AppError::Domain(DomainError::Validation(format!(
@@ -209,6 +1095,17 @@ where
                 DomainError::NotFound(format!("Reviewer not found: {}", reviewer_agent_id))
             })?;
 
+        // Under a stricter policy, linked identities count as self-reviews too
+        if self.self_review_policy != SelfReviewPolicy::Exact {
+            if let Some(reviewed) = self.agents.find_by_id(reviewed_agent_id).await? {
+                if self.self_review_policy.is_self_review(&reviewer, &reviewed) {
+                    return Err(AppError::Domain(DomainError::Validation(
+                        "Cannot review a PR by an agent linked to your account".to_string(),
+                    )));
+                }
+            }
+        }
+
         // Create the review
         let review = NewAgentReview {
             pr_id,
@@ -240,12 +1137,54 @@ where
         Ok(None)
     }
 
+    /// Whether the agent is still on probation (always false when probation
+    /// is disabled)
+    ///
+    /// Replays the agent's reverts: graduating before a revert ends probation
+    /// for good, a revert while still on probation restarts the count. Each
+    /// step is a count query, so the cost grows with reverts, not history.
+    pub async fn is_on_probation(&self, agent_id: &AgentId) -> Result<bool, AppError> {
+        let Some(policy) = self.probation.filter(|p| p.contributions > 0) else {
+            return Ok(false);
+        };
+        let required = i64::from(policy.contributions);
+        let window = policy.survival_window();
+        let now = Utc::now();
+
+        let mut since = None;
+        for revert in self.contributions.find_revert_times(agent_id).await? {
+            if revert > now {
+                break;
+            }
+            let survivors = self
+                .contributions
+                .count_healthy_between(agent_id, since, revert - window, revert)
+                .await?;
+            if survivors >= required {
+                return Ok(false);
+            }
+            since = Some(revert);
+        }
+
+        let survivors = self
+            .contributions
+            .count_healthy_between(agent_id, since, now - window, now)
+            .await?;
+        Ok(survivors < required)
+    }
+
     /// Handle a commit revert being detected.
-    /// Deducts -30 ELO from the original author.
+    /// Deducts -30 ELO from the original author, scaled up while the author
+    /// is on probation.
+    ///
+    /// `commit_author` is the Gitea login that authored the reverted commit
+    /// (from `GiteaClient::get_commit`), when known. It's recorded in the ELO
+    /// event details and cross-checked against the contribution's agent.
     pub async fn on_commit_reverted(
         &self,
         reverted_sha: &str,
         reverting_sha: &str,
+        commit_author: Option<&str>,
     ) -> Result<Option<EloChangeResult>, AppError> {
         let Some(contribution) = self.contributions.find_by_commit_sha(reverted_sha).await? else {
             tracing::debug!(
@@ -261,35 +1200,82 @@ where
             return Ok(None);
         }
 
+        // Check probation before this revert lands in the history
+        let probation = match self.probation {
+            Some(policy) if self.is_on_probation(&contribution.agent_id).await? => Some(policy),
+            _ => None,
+        };
+
         // Update contribution status
         self.contributions
             .update_status(&contribution.id, ContributionStatus::Reverted, Utc::now())
             .await?;
 
+        let mut details = format!("Commit {} reverted by {}", reverted_sha, reverting_sha);
+        if let Some(author) = commit_author {
+            details.push_str(&format!(" (authored by {})", author));
+            let recorded = self.agents.find_by_id(&contribution.agent_id).await?;
+            if let Some(recorded) = recorded.filter(|a| a.gitea_username != author) {
+                tracing::warn!(
+                    reverted_sha = reverted_sha,
+                    commit_author = author,
+                    recorded_author = %recorded.gitea_username,
+                    "Reverted commit author differs from the recorded contributor"
+                );
+                details.push_str(&format!(
+                    ", recorded contributor {}",
+                    recorded.gitea_username
+                ));
+            }
+        }
+
+        let penalty = match probation {
+            Some(policy) => {
+                details.push_str(&format!(
+                    " [probation: {}% penalty]",
+                    policy.revert_penalty_percent
+                ));
+                policy.scale_revert(ELO_COMMIT_REVERTED)
+            }
+            None => ELO_COMMIT_REVERTED,
+        };
+
+        let (delta, details) = self
+            .apply_project_multiplier(&contribution.project_id, penalty, details)
+            .await?;
+
         // Deduct ELO
         let result = self
             .apply_elo_change(
                 &contribution.agent_id,
-                ELO_COMMIT_REVERTED,
+                delta,
                 EloEventType::CommitReverted,
                 Some(contribution.id.0),
-                Some(format!(
-                    "Commit {} reverted by {}",
-                    reverted_sha, reverting_sha
-                )),
+                Some(details),
             )
             .await?;
 
+        self.penalize_approvers(
+            &contribution.project_id,
+            contribution.pr_number,
+            &format!("commit {} was reverted", reverted_sha),
+        )
+        .await?;
+
         Ok(Some(result))
     }
 
     /// Handle a bug issue referencing a PR.
     /// Deducts -15 ELO from the PR author.
+    ///
+    /// `code` is what the bug report points at. With strict attribution on,
+    /// a PR that didn't change any of it isn't penalized.
     pub async fn on_bug_referenced(
         &self,
         project_id: &ProjectId,
         pr_number: i64,
         issue_url: &str,
+        code: &[CodeReference],
     ) -> Result<Option<EloChangeResult>, AppError> {
         let Some(contribution) = self.contributions.find_by_pr(project_id, pr_number).await? else {
             tracing::debug!(
@@ -299,25 +1285,172 @@ where
             return Ok(None);
         };
 
+        if !self.bug_touches_pr(project_id, &contribution, code).await? {
+            tracing::info!(
+                pr_number = pr_number,
+                issue_url = issue_url,
+                "Bug report points at code the PR didn't change, skipping penalty"
+            );
+            return Ok(None);
+        }
+
         // Increment bug count
         self.contributions
             .increment_bug_count(&contribution.id)
             .await?;
 
+        let (delta, details) = self
+            .apply_project_multiplier(
+                project_id,
+                ELO_BUG_REFERENCED,
+                format!("Bug {} references PR #{}", issue_url, pr_number),
+            )
+            .await?;
+
         // Deduct ELO
         let result = self
             .apply_elo_change(
                 &contribution.agent_id,
-                ELO_BUG_REFERENCED,
+                delta,
                 EloEventType::BugReferenced,
                 Some(contribution.id.0),
-                Some(format!("Bug {} references PR #{}", issue_url, pr_number)),
+                Some(details),
             )
             .await?;
 
+        self.penalize_approvers(
+            project_id,
+            pr_number,
+            &format!("bug {} references it", issue_url),
+        )
+        .await?;
+
         Ok(Some(result))
     }
 
+    /// Whether a bug report can be pinned on the PR behind `contribution`.
+    /// Without strict attribution, when the report points at no code, or
+    /// when Gitea can't be read to check, every reference counts.
+    async fn bug_touches_pr(
+        &self,
+        project_id: &ProjectId,
+        contribution: &CodeContribution,
+        code: &[CodeReference],
+    ) -> Result<bool, AppError> {
+        let Some(attribution) = &self.bug_attribution else {
+            return Ok(true);
+        };
+        if code.is_empty() {
+            return Ok(true);
+        }
+        let Some(project) = self.projects.find_by_id(project_id).await? else {
+            return Ok(true);
+        };
+        match attribution
+            .pr_touched_code(&project, contribution, code)
+            .await
+        {
+            Ok(touched) => Ok(touched),
+            Err(e) => {
+                tracing::warn!(
+                    error = %e,
+                    pr_number = contribution.pr_number,
+                    "Couldn't check bug against PR, penalizing anyway"
+                );
+                Ok(true)
+            }
+        }
+    }
+
+    /// Deduct ELO from agents who approved a PR that turned out bad.
+    /// Approvals older than `BAD_APPROVAL_WINDOW_DAYS` are exempt, and each
+    /// approver is penalized at most once per PR, however many bugs or
+    /// reverts it collects.
+    async fn penalize_approvers(
+        &self,
+        project_id: &ProjectId,
+        pr_number: i64,
+        reason: &str,
+    ) -> Result<Vec<EloChangeResult>, AppError> {
+        let cutoff = Utc::now() - Duration::days(BAD_APPROVAL_WINDOW_DAYS);
+        let mut reviews = self.reviews.find_by_pr(project_id, pr_number).await?;
+        reviews.retain(|r| {
+            r.verdict == ReviewVerdict::Approved && !r.is_self_review() && r.created_at >= cutoff
+        });
+
+        // Approvers already penalized for this PR, by an earlier bug or revert
+        let mut penalized = HashSet::new();
+        for review in &reviews {
+            let events = self.elo_events.find_by_reference(review.id.0).await?;
+            if events
+                .iter()
+                .any(|e| e.event_type == EloEventType::BadApproval)
+            {
+                penalized.insert(review.reviewer_agent_id);
+            }
+        }
+
+        let mut results = Vec::new();
+        for review in reviews {
+            if !penalized.insert(review.reviewer_agent_id) {
+                continue;
+            }
+            let (delta, details) = self
+                .apply_project_multiplier(
+                    project_id,
+                    ELO_BAD_APPROVAL,
+                    format!("Approved PR #{} but {}", pr_number, reason),
+                )
+                .await?;
+
+            let result = self
+                .apply_elo_change(
+                    &review.reviewer_agent_id,
+                    delta,
+                    EloEventType::BadApproval,
+                    Some(review.id.0),
+                    Some(details),
+                )
+                .await?;
+
+            tracing::info!(
+                reviewer_id = %review.reviewer_agent_id,
+                pr_number = pr_number,
+                delta = result.delta,
+                "Bad approval penalty applied"
+            );
+            results.push(result);
+        }
+
+        Ok(results)
+    }
+
+    /// Handle a bug issue in `source_project` referencing a PR, which lives
+    /// in the project for `repo` (`owner/repo`) when given.
+    /// References to repos that aren't projects are ignored.
+    pub async fn on_bug_reference(
+        &self,
+        source_project: &ProjectId,
+        pr_number: i64,
+        repo: Option<&str>,
+        issue_url: &str,
+        code: &[CodeReference],
+    ) -> Result<Option<EloChangeResult>, AppError> {
+        let project_id = match repo.and_then(|r| r.split_once('/')) {
+            None => *source_project,
+            Some((owner, name)) => match self.projects.find_by_gitea_repo(owner, name).await? {
+                Some(project) => project.id,
+                None => {
+                    tracing::debug!(repo = ?repo, "Referenced repo is not a project");
+                    return Ok(None);
+                }
+            },
+        };
+
+        self.on_bug_referenced(&project_id, pr_number, issue_url, code)
+            .await
+    }
+
     /// Handle a PR being rejected/closed without merge.
     /// Deducts -5 ELO.
     pub async fn on_pr_rejected(
@@ -326,15 +1459,70 @@ where
         project_id: &ProjectId,
         pr_number: i64,
     ) -> Result<EloChangeResult, AppError> {
-        self.apply_elo_change(
+        let (delta, details) = self
+            .apply_project_multiplier(
+                project_id,
+                ELO_PR_REJECTED,
+                format!("PR #{} rejected in project {}", pr_number, project_id),
+            )
+            .await?;
+
+        self.apply_pr_elo_change(
             agent_id,
-            ELO_PR_REJECTED,
+            delta,
             EloEventType::PrRejected,
             None,
-            Some(format!(
-                "PR #{} rejected in project {}",
-                pr_number, project_id
-            )),
+            Some(details),
+            Some(pr_number),
+        )
+        .await
+    }
+
+    /// Handle an agent explicitly abandoning a ticket they had claimed.
+    /// Deducts the configured abandon penalty (-2 by default).
+    pub async fn on_ticket_abandoned(
+        &self,
+        agent_id: &AgentId,
+        ticket: &Ticket,
+    ) -> Result<EloChangeResult, AppError> {
+        let (delta, details) = self
+            .apply_project_multiplier(
+                &ticket.project_id,
+                self.ticket_abandon_penalty,
+                format!("Abandoned ticket '{}'", ticket.title),
+            )
+            .await?;
+
+        self.apply_elo_change(
+            agent_id,
+            delta,
+            EloEventType::TicketAbandoned,
+            Some(ticket.id.0),
+            Some(details),
+        )
+        .await
+    }
+
+    /// Handle a requested reviewer letting the review request lapse.
+    /// Deducts the configured abandon penalty (-3 by default).
+    pub async fn on_review_abandoned(
+        &self,
+        request: &ReviewRequest,
+    ) -> Result<EloChangeResult, AppError> {
+        let (delta, details) = self
+            .apply_project_multiplier(
+                &request.project_id,
+                self.review_abandon_penalty,
+                format!("Never reviewed PR #{} after being asked", request.pr_number),
+            )
+            .await?;
+
+        self.apply_elo_change(
+            &request.reviewer_agent_id,
+            delta,
+            EloEventType::ReviewAbandoned,
+            Some(request.id.0),
+            Some(details),
         )
         .await
     }
@@ -361,17 +1549,25 @@ where
             .update_status(contribution_id, ContributionStatus::Replaced, Utc::now())
             .await?;
 
+        let (delta, details) = self
+            .apply_project_multiplier(
+                &contribution.project_id,
+                ELO_CODE_REPLACED,
+                format!(
+                    "Code replaced {} days after merge (within {}-day window)",
+                    days_since_merge, REPLACEMENT_WINDOW_DAYS
+                ),
+            )
+            .await?;
+
         // Deduct ELO
         let result = self
             .apply_elo_change(
                 &contribution.agent_id,
-                ELO_CODE_REPLACED,
+                delta,
                 EloEventType::CodeReplaced,
                 Some(contribution_id.0),
-                Some(format!(
-                    "Code replaced {} days after merge (within {}-day window)",
-                    days_since_merge, REPLACEMENT_WINDOW_DAYS
-                )),
+                Some(details),
             )
             .await?;
 
@@ -402,6 +1598,11 @@ where
     /// Process longevity bonuses for all eligible contributions.
     /// Awards +10 ELO for code that survives 30 days.
     /// Should be called periodically (e.g., daily cron job).
+    ///
+    /// Agents are processed concurrently (up to the configured limit), but each
+    /// agent's contributions run one at a time since ELO updates read the
+    /// current rating. Every batch runs to completion; the first error is
+    /// returned afterwards.
     pub async fn process_longevity_bonuses(&self) -> Result<Vec<EloChangeResult>, AppError> {
         let threshold = Utc::now() - Duration::days(LONGEVITY_DAYS);
         let eligible = self
@@ -409,15 +1610,50 @@ where
             .find_eligible_for_longevity_bonus(threshold)
             .await?;
 
+        let mut by_agent: HashMap<AgentId, Vec<CodeContribution>> = HashMap::new();
+        for contribution in eligible {
+            by_agent
+                .entry(contribution.agent_id)
+                .or_default()
+                .push(contribution);
+        }
+
+        let batches: Vec<Result<Vec<EloChangeResult>, AppError>> =
+            stream::iter(by_agent.into_values())
+                .map(|contributions| self.pay_longevity_bonuses(contributions))
+                .buffer_unordered(self.longevity_concurrency)
+                .collect()
+                .await;
+
         let mut results = Vec::new();
+        for batch in batches {
+            results.extend(batch?);
+        }
 
-        for contribution in eligible {
-            // Mark bonus as paid
-            self.contributions
+        if !results.is_empty() {
+            tracing::info!(count = results.len(), "Processed longevity bonuses");
+        }
+
+        Ok(results)
+    }
+
+    /// Pay the longevity bonus for one agent's eligible contributions
+    async fn pay_longevity_bonuses(
+        &self,
+        contributions: Vec<CodeContribution>,
+    ) -> Result<Vec<EloChangeResult>, AppError> {
+        let mut results = Vec::new();
+
+        for contribution in contributions {
+            // Claim the bonus first; another sweep may have paid it already
+            if !self
+                .contributions
                 .mark_longevity_bonus_paid(&contribution.id)
-                .await?;
+                .await?
+            {
+                continue;
+            }
 
-            // Award ELO
             let result = self
                 .apply_elo_change(
                     &contribution.agent_id,
@@ -435,10 +1671,6 @@ where
             results.push(result);
         }
 
-        if !results.is_empty() {
-            tracing::info!(count = results.len(), "Processed longevity bonuses");
-        }
-
         Ok(results)
     }
 
@@ -466,6 +1698,85 @@ where
         )
         .await
     }
+
+    /// Credit the earlier contributions a newly merged PR says it builds on.
+    ///
+    /// A dependency only counts when its head commit is an ancestor of the
+    /// merged PR's head (`head_sha`). References to the PR itself or to the
+    /// author's own contributions are ignored, at most
+    /// `MAX_DEPENDENCIES_PER_PR` dependencies are credited per PR, and a
+    /// contribution stops earning once it has `MAX_DEPENDENT_AWARDS_PER_PR`
+    /// awards.
+    pub async fn on_dependencies_merged<G: GiteaClient>(
+        &self,
+        gitea: &G,
+        project: &Project,
+        pr_number: i64,
+        head_sha: &str,
+        author_id: &AgentId,
+        body: &str,
+    ) -> Result<Vec<EloChangeResult>, AppError> {
+        let mut results = Vec::new();
+
+        for dependency in parse_dependency_references(body) {
+            if results.len() >= MAX_DEPENDENCIES_PER_PR {
+                break;
+            }
+            if dependency == pr_number {
+                continue;
+            }
+            let Some(contribution) = self
+                .contributions
+                .find_by_pr(&project.id, dependency)
+                .await?
+            else {
+                tracing::debug!(
+                    pr_number = dependency,
+                    "No contribution found for dependency"
+                );
+                continue;
+            };
+            if contribution.agent_id == *author_id
+                || contribution.dependent_prs_count >= MAX_DEPENDENT_AWARDS_PER_PR
+            {
+                continue;
+            }
+            if !is_ancestor(gitea, project, &contribution.commit_sha, head_sha).await {
+                tracing::info!(
+                    pr_number = pr_number,
+                    dependency = dependency,
+                    "Referenced PR is not in the merged PR's history, skipping credit"
+                );
+                continue;
+            }
+            results.push(self.on_dependent_pr(&contribution).await?);
+        }
+
+        Ok(results)
+    }
+}
+
+/// Whether `ancestor` is in the history of `head`. Unknown commits and
+/// Gitea errors count as not.
+async fn is_ancestor<G: GiteaClient>(
+    gitea: &G,
+    project: &Project,
+    ancestor: &str,
+    head: &str,
+) -> bool {
+    if ancestor.is_empty() || head.is_empty() {
+        return false;
+    }
+    match gitea
+        .compare_commits(&project.gitea_org, &project.gitea_repo, ancestor, head)
+        .await
+    {
+        Ok(comparison) => comparison.behind_by == 0,
+        Err(e) => {
+            tracing::warn!(error = %e, ancestor, head, "Failed to compare commits");
+            false
+        }
+    }
 }
 
 /// Parse a revert commit message to extract the reverted SHA.
@@ -494,29 +1805,110 @@ pub fn parse_revert_commit(message: &str) -> Option<String> {
     None
 }
 
-/// Parse issue body for PR/commit references.
-/// Looks for patterns like "#123" or "PR #123" or commit SHAs.
+/// Parse issue body for PR references.
+/// Looks for patterns like "#123", "PR #123" or cross-repo "owner/repo#123";
+/// the second element is the `owner/repo` of a cross-repo reference.
 pub fn parse_bug_references(body: &str) -> Vec<(i64, Option<String>)> {
     let mut refs = Vec::new();
 
-    // Pattern: PR #123 or #123
-    let re_pr = regex::Regex::new(r"(?:PR\s*)?#(\d+)").unwrap();
+    // Pattern: PR #123, #123 or owner/repo#123
+    let re_pr = regex::Regex::new(r"(?:PR\s*)?(?:\b([\w.-]+/[\w.-]+))?#(\d+)").unwrap();
     for cap in re_pr.captures_iter(body) {
+        if let Ok(num) = cap[2].parse::<i64>() {
+            refs.push((num, cap.get(1).map(|repo| repo.as_str().to_string())));
+        }
+    }
+
+    refs
+}
+
+/// Parse a bug report for the code it points at, like `src/lib.rs:42` or
+/// `api/handlers.rs`. Bare file names only count with a line number, so
+/// prose like "e.g." isn't mistaken for a file.
+pub fn parse_code_references(body: &str) -> Vec<CodeReference> {
+    let mut refs: Vec<CodeReference> = Vec::new();
+
+    let re_path =
+        regex::Regex::new(r#"(?:^|[\s`'"(\[])((?:[\w.-]+/)*[\w-][\w.-]*\.[A-Za-z]\w*)(?::(\d+))?"#)
+            .unwrap();
+    for cap in re_path.captures_iter(body) {
+        let path = cap[1].to_string();
+        let line = cap
+            .get(2)
+            .and_then(|l| l.as_str().parse::<usize>().ok())
+            .filter(|l| *l > 0);
+        if !path.contains('/') && line.is_none() {
+            continue;
+        }
+        let reference = CodeReference { path, line };
+        if !refs.contains(&reference) {
+            refs.push(reference);
+        }
+    }
+
+    refs
+}
+
+/// Parse a PR body for the PRs it builds on.
+/// Looks for patterns like "builds on #42", "depends on PR #42" or "based on #42".
+pub fn parse_dependency_references(body: &str) -> Vec<i64> {
+    let mut refs = Vec::new();
+
+    let re_dep =
+        regex::Regex::new(r"(?i)\b(?:builds?\s+on|depends\s+on|based\s+on)\s+(?:PR\s*)?#(\d+)")
+            .unwrap();
+    for cap in re_dep.captures_iter(body) {
         if let Ok(num) = cap[1].parse::<i64>() {
-            refs.push((num, None));
+            if !refs.contains(&num) {
+                refs.push(num);
+            }
         }
     }
 
     refs
 }
 
+/// Clamp `delta` so the agent's net change for the day stays within `[-cap, cap]`.
+/// Never reverses direction: if today's total is already past the band, the change is zeroed.
+fn clamp_to_daily_cap(delta: i32, today: i64, cap: i32) -> i32 {
+    let cap = i64::from(cap.abs());
+    let max_gain = (cap - today).max(0);
+    let max_loss = (-cap - today).min(0);
+    i64::from(delta).clamp(max_loss, max_gain) as i32
+}
+
+/// Scale `delta` by a project's ELO multiplier (clamped to the allowed range),
+/// recording a non-default multiplier in `details`
+fn scale_by_multiplier(delta: i32, multiplier: f32, mut details: String) -> (i32, String) {
+    let multiplier = multiplier.clamp(ELO_MULTIPLIER_MIN, ELO_MULTIPLIER_MAX);
+    if multiplier == 1.0 {
+        return (delta, details);
+    }
+    details.push_str(&format!(" (project ELO multiplier x{})", multiplier));
+    ((delta as f32 * multiplier).round() as i32, details)
+}
+
+/// Merge award after `recent_merges` earlier merges within the cooldown window.
+/// Halves per recent merge: 15, 7, 3, 1, 0, ...
+fn diminished_merge_award(recent_merges: i64) -> i32 {
+    u32::try_from(recent_merges)
+        .ok()
+        .and_then(|n| ELO_PR_MERGED.checked_shr(n))
+        .unwrap_or(0)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::app::GiteaBugAttribution;
+    use crate::domain::entities::{AgentReview, ReviewEligibility};
+    use crate::domain::ports::GiteaClient;
     use crate::test_utils::{
-        test_agent_with_elo, test_code_contribution_merged_at, test_project,
-        InMemoryAgentRepository, InMemoryAgentReviewRepository, InMemoryCodeContributionRepository,
-        InMemoryEloEventRepository,
+        test_agent_review, test_agent_with_elo, test_code_contribution,
+        test_code_contribution_merged_at, test_elo_event, test_project, test_reverted_contribution,
+        test_ticket_assigned, InMemoryAgentRepository, InMemoryAgentReviewRepository,
+        InMemoryCodeContributionRepository, InMemoryEloEventRepository,
+        InMemoryEloIntegrityRepository, InMemoryProjectRepository, MockGiteaClient, MockNotifier,
     };
 
     fn create_test_service() -> ReactiveEloService<
@@ -524,12 +1916,14 @@ mod tests {
         InMemoryCodeContributionRepository,
         InMemoryAgentReviewRepository,
         InMemoryEloEventRepository,
+        InMemoryProjectRepository,
     > {
         ReactiveEloService::new(
             Arc::new(InMemoryAgentRepository::new()),
             Arc::new(InMemoryCodeContributionRepository::new()),
             Arc::new(InMemoryAgentReviewRepository::new()),
             Arc::new(InMemoryEloEventRepository::new()),
+            Arc::new(InMemoryProjectRepository::new()),
         )
     }
 
@@ -541,6 +1935,7 @@ mod tests {
             InMemoryCodeContributionRepository,
             InMemoryAgentReviewRepository,
             InMemoryEloEventRepository,
+            InMemoryProjectRepository,
         >,
         Arc<InMemoryAgentRepository>,
         Arc<InMemoryCodeContributionRepository>,
@@ -556,40 +1951,250 @@ mod tests {
             contrib_repo.clone(),
             review_repo,
             elo_repo.clone(),
+            Arc::new(InMemoryProjectRepository::new()),
         );
 
         (service, agent_repo, contrib_repo, elo_repo)
     }
 
     // ==========================================================================
-    // Parser tests
+    // Head-to-head tests
     // ==========================================================================
 
-    #[test]
-    fn parse_revert_commit_this_reverts() {
-        let msg = "Revert \"Add feature X\"\n\nThis reverts commit abc123def456.";
-        assert_eq!(parse_revert_commit(msg), Some("abc123def456".to_string()));
-    }
+    #[tokio::test]
+    async fn test_head_to_head_counts_reviews_each_way() {
+        let a = crate::test_utils::test_agent_named("rival-a");
+        let b = crate::test_utils::test_agent_named("rival-b");
+        let shared = test_project();
+        let solo = test_project();
+
+        let reviews = InMemoryAgentReviewRepository::new()
+            .with_review(test_agent_review(
+                a.id,
+                b.id,
+                shared.id,
+                ReviewVerdict::Approved,
+            ))
+            .with_review(test_agent_review(
+                b.id,
+                a.id,
+                shared.id,
+                ReviewVerdict::ChangesRequested,
+            ));
+        let contributions = InMemoryCodeContributionRepository::new()
+            .with_contribution(test_code_contribution(a.id, shared.id))
+            .with_contribution(test_code_contribution(a.id, solo.id))
+            .with_contribution(test_reverted_contribution(b.id, shared.id));
+        let service = ReactiveEloService::new(
+            Arc::new(
+                InMemoryAgentRepository::new()
+                    .with_agent(a.clone())
+                    .with_agent(b.clone()),
+            ),
+            Arc::new(contributions),
+            Arc::new(reviews),
+            Arc::new(InMemoryEloEventRepository::new()),
+            Arc::new(InMemoryProjectRepository::new()),
+        );
 
-    #[test]
-    fn parse_revert_commit_short_sha() {
-        let msg = "This reverts commit abc1234";
-        assert_eq!(parse_revert_commit(msg), Some("abc1234".to_string()));
-    }
+        let record = service.get_head_to_head(&a.id, &b.id).await.unwrap();
 
-    #[test]
-    fn parse_revert_commit_no_match() {
-        let msg = "Fix bug in feature X";
-        assert_eq!(parse_revert_commit(msg), None);
+        assert_eq!(
+            record.a.reviews_given,
+            ReviewTally {
+                approved: 1,
+                changes_requested: 0
+            }
+        );
+        assert_eq!(
+            record.b.reviews_given,
+            ReviewTally {
+                approved: 0,
+                changes_requested: 1
+            }
+        );
+        assert_eq!(record.shared_projects, vec![shared.id]);
+        assert_eq!(record.a.contested_contributions, 1);
+        assert_eq!(record.b.contested_surviving, 0);
+        assert_eq!(record.leader, Some(a.id));
     }
 
-    #[test]
-    fn parse_revert_commit_too_short_sha() {
-        let msg = "This reverts commit abc";
-        assert_eq!(parse_revert_commit(msg), None);
+    #[tokio::test]
+    async fn test_head_to_head_rejects_same_agent() {
+        let agent = test_agent_with_elo(1000);
+        let (service, _, _, _) = create_service_with_agent(agent.clone());
+
+        let result = service.get_head_to_head(&agent.id, &agent.id).await;
+
+        assert!(matches!(result, Err(AppError::BadRequest(_))));
     }
 
-    #[test]
+    #[tokio::test]
+    async fn test_get_pr_contribution_for_merged_and_open_prs() {
+        let agent = test_agent_with_elo(1000);
+        let project = test_project();
+        let (service, _, _, _) = create_service_with_agent(agent.clone());
+
+        service
+            .on_pr_merged(&agent.id, &project.id, 42, "abc123", Difficulty::Medium)
+            .await
+            .unwrap();
+
+        let contribution = service.get_pr_contribution(&project.id, 42).await.unwrap();
+        assert_eq!(contribution.agent_id, agent.id);
+        assert_eq!(contribution.commit_sha, "abc123");
+        assert_eq!(contribution.status, ContributionStatus::Healthy);
+
+        // PR 43 is still open, so nothing has been recorded for it
+        let err = service
+            .get_pr_contribution(&project.id, 43)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, AppError::NotFound(_)));
+    }
+
+    #[tokio::test]
+    async fn test_list_reviews_by_direction() {
+        let agent = crate::test_utils::test_agent_named("subject");
+        let author = crate::test_utils::test_agent_named("author");
+        let critic = crate::test_utils::test_agent_named("critic");
+        let project = test_project();
+
+        let given = test_agent_review(agent.id, author.id, project.id, ReviewVerdict::Approved);
+        let received = test_agent_review(
+            critic.id,
+            agent.id,
+            project.id,
+            ReviewVerdict::ChangesRequested,
+        );
+        let service = ReactiveEloService::new(
+            Arc::new(
+                InMemoryAgentRepository::new()
+                    .with_agent(agent.clone())
+                    .with_agent(author.clone())
+                    .with_agent(critic.clone()),
+            ),
+            Arc::new(InMemoryCodeContributionRepository::new()),
+            Arc::new(
+                InMemoryAgentReviewRepository::new()
+                    .with_review(given.clone())
+                    .with_review(received.clone()),
+            ),
+            Arc::new(InMemoryEloEventRepository::new()),
+            Arc::new(InMemoryProjectRepository::new().with_project(project.clone())),
+        );
+
+        let (gave, total) = service
+            .list_reviews(&agent.id, ReviewDirection::Given, 20, 0)
+            .await
+            .unwrap();
+        assert_eq!(total, 1);
+        assert_eq!(gave[0].review.id, given.id);
+        assert_eq!(gave[0].review.verdict, ReviewVerdict::Approved);
+        assert_eq!(gave[0].counterpart.as_ref().unwrap().id, author.id);
+        assert_eq!(gave[0].project_name.as_deref(), Some(project.name.as_str()));
+
+        let (got, total) = service
+            .list_reviews(&agent.id, ReviewDirection::Received, 20, 0)
+            .await
+            .unwrap();
+        assert_eq!(total, 1);
+        assert_eq!(got[0].review.id, received.id);
+        assert_eq!(got[0].review.verdict, ReviewVerdict::ChangesRequested);
+        assert_eq!(got[0].counterpart.as_ref().unwrap().id, critic.id);
+
+        let (page, total) = service
+            .list_reviews(&agent.id, ReviewDirection::Received, 20, 1)
+            .await
+            .unwrap();
+        assert!(page.is_empty());
+        assert_eq!(total, 1);
+    }
+
+    // ==========================================================================
+    // Leaderboard movers tests
+    // ==========================================================================
+
+    #[tokio::test]
+    async fn test_movers_rank_by_net_delta_within_range() {
+        let climber = crate::test_utils::test_agent_named("climber");
+        let steady = crate::test_utils::test_agent_named("steady");
+        let slider = crate::test_utils::test_agent_named("slider");
+
+        let mut old_win = test_elo_event(steady.id, EloEventType::PrMerged, 100);
+        old_win.created_at = Utc::now() - Duration::days(10);
+
+        let elo_repo = InMemoryEloEventRepository::new()
+            .with_event(test_elo_event(climber.id, EloEventType::PrMerged, 30))
+            .with_event(test_elo_event(climber.id, EloEventType::PrMerged, 20))
+            .with_event(test_elo_event(steady.id, EloEventType::PrMerged, 10))
+            .with_event(old_win)
+            .with_event(test_elo_event(slider.id, EloEventType::CommitReverted, -30));
+        let service = ReactiveEloService::new(
+            Arc::new(
+                InMemoryAgentRepository::new()
+                    .with_agent(climber.clone())
+                    .with_agent(steady.clone())
+                    .with_agent(slider.clone()),
+            ),
+            Arc::new(InMemoryCodeContributionRepository::new()),
+            Arc::new(InMemoryAgentReviewRepository::new()),
+            Arc::new(elo_repo),
+            Arc::new(InMemoryProjectRepository::new()),
+        );
+
+        let movers = service
+            .leaderboard_movers(MoversRange::Week, 10)
+            .await
+            .unwrap();
+
+        let gainers: Vec<(AgentId, i64)> = movers
+            .gainers
+            .iter()
+            .map(|m| (m.agent.id, m.delta))
+            .collect();
+        assert_eq!(gainers, vec![(climber.id, 50), (steady.id, 10)]);
+        assert_eq!(movers.losers.len(), 1);
+        assert_eq!(movers.losers[0].agent.id, slider.id);
+        assert_eq!(movers.losers[0].delta, -30);
+    }
+
+    #[test]
+    fn test_movers_range_parse() {
+        assert_eq!("week".parse::<MoversRange>().unwrap(), MoversRange::Week);
+        assert_eq!("DAY".parse::<MoversRange>().unwrap(), MoversRange::Day);
+        assert!("year".parse::<MoversRange>().is_err());
+    }
+
+    // ==========================================================================
+    // Parser tests
+    // ==========================================================================
+
+    #[test]
+    fn parse_revert_commit_this_reverts() {
+        let msg = "Revert \"Add feature X\"\n\nThis reverts commit abc123def456.";
+        assert_eq!(parse_revert_commit(msg), Some("abc123def456".to_string()));
+    }
+
+    #[test]
+    fn parse_revert_commit_short_sha() {
+        let msg = "This reverts commit abc1234";
+        assert_eq!(parse_revert_commit(msg), Some("abc1234".to_string()));
+    }
+
+    #[test]
+    fn parse_revert_commit_no_match() {
+        let msg = "Fix bug in feature X";
+        assert_eq!(parse_revert_commit(msg), None);
+    }
+
+    #[test]
+    fn parse_revert_commit_too_short_sha() {
+        let msg = "This reverts commit abc";
+        assert_eq!(parse_revert_commit(msg), None);
+    }
+
+    #[test]
     fn parse_bug_references_pr_number() {
         let body = "This bug was introduced in PR #42";
         let refs = parse_bug_references(body);
@@ -606,6 +2211,37 @@ mod tests {
         assert!(refs.iter().any(|(n, _)| *n == 20));
     }
 
+    #[test]
+    fn parse_bug_references_cross_repo() {
+        let refs = parse_bug_references("Broken by foo/bar#12, see also #3");
+        assert_eq!(refs, vec![(12, Some("foo/bar".to_string())), (3, None)]);
+    }
+
+    #[test]
+    fn parse_code_references_finds_paths_and_lines() {
+        let refs = parse_code_references(
+            "Crash in `src/lib.rs:42` (see api/util.rs), e.g. main.rs:7 or https://x.io/a/b.rs",
+        );
+        assert_eq!(
+            refs,
+            vec![
+                CodeReference {
+                    path: "src/lib.rs".to_string(),
+                    line: Some(42)
+                },
+                CodeReference {
+                    path: "api/util.rs".to_string(),
+                    line: None
+                },
+                CodeReference {
+                    path: "main.rs".to_string(),
+                    line: Some(7)
+                },
+            ]
+        );
+        assert!(parse_code_references("Fails on startup, see #12").is_empty());
+    }
+
     #[test]
     fn parse_bug_references_no_match() {
         let body = "No references here";
@@ -617,6 +2253,85 @@ mod tests {
     // Service integration tests
     // ==========================================================================
 
+    #[tokio::test]
+    async fn test_backfill_contributions_skips_unknown_and_existing() {
+        use crate::test_utils::MockGiteaClient;
+
+        let agent = test_agent_with_elo(1000);
+        let project = test_project();
+        let (service, agent_repo, contrib_repo, elo_repo) =
+            create_service_with_agent(agent.clone());
+
+        let (org, repo) = (project.gitea_org.as_str(), project.gitea_repo.as_str());
+        let gitea = MockGiteaClient::new()
+            .with_merged_pr(org, repo, 1, &agent.gitea_username)
+            .with_merged_pr(org, repo, 2, "not-an-agent")
+            .with_pr(org, repo, 3);
+
+        let report = service
+            .backfill_contributions(&gitea, &project)
+            .await
+            .unwrap();
+        assert_eq!(
+            report,
+            BackfillReport {
+                created: 1,
+                failed: 0
+            }
+        );
+
+        let contribution = contrib_repo
+            .find_by_pr(&project.id, 1)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(contribution.agent_id, agent.id);
+        assert_eq!(contribution.commit_sha, "merge1");
+
+        // Re-running is idempotent and never touches ELO
+        let report = service
+            .backfill_contributions(&gitea, &project)
+            .await
+            .unwrap();
+        assert_eq!(report.created, 0);
+        let updated = agent_repo.find_by_id(&agent.id).await.unwrap().unwrap();
+        assert_eq!(updated.elo, 1000);
+        assert!(elo_repo.get_all_events().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_backfill_contributions_pages_and_skips_longevity_bonus() {
+        use crate::test_utils::MockGiteaClient;
+
+        let mut agent = test_agent_with_elo(1000);
+        agent.gitea_username = "importer".to_string();
+        let project = test_project();
+        let (service, agent_repo, contrib_repo, _) = create_service_with_agent(agent.clone());
+
+        let (org, repo) = (project.gitea_org.as_str(), project.gitea_repo.as_str());
+        let total = BACKFILL_PAGE_SIZE as i64 + 5;
+        let gitea = (1..=total).fold(MockGiteaClient::new(), |gitea, number| {
+            gitea.with_merged_pr(org, repo, number, "importer")
+        });
+
+        let report = service
+            .backfill_contributions(&gitea, &project)
+            .await
+            .unwrap();
+        assert_eq!(report.created, total as usize);
+        assert!(contrib_repo
+            .find_by_pr(&project.id, total)
+            .await
+            .unwrap()
+            .is_some());
+
+        // Old merges are past the longevity window, yet earn nothing
+        let results = service.process_longevity_bonuses().await.unwrap();
+        assert!(results.is_empty());
+        let updated = agent_repo.find_by_id(&agent.id).await.unwrap().unwrap();
+        assert_eq!(updated.elo, 1000);
+    }
+
     #[tokio::test]
     async fn test_pr_merged_awards_elo() {
         let agent = test_agent_with_elo(1000);
@@ -625,9 +2340,10 @@ mod tests {
             create_service_with_agent(agent.clone());
 
         let result = service
-            .on_pr_merged(&agent.id, &project.id, 42, "abc123")
+            .on_pr_merged(&agent.id, &project.id, 42, "abc123", Difficulty::Medium)
             .await
-            .expect("PR merge should succeed");
+            .expect("PR merge should succeed")
+            .expect("first merge of the PR awards ELO");
 
         // Verify ELO change
         assert_eq!(result.old_elo, 1000);
@@ -635,20 +2351,632 @@ mod tests {
         assert_eq!(result.delta, ELO_PR_MERGED);
         assert_eq!(result.event_type, EloEventType::PrMerged);
 
-        // Verify agent was updated
-        let updated_agent = agent_repo.find_by_id(&agent.id).await.unwrap().unwrap();
-        assert_eq!(updated_agent.elo, 1015);
+        // Verify agent was updated
+        let updated_agent = agent_repo.find_by_id(&agent.id).await.unwrap().unwrap();
+        assert_eq!(updated_agent.elo, 1015);
+
+        // Verify contribution was created
+        let contribs = contrib_repo.find_by_agent(&agent.id).await.unwrap();
+        assert_eq!(contribs.len(), 1);
+        assert_eq!(contribs[0].pr_number, 42);
+        assert_eq!(contribs[0].commit_sha, "abc123");
+
+        // Verify ELO event was logged
+        let events = elo_repo.find_by_agent(&agent.id).await.unwrap();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].delta, ELO_PR_MERGED);
+    }
+
+    #[tokio::test]
+    async fn test_hard_ticket_merge_awards_more_than_easy() {
+        let weights: DifficultyWeights = "easy=50,hard=200".parse().unwrap();
+        let project = test_project();
+
+        let easy_agent = test_agent_with_elo(1000);
+        let (service, _, _, _) = create_service_with_agent(easy_agent.clone());
+        let easy = service
+            .with_difficulty_weights(weights)
+            .on_pr_merged(&easy_agent.id, &project.id, 1, "sha1", Difficulty::Easy)
+            .await
+            .unwrap()
+            .unwrap();
+
+        let hard_agent = test_agent_with_elo(1000);
+        let (service, _, _, _) = create_service_with_agent(hard_agent.clone());
+        let hard = service
+            .with_difficulty_weights(weights)
+            .on_pr_merged(&hard_agent.id, &project.id, 2, "sha2", Difficulty::Hard)
+            .await
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(easy.delta, ELO_PR_MERGED / 2);
+        assert_eq!(hard.delta, ELO_PR_MERGED * 2);
+        assert!(hard.delta > easy.delta);
+    }
+
+    #[tokio::test]
+    async fn test_pr_merged_outside_cooldown_awards_full_elo() {
+        let agent = test_agent_with_elo(1000);
+        let project = test_project();
+
+        // Previous merge on the same project well outside the cooldown window
+        let earlier = test_code_contribution_merged_at(
+            agent.id,
+            project.id,
+            Utc::now() - Duration::seconds(MERGE_COOLDOWN_SECS * 2),
+        );
+        let service = ReactiveEloService::new(
+            Arc::new(InMemoryAgentRepository::new().with_agent(agent.clone())),
+            Arc::new(InMemoryCodeContributionRepository::new().with_contribution(earlier)),
+            Arc::new(InMemoryAgentReviewRepository::new()),
+            Arc::new(InMemoryEloEventRepository::new()),
+            Arc::new(InMemoryProjectRepository::new()),
+        );
+
+        let result = service
+            .on_pr_merged(&agent.id, &project.id, 43, "def456", Difficulty::Medium)
+            .await
+            .expect("PR merge should succeed")
+            .expect("first merge of the PR awards ELO");
+
+        assert_eq!(result.delta, ELO_PR_MERGED);
+        assert_eq!(result.new_elo, 1015);
+    }
+
+    #[tokio::test]
+    async fn test_pr_merged_just_inside_cooldown_is_diminished() {
+        let agent = test_agent_with_elo(1000);
+        let project = test_project();
+
+        // Previous merge on the same project a minute before the window closes
+        let earlier = test_code_contribution_merged_at(
+            agent.id,
+            project.id,
+            Utc::now() - Duration::seconds(MERGE_COOLDOWN_SECS - 60),
+        );
+        let service = ReactiveEloService::new(
+            Arc::new(InMemoryAgentRepository::new().with_agent(agent.clone())),
+            Arc::new(InMemoryCodeContributionRepository::new().with_contribution(earlier)),
+            Arc::new(InMemoryAgentReviewRepository::new()),
+            Arc::new(InMemoryEloEventRepository::new()),
+            Arc::new(InMemoryProjectRepository::new()),
+        );
+
+        let result = service
+            .on_pr_merged(&agent.id, &project.id, 43, "def456", Difficulty::Medium)
+            .await
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(result.delta, diminished_merge_award(1));
+        assert!(result.delta < ELO_PR_MERGED);
+    }
+
+    #[tokio::test]
+    async fn test_pr_merged_twice_awards_once() {
+        let agent = test_agent_with_elo(1000);
+        let project = test_project();
+        let (service, agent_repo, contrib_repo, elo_repo) =
+            create_service_with_agent(agent.clone());
+
+        let first = service
+            .on_pr_merged(&agent.id, &project.id, 42, "abc123", Difficulty::Medium)
+            .await
+            .unwrap();
+        let second = service
+            .on_pr_merged(&agent.id, &project.id, 42, "abc123", Difficulty::Medium)
+            .await
+            .unwrap();
+
+        assert!(first.is_some());
+        assert!(second.is_none());
+        assert_eq!(
+            contrib_repo.find_by_agent(&agent.id).await.unwrap().len(),
+            1
+        );
+        assert_eq!(elo_repo.find_by_agent(&agent.id).await.unwrap().len(), 1);
+        let stored = agent_repo.find_by_id(&agent.id).await.unwrap().unwrap();
+        assert_eq!(stored.elo, 1000 + ELO_PR_MERGED);
+    }
+
+    #[tokio::test]
+    async fn test_pr_merged_retry_pays_award_that_failed_to_land() {
+        let agent = test_agent_with_elo(1000);
+        let project = test_project();
+        let (service, agent_repo, contrib_repo, elo_repo) =
+            create_service_with_agent(agent.clone());
+
+        // The contribution is recorded but the ELO write fails
+        *agent_repo.fail_update_elo.write().unwrap() = true;
+        assert!(service
+            .on_pr_merged(&agent.id, &project.id, 42, "abc123", Difficulty::Medium)
+            .await
+            .is_err());
+        *agent_repo.fail_update_elo.write().unwrap() = false;
+
+        // The redelivery pays the full award once, on the same contribution
+        let retried = service
+            .on_pr_merged(&agent.id, &project.id, 42, "abc123", Difficulty::Medium)
+            .await
+            .unwrap()
+            .expect("retry pays the award");
+        assert_eq!(retried.delta, ELO_PR_MERGED);
+        assert!(service
+            .on_pr_merged(&agent.id, &project.id, 42, "abc123", Difficulty::Medium)
+            .await
+            .unwrap()
+            .is_none());
+
+        assert_eq!(
+            contrib_repo.find_by_agent(&agent.id).await.unwrap().len(),
+            1
+        );
+        assert_eq!(elo_repo.find_by_agent(&agent.id).await.unwrap().len(), 1);
+        let stored = agent_repo.find_by_id(&agent.id).await.unwrap().unwrap();
+        assert_eq!(stored.elo, 1000 + ELO_PR_MERGED);
+    }
+
+    #[tokio::test]
+    async fn test_rapid_fire_merges_diminish_elo() {
+        let agent = test_agent_with_elo(1000);
+        let project = test_project();
+        let (service, agent_repo, _, elo_repo) = create_service_with_agent(agent.clone());
+
+        let mut deltas = Vec::new();
+        for pr in 1..=4 {
+            let result = service
+                .on_pr_merged(
+                    &agent.id,
+                    &project.id,
+                    pr,
+                    &format!("sha{}", pr),
+                    Difficulty::Medium,
+                )
+                .await
+                .expect("PR merge should succeed")
+                .expect("first merge of the PR awards ELO");
+            deltas.push(result.delta);
+        }
+
+        assert_eq!(deltas, vec![15, 7, 3, 1]);
+        let updated = agent_repo.find_by_id(&agent.id).await.unwrap().unwrap();
+        assert_eq!(updated.elo, 1026);
+
+        let events = elo_repo.find_by_agent(&agent.id).await.unwrap();
+        assert!(events
+            .iter()
+            .any(|e| e.details.as_deref().unwrap_or("").contains("cooldown")));
+
+        // Merges on another project are unaffected
+        let other = service
+            .on_pr_merged(&agent.id, &test_project().id, 5, "sha5", Difficulty::Medium)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(other.delta, ELO_PR_MERGED);
+    }
+
+    fn create_capped_service(
+        agent: crate::domain::entities::Agent,
+        elo_repo: InMemoryEloEventRepository,
+        cap: i32,
+    ) -> (
+        ReactiveEloService<
+            InMemoryAgentRepository,
+            InMemoryCodeContributionRepository,
+            InMemoryAgentReviewRepository,
+            InMemoryEloEventRepository,
+            InMemoryProjectRepository,
+        >,
+        Arc<InMemoryEloEventRepository>,
+    ) {
+        let elo_repo = Arc::new(elo_repo);
+        let service = ReactiveEloService::new(
+            Arc::new(InMemoryAgentRepository::new().with_agent(agent)),
+            Arc::new(InMemoryCodeContributionRepository::new()),
+            Arc::new(InMemoryAgentReviewRepository::new()),
+            elo_repo.clone(),
+            Arc::new(InMemoryProjectRepository::new()),
+        )
+        .with_daily_cap(Some(cap));
+        (service, elo_repo)
+    }
+
+    #[tokio::test]
+    async fn test_daily_cap_clamps_successive_penalties() {
+        let agent = test_agent_with_elo(1000);
+        let (service, _) =
+            create_capped_service(agent.clone(), InMemoryEloEventRepository::new(), 50);
+
+        // -30, -30 would be -60 today; second is clamped to -20, third to 0
+        let first = service
+            .apply_elo_change(
+                &agent.id,
+                ELO_COMMIT_REVERTED,
+                EloEventType::CommitReverted,
+                None,
+                None,
+            )
+            .await
+            .unwrap();
+        let second = service
+            .apply_elo_change(
+                &agent.id,
+                ELO_COMMIT_REVERTED,
+                EloEventType::CommitReverted,
+                None,
+                None,
+            )
+            .await
+            .unwrap();
+        let third = service
+            .apply_elo_change(
+                &agent.id,
+                ELO_BUG_REFERENCED,
+                EloEventType::BugReferenced,
+                None,
+                None,
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(first.delta, -30);
+        assert_eq!(second.delta, -20);
+        assert_eq!(third.delta, 0);
+        assert_eq!(third.new_elo, 950);
+
+        // Gains are still allowed once clamped at the floor
+        let gain = service
+            .apply_elo_change(&agent.id, ELO_PR_MERGED, EloEventType::PrMerged, None, None)
+            .await
+            .unwrap();
+        assert_eq!(gain.delta, ELO_PR_MERGED);
+    }
+
+    #[tokio::test]
+    async fn test_elo_drift_reports_only_desynced_agents() {
+        let consistent = crate::test_utils::test_agent_named("consistent");
+        let desynced = crate::test_utils::test_agent_named("desynced");
+        let untouched = crate::test_utils::test_agent_named("untouched");
+        let agents = Arc::new(
+            InMemoryAgentRepository::new()
+                .with_agent(consistent.clone())
+                .with_agent(desynced.clone())
+                .with_agent(untouched),
+        );
+        let elo_events = Arc::new(InMemoryEloEventRepository::new());
+        let service = ReactiveEloService::new(
+            agents.clone(),
+            Arc::new(InMemoryCodeContributionRepository::new()),
+            Arc::new(InMemoryAgentReviewRepository::new()),
+            elo_events.clone(),
+            Arc::new(InMemoryProjectRepository::new()),
+        );
+        for agent in [&consistent, &desynced] {
+            service
+                .apply_elo_change(&agent.id, ELO_PR_MERGED, EloEventType::PrMerged, None, None)
+                .await
+                .unwrap();
+        }
+        let integrity = InMemoryEloIntegrityRepository::new(agents.clone(), elo_events);
+
+        assert!(service
+            .find_elo_drift(&integrity, 100)
+            .await
+            .unwrap()
+            .is_empty());
+
+        // Write ELO behind the event log's back
+        agents.update_elo(&desynced.id, 1100).await.unwrap();
+
+        let drift = service.find_elo_drift(&integrity, 100).await.unwrap();
+        assert_eq!(drift.len(), 1);
+        assert_eq!(drift[0].agent_id, desynced.id);
+        assert_eq!(drift[0].stored_elo, 1100);
+        assert_eq!(drift[0].expected_elo, ELO_STARTING + ELO_PR_MERGED);
+        assert_eq!(drift[0].event_sum, ELO_PR_MERGED as i64);
+        assert_eq!(drift[0].drift(), 1100 - ELO_STARTING - ELO_PR_MERGED);
+    }
+
+    #[tokio::test]
+    async fn test_elo_drift_ignores_recovery_from_the_floor() {
+        let agent = test_agent_with_elo(ELO_STARTING);
+        let agents = Arc::new(InMemoryAgentRepository::new().with_agent(agent.clone()));
+        let elo_events = Arc::new(InMemoryEloEventRepository::new());
+        let service = ReactiveEloService::new(
+            agents.clone(),
+            Arc::new(InMemoryCodeContributionRepository::new()),
+            Arc::new(InMemoryAgentReviewRepository::new()),
+            elo_events.clone(),
+            Arc::new(InMemoryProjectRepository::new()),
+        )
+        .with_elo_bounds(EloBounds {
+            floor: ELO_STARTING - 20,
+            ..EloBounds::default()
+        });
+
+        // Two reverts hit the floor, then a merge climbs back off it
+        for (delta, event_type) in [
+            (ELO_COMMIT_REVERTED, EloEventType::CommitReverted),
+            (ELO_COMMIT_REVERTED, EloEventType::CommitReverted),
+            (ELO_PR_MERGED, EloEventType::PrMerged),
+        ] {
+            service
+                .apply_elo_change(&agent.id, delta, event_type, None, None)
+                .await
+                .unwrap();
+        }
+        let stored = agents.find_by_id(&agent.id).await.unwrap().unwrap();
+        assert_eq!(stored.elo, ELO_STARTING - 20 + ELO_PR_MERGED);
+
+        let integrity = InMemoryEloIntegrityRepository::new(agents, elo_events);
+        assert!(service
+            .find_elo_drift(&integrity, 100)
+            .await
+            .unwrap()
+            .is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_simulated_change_matches_applied_change_without_writing() {
+        let agent = test_agent_with_elo(1000);
+        let (service, elo_repo) =
+            create_capped_service(agent.clone(), InMemoryEloEventRepository::new(), 50);
+        service
+            .apply_elo_change(
+                &agent.id,
+                ELO_COMMIT_REVERTED,
+                EloEventType::CommitReverted,
+                None,
+                None,
+            )
+            .await
+            .unwrap();
+
+        let simulated = service
+            .simulate_elo_change(&agent.id, EloEventType::CommitReverted, None, None)
+            .await
+            .unwrap();
+
+        // Nothing was written
+        let stored = service.agents.find_by_id(&agent.id).await.unwrap().unwrap();
+        assert_eq!(stored.elo, 970);
+        assert_eq!(elo_repo.find_by_agent(&agent.id).await.unwrap().len(), 1);
+
+        let applied = service
+            .apply_elo_change(
+                &agent.id,
+                ELO_COMMIT_REVERTED,
+                EloEventType::CommitReverted,
+                None,
+                None,
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(simulated.requested_delta, ELO_COMMIT_REVERTED);
+        assert_eq!(simulated.delta, applied.delta);
+        assert_eq!(simulated.old_elo, applied.old_elo);
+        assert_eq!(simulated.new_elo, applied.new_elo);
+        assert_eq!(simulated.new_tier, Tier::from_elo(applied.new_elo));
+        // Clamped by the daily cap, and the simulation says so
+        assert_eq!(simulated.delta, -20);
+        assert!(simulated.details.unwrap().contains("daily ELO cap"));
+    }
+
+    #[tokio::test]
+    async fn test_simulated_change_applies_project_multiplier() {
+        let agent = test_agent_with_elo(1000);
+        let mut project = test_project();
+        project.elo_multiplier = 2.0;
+        let service = ReactiveEloService::new(
+            Arc::new(InMemoryAgentRepository::new().with_agent(agent.clone())),
+            Arc::new(InMemoryCodeContributionRepository::new()),
+            Arc::new(InMemoryAgentReviewRepository::new()),
+            Arc::new(InMemoryEloEventRepository::new()),
+            Arc::new(InMemoryProjectRepository::new().with_project(project.clone())),
+        );
+
+        let simulated = service
+            .simulate_elo_change(
+                &agent.id,
+                EloEventType::BugReferenced,
+                None,
+                Some(&project.id),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(simulated.requested_delta, ELO_BUG_REFERENCED * 2);
+        assert_eq!(simulated.delta, ELO_BUG_REFERENCED * 2);
+        assert_eq!(simulated.new_elo, 1000 + ELO_BUG_REFERENCED * 2);
+        assert_eq!(
+            simulated.details.as_deref(),
+            Some("(project ELO multiplier x2)")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_daily_cap_records_clamped_amount() {
+        let agent = test_agent_with_elo(1000);
+        let (service, elo_repo) =
+            create_capped_service(agent.clone(), InMemoryEloEventRepository::new(), 20);
+
+        service
+            .apply_elo_change(
+                &agent.id,
+                ELO_COMMIT_REVERTED,
+                EloEventType::CommitReverted,
+                None,
+                Some("Commit abc reverted".to_string()),
+            )
+            .await
+            .unwrap();
+
+        let events = elo_repo.find_by_agent(&agent.id).await.unwrap();
+        assert_eq!(events[0].delta, -20);
+        let details = events[0].details.as_deref().unwrap();
+        assert!(details.starts_with("Commit abc reverted"));
+        assert!(details.contains("-30 clamped to -20"));
+    }
+
+    #[tokio::test]
+    async fn test_daily_cap_resets_next_day() {
+        let agent = test_agent_with_elo(950);
+
+        // Yesterday the agent already hit the floor
+        let mut yesterday = test_elo_event(agent.id, EloEventType::CommitReverted, -50);
+        yesterday.created_at = Utc::now() - Duration::days(1);
+        let (service, _) = create_capped_service(
+            agent.clone(),
+            InMemoryEloEventRepository::new().with_event(yesterday),
+            50,
+        );
+
+        let result = service
+            .apply_elo_change(
+                &agent.id,
+                ELO_COMMIT_REVERTED,
+                EloEventType::CommitReverted,
+                None,
+                None,
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(result.delta, ELO_COMMIT_REVERTED);
+        assert_eq!(result.new_elo, 920);
+    }
+
+    fn bounded_service(
+        elo: i32,
+        bounds: EloBounds,
+    ) -> (
+        ReactiveEloService<
+            InMemoryAgentRepository,
+            InMemoryCodeContributionRepository,
+            InMemoryAgentReviewRepository,
+            InMemoryEloEventRepository,
+            InMemoryProjectRepository,
+        >,
+        AgentId,
+        Arc<InMemoryEloEventRepository>,
+    ) {
+        let agent = test_agent_with_elo(elo);
+        let (service, _, _, elo_repo) = create_service_with_agent(agent.clone());
+        (service.with_elo_bounds(bounds), agent.id, elo_repo)
+    }
+
+    #[test]
+    fn soft_cap_dampens_only_the_gain_past_the_cap() {
+        let bounds = EloBounds {
+            soft_cap: Some(1500),
+            soft_cap_span: 200,
+            ..EloBounds::default()
+        };
+        assert_eq!(bounds.dampen(1000, 20), 20);
+        assert_eq!(bounds.dampen(1490, 20), 20); // 10 below the cap, 10 at factor 1.0
+        assert_eq!(bounds.dampen(1700, 20), 10);
+        assert_eq!(bounds.dampen(1700, -20), -20);
+    }
+
+    #[tokio::test]
+    async fn test_gain_near_soft_cap_is_reduced_and_recorded() {
+        let bounds = EloBounds {
+            soft_cap: Some(1500),
+            soft_cap_span: 200,
+            ..EloBounds::default()
+        };
+        let (service, agent_id, elo_repo) = bounded_service(1700, bounds);
+
+        let result = service
+            .apply_elo_change(&agent_id, 20, EloEventType::PrMerged, None, None)
+            .await
+            .unwrap();
+
+        assert_eq!(result.delta, 10);
+        assert_eq!(result.new_elo, 1710);
+        let events = elo_repo.find_by_agent(&agent_id).await.unwrap();
+        let details = events[0].details.as_deref().unwrap();
+        assert!(details.contains("+20 dampened to +10"));
+    }
+
+    #[tokio::test]
+    async fn test_gain_well_below_soft_cap_is_full() {
+        let bounds = EloBounds {
+            soft_cap: Some(1500),
+            ..EloBounds::default()
+        };
+        let (service, agent_id, elo_repo) = bounded_service(1000, bounds);
+
+        let result = service
+            .apply_elo_change(&agent_id, 20, EloEventType::PrMerged, None, None)
+            .await
+            .unwrap();
+
+        assert_eq!(result.delta, 20);
+        assert_eq!(result.new_elo, 1020);
+        let events = elo_repo.find_by_agent(&agent_id).await.unwrap();
+        assert!(events[0].details.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_elo_ceiling_is_never_exceeded() {
+        let bounds = EloBounds {
+            ceiling: Some(2000),
+            ..EloBounds::default()
+        };
+        let (service, agent_id, _) = bounded_service(1990, bounds);
+
+        let first = service
+            .apply_elo_change(&agent_id, 25, EloEventType::PrMerged, None, None)
+            .await
+            .unwrap();
+        assert_eq!(first.new_elo, 2000);
+        assert_eq!(first.delta, 10);
+
+        let second = service
+            .apply_elo_change(&agent_id, 25, EloEventType::PrMerged, None, None)
+            .await
+            .unwrap();
+        assert_eq!(second.new_elo, 2000);
+        assert_eq!(second.delta, 0);
+    }
+
+    #[test]
+    fn clamp_to_daily_cap_never_reverses_direction() {
+        assert_eq!(clamp_to_daily_cap(-30, 0, 50), -30);
+        assert_eq!(clamp_to_daily_cap(-30, -40, 50), -10);
+        assert_eq!(clamp_to_daily_cap(15, 45, 50), 5);
+        // Already past the band (e.g. cap lowered mid-day)
+        assert_eq!(clamp_to_daily_cap(-5, -80, 50), 0);
+        assert_eq!(clamp_to_daily_cap(15, -80, 50), 15);
+    }
 
-        // Verify contribution was created
-        let contribs = contrib_repo.find_by_agent(&agent.id).await.unwrap();
-        assert_eq!(contribs.len(), 1);
-        assert_eq!(contribs[0].pr_number, 42);
-        assert_eq!(contribs[0].commit_sha, "abc123");
+    #[test]
+    fn scale_by_multiplier_clamps_out_of_range_multipliers() {
+        // A stored multiplier outside the allowed range never zeroes out,
+        // inverts or blows up a delta
+        let (delta, details) = scale_by_multiplier(10, 10.0, String::new());
+        assert_eq!(delta, 30);
+        assert_eq!(details, " (project ELO multiplier x3)");
+
+        let (delta, _) = scale_by_multiplier(10, 0.0, String::new());
+        assert_eq!(delta, 5);
+        let (delta, _) = scale_by_multiplier(-10, -2.0, String::new());
+        assert_eq!(delta, -5);
+
+        let (delta, details) = scale_by_multiplier(10, 1.0, "merged".to_string());
+        assert_eq!((delta, details.as_str()), (10, "merged"));
+    }
 
-        // Verify ELO event was logged
-        let events = elo_repo.find_by_agent(&agent.id).await.unwrap();
-        assert_eq!(events.len(), 1);
-        assert_eq!(events[0].delta, ELO_PR_MERGED);
+    #[test]
+    fn diminished_merge_award_halves_and_bottoms_out() {
+        assert_eq!(diminished_merge_award(0), ELO_PR_MERGED);
+        assert_eq!(diminished_merge_award(1), 7);
+        assert_eq!(diminished_merge_award(4), 0);
+        assert_eq!(diminished_merge_award(100), 0);
     }
 
     #[tokio::test]
@@ -672,10 +3000,11 @@ mod tests {
             contrib_repo.clone(),
             review_repo,
             elo_repo.clone(),
+            Arc::new(InMemoryProjectRepository::new()),
         );
 
         let result = service
-            .on_commit_reverted(&commit_sha, "revert123")
+            .on_commit_reverted(&commit_sha, "revert123", None)
             .await
             .expect("Revert should succeed")
             .expect("Should return result");
@@ -695,6 +3024,449 @@ mod tests {
         assert_eq!(contribs[0].status, ContributionStatus::Reverted);
     }
 
+    /// Contribution merged `days_ago` with its own commit sha, optionally
+    /// reverted `reverted_days_ago`
+    fn aged_contribution(
+        agent_id: AgentId,
+        project_id: ProjectId,
+        sha: &str,
+        days_ago: i64,
+        reverted_days_ago: Option<i64>,
+    ) -> CodeContribution {
+        let mut contribution = test_code_contribution_merged_at(
+            agent_id,
+            project_id,
+            Utc::now() - Duration::days(days_ago) - Duration::hours(1),
+        );
+        contribution.commit_sha = sha.to_string();
+        if let Some(days) = reverted_days_ago {
+            contribution.status = ContributionStatus::Reverted;
+            contribution.reverted_at = Some(Utc::now() - Duration::days(days));
+        }
+        contribution
+    }
+
+    async fn revert_with_probation(
+        contributions: Vec<CodeContribution>,
+        agent: crate::domain::entities::Agent,
+        reverted_sha: &str,
+    ) -> (EloChangeResult, bool) {
+        let contrib_repo = contributions
+            .into_iter()
+            .fold(InMemoryCodeContributionRepository::new(), |repo, c| {
+                repo.with_contribution(c)
+            });
+        let service = ReactiveEloService::new(
+            Arc::new(InMemoryAgentRepository::new().with_agent(agent.clone())),
+            Arc::new(contrib_repo),
+            Arc::new(InMemoryAgentReviewRepository::new()),
+            Arc::new(InMemoryEloEventRepository::new()),
+            Arc::new(InMemoryProjectRepository::new()),
+        )
+        .with_probation(Some(ProbationPolicy::default()));
+
+        let result = service
+            .on_commit_reverted(reverted_sha, "revert123", None)
+            .await
+            .unwrap()
+            .unwrap();
+        let on_probation = service.is_on_probation(&agent.id).await.unwrap();
+        (result, on_probation)
+    }
+
+    #[tokio::test]
+    async fn test_probationary_revert_is_steeper_and_resets_probation() {
+        let agent = test_agent_with_elo(1000);
+        let project = test_project();
+
+        // Two matured contributions weren't enough when the first revert hit,
+        // so only the one merged after it counts toward leaving probation
+        let contributions = vec![
+            aged_contribution(agent.id, project.id, "sha1", 30, None),
+            aged_contribution(agent.id, project.id, "sha2", 30, None),
+            aged_contribution(agent.id, project.id, "sha3", 20, Some(10)),
+            aged_contribution(agent.id, project.id, "sha4", 9, None),
+            aged_contribution(agent.id, project.id, "sha5", 0, None),
+        ];
+
+        let (result, on_probation) = revert_with_probation(contributions, agent, "sha5").await;
+
+        assert_eq!(result.delta, ELO_COMMIT_REVERTED * 2);
+        assert_eq!(result.new_elo, 940);
+        assert!(on_probation);
+    }
+
+    #[tokio::test]
+    async fn test_post_probation_revert_is_normal() {
+        let agent = test_agent_with_elo(1000);
+        let project = test_project();
+
+        // Three matured contributions graduate the agent; a later revert
+        // doesn't put it back on probation
+        let contributions = vec![
+            aged_contribution(agent.id, project.id, "sha1", 30, None),
+            aged_contribution(agent.id, project.id, "sha2", 30, None),
+            aged_contribution(agent.id, project.id, "sha3", 30, None),
+            aged_contribution(agent.id, project.id, "sha4", 10, Some(5)),
+            aged_contribution(agent.id, project.id, "sha5", 0, None),
+        ];
+
+        let (result, on_probation) = revert_with_probation(contributions, agent, "sha5").await;
+
+        assert_eq!(result.delta, ELO_COMMIT_REVERTED);
+        assert_eq!(result.new_elo, 970);
+        assert!(!on_probation);
+    }
+
+    #[tokio::test]
+    async fn test_no_probation_unless_configured() {
+        let agent = test_agent_with_elo(1000);
+        let project = test_project();
+        let service = ReactiveEloService::new(
+            Arc::new(InMemoryAgentRepository::new().with_agent(agent.clone())),
+            Arc::new(
+                InMemoryCodeContributionRepository::new()
+                    .with_contribution(aged_contribution(agent.id, project.id, "sha1", 0, None)),
+            ),
+            Arc::new(InMemoryAgentReviewRepository::new()),
+            Arc::new(InMemoryEloEventRepository::new()),
+            Arc::new(InMemoryProjectRepository::new()),
+        );
+
+        assert!(!service.is_on_probation(&agent.id).await.unwrap());
+        let result = service
+            .on_commit_reverted("sha1", "revert123", None)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(result.delta, ELO_COMMIT_REVERTED);
+    }
+
+    #[tokio::test]
+    async fn test_revert_records_commit_author_from_gitea() {
+        let agent = test_agent_with_elo(1000);
+        let project = test_project();
+        let contribution =
+            test_code_contribution_merged_at(agent.id, project.id, Utc::now() - Duration::hours(1));
+        let commit_sha = contribution.commit_sha.clone();
+
+        let gitea = MockGiteaClient::new().with_commit(
+            "org",
+            "repo",
+            &commit_sha,
+            Some("someone-else"),
+            "Add feature",
+        );
+        let elo_repo = Arc::new(InMemoryEloEventRepository::new());
+        let service = ReactiveEloService::new(
+            Arc::new(InMemoryAgentRepository::new().with_agent(agent.clone())),
+            Arc::new(InMemoryCodeContributionRepository::new().with_contribution(contribution)),
+            Arc::new(InMemoryAgentReviewRepository::new()),
+            elo_repo.clone(),
+            Arc::new(InMemoryProjectRepository::new()),
+        );
+
+        let commit = gitea.get_commit("org", "repo", &commit_sha).await.unwrap();
+        assert_eq!(commit.message, "Add feature");
+
+        service
+            .on_commit_reverted(&commit_sha, "revert123", commit.author_login.as_deref())
+            .await
+            .unwrap()
+            .expect("Should return result");
+
+        let events = elo_repo.find_by_agent(&agent.id).await.unwrap();
+        let details = events[0].details.as_deref().unwrap();
+        assert!(details.contains("authored by someone-else"));
+        assert!(details.contains(&format!("recorded contributor {}", agent.gitea_username)));
+    }
+
+    #[tokio::test]
+    async fn test_revert_penalizes_recent_approvers_only() {
+        let author = test_agent_with_elo(1000);
+        let approver = test_agent_with_elo(1500);
+        let requester = test_agent_with_elo(1500);
+        let stale_approver = test_agent_with_elo(1500);
+        let project = test_project();
+        let contribution = test_code_contribution_merged_at(
+            author.id,
+            project.id,
+            Utc::now() - Duration::hours(1),
+        );
+        let commit_sha = contribution.commit_sha.clone();
+
+        let review = |reviewer: &Agent, verdict| AgentReview {
+            pr_id: contribution.pr_number,
+            ..test_agent_review(reviewer.id, author.id, project.id, verdict)
+        };
+        let approval = review(&approver, ReviewVerdict::Approved);
+        let stale_approval = AgentReview {
+            created_at: Utc::now() - Duration::days(BAD_APPROVAL_WINDOW_DAYS + 1),
+            ..review(&stale_approver, ReviewVerdict::Approved)
+        };
+
+        let agent_repo = Arc::new(
+            InMemoryAgentRepository::new()
+                .with_agent(author.clone())
+                .with_agent(approver.clone())
+                .with_agent(requester.clone())
+                .with_agent(stale_approver.clone()),
+        );
+        let review_repo = Arc::new(
+            InMemoryAgentReviewRepository::new()
+                .with_review(approval.clone())
+                .with_review(review(&requester, ReviewVerdict::ChangesRequested))
+                .with_review(stale_approval),
+        );
+        let elo_repo = Arc::new(InMemoryEloEventRepository::new());
+        let service = ReactiveEloService::new(
+            agent_repo.clone(),
+            Arc::new(InMemoryCodeContributionRepository::new().with_contribution(contribution)),
+            review_repo,
+            elo_repo.clone(),
+            Arc::new(InMemoryProjectRepository::new()),
+        );
+
+        service
+            .on_commit_reverted(&commit_sha, "revert123", None)
+            .await
+            .unwrap()
+            .expect("Should return result");
+
+        for (agent, expected) in [
+            (&approver, 1500 + ELO_BAD_APPROVAL),
+            (&requester, 1500),
+            (&stale_approver, 1500),
+        ] {
+            let updated = agent_repo.find_by_id(&agent.id).await.unwrap().unwrap();
+            assert_eq!(updated.elo, expected);
+        }
+
+        let events = elo_repo.find_by_agent(&approver.id).await.unwrap();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].event_type, EloEventType::BadApproval);
+        assert_eq!(events[0].reference_id, Some(approval.id.0));
+        assert!(elo_repo
+            .find_by_agent(&requester.id)
+            .await
+            .unwrap()
+            .is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_bug_references_penalize_each_approver_once() {
+        let author = test_agent_with_elo(1000);
+        let approver = test_agent_with_elo(1500);
+        let project = test_project();
+        let contribution = test_code_contribution_merged_at(
+            author.id,
+            project.id,
+            Utc::now() - Duration::hours(1),
+        );
+        let approval = || AgentReview {
+            pr_id: contribution.pr_number,
+            ..test_agent_review(approver.id, author.id, project.id, ReviewVerdict::Approved)
+        };
+
+        let agent_repo = Arc::new(
+            InMemoryAgentRepository::new()
+                .with_agent(author.clone())
+                .with_agent(approver.clone()),
+        );
+        let elo_repo = Arc::new(InMemoryEloEventRepository::new());
+        let service = ReactiveEloService::new(
+            agent_repo.clone(),
+            Arc::new(
+                InMemoryCodeContributionRepository::new().with_contribution(contribution.clone()),
+            ),
+            // The approver approved twice, e.g. again after a force-push
+            Arc::new(
+                InMemoryAgentReviewRepository::new()
+                    .with_review(approval())
+                    .with_review(approval()),
+            ),
+            elo_repo.clone(),
+            Arc::new(InMemoryProjectRepository::new()),
+        );
+
+        for issue in [98, 99] {
+            service
+                .on_bug_referenced(
+                    &project.id,
+                    42,
+                    &format!("https://gitea.local/issues/{}", issue),
+                    &[],
+                )
+                .await
+                .unwrap()
+                .expect("Should return result");
+        }
+
+        // The author pays for each bug, the approver only once
+        let author_elo = agent_repo
+            .find_by_id(&author.id)
+            .await
+            .unwrap()
+            .unwrap()
+            .elo;
+        assert_eq!(author_elo, 1000 + 2 * ELO_BUG_REFERENCED);
+        let updated = agent_repo.find_by_id(&approver.id).await.unwrap().unwrap();
+        assert_eq!(updated.elo, 1500 + ELO_BAD_APPROVAL);
+        assert_eq!(elo_repo.find_by_agent(&approver.id).await.unwrap().len(), 1);
+    }
+
+    fn create_strict_attribution_service(
+        author: &Agent,
+        project: &crate::domain::entities::Project,
+        diff: &str,
+    ) -> (
+        ReactiveEloService<
+            InMemoryAgentRepository,
+            InMemoryCodeContributionRepository,
+            InMemoryAgentReviewRepository,
+            InMemoryEloEventRepository,
+            InMemoryProjectRepository,
+        >,
+        Arc<InMemoryAgentRepository>,
+    ) {
+        let contribution = test_code_contribution_merged_at(
+            author.id,
+            project.id,
+            Utc::now() - Duration::hours(1),
+        );
+        let gitea = MockGiteaClient::new()
+            .with_pr_diff(&project.gitea_org, &project.gitea_repo, 42, diff)
+            .with_file_at_ref(
+                &project.gitea_org,
+                &project.gitea_repo,
+                "src/lib.rs",
+                "main",
+                "fn main() {\n    let total = add(1, 2);\n}\n",
+            );
+        let agent_repo = Arc::new(InMemoryAgentRepository::new().with_agent(author.clone()));
+        let service = ReactiveEloService::new(
+            agent_repo.clone(),
+            Arc::new(InMemoryCodeContributionRepository::new().with_contribution(contribution)),
+            Arc::new(InMemoryAgentReviewRepository::new()),
+            Arc::new(InMemoryEloEventRepository::new()),
+            Arc::new(InMemoryProjectRepository::new().with_project(project.clone())),
+        )
+        .with_bug_attribution(Some(Arc::new(GiteaBugAttribution::new(Arc::new(gitea)))));
+        (service, agent_repo)
+    }
+
+    const SRC_LIB_DIFF: &str = "diff --git a/src/lib.rs b/src/lib.rs\n\
+        --- a/src/lib.rs\n\
+        +++ b/src/lib.rs\n\
+        @@ -1,2 +1,3 @@\n \
+        fn main() {\n\
+        +    let total = add(1, 2);\n \
+        }\n";
+
+    #[tokio::test]
+    async fn test_strict_attribution_skips_bug_in_unrelated_code() {
+        let author = test_agent_with_elo(1000);
+        let project = test_project();
+        let (service, agent_repo) =
+            create_strict_attribution_service(&author, &project, SRC_LIB_DIFF);
+
+        let result = service
+            .on_bug_referenced(
+                &project.id,
+                42,
+                "https://gitea.local/issues/99",
+                &[CodeReference {
+                    path: "src/other.rs".to_string(),
+                    line: None,
+                }],
+            )
+            .await
+            .unwrap();
+
+        assert!(result.is_none());
+        let updated = agent_repo.find_by_id(&author.id).await.unwrap().unwrap();
+        assert_eq!(updated.elo, 1000);
+    }
+
+    #[tokio::test]
+    async fn test_strict_attribution_penalizes_when_gitea_is_unreachable() {
+        let author = test_agent_with_elo(1000);
+        let project = test_project();
+        let contribution = test_code_contribution_merged_at(
+            author.id,
+            project.id,
+            Utc::now() - Duration::hours(1),
+        );
+        let gitea =
+            MockGiteaClient::new().with_unreachable_pr(&project.gitea_org, &project.gitea_repo, 42);
+        let agent_repo = Arc::new(InMemoryAgentRepository::new().with_agent(author.clone()));
+        let service = ReactiveEloService::new(
+            agent_repo.clone(),
+            Arc::new(InMemoryCodeContributionRepository::new().with_contribution(contribution)),
+            Arc::new(InMemoryAgentReviewRepository::new()),
+            Arc::new(InMemoryEloEventRepository::new()),
+            Arc::new(InMemoryProjectRepository::new().with_project(project.clone())),
+        )
+        .with_bug_attribution(Some(Arc::new(GiteaBugAttribution::new(Arc::new(gitea)))));
+
+        let result = service
+            .on_bug_referenced(
+                &project.id,
+                42,
+                "https://gitea.local/issues/99",
+                &[CodeReference {
+                    path: "src/lib.rs".to_string(),
+                    line: Some(2),
+                }],
+            )
+            .await
+            .unwrap();
+
+        assert!(result.is_some());
+        let updated = agent_repo.find_by_id(&author.id).await.unwrap().unwrap();
+        assert_eq!(updated.elo, 1000 + ELO_BUG_REFERENCED);
+    }
+
+    #[tokio::test]
+    async fn test_strict_attribution_penalizes_bug_on_added_line() {
+        let author = test_agent_with_elo(1000);
+        let project = test_project();
+        let (service, agent_repo) =
+            create_strict_attribution_service(&author, &project, SRC_LIB_DIFF);
+
+        let touched = |line| {
+            [CodeReference {
+                path: "src/lib.rs".to_string(),
+                line: Some(line),
+            }]
+        };
+        // Line 1 exists in the file but wasn't added by the PR
+        let result = service
+            .on_bug_referenced(
+                &project.id,
+                42,
+                "https://gitea.local/issues/99",
+                &touched(1),
+            )
+            .await
+            .unwrap();
+        assert!(result.is_none());
+
+        let result = service
+            .on_bug_referenced(
+                &project.id,
+                42,
+                "https://gitea.local/issues/99",
+                &touched(2),
+            )
+            .await
+            .unwrap()
+            .expect("Should penalize the PR that added the line");
+        assert_eq!(result.delta, ELO_BUG_REFERENCED);
+        let updated = agent_repo.find_by_id(&author.id).await.unwrap().unwrap();
+        assert_eq!(updated.elo, 1000 + ELO_BUG_REFERENCED);
+    }
+
     #[tokio::test]
     async fn test_revert_idempotent() {
         let agent = test_agent_with_elo(1000);
@@ -713,12 +3485,17 @@ mod tests {
         let review_repo = Arc::new(InMemoryAgentReviewRepository::new());
         let elo_repo = Arc::new(InMemoryEloEventRepository::new());
 
-        let service =
-            ReactiveEloService::new(agent_repo.clone(), contrib_repo, review_repo, elo_repo);
+        let service = ReactiveEloService::new(
+            agent_repo.clone(),
+            contrib_repo,
+            review_repo,
+            elo_repo,
+            Arc::new(InMemoryProjectRepository::new()),
+        );
 
         // Should return None for already-reverted contribution
         let result = service
-            .on_commit_reverted(&commit_sha, "revert123")
+            .on_commit_reverted(&commit_sha, "revert123", None)
             .await
             .expect("Revert should succeed");
 
@@ -752,6 +3529,7 @@ mod tests {
             contrib_repo,
             review_repo,
             elo_repo.clone(),
+            Arc::new(InMemoryProjectRepository::new()),
         );
 
         let result = service
@@ -793,8 +3571,13 @@ mod tests {
         let review_repo = Arc::new(InMemoryAgentReviewRepository::new());
         let elo_repo = Arc::new(InMemoryEloEventRepository::new());
 
-        let service =
-            ReactiveEloService::new(agent_repo.clone(), contrib_repo, review_repo, elo_repo);
+        let service = ReactiveEloService::new(
+            agent_repo.clone(),
+            contrib_repo,
+            review_repo,
+            elo_repo,
+            Arc::new(InMemoryProjectRepository::new()),
+        );
 
         let result = service
             .on_peer_review(
@@ -815,6 +3598,70 @@ mod tests {
         assert_eq!(updated_reviewed.elo, 1000);
     }
 
+    /// Review PR 42 on a project with the given eligibility, optionally as a member
+    async fn review_with_eligibility(
+        eligibility: ReviewEligibility,
+        reviewer_is_member: bool,
+    ) -> Result<Option<EloChangeResult>, AppError> {
+        let reviewer = test_agent_with_elo(1200);
+        let reviewed = test_agent_with_elo(1000);
+        let mut project = test_project();
+        project.review_eligibility = eligibility;
+
+        let projects = InMemoryProjectRepository::new();
+        let projects = if reviewer_is_member {
+            projects.with_project_and_member(project.clone(), reviewer.id)
+        } else {
+            projects.with_project(project.clone())
+        };
+        let service = ReactiveEloService::new(
+            Arc::new(
+                InMemoryAgentRepository::new()
+                    .with_agent(reviewer.clone())
+                    .with_agent(reviewed.clone()),
+            ),
+            Arc::new(InMemoryCodeContributionRepository::new()),
+            Arc::new(InMemoryAgentReviewRepository::new()),
+            Arc::new(InMemoryEloEventRepository::new()),
+            Arc::new(projects),
+        );
+
+        service
+            .on_peer_review(
+                42,
+                &project.id,
+                &reviewer.id,
+                &reviewed.id,
+                ReviewVerdict::Approved,
+            )
+            .await
+    }
+
+    #[tokio::test]
+    async fn test_peer_review_by_member_on_member_only_project_allowed() {
+        review_with_eligibility(ReviewEligibility::Members, true)
+            .await
+            .expect("members may review");
+    }
+
+    #[tokio::test]
+    async fn test_peer_review_by_non_member_on_member_only_project_rejected() {
+        let err = review_with_eligibility(ReviewEligibility::Members, false)
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            AppError::Domain(DomainError::Validation(ref msg)) if msg.contains("members")
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_peer_review_by_non_member_on_open_project_allowed() {
+        review_with_eligibility(ReviewEligibility::Open, false)
+            .await
+            .expect("open projects take reviews from anyone");
+    }
+
     #[tokio::test]
     async fn test_peer_review_self_review_rejected() {
         let agent = test_agent_with_elo(1500);
@@ -826,6 +3673,7 @@ mod tests {
             Arc::new(InMemoryCodeContributionRepository::new()),
             Arc::new(InMemoryAgentReviewRepository::new()),
             Arc::new(InMemoryEloEventRepository::new()),
+            Arc::new(InMemoryProjectRepository::new()),
         );
 
         let result = service
@@ -843,6 +3691,59 @@ mod tests {
         assert!(err_msg.contains("Cannot review your own PR"));
     }
 
+    #[tokio::test]
+    async fn test_peer_review_linked_identity_rejected_when_strict() {
+        let author = Agent {
+            github_id: Some(4242),
+            ..test_agent_with_elo(1000)
+        };
+        let alt = Agent {
+            github_id: Some(4242),
+            ..test_agent_with_elo(1500)
+        };
+        let project = test_project();
+
+        let service = |policy| {
+            ReactiveEloService::new(
+                Arc::new(
+                    InMemoryAgentRepository::new()
+                        .with_agent(author.clone())
+                        .with_agent(alt.clone()),
+                ),
+                Arc::new(InMemoryCodeContributionRepository::new()),
+                Arc::new(InMemoryAgentReviewRepository::new()),
+                Arc::new(InMemoryEloEventRepository::new()),
+                Arc::new(InMemoryProjectRepository::new()),
+            )
+            .with_self_review_policy(policy)
+        };
+
+        let err = service(SelfReviewPolicy::Linked)
+            .on_peer_review(
+                42,
+                &project.id,
+                &alt.id,
+                &author.id,
+                ReviewVerdict::Approved,
+            )
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("linked to your account"));
+
+        // The default policy only rejects the exact same agent
+        let result = service(SelfReviewPolicy::Exact)
+            .on_peer_review(
+                42,
+                &project.id,
+                &alt.id,
+                &author.id,
+                ReviewVerdict::Approved,
+            )
+            .await
+            .unwrap();
+        assert!(result.is_some());
+    }
+
     #[tokio::test]
     async fn test_peer_review_duplicate_rejected() {
         let reviewer = test_agent_with_elo(1500);
@@ -860,6 +3761,7 @@ mod tests {
             Arc::new(InMemoryCodeContributionRepository::new()),
             review_repo.clone(),
             Arc::new(InMemoryEloEventRepository::new()),
+            Arc::new(InMemoryProjectRepository::new()),
         );
 
         // First review succeeds
@@ -909,10 +3811,11 @@ mod tests {
             contrib_repo.clone(),
             Arc::new(InMemoryAgentReviewRepository::new()),
             elo_repo.clone(),
+            Arc::new(InMemoryProjectRepository::new()),
         );
 
         let result = service
-            .on_bug_referenced(&project.id, 42, "https://gitea.local/issues/99")
+            .on_bug_referenced(&project.id, 42, "https://gitea.local/issues/99", &[])
             .await
             .expect("Bug reference should succeed")
             .expect("Should return result");
@@ -928,6 +3831,68 @@ mod tests {
         assert_eq!(contribs[0].bug_count, 1);
     }
 
+    #[tokio::test]
+    async fn test_cross_repo_bug_reference_resolves_referenced_project() {
+        let agent = test_agent_with_elo(1000);
+        let reporting_project = test_project();
+        let mut referenced_project = test_project();
+        referenced_project.gitea_org = "foo".to_string();
+        referenced_project.gitea_repo = "bar".to_string();
+
+        let mut contribution = test_code_contribution_merged_at(
+            agent.id,
+            referenced_project.id,
+            Utc::now() - Duration::hours(1),
+        );
+        contribution.pr_number = 12;
+
+        let contrib_repo =
+            Arc::new(InMemoryCodeContributionRepository::new().with_contribution(contribution));
+        let service = ReactiveEloService::new(
+            Arc::new(InMemoryAgentRepository::new().with_agent(agent.clone())),
+            contrib_repo.clone(),
+            Arc::new(InMemoryAgentReviewRepository::new()),
+            Arc::new(InMemoryEloEventRepository::new()),
+            Arc::new(
+                InMemoryProjectRepository::new()
+                    .with_project(reporting_project.clone())
+                    .with_project(referenced_project.clone()),
+            ),
+        );
+
+        let refs = parse_bug_references("Regression from foo/bar#12");
+        assert_eq!(refs, vec![(12, Some("foo/bar".to_string()))]);
+        let (number, repo) = &refs[0];
+
+        let result = service
+            .on_bug_reference(
+                &reporting_project.id,
+                *number,
+                repo.as_deref(),
+                "https://gitea.local/issues/5",
+                &[],
+            )
+            .await
+            .unwrap()
+            .expect("contribution in foo/bar should be penalized");
+
+        assert_eq!(result.agent_id, agent.id);
+        assert_eq!(result.delta, ELO_BUG_REFERENCED);
+        let contribution = contrib_repo
+            .find_by_pr(&referenced_project.id, 12)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(contribution.bug_count, 1);
+
+        // A repo that isn't a project is ignored
+        let unknown = service
+            .on_bug_reference(&reporting_project.id, 12, Some("other/repo"), "url", &[])
+            .await
+            .unwrap();
+        assert!(unknown.is_none());
+    }
+
     #[tokio::test]
     async fn test_pr_rejected_deducts_elo() {
         let agent = test_agent_with_elo(1000);
@@ -951,6 +3916,97 @@ mod tests {
         assert_eq!(updated_agent.elo, 995);
     }
 
+    fn create_service_with_project(
+        agent: crate::domain::entities::Agent,
+        project: crate::domain::entities::Project,
+        contrib_repo: InMemoryCodeContributionRepository,
+    ) -> (
+        ReactiveEloService<
+            InMemoryAgentRepository,
+            InMemoryCodeContributionRepository,
+            InMemoryAgentReviewRepository,
+            InMemoryEloEventRepository,
+            InMemoryProjectRepository,
+        >,
+        Arc<InMemoryEloEventRepository>,
+    ) {
+        let elo_repo = Arc::new(InMemoryEloEventRepository::new());
+        let service = ReactiveEloService::new(
+            Arc::new(InMemoryAgentRepository::new().with_agent(agent)),
+            Arc::new(contrib_repo),
+            Arc::new(InMemoryAgentReviewRepository::new()),
+            elo_repo.clone(),
+            Arc::new(InMemoryProjectRepository::new().with_project(project)),
+        );
+        (service, elo_repo)
+    }
+
+    #[tokio::test]
+    async fn test_project_multiplier_doubles_merge_award() {
+        let agent = test_agent_with_elo(1000);
+        let mut project = test_project();
+        project.elo_multiplier = 2.0;
+        let (service, elo_repo) = create_service_with_project(
+            agent.clone(),
+            project.clone(),
+            InMemoryCodeContributionRepository::new(),
+        );
+
+        let result = service
+            .on_pr_merged(&agent.id, &project.id, 42, "abc123", Difficulty::Medium)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(result.delta, ELO_PR_MERGED * 2);
+
+        let events = elo_repo.find_by_agent(&agent.id).await.unwrap();
+        let details = events[0].details.as_deref().unwrap();
+        assert!(details.contains("project ELO multiplier x2"), "{}", details);
+    }
+
+    #[tokio::test]
+    async fn test_project_multiplier_halves_penalties() {
+        let agent = test_agent_with_elo(1000);
+        let mut project = test_project();
+        project.elo_multiplier = 0.5;
+        let contribution =
+            test_code_contribution_merged_at(agent.id, project.id, Utc::now() - Duration::hours(1));
+        let commit_sha = contribution.commit_sha.clone();
+        let (service, _) = create_service_with_project(
+            agent.clone(),
+            project,
+            InMemoryCodeContributionRepository::new().with_contribution(contribution),
+        );
+
+        let result = service
+            .on_commit_reverted(&commit_sha, "revert123", None)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(result.delta, ELO_COMMIT_REVERTED / 2);
+        assert_eq!(result.new_elo, 985);
+    }
+
+    #[tokio::test]
+    async fn test_ticket_abandoned_uses_configured_penalty() {
+        let agent = test_agent_with_elo(1000);
+        let project = test_project();
+        let ticket = test_ticket_assigned(project.id, agent.id);
+
+        let (service, agent_repo, _, _) = create_service_with_agent(agent.clone());
+        let service = service.with_ticket_abandon_penalty(-3);
+
+        let result = service
+            .on_ticket_abandoned(&agent.id, &ticket)
+            .await
+            .expect("ticket abandon should succeed");
+
+        assert_eq!(result.delta, -3);
+        assert_eq!(result.event_type, EloEventType::TicketAbandoned);
+        let updated_agent = agent_repo.find_by_id(&agent.id).await.unwrap().unwrap();
+        assert_eq!(updated_agent.elo, 997);
+    }
+
     #[tokio::test]
     async fn test_longevity_bonus_awarded() {
         let agent = test_agent_with_elo(1000);
@@ -970,6 +4026,7 @@ mod tests {
             contrib_repo.clone(),
             Arc::new(InMemoryAgentReviewRepository::new()),
             elo_repo.clone(),
+            Arc::new(InMemoryProjectRepository::new()),
         );
 
         let results = service
@@ -1014,6 +4071,7 @@ mod tests {
             contrib_repo,
             Arc::new(InMemoryAgentReviewRepository::new()),
             elo_repo,
+            Arc::new(InMemoryProjectRepository::new()),
         );
 
         let results = service
@@ -1029,6 +4087,64 @@ mod tests {
         assert_eq!(updated_agent.elo, 1000);
     }
 
+    #[tokio::test]
+    async fn test_longevity_bonuses_paid_exactly_once_under_concurrency() {
+        let project = test_project();
+        let merged_at = Utc::now() - Duration::days(31);
+        let agents: Vec<Agent> = (0..10).map(|_| test_agent_with_elo(1000)).collect();
+
+        let mut agent_repo = InMemoryAgentRepository::new();
+        let mut contrib_repo = InMemoryCodeContributionRepository::new();
+        for agent in &agents {
+            agent_repo = agent_repo.with_agent(agent.clone());
+            for _ in 0..6 {
+                contrib_repo = contrib_repo.with_contribution(test_code_contribution_merged_at(
+                    agent.id, project.id, merged_at,
+                ));
+            }
+        }
+        let agent_repo = Arc::new(agent_repo);
+        let contrib_repo = Arc::new(contrib_repo);
+        let elo_repo = Arc::new(InMemoryEloEventRepository::new());
+
+        let service = ReactiveEloService::new(
+            agent_repo.clone(),
+            contrib_repo.clone(),
+            Arc::new(InMemoryAgentReviewRepository::new()),
+            elo_repo.clone(),
+            Arc::new(InMemoryProjectRepository::new()),
+        )
+        .with_longevity_concurrency(4);
+
+        // Two overlapping sweeps must not pay any bonus twice
+        let (first, second) = tokio::join!(
+            service.process_longevity_bonuses(),
+            service.process_longevity_bonuses()
+        );
+        let paid = first.unwrap().len() + second.unwrap().len();
+        assert_eq!(paid, 60);
+
+        let events = elo_repo.get_all_events();
+        assert_eq!(events.len(), 60);
+        let mut referenced: Vec<_> = events.iter().map(|e| e.reference_id).collect();
+        referenced.sort();
+        referenced.dedup();
+        assert_eq!(referenced.len(), 60);
+
+        for agent in &agents {
+            let updated = agent_repo.find_by_id(&agent.id).await.unwrap().unwrap();
+            assert_eq!(updated.elo, 1000 + 6 * ELO_LONGEVITY_BONUS);
+            let contribs = contrib_repo.find_by_agent(&agent.id).await.unwrap();
+            assert!(contribs.iter().all(|c| c.longevity_bonus_paid));
+        }
+
+        assert!(service
+            .process_longevity_bonuses()
+            .await
+            .unwrap()
+            .is_empty());
+    }
+
     #[tokio::test]
     async fn test_code_replaced_within_window() {
         let agent = test_agent_with_elo(1000);
@@ -1049,6 +4165,7 @@ mod tests {
             contrib_repo.clone(),
             Arc::new(InMemoryAgentReviewRepository::new()),
             elo_repo,
+            Arc::new(InMemoryProjectRepository::new()),
         );
 
         let result = service
@@ -1088,6 +4205,7 @@ mod tests {
             contrib_repo,
             Arc::new(InMemoryAgentReviewRepository::new()),
             elo_repo,
+            Arc::new(InMemoryProjectRepository::new()),
         );
 
         let result = service
@@ -1123,10 +4241,11 @@ mod tests {
             contrib_repo,
             Arc::new(InMemoryAgentReviewRepository::new()),
             elo_repo,
+            Arc::new(InMemoryProjectRepository::new()),
         );
 
         let result = service
-            .on_commit_reverted(&commit_sha, "revert123")
+            .on_commit_reverted(&commit_sha, "revert123", None)
             .await
             .expect("Revert should succeed")
             .expect("Should return result");
@@ -1159,6 +4278,7 @@ mod tests {
             contrib_repo.clone(),
             Arc::new(InMemoryAgentReviewRepository::new()),
             elo_repo,
+            Arc::new(InMemoryProjectRepository::new()),
         );
 
         let result = service
@@ -1177,6 +4297,184 @@ mod tests {
         assert_eq!(contribs[0].dependent_prs_count, 1);
     }
 
+    #[tokio::test]
+    async fn test_merged_pr_credits_contribution_it_builds_on() {
+        let original_author = test_agent_with_elo(1000);
+        let builder = test_agent_with_elo(1000);
+        let project = test_project();
+
+        // Contribution fixtures are PR #42 at commit abc123def456
+        let contribution = test_code_contribution_merged_at(
+            original_author.id,
+            project.id,
+            Utc::now() - Duration::days(1),
+        );
+        let gitea = MockGiteaClient::new().with_comparison(
+            &project.gitea_org,
+            &project.gitea_repo,
+            "abc123def456",
+            "head43",
+            2,
+            0,
+        );
+
+        let agent_repo = Arc::new(
+            InMemoryAgentRepository::new()
+                .with_agent(original_author.clone())
+                .with_agent(builder.clone()),
+        );
+        let contrib_repo = Arc::new(
+            InMemoryCodeContributionRepository::new().with_contribution(contribution.clone()),
+        );
+
+        let service = ReactiveEloService::new(
+            agent_repo.clone(),
+            contrib_repo.clone(),
+            Arc::new(InMemoryAgentReviewRepository::new()),
+            Arc::new(InMemoryEloEventRepository::new()),
+            Arc::new(InMemoryProjectRepository::new()),
+        );
+
+        let results = service
+            .on_dependencies_merged(
+                &gitea,
+                &project,
+                43,
+                "head43",
+                &builder.id,
+                "Adds caching. Builds on #42 and #999.",
+            )
+            .await
+            .expect("Dependency credit should succeed");
+
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].agent_id, original_author.id);
+        assert_eq!(results[0].delta, ELO_DEPENDENT_PR);
+        let contribs = contrib_repo
+            .find_by_agent(&original_author.id)
+            .await
+            .unwrap();
+        assert_eq!(contribs[0].dependent_prs_count, 1);
+
+        // Building on your own PR earns nothing
+        let own = service
+            .on_dependencies_merged(
+                &gitea,
+                &project,
+                44,
+                "head43",
+                &original_author.id,
+                "builds on #42",
+            )
+            .await
+            .unwrap();
+        assert!(own.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_dependency_outside_pr_history_earns_nothing() {
+        let original_author = test_agent_with_elo(1000);
+        let builder = test_agent_with_elo(1000);
+        let project = test_project();
+        let contribution = test_code_contribution_merged_at(
+            original_author.id,
+            project.id,
+            Utc::now() - Duration::days(1),
+        );
+        // The PR's head doesn't contain PR #42's commit
+        let gitea = MockGiteaClient::new().with_comparison(
+            &project.gitea_org,
+            &project.gitea_repo,
+            "abc123def456",
+            "unrelated",
+            3,
+            4,
+        );
+
+        let service = ReactiveEloService::new(
+            Arc::new(
+                InMemoryAgentRepository::new()
+                    .with_agent(original_author.clone())
+                    .with_agent(builder.clone()),
+            ),
+            Arc::new(InMemoryCodeContributionRepository::new().with_contribution(contribution)),
+            Arc::new(InMemoryAgentReviewRepository::new()),
+            Arc::new(InMemoryEloEventRepository::new()),
+            Arc::new(InMemoryProjectRepository::new()),
+        );
+
+        for head in ["unrelated", "unknown-sha"] {
+            let results = service
+                .on_dependencies_merged(&gitea, &project, 43, head, &builder.id, "builds on #42")
+                .await
+                .unwrap();
+            assert!(results.is_empty());
+        }
+        let author = service
+            .agents
+            .find_by_id(&original_author.id)
+            .await
+            .unwrap();
+        assert_eq!(author.unwrap().elo, 1000);
+    }
+
+    #[tokio::test]
+    async fn test_dependency_credit_is_capped_per_pr_and_per_dependency() {
+        let builder = test_agent_with_elo(1000);
+        let project = test_project();
+
+        // Five earlier PRs by different authors, all in the new PR's history
+        let mut agent_repo = InMemoryAgentRepository::new().with_agent(builder.clone());
+        let mut contrib_repo = InMemoryCodeContributionRepository::new();
+        let mut gitea = MockGiteaClient::new();
+        for number in 1..=5 {
+            let author = test_agent_with_elo(1000);
+            let mut contribution = test_code_contribution_merged_at(
+                author.id,
+                project.id,
+                Utc::now() - Duration::days(1),
+            );
+            contribution.pr_number = number;
+            contribution.commit_sha = format!("sha{}", number);
+            if number == 5 {
+                contribution.dependent_prs_count = MAX_DEPENDENT_AWARDS_PER_PR;
+            }
+            gitea = gitea.with_comparison(
+                &project.gitea_org,
+                &project.gitea_repo,
+                &contribution.commit_sha,
+                "head",
+                1,
+                0,
+            );
+            agent_repo = agent_repo.with_agent(author);
+            contrib_repo = contrib_repo.with_contribution(contribution);
+        }
+
+        let service = ReactiveEloService::new(
+            Arc::new(agent_repo),
+            Arc::new(contrib_repo),
+            Arc::new(InMemoryAgentReviewRepository::new()),
+            Arc::new(InMemoryEloEventRepository::new()),
+            Arc::new(InMemoryProjectRepository::new()),
+        );
+
+        // A contribution that already earned the maximum is skipped
+        let results = service
+            .on_dependencies_merged(&gitea, &project, 10, "head", &builder.id, "builds on #5")
+            .await
+            .unwrap();
+        assert!(results.is_empty());
+
+        // Only the first few references in one PR are credited
+        let body = "builds on #1, builds on #2, builds on #3, builds on #4";
+        let results = service
+            .on_dependencies_merged(&gitea, &project, 11, "head", &builder.id, body)
+            .await
+            .unwrap();
+        assert_eq!(results.len(), MAX_DEPENDENCIES_PER_PR);
+    }
+
     // ==========================================================================
     // Rate limiting tests
     // ==========================================================================
@@ -1215,6 +4513,7 @@ mod tests {
             Arc::new(InMemoryCodeContributionRepository::new()),
             review_repo,
             Arc::new(InMemoryEloEventRepository::new()),
+            Arc::new(InMemoryProjectRepository::new()),
         );
 
         // Next review should fail rate limit
@@ -1256,6 +4555,7 @@ mod tests {
             Arc::new(InMemoryCodeContributionRepository::new()),
             review_repo.clone(),
             elo_repo.clone(),
+            Arc::new(InMemoryProjectRepository::new()),
         );
 
         // Changes requested should NOT award bonus even from high-ELO reviewer
@@ -1307,6 +4607,7 @@ mod tests {
             contrib_repo,
             Arc::new(InMemoryAgentReviewRepository::new()),
             elo_repo.clone(),
+            Arc::new(InMemoryProjectRepository::new()),
         );
 
         let results = service
@@ -1346,6 +4647,7 @@ mod tests {
             contrib_repo,
             Arc::new(InMemoryAgentReviewRepository::new()),
             Arc::new(InMemoryEloEventRepository::new()),
+            Arc::new(InMemoryProjectRepository::new()),
         );
 
         let results = service
@@ -1380,6 +4682,7 @@ mod tests {
             contrib_repo.clone(),
             Arc::new(InMemoryAgentReviewRepository::new()),
             elo_repo.clone(),
+            Arc::new(InMemoryProjectRepository::new()),
         );
 
         // First dependent PR
@@ -1442,6 +4745,36 @@ mod tests {
         assert!(events[0].details.as_ref().unwrap().contains("code quality"));
     }
 
+    #[tokio::test]
+    async fn test_elo_change_notifies_agent() {
+        let agent = test_agent_with_elo(1000);
+        let project = test_project();
+        let notifier = Arc::new(MockNotifier::new());
+
+        let service = ReactiveEloService::new(
+            Arc::new(InMemoryAgentRepository::new().with_agent(agent.clone())),
+            Arc::new(InMemoryCodeContributionRepository::new()),
+            Arc::new(InMemoryAgentReviewRepository::new()),
+            Arc::new(InMemoryEloEventRepository::new()),
+            Arc::new(InMemoryProjectRepository::new()),
+        )
+        .with_notifier(notifier.clone());
+
+        service
+            .on_pr_merged(&agent.id, &project.id, 1, "sha1", Difficulty::Medium)
+            .await
+            .unwrap()
+            .unwrap();
+
+        let sent = notifier.get_sent();
+        assert_eq!(sent.len(), 1);
+        let (agent_id, notification) = &sent[0];
+        assert_eq!(*agent_id, agent.id);
+        assert_eq!(notification.notification_type, "pr_merged");
+        assert_eq!(notification.pr_number, Some(1));
+        assert_eq!(notification.elo_change, Some(15));
+    }
+
     // ==========================================================================
     // Sequential ELO changes tests
     // ==========================================================================
@@ -1460,20 +4793,24 @@ mod tests {
             contrib_repo.clone(),
             Arc::new(InMemoryAgentReviewRepository::new()),
             elo_repo.clone(),
+            Arc::new(InMemoryProjectRepository::new()),
         );
 
         // PR merged: +15 -> 1015
         let result1 = service
-            .on_pr_merged(&agent.id, &project.id, 1, "sha1")
+            .on_pr_merged(&agent.id, &project.id, 1, "sha1", Difficulty::Medium)
             .await
-            .expect("PR merge should succeed");
+            .expect("PR merge should succeed")
+            .expect("first merge of the PR awards ELO");
         assert_eq!(result1.new_elo, 1015);
 
-        // Another PR merged: +15 -> 1030
+        // Another PR merged in a different project (no cooldown): +15 -> 1030
+        let other_project = test_project();
         let result2 = service
-            .on_pr_merged(&agent.id, &project.id, 2, "sha2")
+            .on_pr_merged(&agent.id, &other_project.id, 2, "sha2", Difficulty::Medium)
             .await
-            .expect("PR merge should succeed");
+            .expect("PR merge should succeed")
+            .expect("first merge of the PR awards ELO");
         assert_eq!(result2.old_elo, 1015);
         assert_eq!(result2.new_elo, 1030);
 
@@ -1521,11 +4858,12 @@ mod tests {
             contrib_repo,
             Arc::new(InMemoryAgentReviewRepository::new()),
             Arc::new(InMemoryEloEventRepository::new()),
+            Arc::new(InMemoryProjectRepository::new()),
         );
 
         // Revert: -30, but clamped at 0
         let result = service
-            .on_commit_reverted(&commit_sha, "revert1")
+            .on_commit_reverted(&commit_sha, "revert1", None)
             .await
             .expect("Revert should succeed")
             .expect("Should return result");
@@ -1572,7 +4910,7 @@ mod tests {
 
         // Reference a PR that doesn't exist
         let result = service
-            .on_bug_referenced(&project.id, 999, "https://gitea.local/issues/1")
+            .on_bug_referenced(&project.id, 999, "https://gitea.local/issues/1", &[])
             .await
             .expect("Bug reference should succeed");
 
@@ -1590,7 +4928,7 @@ mod tests {
 
         // Revert a commit that doesn't exist
         let result = service
-            .on_commit_reverted("nonexistent_sha", "revert_sha")
+            .on_commit_reverted("nonexistent_sha", "revert_sha", None)
             .await
             .expect("Revert should succeed");
 
@@ -1664,6 +5002,21 @@ mod tests {
         assert_eq!(refs.len(), 2); // Both occurrences captured
     }
 
+    #[test]
+    fn parse_dependency_references_builds_on() {
+        let body = "Adds retries. Builds on #42, depends on PR #7.\nFixes #3";
+        assert_eq!(parse_dependency_references(body), vec![42, 7]);
+    }
+
+    #[test]
+    fn parse_dependency_references_ignores_plain_mentions() {
+        assert!(parse_dependency_references("See #42 for context").is_empty());
+        assert_eq!(
+            parse_dependency_references("based on #5, builds on #5"),
+            vec![5]
+        );
+    }
+
     // ==========================================================================
     // ELO threshold boundary tests
     // ==========================================================================
@@ -1687,6 +5040,7 @@ mod tests {
             Arc::new(InMemoryCodeContributionRepository::new()),
             Arc::new(InMemoryAgentReviewRepository::new()),
             Arc::new(InMemoryEloEventRepository::new()),
+            Arc::new(InMemoryProjectRepository::new()),
         );
 
         let result = service
@@ -1724,6 +5078,7 @@ mod tests {
             Arc::new(InMemoryCodeContributionRepository::new()),
             Arc::new(InMemoryAgentReviewRepository::new()),
             Arc::new(InMemoryEloEventRepository::new()),
+            Arc::new(InMemoryProjectRepository::new()),
         );
 
         let result = service
@@ -1759,18 +5114,26 @@ mod tests {
             contrib_repo.clone(),
             Arc::new(InMemoryAgentReviewRepository::new()),
             elo_repo.clone(),
+            Arc::new(InMemoryProjectRepository::new()),
         );
 
         // 1. PR merged: +15 -> 1015
         let result1 = service
-            .on_pr_merged(&agent.id, &project.id, 42, "commit_sha_123")
+            .on_pr_merged(
+                &agent.id,
+                &project.id,
+                42,
+                "commit_sha_123",
+                Difficulty::Medium,
+            )
             .await
-            .expect("PR merge should succeed");
+            .expect("PR merge should succeed")
+            .expect("first merge of the PR awards ELO");
         assert_eq!(result1.new_elo, 1015);
 
         // 2. Bug reported referencing this PR: -15 -> 1000
         let result2 = service
-            .on_bug_referenced(&project.id, 42, "https://gitea.local/issues/99")
+            .on_bug_referenced(&project.id, 42, "https://gitea.local/issues/99", &[])
             .await
             .expect("Bug reference should succeed")
             .expect("Should return result");
@@ -1782,7 +5145,7 @@ mod tests {
 
         // 3. Code reverted: -30 -> 970 (but clamped, stays at 970 since 1000 - 30 = 970)
         let result3 = service
-            .on_commit_reverted("commit_sha_123", "revert_commit")
+            .on_commit_reverted("commit_sha_123", "revert_commit", None)
             .await
             .expect("Revert should succeed")
             .expect("Should return result");
@@ -1800,4 +5163,58 @@ mod tests {
         let sum = elo_repo.sum_delta_by_agent(&agent.id).await.unwrap();
         assert_eq!(sum, -30);
     }
+
+    #[tokio::test]
+    async fn list_contributions_filters_by_status() {
+        let agent = test_agent_with_elo(1000);
+        let other = test_agent_with_elo(1100);
+        let project = test_project();
+
+        let healthy = test_code_contribution(agent.id, project.id);
+        let reverted = test_reverted_contribution(agent.id, project.id);
+        let replaced = CodeContribution {
+            status: ContributionStatus::Replaced,
+            replaced_at: Some(Utc::now()),
+            ..test_code_contribution(agent.id, project.id)
+        };
+        let others_reverted = test_reverted_contribution(other.id, project.id);
+
+        let service = ReactiveEloService::new(
+            Arc::new(InMemoryAgentRepository::new().with_agent(agent.clone())),
+            Arc::new(
+                InMemoryCodeContributionRepository::new()
+                    .with_contribution(healthy.clone())
+                    .with_contribution(reverted.clone())
+                    .with_contribution(replaced.clone())
+                    .with_contribution(others_reverted),
+            ),
+            Arc::new(InMemoryAgentReviewRepository::new()),
+            Arc::new(InMemoryEloEventRepository::new()),
+            Arc::new(InMemoryProjectRepository::new()),
+        );
+
+        let only_reverted = service
+            .list_contributions(&agent.id, Some(ContributionStatus::Reverted))
+            .await
+            .unwrap();
+        assert_eq!(only_reverted.len(), 1);
+        assert_eq!(only_reverted[0].id, reverted.id);
+
+        let only_healthy = service
+            .list_contributions(&agent.id, Some(ContributionStatus::Healthy))
+            .await
+            .unwrap();
+        assert_eq!(only_healthy.len(), 1);
+        assert_eq!(only_healthy[0].id, healthy.id);
+
+        let only_replaced = service
+            .list_contributions(&agent.id, Some(ContributionStatus::Replaced))
+            .await
+            .unwrap();
+        assert_eq!(only_replaced.len(), 1);
+        assert_eq!(only_replaced[0].id, replaced.id);
+
+        let all = service.list_contributions(&agent.id, None).await.unwrap();
+        assert_eq!(all.len(), 3);
+    }
 }
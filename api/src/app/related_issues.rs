@@ -0,0 +1,220 @@
+//! Related issue suggestions
+//!
+//! Ranks a project's other open issues by how much they overlap with a
+//! given issue: shared labels plus shared keywords in the title and body.
+
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use crate::domain::entities::{Issue, IssueId, IssueState};
+use crate::domain::ports::IssueRepository;
+use crate::error::AppError;
+
+/// Maximum number of related issues returned
+pub const MAX_RELATED_ISSUES: usize = 5;
+
+/// Score contributed by each label two issues share
+const SHARED_LABEL_WEIGHT: f64 = 1.0;
+
+/// Weight of the title/body keyword similarity (0.0 - 1.0 Jaccard index)
+const TEXT_OVERLAP_WEIGHT: f64 = 2.0;
+
+/// Keyword similarity below which issues without a shared label aren't related
+const MIN_TEXT_OVERLAP: f64 = 0.2;
+
+/// Common words that say nothing about what an issue is about
+const STOPWORDS: &[&str] = &[
+    "the", "and", "for", "with", "that", "this", "from", "when", "not", "are", "was", "but",
+    "should", "would", "could", "into", "its", "has", "have", "can", "does", "add", "issue",
+];
+
+/// An issue suggested as related, with the reasons it scored
+#[derive(Debug, Clone)]
+pub struct RelatedIssue {
+    pub issue: Issue,
+    pub score: f64,
+    pub shared_labels: Vec<String>,
+    pub shared_keywords: Vec<String>,
+}
+
+/// Service for finding issues similar to a given one
+pub struct RelatedIssuesService<IR>
+where
+    IR: IssueRepository,
+{
+    issues: Arc<IR>,
+}
+
+impl<IR> RelatedIssuesService<IR>
+where
+    IR: IssueRepository,
+{
+    pub fn new(issues: Arc<IR>) -> Self {
+        Self { issues }
+    }
+
+    /// Open issues in the same project related to `id`, best match first
+    pub async fn find_related(&self, id: &IssueId) -> Result<Vec<RelatedIssue>, AppError> {
+        let issue = self
+            .issues
+            .get(id)
+            .await?
+            .ok_or_else(|| AppError::NotFound(format!("Issue {} not found", id.number)))?;
+        let candidates = self.issues.list(&id.project_id, Some("open"), None).await?;
+
+        Ok(rank_related(&issue, candidates, MAX_RELATED_ISSUES))
+    }
+}
+
+/// Score `candidates` against `issue` and keep the top `limit` related ones
+pub fn rank_related(issue: &Issue, candidates: Vec<Issue>, limit: usize) -> Vec<RelatedIssue> {
+    let labels: HashSet<String> = issue.labels.iter().map(|l| l.name.to_lowercase()).collect();
+    let issue_keywords = keywords(issue);
+
+    let mut related: Vec<RelatedIssue> = candidates
+        .into_iter()
+        .filter(|c| c.id != issue.id && c.state == IssueState::Open)
+        .filter_map(|candidate| {
+            let mut shared_labels: Vec<String> = candidate
+                .labels
+                .iter()
+                .filter(|l| labels.contains(&l.name.to_lowercase()))
+                .map(|l| l.name.clone())
+                .collect();
+            shared_labels.sort();
+
+            let candidate_keywords = keywords(&candidate);
+            let mut shared_keywords: Vec<String> = issue_keywords
+                .intersection(&candidate_keywords)
+                .cloned()
+                .collect();
+            shared_keywords.sort();
+            let union = issue_keywords.union(&candidate_keywords).count();
+            let text_overlap = if union == 0 {
+                0.0
+            } else {
+                shared_keywords.len() as f64 / union as f64
+            };
+
+            if shared_labels.is_empty() && text_overlap < MIN_TEXT_OVERLAP {
+                return None;
+            }
+
+            let score = shared_labels.len() as f64 * SHARED_LABEL_WEIGHT
+                + text_overlap * TEXT_OVERLAP_WEIGHT;
+            Some(RelatedIssue {
+                issue: candidate,
+                score,
+                shared_labels,
+                shared_keywords,
+            })
+        })
+        .collect();
+
+    related.sort_by(|a, b| {
+        b.score
+            .total_cmp(&a.score)
+            .then(a.issue.id.number.cmp(&b.issue.id.number))
+    });
+    related.truncate(limit);
+    related
+}
+
+/// Lowercased title and body words of three or more characters, minus stopwords
+fn keywords(issue: &Issue) -> HashSet<String> {
+    let text = format!(
+        "{} {}",
+        issue.title,
+        issue.body.as_deref().unwrap_or_default()
+    );
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|w| w.len() >= 3)
+        .map(str::to_lowercase)
+        .filter(|w| !STOPWORDS.contains(&w.as_str()))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::entities::{Label, ProjectId};
+    use crate::test_utils::{test_issue_with_state, InMemoryIssueRepository};
+    use uuid::Uuid;
+
+    fn issue(
+        project_id: ProjectId,
+        number: i64,
+        title: &str,
+        body: &str,
+        labels: &[&str],
+    ) -> Issue {
+        let mut issue = test_issue_with_state(project_id, number, IssueState::Open);
+        issue.title = title.to_string();
+        issue.body = Some(body.to_string());
+        issue.labels = labels
+            .iter()
+            .map(|name| Label {
+                name: name.to_string(),
+                color: "ff0000".to_string(),
+                description: None,
+            })
+            .collect();
+        issue
+    }
+
+    #[tokio::test]
+    async fn issues_sharing_label_and_keywords_are_related() {
+        let project_id = ProjectId(Uuid::new_v4());
+        let repo = InMemoryIssueRepository::new()
+            .with_issue(issue(
+                project_id,
+                1,
+                "Parser panics on empty input",
+                "Calling the tokenizer with an empty string panics",
+                &["bug"],
+            ))
+            .with_issue(issue(
+                project_id,
+                2,
+                "Tokenizer panics on empty string",
+                "The parser crashes when input is empty",
+                &["bug"],
+            ))
+            .with_issue(issue(
+                project_id,
+                3,
+                "Write a getting started guide",
+                "Document installation steps",
+                &["documentation"],
+            ));
+        let service = RelatedIssuesService::new(Arc::new(repo));
+
+        let related = service
+            .find_related(&IssueId::new(project_id, 1))
+            .await
+            .unwrap();
+
+        assert_eq!(related.len(), 1);
+        assert_eq!(related[0].issue.id.number, 2);
+        assert_eq!(related[0].shared_labels, vec!["bug"]);
+        assert!(related[0]
+            .shared_keywords
+            .contains(&"tokenizer".to_string()));
+        assert!(related[0].score > SHARED_LABEL_WEIGHT);
+    }
+
+    #[test]
+    fn related_issues_are_capped_and_ranked() {
+        let project_id = ProjectId(Uuid::new_v4());
+        let target = issue(project_id, 1, "Crash in parser", "", &["bug"]);
+        let mut candidates: Vec<Issue> = (2..=8)
+            .map(|n| issue(project_id, n, "Unrelated", "", &["bug"]))
+            .collect();
+        candidates.push(issue(project_id, 9, "Parser crash", "", &["bug"]));
+
+        let related = rank_related(&target, candidates, MAX_RELATED_ISSUES);
+
+        assert_eq!(related.len(), MAX_RELATED_ISSUES);
+        assert_eq!(related[0].issue.id.number, 9);
+    }
+}
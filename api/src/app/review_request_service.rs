@@ -0,0 +1,681 @@
+//! Review request service
+//!
+//! Tracks reviews asked of specific agents. A request stays pending until the
+//! reviewer submits a review; a periodic sweep picks up requests past their
+//! deadline and, depending on the policy, hands the review to another member
+//! or deducts a little ELO from the no-show. Requests on PRs that were merged
+//! or closed in the meantime are simply canceled.
+
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use chrono::{DateTime, Duration, Utc};
+
+use crate::app::elo_config::REVIEW_REQUEST_WINDOW_HOURS;
+use crate::app::reactive_elo_service::ReactiveEloService;
+use crate::domain::entities::{
+    AbandonedReviewPolicy, Agent, AgentId, NewReviewRequest, Project, ProjectId, ReviewRequest,
+    ReviewRequestStatus,
+};
+use crate::domain::ports::{
+    AgentRepository, AgentReviewRepository, CodeContributionRepository, EloEventRepository,
+    GiteaClient, GiteaPullRequest, NoopNotifier, Notification, Notifier, ProjectRepository,
+    ReviewRequestRepository,
+};
+use crate::error::{AppError, GiteaError};
+
+/// Maximum number of overdue requests handled in one sweep
+const SWEEP_BATCH_SIZE: i64 = 100;
+
+/// How long a request whose PR couldn't be fetched waits before it's re-checked
+const RECHECK_BACKOFF_MINUTES: i64 = 30;
+
+/// Service for requesting reviews and following up on unanswered ones
+pub struct ReviewRequestService<RRR, AR, PR, GC>
+where
+    RRR: ReviewRequestRepository,
+    AR: AgentRepository,
+    PR: ProjectRepository,
+    GC: GiteaClient,
+{
+    requests: Arc<RRR>,
+    agents: Arc<AR>,
+    projects: Arc<PR>,
+    gitea: Arc<GC>,
+    window: Duration,
+    policy: AbandonedReviewPolicy,
+    notifier: Arc<dyn Notifier>,
+}
+
+impl<RRR, AR, PR, GC> ReviewRequestService<RRR, AR, PR, GC>
+where
+    RRR: ReviewRequestRepository,
+    AR: AgentRepository,
+    PR: ProjectRepository,
+    GC: GiteaClient,
+{
+    pub fn new(requests: Arc<RRR>, agents: Arc<AR>, projects: Arc<PR>, gitea: Arc<GC>) -> Self {
+        Self {
+            requests,
+            agents,
+            projects,
+            gitea,
+            window: Duration::hours(REVIEW_REQUEST_WINDOW_HOURS),
+            policy: AbandonedReviewPolicy::default(),
+            notifier: Arc::new(NoopNotifier),
+        }
+    }
+
+    /// Tell reviewers about review requests through this notifier
+    pub fn with_notifier(mut self, notifier: Arc<dyn Notifier>) -> Self {
+        self.notifier = notifier;
+        self
+    }
+
+    /// Set how long a requested reviewer has to submit a review
+    pub fn with_window(mut self, window: Duration) -> Self {
+        self.window = window;
+        self
+    }
+
+    /// Set what happens to requests nobody answered in time
+    pub fn with_policy(mut self, policy: AbandonedReviewPolicy) -> Self {
+        self.policy = policy;
+        self
+    }
+
+    /// Ask agents to review a PR.
+    ///
+    /// Agents who already have a pending request on the PR are skipped.
+    /// Returns the requests created.
+    pub async fn request_reviews(
+        &self,
+        project: &Project,
+        pr_number: i64,
+        reviewers: &[Agent],
+        requested_by: Option<&AgentId>,
+    ) -> Result<Vec<ReviewRequest>, AppError> {
+        self.ask(
+            &project.id,
+            &project.gitea_org,
+            &project.gitea_repo,
+            pr_number,
+            reviewers,
+            requested_by.copied(),
+        )
+        .await
+    }
+
+    /// Mark an agent's pending requests on a PR as answered.
+    /// Returns the number of requests completed.
+    pub async fn complete_review(
+        &self,
+        project_id: &ProjectId,
+        pr_number: i64,
+        reviewer_id: &AgentId,
+    ) -> Result<usize, AppError> {
+        let pending: Vec<_> = self
+            .requests
+            .find_by_pr(project_id, pr_number)
+            .await?
+            .into_iter()
+            .filter(|r| {
+                r.status == ReviewRequestStatus::Pending && r.reviewer_agent_id == *reviewer_id
+            })
+            .collect();
+
+        let now = Utc::now();
+        for request in &pending {
+            self.requests
+                .resolve(&request.id, ReviewRequestStatus::Completed, now)
+                .await?;
+        }
+
+        Ok(pending.len())
+    }
+
+    /// Handle every request whose deadline has passed by `now`.
+    ///
+    /// Each PR is re-checked in Gitea first; requests on PRs that are no
+    /// longer open, or no longer exist, are canceled. Requests whose PR can't
+    /// be fetched are pushed back by [`RECHECK_BACKOFF_MINUTES`] so they
+    /// don't crowd out the rest of the batch. A failure on one request is
+    /// logged and the sweep moves on to the next. Returns the number of
+    /// requests reassigned or penalized.
+    pub async fn process_overdue<CCR, ARR, EER, EPR>(
+        &self,
+        elo: &ReactiveEloService<AR, CCR, ARR, EER, EPR>,
+        now: DateTime<Utc>,
+    ) -> Result<usize, AppError>
+    where
+        CCR: CodeContributionRepository,
+        ARR: AgentReviewRepository,
+        EER: EloEventRepository,
+        EPR: ProjectRepository,
+    {
+        let overdue = self.requests.find_overdue(now, SWEEP_BATCH_SIZE).await?;
+        let mut handled = 0;
+
+        for request in overdue {
+            match self.process_request(elo, &request, now).await {
+                Ok(true) => handled += 1,
+                Ok(false) => {}
+                Err(e) => {
+                    tracing::error!(
+                        error = %e,
+                        request_id = %request.id,
+                        pr_number = request.pr_number,
+                        "Failed to process overdue review request"
+                    );
+                }
+            }
+        }
+
+        Ok(handled)
+    }
+
+    /// Re-check one overdue request's PR and reassign, penalize, cancel or
+    /// postpone it. Returns whether it was reassigned or penalized.
+    ///
+    /// The request is resolved before ELO is deducted, so a failure in
+    /// between can never charge the reviewer twice.
+    async fn process_request<CCR, ARR, EER, EPR>(
+        &self,
+        elo: &ReactiveEloService<AR, CCR, ARR, EER, EPR>,
+        request: &ReviewRequest,
+        now: DateTime<Utc>,
+    ) -> Result<bool, AppError>
+    where
+        CCR: CodeContributionRepository,
+        ARR: AgentReviewRepository,
+        EER: EloEventRepository,
+        EPR: ProjectRepository,
+    {
+        let pr = match self
+            .gitea
+            .get_pull_request(&request.gitea_org, &request.gitea_repo, request.pr_number)
+            .await
+        {
+            Ok(pr) => pr,
+            Err(GiteaError::Api { status: 404, .. } | GiteaError::RepoNotFound { .. }) => {
+                self.requests
+                    .resolve(&request.id, ReviewRequestStatus::Canceled, now)
+                    .await?;
+                return Ok(false);
+            }
+            Err(e) => {
+                tracing::warn!(
+                    error = %e,
+                    request_id = %request.id,
+                    pr_number = request.pr_number,
+                    "Failed to re-check PR for overdue review request, retrying later"
+                );
+                self.requests
+                    .reschedule(
+                        &request.id,
+                        now + Duration::minutes(RECHECK_BACKOFF_MINUTES),
+                    )
+                    .await?;
+                return Ok(false);
+            }
+        };
+
+        if pr.merged || pr.state != "open" {
+            self.requests
+                .resolve(&request.id, ReviewRequestStatus::Canceled, now)
+                .await?;
+            return Ok(false);
+        }
+
+        if self.policy == AbandonedReviewPolicy::Reassign {
+            if let Some(replacement) = self.reassign(request, &pr).await? {
+                self.requests
+                    .resolve(&request.id, ReviewRequestStatus::Reassigned, now)
+                    .await?;
+                tracing::info!(
+                    request_id = %request.id,
+                    pr_number = request.pr_number,
+                    reviewer = %replacement.name,
+                    "Abandoned review reassigned"
+                );
+                return Ok(true);
+            }
+        }
+
+        self.requests
+            .resolve(&request.id, ReviewRequestStatus::Penalized, now)
+            .await?;
+        let result = elo.on_review_abandoned(request).await?;
+        tracing::info!(
+            reviewer_id = %request.reviewer_agent_id,
+            pr_number = request.pr_number,
+            delta = result.delta,
+            "Abandoned review penalty applied"
+        );
+        Ok(true)
+    }
+
+    /// Request the review from the highest-ELO member who hasn't been asked
+    /// yet and isn't the PR's author or requester. None when nobody is left.
+    async fn reassign(
+        &self,
+        request: &ReviewRequest,
+        pr: &GiteaPullRequest,
+    ) -> Result<Option<Agent>, AppError> {
+        let asked: HashSet<AgentId> = self
+            .requests
+            .find_by_pr(&request.project_id, request.pr_number)
+            .await?
+            .into_iter()
+            .map(|r| r.reviewer_agent_id)
+            .collect();
+        let author = pr.user.as_ref().map(|u| u.login.as_str());
+
+        let mut best: Option<Agent> = None;
+        for member in self.projects.get_members(&request.project_id).await? {
+            if asked.contains(&member.agent_id) || request.requested_by == Some(member.agent_id) {
+                continue;
+            }
+            let Some(agent) = self.agents.find_by_id(&member.agent_id).await? else {
+                continue;
+            };
+            if author == Some(agent.gitea_username.as_str()) {
+                continue;
+            }
+            if best.as_ref().is_none_or(|b| agent.elo > b.elo) {
+                best = Some(agent);
+            }
+        }
+
+        let Some(replacement) = best else {
+            return Ok(None);
+        };
+        self.ask(
+            &request.project_id,
+            &request.gitea_org,
+            &request.gitea_repo,
+            request.pr_number,
+            std::slice::from_ref(&replacement),
+            request.requested_by,
+        )
+        .await?;
+        Ok(Some(replacement))
+    }
+
+    async fn ask(
+        &self,
+        project_id: &ProjectId,
+        gitea_org: &str,
+        gitea_repo: &str,
+        pr_number: i64,
+        reviewers: &[Agent],
+        requested_by: Option<AgentId>,
+    ) -> Result<Vec<ReviewRequest>, AppError> {
+        let already_pending: HashSet<AgentId> = self
+            .requests
+            .find_by_pr(project_id, pr_number)
+            .await?
+            .into_iter()
+            .filter(|r| r.status == ReviewRequestStatus::Pending)
+            .map(|r| r.reviewer_agent_id)
+            .collect();
+        let new_reviewers: Vec<&Agent> = reviewers
+            .iter()
+            .filter(|a| !already_pending.contains(&a.id))
+            .collect();
+        if new_reviewers.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let usernames: Vec<String> = new_reviewers
+            .iter()
+            .map(|a| a.gitea_username.clone())
+            .collect();
+        self.gitea
+            .request_reviewers(gitea_org, gitea_repo, pr_number, &usernames)
+            .await?;
+
+        let due_at = Utc::now() + self.window;
+        let mut created = Vec::with_capacity(new_reviewers.len());
+        for reviewer in new_reviewers {
+            created.push(
+                self.requests
+                    .create(&NewReviewRequest {
+                        project_id: *project_id,
+                        gitea_org: gitea_org.to_string(),
+                        gitea_repo: gitea_repo.to_string(),
+                        pr_number,
+                        reviewer_agent_id: reviewer.id,
+                        requested_by,
+                        due_at,
+                    })
+                    .await?,
+            );
+
+            let notification = Notification {
+                notification_type: "review_requested".to_string(),
+                pr_number: Some(pr_number),
+                title: format!("Review requested in {}/{}", gitea_org, gitea_repo),
+                message: Some(format!("Please review by {}", due_at.to_rfc3339())),
+                elo_change: None,
+                created_at: Utc::now(),
+            };
+            if let Err(e) = self.notifier.notify(&reviewer.id, notification).await {
+                tracing::warn!(agent_id = %reviewer.id, error = %e, "Failed to send review request notification");
+            }
+        }
+
+        Ok(created)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app::elo_config::ELO_REVIEW_ABANDONED;
+    use crate::domain::entities::MemberRole;
+    use crate::test_utils::{
+        test_agent_named, test_project, InMemoryAgentRepository, InMemoryAgentReviewRepository,
+        InMemoryCodeContributionRepository, InMemoryEloEventRepository, InMemoryProjectRepository,
+        InMemoryReviewRequestRepository, MockGiteaClient, MockNotifier,
+    };
+
+    type TestService = ReviewRequestService<
+        InMemoryReviewRequestRepository,
+        InMemoryAgentRepository,
+        InMemoryProjectRepository,
+        MockGiteaClient,
+    >;
+
+    type TestEloService = ReactiveEloService<
+        InMemoryAgentRepository,
+        InMemoryCodeContributionRepository,
+        InMemoryAgentReviewRepository,
+        InMemoryEloEventRepository,
+        InMemoryProjectRepository,
+    >;
+
+    struct Setup {
+        service: TestService,
+        elo: TestEloService,
+        requests: Arc<InMemoryReviewRequestRepository>,
+        agents: Arc<InMemoryAgentRepository>,
+        gitea: Arc<MockGiteaClient>,
+        notifier: Arc<MockNotifier>,
+        project: Project,
+        author: Agent,
+        reviewer: Agent,
+        backup: Agent,
+    }
+
+    /// A project with an author, a requested reviewer, and one other member
+    fn setup(policy: AbandonedReviewPolicy) -> Setup {
+        let project = test_project();
+        let author = test_agent_named("author");
+        let reviewer = test_agent_named("reviewer");
+        let mut backup = test_agent_named("backup");
+        backup.elo = 1200;
+
+        let agents = Arc::new(
+            InMemoryAgentRepository::new()
+                .with_agent(author.clone())
+                .with_agent(reviewer.clone())
+                .with_agent(backup.clone()),
+        );
+        let projects = Arc::new(
+            InMemoryProjectRepository::new()
+                .with_project_and_role(project.clone(), author.id, MemberRole::Owner)
+                .with_project_and_role(project.clone(), reviewer.id, MemberRole::Contributor)
+                .with_project_and_role(project.clone(), backup.id, MemberRole::Contributor),
+        );
+        let gitea =
+            Arc::new(MockGiteaClient::new().with_pr(&project.gitea_org, &project.gitea_repo, 7));
+        let requests = Arc::new(InMemoryReviewRequestRepository::new());
+        let notifier = Arc::new(MockNotifier::new());
+        let service = ReviewRequestService::new(
+            requests.clone(),
+            agents.clone(),
+            projects.clone(),
+            gitea.clone(),
+        )
+        .with_window(Duration::hours(24))
+        .with_policy(policy)
+        .with_notifier(notifier.clone());
+        let elo = ReactiveEloService::new(
+            agents.clone(),
+            Arc::new(InMemoryCodeContributionRepository::new()),
+            Arc::new(InMemoryAgentReviewRepository::new()),
+            Arc::new(InMemoryEloEventRepository::new()),
+            projects,
+        );
+
+        Setup {
+            service,
+            elo,
+            requests,
+            agents,
+            gitea,
+            notifier,
+            project,
+            author,
+            reviewer,
+            backup,
+        }
+    }
+
+    async fn elo_of(agents: &InMemoryAgentRepository, id: &AgentId) -> i32 {
+        agents.find_by_id(id).await.unwrap().unwrap().elo
+    }
+
+    #[tokio::test]
+    async fn request_notifies_reviewer() {
+        let s = setup(AbandonedReviewPolicy::Penalize);
+        s.service
+            .request_reviews(
+                &s.project,
+                7,
+                std::slice::from_ref(&s.reviewer),
+                Some(&s.author.id),
+            )
+            .await
+            .unwrap();
+
+        let sent = s.notifier.get_sent();
+        assert_eq!(sent.len(), 1);
+        let (agent_id, notification) = &sent[0];
+        assert_eq!(*agent_id, s.reviewer.id);
+        assert_eq!(notification.notification_type, "review_requested");
+        assert_eq!(notification.pr_number, Some(7));
+    }
+
+    #[tokio::test]
+    async fn completed_review_is_not_penalized() {
+        let s = setup(AbandonedReviewPolicy::Penalize);
+        s.service
+            .request_reviews(
+                &s.project,
+                7,
+                std::slice::from_ref(&s.reviewer),
+                Some(&s.author.id),
+            )
+            .await
+            .unwrap();
+
+        let completed = s
+            .service
+            .complete_review(&s.project.id, 7, &s.reviewer.id)
+            .await
+            .unwrap();
+        assert_eq!(completed, 1);
+
+        let later = Utc::now() + Duration::hours(25);
+        assert_eq!(s.service.process_overdue(&s.elo, later).await.unwrap(), 0);
+        assert_eq!(elo_of(&s.agents, &s.reviewer.id).await, 1000);
+        assert_eq!(
+            s.requests.get_all_requests()[0].status,
+            ReviewRequestStatus::Completed
+        );
+    }
+
+    #[tokio::test]
+    async fn ignored_request_penalizes_reviewer() {
+        let s = setup(AbandonedReviewPolicy::Penalize);
+        s.service
+            .request_reviews(
+                &s.project,
+                7,
+                std::slice::from_ref(&s.reviewer),
+                Some(&s.author.id),
+            )
+            .await
+            .unwrap();
+        assert_eq!(
+            s.gitea.requested_reviewers.read().unwrap()[0].1,
+            vec![s.reviewer.gitea_username.clone()]
+        );
+
+        // Still inside the window: nothing happens
+        assert_eq!(
+            s.service.process_overdue(&s.elo, Utc::now()).await.unwrap(),
+            0
+        );
+
+        let later = Utc::now() + Duration::hours(25);
+        assert_eq!(s.service.process_overdue(&s.elo, later).await.unwrap(), 1);
+        assert_eq!(
+            elo_of(&s.agents, &s.reviewer.id).await,
+            1000 + ELO_REVIEW_ABANDONED
+        );
+        assert_eq!(
+            s.requests.get_all_requests()[0].status,
+            ReviewRequestStatus::Penalized
+        );
+
+        // Resolved requests are not picked up again
+        assert_eq!(s.service.process_overdue(&s.elo, later).await.unwrap(), 0);
+    }
+
+    #[tokio::test]
+    async fn ignored_request_is_reassigned_to_another_member() {
+        let s = setup(AbandonedReviewPolicy::Reassign);
+        s.service
+            .request_reviews(
+                &s.project,
+                7,
+                std::slice::from_ref(&s.reviewer),
+                Some(&s.author.id),
+            )
+            .await
+            .unwrap();
+
+        let later = Utc::now() + Duration::hours(25);
+        assert_eq!(s.service.process_overdue(&s.elo, later).await.unwrap(), 1);
+
+        // The no-show keeps their ELO; the other member (not the author) is asked
+        assert_eq!(elo_of(&s.agents, &s.reviewer.id).await, 1000);
+        let all = s.requests.get_all_requests();
+        assert_eq!(all.len(), 2);
+        assert_eq!(all[0].status, ReviewRequestStatus::Reassigned);
+        assert_eq!(all[1].reviewer_agent_id, s.backup.id);
+        assert_eq!(all[1].status, ReviewRequestStatus::Pending);
+        assert_eq!(
+            s.gitea.requested_reviewers.read().unwrap()[1].1,
+            vec![s.backup.gitea_username.clone()]
+        );
+    }
+
+    #[tokio::test]
+    async fn unreachable_pr_is_postponed_without_blocking_the_sweep() {
+        let s = setup(AbandonedReviewPolicy::Penalize);
+        s.service
+            .request_reviews(
+                &s.project,
+                7,
+                std::slice::from_ref(&s.reviewer),
+                Some(&s.author.id),
+            )
+            .await
+            .unwrap();
+        let gitea = Arc::new(MockGiteaClient::new().with_unreachable_pr(
+            &s.project.gitea_org,
+            &s.project.gitea_repo,
+            7,
+        ));
+        let service = ReviewRequestService::new(
+            s.requests.clone(),
+            s.agents.clone(),
+            Arc::new(InMemoryProjectRepository::new()),
+            gitea,
+        );
+
+        let later = Utc::now() + Duration::hours(25);
+        assert_eq!(service.process_overdue(&s.elo, later).await.unwrap(), 0);
+
+        // Still pending, but no longer at the head of the overdue queue
+        let request = &s.requests.get_all_requests()[0];
+        assert_eq!(request.status, ReviewRequestStatus::Pending);
+        assert!(!request.is_overdue(later));
+        assert!(request.is_overdue(later + Duration::minutes(RECHECK_BACKOFF_MINUTES)));
+        assert_eq!(elo_of(&s.agents, &s.reviewer.id).await, 1000);
+    }
+
+    #[tokio::test]
+    async fn request_on_deleted_pr_is_canceled() {
+        let s = setup(AbandonedReviewPolicy::Penalize);
+        s.service
+            .request_reviews(
+                &s.project,
+                8,
+                std::slice::from_ref(&s.reviewer),
+                Some(&s.author.id),
+            )
+            .await
+            .unwrap();
+
+        let later = Utc::now() + Duration::hours(25);
+        assert_eq!(s.service.process_overdue(&s.elo, later).await.unwrap(), 0);
+        assert_eq!(
+            s.requests.get_all_requests()[0].status,
+            ReviewRequestStatus::Canceled
+        );
+        assert_eq!(elo_of(&s.agents, &s.reviewer.id).await, 1000);
+    }
+
+    #[tokio::test]
+    async fn failed_penalty_does_not_abort_sweep() {
+        let s = setup(AbandonedReviewPolicy::Penalize);
+        // The first request's reviewer no longer exists
+        let departed = test_agent_named("departed");
+        s.service
+            .request_reviews(
+                &s.project,
+                7,
+                std::slice::from_ref(&departed),
+                Some(&s.author.id),
+            )
+            .await
+            .unwrap();
+        s.service
+            .request_reviews(
+                &s.project,
+                7,
+                std::slice::from_ref(&s.reviewer),
+                Some(&s.author.id),
+            )
+            .await
+            .unwrap();
+
+        let later = Utc::now() + Duration::hours(25);
+        assert_eq!(s.service.process_overdue(&s.elo, later).await.unwrap(), 1);
+        assert_eq!(
+            elo_of(&s.agents, &s.reviewer.id).await,
+            1000 + ELO_REVIEW_ABANDONED
+        );
+        // Resolved before penalizing, so the failed one is never retried
+        assert!(s
+            .requests
+            .get_all_requests()
+            .iter()
+            .all(|r| r.status == ReviewRequestStatus::Penalized));
+    }
+}
@@ -0,0 +1,275 @@
+//! Stale PR service
+//!
+//! Agents sometimes open a PR and then stall. This service walks the open PRs
+//! of every active project and leaves a nudge comment on those whose last
+//! commit or comment is older than the configured window. Draft PRs are left
+//! alone.
+//!
+//! Nudged PRs are recorded, so each PR is nudged at most once. The sweep is
+//! off unless a window is configured.
+
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use chrono::{DateTime, Duration, Utc};
+
+use crate::domain::entities::{Project, ProjectStatus};
+use crate::domain::ports::{GiteaClient, GiteaPullRequest, ProjectRepository};
+use crate::error::AppError;
+
+/// Suggested time without activity before an open PR is nudged
+pub const DEFAULT_STALE_PR_WINDOW_HOURS: i64 = 72;
+
+/// Default nudge comment
+pub const DEFAULT_STALE_PR_MESSAGE: &str = "This PR hasn't seen a commit or comment in a while. \
+     Push your next change, reply to the open feedback, or close it if you've moved on.";
+
+/// Projects fetched per page while sweeping
+const PROJECT_PAGE: i64 = 100;
+
+/// Open PRs fetched per page from Gitea
+const PR_PAGE: usize = 50;
+
+/// Service for nudging open PRs that have gone quiet
+pub struct StalePrService<PR, GC>
+where
+    PR: ProjectRepository,
+    GC: GiteaClient,
+{
+    projects: Arc<PR>,
+    gitea: Arc<GC>,
+    window: Duration,
+    message: String,
+}
+
+impl<PR, GC> StalePrService<PR, GC>
+where
+    PR: ProjectRepository,
+    GC: GiteaClient,
+{
+    pub fn new(projects: Arc<PR>, gitea: Arc<GC>) -> Self {
+        Self {
+            projects,
+            gitea,
+            window: Duration::hours(DEFAULT_STALE_PR_WINDOW_HOURS),
+            message: DEFAULT_STALE_PR_MESSAGE.to_string(),
+        }
+    }
+
+    /// Set how long a PR may go without activity before it's nudged
+    pub fn with_window(mut self, window: Duration) -> Self {
+        self.window = window;
+        self
+    }
+
+    /// Set the nudge comment
+    pub fn with_message(mut self, message: impl Into<String>) -> Self {
+        self.message = message.into();
+        self
+    }
+
+    /// Nudge every stale open PR across active projects
+    ///
+    /// Returns the number of PRs nudged. A project whose PRs can't be
+    /// inspected is logged and skipped so one bad repo doesn't stall the sweep.
+    pub async fn nudge_stale_prs(&self, now: DateTime<Utc>) -> Result<usize, AppError> {
+        let mut nudged = 0;
+        let mut offset = 0;
+        loop {
+            let page = self.projects.find_all(PROJECT_PAGE, offset).await?;
+            for project in page.iter().filter(|p| p.status == ProjectStatus::Active) {
+                match self.nudge_project(project, now).await {
+                    Ok(count) => nudged += count,
+                    Err(e) => {
+                        tracing::warn!(
+                            project = %project.name,
+                            error = %e,
+                            "Failed to check project for stale PRs"
+                        );
+                    }
+                }
+            }
+            if (page.len() as i64) < PROJECT_PAGE {
+                return Ok(nudged);
+            }
+            offset += PROJECT_PAGE;
+        }
+    }
+
+    /// Nudge the stale open PRs of one project that haven't been nudged yet
+    ///
+    /// A PR that can't be checked or nudged is logged and skipped; only
+    /// failing to list the project's PRs is an error.
+    pub async fn nudge_project(
+        &self,
+        project: &Project,
+        now: DateTime<Utc>,
+    ) -> Result<usize, AppError> {
+        let (owner, repo) = (&project.gitea_org, &project.gitea_repo);
+        let already_nudged: HashSet<i64> = self
+            .projects
+            .find_nudged_prs(&project.id)
+            .await?
+            .into_iter()
+            .collect();
+
+        let mut nudged = 0;
+        for page in 1.. {
+            let prs = self
+                .gitea
+                .list_pull_requests_page(owner, repo, Some("open"), page, PR_PAGE)
+                .await?;
+
+            for pr in prs
+                .iter()
+                .filter(|pr| !pr.draft && !already_nudged.contains(&pr.number))
+            {
+                match self.nudge_if_stale(project, pr, now).await {
+                    Ok(true) => nudged += 1,
+                    Ok(false) => {}
+                    Err(e) => {
+                        tracing::warn!(
+                            project = %project.name,
+                            pr = pr.number,
+                            error = %e,
+                            "Failed to nudge stale PR"
+                        );
+                    }
+                }
+            }
+
+            if prs.len() < PR_PAGE {
+                break;
+            }
+        }
+        Ok(nudged)
+    }
+
+    /// Nudge a PR if it has gone quiet; true if it was nudged
+    async fn nudge_if_stale(
+        &self,
+        project: &Project,
+        pr: &GiteaPullRequest,
+        now: DateTime<Utc>,
+    ) -> Result<bool, AppError> {
+        let Some(last_activity) = self.last_activity(project, pr).await? else {
+            return Ok(false);
+        };
+        if now - last_activity < self.window {
+            return Ok(false);
+        }
+
+        self.gitea
+            .post_pr_comment(
+                &project.gitea_org,
+                &project.gitea_repo,
+                pr.number,
+                &self.message,
+                None,
+            )
+            .await?;
+        self.projects
+            .record_pr_nudge(&project.id, pr.number, now)
+            .await?;
+        tracing::info!(
+            project = %project.name,
+            pr = pr.number,
+            %last_activity,
+            "Nudged stale PR"
+        );
+        Ok(true)
+    }
+
+    /// Time of the latest commit or comment on a PR (None when it has neither)
+    async fn last_activity(
+        &self,
+        project: &Project,
+        pr: &GiteaPullRequest,
+    ) -> Result<Option<DateTime<Utc>>, AppError> {
+        let (owner, repo) = (&project.gitea_org, &project.gitea_repo);
+        let commits = self.gitea.get_pr_commits(owner, repo, pr.number).await?;
+        let comments = self.gitea.get_pr_comments(owner, repo, pr.number).await?;
+
+        Ok(commits
+            .iter()
+            .map(|c| c.timestamp.as_str())
+            .chain(comments.iter().map(|c| c.created_at.as_str()))
+            .filter_map(|ts| DateTime::parse_from_rfc3339(ts).ok())
+            .map(|ts| ts.with_timezone(&Utc))
+            .max())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{test_project, InMemoryProjectRepository, MockGiteaClient};
+
+    #[tokio::test]
+    async fn nudges_stale_pr_but_not_recently_active_or_draft_ones() {
+        let project = test_project();
+        let (org, repo) = (project.gitea_org.clone(), project.gitea_repo.clone());
+        let gitea = Arc::new(
+            MockGiteaClient::new()
+                // Stale: last commit and comment are both weeks old
+                .with_pr(&org, &repo, 1)
+                .with_pr_commit(&org, &repo, 1, "aaa", "2026-03-01T10:00:00Z")
+                .with_pr_comment(&org, &repo, 1, "reviewer", "2026-03-02T10:00:00Z")
+                // Old commit, but a comment yesterday
+                .with_pr(&org, &repo, 2)
+                .with_pr_commit(&org, &repo, 2, "bbb", "2026-03-01T10:00:00Z")
+                .with_pr_comment(&org, &repo, 2, "author", "2026-03-19T10:00:00Z")
+                // Stale, but still a draft
+                .with_draft_pr(&org, &repo, 3)
+                .with_pr_commit(&org, &repo, 3, "ccc", "2026-03-01T10:00:00Z")
+                .with_pr_comment(&org, &repo, 3, "author", "2026-03-01T11:00:00Z"),
+        );
+        let projects = Arc::new(InMemoryProjectRepository::new().with_project(project));
+        let service = StalePrService::new(projects, gitea.clone())
+            .with_window(Duration::hours(48))
+            .with_message("Still working on this?");
+
+        let now = DateTime::parse_from_rfc3339("2026-03-20T10:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        let nudged = service.nudge_stale_prs(now).await.unwrap();
+
+        assert_eq!(nudged, 1);
+        let posted = gitea.posted_pr_comments.read().unwrap();
+        assert_eq!(
+            *posted,
+            vec![((org, repo, 1), "Still working on this?".to_string())]
+        );
+    }
+
+    #[tokio::test]
+    async fn nudges_each_pr_once_and_skips_prs_it_cannot_check() {
+        let project = test_project();
+        let (org, repo) = (project.gitea_org.clone(), project.gitea_repo.clone());
+        let gitea = Arc::new(
+            MockGiteaClient::new()
+                // Its commits can't be fetched
+                .with_pr(&org, &repo, 1)
+                .with_unreachable_pr(&org, &repo, 1)
+                .with_pr(&org, &repo, 2)
+                .with_pr_commit(&org, &repo, 2, "bbb", "2026-03-01T10:00:00Z")
+                .with_pr_comment(&org, &repo, 2, "author", "2026-03-01T11:00:00Z"),
+        );
+        let projects = Arc::new(InMemoryProjectRepository::new().with_project(project));
+        let service =
+            StalePrService::new(projects.clone(), gitea.clone()).with_window(Duration::hours(48));
+
+        let now = DateTime::parse_from_rfc3339("2026-03-20T10:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        assert_eq!(service.nudge_stale_prs(now).await.unwrap(), 1);
+        // A window later, PR #2 is still quiet but was already nudged
+        let later = now + Duration::hours(72);
+        assert_eq!(service.nudge_stale_prs(later).await.unwrap(), 0);
+
+        let posted = gitea.posted_pr_comments.read().unwrap();
+        assert_eq!(posted.len(), 1);
+        assert_eq!(posted[0].0, (org, repo, 2));
+        assert_eq!(projects.pr_nudges.read().unwrap().len(), 1);
+    }
+}
@@ -0,0 +1,191 @@
+//! Ticket count batcher
+//!
+//! Bulk-labelling or closing issues in Gitea sends a webhook per issue, and
+//! each one used to hit the project's `open_ticket_count` on its own. The
+//! batcher only remembers which projects saw issue events and, once per flush,
+//! sets each one's count to the open issue count Gitea reports, so a burst of
+//! 50 closes becomes a single update.
+//!
+//! Our own issue endpoints still adjust the count as they go; the recount just
+//! settles it to Gitea's figure, so it can't double count their changes. The
+//! only state kept between flushes is the set of projects to recount, so a
+//! restart at worst leaves a count as it was until that project's next issue
+//! event.
+//!
+//! The recount also refreshes the stored star count from the same repo read,
+//! which keeps stars given directly in Gitea from going stale for long.
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+use crate::domain::entities::{Project, ProjectId};
+use crate::domain::ports::{GiteaClient, ProjectRepository};
+use crate::error::{AppError, GiteaError};
+
+/// Default time between flushes of pending ticket count changes
+pub const DEFAULT_TICKET_COUNT_FLUSH_MS: u64 = 2000;
+
+/// Gitea repo (owner, name) a pending recount reads from
+type RepoKey = (String, String);
+
+/// Coalesces open ticket count recounts per project
+pub struct TicketCountBatcher<PR, G>
+where
+    PR: ProjectRepository,
+    G: GiteaClient,
+{
+    projects: Arc<PR>,
+    gitea: Arc<G>,
+    pending: Mutex<HashMap<ProjectId, RepoKey>>,
+    /// Recount as each change arrives instead of waiting for a flush
+    immediate: bool,
+}
+
+impl<PR, G> TicketCountBatcher<PR, G>
+where
+    PR: ProjectRepository,
+    G: GiteaClient,
+{
+    pub fn new(projects: Arc<PR>, gitea: Arc<G>) -> Self {
+        Self {
+            projects,
+            gitea,
+            pending: Mutex::new(HashMap::new()),
+            immediate: false,
+        }
+    }
+
+    /// Recount as changes arrive (for deployments without a flush loop)
+    pub fn immediate(mut self) -> Self {
+        self.immediate = true;
+        self
+    }
+
+    /// Whether changes wait for `flush`
+    pub fn is_batched(&self) -> bool {
+        !self.immediate
+    }
+
+    /// Note that a project's open issues changed
+    pub async fn record(&self, project: &Project) -> Result<(), AppError> {
+        let repo = (project.gitea_org.clone(), project.gitea_repo.clone());
+        if self.immediate {
+            return self.recount(&project.id, &repo).await.map(|_| ());
+        }
+        self.pending.lock().unwrap().insert(project.id, repo);
+        Ok(())
+    }
+
+    /// Recount each project with pending changes
+    ///
+    /// Returns the number of projects updated. Projects that fail to recount
+    /// are kept for the next flush, except for repos that no longer exist.
+    pub async fn flush(&self) -> Result<usize, AppError> {
+        let pending = std::mem::take(&mut *self.pending.lock().unwrap());
+
+        let mut updated = 0;
+        let mut first_error = None;
+        for (project_id, repo) in pending {
+            match self.recount(&project_id, &repo).await {
+                Ok(true) => updated += 1,
+                Ok(false) => {}
+                Err(e) => {
+                    self.pending
+                        .lock()
+                        .unwrap()
+                        .entry(project_id)
+                        .or_insert(repo);
+                    first_error.get_or_insert(e);
+                }
+            }
+        }
+
+        match first_error {
+            Some(e) => Err(e),
+            None => Ok(updated),
+        }
+    }
+
+    /// Set a project's open ticket and star counts from Gitea; false if the repo is gone
+    async fn recount(
+        &self,
+        project_id: &ProjectId,
+        (owner, name): &RepoKey,
+    ) -> Result<bool, AppError> {
+        let repo = match self.gitea.get_repo(owner, name).await {
+            Ok(repo) => repo,
+            Err(GiteaError::RepoNotFound { .. }) | Err(GiteaError::Api { status: 404, .. }) => {
+                return Ok(false)
+            }
+            Err(e) => return Err(e.into()),
+        };
+        self.projects
+            .set_open_ticket_count(project_id, repo.open_issues_count as i32)
+            .await?;
+        self.projects
+            .set_star_count(project_id, repo.stars_count as i32)
+            .await?;
+        Ok(true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{test_project, InMemoryProjectRepository, MockGiteaClient};
+
+    #[tokio::test]
+    async fn burst_of_closes_is_applied_as_one_update() {
+        let mut project = test_project();
+        project.open_ticket_count = 25;
+        let project_id = project.id;
+        let gitea = Arc::new(MockGiteaClient::new().with_open_issues(
+            &project.gitea_org,
+            &project.gitea_repo,
+            5,
+        ));
+        let projects = Arc::new(InMemoryProjectRepository::new().with_project(project.clone()));
+        let batcher = Arc::new(TicketCountBatcher::new(projects.clone(), gitea));
+
+        let mut burst = tokio::task::JoinSet::new();
+        for _ in 0..20 {
+            let batcher = batcher.clone();
+            let project = project.clone();
+            burst.spawn(async move { batcher.record(&project).await });
+        }
+        while let Some(result) = burst.join_next().await {
+            result.unwrap().unwrap();
+        }
+
+        assert_eq!(batcher.flush().await.unwrap(), 1);
+        assert_eq!(batcher.flush().await.unwrap(), 0);
+
+        let project = projects.find_by_id(&project_id).await.unwrap().unwrap();
+        assert_eq!(project.open_ticket_count, 5);
+        let updates = projects.ticket_count_updates.read().unwrap();
+        assert!(updates.len() < 20);
+        assert_eq!(*updates, vec![(project_id, 5)]);
+    }
+
+    #[tokio::test]
+    async fn recount_does_not_double_count_api_changes() {
+        let mut project = test_project();
+        project.open_ticket_count = 3;
+        let project_id = project.id;
+        let gitea = Arc::new(MockGiteaClient::new().with_open_issues(
+            &project.gitea_org,
+            &project.gitea_repo,
+            4,
+        ));
+        let projects = Arc::new(InMemoryProjectRepository::new().with_project(project.clone()));
+        let batcher = TicketCountBatcher::new(projects.clone(), gitea);
+
+        // The create endpoint adjusts synchronously, then its webhook arrives
+        projects.adjust_ticket_count(&project_id, 1).await.unwrap();
+        batcher.record(&project).await.unwrap();
+        batcher.flush().await.unwrap();
+
+        let project = projects.find_by_id(&project_id).await.unwrap().unwrap();
+        assert_eq!(project.open_ticket_count, 4);
+    }
+}
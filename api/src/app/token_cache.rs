@@ -0,0 +1,150 @@
+//! Decrypted token cache
+//!
+//! Keeps recently decrypted agent Gitea tokens in memory for a short,
+//! fixed TTL so repeated actions don't redo the decryption. Entries live
+//! only in process memory and are never written to disk or logged.
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use crate::domain::entities::AgentId;
+
+/// Default lifetime of a cached token
+pub const DEFAULT_TOKEN_CACHE_TTL_SECS: u64 = 60;
+
+/// Default maximum number of cached tokens
+pub const DEFAULT_TOKEN_CACHE_MAX_ENTRIES: usize = 1000;
+
+struct CachedToken {
+    token: String,
+    expires_at: Instant,
+}
+
+/// Time-bounded, size-bounded cache of decrypted tokens keyed by agent
+pub struct TokenCache {
+    ttl: Duration,
+    max_entries: usize,
+    entries: Mutex<HashMap<AgentId, CachedToken>>,
+}
+
+impl TokenCache {
+    /// A cache holding at most `max_entries` tokens for `ttl` each.
+    /// A zero TTL or size disables caching.
+    pub fn new(ttl: Duration, max_entries: usize) -> Self {
+        Self {
+            ttl,
+            max_entries,
+            entries: Mutex::new(HashMap::new()),
+        }
+    }
+
+    fn is_enabled(&self) -> bool {
+        !self.ttl.is_zero() && self.max_entries > 0
+    }
+
+    /// The cached token for `agent_id`, if present and not expired
+    pub fn get(&self, agent_id: &AgentId) -> Option<String> {
+        if !self.is_enabled() {
+            return None;
+        }
+        let mut entries = self.entries.lock().unwrap();
+        match entries.get(agent_id) {
+            Some(cached) if cached.expires_at > Instant::now() => Some(cached.token.clone()),
+            Some(_) => {
+                entries.remove(agent_id);
+                None
+            }
+            None => None,
+        }
+    }
+
+    /// Cache `token` for `agent_id`, evicting expired entries (then the
+    /// soonest-to-expire one) when the cache is full
+    pub fn insert(&self, agent_id: AgentId, token: String) {
+        if !self.is_enabled() {
+            return;
+        }
+        let now = Instant::now();
+        let mut entries = self.entries.lock().unwrap();
+        if entries.len() >= self.max_entries && !entries.contains_key(&agent_id) {
+            entries.retain(|_, cached| cached.expires_at > now);
+            if entries.len() >= self.max_entries {
+                let oldest = entries
+                    .iter()
+                    .min_by_key(|(_, cached)| cached.expires_at)
+                    .map(|(id, _)| *id);
+                if let Some(oldest) = oldest {
+                    entries.remove(&oldest);
+                }
+            }
+        }
+        entries.insert(
+            agent_id,
+            CachedToken {
+                token,
+                expires_at: now + self.ttl,
+            },
+        );
+    }
+
+    /// Drop any cached token for `agent_id` (e.g. after the token is rotated)
+    pub fn invalidate(&self, agent_id: &AgentId) {
+        self.entries.lock().unwrap().remove(agent_id);
+    }
+}
+
+impl Default for TokenCache {
+    fn default() -> Self {
+        Self::new(
+            Duration::from_secs(DEFAULT_TOKEN_CACHE_TTL_SECS),
+            DEFAULT_TOKEN_CACHE_MAX_ENTRIES,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn expired_tokens_are_not_returned() {
+        let cache = TokenCache::new(Duration::from_millis(1), 10);
+        let agent = AgentId::new();
+        cache.insert(agent, "token".to_string());
+
+        std::thread::sleep(Duration::from_millis(5));
+        assert_eq!(cache.get(&agent), None);
+    }
+
+    #[test]
+    fn full_cache_evicts_the_oldest_entry() {
+        let cache = TokenCache::new(Duration::from_secs(60), 2);
+        let (first, second, third) = (AgentId::new(), AgentId::new(), AgentId::new());
+        cache.insert(first, "one".to_string());
+        cache.insert(second, "two".to_string());
+        cache.insert(third, "three".to_string());
+
+        assert_eq!(cache.get(&first), None);
+        assert_eq!(cache.get(&second).as_deref(), Some("two"));
+        assert_eq!(cache.get(&third).as_deref(), Some("three"));
+    }
+
+    #[test]
+    fn invalidated_tokens_are_not_returned() {
+        let cache = TokenCache::new(Duration::from_secs(60), 10);
+        let agent = AgentId::new();
+        cache.insert(agent, "token".to_string());
+
+        cache.invalidate(&agent);
+        assert_eq!(cache.get(&agent), None);
+    }
+
+    #[test]
+    fn zero_ttl_disables_caching() {
+        let cache = TokenCache::new(Duration::ZERO, 10);
+        let agent = AgentId::new();
+        cache.insert(agent, "token".to_string());
+        assert_eq!(cache.get(&agent), None);
+    }
+}
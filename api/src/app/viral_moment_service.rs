@@ -3,6 +3,7 @@
 //! Detects and manages viral moments - interesting events worth sharing.
 //! Uses engagement signals and optional LLM classification.
 
+use chrono::{DateTime, Duration, NaiveTime, Utc};
 use sha2::{Digest, Sha256};
 use std::sync::Arc;
 use uuid::Uuid;
@@ -19,16 +20,33 @@ fn deterministic_uuid(input: &str) -> Uuid {
 }
 
 use crate::domain::entities::{
-    Agent, AgentId, BattleRacer, BattleSnapshot, MomentType, NewViralMoment, ReferenceType,
-    ShameSnapshot, Tier, UpsetLoser, UpsetSnapshot, ViralMoment, ViralMomentId,
+    Agent, AgentId, BattleRacer, BattleSnapshot, Difficulty, DramaReviewer, DramaSnapshot,
+    EngagementDecay, EngagementWeights, MomentFilter, MomentType, NewViralMoment, ReferenceType,
+    ShameSnapshot, TargetType, Tier, UpsetLoser, UpsetSnapshot, ViralMoment, ViralMomentId,
 };
 use crate::domain::ports::{EngagementRepository, ViralMomentRepository};
-use crate::error::AppError;
+use crate::error::{AppError, DomainError};
+
+/// How many more moments than requested are considered when ranking the top feed
+const TOP_CANDIDATE_MULTIPLIER: i64 = 3;
+
+/// Most recent engagements on a target counted toward its ranking score
+const RANKING_ENGAGEMENT_LIMIT: i64 = 500;
+
+/// Default age in days after which unpromoted moments have their snapshot
+/// compacted (0: compaction is off and every snapshot is kept in full)
+pub const DEFAULT_SNAPSHOT_RETENTION_DAYS: i64 = 0;
+
+/// Moments compacted per repository round trip
+const SNAPSHOT_COMPACTION_BATCH: i64 = 100;
 
 /// Thresholds for detecting viral moments
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct ViralThresholds {
     /// Minimum engagement score to consider for viral
     pub min_engagement_score: i32,
+    /// Minimum shame score for a revert or rejection to reach the Hall of Shame
+    pub min_shame_score: i32,
     /// Minimum ELO differential for David vs Goliath
     pub min_elo_differential: i32,
     /// Minimum number of conflicting reviews for drama
@@ -41,6 +59,7 @@ impl Default for ViralThresholds {
     fn default() -> Self {
         Self {
             min_engagement_score: 10,
+            min_shame_score: 10,
             min_elo_differential: 200,
             min_conflicting_reviews: 2,
             min_battle_racers: 2,
@@ -48,6 +67,101 @@ impl Default for ViralThresholds {
     }
 }
 
+/// What to do when promoting a moment would exceed the promotion quota
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PromotionOverflow {
+    /// Demote the lowest-scoring promoted moment to make room
+    #[default]
+    DemoteWeakest,
+    /// Refuse the promotion until an operator demotes one by hand
+    Reject,
+}
+
+impl std::str::FromStr for PromotionOverflow {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "demote" | "demote_weakest" => Ok(PromotionOverflow::DemoteWeakest),
+            "reject" => Ok(PromotionOverflow::Reject),
+            other => Err(format!("Unknown promotion overflow policy: {}", other)),
+        }
+    }
+}
+
+/// Cap on how many moments can be promoted at once
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PromotionQuota {
+    pub max_promoted: i64,
+    pub overflow: PromotionOverflow,
+}
+
+impl Default for PromotionQuota {
+    fn default() -> Self {
+        Self {
+            max_promoted: 10,
+            overflow: PromotionOverflow::default(),
+        }
+    }
+}
+
+/// Time window in which new moments are recorded hidden instead of published,
+/// e.g. during automated test runs or maintenance
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum QuietHours {
+    /// Moments are published as usual
+    #[default]
+    Off,
+    /// Every new moment is suppressed
+    Always,
+    /// Moments created between `start` and `end` (UTC) are suppressed;
+    /// the window wraps past midnight when `end` is before `start`
+    Window { start: NaiveTime, end: NaiveTime },
+}
+
+impl QuietHours {
+    /// Whether moments created at `now` should be suppressed
+    pub fn is_quiet_at(&self, now: DateTime<Utc>) -> bool {
+        match *self {
+            QuietHours::Off => false,
+            QuietHours::Always => true,
+            QuietHours::Window { start, end } => {
+                let time = now.time();
+                if start <= end {
+                    start <= time && time < end
+                } else {
+                    time >= start || time < end
+                }
+            }
+        }
+    }
+}
+
+impl std::str::FromStr for QuietHours {
+    type Err = String;
+
+    /// Parse `off`, `always`, or a `HH:MM-HH:MM` UTC window
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "" | "off" | "false" => Ok(QuietHours::Off),
+            "always" | "on" | "true" => Ok(QuietHours::Always),
+            window => {
+                let (start, end) = window
+                    .split_once('-')
+                    .ok_or_else(|| format!("Invalid quiet hours: {}", s))?;
+                let parse = |t: &str| {
+                    NaiveTime::parse_from_str(t.trim(), "%H:%M")
+                        .map_err(|_| format!("Invalid quiet hours time: {}", t))
+                };
+                Ok(QuietHours::Window {
+                    start: parse(start)?,
+                    end: parse(end)?,
+                })
+            }
+        }
+    }
+}
+
 /// Service for managing viral moments
 pub struct ViralMomentService<VMR, ER>
 where
@@ -57,6 +171,11 @@ where
     moments: Arc<VMR>,
     engagements: Arc<ER>,
     thresholds: ViralThresholds,
+    weights: EngagementWeights,
+    decay: EngagementDecay,
+    promotion_quota: PromotionQuota,
+    quiet_hours: QuietHours,
+    snapshot_retention: Option<Duration>,
 }
 
 impl<VMR, ER> ViralMomentService<VMR, ER>
@@ -69,6 +188,11 @@ where
             moments,
             engagements,
             thresholds: ViralThresholds::default(),
+            weights: EngagementWeights::default(),
+            decay: EngagementDecay::default(),
+            promotion_quota: PromotionQuota::default(),
+            quiet_hours: QuietHours::default(),
+            snapshot_retention: None,
         }
     }
 
@@ -77,6 +201,69 @@ where
         self
     }
 
+    pub fn with_engagement_weights(mut self, weights: EngagementWeights) -> Self {
+        self.weights = weights;
+        self
+    }
+
+    pub fn with_engagement_decay(mut self, decay: EngagementDecay) -> Self {
+        self.decay = decay;
+        self
+    }
+
+    pub fn with_promotion_quota(mut self, quota: PromotionQuota) -> Self {
+        self.promotion_quota = quota;
+        self
+    }
+
+    pub fn with_quiet_hours(mut self, quiet_hours: QuietHours) -> Self {
+        self.quiet_hours = quiet_hours;
+        self
+    }
+
+    /// Compact the snapshots of unpromoted moments older than `retention`
+    /// (kept in full when None)
+    pub fn with_snapshot_retention(mut self, retention: Option<Duration>) -> Self {
+        self.snapshot_retention = retention;
+        self
+    }
+
+    /// Whether old snapshots are compacted at all
+    pub fn compacts_snapshots(&self) -> bool {
+        self.snapshot_retention.is_some()
+    }
+
+    // ========== Retention ==========
+
+    /// Reduce the snapshots of unpromoted moments older than the retention
+    /// window to a summary, keeping title, score and agents. Returns how many
+    /// were compacted.
+    pub async fn compact_old_snapshots(&self, now: DateTime<Utc>) -> Result<usize, AppError> {
+        let Some(retention) = self.snapshot_retention else {
+            return Ok(0);
+        };
+
+        let mut compacted = 0;
+        loop {
+            let batch = self
+                .moments
+                .find_compactable(now - retention, SNAPSHOT_COMPACTION_BATCH)
+                .await?;
+            for moment in &batch {
+                if self
+                    .moments
+                    .compact_snapshot(&moment.id, &moment.compacted_snapshot())
+                    .await?
+                {
+                    compacted += 1;
+                }
+            }
+            if (batch.len() as i64) < SNAPSHOT_COMPACTION_BATCH {
+                return Ok(compacted);
+            }
+        }
+    }
+
     // ========== Feed Generation ==========
 
     /// Get Hall of Shame feed (PR failures, reverts, etc.)
@@ -128,8 +315,79 @@ where
     }
 
     /// Get top moments across all types
+    ///
+    /// The highest-scoring candidates are re-ranked by their score plus the
+    /// time-decayed engagement on them, so fresh reactions lift a moment.
+    /// Scores and engagement are in different units, so each is first scaled
+    /// against the best candidate and the two weigh equally.
     pub async fn get_top_moments(&self, limit: i64) -> Result<Vec<ViralMoment>, AppError> {
-        Ok(self.moments.find_top(limit).await?)
+        let limit = limit.max(0);
+        let candidates = self
+            .moments
+            .find_top(limit.saturating_mul(TOP_CANDIDATE_MULTIPLIER))
+            .await?;
+
+        let ids: Vec<Uuid> = candidates.iter().map(|m| m.id.0).collect();
+        let mut engagements = self
+            .engagements
+            .find_recent_by_targets(
+                &TargetType::ViralMoment.to_string(),
+                &ids,
+                RANKING_ENGAGEMENT_LIMIT,
+            )
+            .await?;
+        let now = Utc::now();
+        let engagement: Vec<f64> = candidates
+            .iter()
+            .map(|m| {
+                let on_moment = engagements.remove(&m.id.0).unwrap_or_default();
+                self.decay.score(&self.weights, &on_moment, now)
+            })
+            .collect();
+
+        let max_score = candidates.iter().map(|m| m.score).max().unwrap_or(0) as f64;
+        let max_engagement = engagement.iter().copied().fold(0.0, f64::max);
+        let scaled = |value: f64, max: f64| if max > 0.0 { value / max } else { 0.0 };
+
+        let mut ranked: Vec<_> = candidates
+            .into_iter()
+            .zip(engagement)
+            .map(|(moment, engagement)| {
+                let rank =
+                    scaled(moment.score as f64, max_score) + scaled(engagement, max_engagement);
+                (rank, moment)
+            })
+            .collect();
+        ranked.sort_by(|(a_rank, a), (b_rank, b)| {
+            b_rank
+                .total_cmp(a_rank)
+                .then_with(|| b.created_at.cmp(&a.created_at))
+        });
+
+        Ok(ranked
+            .into_iter()
+            .take(limit as usize)
+            .map(|(_, moment)| moment)
+            .collect())
+    }
+
+    /// Effective engagement score of a target for ranking: the weighted
+    /// score with each engagement decayed by its age. Raw counts are untouched.
+    pub async fn ranking_score(
+        &self,
+        target_type: TargetType,
+        target_id: Uuid,
+    ) -> Result<f64, AppError> {
+        let engagements = self
+            .engagements
+            .find_by_target(
+                &target_type.to_string(),
+                target_id,
+                RANKING_ENGAGEMENT_LIMIT,
+                0,
+            )
+            .await?;
+        Ok(self.decay.score(&self.weights, &engagements, Utc::now()))
     }
 
     /// Get a specific moment by ID
@@ -142,6 +400,18 @@ where
         Ok(self.moments.find_promoted(limit).await?)
     }
 
+    /// List moments matching `filter`, with the total number of matches
+    pub async fn list_moments(
+        &self,
+        filter: &MomentFilter,
+        limit: i64,
+        offset: i64,
+    ) -> Result<(Vec<ViralMoment>, i64), AppError> {
+        let moments = self.moments.find_filtered(filter, limit, offset).await?;
+        let total = self.moments.count_filtered(filter).await?;
+        Ok((moments, total))
+    }
+
     // ========== Moment Detection ==========
 
     /// Check if a PR revert should be in Hall of Shame
@@ -169,7 +439,7 @@ where
         let score = self.calculate_revert_shame_score(agent, revert_reason);
 
         // Check threshold
-        if score < 10 {
+        if score < self.thresholds.min_shame_score {
             return Ok(None);
         }
 
@@ -178,15 +448,15 @@ where
             .map(|r| truncate(r, 80).to_string())
             .unwrap_or_else(|| format!("PR #{} in {}", pr_number, project_name));
 
-        let snapshot = ShameSnapshot {
-            agent_name: agent.name.clone(),
-            agent_elo: agent.elo,
-            agent_tier: agent.tier.to_string(),
-            stderr: revert_reason.map(|s| s.to_string()),
-            exit_code: None,
-            issue_title: pr_title.to_string(),
-            issue_difficulty: "N/A".to_string(),
-        };
+        let snapshot = self
+            .capture_snapshot(SnapshotSubject::Shame {
+                agent,
+                pr_number,
+                pr_title,
+                project_name,
+                reason: revert_reason,
+            })
+            .await?;
 
         let new_moment = NewViralMoment {
             moment_type: MomentType::HallOfShame,
@@ -196,11 +466,10 @@ where
             agent_ids: vec![agent.id],
             reference_type: ReferenceType::Review,
             reference_id,
-            snapshot: serde_json::to_value(&snapshot).unwrap_or_default(),
+            snapshot,
         };
 
-        let moment = self.moments.create(&new_moment).await?;
-        Ok(Some(moment))
+        self.record(&new_moment).await
     }
 
     /// Check if a PR rejection should be in Hall of Shame
@@ -228,7 +497,7 @@ where
         let score = self.calculate_rejection_shame_score(agent, rejection_count);
 
         // Check threshold - must be somewhat notable
-        if score < 10 {
+        if score < self.thresholds.min_shame_score {
             return Ok(None);
         }
 
@@ -245,15 +514,15 @@ where
             project_name
         );
 
-        let snapshot = ShameSnapshot {
-            agent_name: agent.name.clone(),
-            agent_elo: agent.elo,
-            agent_tier: agent.tier.to_string(),
-            stderr: None,
-            exit_code: None,
-            issue_title: pr_title.to_string(),
-            issue_difficulty: "N/A".to_string(),
-        };
+        let snapshot = self
+            .capture_snapshot(SnapshotSubject::Shame {
+                agent,
+                pr_number,
+                pr_title,
+                project_name,
+                reason: None,
+            })
+            .await?;
 
         let new_moment = NewViralMoment {
             moment_type: MomentType::HallOfShame,
@@ -263,11 +532,10 @@ where
             agent_ids: vec![agent.id],
             reference_type: ReferenceType::PullRequest,
             reference_id,
-            snapshot: serde_json::to_value(&snapshot).unwrap_or_default(),
+            snapshot,
         };
 
-        let moment = self.moments.create(&new_moment).await?;
-        Ok(Some(moment))
+        self.record(&new_moment).await
     }
 
     /// Check if conflicting reviews create drama
@@ -283,6 +551,9 @@ where
         if approvers.is_empty() || rejectors.is_empty() {
             return Ok(None);
         }
+        if ((approvers.len() + rejectors.len()) as i32) < self.thresholds.min_conflicting_reviews {
+            return Ok(None);
+        }
 
         // Use a deterministic reference ID
         let reference_id = deterministic_uuid(&format!("pr_drama:{}:{}", project_name, pr_number));
@@ -320,22 +591,15 @@ where
             truncate(pr_title, 30)
         );
 
-        // Build drama snapshot
-        let snapshot = serde_json::json!({
-            "pr_number": pr_number,
-            "pr_title": pr_title,
-            "project": project_name,
-            "approvers": approvers.iter().map(|a| serde_json::json!({
-                "name": a.name,
-                "elo": a.elo,
-                "tier": a.tier.to_string(),
-            })).collect::<Vec<_>>(),
-            "rejectors": rejectors.iter().map(|a| serde_json::json!({
-                "name": a.name,
-                "elo": a.elo,
-                "tier": a.tier.to_string(),
-            })).collect::<Vec<_>>(),
-        });
+        let snapshot = self
+            .capture_snapshot(SnapshotSubject::Drama {
+                pr_number,
+                pr_title,
+                project_name,
+                approvers,
+                rejectors,
+            })
+            .await?;
 
         let mut agent_ids: Vec<AgentId> = approvers.iter().map(|a| a.id).collect();
         agent_ids.extend(rejectors.iter().map(|a| a.id));
@@ -351,8 +615,7 @@ where
             snapshot,
         };
 
-        let moment = self.moments.create(&new_moment).await?;
-        Ok(Some(moment))
+        self.record(&new_moment).await
     }
 
     /// Check if a solved issue is a David vs Goliath upset
@@ -393,22 +656,16 @@ where
             if losers.len() > 1 { "s" } else { "" }
         );
 
-        let snapshot = UpsetSnapshot {
-            winner_name: winner.name.clone(),
-            winner_elo: winner.elo,
-            winner_tier: winner.tier.to_string(),
-            losers: losers
-                .iter()
-                .map(|a| UpsetLoser {
-                    name: a.name.clone(),
-                    elo: a.elo,
-                    tier: a.tier.to_string(),
-                })
-                .collect(),
-            issue_title: issue_title.to_string(),
-            issue_difficulty: issue_difficulty.to_string(),
-            elo_differential,
-        };
+        let snapshot = self
+            .capture_snapshot(SnapshotSubject::Upset {
+                winner,
+                losers,
+                issue_id,
+                issue_title,
+                issue_difficulty,
+                elo_differential,
+            })
+            .await?;
 
         let mut agent_ids: Vec<AgentId> = vec![winner.id];
         agent_ids.extend(losers.iter().map(|a| a.id));
@@ -421,11 +678,42 @@ where
             agent_ids,
             reference_type: ReferenceType::Issue,
             reference_id: issue_id,
-            snapshot: serde_json::to_value(&snapshot).unwrap_or_default(),
+            snapshot,
         };
 
-        let moment = self.moments.create(&new_moment).await?;
-        Ok(Some(moment))
+        self.record(&new_moment).await
+    }
+
+    /// Check whether a merged PR beat higher-ELO competitors on the same issue.
+    ///
+    /// `competitors` are the authors of the other, unmerged PRs targeting the
+    /// issue; only those rated above the winner count as losers. The moment is
+    /// keyed on project + issue number so redelivered merge events don't
+    /// record the same upset twice.
+    pub async fn check_issue_upset(
+        &self,
+        project_name: &str,
+        issue_number: i64,
+        winner: &Agent,
+        competitors: &[Agent],
+        issue_title: &str,
+        difficulty: Difficulty,
+    ) -> Result<Option<ViralMoment>, AppError> {
+        let losers: Vec<Agent> = competitors
+            .iter()
+            .filter(|a| a.id != winner.id && a.elo > winner.elo)
+            .cloned()
+            .collect();
+
+        let issue_id = deterministic_uuid(&format!("upset:{}:{}", project_name, issue_number));
+        self.check_upset(
+            winner,
+            &losers,
+            issue_id,
+            issue_title,
+            &difficulty.to_string(),
+        )
+        .await
     }
 
     /// Create or update a live battle moment
@@ -455,24 +743,13 @@ where
             truncate(issue_title, 50)
         );
 
-        let snapshot = BattleSnapshot {
-            issue_title: issue_title.to_string(),
-            issue_id,
-            racers: racers
-                .iter()
-                .map(|(agent, status)| BattleRacer {
-                    agent_id: agent.id.0,
-                    agent_name: agent.name.clone(),
-                    agent_elo: agent.elo,
-                    agent_tier: agent.tier.to_string(),
-                    status: status.clone(),
-                    progress: None,
-                })
-                .collect(),
-            started_at: chrono::Utc::now(),
-            ended_at: None,
-            winner_id: None,
-        };
+        let snapshot = self
+            .capture_snapshot(SnapshotSubject::Battle {
+                issue_id,
+                issue_title,
+                racers: &racers,
+            })
+            .await?;
 
         let new_moment = NewViralMoment {
             moment_type: MomentType::LiveBattle,
@@ -482,13 +759,136 @@ where
             agent_ids: racers.iter().map(|(a, _)| a.id).collect(),
             reference_type: ReferenceType::Issue,
             reference_id: issue_id,
-            snapshot: serde_json::to_value(&snapshot).unwrap_or_default(),
+            snapshot,
         };
 
-        let moment = self.moments.create(&new_moment).await?;
+        self.record(&new_moment).await
+    }
+
+    /// Store a detected moment. During quiet hours it is still recorded (so
+    /// the event isn't detected again later) but hidden, and not returned.
+    async fn record(&self, new_moment: &NewViralMoment) -> Result<Option<ViralMoment>, AppError> {
+        let moment = self.moments.create(new_moment).await?;
+        if self.quiet_hours.is_quiet_at(Utc::now()) {
+            self.moments.set_hidden(&moment.id, true).await?;
+            tracing::debug!(
+                moment_id = %moment.id,
+                moment_type = %moment.moment_type,
+                "Viral moment suppressed during quiet hours"
+            );
+            return Ok(None);
+        }
         Ok(Some(moment))
     }
 
+    // ========== Snapshots ==========
+
+    /// Capture the state a moment refers to into its typed snapshot
+    ///
+    /// Agent ELOs and tiers, PR/issue details, and source engagement are frozen
+    /// at creation time so the moment reads the same after the source changes.
+    async fn capture_snapshot(
+        &self,
+        subject: SnapshotSubject<'_>,
+    ) -> Result<serde_json::Value, AppError> {
+        let engagement = match subject.engagement_target() {
+            Some((target_type, target_id)) => {
+                let mut counts = self
+                    .engagements
+                    .get_counts(&target_type.to_string(), target_id)
+                    .await?;
+                counts.total_score = self.weights.score(&counts);
+                Some(counts)
+            }
+            None => None,
+        };
+
+        let snapshot = match subject {
+            SnapshotSubject::Shame {
+                agent,
+                pr_number,
+                pr_title,
+                project_name,
+                reason,
+            } => serde_json::to_value(ShameSnapshot {
+                agent_name: agent.name.clone(),
+                agent_elo: agent.elo,
+                agent_tier: agent.tier.to_string(),
+                stderr: reason.map(|s| s.to_string()),
+                exit_code: None,
+                issue_title: pr_title.to_string(),
+                issue_difficulty: "N/A".to_string(),
+                pr_number: Some(pr_number),
+                project: Some(project_name.to_string()),
+                engagement,
+            }),
+            SnapshotSubject::Drama {
+                pr_number,
+                pr_title,
+                project_name,
+                approvers,
+                rejectors,
+            } => serde_json::to_value(DramaSnapshot {
+                pr_number,
+                pr_title: pr_title.to_string(),
+                project: project_name.to_string(),
+                approvers: approvers.iter().map(drama_reviewer).collect(),
+                rejectors: rejectors.iter().map(drama_reviewer).collect(),
+                engagement,
+            }),
+            SnapshotSubject::Upset {
+                winner,
+                losers,
+                issue_id,
+                issue_title,
+                issue_difficulty,
+                elo_differential,
+            } => serde_json::to_value(UpsetSnapshot {
+                winner_name: winner.name.clone(),
+                winner_elo: winner.elo,
+                winner_tier: winner.tier.to_string(),
+                losers: losers
+                    .iter()
+                    .map(|a| UpsetLoser {
+                        name: a.name.clone(),
+                        elo: a.elo,
+                        tier: a.tier.to_string(),
+                    })
+                    .collect(),
+                issue_title: issue_title.to_string(),
+                issue_difficulty: issue_difficulty.to_string(),
+                elo_differential,
+                issue_id: Some(issue_id),
+                engagement,
+            }),
+            SnapshotSubject::Battle {
+                issue_id,
+                issue_title,
+                racers,
+            } => serde_json::to_value(BattleSnapshot {
+                issue_title: issue_title.to_string(),
+                issue_id,
+                racers: racers
+                    .iter()
+                    .map(|(agent, status)| BattleRacer {
+                        agent_id: agent.id.0,
+                        agent_name: agent.name.clone(),
+                        agent_elo: agent.elo,
+                        agent_tier: agent.tier.to_string(),
+                        status: status.clone(),
+                        progress: None,
+                    })
+                    .collect(),
+                started_at: chrono::Utc::now(),
+                ended_at: None,
+                winner_id: None,
+                engagement,
+            }),
+        };
+
+        snapshot.map_err(|e| AppError::Internal(format!("Failed to serialize snapshot: {}", e)))
+    }
+
     // ========== Scoring Algorithms ==========
 
     fn calculate_revert_shame_score(&self, agent: &Agent, revert_reason: Option<&str>) -> i32 {
@@ -640,7 +1040,45 @@ where
     // ========== Moderation ==========
 
     /// Promote a moment (staff pick)
+    ///
+    /// When the promotion quota is full, either demotes the lowest-scoring
+    /// promoted moment or rejects the promotion, depending on the quota's
+    /// overflow policy.
     pub async fn promote(&self, id: &ViralMomentId) -> Result<(), AppError> {
+        let moment = self
+            .moments
+            .find_by_id(id)
+            .await?
+            .ok_or_else(|| AppError::NotFound(format!("Viral moment {}", id)))?;
+        if moment.promoted {
+            return Ok(());
+        }
+
+        let quota = self.promotion_quota;
+        let promoted = self.moments.count_promoted().await?;
+        if promoted >= quota.max_promoted {
+            match quota.overflow {
+                PromotionOverflow::Reject => {
+                    return Err(AppError::Domain(DomainError::Conflict(format!(
+                        "Promotion quota reached ({} of {} promoted); demote a moment first",
+                        promoted, quota.max_promoted
+                    ))));
+                }
+                PromotionOverflow::DemoteWeakest => {
+                    // find_promoted is ordered by score, so the weakest is last
+                    let weakest = self.moments.find_promoted(promoted).await?.pop();
+                    if let Some(weakest) = weakest {
+                        tracing::info!(
+                            "Promotion quota reached, demoting moment {} (score {})",
+                            weakest.id,
+                            weakest.score
+                        );
+                        self.moments.set_promoted(&weakest.id, false).await?;
+                    }
+                }
+            }
+        }
+
         Ok(self.moments.set_promoted(id, true).await?)
     }
 
@@ -666,7 +1104,7 @@ where
             .await?;
 
         // Update score based on engagement
-        let engagement_bonus = counts.total_score;
+        let engagement_bonus = self.weights.score(&counts);
         let new_score = moment.score + engagement_bonus;
 
         self.moments.update_score(moment_id, new_score).await?;
@@ -675,6 +1113,60 @@ where
     }
 }
 
+/// Source state for a moment, per moment type
+enum SnapshotSubject<'a> {
+    Shame {
+        agent: &'a Agent,
+        pr_number: i64,
+        pr_title: &'a str,
+        project_name: &'a str,
+        reason: Option<&'a str>,
+    },
+    Drama {
+        pr_number: i64,
+        pr_title: &'a str,
+        project_name: &'a str,
+        approvers: &'a [Agent],
+        rejectors: &'a [Agent],
+    },
+    Upset {
+        winner: &'a Agent,
+        losers: &'a [Agent],
+        issue_id: Uuid,
+        issue_title: &'a str,
+        issue_difficulty: &'a str,
+        elo_differential: i32,
+    },
+    Battle {
+        issue_id: Uuid,
+        issue_title: &'a str,
+        racers: &'a [(Agent, String)],
+    },
+}
+
+impl SnapshotSubject<'_> {
+    /// Engagement target whose counts are frozen into the snapshot
+    ///
+    /// PR moments are keyed by project and PR number rather than an
+    /// engagement target id, so only issue-backed moments carry counts.
+    fn engagement_target(&self) -> Option<(TargetType, Uuid)> {
+        match self {
+            SnapshotSubject::Upset { issue_id, .. } | SnapshotSubject::Battle { issue_id, .. } => {
+                Some((TargetType::Issue, *issue_id))
+            }
+            SnapshotSubject::Shame { .. } | SnapshotSubject::Drama { .. } => None,
+        }
+    }
+}
+
+fn drama_reviewer(agent: &Agent) -> DramaReviewer {
+    DramaReviewer {
+        name: agent.name.clone(),
+        elo: agent.elo,
+        tier: agent.tier.to_string(),
+    }
+}
+
 /// Truncate a string to max length with ellipsis
 fn truncate(s: &str, max: usize) -> String {
     if s.len() <= max {
@@ -687,6 +1179,353 @@ fn truncate(s: &str, max: usize) -> String {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::domain::entities::{
+        Engagement, EngagementCounts, EngagementId, EngagementType, MomentSort, NewEngagement,
+        ReactionType,
+    };
+    use crate::test_utils::{
+        test_agent_with_elo, InMemoryEngagementRepository, InMemoryViralMomentRepository,
+    };
+    use chrono::Utc;
+
+    fn moment(score: i32, promoted: bool) -> ViralMoment {
+        ViralMoment {
+            id: ViralMomentId::new(),
+            moment_type: MomentType::LiveBattle,
+            title: format!("Moment scoring {}", score),
+            subtitle: None,
+            score,
+            agent_ids: vec![],
+            reference_type: ReferenceType::PullRequest,
+            reference_id: Uuid::new_v4(),
+            snapshot: serde_json::json!({}),
+            promoted,
+            hidden: false,
+            llm_classified: false,
+            llm_classification: None,
+            created_at: Utc::now(),
+            snapshot_compacted_at: None,
+        }
+    }
+
+    fn service(
+        moments: InMemoryViralMomentRepository,
+        overflow: PromotionOverflow,
+    ) -> (
+        Arc<InMemoryViralMomentRepository>,
+        ViralMomentService<InMemoryViralMomentRepository, InMemoryEngagementRepository>,
+    ) {
+        let moments = Arc::new(moments);
+        let service = ViralMomentService::new(
+            moments.clone(),
+            Arc::new(InMemoryEngagementRepository::new()),
+        )
+        .with_promotion_quota(PromotionQuota {
+            max_promoted: 2,
+            overflow,
+        });
+        (moments, service)
+    }
+
+    fn reaction_at(target_id: Uuid, reaction: ReactionType, at: DateTime<Utc>) -> Engagement {
+        Engagement {
+            id: EngagementId::new(),
+            agent_id: AgentId::new(),
+            target_type: TargetType::ViralMoment,
+            target_id,
+            engagement_type: EngagementType::Reaction,
+            reaction: Some(reaction),
+            body: None,
+            gitea_synced: false,
+            gitea_id: None,
+            created_at: at,
+            gitea_org: None,
+            gitea_repo: None,
+            sync_attempts: 0,
+            sync_failed: false,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_compaction_shrinks_old_unpromoted_snapshots_only() {
+        let snapshot = serde_json::json!({
+            "pr_number": 42,
+            "pr_title": "Rewrite the scheduler",
+            "project": "antfarm",
+            "approvers": [{"name": "a", "elo": 1200, "tier": "silver"}],
+            "rejectors": [
+                {"name": "b", "elo": 1100, "tier": "bronze"},
+                {"name": "c", "elo": 1300, "tier": "silver"}
+            ],
+            "stderr": "x".repeat(5000),
+            "engagement": {"laugh": 3}
+        });
+        let old_at = Utc::now() - Duration::days(40);
+        let mut old = moment(10, false);
+        old.snapshot = snapshot.clone();
+        old.created_at = old_at;
+        let mut promoted = moment(20, true);
+        promoted.snapshot = snapshot.clone();
+        promoted.created_at = old_at;
+        let mut recent = moment(30, false);
+        recent.snapshot = snapshot.clone();
+
+        let (moments, service) = service(
+            InMemoryViralMomentRepository::new()
+                .with_moment(old.clone())
+                .with_moment(promoted.clone())
+                .with_moment(recent.clone()),
+            PromotionOverflow::DemoteWeakest,
+        );
+        let service = service.with_snapshot_retention(Some(Duration::days(30)));
+
+        let compacted = service.compact_old_snapshots(Utc::now()).await.unwrap();
+        assert_eq!(compacted, 1);
+
+        let old = moments.find_by_id(&old.id).await.unwrap().unwrap();
+        assert_eq!(
+            old.snapshot,
+            serde_json::json!({
+                "pr_number": 42,
+                "pr_title": "Rewrite the scheduler",
+                "project": "antfarm",
+                "approvers_count": 1,
+                "rejectors_count": 2,
+                "compacted": true
+            })
+        );
+        assert!(old.snapshot_compacted_at.is_some());
+        assert_eq!(old.title, "Moment scoring 10");
+        assert_eq!(old.score, 10);
+
+        let promoted = moments.find_by_id(&promoted.id).await.unwrap().unwrap();
+        assert_eq!(promoted.snapshot, snapshot);
+        let recent = moments.find_by_id(&recent.id).await.unwrap().unwrap();
+        assert_eq!(recent.snapshot, snapshot);
+
+        // Already compacted moments aren't picked up again
+        assert_eq!(service.compact_old_snapshots(Utc::now()).await.unwrap(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_old_reactions_rank_below_equal_recent_ones() {
+        let old = moment(20, false);
+        let fresh = moment(20, false);
+        let now = Utc::now();
+        let mut engagements = InMemoryEngagementRepository::new();
+        for _ in 0..5 {
+            engagements = engagements
+                .with_engagement(reaction_at(
+                    old.id.0,
+                    ReactionType::Fire,
+                    now - chrono::Duration::days(3),
+                ))
+                .with_engagement(reaction_at(fresh.id.0, ReactionType::Fire, now));
+        }
+        let service = ViralMomentService::new(
+            Arc::new(
+                InMemoryViralMomentRepository::new()
+                    .with_moment(old.clone())
+                    .with_moment(fresh.clone()),
+            ),
+            Arc::new(engagements),
+        )
+        .with_engagement_decay(EngagementDecay {
+            half_life: chrono::Duration::hours(24),
+        });
+
+        let old_score = service
+            .ranking_score(TargetType::ViralMoment, old.id.0)
+            .await
+            .unwrap();
+        let fresh_score = service
+            .ranking_score(TargetType::ViralMoment, fresh.id.0)
+            .await
+            .unwrap();
+        assert!(old_score < fresh_score);
+        // Five fire reactions (weight 3), three half-lives old
+        assert!((old_score - 15.0 / 8.0).abs() < 0.01);
+
+        let top: Vec<_> = service
+            .get_top_moments(2)
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|m| m.id)
+            .collect();
+        assert_eq!(top, vec![fresh.id, old.id]);
+    }
+
+    #[tokio::test]
+    async fn test_top_moments_weigh_score_and_engagement_equally() {
+        // A huge score alone no longer drowns out engagement measured in
+        // another unit
+        let high_score = moment(1000, false);
+        let engaged = moment(900, false);
+        let now = Utc::now();
+        let mut engagements = InMemoryEngagementRepository::new();
+        for _ in 0..5 {
+            engagements =
+                engagements.with_engagement(reaction_at(engaged.id.0, ReactionType::Fire, now));
+        }
+        let service = ViralMomentService::new(
+            Arc::new(
+                InMemoryViralMomentRepository::new()
+                    .with_moment(high_score.clone())
+                    .with_moment(engaged.clone()),
+            ),
+            Arc::new(engagements),
+        );
+
+        let top: Vec<_> = service
+            .get_top_moments(2)
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|m| m.id)
+            .collect();
+        assert_eq!(top, vec![engaged.id, high_score.id]);
+    }
+
+    #[tokio::test]
+    async fn test_promote_under_quota() {
+        let existing = moment(50, true);
+        let candidate = moment(10, false);
+        let (moments, service) = service(
+            InMemoryViralMomentRepository::new()
+                .with_moment(existing.clone())
+                .with_moment(candidate.clone()),
+            PromotionOverflow::DemoteWeakest,
+        );
+
+        service.promote(&candidate.id).await.unwrap();
+
+        assert_eq!(moments.count_promoted().await.unwrap(), 2);
+        let existing = moments.find_by_id(&existing.id).await.unwrap().unwrap();
+        assert!(existing.promoted);
+    }
+
+    #[tokio::test]
+    async fn test_promote_over_quota_demotes_weakest() {
+        let strong = moment(80, true);
+        let weak = moment(20, true);
+        let candidate = moment(40, false);
+        let (moments, service) = service(
+            InMemoryViralMomentRepository::new()
+                .with_moment(strong.clone())
+                .with_moment(weak.clone())
+                .with_moment(candidate.clone()),
+            PromotionOverflow::DemoteWeakest,
+        );
+
+        service.promote(&candidate.id).await.unwrap();
+
+        assert_eq!(moments.count_promoted().await.unwrap(), 2);
+        let promoted: Vec<_> = moments
+            .find_promoted(10)
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|m| m.id)
+            .collect();
+        assert_eq!(promoted, vec![strong.id, candidate.id]);
+    }
+
+    #[tokio::test]
+    async fn test_promote_over_quota_rejects() {
+        let candidate = moment(40, false);
+        let (moments, service) = service(
+            InMemoryViralMomentRepository::new()
+                .with_moment(moment(80, true))
+                .with_moment(moment(20, true))
+                .with_moment(candidate.clone()),
+            PromotionOverflow::Reject,
+        );
+
+        let result = service.promote(&candidate.id).await;
+
+        assert!(matches!(
+            result,
+            Err(AppError::Domain(DomainError::Conflict(_)))
+        ));
+        let candidate = moments.find_by_id(&candidate.id).await.unwrap().unwrap();
+        assert!(!candidate.promoted);
+    }
+
+    #[tokio::test]
+    async fn test_promote_already_promoted_is_noop() {
+        let strong = moment(80, true);
+        let weak = moment(20, true);
+        let (moments, service) = service(
+            InMemoryViralMomentRepository::new()
+                .with_moment(strong)
+                .with_moment(weak.clone()),
+            PromotionOverflow::DemoteWeakest,
+        );
+
+        service.promote(&weak.id).await.unwrap();
+
+        assert_eq!(moments.count_promoted().await.unwrap(), 2);
+    }
+
+    #[test]
+    fn test_promotion_overflow_from_str() {
+        assert_eq!(
+            "reject".parse::<PromotionOverflow>().unwrap(),
+            PromotionOverflow::Reject
+        );
+        assert_eq!(
+            "demote".parse::<PromotionOverflow>().unwrap(),
+            PromotionOverflow::DemoteWeakest
+        );
+        assert!("shrug".parse::<PromotionOverflow>().is_err());
+    }
+
+    #[test]
+    fn test_quiet_hours_from_str_and_window() {
+        assert_eq!("off".parse::<QuietHours>().unwrap(), QuietHours::Off);
+        assert_eq!("always".parse::<QuietHours>().unwrap(), QuietHours::Always);
+        assert!("22:00".parse::<QuietHours>().is_err());
+
+        let overnight: QuietHours = "22:00-06:00".parse().unwrap();
+        let at = |h: u32| {
+            Utc::now()
+                .date_naive()
+                .and_hms_opt(h, 30, 0)
+                .unwrap()
+                .and_utc()
+        };
+        assert!(overnight.is_quiet_at(at(23)));
+        assert!(overnight.is_quiet_at(at(5)));
+        assert!(!overnight.is_quiet_at(at(12)));
+        assert!(!QuietHours::Off.is_quiet_at(at(23)));
+    }
+
+    #[tokio::test]
+    async fn test_quiet_hours_suppress_moments_until_disabled() {
+        let moments = Arc::new(InMemoryViralMomentRepository::new());
+        let engagements = Arc::new(InMemoryEngagementRepository::new());
+        let agent = test_agent_with_elo(1000);
+
+        let quiet = ViralMomentService::new(moments.clone(), engagements.clone())
+            .with_quiet_hours(QuietHours::Always);
+        let moment = quiet
+            .check_hall_of_shame_revert(&agent, 7, "Add cache", "proj", None)
+            .await
+            .unwrap();
+        assert!(moment.is_none());
+        assert!(quiet.get_top_moments(10).await.unwrap().is_empty());
+
+        let resumed = ViralMomentService::new(moments, engagements);
+        let moment = resumed
+            .check_hall_of_shame_revert(&agent, 8, "Add cache", "proj", None)
+            .await
+            .unwrap()
+            .expect("moment is published once quiet hours are off");
+        let top = resumed.get_top_moments(10).await.unwrap();
+        assert_eq!(top.len(), 1);
+        assert_eq!(top[0].id, moment.id);
+    }
 
     #[test]
     fn test_truncate() {
@@ -698,6 +1537,444 @@ mod tests {
     fn test_default_thresholds() {
         let thresholds = ViralThresholds::default();
         assert_eq!(thresholds.min_engagement_score, 10);
+        assert_eq!(thresholds.min_shame_score, 10);
         assert_eq!(thresholds.min_elo_differential, 200);
     }
+
+    // ==========================================================================
+    // Viral threshold boundary tests
+    // ==========================================================================
+
+    fn service_with_thresholds(
+        thresholds: ViralThresholds,
+    ) -> ViralMomentService<InMemoryViralMomentRepository, InMemoryEngagementRepository> {
+        ViralMomentService::new(
+            Arc::new(InMemoryViralMomentRepository::new()),
+            Arc::new(InMemoryEngagementRepository::new()),
+        )
+        .with_thresholds(thresholds)
+    }
+
+    #[tokio::test]
+    async fn test_revert_shame_exactly_at_threshold() {
+        // Bronze agent at 1000 ELO with no revert reason scores 1000 / 100 = 10
+        let service = service_with_thresholds(ViralThresholds {
+            min_shame_score: 10,
+            ..Default::default()
+        });
+
+        let moment = service
+            .check_hall_of_shame_revert(&test_agent_with_elo(1000), 7, "Add cache", "proj", None)
+            .await
+            .unwrap();
+
+        assert_eq!(moment.unwrap().score, 10);
+    }
+
+    #[tokio::test]
+    async fn test_revert_shame_one_below_threshold() {
+        let service = service_with_thresholds(ViralThresholds {
+            min_shame_score: 11,
+            ..Default::default()
+        });
+
+        let moment = service
+            .check_hall_of_shame_revert(&test_agent_with_elo(1000), 7, "Add cache", "proj", None)
+            .await
+            .unwrap();
+
+        assert!(moment.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_rejection_shame_exactly_at_threshold() {
+        // Bronze agent at 1000 ELO, rejected once: 10 + 5 base = 15
+        let service = service_with_thresholds(ViralThresholds {
+            min_shame_score: 15,
+            ..Default::default()
+        });
+
+        let moment = service
+            .check_hall_of_shame_rejection(&test_agent_with_elo(1000), 7, "Add cache", "proj", 1)
+            .await
+            .unwrap();
+
+        assert_eq!(moment.unwrap().score, 15);
+    }
+
+    #[tokio::test]
+    async fn test_rejection_shame_one_below_threshold() {
+        let service = service_with_thresholds(ViralThresholds {
+            min_shame_score: 16,
+            ..Default::default()
+        });
+
+        let moment = service
+            .check_hall_of_shame_rejection(&test_agent_with_elo(1000), 7, "Add cache", "proj", 1)
+            .await
+            .unwrap();
+
+        assert!(moment.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_drama_exactly_at_threshold() {
+        // 1 vs 1 Bronze reviewers: 2 * 5 reviewers + 20 for the even split = 30
+        let service = service_with_thresholds(ViralThresholds {
+            min_engagement_score: 30,
+            min_conflicting_reviews: 2,
+            ..Default::default()
+        });
+
+        let moment = service
+            .check_drama(
+                7,
+                "proj",
+                "Add cache",
+                &[test_agent_with_elo(1000)],
+                &[test_agent_with_elo(1001)],
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(moment.unwrap().score, 30);
+    }
+
+    #[tokio::test]
+    async fn test_drama_one_below_score_threshold() {
+        let service = service_with_thresholds(ViralThresholds {
+            min_engagement_score: 31,
+            ..Default::default()
+        });
+
+        let moment = service
+            .check_drama(
+                7,
+                "proj",
+                "Add cache",
+                &[test_agent_with_elo(1000)],
+                &[test_agent_with_elo(1001)],
+            )
+            .await
+            .unwrap();
+
+        assert!(moment.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_drama_one_below_review_threshold() {
+        let service = service_with_thresholds(ViralThresholds {
+            min_engagement_score: 0,
+            min_conflicting_reviews: 3,
+            ..Default::default()
+        });
+
+        let moment = service
+            .check_drama(
+                7,
+                "proj",
+                "Add cache",
+                &[test_agent_with_elo(1000)],
+                &[test_agent_with_elo(1001)],
+            )
+            .await
+            .unwrap();
+
+        assert!(moment.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_upset_exactly_at_threshold() {
+        let service = service_with_thresholds(ViralThresholds::default());
+        let winner = test_agent_with_elo(1000);
+        let loser = test_agent_with_elo(1000 + service.thresholds.min_elo_differential);
+
+        let moment = service
+            .check_upset(&winner, &[loser], Uuid::new_v4(), "Add cache", "easy")
+            .await
+            .unwrap();
+
+        assert!(moment.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_upset_one_below_threshold() {
+        let service = service_with_thresholds(ViralThresholds::default());
+        let winner = test_agent_with_elo(1000);
+        let loser = test_agent_with_elo(1000 + service.thresholds.min_elo_differential - 1);
+
+        let moment = service
+            .check_upset(&winner, &[loser], Uuid::new_v4(), "Add cache", "easy")
+            .await
+            .unwrap();
+
+        assert!(moment.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_battle_exactly_at_threshold() {
+        let service = service_with_thresholds(ViralThresholds {
+            min_battle_racers: 2,
+            ..Default::default()
+        });
+        let racers = vec![
+            (test_agent_with_elo(1000), "working".to_string()),
+            (test_agent_with_elo(1100), "working".to_string()),
+        ];
+
+        let moment = service
+            .track_battle(Uuid::new_v4(), "Add cache", racers)
+            .await
+            .unwrap();
+
+        assert!(moment.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_battle_one_below_threshold() {
+        let service = service_with_thresholds(ViralThresholds {
+            min_battle_racers: 3,
+            ..Default::default()
+        });
+        let racers = vec![
+            (test_agent_with_elo(1000), "working".to_string()),
+            (test_agent_with_elo(1100), "working".to_string()),
+        ];
+
+        let moment = service
+            .track_battle(Uuid::new_v4(), "Add cache", racers)
+            .await
+            .unwrap();
+
+        assert!(moment.is_none());
+    }
+
+    // ==========================================================================
+    // Snapshot capture tests
+    // ==========================================================================
+
+    #[tokio::test]
+    async fn test_shame_snapshot_captures_agent_and_pr() {
+        let service = service_with_thresholds(ViralThresholds {
+            min_shame_score: 0,
+            ..Default::default()
+        });
+        let agent = test_agent_with_elo(1400);
+
+        let moment = service
+            .check_hall_of_shame_revert(&agent, 7, "Add cache", "proj", Some("broke prod"))
+            .await
+            .unwrap()
+            .unwrap();
+
+        let snapshot = moment.as_shame_snapshot().unwrap();
+        assert_eq!(snapshot.agent_name, agent.name);
+        assert_eq!(snapshot.agent_elo, 1400);
+        assert_eq!(snapshot.stderr.as_deref(), Some("broke prod"));
+        assert_eq!(snapshot.issue_title, "Add cache");
+        assert_eq!(snapshot.pr_number, Some(7));
+        assert_eq!(snapshot.project.as_deref(), Some("proj"));
+    }
+
+    #[tokio::test]
+    async fn test_drama_snapshot_captures_reviewer_elos() {
+        let service = service_with_thresholds(ViralThresholds {
+            min_engagement_score: 0,
+            min_conflicting_reviews: 2,
+            ..Default::default()
+        });
+        let approver = test_agent_with_elo(1300);
+        let rejector = test_agent_with_elo(900);
+
+        let moment = service
+            .check_drama(
+                7,
+                "proj",
+                "Add cache",
+                std::slice::from_ref(&approver),
+                std::slice::from_ref(&rejector),
+            )
+            .await
+            .unwrap()
+            .unwrap();
+
+        let snapshot = moment.as_drama_snapshot().unwrap();
+        assert_eq!(snapshot.pr_number, 7);
+        assert_eq!(snapshot.pr_title, "Add cache");
+        assert_eq!(snapshot.project, "proj");
+        assert_eq!(snapshot.approvers.len(), 1);
+        assert_eq!(snapshot.approvers[0].elo, 1300);
+        assert_eq!(snapshot.rejectors[0].elo, 900);
+    }
+
+    #[tokio::test]
+    async fn test_upset_snapshot_captures_loser_elo() {
+        let service = service_with_thresholds(ViralThresholds::default());
+        let winner = test_agent_with_elo(1000);
+        let loser = test_agent_with_elo(1500);
+        let issue_id = Uuid::new_v4();
+
+        let moment = service
+            .check_upset(
+                &winner,
+                std::slice::from_ref(&loser),
+                issue_id,
+                "Add cache",
+                "easy",
+            )
+            .await
+            .unwrap()
+            .unwrap();
+
+        let snapshot = moment.as_upset_snapshot().unwrap();
+        assert_eq!(snapshot.winner_elo, 1000);
+        assert_eq!(snapshot.losers.len(), 1);
+        assert_eq!(snapshot.losers[0].name, loser.name);
+        assert_eq!(snapshot.losers[0].elo, 1500);
+        assert_eq!(snapshot.elo_differential, 500);
+        assert_eq!(snapshot.issue_id, Some(issue_id));
+        assert_eq!(snapshot.engagement, Some(EngagementCounts::default()));
+    }
+
+    #[tokio::test]
+    async fn test_issue_upset_records_elo_gap_once() {
+        let service = service_with_thresholds(ViralThresholds::default());
+        let winner = test_agent_with_elo(1000);
+        let favourite = test_agent_with_elo(1600);
+        let weaker = test_agent_with_elo(900);
+        let competitors = [favourite.clone(), weaker];
+
+        let moment = service
+            .check_issue_upset(
+                "test-project",
+                7,
+                &winner,
+                &competitors,
+                "Fix #7: add cache",
+                Difficulty::Hard,
+            )
+            .await
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(moment.moment_type, MomentType::DavidVsGoliath);
+        assert_eq!(moment.agent_ids, vec![winner.id, favourite.id]);
+        let snapshot = moment.as_upset_snapshot().unwrap();
+        assert_eq!(snapshot.elo_differential, 600);
+        assert_eq!(snapshot.issue_difficulty, "hard");
+
+        // A redelivered merge event doesn't record the upset again
+        let again = service
+            .check_issue_upset(
+                "test-project",
+                7,
+                &winner,
+                &competitors,
+                "Fix #7: add cache",
+                Difficulty::Hard,
+            )
+            .await
+            .unwrap();
+        assert!(again.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_battle_snapshot_captures_racers_and_issue_engagement() {
+        let engagements = InMemoryEngagementRepository::new();
+        let issue_id = Uuid::new_v4();
+        engagements
+            .create(&NewEngagement {
+                agent_id: AgentId::new(),
+                target_type: TargetType::Issue,
+                target_id: issue_id,
+                engagement_type: EngagementType::Reaction,
+                reaction: Some(ReactionType::Fire),
+                body: None,
+                gitea_org: None,
+                gitea_repo: None,
+            })
+            .await
+            .unwrap();
+        let service = ViralMomentService::new(
+            Arc::new(InMemoryViralMomentRepository::new()),
+            Arc::new(engagements),
+        )
+        .with_thresholds(ViralThresholds {
+            min_battle_racers: 2,
+            ..Default::default()
+        });
+        let racers = vec![
+            (test_agent_with_elo(1000), "working".to_string()),
+            (test_agent_with_elo(1100), "submitted".to_string()),
+        ];
+
+        let moment = service
+            .track_battle(issue_id, "Add cache", racers)
+            .await
+            .unwrap()
+            .unwrap();
+
+        let snapshot = moment.as_battle_snapshot().unwrap();
+        assert_eq!(snapshot.issue_id, issue_id);
+        assert_eq!(snapshot.racers.len(), 2);
+        assert_eq!(snapshot.racers[1].agent_elo, 1100);
+        assert_eq!(snapshot.racers[1].status, "submitted");
+        assert_eq!(snapshot.engagement.unwrap().fire_count, 1);
+    }
+
+    #[tokio::test]
+    async fn test_list_moments_filters_by_type_and_promoted() {
+        let promoted_battle = moment(10, true);
+        let unpromoted_battle = moment(90, false);
+        let mut promoted_shame = moment(50, true);
+        promoted_shame.moment_type = MomentType::HallOfShame;
+        let mut hidden_battle = moment(70, true);
+        hidden_battle.hidden = true;
+        let (_, service) = service(
+            InMemoryViralMomentRepository::new()
+                .with_moment(promoted_battle.clone())
+                .with_moment(unpromoted_battle)
+                .with_moment(promoted_shame)
+                .with_moment(hidden_battle),
+            PromotionOverflow::DemoteWeakest,
+        );
+
+        let filter = MomentFilter {
+            moment_type: Some(MomentType::LiveBattle),
+            promoted: Some(true),
+            hidden: Some(false),
+            ..Default::default()
+        };
+        let (moments, total) = service.list_moments(&filter, 20, 0).await.unwrap();
+
+        assert_eq!(total, 1);
+        assert_eq!(moments.len(), 1);
+        assert_eq!(moments[0].id, promoted_battle.id);
+    }
+
+    #[tokio::test]
+    async fn test_list_moments_sorted_by_recency() {
+        let mut old = moment(100, false);
+        old.created_at = Utc::now() - chrono::Duration::hours(2);
+        let mut newer = moment(1, false);
+        newer.created_at = Utc::now() - chrono::Duration::hours(1);
+        let newest = moment(50, false);
+        let (_, service) = service(
+            InMemoryViralMomentRepository::new()
+                .with_moment(old.clone())
+                .with_moment(newer.clone())
+                .with_moment(newest.clone()),
+            PromotionOverflow::DemoteWeakest,
+        );
+
+        let filter = MomentFilter {
+            sort: MomentSort::Recent,
+            ..Default::default()
+        };
+        let (moments, total) = service.list_moments(&filter, 2, 0).await.unwrap();
+
+        assert_eq!(total, 3);
+        let ids: Vec<_> = moments.iter().map(|m| m.id).collect();
+        assert_eq!(ids, vec![newest.id, newer.id]);
+    }
 }
@@ -0,0 +1,188 @@
+//! Webhook delivery log service
+//!
+//! Records every received Gitea webhook with its outcome (signature failure,
+//! duplicate redelivery, handler error, or success) so operators can trace why
+//! an expected ELO change never happened. Also lets the webhook handler skip
+//! deliveries Gitea resends after they were already processed. Finished
+//! deliveries are deleted once they are older than the retention window.
+
+use std::sync::Arc;
+
+use chrono::{DateTime, Duration, Utc};
+
+use crate::domain::entities::{DeliveryOutcome, NewWebhookDelivery, WebhookDelivery};
+use crate::domain::ports::WebhookDeliveryRepository;
+use crate::error::AppError;
+
+/// Largest number of deliveries returned by one listing
+pub const MAX_RECENT_DELIVERIES: i64 = 100;
+
+/// Default days a finished delivery is kept in the log
+pub const DEFAULT_DELIVERY_RETENTION_DAYS: i64 = 30;
+
+/// Service for logging and inspecting webhook deliveries
+pub struct WebhookDeliveryService<WDR>
+where
+    WDR: WebhookDeliveryRepository,
+{
+    deliveries: Arc<WDR>,
+    retention: Option<Duration>,
+}
+
+impl<WDR> WebhookDeliveryService<WDR>
+where
+    WDR: WebhookDeliveryRepository,
+{
+    pub fn new(deliveries: Arc<WDR>) -> Self {
+        Self {
+            deliveries,
+            retention: None,
+        }
+    }
+
+    /// Delete finished deliveries older than `retention` (kept forever when None)
+    pub fn with_retention(mut self, retention: Option<Duration>) -> Self {
+        self.retention = retention;
+        self
+    }
+
+    /// Whether old deliveries are deleted at all
+    pub fn prunes(&self) -> bool {
+        self.retention.is_some()
+    }
+
+    /// Whether this delivery id was already processed successfully
+    /// (deliveries without an id are never treated as duplicates)
+    pub async fn is_duplicate(&self, delivery_id: Option<&str>) -> Result<bool, AppError> {
+        match delivery_id {
+            Some(id) => Ok(self.deliveries.is_processed(id).await?),
+            None => Ok(false),
+        }
+    }
+
+    /// Log a received webhook and its outcome
+    pub async fn record(&self, delivery: &NewWebhookDelivery) -> Result<WebhookDelivery, AppError> {
+        Ok(self.deliveries.create(delivery).await?)
+    }
+
+    /// Record how processing of a queued delivery ended
+    pub async fn complete(
+        &self,
+        delivery: &WebhookDelivery,
+        outcome: DeliveryOutcome,
+        error: Option<String>,
+    ) -> Result<(), AppError> {
+        Ok(self
+            .deliveries
+            .complete(&delivery.id, outcome, error)
+            .await?)
+    }
+
+    /// Deliveries accepted before a restart that were never processed
+    pub async fn queued(&self, limit: i64) -> Result<Vec<WebhookDelivery>, AppError> {
+        Ok(self.deliveries.find_queued(limit).await?)
+    }
+
+    /// Most recent deliveries, newest first (`limit` is clamped to 1..=100)
+    pub async fn recent(&self, limit: i64) -> Result<Vec<WebhookDelivery>, AppError> {
+        let limit = limit.clamp(1, MAX_RECENT_DELIVERIES);
+        Ok(self.deliveries.find_recent(limit).await?)
+    }
+
+    /// Delete finished deliveries received before the retention window.
+    /// Deliveries still queued are kept. Returns how many were deleted.
+    pub async fn prune(&self, now: DateTime<Utc>) -> Result<u64, AppError> {
+        let Some(retention) = self.retention else {
+            return Ok(0);
+        };
+        Ok(self
+            .deliveries
+            .delete_received_before(now - retention)
+            .await?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::InMemoryWebhookDeliveryRepository;
+
+    fn service() -> WebhookDeliveryService<InMemoryWebhookDeliveryRepository> {
+        WebhookDeliveryService::new(Arc::new(InMemoryWebhookDeliveryRepository::new()))
+    }
+
+    #[tokio::test]
+    async fn processed_webhook_appears_in_recent_list() {
+        let service = service();
+        let delivery_id = Some("d7a1c0de-0001".to_string());
+        assert!(!service.is_duplicate(delivery_id.as_deref()).await.unwrap());
+
+        let mut delivery = NewWebhookDelivery::received(delivery_id.clone(), "pull_request");
+        delivery.repo = Some("org/test-repo".to_string());
+        delivery.action = Some("closed".to_string());
+        service.record(&delivery).await.unwrap();
+
+        let recent = service.recent(10).await.unwrap();
+        assert_eq!(recent.len(), 1);
+        assert_eq!(recent[0].delivery_id, delivery_id);
+        assert_eq!(recent[0].event_type, "pull_request");
+        assert_eq!(recent[0].repo.as_deref(), Some("org/test-repo"));
+        assert_eq!(recent[0].outcome, DeliveryOutcome::Success);
+        assert!(recent[0].error.is_none());
+
+        // Gitea resends with the same delivery id
+        assert!(service.is_duplicate(delivery_id.as_deref()).await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn failed_deliveries_are_not_duplicates_and_list_newest_first() {
+        let service = service();
+
+        let mut failed = NewWebhookDelivery::received(Some("retry-me".to_string()), "push");
+        failed.fail(DeliveryOutcome::Failed, "Database error: timeout");
+        service.record(&failed).await.unwrap();
+
+        let mut unsigned = NewWebhookDelivery::received(None, "push");
+        unsigned.fail(DeliveryOutcome::SignatureFailed, "Unauthorized");
+        service.record(&unsigned).await.unwrap();
+
+        // A failed attempt should still be retried when redelivered
+        assert!(!service.is_duplicate(Some("retry-me")).await.unwrap());
+        assert!(!service.is_duplicate(None).await.unwrap());
+
+        let recent = service.recent(0).await.unwrap();
+        assert_eq!(recent.len(), 1);
+        assert_eq!(recent[0].outcome, DeliveryOutcome::SignatureFailed);
+
+        let recent = service.recent(10).await.unwrap();
+        assert_eq!(recent[1].outcome, DeliveryOutcome::Failed);
+        assert_eq!(recent[1].error.as_deref(), Some("Database error: timeout"));
+    }
+
+    #[tokio::test]
+    async fn prune_deletes_old_finished_deliveries_but_keeps_queued_ones() {
+        let service = service().with_retention(Some(Duration::days(30)));
+
+        let done = NewWebhookDelivery::received(Some("done".to_string()), "push");
+        service.record(&done).await.unwrap();
+        let mut waiting = NewWebhookDelivery::received(Some("waiting".to_string()), "push");
+        waiting.queue("{}");
+        service.record(&waiting).await.unwrap();
+
+        // Nothing is old enough yet
+        assert_eq!(service.prune(Utc::now()).await.unwrap(), 0);
+
+        let later = Utc::now() + Duration::days(31);
+        assert_eq!(service.prune(later).await.unwrap(), 1);
+        let recent = service.recent(10).await.unwrap();
+        assert_eq!(recent.len(), 1);
+        assert_eq!(recent[0].outcome, DeliveryOutcome::Queued);
+
+        // Without a retention window nothing is deleted
+        let keep_all = service.with_retention(None);
+        let mut finished = NewWebhookDelivery::received(None, "push");
+        finished.fail(DeliveryOutcome::InvalidPayload, "bad json");
+        keep_all.record(&finished).await.unwrap();
+        assert_eq!(keep_all.prune(later).await.unwrap(), 0);
+    }
+}
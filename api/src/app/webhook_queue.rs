@@ -0,0 +1,518 @@
+//! Webhook processing queue
+//!
+//! Lets the webhook endpoint acknowledge a delivery as soon as it has been
+//! validated instead of holding Gitea's connection open while ELO, drama, and
+//! ticket updates run. Accepted deliveries are written to the delivery log
+//! (with their body) before they are acknowledged, then wait in a bounded
+//! in-memory queue and are drained in batches by a pool of workers. Anything
+//! still queued when the process stops is picked up again on startup.
+//!
+//! Deliveries that share an ordering key (the PR or issue they are about, or
+//! else the delivery id) run one after another in arrival order, so events for
+//! one PR never race and a Gitea redelivery waits for the original to finish
+//! before it is checked for duplication.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use serde::de::DeserializeOwned;
+use tokio::sync::{mpsc, Semaphore};
+use tokio::task::JoinHandle;
+
+use crate::app::WebhookDeliveryService;
+use crate::domain::entities::{DeliveryOutcome, WebhookDelivery};
+use crate::domain::ports::WebhookDeliveryRepository;
+use crate::error::AppError;
+
+/// Default number of deliveries processed concurrently
+pub const DEFAULT_WEBHOOK_WORKERS: usize = 4;
+
+/// Default number of accepted deliveries that may wait for a worker
+pub const DEFAULT_WEBHOOK_QUEUE_CAPACITY: usize = 1000;
+
+/// Default number of deliveries taken off the queue per wake-up
+pub const DEFAULT_WEBHOOK_BATCH_SIZE: usize = 16;
+
+/// Most stored deliveries re-queued on startup
+const MAX_REQUEUED_DELIVERIES: i64 = 10_000;
+
+/// A validated delivery waiting to be processed
+pub struct WebhookJob<P> {
+    /// Log entry for the delivery; its outcome is filled in by the worker
+    pub delivery: WebhookDelivery,
+    pub payload: P,
+    /// Deliveries with the same key are processed in arrival order
+    pub ordering_key: Option<String>,
+}
+
+impl<P> WebhookJob<P> {
+    /// The key this job is serialized on (falls back to the delivery id)
+    fn serial_key(&self) -> Option<&str> {
+        self.ordering_key
+            .as_deref()
+            .or(self.delivery.delivery_id.as_deref())
+    }
+}
+
+/// Applies a webhook event to the platform
+#[async_trait]
+pub trait WebhookEventHandler<P>: Send + Sync + 'static {
+    async fn handle(&self, event_type: &str, payload: &P) -> Result<(), AppError>;
+}
+
+/// Sending half of the queue, held by the webhook endpoint
+pub struct WebhookQueue<P> {
+    sender: mpsc::Sender<WebhookJob<P>>,
+}
+
+/// Receiving half of the queue, consumed by [`WebhookWorkers::spawn`]
+pub struct WebhookWorkers<P> {
+    receiver: mpsc::Receiver<WebhookJob<P>>,
+    workers: usize,
+    batch_size: usize,
+}
+
+impl<P: Send + Sync + 'static> WebhookQueue<P> {
+    /// A queue holding at most `capacity` deliveries, drained `batch_size` at a
+    /// time by `workers` concurrent workers (each at least 1)
+    pub fn new(
+        capacity: usize,
+        workers: usize,
+        batch_size: usize,
+    ) -> (WebhookQueue<P>, WebhookWorkers<P>) {
+        let (sender, receiver) = mpsc::channel(capacity.max(1));
+        (
+            WebhookQueue { sender },
+            WebhookWorkers {
+                receiver,
+                workers: workers.max(1),
+                batch_size: batch_size.max(1),
+            },
+        )
+    }
+
+    /// Queue a delivery without waiting for it to be processed.
+    /// Fails when the queue is full so Gitea retries the delivery later.
+    pub fn enqueue(&self, job: WebhookJob<P>) -> Result<(), AppError> {
+        self.sender.try_send(job).map_err(|e| match e {
+            mpsc::error::TrySendError::Full(_) => {
+                AppError::Unavailable("Webhook queue is full".to_string())
+            }
+            mpsc::error::TrySendError::Closed(_) => {
+                AppError::Internal("Webhook workers have stopped".to_string())
+            }
+        })
+    }
+
+    /// Queue every delivery that was accepted but never processed, oldest first.
+    ///
+    /// Call once on startup, after the workers are running; this waits for
+    /// room in the queue rather than failing. Stored bodies that no longer
+    /// parse are marked invalid. Returns the number of deliveries re-queued.
+    pub async fn requeue_stored<WDR>(
+        &self,
+        deliveries: &WebhookDeliveryService<WDR>,
+        ordering_key: impl Fn(&P) -> Option<String>,
+    ) -> Result<usize, AppError>
+    where
+        P: DeserializeOwned,
+        WDR: WebhookDeliveryRepository,
+    {
+        let mut requeued = 0;
+        for delivery in deliveries.queued(MAX_REQUEUED_DELIVERIES).await? {
+            let parsed = delivery
+                .payload
+                .as_deref()
+                .ok_or_else(|| "Stored delivery has no body".to_string())
+                .and_then(|body| serde_json::from_str::<P>(body).map_err(|e| e.to_string()));
+            let payload = match parsed {
+                Ok(payload) => payload,
+                Err(e) => {
+                    deliveries
+                        .complete(&delivery, DeliveryOutcome::InvalidPayload, Some(e))
+                        .await?;
+                    continue;
+                }
+            };
+
+            let job = WebhookJob {
+                ordering_key: ordering_key(&payload),
+                delivery,
+                payload,
+            };
+            self.sender
+                .send(job)
+                .await
+                .map_err(|_| AppError::Internal("Webhook workers have stopped".to_string()))?;
+            requeued += 1;
+        }
+        Ok(requeued)
+    }
+}
+
+impl<P: Send + Sync + 'static> WebhookWorkers<P> {
+    /// Start draining the queue in the background
+    pub fn spawn<H, WDR>(
+        self,
+        handler: Arc<H>,
+        deliveries: Arc<WebhookDeliveryService<WDR>>,
+    ) -> tokio::task::JoinHandle<()>
+    where
+        H: WebhookEventHandler<P>,
+        WDR: WebhookDeliveryRepository + 'static,
+    {
+        tokio::spawn(self.run(handler, deliveries))
+    }
+
+    async fn run<H, WDR>(mut self, handler: Arc<H>, deliveries: Arc<WebhookDeliveryService<WDR>>)
+    where
+        H: WebhookEventHandler<P>,
+        WDR: WebhookDeliveryRepository + 'static,
+    {
+        let limiter = Arc::new(Semaphore::new(self.workers));
+        // Last job started for each ordering key; the next one waits for it
+        let mut tails: HashMap<String, JoinHandle<()>> = HashMap::new();
+        let mut batch = Vec::with_capacity(self.batch_size);
+
+        while self.receiver.recv_many(&mut batch, self.batch_size).await > 0 {
+            tails.retain(|_, task| !task.is_finished());
+            for job in batch.drain(..) {
+                let key = job.serial_key().map(str::to_string);
+                let previous = key.as_ref().and_then(|key| tails.remove(key));
+                let limiter = limiter.clone();
+                let handler = handler.clone();
+                let deliveries = deliveries.clone();
+                let task = tokio::spawn(async move {
+                    if let Some(previous) = previous {
+                        // A panicked predecessor shouldn't hold up the rest
+                        if let Err(e) = previous.await {
+                            tracing::error!(error = %e, "Webhook worker panicked");
+                        }
+                    }
+                    // Only take a worker once it's this job's turn, so jobs
+                    // waiting on a predecessor don't starve other keys
+                    let permit = limiter
+                        .acquire_owned()
+                        .await
+                        .expect("webhook worker limiter is never closed");
+                    process(job, handler.as_ref(), &deliveries).await;
+                    drop(permit);
+                });
+                if let Some(key) = key {
+                    tails.insert(key, task);
+                }
+            }
+        }
+    }
+}
+
+/// Process one delivery and log its outcome
+async fn process<P, H, WDR>(
+    job: WebhookJob<P>,
+    handler: &H,
+    deliveries: &WebhookDeliveryService<WDR>,
+) where
+    H: WebhookEventHandler<P>,
+    WDR: WebhookDeliveryRepository,
+{
+    let WebhookJob {
+        delivery, payload, ..
+    } = job;
+
+    // Gitea redeliveries reuse the delivery id; don't award ELO twice
+    let duplicate = deliveries
+        .is_duplicate(delivery.delivery_id.as_deref())
+        .await
+        .unwrap_or_else(|e| {
+            tracing::warn!(error = %e, "Failed to check for duplicate webhook delivery");
+            false
+        });
+
+    let (outcome, error) = if duplicate {
+        tracing::info!(delivery_id = ?delivery.delivery_id, "Skipping already processed webhook delivery");
+        (DeliveryOutcome::Duplicate, None)
+    } else {
+        match handler.handle(&delivery.event_type, &payload).await {
+            Ok(()) => (DeliveryOutcome::Success, None),
+            Err(e) => {
+                tracing::warn!(
+                    error = %e,
+                    delivery_id = ?delivery.delivery_id,
+                    event_type = %delivery.event_type,
+                    "Failed to process webhook"
+                );
+                (DeliveryOutcome::Failed, Some(e.to_string()))
+            }
+        }
+    };
+
+    if let Err(e) = deliveries.complete(&delivery, outcome, error).await {
+        tracing::warn!(error = %e, "Failed to log webhook delivery");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Mutex;
+    use std::time::Duration;
+
+    use tokio::sync::Notify;
+
+    use crate::domain::entities::NewWebhookDelivery;
+    use crate::test_utils::InMemoryWebhookDeliveryRepository;
+
+    type Deliveries = WebhookDeliveryService<InMemoryWebhookDeliveryRepository>;
+
+    fn deliveries() -> Arc<Deliveries> {
+        Arc::new(WebhookDeliveryService::new(Arc::new(
+            InMemoryWebhookDeliveryRepository::new(),
+        )))
+    }
+
+    /// Counts handled events, blocking each one until released
+    struct GatedHandler {
+        handled: AtomicUsize,
+        release: Notify,
+    }
+
+    #[async_trait]
+    impl WebhookEventHandler<String> for GatedHandler {
+        async fn handle(&self, _event_type: &str, _payload: &String) -> Result<(), AppError> {
+            self.release.notified().await;
+            self.handled.fetch_add(1, Ordering::SeqCst);
+            Ok(())
+        }
+    }
+
+    /// Records payloads in the order they finish; "slow" payloads take a while
+    /// and "fail" payloads fail the first time they are seen
+    #[derive(Default)]
+    struct RecordingHandler {
+        finished: Mutex<Vec<String>>,
+    }
+
+    #[async_trait]
+    impl WebhookEventHandler<String> for RecordingHandler {
+        async fn handle(&self, _event_type: &str, payload: &String) -> Result<(), AppError> {
+            if payload.starts_with("slow") {
+                tokio::time::sleep(Duration::from_millis(50)).await;
+            }
+            let mut finished = self.finished.lock().unwrap();
+            let seen = finished.contains(payload);
+            finished.push(payload.clone());
+            if payload.starts_with("fail") && !seen {
+                return Err(AppError::Internal("transient failure".to_string()));
+            }
+            Ok(())
+        }
+    }
+
+    /// Record a delivery as accepted and build its job, like the endpoint does
+    async fn job(
+        deliveries: &Deliveries,
+        delivery_id: &str,
+        payload: &str,
+        ordering_key: Option<&str>,
+    ) -> WebhookJob<String> {
+        let mut delivery = NewWebhookDelivery::received(Some(delivery_id.to_string()), "push");
+        delivery.queue(serde_json::to_string(payload).unwrap());
+        WebhookJob {
+            delivery: deliveries.record(&delivery).await.unwrap(),
+            payload: payload.to_string(),
+            ordering_key: ordering_key.map(str::to_string),
+        }
+    }
+
+    /// Wait until no delivery is still queued, then return them newest first
+    async fn settled(deliveries: &Deliveries, count: usize) -> Vec<WebhookDelivery> {
+        tokio::time::timeout(Duration::from_secs(5), async {
+            loop {
+                let recent = deliveries.recent(100).await.unwrap();
+                if recent.len() == count
+                    && recent.iter().all(|d| d.outcome != DeliveryOutcome::Queued)
+                {
+                    return recent;
+                }
+                tokio::time::sleep(Duration::from_millis(5)).await;
+            }
+        })
+        .await
+        .expect("deliveries were not processed")
+    }
+
+    #[tokio::test]
+    async fn enqueue_returns_before_processing_and_redelivery_runs_once() {
+        let deliveries = deliveries();
+        let handler = Arc::new(GatedHandler {
+            handled: AtomicUsize::new(0),
+            release: Notify::new(),
+        });
+        let (queue, workers) = WebhookQueue::new(10, 4, 16);
+        workers.spawn(handler.clone(), deliveries.clone());
+
+        // Both enqueues return while the handler is still blocked
+        queue
+            .enqueue(job(&deliveries, "delivery-1", "a", None).await)
+            .unwrap();
+        queue
+            .enqueue(job(&deliveries, "delivery-1", "a", None).await)
+            .unwrap();
+        assert_eq!(handler.handled.load(Ordering::SeqCst), 0);
+
+        handler.release.notify_one();
+        let recent = settled(&deliveries, 2).await;
+
+        assert_eq!(handler.handled.load(Ordering::SeqCst), 1);
+        let mut outcomes: Vec<_> = recent.iter().map(|d| d.outcome.to_string()).collect();
+        outcomes.sort();
+        assert_eq!(outcomes, vec!["duplicate", "success"]);
+        // Processed deliveries no longer keep their body
+        assert!(recent.iter().all(|d| d.payload.is_none()));
+    }
+
+    #[tokio::test]
+    async fn events_for_the_same_pr_run_in_arrival_order() {
+        let deliveries = deliveries();
+        let handler = Arc::new(RecordingHandler::default());
+        let (queue, workers) = WebhookQueue::new(10, 4, 16);
+        workers.spawn(handler.clone(), deliveries.clone());
+
+        queue
+            .enqueue(job(&deliveries, "d-1", "slow-opened", Some("org/repo#1")).await)
+            .unwrap();
+        queue
+            .enqueue(job(&deliveries, "d-2", "closed", Some("org/repo#1")).await)
+            .unwrap();
+        settled(&deliveries, 2).await;
+
+        assert_eq!(
+            *handler.finished.lock().unwrap(),
+            vec!["slow-opened".to_string(), "closed".to_string()]
+        );
+    }
+
+    #[tokio::test]
+    async fn jobs_waiting_on_their_key_do_not_hold_a_worker() {
+        let deliveries = deliveries();
+        let handler = Arc::new(RecordingHandler::default());
+        let (queue, workers) = WebhookQueue::new(10, 2, 16);
+        workers.spawn(handler.clone(), deliveries.clone());
+
+        // "closed" waits for "slow-opened"; "pushed" has a worker free meanwhile
+        queue
+            .enqueue(job(&deliveries, "d-1", "slow-opened", Some("org/repo#1")).await)
+            .unwrap();
+        queue
+            .enqueue(job(&deliveries, "d-2", "closed", Some("org/repo#1")).await)
+            .unwrap();
+        queue
+            .enqueue(job(&deliveries, "d-3", "pushed", Some("org/repo#2")).await)
+            .unwrap();
+        settled(&deliveries, 3).await;
+
+        assert_eq!(
+            *handler.finished.lock().unwrap(),
+            vec![
+                "pushed".to_string(),
+                "slow-opened".to_string(),
+                "closed".to_string()
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn redelivery_of_a_failed_in_flight_delivery_is_retried() {
+        let deliveries = deliveries();
+        let handler = Arc::new(RecordingHandler::default());
+        let (queue, workers) = WebhookQueue::new(10, 4, 16);
+        workers.spawn(handler.clone(), deliveries.clone());
+
+        // Gitea resends while the original is still running; the original fails
+        queue
+            .enqueue(job(&deliveries, "d-1", "fail-merged", None).await)
+            .unwrap();
+        queue
+            .enqueue(job(&deliveries, "d-1", "fail-merged", None).await)
+            .unwrap();
+        let recent = settled(&deliveries, 2).await;
+
+        assert_eq!(handler.finished.lock().unwrap().len(), 2);
+        let mut outcomes: Vec<_> = recent.iter().map(|d| d.outcome.to_string()).collect();
+        outcomes.sort();
+        assert_eq!(outcomes, vec!["failed", "success"]);
+    }
+
+    #[tokio::test]
+    async fn only_a_successful_delivery_makes_redeliveries_duplicates() {
+        let deliveries = deliveries();
+        let handler = Arc::new(RecordingHandler::default());
+        let (queue, workers) = WebhookQueue::new(10, 4, 16);
+        workers.spawn(handler.clone(), deliveries.clone());
+
+        // The first attempt fails and is logged as such, not as a success
+        queue
+            .enqueue(job(&deliveries, "d-1", "fail-closed", None).await)
+            .unwrap();
+        let recent = settled(&deliveries, 1).await;
+        assert_eq!(recent[0].outcome, DeliveryOutcome::Failed);
+        assert_eq!(
+            recent[0].error.as_deref(),
+            Some("Internal server error: transient failure")
+        );
+
+        // Redelivered from Gitea later: processed again, and this time it works
+        queue
+            .enqueue(job(&deliveries, "d-1", "fail-closed", None).await)
+            .unwrap();
+        let recent = settled(&deliveries, 2).await;
+        assert_eq!(recent[0].outcome, DeliveryOutcome::Success);
+
+        // Only now is a further redelivery skipped
+        queue
+            .enqueue(job(&deliveries, "d-1", "fail-closed", None).await)
+            .unwrap();
+        let recent = settled(&deliveries, 3).await;
+        assert_eq!(recent[0].outcome, DeliveryOutcome::Duplicate);
+        assert_eq!(handler.finished.lock().unwrap().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn stored_deliveries_are_requeued_after_a_restart() {
+        let deliveries = deliveries();
+        // Accepted before the restart but never processed
+        job(&deliveries, "d-1", "opened", None).await;
+        let mut broken = NewWebhookDelivery::received(Some("d-2".to_string()), "push");
+        broken.queue("not json");
+        deliveries.record(&broken).await.unwrap();
+
+        let handler = Arc::new(RecordingHandler::default());
+        let (queue, workers) = WebhookQueue::<String>::new(10, 4, 16);
+        workers.spawn(handler.clone(), deliveries.clone());
+        let requeued = queue.requeue_stored(&deliveries, |_| None).await.unwrap();
+        let recent = settled(&deliveries, 2).await;
+
+        assert_eq!(requeued, 1);
+        assert_eq!(
+            *handler.finished.lock().unwrap(),
+            vec!["opened".to_string()]
+        );
+        assert_eq!(recent[0].outcome, DeliveryOutcome::InvalidPayload);
+        assert_eq!(recent[1].outcome, DeliveryOutcome::Success);
+    }
+
+    #[tokio::test]
+    async fn enqueue_fails_when_queue_is_full() {
+        let deliveries = deliveries();
+        let (queue, _workers) = WebhookQueue::new(1, 1, 1);
+
+        queue
+            .enqueue(job(&deliveries, "first", "a", None).await)
+            .unwrap();
+        let result = queue.enqueue(job(&deliveries, "second", "b", None).await);
+
+        assert!(matches!(result, Err(AppError::Unavailable(_))));
+    }
+}
@@ -8,19 +8,31 @@
 //! Philosophy: Gitea is the source of truth for git operations.
 //! We only track ticket assignments locally.
 
+use std::collections::HashMap;
 use std::sync::Arc;
 
-use crate::domain::entities::{Agent, Project, Ticket, TicketStatus};
+use crate::app::reactive_elo_service::{EloChangeResult, ReactiveEloService};
+use crate::domain::entities::{
+    Agent, AgentId, MemberRole, Project, Ticket, TicketId, TicketStatus, MAX_REQUIRED_APPROVALS,
+};
 use crate::domain::ports::{
-    GiteaClient, GiteaPRReview, GiteaPullRequest, ProjectRepository, TicketRepository,
+    AgentRepository, AgentReviewRepository, CodeContributionRepository, EloEventRepository,
+    GiteaClient, GiteaPRReview, GiteaPullRequest, GiteaStatus, ProjectRepository, TicketRepository,
 };
-use crate::error::{AppError, DomainError};
+use crate::error::{AppError, DomainError, GiteaError};
+
+/// Commits a PR's branch may trail its base before the status warns it needs a rebase
+pub const BEHIND_BASE_WARNING_COMMITS: i64 = 10;
 
 /// Result of assigning a ticket
 #[derive(Debug)]
 pub struct AssignResult {
     pub ticket: Ticket,
     pub message: String,
+    /// SSH URL to clone the project's repo from
+    pub clone_url: String,
+    /// Working branch for the ticket, created in Gitea off the default branch
+    pub branch: String,
 }
 
 /// Result of submitting a PR
@@ -37,6 +49,74 @@ pub struct ReviewResult {
     pub message: String,
 }
 
+/// Combined CI status of a PR's head commit
+#[derive(Debug)]
+pub struct PrCiStatus {
+    pub sha: String,
+    /// Worst state across all checks
+    pub state: String,
+    pub statuses: Vec<GiteaStatus>,
+    /// Commits on the head branch that base doesn't have (None if the comparison failed)
+    pub ahead_by: Option<i64>,
+    /// Commits on base that the head branch doesn't have (None if the comparison failed)
+    pub behind_by: Option<i64>,
+    /// Set when the branch is far enough behind base that it should be rebased
+    pub warning: Option<String>,
+}
+
+/// A PR's reviews tallied by state
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ReviewSummary {
+    /// Reviewers whose latest verdict is an approval
+    pub approved: usize,
+    /// Reviewers whose latest verdict still requests changes
+    pub changes_requested: usize,
+    pub commented: usize,
+    pub pending: usize,
+    /// Enough approvals and no outstanding change requests
+    pub approvable: bool,
+}
+
+/// Tally a PR's reviews. Only each reviewer's latest approve/request-changes
+/// verdict counts, so a reviewer who approves after requesting changes no
+/// longer blocks the PR.
+pub fn summarize_reviews(reviews: &[GiteaPRReview], required_approvals: usize) -> ReviewSummary {
+    let mut summary = ReviewSummary::default();
+    let mut verdicts: HashMap<&str, (i64, bool)> = HashMap::new();
+
+    for review in reviews {
+        let approved = match review.state.to_uppercase().as_str() {
+            "APPROVED" => true,
+            "REQUEST_CHANGES" | "CHANGES_REQUESTED" => false,
+            "COMMENT" => {
+                summary.commented += 1;
+                continue;
+            }
+            _ => {
+                summary.pending += 1;
+                continue;
+            }
+        };
+        let latest = verdicts
+            .entry(review.user.login.as_str())
+            .or_insert((review.id, approved));
+        if review.id >= latest.0 {
+            *latest = (review.id, approved);
+        }
+    }
+
+    for (_, approved) in verdicts.values() {
+        if *approved {
+            summary.approved += 1;
+        } else {
+            summary.changes_requested += 1;
+        }
+    }
+    summary.approvable =
+        summary.changes_requested == 0 && summary.approved >= required_approvals.max(1);
+    summary
+}
+
 /// Current work status for an agent
 #[derive(Debug)]
 pub struct WorkStatus {
@@ -55,6 +135,10 @@ where
     projects: Arc<PR>,
     /// Gitea client for git operations (exposed for handlers that need to query PRs)
     pub gitea: Arc<GC>,
+    /// Most open/in-progress tickets an agent may hold at once (unlimited when unset)
+    max_open_claims: Option<usize>,
+    /// Approvals a PR needs before it can be merged (0 disables the check)
+    required_approvals: usize,
 }
 
 impl<TR, PR, GC> WorkLoopService<TR, PR, GC>
@@ -68,9 +152,24 @@ where
             tickets,
             projects,
             gitea,
+            max_open_claims: None,
+            required_approvals: 0,
         }
     }
 
+    /// Require this many approvals, and no outstanding change requests, before merging
+    pub fn with_required_approvals(mut self, count: usize) -> Self {
+        self.required_approvals = count;
+        self
+    }
+
+    /// Limit how many open tickets one agent can hold; project owners and
+    /// maintainers are exempt when claiming in their own projects
+    pub fn with_max_open_claims(mut self, max: Option<usize>) -> Self {
+        self.max_open_claims = max;
+        self
+    }
+
     /// Assign a ticket to an agent (work-on command)
     pub async fn assign_ticket(
         &self,
@@ -101,6 +200,8 @@ where
             )));
         }
 
+        self.check_open_claim_limit(agent, project).await?;
+
         // Assign the ticket
         self.tickets.assign(&ticket.id, &agent.id).await?;
         self.tickets
@@ -115,27 +216,95 @@ where
 
         let ticket_prefix = ticket.id.0.to_string();
         let ticket_prefix = ticket_prefix.split('-').next().unwrap_or("fix");
+        let clone_url = format!("git@gitea:{}/{}.git", project.gitea_org, project.gitea_repo);
+        let branch = format!("fix/{}", ticket_prefix);
+
+        // The branch is a convenience; the claim stands even if Gitea refuses it
+        match self
+            .gitea
+            .create_branch(&project.gitea_org, &project.gitea_repo, &branch, None)
+            .await
+        {
+            Ok(_) | Err(GiteaError::Api { status: 409, .. }) => {}
+            Err(e) => tracing::warn!(
+                "Failed to create branch {} in {}/{}: {}",
+                branch,
+                project.gitea_org,
+                project.gitea_repo,
+                e
+            ),
+        }
+
         let message = format!(
             "You are now working on: {}\n\n\
             Clone the repo and create a branch:\n\
             ```\n\
-            git clone git@gitea:{}/{}.git\n\
-            git checkout -b fix/{}\n\
+            git clone {}\n\
+            git checkout -b {}\n\
             ```\n\n\
             When ready, push your branch and run `submit <branch-name>`",
-            ticket.title, project.gitea_org, project.gitea_repo, ticket_prefix
+            ticket.title, clone_url, branch
         );
 
         Ok(AssignResult {
             ticket: updated,
             message,
+            clone_url,
+            branch,
         })
     }
 
-    /// Abandon current ticket assignment
-    pub async fn abandon_ticket(&self, agent: &Agent) -> Result<String, AppError> {
-        // Find tickets assigned to this agent
-        let tickets = self.tickets.find_open_by_agent(&agent.id).await?;
+    /// Reject a new claim if the agent already holds the maximum number of open tickets
+    async fn check_open_claim_limit(
+        &self,
+        agent: &Agent,
+        project: &Project,
+    ) -> Result<(), AppError> {
+        let Some(max) = self.max_open_claims else {
+            return Ok(());
+        };
+
+        let open = self.tickets.find_open_by_agent(&agent.id).await?.len();
+        if open < max {
+            return Ok(());
+        }
+
+        let role = self
+            .projects
+            .get_member_role(&project.id, &agent.id)
+            .await?;
+        if matches!(role, Some(MemberRole::Owner) | Some(MemberRole::Maintainer)) {
+            return Ok(());
+        }
+
+        Err(AppError::BadRequest(format!(
+            "You already have {} open tickets (limit {}). Finish or abandon one before claiming another",
+            open, max
+        )))
+    }
+
+    /// Abandon every ticket the agent has claimed, paying the abandon penalty
+    /// for each (the feed's `abandon` command)
+    pub async fn abandon_ticket<AR, CCR, ARR, EER, EPR>(
+        &self,
+        elo: &ReactiveEloService<AR, CCR, ARR, EER, EPR>,
+        agent: &Agent,
+        gitea_token: Option<&str>,
+    ) -> Result<String, AppError>
+    where
+        AR: AgentRepository,
+        CCR: CodeContributionRepository,
+        ARR: AgentReviewRepository,
+        EER: EloEventRepository,
+        EPR: ProjectRepository,
+    {
+        let tickets: Vec<Ticket> = self
+            .tickets
+            .find_open_by_agent(&agent.id)
+            .await?
+            .into_iter()
+            .filter(|t| t.status.is_claimed())
+            .collect();
 
         if tickets.is_empty() {
             return Err(AppError::BadRequest(
@@ -144,21 +313,110 @@ where
         }
 
         let mut abandoned = Vec::new();
+        let mut elo_change = 0;
         for ticket in tickets {
-            self.tickets.unassign(&ticket.id).await?;
-            self.tickets
-                .update_status(&ticket.id, TicketStatus::Open)
-                .await?;
+            let (ticket, result) = self.abandon_claim(elo, agent, ticket, gitea_token).await?;
+            elo_change += result.delta;
             abandoned.push(ticket.title);
         }
 
         Ok(format!(
-            "Abandoned {} ticket(s): {}",
+            "Abandoned {} ticket(s): {} ({:+} ELO)",
             abandoned.len(),
-            abandoned.join(", ")
+            abandoned.join(", "),
+            elo_change
         ))
     }
 
+    /// Give up a single claimed ticket. Only the current assignee may abandon
+    /// it, and only while it is claimed.
+    pub async fn abandon_assigned_ticket<AR, CCR, ARR, EER, EPR>(
+        &self,
+        elo: &ReactiveEloService<AR, CCR, ARR, EER, EPR>,
+        agent: &Agent,
+        ticket_id: &TicketId,
+        gitea_token: Option<&str>,
+    ) -> Result<(Ticket, EloChangeResult), AppError>
+    where
+        AR: AgentRepository,
+        CCR: CodeContributionRepository,
+        ARR: AgentReviewRepository,
+        EER: EloEventRepository,
+        EPR: ProjectRepository,
+    {
+        let ticket = self
+            .tickets
+            .find_by_id(ticket_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound(format!("Ticket {} not found", ticket_id)))?;
+
+        if ticket.assigned_to != Some(agent.id) {
+            return Err(DomainError::Forbidden(
+                "Only the ticket's assignee can abandon it".to_string(),
+            )
+            .into());
+        }
+        if !ticket.status.is_claimed() {
+            return Err(AppError::BadRequest(format!(
+                "Ticket '{}' is {} and can't be abandoned",
+                ticket.title, ticket.status
+            )));
+        }
+
+        self.abandon_claim(elo, agent, ticket, gitea_token).await
+    }
+
+    /// Unassign and reopen a claimed ticket, leave a note on the Gitea issue,
+    /// and charge the abandon penalty. Every way of abandoning goes through here.
+    async fn abandon_claim<AR, CCR, ARR, EER, EPR>(
+        &self,
+        elo: &ReactiveEloService<AR, CCR, ARR, EER, EPR>,
+        agent: &Agent,
+        mut ticket: Ticket,
+        gitea_token: Option<&str>,
+    ) -> Result<(Ticket, EloChangeResult), AppError>
+    where
+        AR: AgentRepository,
+        CCR: CodeContributionRepository,
+        ARR: AgentReviewRepository,
+        EER: EloEventRepository,
+        EPR: ProjectRepository,
+    {
+        self.tickets.unassign(&ticket.id).await?;
+        self.tickets
+            .update_status(&ticket.id, TicketStatus::Open)
+            .await?;
+        ticket.assigned_to = None;
+        ticket.status = TicketStatus::Open;
+
+        let result = elo.on_ticket_abandoned(&agent.id, &ticket).await?;
+
+        // Let watchers know the issue is up for grabs again (best effort)
+        if let Some(number) = ticket.gitea_issue_number {
+            if let Some(project) = self.projects.find_by_id(&ticket.project_id).await? {
+                let body = format!(
+                    "{} abandoned this issue; it is open for someone else to pick up.",
+                    agent.name
+                );
+                if let Err(e) = self
+                    .gitea
+                    .create_issue_comment(
+                        &project.gitea_org,
+                        &project.gitea_repo,
+                        number as i64,
+                        &body,
+                        gitea_token,
+                    )
+                    .await
+                {
+                    tracing::warn!("Failed to comment on abandoned issue #{}: {}", number, e);
+                }
+            }
+        }
+
+        Ok((ticket, result))
+    }
+
     /// Submit a PR from a branch (calls Gitea directly)
     ///
     /// If `gitea_token` is provided, the PR will be created using the agent's
@@ -194,6 +452,8 @@ where
         // Generate title from branch name if not provided
         let pr_title = title.unwrap_or(branch);
 
+        let base = self.default_branch(project).await?;
+
         // Create PR in Gitea using agent's token for proper attribution
         let pr = self
             .gitea
@@ -203,7 +463,7 @@ where
                 pr_title,
                 body,
                 branch,
-                "main", // TODO: use project's default branch
+                &base,
                 gitea_token,
             )
             .await
@@ -219,6 +479,213 @@ where
         Ok(SubmitResult { pr, message })
     }
 
+    /// Ensure a PR targets the project's default branch
+    ///
+    /// PRs against other branches would be credited like regular merges, so
+    /// they are rejected unless the project opted in to alternate bases.
+    pub async fn validate_pr_base(&self, project: &Project, base: &str) -> Result<(), AppError> {
+        if project.allow_alternate_bases {
+            return Ok(());
+        }
+
+        let default_branch = self.default_branch(project).await?;
+        if base != default_branch {
+            return Err(AppError::Domain(DomainError::Validation(format!(
+                "PRs must target the default branch '{}', not '{}'",
+                default_branch, base
+            ))));
+        }
+
+        Ok(())
+    }
+
+    async fn default_branch(&self, project: &Project) -> Result<String, AppError> {
+        let repo = self
+            .gitea
+            .get_repo(&project.gitea_org, &project.gitea_repo)
+            .await
+            .map_err(|e| AppError::Internal(format!("Failed to load repository: {}", e)))?;
+        Ok(repo.default_branch)
+    }
+
+    /// Merge a PR after confirming it can be merged cleanly
+    ///
+    /// Refuses PRs that are already merged, closed, or conflict with their
+    /// base. When the project requires green CI, the head commit's combined
+    /// status must also be `success`. When approvals are required, the PR
+    /// needs that many and no reviewer may still be requesting changes. The
+    /// project's `required_approvals` raises the configured minimum. `force`
+    /// lets the project owner waive the project's own requirement (the
+    /// configured minimum still applies); every force merge is recorded.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn merge_pull_request(
+        &self,
+        project: &Project,
+        agent_id: &AgentId,
+        pr_number: i64,
+        merge_style: &str,
+        force: bool,
+        gitea_token: Option<&str>,
+    ) -> Result<(), AppError> {
+        if force {
+            let role = self.projects.get_member_role(&project.id, agent_id).await?;
+            if role != Some(MemberRole::Owner) {
+                return Err(AppError::Domain(DomainError::Forbidden(
+                    "Only the project owner can force-merge PRs".to_string(),
+                )));
+            }
+        }
+
+        let status = self
+            .gitea
+            .get_pull_request_merge_status(&project.gitea_org, &project.gitea_repo, pr_number)
+            .await
+            .map_err(|_| AppError::NotFound(format!("PR #{} not found", pr_number)))?;
+
+        if status.merged {
+            return Err(AppError::Domain(DomainError::Conflict(format!(
+                "PR #{} is already merged",
+                pr_number
+            ))));
+        }
+        if status.state != "open" {
+            return Err(AppError::Domain(DomainError::Conflict(format!(
+                "PR #{} is {} and cannot be merged",
+                pr_number, status.state
+            ))));
+        }
+        if status.has_conflicts() {
+            return Err(AppError::Domain(DomainError::Conflict(format!(
+                "PR #{} has merge conflicts with its base branch; rebase and push before merging",
+                pr_number
+            ))));
+        }
+
+        let project_approvals =
+            project.required_approvals.clamp(0, MAX_REQUIRED_APPROVALS) as usize;
+        let required_approvals = if force {
+            self.required_approvals
+        } else {
+            self.required_approvals.max(project_approvals)
+        };
+        if required_approvals > 0 {
+            let reviews = self
+                .gitea
+                .get_pr_reviews(&project.gitea_org, &project.gitea_repo, pr_number)
+                .await
+                .map_err(|e| AppError::Internal(format!("Failed to load reviews: {}", e)))?;
+            let summary = summarize_reviews(&reviews, required_approvals);
+            if !summary.approvable {
+                return Err(AppError::Domain(DomainError::Conflict(format!(
+                    "PR #{} needs {} approval(s) and no outstanding change requests \
+                     (approved: {}, changes requested: {})",
+                    pr_number, required_approvals, summary.approved, summary.changes_requested
+                ))));
+            }
+        }
+
+        if project.require_green_ci {
+            let ci = self
+                .gitea
+                .get_commit_status(&project.gitea_org, &project.gitea_repo, &status.head_sha)
+                .await
+                .map_err(|e| AppError::Internal(format!("Failed to check CI status: {}", e)))?;
+            if ci.state != "success" {
+                return Err(AppError::Domain(DomainError::Conflict(format!(
+                    "PR #{} cannot be merged until CI passes (status: {})",
+                    pr_number, ci.state
+                ))));
+            }
+        }
+
+        if force {
+            let waived = project_approvals.saturating_sub(self.required_approvals);
+            tracing::warn!(
+                project = %project.name,
+                pr = pr_number,
+                agent = %agent_id,
+                waived,
+                "Force-merging PR without the project's required approvals"
+            );
+            // Recorded before merging so a force merge never goes unaudited
+            self.projects
+                .record_force_merge(&project.id, agent_id, pr_number, waived as i32)
+                .await?;
+        }
+
+        self.gitea
+            .merge_pull_request(
+                &project.gitea_org,
+                &project.gitea_repo,
+                pr_number,
+                merge_style,
+                force,
+                gitea_token,
+            )
+            .await
+            .map_err(|e| AppError::Internal(format!("Failed to merge PR: {}", e)))
+    }
+
+    /// Combined CI status of a PR's head commit, with per-context checks
+    pub async fn get_pr_status(
+        &self,
+        project: &Project,
+        pr_number: i64,
+    ) -> Result<PrCiStatus, AppError> {
+        let pr = self
+            .gitea
+            .get_pull_request(&project.gitea_org, &project.gitea_repo, pr_number)
+            .await
+            .map_err(|_| AppError::NotFound(format!("PR #{} not found", pr_number)))?;
+
+        let combined = self
+            .gitea
+            .get_commit_status(&project.gitea_org, &project.gitea_repo, &pr.head.sha)
+            .await
+            .map_err(|e| AppError::Internal(format!("Failed to fetch CI status: {}", e)))?;
+
+        // Divergence from base is informational; don't fail the status if it can't be computed
+        let comparison = match self
+            .gitea
+            .compare_commits(
+                &project.gitea_org,
+                &project.gitea_repo,
+                &pr.base.ref_name,
+                &pr.head.sha,
+            )
+            .await
+        {
+            Ok(comparison) => Some(comparison),
+            Err(e) => {
+                tracing::warn!(
+                    "Failed to compare PR #{} with {}: {}",
+                    pr_number,
+                    pr.base.ref_name,
+                    e
+                );
+                None
+            }
+        };
+        let behind_by = comparison.as_ref().map(|c| c.behind_by);
+        let warning = behind_by
+            .filter(|behind| *behind >= BEHIND_BASE_WARNING_COMMITS)
+            .map(|behind| {
+                format!(
+                    "Branch is {} commits behind {}; rebase before merging",
+                    behind, pr.base.ref_name
+                )
+            });
+
+        Ok(PrCiStatus {
+            sha: pr.head.sha,
+            state: combined.overall_state().to_string(),
+            statuses: combined.statuses,
+            ahead_by: comparison.as_ref().map(|c| c.ahead_by),
+            behind_by,
+            warning,
+        })
+    }
+
     /// Review a PR (calls Gitea directly)
     ///
     /// If `gitea_token` is provided, the review will be submitted using the agent's
@@ -232,14 +699,6 @@ where
         comment: Option<&str>,
         gitea_token: Option<&str>,
     ) -> Result<ReviewResult, AppError> {
-        // Validate agent is a project member
-        if !self.projects.is_member(&project.id, &agent.id).await? {
-            return Err(AppError::Domain(DomainError::Forbidden(format!(
-                "You must join project '{}' before reviewing PRs",
-                project.name
-            ))));
-        }
-
         // Get the PR to verify it exists and agent isn't reviewing their own
         let pr = self
             .gitea
@@ -262,17 +721,8 @@ where
 
         // Submit review to Gitea using agent's token for proper attribution
         let review = self
-            .gitea
-            .submit_pr_review(
-                &project.gitea_org,
-                &project.gitea_repo,
-                pr_number,
-                event,
-                comment,
-                gitea_token,
-            )
-            .await
-            .map_err(|e| AppError::Internal(format!("Failed to submit review: {}", e)))?;
+            .submit_or_replace_review(agent, project, pr_number, event, comment, gitea_token)
+            .await?;
 
         let action_past = match event {
             "APPROVE" => "approved",
@@ -289,6 +739,70 @@ where
         Ok(ReviewResult { review, message })
     }
 
+    /// Submit a review to Gitea, replacing the agent's previous review on the PR
+    ///
+    /// Gitea keeps every submitted review, so a second review by the same agent
+    /// would otherwise stack on top of the first. Our own review records remain
+    /// authoritative for ELO; this only keeps the Gitea state in sync.
+    ///
+    /// The project's review eligibility is enforced here, before Gitea sees
+    /// the review: once posted it counts towards the PR's approvals.
+    pub async fn submit_or_replace_review(
+        &self,
+        agent: &Agent,
+        project: &Project,
+        pr_number: i64,
+        event: &str,
+        body: Option<&str>,
+        gitea_token: Option<&str>,
+    ) -> Result<GiteaPRReview, AppError> {
+        let is_member = self.projects.is_member(&project.id, &agent.id).await?;
+        if !project.review_eligibility.permits(is_member) {
+            return Err(AppError::Domain(DomainError::Forbidden(format!(
+                "Only members of project '{}' can review its PRs",
+                project.name
+            ))));
+        }
+
+        let existing = self
+            .gitea
+            .get_pr_review_by_user(
+                &project.gitea_org,
+                &project.gitea_repo,
+                pr_number,
+                &agent.gitea_username,
+            )
+            .await
+            .map_err(|e| AppError::Internal(format!("Failed to check existing reviews: {}", e)))?;
+
+        if let Some(existing) = existing {
+            self.gitea
+                .delete_pr_review(
+                    &project.gitea_org,
+                    &project.gitea_repo,
+                    pr_number,
+                    existing.id,
+                    gitea_token,
+                )
+                .await
+                .map_err(|e| {
+                    AppError::Internal(format!("Failed to replace existing review: {}", e))
+                })?;
+        }
+
+        self.gitea
+            .submit_pr_review(
+                &project.gitea_org,
+                &project.gitea_repo,
+                pr_number,
+                event,
+                body,
+                gitea_token,
+            )
+            .await
+            .map_err(|e| AppError::Internal(format!("Failed to submit review: {}", e)))
+    }
+
     /// Get agent's current work status
     pub async fn get_work_status(&self, agent: &Agent) -> Result<WorkStatus, AppError> {
         // Get assigned tickets
@@ -327,11 +841,35 @@ where
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::app::elo_config::ELO_TICKET_ABANDONED;
+    use crate::domain::entities::ReviewEligibility;
     use crate::test_utils::{
-        test_agent, test_project, test_ticket, test_ticket_assigned, InMemoryProjectRepository,
-        InMemoryTicketRepository, MockGiteaClient,
+        test_agent, test_project, test_ticket, test_ticket_assigned, ForceMergeRecord,
+        InMemoryAgentRepository, InMemoryAgentReviewRepository, InMemoryCodeContributionRepository,
+        InMemoryEloEventRepository, InMemoryProjectRepository, InMemoryTicketRepository,
+        MockGiteaClient,
     };
 
+    type TestEloService = ReactiveEloService<
+        InMemoryAgentRepository,
+        InMemoryCodeContributionRepository,
+        InMemoryAgentReviewRepository,
+        InMemoryEloEventRepository,
+        InMemoryProjectRepository,
+    >;
+
+    fn create_elo_service(agent: &Agent) -> (TestEloService, Arc<InMemoryAgentRepository>) {
+        let agent_repo = Arc::new(InMemoryAgentRepository::new().with_agent(agent.clone()));
+        let elo = ReactiveEloService::new(
+            agent_repo.clone(),
+            Arc::new(InMemoryCodeContributionRepository::new()),
+            Arc::new(InMemoryAgentReviewRepository::new()),
+            Arc::new(InMemoryEloEventRepository::new()),
+            Arc::new(InMemoryProjectRepository::new()),
+        );
+        (elo, agent_repo)
+    }
+
     fn create_service(
         ticket_repo: InMemoryTicketRepository,
         project_repo: InMemoryProjectRepository,
@@ -369,6 +907,40 @@ mod tests {
         assert!(assign_result.message.contains(&ticket.title));
     }
 
+    #[tokio::test]
+    async fn assign_ticket_creates_working_branch() {
+        let agent = test_agent();
+        let project = test_project();
+        let ticket = test_ticket(project.id);
+
+        let project_repo =
+            InMemoryProjectRepository::new().with_project_and_member(project.clone(), agent.id);
+        let ticket_repo = InMemoryTicketRepository::new().with_ticket(ticket.clone());
+        let gitea = Arc::new(MockGiteaClient::new());
+        let service =
+            WorkLoopService::new(Arc::new(ticket_repo), Arc::new(project_repo), gitea.clone());
+
+        let result = service
+            .assign_ticket(&agent, &ticket, &project)
+            .await
+            .unwrap();
+
+        let prefix = ticket.id.0.to_string();
+        let prefix = prefix.split('-').next().unwrap();
+        assert_eq!(result.branch, format!("fix/{}", prefix));
+        assert_eq!(
+            result.clone_url,
+            format!("git@gitea:{}/{}.git", project.gitea_org, project.gitea_repo)
+        );
+        assert!(result
+            .message
+            .contains(&format!("git checkout -b {}", result.branch)));
+        gitea
+            .get_branch(&project.gitea_org, &project.gitea_repo, &result.branch)
+            .await
+            .unwrap();
+    }
+
     #[tokio::test]
     async fn assign_ticket_not_project_member() {
         let agent = test_agent();
@@ -428,6 +1000,77 @@ mod tests {
         assert!(err.to_string().contains("already assigned"));
     }
 
+    #[tokio::test]
+    async fn assign_ticket_rejected_at_open_claim_limit() {
+        let agent = test_agent();
+        let project = test_project();
+        let held = test_ticket_assigned(project.id, agent.id);
+        let ticket = test_ticket(project.id);
+
+        let project_repo =
+            InMemoryProjectRepository::new().with_project_and_member(project.clone(), agent.id);
+        let ticket_repo = InMemoryTicketRepository::new()
+            .with_ticket(held)
+            .with_ticket(ticket.clone());
+        let service = create_service(ticket_repo, project_repo, MockGiteaClient::new())
+            .with_max_open_claims(Some(1));
+
+        let err = service
+            .assign_ticket(&agent, &ticket, &project)
+            .await
+            .unwrap_err();
+
+        assert!(matches!(err, AppError::BadRequest(_)));
+        assert!(err.to_string().contains("limit 1"));
+    }
+
+    #[tokio::test]
+    async fn assign_ticket_allowed_below_open_claim_limit() {
+        let agent = test_agent();
+        let project = test_project();
+        let held = test_ticket_assigned(project.id, agent.id);
+        let ticket = test_ticket(project.id);
+
+        let project_repo =
+            InMemoryProjectRepository::new().with_project_and_member(project.clone(), agent.id);
+        let ticket_repo = InMemoryTicketRepository::new()
+            .with_ticket(held)
+            .with_ticket(ticket.clone());
+        let service = create_service(ticket_repo, project_repo, MockGiteaClient::new())
+            .with_max_open_claims(Some(2));
+
+        let result = service
+            .assign_ticket(&agent, &ticket, &project)
+            .await
+            .unwrap();
+
+        assert_eq!(result.ticket.assigned_to, Some(agent.id));
+    }
+
+    #[tokio::test]
+    async fn maintainer_exempt_from_open_claim_limit() {
+        let agent = test_agent();
+        let project = test_project();
+        let held = test_ticket_assigned(project.id, agent.id);
+        let ticket = test_ticket(project.id);
+
+        let project_repo = InMemoryProjectRepository::new().with_project_and_role(
+            project.clone(),
+            agent.id,
+            MemberRole::Maintainer,
+        );
+        let ticket_repo = InMemoryTicketRepository::new()
+            .with_ticket(held)
+            .with_ticket(ticket.clone());
+        let service = create_service(ticket_repo, project_repo, MockGiteaClient::new())
+            .with_max_open_claims(Some(1));
+
+        assert!(service
+            .assign_ticket(&agent, &ticket, &project)
+            .await
+            .is_ok());
+    }
+
     // =========================================================================
     // abandon_ticket tests
     // =========================================================================
@@ -441,9 +1084,10 @@ mod tests {
         let project_repo = InMemoryProjectRepository::new().with_project(project.clone());
         let ticket_repo = InMemoryTicketRepository::new().with_ticket(ticket.clone());
         let gitea = MockGiteaClient::new();
+        let (elo, _) = create_elo_service(&agent);
 
         let service = create_service(ticket_repo, project_repo, gitea);
-        let result = service.abandon_ticket(&agent).await;
+        let result = service.abandon_ticket(&elo, &agent, None).await;
 
         assert!(result.is_ok());
         let message = result.unwrap();
@@ -452,15 +1096,46 @@ mod tests {
     }
 
     #[tokio::test]
-    async fn abandon_ticket_no_assigned_tickets() {
+    async fn abandon_ticket_from_feed_charges_penalty_per_ticket() {
         let agent = test_agent();
+        let project = test_project();
+        let first = test_ticket_assigned(project.id, agent.id);
+        let second = test_ticket_assigned(project.id, agent.id);
 
-        let project_repo = InMemoryProjectRepository::new();
-        let ticket_repo = InMemoryTicketRepository::new();
+        let ticket_repo = Arc::new(
+            InMemoryTicketRepository::new()
+                .with_ticket(first.clone())
+                .with_ticket(second.clone()),
+        );
+        let project_repo = InMemoryProjectRepository::new().with_project(project.clone());
+        let gitea = Arc::new(MockGiteaClient::new());
+        let service =
+            WorkLoopService::new(ticket_repo.clone(), Arc::new(project_repo), gitea.clone());
+        let (elo, agent_repo) = create_elo_service(&agent);
+
+        service.abandon_ticket(&elo, &agent, None).await.unwrap();
+
+        let stored = agent_repo.find_by_id(&agent.id).await.unwrap().unwrap();
+        assert_eq!(stored.elo, agent.elo + 2 * ELO_TICKET_ABANDONED);
+        for ticket in [&first, &second] {
+            let stored = ticket_repo.find_by_id(&ticket.id).await.unwrap().unwrap();
+            assert_eq!(stored.status, TicketStatus::Open);
+            assert_eq!(stored.assigned_to, None);
+        }
+        assert_eq!(gitea.posted_comments.read().unwrap().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn abandon_ticket_no_assigned_tickets() {
+        let agent = test_agent();
+
+        let project_repo = InMemoryProjectRepository::new();
+        let ticket_repo = InMemoryTicketRepository::new();
         let gitea = MockGiteaClient::new();
+        let (elo, _) = create_elo_service(&agent);
 
         let service = create_service(ticket_repo, project_repo, gitea);
-        let result = service.abandon_ticket(&agent).await;
+        let result = service.abandon_ticket(&elo, &agent, None).await;
 
         assert!(result.is_err());
         let err = result.unwrap_err();
@@ -468,6 +1143,82 @@ mod tests {
         assert!(err.to_string().contains("don't have any assigned tickets"));
     }
 
+    #[tokio::test]
+    async fn abandon_assigned_ticket_by_assignee_reopens_it() {
+        let agent = test_agent();
+        let project = test_project();
+        let ticket = test_ticket_assigned(project.id, agent.id);
+
+        let ticket_repo = Arc::new(InMemoryTicketRepository::new().with_ticket(ticket.clone()));
+        let project_repo = InMemoryProjectRepository::new().with_project(project.clone());
+        let gitea = Arc::new(MockGiteaClient::new());
+        let service =
+            WorkLoopService::new(ticket_repo.clone(), Arc::new(project_repo), gitea.clone());
+        let (elo, agent_repo) = create_elo_service(&agent);
+
+        let (abandoned, result) = service
+            .abandon_assigned_ticket(&elo, &agent, &ticket.id, None)
+            .await
+            .expect("assignee should be able to abandon");
+
+        assert_eq!(abandoned.status, TicketStatus::Open);
+        assert_eq!(abandoned.assigned_to, None);
+        let stored = ticket_repo.find_by_id(&ticket.id).await.unwrap().unwrap();
+        assert_eq!(stored.status, TicketStatus::Open);
+        assert_eq!(stored.assigned_to, None);
+        assert_eq!(result.delta, ELO_TICKET_ABANDONED);
+        let stored = agent_repo.find_by_id(&agent.id).await.unwrap().unwrap();
+        assert_eq!(stored.elo, agent.elo + ELO_TICKET_ABANDONED);
+
+        let comments = gitea.posted_comments.read().unwrap();
+        assert_eq!(comments.len(), 1);
+        assert_eq!(comments[0].0 .2, 2);
+        assert!(comments[0].1.contains("abandoned"));
+    }
+
+    #[tokio::test]
+    async fn abandon_assigned_ticket_by_non_assignee_forbidden() {
+        let assignee = test_agent();
+        let other = test_agent();
+        let project = test_project();
+        let ticket = test_ticket_assigned(project.id, assignee.id);
+
+        let project_repo = InMemoryProjectRepository::new().with_project(project.clone());
+        let ticket_repo = InMemoryTicketRepository::new().with_ticket(ticket.clone());
+        let service = create_service(ticket_repo, project_repo, MockGiteaClient::new());
+        let (elo, _) = create_elo_service(&other);
+
+        let result = service
+            .abandon_assigned_ticket(&elo, &other, &ticket.id, None)
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(AppError::Domain(DomainError::Forbidden(_)))
+        ));
+    }
+
+    #[tokio::test]
+    async fn abandon_assigned_ticket_rejects_closed_ticket() {
+        let agent = test_agent();
+        let project = test_project();
+        let mut ticket = test_ticket_assigned(project.id, agent.id);
+        ticket.status = TicketStatus::Closed;
+
+        let project_repo = InMemoryProjectRepository::new().with_project(project.clone());
+        let ticket_repo = InMemoryTicketRepository::new().with_ticket(ticket.clone());
+        let service = create_service(ticket_repo, project_repo, MockGiteaClient::new());
+        let (elo, agent_repo) = create_elo_service(&agent);
+
+        let result = service
+            .abandon_assigned_ticket(&elo, &agent, &ticket.id, None)
+            .await;
+
+        assert!(matches!(result, Err(AppError::BadRequest(_))));
+        let stored = agent_repo.find_by_id(&agent.id).await.unwrap().unwrap();
+        assert_eq!(stored.elo, agent.elo);
+    }
+
     // =========================================================================
     // submit_pr tests
     // =========================================================================
@@ -494,6 +1245,459 @@ mod tests {
         assert!(submit_result.message.contains("PR created"));
     }
 
+    // =========================================================================
+    // validate_pr_base tests
+    // =========================================================================
+
+    #[tokio::test]
+    async fn validate_pr_base_allows_default_branch() {
+        let project = test_project();
+        let service = create_service(
+            InMemoryTicketRepository::new(),
+            InMemoryProjectRepository::new().with_project(project.clone()),
+            MockGiteaClient::new(),
+        );
+
+        let result = service.validate_pr_base(&project, "main").await;
+
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn validate_pr_base_rejects_other_branch() {
+        let project = test_project();
+        let service = create_service(
+            InMemoryTicketRepository::new(),
+            InMemoryProjectRepository::new().with_project(project.clone()),
+            MockGiteaClient::new(),
+        );
+
+        let result = service
+            .validate_pr_base(&project, "release-candidate")
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(AppError::Domain(DomainError::Validation(_)))
+        ));
+    }
+
+    #[tokio::test]
+    async fn validate_pr_base_allows_other_branch_when_enabled() {
+        let mut project = test_project();
+        project.allow_alternate_bases = true;
+        let service = create_service(
+            InMemoryTicketRepository::new(),
+            InMemoryProjectRepository::new().with_project(project.clone()),
+            MockGiteaClient::new(),
+        );
+
+        let result = service
+            .validate_pr_base(&project, "release-candidate")
+            .await;
+
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn merge_pull_request_merges_clean_green_pr() {
+        let mut project = test_project();
+        project.require_green_ci = true;
+        let gitea = MockGiteaClient::new().with_pr(&project.gitea_org, &project.gitea_repo, 7);
+        let merged = gitea.merged_prs.clone();
+        let service = create_service(
+            InMemoryTicketRepository::new(),
+            InMemoryProjectRepository::new().with_project(project.clone()),
+            gitea,
+        );
+
+        let result = service
+            .merge_pull_request(&project, &AgentId::new(), 7, "merge", false, None)
+            .await;
+
+        assert!(result.is_ok());
+        assert_eq!(
+            merged.read().unwrap().as_slice(),
+            &[(project.gitea_org.clone(), project.gitea_repo.clone(), 7)]
+        );
+    }
+
+    #[tokio::test]
+    async fn merge_pull_request_refuses_conflicting_pr() {
+        let project = test_project();
+        let gitea = MockGiteaClient::new()
+            .with_pr(&project.gitea_org, &project.gitea_repo, 7)
+            .with_conflicting_pr(&project.gitea_org, &project.gitea_repo, 7);
+        let merged = gitea.merged_prs.clone();
+        let service = create_service(
+            InMemoryTicketRepository::new(),
+            InMemoryProjectRepository::new().with_project(project.clone()),
+            gitea,
+        );
+
+        let result = service
+            .merge_pull_request(&project, &AgentId::new(), 7, "merge", false, None)
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(AppError::Domain(DomainError::Conflict(msg))) if msg.contains("conflicts")
+        ));
+        assert!(merged.read().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn merge_pull_request_refuses_failing_ci_when_required() {
+        let mut project = test_project();
+        project.require_green_ci = true;
+        let gitea = MockGiteaClient::new()
+            .with_pr(&project.gitea_org, &project.gitea_repo, 7)
+            .with_commit_status(&project.gitea_org, &project.gitea_repo, "abc123", "failure");
+        let merged = gitea.merged_prs.clone();
+        let service = create_service(
+            InMemoryTicketRepository::new(),
+            InMemoryProjectRepository::new().with_project(project.clone()),
+            gitea,
+        );
+
+        let result = service
+            .merge_pull_request(&project, &AgentId::new(), 7, "merge", false, None)
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(AppError::Domain(DomainError::Conflict(msg))) if msg.contains("failure")
+        ));
+        assert!(merged.read().unwrap().is_empty());
+    }
+
+    fn review(id: i64, login: &str, state: &str) -> GiteaPRReview {
+        GiteaPRReview {
+            id,
+            user: crate::domain::ports::GiteaUser {
+                id,
+                login: login.to_string(),
+                email: format!("{}@test.com", login),
+                full_name: None,
+            },
+            state: state.to_string(),
+            body: None,
+            submitted_at: None,
+        }
+    }
+
+    #[test]
+    fn summarize_reviews_one_approval_is_approvable() {
+        let summary = summarize_reviews(
+            &[review(1, "alice", "APPROVED"), review(2, "bob", "COMMENT")],
+            1,
+        );
+
+        assert_eq!(summary.approved, 1);
+        assert_eq!(summary.commented, 1);
+        assert!(summary.approvable);
+    }
+
+    #[test]
+    fn summarize_reviews_outstanding_changes_block() {
+        let summary = summarize_reviews(
+            &[
+                review(1, "alice", "APPROVED"),
+                review(2, "bob", "REQUEST_CHANGES"),
+            ],
+            1,
+        );
+
+        assert_eq!(summary.changes_requested, 1);
+        assert!(!summary.approvable);
+    }
+
+    #[test]
+    fn summarize_reviews_uses_latest_verdict_per_reviewer() {
+        let summary = summarize_reviews(
+            &[
+                review(1, "bob", "REQUEST_CHANGES"),
+                review(2, "bob", "COMMENT"),
+                review(3, "bob", "APPROVED"),
+            ],
+            1,
+        );
+
+        assert_eq!(summary.approved, 1);
+        assert_eq!(summary.changes_requested, 0);
+        assert!(summary.approvable);
+    }
+
+    #[tokio::test]
+    async fn merge_pull_request_requires_approvals_when_configured() {
+        let project = test_project();
+        let gitea = MockGiteaClient::new().with_pr(&project.gitea_org, &project.gitea_repo, 7);
+        let merged = gitea.merged_prs.clone();
+        let service = create_service(
+            InMemoryTicketRepository::new(),
+            InMemoryProjectRepository::new().with_project(project.clone()),
+            gitea,
+        )
+        .with_required_approvals(1);
+
+        let result = service
+            .merge_pull_request(&project, &AgentId::new(), 7, "merge", false, None)
+            .await;
+        assert!(matches!(
+            result,
+            Err(AppError::Domain(DomainError::Conflict(msg))) if msg.contains("approval")
+        ));
+        assert!(merged.read().unwrap().is_empty());
+
+        service
+            .gitea
+            .submit_pr_review(
+                &project.gitea_org,
+                &project.gitea_repo,
+                7,
+                "APPROVED",
+                None,
+                Some("alice"),
+            )
+            .await
+            .unwrap();
+        let result = service
+            .merge_pull_request(&project, &AgentId::new(), 7, "merge", false, None)
+            .await;
+        assert!(result.is_ok());
+    }
+
+    /// A project requiring two approvals, with PR #7 approved by `approvers`,
+    /// and its owner
+    async fn service_with_approvals(
+        approvers: &[&str],
+    ) -> (
+        Project,
+        AgentId,
+        WorkLoopService<InMemoryTicketRepository, InMemoryProjectRepository, MockGiteaClient>,
+    ) {
+        let mut project = test_project();
+        project.required_approvals = 2;
+        let owner = AgentId::new();
+        let gitea = MockGiteaClient::new().with_pr(&project.gitea_org, &project.gitea_repo, 7);
+        for approver in approvers {
+            gitea
+                .submit_pr_review(
+                    &project.gitea_org,
+                    &project.gitea_repo,
+                    7,
+                    "APPROVED",
+                    None,
+                    Some(approver),
+                )
+                .await
+                .unwrap();
+        }
+        let service = create_service(
+            InMemoryTicketRepository::new(),
+            InMemoryProjectRepository::new().with_project_and_role(
+                project.clone(),
+                owner,
+                MemberRole::Owner,
+            ),
+            gitea,
+        );
+        (project, owner, service)
+    }
+
+    #[tokio::test]
+    async fn merge_pull_request_blocks_below_project_required_approvals() {
+        // The same reviewer approving twice is still one approval
+        let (project, owner, service) = service_with_approvals(&["alice", "alice"]).await;
+
+        let result = service
+            .merge_pull_request(&project, &owner, 7, "merge", false, None)
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(AppError::Domain(DomainError::Conflict(msg))) if msg.contains("needs 2 approval")
+        ));
+    }
+
+    #[tokio::test]
+    async fn merge_pull_request_allows_project_required_approvals() {
+        let (project, owner, service) = service_with_approvals(&["alice", "bob"]).await;
+
+        let result = service
+            .merge_pull_request(&project, &owner, 7, "merge", false, None)
+            .await;
+
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn owner_force_merge_waives_project_approvals_and_is_recorded() {
+        let (project, owner, service) = service_with_approvals(&["alice"]).await;
+        let merged = service.gitea.merged_prs.clone();
+
+        let result = service
+            .merge_pull_request(&project, &owner, 7, "merge", true, None)
+            .await;
+
+        assert!(result.is_ok());
+        assert_eq!(merged.read().unwrap().len(), 1);
+        let audit = service.projects.force_merges.read().unwrap().clone();
+        assert_eq!(
+            audit,
+            vec![ForceMergeRecord {
+                project_id: project.id,
+                agent_id: owner,
+                pr_number: 7,
+                waived_approvals: 2,
+            }]
+        );
+    }
+
+    #[tokio::test]
+    async fn maintainer_cannot_force_merge() {
+        let (project, _, service) = service_with_approvals(&["alice"]).await;
+        let maintainer = AgentId::new();
+        service
+            .projects
+            .add_member(&project.id, &maintainer, MemberRole::Maintainer)
+            .await
+            .unwrap();
+
+        let result = service
+            .merge_pull_request(&project, &maintainer, 7, "merge", true, None)
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(AppError::Domain(DomainError::Forbidden(_)))
+        ));
+        assert!(service.gitea.merged_prs.read().unwrap().is_empty());
+        assert!(service.projects.force_merges.read().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn force_merge_still_needs_the_configured_minimum() {
+        let (project, owner, service) = service_with_approvals(&[]).await;
+        let service = service.with_required_approvals(1);
+
+        let result = service
+            .merge_pull_request(&project, &owner, 7, "merge", true, None)
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(AppError::Domain(DomainError::Conflict(msg))) if msg.contains("needs 1 approval")
+        ));
+    }
+
+    #[tokio::test]
+    async fn get_pr_status_reports_worst_check() {
+        let project = test_project();
+        let gitea = MockGiteaClient::new()
+            .with_pr(&project.gitea_org, &project.gitea_repo, 7)
+            .with_commit_statuses(
+                &project.gitea_org,
+                &project.gitea_repo,
+                "abc123",
+                &[
+                    ("lint", "success"),
+                    ("build", "pending"),
+                    ("test", "failure"),
+                ],
+            );
+        let service = create_service(
+            InMemoryTicketRepository::new(),
+            InMemoryProjectRepository::new().with_project(project.clone()),
+            gitea,
+        );
+
+        let status = service.get_pr_status(&project, 7).await.unwrap();
+
+        assert_eq!(status.sha, "abc123");
+        assert_eq!(status.state, "failure");
+        assert_eq!(status.statuses.len(), 3);
+        assert!(status
+            .statuses
+            .iter()
+            .any(|s| s.context == "build" && s.state == "pending"));
+    }
+
+    #[tokio::test]
+    async fn get_pr_status_warns_when_branch_is_far_behind_base() {
+        let project = test_project();
+        let gitea = MockGiteaClient::new()
+            .with_pr(&project.gitea_org, &project.gitea_repo, 7)
+            .with_comparison(
+                &project.gitea_org,
+                &project.gitea_repo,
+                "main",
+                "abc123",
+                2,
+                BEHIND_BASE_WARNING_COMMITS + 5,
+            );
+        let service = create_service(
+            InMemoryTicketRepository::new(),
+            InMemoryProjectRepository::new().with_project(project.clone()),
+            gitea,
+        );
+
+        let status = service.get_pr_status(&project, 7).await.unwrap();
+
+        assert_eq!(status.ahead_by, Some(2));
+        assert_eq!(status.behind_by, Some(BEHIND_BASE_WARNING_COMMITS + 5));
+        assert!(status.warning.unwrap().contains("15 commits behind main"));
+    }
+
+    #[tokio::test]
+    async fn get_pr_status_without_comparison_has_no_warning() {
+        let project = test_project();
+        let gitea = MockGiteaClient::new().with_pr(&project.gitea_org, &project.gitea_repo, 7);
+        let service = create_service(
+            InMemoryTicketRepository::new(),
+            InMemoryProjectRepository::new().with_project(project.clone()),
+            gitea,
+        );
+
+        let status = service.get_pr_status(&project, 7).await.unwrap();
+
+        assert_eq!(status.behind_by, None);
+        assert!(status.warning.is_none());
+    }
+
+    #[tokio::test]
+    async fn get_pr_status_unknown_pr() {
+        let project = test_project();
+        let service = create_service(
+            InMemoryTicketRepository::new(),
+            InMemoryProjectRepository::new().with_project(project.clone()),
+            MockGiteaClient::new(),
+        );
+
+        let result = service.get_pr_status(&project, 99).await;
+
+        assert!(matches!(result, Err(AppError::NotFound(_))));
+    }
+
+    #[tokio::test]
+    async fn merge_pull_request_ignores_ci_when_not_required() {
+        let project = test_project();
+        let gitea = MockGiteaClient::new()
+            .with_pr(&project.gitea_org, &project.gitea_repo, 7)
+            .with_commit_status(&project.gitea_org, &project.gitea_repo, "abc123", "failure");
+        let service = create_service(
+            InMemoryTicketRepository::new(),
+            InMemoryProjectRepository::new().with_project(project.clone()),
+            gitea,
+        );
+
+        let result = service
+            .merge_pull_request(&project, &AgentId::new(), 7, "merge", false, None)
+            .await;
+
+        assert!(result.is_ok());
+    }
+
     #[tokio::test]
     async fn submit_pr_not_project_member() {
         let agent = test_agent();
@@ -588,14 +1792,54 @@ mod tests {
     }
 
     #[tokio::test]
-    async fn review_pr_not_project_member() {
+    async fn review_pr_twice_replaces_existing_gitea_review() {
         let agent = test_agent();
         let project = test_project();
 
+        let project_repo =
+            InMemoryProjectRepository::new().with_project_and_member(project.clone(), agent.id);
+        let ticket_repo = InMemoryTicketRepository::new();
+        let gitea = MockGiteaClient::new().with_pr(&project.gitea_org, &project.gitea_repo, 42);
+
+        let service = create_service(ticket_repo, project_repo, gitea);
+        // The mock attributes reviews to the login passed as the auth token
+        let token = Some(agent.gitea_username.as_str());
+        service
+            .review_pr(
+                &agent,
+                &project,
+                42,
+                "request-changes",
+                Some("Fix X"),
+                token,
+            )
+            .await
+            .unwrap();
+        service
+            .review_pr(&agent, &project, 42, "approve", Some("Fixed"), token)
+            .await
+            .unwrap();
+
+        let reviews = service
+            .gitea
+            .get_pr_reviews(&project.gitea_org, &project.gitea_repo, 42)
+            .await
+            .unwrap();
+        assert_eq!(reviews.len(), 1);
+        assert_eq!(reviews[0].state, "APPROVE");
+        assert_eq!(reviews[0].body.as_deref(), Some("Fixed"));
+    }
+
+    #[tokio::test]
+    async fn review_pr_not_project_member() {
+        let agent = test_agent();
+        let mut project = test_project();
+        project.review_eligibility = ReviewEligibility::Members;
+
         // Agent is NOT a member
         let project_repo = InMemoryProjectRepository::new().with_project(project.clone());
         let ticket_repo = InMemoryTicketRepository::new();
-        let gitea = MockGiteaClient::new();
+        let gitea = MockGiteaClient::new().with_pr(&project.gitea_org, &project.gitea_repo, 42);
 
         let service = create_service(ticket_repo, project_repo, gitea);
         let result = service
@@ -605,6 +1849,38 @@ mod tests {
         assert!(result.is_err());
         let err = result.unwrap_err();
         assert!(matches!(err, AppError::Domain(DomainError::Forbidden(_))));
+
+        // Nothing reached Gitea, so it can't count towards approvals
+        let reviews = service
+            .gitea
+            .get_pr_reviews(&project.gitea_org, &project.gitea_repo, 42)
+            .await
+            .unwrap();
+        assert!(reviews.is_empty());
+    }
+
+    #[tokio::test]
+    async fn review_pr_open_project_allows_non_member() {
+        let agent = test_agent();
+        let project = test_project();
+        assert_eq!(project.review_eligibility, ReviewEligibility::Open);
+
+        let project_repo = InMemoryProjectRepository::new().with_project(project.clone());
+        let ticket_repo = InMemoryTicketRepository::new();
+        let gitea = MockGiteaClient::new().with_pr(&project.gitea_org, &project.gitea_repo, 42);
+
+        let service = create_service(ticket_repo, project_repo, gitea);
+        service
+            .review_pr(&agent, &project, 42, "approve", None, None)
+            .await
+            .unwrap();
+
+        let reviews = service
+            .gitea
+            .get_pr_reviews(&project.gitea_org, &project.gitea_repo, 42)
+            .await
+            .unwrap();
+        assert_eq!(reviews.len(), 1);
     }
 
     #[tokio::test]
@@ -57,7 +57,6 @@ pub async fn auth_middleware(
 ///
 /// Like auth_middleware but doesn't fail if no auth is provided.
 /// The agent will be None in extensions if not authenticated.
-#[allow(dead_code)]
 pub async fn optional_auth_middleware(
     State(state): State<AppState>,
     mut request: Request<Body>,
@@ -1,5 +1,9 @@
 //! Authentication module
 
+pub mod admin_token;
 pub mod api_key;
+pub mod project_access;
 
-pub use api_key::auth_middleware;
+pub use admin_token::AdminToken;
+pub use api_key::{auth_middleware, optional_auth_middleware};
+pub use project_access::project_visibility_middleware;
@@ -0,0 +1,46 @@
+//! Project visibility guard
+
+use std::collections::HashMap;
+
+use axum::{
+    body::Body,
+    extract::{Path, State},
+    http::Request,
+    middleware::Next,
+    response::Response,
+};
+use uuid::Uuid;
+
+use crate::domain::entities::{Agent, ProjectId};
+use crate::error::AppError;
+use crate::AppState;
+
+/// Project visibility middleware
+///
+/// Guards every route under `/projects/:id`: private projects read as not
+/// found unless the requesting agent is a member or invitee. Must run after
+/// `auth_middleware` or `optional_auth_middleware` so the agent is known,
+/// and be added with `route_layer` so the path is matched. The visible
+/// project is injected into request extensions.
+pub async fn project_visibility_middleware(
+    State(state): State<AppState>,
+    Path(params): Path<HashMap<String, String>>,
+    mut request: Request<Body>,
+    next: Next,
+) -> Result<Response, AppError> {
+    let raw_id = params
+        .get("id")
+        .ok_or_else(|| AppError::Internal("Project route has no :id parameter".to_string()))?;
+    let id = Uuid::parse_str(raw_id)
+        .map_err(|_| AppError::BadRequest(format!("Invalid project ID: {}", raw_id)))?;
+
+    let viewer = request.extensions().get::<Agent>();
+    let project = state
+        .antfarm_service
+        .get_visible_project(&ProjectId(id), viewer)
+        .await?
+        .ok_or_else(|| AppError::NotFound(format!("Project {} not found", id)))?;
+
+    request.extensions_mut().insert(project);
+    Ok(next.run(request).await)
+}
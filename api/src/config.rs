@@ -1,5 +1,34 @@
 use std::env;
 
+use crate::adapters::gitea::client::DEFAULT_MAX_CONCURRENT_REQUESTS;
+use crate::app::elo_config::{
+    ELO_FLOOR, ELO_REVIEW_ABANDONED, ELO_SOFT_CAP_SPAN, ELO_TICKET_ABANDONED,
+    LONGEVITY_BATCH_CONCURRENCY, MERGE_COOLDOWN_SECS, PR_REJECTION_GRACE_SECS,
+    REVIEW_REQUEST_WINDOW_HOURS,
+};
+use crate::app::engagement_service::DEFAULT_SYNC_MAX_ATTEMPTS;
+use crate::app::feed_cache::DEFAULT_FEED_CACHE_MAX_AGE_SECS;
+use crate::app::feed_service::WelcomeMessage;
+use crate::app::reactive_elo_service::EloBounds;
+use crate::app::stale_pr_service::DEFAULT_STALE_PR_MESSAGE;
+use crate::app::ticket_count_batcher::DEFAULT_TICKET_COUNT_FLUSH_MS;
+use crate::app::token_cache::{DEFAULT_TOKEN_CACHE_MAX_ENTRIES, DEFAULT_TOKEN_CACHE_TTL_SECS};
+use crate::app::viral_moment_service::{
+    PromotionOverflow, PromotionQuota, QuietHours, DEFAULT_SNAPSHOT_RETENTION_DAYS,
+};
+use crate::app::webhook_delivery_service::DEFAULT_DELIVERY_RETENTION_DAYS;
+use crate::app::webhook_queue::{
+    DEFAULT_WEBHOOK_BATCH_SIZE, DEFAULT_WEBHOOK_QUEUE_CAPACITY, DEFAULT_WEBHOOK_WORKERS,
+};
+use crate::domain::entities::{
+    AbandonedReviewPolicy, ActionEloThresholds, AutoLabelRules, DifficultyWeights,
+    EngagementCooldowns, EngagementDecay, EngagementWeights, IssueHooks, LanguageAliases,
+    NamePolicy, NonMemberMergePolicy, ProbationPolicy, ReactionType, SelfReviewPolicy, TierFlair,
+    ENGAGEMENT_COMMENT_COOLDOWN_SECS, ENGAGEMENT_HALF_LIFE_HOURS,
+    ENGAGEMENT_REACTION_COOLDOWN_SECS, MAX_REQUIRED_APPROVALS,
+};
+use crate::domain::ports::RepoTemplate;
+
 #[derive(Clone)]
 pub struct Config {
     pub database_url: String,
@@ -8,6 +37,8 @@ pub struct Config {
     pub clickhouse_url: String,
     pub gitea_url: String,
     pub gitea_admin_token: String,
+    /// Maximum number of Gitea API requests in flight at once
+    pub gitea_max_concurrent_requests: usize,
     pub encryption_key: String,
     /// Webhook secret for verifying Gitea webhooks (HMAC-SHA256)
     pub webhook_secret: Option<String>,
@@ -17,8 +48,112 @@ pub struct Config {
     pub github_client_id: Option<String>,
     /// GitHub OAuth client secret
     pub github_client_secret: Option<String>,
+    /// Maximum request body size in bytes for regular API routes
+    pub max_body_bytes: usize,
+    /// Maximum request body size in bytes for the Gitea webhook (payloads can be large)
+    pub webhook_max_body_bytes: usize,
+    /// Per-reaction weights used when computing engagement scores
+    pub engagement_weights: EngagementWeights,
+    /// Whether PR engagement reactions are mirrored to Gitea
+    pub engagement_gitea_sync: bool,
+    /// Reactions mirrored to Gitea when sync is on (all when unset)
+    pub engagement_sync_reactions: Option<Vec<ReactionType>>,
+    /// Failed Gitea sync attempts before an engagement is no longer retried
+    pub engagement_sync_max_attempts: i32,
+    /// Half-life of an engagement's weight in viral ranking (`0` disables decay)
+    pub engagement_decay: EngagementDecay,
+    /// Minimum time between an agent's reactions / comments on the same target
+    pub engagement_cooldowns: EngagementCooldowns,
+    /// Per-difficulty percentage weights for the merge award and feed ordering
+    pub difficulty_weights: DifficultyWeights,
+    /// Window in seconds in which repeated merges on a project earn diminishing ELO
+    pub merge_cooldown_secs: i64,
+    /// Optional cap on an agent's net ELO change per UTC day (disabled when unset)
+    pub elo_daily_cap: Option<i32>,
+    /// Whether merged PRs by non-members are skipped or enroll the author (`skip` or `enroll`)
+    pub non_member_merges: NonMemberMergePolicy,
+    /// Only penalize a bug-referenced PR when its diff touched the code the bug points at
+    pub strict_bug_attribution: bool,
+    /// Steeper revert penalties for new agents (disabled when the contribution count is 0)
+    pub probation: Option<ProbationPolicy>,
+    /// ELO floor, optional hard ceiling, and optional soft cap past which gains are dampened
+    pub elo_bounds: EloBounds,
+    /// Which reviewer/author pairs count as self-reviews (`exact` or `linked`)
+    pub self_review_policy: SelfReviewPolicy,
+    /// Agents whose longevity bonuses are processed in parallel per sweep
+    pub longevity_batch_concurrency: usize,
+    /// ELO delta applied when an agent abandons a claimed ticket
+    pub ticket_abandon_elo: i32,
+    /// Most open tickets one agent may have claimed at once (unlimited when unset)
+    pub max_open_claims_per_agent: Option<usize>,
+    /// Approvals a PR needs before it can be merged (0 disables the check)
+    pub required_approvals: usize,
+    /// Seconds a closed PR must stay closed before its author loses ELO (0 applies immediately)
+    pub pr_rejection_grace_secs: i64,
+    /// Minimum ELO required to review or merge PRs (each disabled when unset)
+    pub action_elo_thresholds: ActionEloThresholds,
+    /// Window in seconds in which same-type feed notifications are coalesced (0 disables)
+    pub notification_batch_window_secs: i64,
+    /// Onboarding notification in an agent's first feed
+    pub feed_welcome_message: WelcomeMessage,
+    /// Mapping from generated issue metadata to labels
+    pub auto_label_rules: AutoLabelRules,
+    /// Actions run when issues are closed or reopened through the API
+    pub issue_hooks: IssueHooks,
+    /// Alternate language spellings folded together in project filters and facets
+    pub language_aliases: LanguageAliases,
+    /// Template repository new project repos are generated from (empty repos when unset)
+    pub project_template_repo: Option<RepoTemplate>,
+    /// Cap on promoted viral moments and what happens when it is reached
+    pub promotion_quota: PromotionQuota,
+    /// When new viral moments are recorded hidden instead of published
+    pub viral_quiet_hours: QuietHours,
+    /// Days after which unpromoted moments' snapshots are compacted (0, the default, keeps them in full)
+    pub viral_snapshot_retention_days: i64,
+    /// Hours without a commit or comment before an open PR is nudged (0, the default, disables)
+    pub stale_pr_nudge_hours: i64,
+    /// Comment posted on stale PRs
+    pub stale_pr_nudge_message: String,
+    /// Hours a requested reviewer has to submit a review (0 disables the follow-up)
+    pub review_request_window_hours: i64,
+    /// What happens to unanswered review requests (`penalize` or `reassign`)
+    pub abandoned_review_policy: AbandonedReviewPolicy,
+    /// ELO delta applied to a reviewer who lets a review request lapse
+    pub review_abandon_elo: i32,
+    /// Blocked and reserved names for agent registration
+    pub agent_name_policy: NamePolicy,
+    /// Badge shown next to each tier in profiles and the leaderboard
+    pub tier_flair: TierFlair,
+    /// Operator token for admin-only actions such as featuring projects
+    /// (admin actions are disabled when unset)
+    pub admin_token: Option<String>,
+    /// How long a decrypted agent Gitea token stays cached in memory (0 disables)
+    pub gitea_token_cache_ttl_secs: u64,
+    /// Maximum number of decrypted agent Gitea tokens cached at once
+    pub gitea_token_cache_max: usize,
+    /// Webhook deliveries processed concurrently
+    pub webhook_workers: usize,
+    /// Accepted webhook deliveries that may wait for a worker before new ones are refused
+    pub webhook_queue_capacity: usize,
+    /// Webhook deliveries taken off the queue per wake-up
+    pub webhook_batch_size: usize,
+    /// Days finished webhook deliveries are kept in the delivery log (0 keeps them forever)
+    pub webhook_delivery_retention_days: i64,
+    /// How often projects with issue events are recounted (0 recounts on every event)
+    pub ticket_count_flush_ms: u64,
+    /// How long clients may reuse a feed response before revalidating (0 = always revalidate)
+    pub feed_cache_max_age_secs: u64,
 }
 
+/// Default body limit for API routes (1 MB)
+const DEFAULT_MAX_BODY_BYTES: usize = 1024 * 1024;
+
+/// Default body limit for the Gitea webhook (10 MB)
+const DEFAULT_WEBHOOK_MAX_BODY_BYTES: usize = 10 * 1024 * 1024;
+
+/// Default window for coalescing feed notifications (5 minutes)
+const DEFAULT_NOTIFICATION_BATCH_WINDOW_SECS: i64 = 300;
+
 impl Config {
     pub fn from_env() -> Self {
         dotenvy::dotenv().ok();
@@ -30,6 +165,10 @@ impl Config {
             gitea_url: env::var("GITEA_URL")
                 .unwrap_or_else(|_| "http://localhost:3000".to_string()),
             gitea_admin_token: env::var("GITEA_ADMIN_TOKEN").unwrap_or_default(),
+            gitea_max_concurrent_requests: env::var("GITEA_MAX_CONCURRENT_REQUESTS")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(DEFAULT_MAX_CONCURRENT_REQUESTS),
             encryption_key: env::var("ENCRYPTION_KEY")
                 .unwrap_or_else(|_| "dev-key-not-for-production".to_string()),
             webhook_secret: env::var("WEBHOOK_SECRET").ok(),
@@ -37,6 +176,233 @@ impl Config {
                 .unwrap_or_else(|_| "http://localhost:8080".to_string()),
             github_client_id: env::var("GITHUB_CLIENT_ID").ok(),
             github_client_secret: env::var("GITHUB_CLIENT_SECRET").ok(),
+            max_body_bytes: env::var("MAX_BODY_BYTES")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(DEFAULT_MAX_BODY_BYTES),
+            webhook_max_body_bytes: env::var("WEBHOOK_MAX_BODY_BYTES")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(DEFAULT_WEBHOOK_MAX_BODY_BYTES),
+            engagement_weights: env::var("ENGAGEMENT_WEIGHTS")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or_default(),
+            engagement_gitea_sync: env::var("ENGAGEMENT_GITEA_SYNC")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(true),
+            engagement_sync_reactions: env::var("ENGAGEMENT_SYNC_REACTIONS")
+                .ok()
+                .map(|v| v.split(',').filter_map(|r| r.trim().parse().ok()).collect()),
+            engagement_sync_max_attempts: env::var("ENGAGEMENT_SYNC_MAX_ATTEMPTS")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(DEFAULT_SYNC_MAX_ATTEMPTS),
+            engagement_decay: EngagementDecay {
+                half_life: chrono::Duration::hours(
+                    env::var("ENGAGEMENT_HALF_LIFE_HOURS")
+                        .ok()
+                        .and_then(|v| v.parse().ok())
+                        .unwrap_or(ENGAGEMENT_HALF_LIFE_HOURS),
+                ),
+            },
+            engagement_cooldowns: EngagementCooldowns {
+                reaction: chrono::Duration::seconds(
+                    env::var("ENGAGEMENT_REACTION_COOLDOWN_SECS")
+                        .ok()
+                        .and_then(|v| v.parse().ok())
+                        .unwrap_or(ENGAGEMENT_REACTION_COOLDOWN_SECS),
+                ),
+                comment: chrono::Duration::seconds(
+                    env::var("ENGAGEMENT_COMMENT_COOLDOWN_SECS")
+                        .ok()
+                        .and_then(|v| v.parse().ok())
+                        .unwrap_or(ENGAGEMENT_COMMENT_COOLDOWN_SECS),
+                ),
+            },
+            difficulty_weights: env::var("DIFFICULTY_WEIGHTS")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or_default(),
+            merge_cooldown_secs: env::var("MERGE_COOLDOWN_SECS")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(MERGE_COOLDOWN_SECS),
+            elo_daily_cap: env::var("ELO_DAILY_CAP").ok().and_then(|v| v.parse().ok()),
+            non_member_merges: env::var("NON_MEMBER_MERGE_POLICY")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or_default(),
+            strict_bug_attribution: env::var("STRICT_BUG_ATTRIBUTION")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(false),
+            probation: {
+                let defaults = ProbationPolicy::default();
+                // Off unless a number of contributions is configured
+                Some(ProbationPolicy {
+                    contributions: env::var("PROBATION_CONTRIBUTIONS")
+                        .ok()
+                        .and_then(|v| v.parse().ok())
+                        .unwrap_or(0),
+                    survival_days: env::var("PROBATION_SURVIVAL_DAYS")
+                        .ok()
+                        .and_then(|v| v.parse().ok())
+                        .unwrap_or(defaults.survival_days),
+                    revert_penalty_percent: env::var("PROBATION_REVERT_PENALTY_PERCENT")
+                        .ok()
+                        .and_then(|v| v.parse().ok())
+                        .unwrap_or(defaults.revert_penalty_percent),
+                })
+                .filter(|policy| policy.contributions > 0)
+            },
+            elo_bounds: EloBounds {
+                floor: env::var("ELO_FLOOR")
+                    .ok()
+                    .and_then(|v| v.parse().ok())
+                    .unwrap_or(ELO_FLOOR),
+                ceiling: env::var("ELO_CEILING").ok().and_then(|v| v.parse().ok()),
+                soft_cap: env::var("ELO_SOFT_CAP").ok().and_then(|v| v.parse().ok()),
+                soft_cap_span: env::var("ELO_SOFT_CAP_SPAN")
+                    .ok()
+                    .and_then(|v| v.parse().ok())
+                    .unwrap_or(ELO_SOFT_CAP_SPAN),
+            },
+            self_review_policy: env::var("SELF_REVIEW_POLICY")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or_default(),
+            longevity_batch_concurrency: env::var("LONGEVITY_BATCH_CONCURRENCY")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(LONGEVITY_BATCH_CONCURRENCY),
+            ticket_abandon_elo: env::var("TICKET_ABANDON_ELO")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(ELO_TICKET_ABANDONED),
+            max_open_claims_per_agent: env::var("MAX_OPEN_CLAIMS_PER_AGENT")
+                .ok()
+                .and_then(|v| v.parse().ok()),
+            required_approvals: env::var("REQUIRED_APPROVALS")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(0)
+                .min(MAX_REQUIRED_APPROVALS as usize),
+            pr_rejection_grace_secs: env::var("PR_REJECTION_GRACE_SECS")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(PR_REJECTION_GRACE_SECS),
+            action_elo_thresholds: ActionEloThresholds {
+                review: env::var("MIN_ELO_TO_REVIEW")
+                    .ok()
+                    .and_then(|v| v.parse().ok()),
+                merge: env::var("MIN_ELO_TO_MERGE")
+                    .ok()
+                    .and_then(|v| v.parse().ok()),
+            },
+            notification_batch_window_secs: env::var("NOTIFICATION_BATCH_WINDOW_SECS")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(DEFAULT_NOTIFICATION_BATCH_WINDOW_SECS),
+            feed_welcome_message: env::var("FEED_WELCOME_MESSAGE")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or_default(),
+            auto_label_rules: env::var("AUTO_LABEL_RULES")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or_default(),
+            issue_hooks: env::var("ISSUE_HOOKS")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or_default(),
+            language_aliases: env::var("LANGUAGE_ALIASES")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or_default(),
+            project_template_repo: env::var("PROJECT_TEMPLATE_REPO")
+                .ok()
+                .and_then(|v| v.parse().ok()),
+            promotion_quota: PromotionQuota {
+                max_promoted: env::var("VIRAL_MAX_PROMOTED")
+                    .ok()
+                    .and_then(|v| v.parse().ok())
+                    .unwrap_or(PromotionQuota::default().max_promoted),
+                overflow: env::var("VIRAL_PROMOTION_OVERFLOW")
+                    .ok()
+                    .and_then(|v| v.parse::<PromotionOverflow>().ok())
+                    .unwrap_or_default(),
+            },
+            viral_quiet_hours: env::var("VIRAL_QUIET_HOURS")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or_default(),
+            viral_snapshot_retention_days: env::var("VIRAL_SNAPSHOT_RETENTION_DAYS")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(DEFAULT_SNAPSHOT_RETENTION_DAYS),
+            stale_pr_nudge_hours: env::var("STALE_PR_NUDGE_HOURS")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(0),
+            stale_pr_nudge_message: env::var("STALE_PR_NUDGE_MESSAGE")
+                .ok()
+                .filter(|v| !v.trim().is_empty())
+                .unwrap_or_else(|| DEFAULT_STALE_PR_MESSAGE.to_string()),
+            review_request_window_hours: env::var("REVIEW_REQUEST_WINDOW_HOURS")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(REVIEW_REQUEST_WINDOW_HOURS),
+            abandoned_review_policy: env::var("ABANDONED_REVIEW_POLICY")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or_default(),
+            review_abandon_elo: env::var("REVIEW_ABANDON_ELO")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(ELO_REVIEW_ABANDONED),
+            tier_flair: env::var("TIER_FLAIR")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or_default(),
+            agent_name_policy: NamePolicy::from_settings(
+                env::var("AGENT_NAME_BLOCKLIST").ok().as_deref(),
+                env::var("AGENT_NAME_RESERVED_PATTERN").ok().as_deref(),
+            ),
+            admin_token: env::var("ADMIN_TOKEN").ok().filter(|t| !t.is_empty()),
+            gitea_token_cache_ttl_secs: env::var("GITEA_TOKEN_CACHE_TTL_SECS")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(DEFAULT_TOKEN_CACHE_TTL_SECS),
+            gitea_token_cache_max: env::var("GITEA_TOKEN_CACHE_MAX")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(DEFAULT_TOKEN_CACHE_MAX_ENTRIES),
+            webhook_workers: env::var("WEBHOOK_WORKERS")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(DEFAULT_WEBHOOK_WORKERS),
+            webhook_queue_capacity: env::var("WEBHOOK_QUEUE_CAPACITY")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(DEFAULT_WEBHOOK_QUEUE_CAPACITY),
+            webhook_batch_size: env::var("WEBHOOK_BATCH_SIZE")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(DEFAULT_WEBHOOK_BATCH_SIZE),
+            webhook_delivery_retention_days: env::var("WEBHOOK_DELIVERY_RETENTION_DAYS")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(DEFAULT_DELIVERY_RETENTION_DAYS),
+            ticket_count_flush_ms: env::var("TICKET_COUNT_FLUSH_MS")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(DEFAULT_TICKET_COUNT_FLUSH_MS),
+            feed_cache_max_age_secs: env::var("FEED_CACHE_MAX_AGE_SECS")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(DEFAULT_FEED_CACHE_MAX_AGE_SECS),
         }
     }
 
@@ -3,9 +3,12 @@
 //! Represents an AI agent that participates in the SynStack platform.
 
 use chrono::{DateTime, Utc};
+use regex::Regex;
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
+use super::project::MemberRole;
+
 /// Unique identifier for an agent
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct AgentId(pub Uuid);
@@ -44,12 +47,23 @@ pub enum Tier {
 }
 
 impl Tier {
+    /// All tiers, lowest first
+    pub const ALL: [Tier; 3] = [Tier::Bronze, Tier::Silver, Tier::Gold];
+
+    /// Lowest ELO in the Silver tier
+    pub const SILVER_MIN_ELO: i32 = 1200;
+
+    /// Lowest ELO in the Gold tier
+    pub const GOLD_MIN_ELO: i32 = 1600;
+
     /// Get tier from ELO rating
     pub fn from_elo(elo: i32) -> Self {
-        match elo {
-            ..=1199 => Tier::Bronze,
-            1200..=1599 => Tier::Silver,
-            _ => Tier::Gold,
+        if elo >= Self::GOLD_MIN_ELO {
+            Tier::Gold
+        } else if elo >= Self::SILVER_MIN_ELO {
+            Tier::Silver
+        } else {
+            Tier::Bronze
         }
     }
 }
@@ -77,6 +91,57 @@ impl std::str::FromStr for Tier {
     }
 }
 
+/// Badge shown next to an agent's tier in rendered output (none by default)
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TierFlair {
+    pub bronze: String,
+    pub silver: String,
+    pub gold: String,
+}
+
+impl TierFlair {
+    /// Flair for a tier (may be empty)
+    pub fn flair(&self, tier: Tier) -> &str {
+        match tier {
+            Tier::Bronze => &self.bronze,
+            Tier::Silver => &self.silver,
+            Tier::Gold => &self.gold,
+        }
+    }
+
+    /// Tier name followed by its flair, e.g. "gold 🥇"
+    pub fn label(&self, tier: Tier) -> String {
+        match self.flair(tier) {
+            "" => tier.to_string(),
+            flair => format!("{} {}", tier, flair),
+        }
+    }
+}
+
+impl std::str::FromStr for TierFlair {
+    type Err = String;
+
+    /// Parse badges like "gold=🥇,silver=🥈"; unspecified tiers get none
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut flair = Self::default();
+
+        for pair in s.split(',').map(str::trim).filter(|p| !p.is_empty()) {
+            let (key, value) = pair
+                .split_once('=')
+                .ok_or_else(|| format!("Invalid flair '{}', expected <tier>=<flair>", pair))?;
+            let value = value.trim().to_string();
+
+            match key.trim().parse::<Tier>()? {
+                Tier::Bronze => flair.bronze = value,
+                Tier::Silver => flair.silver = value,
+                Tier::Gold => flair.gold = value,
+            }
+        }
+
+        Ok(flair)
+    }
+}
+
 /// An AI agent that can contribute to projects
 #[derive(Debug, Clone, Serialize)]
 pub struct Agent {
@@ -113,6 +178,171 @@ impl Agent {
     }
 }
 
+/// Onboarding checklist for an agent - which setup steps are done
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct OnboardingStatus {
+    /// Always true once the agent exists
+    pub registered: bool,
+    /// A human has claimed the agent via GitHub
+    pub claimed: bool,
+    pub member_of_any_project: bool,
+    pub has_first_contribution: bool,
+    /// Every step above is done
+    pub complete: bool,
+}
+
+impl OnboardingStatus {
+    pub fn for_agent(
+        agent: &Agent,
+        member_of_any_project: bool,
+        has_first_contribution: bool,
+    ) -> Self {
+        let claimed = agent.github_id.is_some();
+        Self {
+            registered: true,
+            claimed,
+            member_of_any_project,
+            has_first_contribution,
+            complete: claimed && member_of_any_project && has_first_contribution,
+        }
+    }
+}
+
+/// Sensitive actions that can require a minimum ELO
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GatedAction {
+    /// Submitting a review on a PR
+    Review,
+    /// Merging a PR
+    Merge,
+}
+
+impl std::fmt::Display for GatedAction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GatedAction::Review => write!(f, "reviewing PRs"),
+            GatedAction::Merge => write!(f, "merging PRs"),
+        }
+    }
+}
+
+/// Minimum ELO required for sensitive actions (`None` disables a gate)
+///
+/// Project owners and maintainers are exempt from both gates.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ActionEloThresholds {
+    pub review: Option<i32>,
+    pub merge: Option<i32>,
+}
+
+impl ActionEloThresholds {
+    /// The ELO floor an agent with `elo` and project `role` still falls short of
+    /// for `action`, or `None` if the action is allowed
+    pub fn unmet_threshold(
+        &self,
+        action: GatedAction,
+        elo: i32,
+        role: Option<MemberRole>,
+    ) -> Option<i32> {
+        let threshold = match action {
+            GatedAction::Review => self.review,
+            GatedAction::Merge => self.merge,
+        };
+        let exempt = matches!(role, Some(MemberRole::Owner) | Some(MemberRole::Maintainer));
+
+        threshold.filter(|min| !exempt && elo < *min)
+    }
+}
+
+/// Names reserved for the platform and its staff
+pub const DEFAULT_BLOCKED_AGENT_NAMES: &[&str] = &[
+    "admin",
+    "administrator",
+    "root",
+    "system",
+    "synstack",
+    "gitea",
+    "support",
+    "moderator",
+    "staff",
+    "security",
+];
+
+/// Prefixes that would let an agent pass itself off as platform staff
+pub const DEFAULT_RESERVED_NAME_PATTERN: &str =
+    r"^(admin|system|synstack|gitea|official|staff|mod)[-_. ]";
+
+/// Which names agents may register under
+///
+/// Names are compared case-insensitively, both as given and in the
+/// hyphenated form used for Gitea usernames.
+#[derive(Debug, Clone)]
+pub struct NamePolicy {
+    /// Exact names that are never allowed (lowercase)
+    pub blocked: Vec<String>,
+    /// Names matching this pattern are reserved
+    pub reserved_pattern: Option<Regex>,
+}
+
+impl Default for NamePolicy {
+    fn default() -> Self {
+        Self {
+            blocked: DEFAULT_BLOCKED_AGENT_NAMES
+                .iter()
+                .map(|n| n.to_string())
+                .collect(),
+            reserved_pattern: Regex::new(DEFAULT_RESERVED_NAME_PATTERN).ok(),
+        }
+    }
+}
+
+impl NamePolicy {
+    /// Build a policy from raw settings, keeping the defaults for unset values
+    ///
+    /// `blocklist` is a comma-separated list of names. An empty
+    /// `reserved_pattern` disables the pattern; an invalid one keeps the default.
+    pub fn from_settings(blocklist: Option<&str>, reserved_pattern: Option<&str>) -> Self {
+        let mut policy = Self::default();
+        if let Some(list) = blocklist {
+            policy.blocked = list
+                .split(',')
+                .map(|n| n.trim().to_lowercase())
+                .filter(|n| !n.is_empty())
+                .collect();
+        }
+        match reserved_pattern.map(str::trim) {
+            Some("") => policy.reserved_pattern = None,
+            Some(pattern) => {
+                if let Ok(re) = Regex::new(&format!("(?i){}", pattern)) {
+                    policy.reserved_pattern = Some(re);
+                }
+            }
+            None => {}
+        }
+        policy
+    }
+
+    /// Why `name` may not be registered, or `None` if it is allowed
+    pub fn violation(&self, name: &str) -> Option<String> {
+        let lower = name.to_lowercase();
+        let slug = lower.replace(' ', "-");
+        let candidates = [lower.as_str(), slug.as_str()];
+
+        if candidates
+            .iter()
+            .any(|c| self.blocked.iter().any(|b| b == c))
+        {
+            return Some(format!("The name '{}' is not allowed", name));
+        }
+        if let Some(re) = &self.reserved_pattern {
+            if candidates.iter().any(|c| re.is_match(c)) {
+                return Some(format!("The name '{}' is reserved", name));
+            }
+        }
+        None
+    }
+}
+
 /// Data needed to create a new agent
 #[derive(Debug, Clone)]
 pub struct NewAgent {
@@ -131,6 +361,23 @@ pub struct ClaimAgent {
     pub github_avatar_url: Option<String>,
 }
 
+/// Records moved from a duplicate agent onto the agent it was merged into
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub struct AgentMergeCounts {
+    pub contributions: u64,
+    /// Reviews the agent gave or received
+    pub reviews: u64,
+    pub elo_events: u64,
+    /// Tickets assigned to the agent
+    pub tickets: u64,
+    /// Project memberships, including ones folded into the target's own
+    pub memberships: u64,
+    pub engagements: u64,
+    pub pending_penalties: u64,
+    /// Viral moments the agent appears in
+    pub viral_moments: u64,
+}
+
 /// Agent with decrypted Gitea token (for internal use only)
 #[derive(Debug, Clone)]
 #[allow(dead_code)]
@@ -187,6 +434,69 @@ mod tests {
         assert!("invalid".parse::<Tier>().is_err());
     }
 
+    #[test]
+    fn elo_gate_blocks_agents_below_threshold() {
+        let gates = ActionEloThresholds {
+            review: Some(1100),
+            merge: Some(1300),
+        };
+        let contributor = Some(MemberRole::Contributor);
+
+        assert_eq!(
+            gates.unmet_threshold(GatedAction::Review, 1099, contributor),
+            Some(1100)
+        );
+        assert_eq!(
+            gates.unmet_threshold(GatedAction::Merge, 1200, contributor),
+            Some(1300)
+        );
+    }
+
+    #[test]
+    fn elo_gate_allows_agents_at_or_above_threshold() {
+        let gates = ActionEloThresholds {
+            review: Some(1100),
+            merge: Some(1300),
+        };
+
+        assert_eq!(
+            gates.unmet_threshold(GatedAction::Review, 1100, Some(MemberRole::Contributor)),
+            None
+        );
+        assert_eq!(
+            gates.unmet_threshold(GatedAction::Merge, 1450, Some(MemberRole::Contributor)),
+            None
+        );
+    }
+
+    #[test]
+    fn elo_gate_exempts_by_role() {
+        let gates = ActionEloThresholds {
+            review: Some(1100),
+            merge: Some(1300),
+        };
+
+        assert_eq!(
+            gates.unmet_threshold(GatedAction::Review, 900, Some(MemberRole::Maintainer)),
+            None
+        );
+        assert_eq!(
+            gates.unmet_threshold(GatedAction::Merge, 900, Some(MemberRole::Maintainer)),
+            None
+        );
+        assert_eq!(
+            gates.unmet_threshold(GatedAction::Merge, 900, Some(MemberRole::Owner)),
+            None
+        );
+    }
+
+    #[test]
+    fn elo_gate_disabled_by_default() {
+        let gates = ActionEloThresholds::default();
+        assert_eq!(gates.unmet_threshold(GatedAction::Review, 0, None), None);
+        assert_eq!(gates.unmet_threshold(GatedAction::Merge, 0, None), None);
+    }
+
     #[test]
     fn agent_update_tier() {
         let mut agent = Agent {
@@ -242,4 +552,64 @@ mod tests {
         let id = AgentId(Uuid::nil());
         assert_eq!(id.to_string(), "00000000-0000-0000-0000-000000000000");
     }
+
+    #[test]
+    fn name_policy_blocks_listed_names_case_insensitively() {
+        let policy = NamePolicy::default();
+        assert!(policy.violation("Admin").unwrap().contains("not allowed"));
+        assert!(policy.violation("system").is_some());
+        assert!(policy.violation("rusty-crab").is_none());
+    }
+
+    #[test]
+    fn name_policy_reserves_prefixes() {
+        let policy = NamePolicy::default();
+        assert!(policy
+            .violation("synstack-bot")
+            .unwrap()
+            .contains("reserved"));
+        assert!(policy.violation("Official Helper").is_some());
+        assert!(policy.violation("administrative-assistant").is_none());
+    }
+
+    #[test]
+    fn name_policy_from_settings() {
+        let policy = NamePolicy::from_settings(Some("alice, Bob"), Some(""));
+        assert!(policy.violation("bob").is_some());
+        assert!(policy.violation("admin").is_none());
+        assert!(policy.reserved_pattern.is_none());
+
+        let policy = NamePolicy::from_settings(None, Some("^team-"));
+        assert!(policy.violation("TEAM-lead").is_some());
+        assert!(policy.violation("admin").is_some());
+
+        let policy = NamePolicy::from_settings(None, Some("("));
+        assert!(policy.violation("synstack-bot").is_some());
+    }
+
+    #[test]
+    fn onboarding_status_unclaimed_agent() {
+        let agent = crate::test_utils::test_agent();
+        let status = OnboardingStatus::for_agent(&agent, false, false);
+
+        assert!(status.registered);
+        assert!(!status.claimed);
+        assert!(!status.member_of_any_project);
+        assert!(!status.has_first_contribution);
+        assert!(!status.complete);
+    }
+
+    #[test]
+    fn onboarding_status_fully_onboarded_agent() {
+        let mut agent = crate::test_utils::test_agent();
+        agent.github_id = Some(42);
+        agent.claimed_at = Some(Utc::now());
+        let status = OnboardingStatus::for_agent(&agent, true, true);
+
+        assert!(status.registered);
+        assert!(status.claimed);
+        assert!(status.member_of_any_project);
+        assert!(status.has_first_contribution);
+        assert!(status.complete);
+    }
 }
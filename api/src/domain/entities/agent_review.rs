@@ -7,7 +7,7 @@ use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
-use super::agent::AgentId;
+use super::agent::{Agent, AgentId};
 use super::project::ProjectId;
 
 /// Unique identifier for an agent review
@@ -69,6 +69,52 @@ impl std::str::FromStr for ReviewVerdict {
     }
 }
 
+/// How strictly peer reviews are checked for being self-reviews
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum SelfReviewPolicy {
+    /// Only the same agent reviewing its own PR
+    #[default]
+    Exact,
+    /// Also agents claimed by the same GitHub account, so a second registered
+    /// identity can't approve its owner's PRs
+    Linked,
+}
+
+impl SelfReviewPolicy {
+    /// Whether `reviewer` reviewing `reviewed`'s PR counts as a self-review
+    pub fn is_self_review(&self, reviewer: &Agent, reviewed: &Agent) -> bool {
+        if reviewer.id == reviewed.id {
+            return true;
+        }
+        match self {
+            SelfReviewPolicy::Exact => false,
+            SelfReviewPolicy::Linked => {
+                let same_id = matches!(
+                    (reviewer.github_id, reviewed.github_id),
+                    (Some(a), Some(b)) if a == b
+                );
+                let same_login = matches!(
+                    (&reviewer.github_username, &reviewed.github_username),
+                    (Some(a), Some(b)) if a.eq_ignore_ascii_case(b)
+                );
+                same_id || same_login
+            }
+        }
+    }
+}
+
+impl std::str::FromStr for SelfReviewPolicy {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "exact" => Ok(SelfReviewPolicy::Exact),
+            "linked" => Ok(SelfReviewPolicy::Linked),
+            _ => Err(format!("Unknown self-review policy: {}", s)),
+        }
+    }
+}
+
 /// ELO threshold for "high-ELO" reviewer bonus
 pub const HIGH_ELO_THRESHOLD: i32 = 1400;
 
@@ -162,6 +208,41 @@ mod tests {
         assert!("invalid".parse::<ReviewVerdict>().is_err());
     }
 
+    #[test]
+    fn linked_policy_matches_shared_github_account() {
+        let reviewer = Agent {
+            github_id: Some(7),
+            ..crate::test_utils::test_agent()
+        };
+        let same_id = Agent {
+            github_id: Some(7),
+            ..crate::test_utils::test_agent()
+        };
+        let same_login = Agent {
+            github_username: Some("Octocat".to_string()),
+            ..crate::test_utils::test_agent()
+        };
+        let reviewer_by_login = Agent {
+            github_username: Some("octocat".to_string()),
+            ..crate::test_utils::test_agent()
+        };
+        let unclaimed = crate::test_utils::test_agent();
+
+        assert!(!SelfReviewPolicy::Exact.is_self_review(&reviewer, &same_id));
+        assert!(SelfReviewPolicy::Exact.is_self_review(&reviewer, &reviewer));
+        assert!(SelfReviewPolicy::Linked.is_self_review(&reviewer, &same_id));
+        assert!(SelfReviewPolicy::Linked.is_self_review(&reviewer_by_login, &same_login));
+        // Unclaimed agents never match each other
+        assert!(
+            !SelfReviewPolicy::Linked.is_self_review(&unclaimed, &crate::test_utils::test_agent())
+        );
+        assert_eq!(
+            "LINKED".parse::<SelfReviewPolicy>(),
+            Ok(SelfReviewPolicy::Linked)
+        );
+        assert!("loose".parse::<SelfReviewPolicy>().is_err());
+    }
+
     #[test]
     fn is_high_elo_approval_true() {
         let review = make_review(ReviewVerdict::Approved, 1500, false);
@@ -121,6 +121,61 @@ impl CodeContribution {
     }
 }
 
+/// A file (and optionally a line in it) a bug report points at
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CodeReference {
+    pub path: String,
+    /// 1-based line number, if the report gave one
+    pub line: Option<usize>,
+}
+
+impl CodeReference {
+    /// Whether `path` (a repository-relative path from a diff) is this file.
+    /// References may leave off leading directories, so `lib.rs` matches
+    /// `src/lib.rs`.
+    pub fn matches_path(&self, path: &str) -> bool {
+        let wanted = self.path.trim_start_matches("./").trim_start_matches('/');
+        path == wanted
+            || path
+                .strip_suffix(wanted)
+                .is_some_and(|prefix| prefix.ends_with('/'))
+    }
+}
+
+/// Probation for new agents: until an agent has `contributions` healthy
+/// contributions that have each survived `survival_days` since merging, its
+/// reverts cost `revert_penalty_percent` of the normal penalty. A revert during
+/// probation restarts the count from that revert.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ProbationPolicy {
+    pub contributions: u32,
+    pub survival_days: i64,
+    pub revert_penalty_percent: i32,
+}
+
+impl Default for ProbationPolicy {
+    fn default() -> Self {
+        Self {
+            contributions: 3,
+            survival_days: 7,
+            revert_penalty_percent: 200,
+        }
+    }
+}
+
+impl ProbationPolicy {
+    /// How long a contribution must stay healthy to count toward leaving
+    /// probation
+    pub fn survival_window(&self) -> chrono::Duration {
+        chrono::Duration::days(self.survival_days)
+    }
+
+    /// Scale a revert penalty for an agent on probation
+    pub fn scale_revert(&self, delta: i32) -> i32 {
+        delta * self.revert_penalty_percent / 100
+    }
+}
+
 /// Data needed to create a new code contribution
 #[derive(Debug, Clone)]
 pub struct NewCodeContribution {
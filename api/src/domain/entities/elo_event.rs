@@ -59,6 +59,12 @@ pub enum EloEventType {
     LowPeerReviewScore,
     /// Code was replaced within 7 days (-10)
     CodeReplaced,
+    /// Agent gave up a ticket they had claimed (-2)
+    TicketAbandoned,
+    /// Reviewer approved a PR that was later reverted or found buggy (-5)
+    BadApproval,
+    /// Reviewer never answered a review request (-3)
+    ReviewAbandoned,
 }
 
 impl std::fmt::Display for EloEventType {
@@ -73,6 +79,9 @@ impl std::fmt::Display for EloEventType {
             EloEventType::PrRejected => write!(f, "pr_rejected"),
             EloEventType::LowPeerReviewScore => write!(f, "low_peer_review_score"),
             EloEventType::CodeReplaced => write!(f, "code_replaced"),
+            EloEventType::TicketAbandoned => write!(f, "ticket_abandoned"),
+            EloEventType::BadApproval => write!(f, "bad_approval"),
+            EloEventType::ReviewAbandoned => write!(f, "review_abandoned"),
         }
     }
 }
@@ -91,6 +100,9 @@ impl std::str::FromStr for EloEventType {
             "pr_rejected" => Ok(EloEventType::PrRejected),
             "low_peer_review_score" => Ok(EloEventType::LowPeerReviewScore),
             "code_replaced" => Ok(EloEventType::CodeReplaced),
+            "ticket_abandoned" => Ok(EloEventType::TicketAbandoned),
+            "bad_approval" => Ok(EloEventType::BadApproval),
+            "review_abandoned" => Ok(EloEventType::ReviewAbandoned),
             _ => Err(format!("Unknown ELO event type: {}", s)),
         }
     }
@@ -136,6 +148,25 @@ pub struct NewEloEvent {
     pub details: Option<String>,
 }
 
+/// An agent whose stored ELO doesn't match the one its events add up to
+#[derive(Debug, Clone, Serialize)]
+pub struct EloDrift {
+    pub agent_id: AgentId,
+    pub agent_name: String,
+    pub stored_elo: i32,
+    /// Starting ELO plus `event_sum`
+    pub expected_elo: i32,
+    /// Sum of the changes the agent's events made (`new_elo - old_elo`)
+    pub event_sum: i64,
+}
+
+impl EloDrift {
+    /// How far the stored ELO is from the expected one (positive when too high)
+    pub fn drift(&self) -> i32 {
+        self.stored_elo - self.expected_elo
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -171,6 +202,7 @@ mod tests {
             "low_peer_review_score"
         );
         assert_eq!(EloEventType::CodeReplaced.to_string(), "code_replaced");
+        assert_eq!(EloEventType::BadApproval.to_string(), "bad_approval");
     }
 
     #[test]
@@ -229,6 +261,7 @@ mod tests {
             EloEventType::PrRejected,
             EloEventType::LowPeerReviewScore,
             EloEventType::CodeReplaced,
+            EloEventType::TicketAbandoned,
         ];
 
         for event_type in types {
@@ -3,7 +3,7 @@
 //! Represents agent engagement (reactions, comments, reviews) on content.
 //! Engagements are proxied to Gitea and tracked in our database.
 
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, Duration, Utc};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
@@ -134,6 +134,18 @@ impl ReactionType {
             ReactionType::Eyes => "eyes",
         }
     }
+
+    /// Map a Gitea reaction back to ours (`None` for reactions we don't track)
+    pub fn from_gitea_content(content: &str) -> Option<Self> {
+        match content {
+            "laugh" => Some(ReactionType::Laugh),
+            "hooray" | "rocket" => Some(ReactionType::Fire),
+            "-1" => Some(ReactionType::Skull),
+            "heart" | "+1" => Some(ReactionType::Heart),
+            "eyes" => Some(ReactionType::Eyes),
+            _ => None,
+        }
+    }
 }
 
 impl std::fmt::Display for ReactionType {
@@ -179,6 +191,14 @@ pub struct Engagement {
     pub gitea_synced: bool,
     pub gitea_id: Option<i64>,
     pub created_at: DateTime<Utc>,
+    /// Gitea org of the PR's repository, for engagements mirrored to Gitea
+    pub gitea_org: Option<String>,
+    /// Gitea repo of the PR's repository, for engagements mirrored to Gitea
+    pub gitea_repo: Option<String>,
+    /// Failed attempts to mirror the engagement to Gitea
+    pub sync_attempts: i32,
+    /// Whether retries were given up after too many failed attempts
+    pub sync_failed: bool,
 }
 
 /// Data needed to create a new engagement
@@ -190,22 +210,260 @@ pub struct NewEngagement {
     pub engagement_type: EngagementType,
     pub reaction: Option<ReactionType>,
     pub body: Option<String>,
+    /// Gitea org of the PR's repository, when the engagement is to be mirrored
+    pub gitea_org: Option<String>,
+    /// Gitea repo of the PR's repository, when the engagement is to be mirrored
+    pub gitea_repo: Option<String>,
 }
 
 /// Engagement counts for a target (cached/denormalized)
-#[derive(Debug, Clone, Serialize, Default)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Default)]
 pub struct EngagementCounts {
     pub laugh_count: i32,
     pub fire_count: i32,
     pub skull_count: i32,
+    pub heart_count: i32,
+    pub eyes_count: i32,
     pub comment_count: i32,
+    pub review_count: i32,
     pub total_score: i32,
 }
 
+impl EngagementCounts {
+    /// Count one more reaction of the given type
+    pub fn record_reaction(&mut self, reaction: ReactionType) {
+        match reaction {
+            ReactionType::Laugh => self.laugh_count += 1,
+            ReactionType::Fire => self.fire_count += 1,
+            ReactionType::Skull => self.skull_count += 1,
+            ReactionType::Heart => self.heart_count += 1,
+            ReactionType::Eyes => self.eyes_count += 1,
+        }
+    }
+
+    /// Add another target's reaction counts (e.g. reactions made on Gitea) to these
+    pub fn add_reactions(&mut self, other: &EngagementCounts) {
+        self.laugh_count += other.laugh_count;
+        self.fire_count += other.fire_count;
+        self.skull_count += other.skull_count;
+        self.heart_count += other.heart_count;
+        self.eyes_count += other.eyes_count;
+    }
+}
+
+/// Weights applied to each engagement kind when computing a target's score
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EngagementWeights {
+    pub laugh: i32,
+    pub fire: i32,
+    pub skull: i32,
+    pub heart: i32,
+    pub eyes: i32,
+    pub comment: i32,
+    pub review: i32,
+}
+
+impl Default for EngagementWeights {
+    /// Matches the weights used by the `engagement_counts` trigger
+    fn default() -> Self {
+        Self {
+            laugh: 2,
+            fire: 3,
+            skull: 2,
+            heart: 1,
+            eyes: 1,
+            comment: 5,
+            review: 1,
+        }
+    }
+}
+
+impl EngagementWeights {
+    /// Weighted engagement score for a set of counts
+    pub fn score(&self, counts: &EngagementCounts) -> i32 {
+        counts.laugh_count * self.laugh
+            + counts.fire_count * self.fire
+            + counts.skull_count * self.skull
+            + counts.heart_count * self.heart
+            + counts.eyes_count * self.eyes
+            + counts.comment_count * self.comment
+            + counts.review_count * self.review
+    }
+
+    /// Weight of a single engagement
+    pub fn weight(&self, engagement: &Engagement) -> i32 {
+        match engagement.engagement_type {
+            EngagementType::Comment => self.comment,
+            EngagementType::Review => self.review,
+            EngagementType::Reaction => match engagement.reaction {
+                Some(ReactionType::Laugh) => self.laugh,
+                Some(ReactionType::Fire) => self.fire,
+                Some(ReactionType::Skull) => self.skull,
+                Some(ReactionType::Heart) => self.heart,
+                Some(ReactionType::Eyes) => self.eyes,
+                None => 0,
+            },
+        }
+    }
+}
+
+/// Default half-life of an engagement's contribution to ranking
+pub const ENGAGEMENT_HALF_LIFE_HOURS: i64 = 24;
+
+/// Default seconds between one agent's reactions on the same target (disabled:
+/// each reaction can already be given only once per target)
+pub const ENGAGEMENT_REACTION_COOLDOWN_SECS: i64 = 0;
+
+/// Default seconds between one agent's comments on the same target
+pub const ENGAGEMENT_COMMENT_COOLDOWN_SECS: i64 = 30;
+
+/// Minimum time between an agent's engagements of one kind on the same
+/// target, so scores can't be farmed by spamming. Zero disables a cooldown.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EngagementCooldowns {
+    pub reaction: Duration,
+    pub comment: Duration,
+}
+
+impl Default for EngagementCooldowns {
+    fn default() -> Self {
+        Self {
+            reaction: Duration::seconds(ENGAGEMENT_REACTION_COOLDOWN_SECS),
+            comment: Duration::seconds(ENGAGEMENT_COMMENT_COOLDOWN_SECS),
+        }
+    }
+}
+
+impl EngagementCooldowns {
+    /// Cooldown for an engagement kind (reviews have none)
+    pub fn for_type(&self, engagement_type: EngagementType) -> Duration {
+        match engagement_type {
+            EngagementType::Reaction => self.reaction,
+            EngagementType::Comment => self.comment,
+            EngagementType::Review => Duration::zero(),
+        }
+    }
+
+    /// How long until another engagement is allowed, given the last one's time
+    pub fn remaining(
+        &self,
+        engagement_type: EngagementType,
+        last: DateTime<Utc>,
+        now: DateTime<Utc>,
+    ) -> Option<Duration> {
+        let remaining = last + self.for_type(engagement_type) - now;
+        (remaining > Duration::zero()).then_some(remaining)
+    }
+}
+
+/// Exponential decay of engagement by age, so fresh content ranks above
+/// content that was popular long ago. Only ranking uses it; raw counts and
+/// `total_score` are never decayed. A zero half-life disables decay.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EngagementDecay {
+    pub half_life: Duration,
+}
+
+impl Default for EngagementDecay {
+    fn default() -> Self {
+        Self {
+            half_life: Duration::hours(ENGAGEMENT_HALF_LIFE_HOURS),
+        }
+    }
+}
+
+impl EngagementDecay {
+    /// Multiplier for an engagement of this age: 1.0 when new, 0.5 after one half-life
+    pub fn factor(&self, age: Duration) -> f64 {
+        if self.half_life <= Duration::zero() {
+            return 1.0;
+        }
+        let half_lives = age.num_seconds().max(0) as f64 / self.half_life.num_seconds() as f64;
+        0.5_f64.powf(half_lives)
+    }
+
+    /// Weighted engagement score with each engagement decayed by its age at `now`
+    pub fn score(
+        &self,
+        weights: &EngagementWeights,
+        engagements: &[Engagement],
+        now: DateTime<Utc>,
+    ) -> f64 {
+        engagements
+            .iter()
+            .map(|e| weights.weight(e) as f64 * self.factor(now - e.created_at))
+            .sum()
+    }
+}
+
+impl std::str::FromStr for EngagementWeights {
+    type Err = String;
+
+    /// Parse overrides like "fire=5,laugh=2"; unspecified kinds keep their default
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut weights = Self::default();
+
+        for pair in s.split(',').map(str::trim).filter(|p| !p.is_empty()) {
+            let (key, value) = pair
+                .split_once('=')
+                .ok_or_else(|| format!("Invalid weight '{}', expected <kind>=<weight>", pair))?;
+            let value: i32 = value
+                .trim()
+                .parse()
+                .map_err(|_| format!("Invalid weight value for '{}': {}", key, value))?;
+
+            match key.trim().to_lowercase().as_str() {
+                "laugh" => weights.laugh = value,
+                "fire" => weights.fire = value,
+                "skull" => weights.skull = value,
+                "heart" => weights.heart = value,
+                "eyes" => weights.eyes = value,
+                "comment" => weights.comment = value,
+                "review" => weights.review = value,
+                other => return Err(format!("Unknown engagement kind: {}", other)),
+            }
+        }
+
+        Ok(weights)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn decay_halves_each_half_life() {
+        let decay = EngagementDecay {
+            half_life: Duration::hours(10),
+        };
+        assert_eq!(decay.factor(Duration::zero()), 1.0);
+        assert_eq!(decay.factor(Duration::hours(10)), 0.5);
+        assert_eq!(decay.factor(Duration::hours(20)), 0.25);
+
+        let disabled = EngagementDecay {
+            half_life: Duration::zero(),
+        };
+        assert_eq!(disabled.factor(Duration::days(365)), 1.0);
+    }
+
+    #[test]
+    fn reaction_type_from_gitea_content_round_trips() {
+        for reaction in [
+            ReactionType::Laugh,
+            ReactionType::Fire,
+            ReactionType::Skull,
+            ReactionType::Heart,
+            ReactionType::Eyes,
+        ] {
+            assert_eq!(
+                ReactionType::from_gitea_content(reaction.gitea_content()),
+                Some(reaction)
+            );
+        }
+        assert_eq!(ReactionType::from_gitea_content("confused"), None);
+    }
+
     #[test]
     fn reaction_type_from_str() {
         assert_eq!(
@@ -229,6 +487,48 @@ mod tests {
         assert_eq!(ReactionType::Skull.emoji(), "💀");
     }
 
+    #[test]
+    fn default_weights_score_fire_above_neutral_reaction() {
+        let weights = EngagementWeights::default();
+        let fire = EngagementCounts {
+            fire_count: 1,
+            ..Default::default()
+        };
+        let eyes = EngagementCounts {
+            eyes_count: 1,
+            ..Default::default()
+        };
+        assert!(weights.score(&fire) > weights.score(&eyes));
+    }
+
+    #[test]
+    fn weights_score_sums_all_kinds() {
+        let weights = EngagementWeights::default();
+        let counts = EngagementCounts {
+            laugh_count: 1,
+            fire_count: 1,
+            skull_count: 1,
+            heart_count: 1,
+            eyes_count: 1,
+            comment_count: 1,
+            review_count: 1,
+            total_score: 0,
+        };
+        assert_eq!(weights.score(&counts), 2 + 3 + 2 + 1 + 1 + 5 + 1);
+    }
+
+    #[test]
+    fn weights_from_str_overrides() {
+        let weights: EngagementWeights = "fire=10, heart=0".parse().unwrap();
+        assert_eq!(weights.fire, 10);
+        assert_eq!(weights.heart, 0);
+        assert_eq!(weights.laugh, EngagementWeights::default().laugh);
+
+        assert!("fire".parse::<EngagementWeights>().is_err());
+        assert!("fire=abc".parse::<EngagementWeights>().is_err());
+        assert!("sparkle=1".parse::<EngagementWeights>().is_err());
+    }
+
     #[test]
     fn target_type_from_str() {
         assert_eq!("pr".parse::<TargetType>().unwrap(), TargetType::Pr);
@@ -3,6 +3,7 @@
 //! Issues live in Gitea (source of truth). This entity represents
 //! the domain's view of an issue.
 
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
 use super::ProjectId;
@@ -40,6 +41,43 @@ pub struct Label {
     pub description: Option<String>,
 }
 
+/// A label that can be offered for a project's issues
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AvailableLabel {
+    #[serde(flatten)]
+    pub label: Label,
+    /// Applied to an issue but no longer defined in the repo
+    pub orphaned: bool,
+}
+
+impl AvailableLabel {
+    /// Merge the repo's labels with labels applied to issues, each name once.
+    /// Applied labels the repo no longer defines are appended as orphans.
+    pub fn reconcile(
+        repo_labels: Vec<Label>,
+        applied: impl IntoIterator<Item = Label>,
+    ) -> Vec<AvailableLabel> {
+        let mut available: Vec<AvailableLabel> = Vec::with_capacity(repo_labels.len());
+        for label in repo_labels {
+            if !available.iter().any(|a| a.label.name == label.name) {
+                available.push(AvailableLabel {
+                    label,
+                    orphaned: false,
+                });
+            }
+        }
+        for label in applied {
+            if !available.iter().any(|a| a.label.name == label.name) {
+                available.push(AvailableLabel {
+                    label,
+                    orphaned: true,
+                });
+            }
+        }
+        available
+    }
+}
+
 /// A comment on an issue
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct IssueComment {
@@ -79,6 +117,125 @@ impl std::str::FromStr for IssueState {
     }
 }
 
+/// How a label filter combines multiple labels
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum LabelMatch {
+    /// Issue must carry every label
+    #[default]
+    All,
+    /// Issue must carry at least one label
+    Any,
+}
+
+impl std::str::FromStr for LabelMatch {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "all" => Ok(LabelMatch::All),
+            "any" => Ok(LabelMatch::Any),
+            _ => Err(format!(
+                "Unknown label match mode: {} (expected all or any)",
+                s
+            )),
+        }
+    }
+}
+
+/// Filter issues by label names (case-insensitive)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LabelFilter {
+    pub labels: Vec<String>,
+    pub mode: LabelMatch,
+}
+
+impl LabelFilter {
+    /// Parse a comma-separated label list, e.g. `bug,help-wanted`
+    pub fn parse(labels: &str, mode: LabelMatch) -> Self {
+        Self {
+            labels: labels
+                .split(',')
+                .map(|l| l.trim().to_string())
+                .filter(|l| !l.is_empty())
+                .collect(),
+            mode,
+        }
+    }
+
+    /// Whether the filter can be answered entirely by Gitea's `labels`
+    /// query param, which only supports all-match
+    pub fn can_push_down(&self) -> bool {
+        self.mode == LabelMatch::All || self.labels.len() <= 1
+    }
+
+    /// Check whether a set of labels satisfies the filter
+    pub fn matches(&self, labels: &[Label]) -> bool {
+        let has = |name: &String| labels.iter().any(|l| l.name.eq_ignore_ascii_case(name));
+        match self.mode {
+            LabelMatch::All => self.labels.iter().all(has),
+            LabelMatch::Any => self.labels.is_empty() || self.labels.iter().any(has),
+        }
+    }
+}
+
+/// Order in which issue comments are listed
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum CommentOrder {
+    /// Oldest first (Gitea's order)
+    #[default]
+    Asc,
+    /// Newest first
+    Desc,
+}
+
+impl std::str::FromStr for CommentOrder {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "asc" => Ok(CommentOrder::Asc),
+            "desc" => Ok(CommentOrder::Desc),
+            _ => Err(format!(
+                "Unknown comment order: {} (expected asc or desc)",
+                s
+            )),
+        }
+    }
+}
+
+/// Which issue comments to list, and in what order
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CommentFilter {
+    /// Only comments updated at or after this time (Gitea's `since`)
+    pub since: Option<DateTime<Utc>>,
+    /// Only comments by this user (case-insensitive)
+    pub author: Option<String>,
+    pub order: CommentOrder,
+}
+
+impl CommentFilter {
+    /// Check whether a comment satisfies the filter
+    pub fn matches(&self, comment: &IssueComment) -> bool {
+        let by_author = self
+            .author
+            .as_ref()
+            .is_none_or(|a| comment.author.eq_ignore_ascii_case(a));
+        let recent = self.since.is_none_or(|since| {
+            DateTime::parse_from_rfc3339(&comment.updated_at).is_ok_and(|updated| updated >= since)
+        });
+        by_author && recent
+    }
+
+    /// Filter comments listed oldest first and put them in the requested order
+    pub fn apply(&self, comments: Vec<IssueComment>) -> Vec<IssueComment> {
+        let mut comments: Vec<_> = comments.into_iter().filter(|c| self.matches(c)).collect();
+        if self.order == CommentOrder::Desc {
+            comments.reverse();
+        }
+        comments
+    }
+}
+
 /// Data needed to create a new issue
 #[derive(Debug, Clone)]
 pub struct NewIssue {
@@ -86,6 +243,156 @@ pub struct NewIssue {
     pub body: String,
 }
 
+/// Metadata attached to a generated issue, used to pick labels for the feed
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct IssueMetadata {
+    /// e.g. "easy", "medium", "hard"
+    pub difficulty: Option<String>,
+    /// e.g. "bug", "feature", "docs"
+    pub kind: Option<String>,
+}
+
+/// Maps issue metadata values to the labels they should receive
+///
+/// A rule fires when the issue's difficulty or kind equals its key
+/// (case-insensitive). Several rules may share a key.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AutoLabelRules {
+    pub rules: Vec<(String, String)>,
+}
+
+impl Default for AutoLabelRules {
+    fn default() -> Self {
+        Self {
+            rules: [
+                ("easy", "good-first-issue"),
+                ("bug", "bug"),
+                ("feature", "enhancement"),
+                ("docs", "documentation"),
+            ]
+            .into_iter()
+            .map(|(key, label)| (key.to_string(), label.to_string()))
+            .collect(),
+        }
+    }
+}
+
+impl AutoLabelRules {
+    /// Labels for an issue, deduplicated and in rule order
+    pub fn labels_for(&self, metadata: &IssueMetadata) -> Vec<String> {
+        let values: Vec<&str> = [metadata.difficulty.as_deref(), metadata.kind.as_deref()]
+            .into_iter()
+            .flatten()
+            .map(str::trim)
+            .collect();
+
+        let mut labels: Vec<String> = Vec::new();
+        for (key, label) in &self.rules {
+            if values.iter().any(|v| v.eq_ignore_ascii_case(key)) && !labels.contains(label) {
+                labels.push(label.clone());
+            }
+        }
+        labels
+    }
+}
+
+impl std::str::FromStr for AutoLabelRules {
+    type Err = String;
+
+    /// Parse rules like "easy=good-first-issue,bug=bug"; replaces the defaults
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let rules = s
+            .split(',')
+            .map(str::trim)
+            .filter(|p| !p.is_empty())
+            .map(|pair| {
+                let (key, label) = pair
+                    .split_once('=')
+                    .ok_or_else(|| format!("Invalid rule '{}', expected <value>=<label>", pair))?;
+                let (key, label) = (key.trim(), label.trim());
+                if key.is_empty() || label.is_empty() {
+                    return Err(format!("Invalid rule '{}', expected <value>=<label>", pair));
+                }
+                Ok((key.to_lowercase(), label.to_string()))
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self { rules })
+    }
+}
+
+/// What an issue hook does when it fires
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum IssueHookAction {
+    /// Post this comment on the issue
+    Comment(String),
+    /// Apply this label to the issue (created in the repo if missing)
+    Label(String),
+}
+
+/// An action run when an issue moves into a state
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IssueHook {
+    /// State the issue transitions into (`closed` on close, `open` on reopen)
+    pub state: IssueState,
+    pub action: IssueHookAction,
+}
+
+/// Configured issue transition hooks (none by default)
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct IssueHooks {
+    pub hooks: Vec<IssueHook>,
+}
+
+impl IssueHooks {
+    /// Actions to run when an issue moves into `state`, in configuration order
+    pub fn actions_for(&self, state: IssueState) -> impl Iterator<Item = &IssueHookAction> {
+        self.hooks
+            .iter()
+            .filter(move |h| h.state == state)
+            .map(|h| &h.action)
+    }
+}
+
+impl std::str::FromStr for IssueHooks {
+    type Err = String;
+
+    /// Parse hooks like "closed:comment=Thanks!;open:label=needs-triage".
+    /// Hooks are separated by `;` so comments may contain commas.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let hooks = s
+            .split(';')
+            .map(str::trim)
+            .filter(|h| !h.is_empty())
+            .map(|hook| {
+                let invalid = || {
+                    format!(
+                        "Invalid hook '{}', expected <state>:<comment|label>=<value>",
+                        hook
+                    )
+                };
+                let (state, action) = hook.split_once(':').ok_or_else(invalid)?;
+                let (kind, value) = action.split_once('=').ok_or_else(invalid)?;
+                let value = value.trim();
+                if value.is_empty() {
+                    return Err(invalid());
+                }
+                let action = match kind.trim().to_lowercase().as_str() {
+                    "comment" => IssueHookAction::Comment(value.to_string()),
+                    "label" => IssueHookAction::Label(value.to_string()),
+                    other => return Err(format!("Unknown hook action: {}", other)),
+                };
+                Ok(IssueHook {
+                    state: state.trim().parse()?,
+                    action,
+                })
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self { hooks })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -97,12 +404,105 @@ mod tests {
         assert_eq!(IssueState::Closed.to_string(), "closed");
     }
 
+    fn comment(id: i64, author: &str, updated_at: &str) -> IssueComment {
+        IssueComment {
+            id,
+            body: format!("comment {}", id),
+            author: author.to_string(),
+            created_at: updated_at.to_string(),
+            updated_at: updated_at.to_string(),
+        }
+    }
+
+    #[test]
+    fn comment_filter_applies_since_and_order() {
+        let comments = vec![
+            comment(1, "alice", "2026-01-01T00:00:00Z"),
+            comment(2, "bob", "2026-01-02T00:00:00Z"),
+            comment(3, "alice", "2026-01-03T00:00:00Z"),
+        ];
+        let filter = CommentFilter {
+            since: Some("2026-01-02T00:00:00Z".parse().unwrap()),
+            author: None,
+            order: CommentOrder::Desc,
+        };
+
+        let ids: Vec<_> = filter.apply(comments).iter().map(|c| c.id).collect();
+
+        assert_eq!(ids, vec![3, 2]);
+        assert_eq!("DESC".parse::<CommentOrder>(), Ok(CommentOrder::Desc));
+        assert!("newest".parse::<CommentOrder>().is_err());
+    }
+
     #[test]
     fn issue_state_parse() {
         assert_eq!("open".parse::<IssueState>().unwrap(), IssueState::Open);
         assert_eq!("CLOSED".parse::<IssueState>().unwrap(), IssueState::Closed);
     }
 
+    fn labels(names: &[&str]) -> Vec<Label> {
+        names
+            .iter()
+            .map(|n| Label {
+                name: n.to_string(),
+                color: "ffffff".to_string(),
+                description: None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn reconcile_flags_applied_labels_missing_from_repo() {
+        let available = AvailableLabel::reconcile(
+            labels(&["bug", "enhancement"]),
+            labels(&["bug", "wontfix", "wontfix"]),
+        );
+
+        let names: Vec<_> = available
+            .iter()
+            .map(|a| (a.label.name.as_str(), a.orphaned))
+            .collect();
+        assert_eq!(
+            names,
+            vec![("bug", false), ("enhancement", false), ("wontfix", true)]
+        );
+    }
+
+    #[test]
+    fn label_filter_all_requires_every_label() {
+        let filter = LabelFilter::parse("bug, help-wanted", LabelMatch::All);
+        assert_eq!(filter.labels, vec!["bug", "help-wanted"]);
+
+        assert!(filter.matches(&labels(&["bug", "help-wanted", "ui"])));
+        assert!(filter.matches(&labels(&["Bug", "Help-Wanted"])));
+        assert!(!filter.matches(&labels(&["bug"])));
+        assert!(!filter.matches(&[]));
+    }
+
+    #[test]
+    fn label_filter_any_requires_one_label() {
+        let filter = LabelFilter::parse("bug,help-wanted", LabelMatch::Any);
+
+        assert!(filter.matches(&labels(&["bug"])));
+        assert!(filter.matches(&labels(&["help-wanted", "ui"])));
+        assert!(!filter.matches(&labels(&["ui"])));
+        assert!(!filter.matches(&[]));
+    }
+
+    #[test]
+    fn label_filter_push_down() {
+        assert!(LabelFilter::parse("bug,ui", LabelMatch::All).can_push_down());
+        assert!(LabelFilter::parse("bug", LabelMatch::Any).can_push_down());
+        assert!(!LabelFilter::parse("bug,ui", LabelMatch::Any).can_push_down());
+    }
+
+    #[test]
+    fn label_match_parse() {
+        assert_eq!("ANY".parse::<LabelMatch>().unwrap(), LabelMatch::Any);
+        assert_eq!("all".parse::<LabelMatch>().unwrap(), LabelMatch::All);
+        assert!("some".parse::<LabelMatch>().is_err());
+    }
+
     #[test]
     fn issue_id_equality() {
         let pid = ProjectId(Uuid::new_v4());
@@ -113,4 +513,63 @@ mod tests {
         assert_eq!(id1, id2);
         assert_ne!(id1, id3);
     }
+
+    #[test]
+    fn auto_label_rules_default_maps_easy_to_good_first_issue() {
+        let metadata = IssueMetadata {
+            difficulty: Some("Easy".to_string()),
+            kind: Some("bug".to_string()),
+        };
+        assert_eq!(
+            AutoLabelRules::default().labels_for(&metadata),
+            vec!["good-first-issue".to_string(), "bug".to_string()]
+        );
+    }
+
+    #[test]
+    fn auto_label_rules_without_metadata_yield_nothing() {
+        assert!(AutoLabelRules::default()
+            .labels_for(&IssueMetadata::default())
+            .is_empty());
+    }
+
+    #[test]
+    fn issue_hooks_parse_and_filter_by_state() {
+        let hooks: IssueHooks = "closed:comment=Thanks, closing this out!; open:label=needs-triage"
+            .parse()
+            .unwrap();
+
+        assert_eq!(
+            hooks.actions_for(IssueState::Closed).collect::<Vec<_>>(),
+            vec![&IssueHookAction::Comment(
+                "Thanks, closing this out!".to_string()
+            )]
+        );
+        assert_eq!(
+            hooks.actions_for(IssueState::Open).collect::<Vec<_>>(),
+            vec![&IssueHookAction::Label("needs-triage".to_string())]
+        );
+        assert!("closed:delete=yes".parse::<IssueHooks>().is_err());
+        assert!("merged:comment=hi".parse::<IssueHooks>().is_err());
+        assert!("closed:comment=".parse::<IssueHooks>().is_err());
+        assert!(IssueHooks::default()
+            .actions_for(IssueState::Closed)
+            .next()
+            .is_none());
+    }
+
+    #[test]
+    fn auto_label_rules_parse() {
+        let rules: AutoLabelRules = "easy=starter, easy=help-wanted,hard=epic".parse().unwrap();
+        let metadata = IssueMetadata {
+            difficulty: Some("easy".to_string()),
+            kind: None,
+        };
+        assert_eq!(
+            rules.labels_for(&metadata),
+            vec!["starter".to_string(), "help-wanted".to_string()]
+        );
+        assert!("easy".parse::<AutoLabelRules>().is_err());
+        assert!("=bug".parse::<AutoLabelRules>().is_err());
+    }
 }
@@ -9,32 +9,56 @@ pub mod code_contribution;
 pub mod elo_event;
 pub mod engagement;
 pub mod issue;
+pub mod pending_penalty;
 pub mod project;
+pub mod review_request;
 pub mod ticket;
 pub mod viral_moment;
+pub mod webhook_delivery;
 
-pub use agent::{Agent, AgentId, ClaimAgent, NewAgent, Tier};
+pub use agent::{
+    ActionEloThresholds, Agent, AgentId, AgentMergeCounts, ClaimAgent, GatedAction, NamePolicy,
+    NewAgent, OnboardingStatus, Tier, TierFlair,
+};
 // Re-export agent review types including threshold for domain consumers
 #[allow(unused_imports)]
 pub use agent_review::{
-    AgentReview, AgentReviewId, NewAgentReview, ReviewVerdict, HIGH_ELO_THRESHOLD,
+    AgentReview, AgentReviewId, NewAgentReview, ReviewVerdict, SelfReviewPolicy, HIGH_ELO_THRESHOLD,
 };
 pub use code_contribution::{
-    CodeContribution, CodeContributionId, ContributionStatus, NewCodeContribution,
+    CodeContribution, CodeContributionId, CodeReference, ContributionStatus, NewCodeContribution,
+    ProbationPolicy,
 };
-pub use elo_event::{EloEvent, EloEventId, EloEventType, NewEloEvent};
+pub use elo_event::{EloDrift, EloEvent, EloEventId, EloEventType, NewEloEvent};
 pub use engagement::{
-    Engagement, EngagementCounts, EngagementId, EngagementType, NewEngagement, ReactionType,
-    TargetType,
+    Engagement, EngagementCooldowns, EngagementCounts, EngagementDecay, EngagementId,
+    EngagementType, EngagementWeights, NewEngagement, ReactionType, TargetType,
+    ENGAGEMENT_COMMENT_COOLDOWN_SECS, ENGAGEMENT_HALF_LIFE_HOURS,
+    ENGAGEMENT_REACTION_COOLDOWN_SECS,
+};
+pub use issue::{
+    AutoLabelRules, AvailableLabel, CommentFilter, CommentOrder, Issue, IssueComment,
+    IssueHookAction, IssueHooks, IssueId, IssueMetadata, IssueState, Label, LabelFilter,
+    LabelMatch, NewIssue,
 };
-pub use issue::{Issue, IssueComment, IssueId, IssueState, Label, NewIssue};
+pub use pending_penalty::{NewPendingPenalty, PenaltyStatus, PendingPenalty, PendingPenaltyId};
 pub use project::{
-    BuildStatus, MemberRole, NewProject, Project, ProjectId, ProjectMember, ProjectStatus,
+    BuildStatus, LanguageAliases, MemberRole, NewProject, NonMemberMergePolicy, Project, ProjectId,
+    ProjectMember, ProjectMirror, ProjectStatus, ProjectVisibility, ReviewEligibility, Succession,
+    MAX_REQUIRED_APPROVALS,
+};
+pub use review_request::{
+    AbandonedReviewPolicy, NewReviewRequest, ReviewRequest, ReviewRequestId, ReviewRequestStatus,
+};
+pub use ticket::{
+    Difficulty, DifficultyWeights, NewTicket, Ticket, TicketId, TicketPriority, TicketStatus,
 };
-pub use ticket::{NewTicket, Ticket, TicketId, TicketPriority, TicketStatus};
 #[allow(unused_imports)]
 pub use viral_moment::{
-    BattleRacer, BattleSnapshot, DramaReviewer, DramaSnapshot, LlmClassification, MomentType,
-    NewViralMoment, ReferenceType, ShameSnapshot, UpsetLoser, UpsetSnapshot, ViralMoment,
-    ViralMomentId,
+    BattleRacer, BattleSnapshot, DramaReviewer, DramaSnapshot, LlmClassification, MomentFilter,
+    MomentSort, MomentType, NewViralMoment, ReferenceType, ShameSnapshot, UpsetLoser,
+    UpsetSnapshot, ViralMoment, ViralMomentId,
+};
+pub use webhook_delivery::{
+    DeliveryOutcome, NewWebhookDelivery, WebhookDelivery, WebhookDeliveryId,
 };
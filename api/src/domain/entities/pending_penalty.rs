@@ -0,0 +1,152 @@
+//! Pending penalty domain entity
+//!
+//! A PR-rejection penalty that has been recorded but not yet applied.
+//! Penalties wait out a grace period so that reopening the PR can cancel them.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use super::agent::AgentId;
+use super::project::ProjectId;
+
+/// Unique identifier for a pending penalty
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct PendingPenaltyId(pub Uuid);
+
+impl PendingPenaltyId {
+    pub fn new() -> Self {
+        Self(Uuid::new_v4())
+    }
+}
+
+impl Default for PendingPenaltyId {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl From<Uuid> for PendingPenaltyId {
+    fn from(id: Uuid) -> Self {
+        Self(id)
+    }
+}
+
+impl std::fmt::Display for PendingPenaltyId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// Lifecycle of a pending penalty
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PenaltyStatus {
+    /// Waiting for the grace period to elapse
+    Pending,
+    /// Grace period elapsed and the ELO deduction was applied
+    Applied,
+    /// PR was reopened (or merged) before the grace period elapsed
+    Canceled,
+}
+
+impl std::fmt::Display for PenaltyStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PenaltyStatus::Pending => write!(f, "pending"),
+            PenaltyStatus::Applied => write!(f, "applied"),
+            PenaltyStatus::Canceled => write!(f, "canceled"),
+        }
+    }
+}
+
+impl std::str::FromStr for PenaltyStatus {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "pending" => Ok(PenaltyStatus::Pending),
+            "applied" => Ok(PenaltyStatus::Applied),
+            "canceled" => Ok(PenaltyStatus::Canceled),
+            _ => Err(format!("Unknown penalty status: {}", s)),
+        }
+    }
+}
+
+/// A deferred PR-rejection penalty
+#[derive(Debug, Clone, Serialize)]
+pub struct PendingPenalty {
+    pub id: PendingPenaltyId,
+    pub agent_id: AgentId,
+    pub project_id: ProjectId,
+    /// Gitea org of the PR's repository (used to re-check the PR)
+    pub gitea_org: String,
+    /// Gitea repo of the PR's repository
+    pub gitea_repo: String,
+    pub pr_number: i64,
+    pub status: PenaltyStatus,
+    /// When the grace period elapses
+    pub apply_at: DateTime<Utc>,
+    pub resolved_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl PendingPenalty {
+    /// Check if the grace period has elapsed for a still-pending penalty
+    pub fn is_due(&self, now: DateTime<Utc>) -> bool {
+        self.status == PenaltyStatus::Pending && self.apply_at <= now
+    }
+}
+
+/// Data needed to record a new pending penalty
+#[derive(Debug, Clone)]
+pub struct NewPendingPenalty {
+    pub agent_id: AgentId,
+    pub project_id: ProjectId,
+    pub gitea_org: String,
+    pub gitea_repo: String,
+    pub pr_number: i64,
+    pub apply_at: DateTime<Utc>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    fn make_penalty(status: PenaltyStatus, apply_at: DateTime<Utc>) -> PendingPenalty {
+        PendingPenalty {
+            id: PendingPenaltyId::new(),
+            agent_id: AgentId::new(),
+            project_id: ProjectId::new(),
+            gitea_org: "org".to_string(),
+            gitea_repo: "repo".to_string(),
+            pr_number: 7,
+            status,
+            apply_at,
+            resolved_at: None,
+            created_at: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn penalty_status_round_trips() {
+        for status in [
+            PenaltyStatus::Pending,
+            PenaltyStatus::Applied,
+            PenaltyStatus::Canceled,
+        ] {
+            assert_eq!(status.to_string().parse::<PenaltyStatus>().unwrap(), status);
+        }
+        assert!("invalid".parse::<PenaltyStatus>().is_err());
+    }
+
+    #[test]
+    fn is_due_only_for_pending_past_apply_at() {
+        let now = Utc::now();
+        assert!(make_penalty(PenaltyStatus::Pending, now - Duration::seconds(1)).is_due(now));
+        assert!(make_penalty(PenaltyStatus::Pending, now).is_due(now));
+        assert!(!make_penalty(PenaltyStatus::Pending, now + Duration::seconds(1)).is_due(now));
+        assert!(!make_penalty(PenaltyStatus::Canceled, now - Duration::seconds(1)).is_due(now));
+    }
+}
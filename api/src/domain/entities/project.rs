@@ -107,6 +107,87 @@ impl std::str::FromStr for BuildStatus {
     }
 }
 
+/// Who can see a project
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ProjectVisibility {
+    /// Listed publicly and visible to everyone
+    #[default]
+    Public,
+    /// Left out of listings and the feed, but visible to anyone with its ID
+    Unlisted,
+    /// Visible to members only
+    Private,
+}
+
+impl std::fmt::Display for ProjectVisibility {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ProjectVisibility::Public => write!(f, "public"),
+            ProjectVisibility::Unlisted => write!(f, "unlisted"),
+            ProjectVisibility::Private => write!(f, "private"),
+        }
+    }
+}
+
+impl std::str::FromStr for ProjectVisibility {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "public" => Ok(ProjectVisibility::Public),
+            "unlisted" => Ok(ProjectVisibility::Unlisted),
+            "private" => Ok(ProjectVisibility::Private),
+            _ => Err(format!(
+                "Unknown project visibility: {} (use public, unlisted or private)",
+                s
+            )),
+        }
+    }
+}
+
+/// Who may peer-review a project's PRs
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ReviewEligibility {
+    /// Any agent may review
+    #[default]
+    Open,
+    /// Only project members may review
+    Members,
+}
+
+impl ReviewEligibility {
+    /// Whether an agent may review the project's PRs
+    pub fn permits(self, is_member: bool) -> bool {
+        self == ReviewEligibility::Open || is_member
+    }
+}
+
+impl std::fmt::Display for ReviewEligibility {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ReviewEligibility::Open => write!(f, "open"),
+            ReviewEligibility::Members => write!(f, "members"),
+        }
+    }
+}
+
+impl std::str::FromStr for ReviewEligibility {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "open" => Ok(ReviewEligibility::Open),
+            "members" => Ok(ReviewEligibility::Members),
+            _ => Err(format!(
+                "Unknown review eligibility: {} (use open or members)",
+                s
+            )),
+        }
+    }
+}
+
 /// An Ant Farm project where agents collaborate
 #[derive(Debug, Clone, Serialize)]
 pub struct Project {
@@ -122,6 +203,38 @@ pub struct Project {
     pub build_status: BuildStatus,
     pub created_by: Option<AgentId>,
     pub created_at: DateTime<Utc>,
+    /// Read-only mirror of an external source repository, if one was created
+    pub mirror: Option<ProjectMirror>,
+    /// Whether PRs may target a branch other than the repository's default branch
+    pub allow_alternate_bases: bool,
+    /// Whether PRs need a successful combined CI status before they can be merged
+    pub require_green_ci: bool,
+    /// Distinct approving reviewers a PR needs before it can be merged (0 = none)
+    pub required_approvals: i32,
+    /// Whether operators have curated this project onto the featured list
+    pub featured: bool,
+    /// Position on the featured list (lower comes first)
+    pub featured_rank: i32,
+    /// Scale applied to ELO awards and penalties earned on this project
+    pub elo_multiplier: f32,
+    /// Who can see the project in listings and lookups
+    pub visibility: ProjectVisibility,
+    /// Who may peer-review the project's PRs
+    pub review_eligibility: ReviewEligibility,
+    /// Whether the merge rules are mirrored onto the default branch's Gitea protection
+    pub protect_default_branch: bool,
+    /// Stars on the project's repository, as last read from Gitea
+    pub star_count: i32,
+}
+
+/// A local Gitea mirror of a project's external source repository
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ProjectMirror {
+    /// URL of the external repository being mirrored
+    pub source_url: String,
+    /// Mirror repository name within the project's Gitea org
+    pub repo: String,
+    pub mirrored_at: DateTime<Utc>,
 }
 
 impl Project {
@@ -147,6 +260,92 @@ pub struct NewProject {
     pub created_by: Option<AgentId>,
 }
 
+/// Maps alternate spellings of a project language onto one canonical name,
+/// so "Golang" and "go" land in the same listing filter and facet
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LanguageAliases {
+    /// (alias, canonical) pairs, both lowercase
+    pub aliases: Vec<(String, String)>,
+}
+
+impl Default for LanguageAliases {
+    fn default() -> Self {
+        Self {
+            aliases: [
+                ("golang", "go"),
+                ("rs", "rust"),
+                ("js", "javascript"),
+                ("ts", "typescript"),
+                ("py", "python"),
+            ]
+            .into_iter()
+            .map(|(alias, canonical)| (alias.to_string(), canonical.to_string()))
+            .collect(),
+        }
+    }
+}
+
+impl LanguageAliases {
+    /// Normalized name for a language: trimmed, lowercased and de-aliased.
+    /// Blank input has no language.
+    pub fn canonical(&self, language: &str) -> Option<String> {
+        let normalized = language.trim().to_lowercase();
+        if normalized.is_empty() {
+            return None;
+        }
+        Some(
+            self.aliases
+                .iter()
+                .find(|(alias, _)| *alias == normalized)
+                .map(|(_, canonical)| canonical.clone())
+                .unwrap_or(normalized),
+        )
+    }
+
+    /// Every normalized spelling stored projects may use for `language`
+    /// (its canonical name first, then its aliases)
+    pub fn spellings(&self, language: &str) -> Vec<String> {
+        let Some(canonical) = self.canonical(language) else {
+            return Vec::new();
+        };
+        let mut spellings = vec![canonical.clone()];
+        for (alias, target) in &self.aliases {
+            if *target == canonical && !spellings.contains(alias) {
+                spellings.push(alias.clone());
+            }
+        }
+        spellings
+    }
+}
+
+impl std::str::FromStr for LanguageAliases {
+    type Err = String;
+
+    /// Parse aliases like "golang=go,rs=rust"; replaces the defaults
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let aliases = s
+            .split(',')
+            .map(str::trim)
+            .filter(|p| !p.is_empty())
+            .map(|pair| {
+                let (alias, canonical) = pair.split_once('=').ok_or_else(|| {
+                    format!("Invalid alias '{}', expected <alias>=<language>", pair)
+                })?;
+                let (alias, canonical) = (alias.trim(), canonical.trim());
+                if alias.is_empty() || canonical.is_empty() {
+                    return Err(format!(
+                        "Invalid alias '{}', expected <alias>=<language>",
+                        pair
+                    ));
+                }
+                Ok((alias.to_lowercase(), canonical.to_lowercase()))
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self { aliases })
+    }
+}
+
 /// Role of a member in a project
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
@@ -179,6 +378,29 @@ impl std::str::FromStr for MemberRole {
     }
 }
 
+/// What happens when a merged PR's author isn't a member of the project
+/// (e.g. they opened it in Gitea directly)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum NonMemberMergePolicy {
+    /// Record nothing and award no ELO
+    Skip,
+    /// Enroll the author as a contributor, then award ELO as usual
+    #[default]
+    Enroll,
+}
+
+impl std::str::FromStr for NonMemberMergePolicy {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "skip" => Ok(NonMemberMergePolicy::Skip),
+            "enroll" => Ok(NonMemberMergePolicy::Enroll),
+            _ => Err(format!("Unknown non-member merge policy: {}", s)),
+        }
+    }
+}
+
 /// A project member
 #[derive(Debug, Clone, Serialize)]
 pub struct ProjectMember {
@@ -188,6 +410,79 @@ pub struct ProjectMember {
     pub joined_at: DateTime<Utc>,
 }
 
+/// Most approving reviews a PR can be required to have before merging
+pub const MAX_REQUIRED_APPROVALS: i32 = 10;
+
+/// Days an owner must be inactive before their role can be claimed
+pub const OWNER_INACTIVITY_DAYS: i64 = 30;
+
+/// Days every maintainer must be inactive before a maintainer role can be claimed
+pub const MAINTAINER_INACTIVITY_DAYS: i64 = 14;
+
+/// Which leadership roles of a project are abandoned and up for claiming
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Succession {
+    pub owner_claimable: bool,
+    /// Days since the owner was last active (if claimable)
+    pub owner_inactive_days: Option<i64>,
+    pub maintainer_claimable: bool,
+    /// Days since a maintainer was last active (if claimable)
+    pub maintainer_inactive_days: Option<i64>,
+}
+
+impl Succession {
+    /// Assess a project from its leaders' roles and when each was last active.
+    ///
+    /// Contributors in `leaders` are ignored. The maintainer role is only
+    /// claimable when the project has maintainers and all of them are inactive.
+    pub fn assess(
+        leaders: impl IntoIterator<Item = (MemberRole, DateTime<Utc>)>,
+        now: DateTime<Utc>,
+    ) -> Self {
+        let mut succession = Self::default();
+        let mut has_maintainers = false;
+        let mut all_maintainers_inactive = true;
+
+        for (role, last_active) in leaders {
+            let inactive_days = (now - last_active).num_days();
+            match role {
+                MemberRole::Owner if inactive_days >= OWNER_INACTIVITY_DAYS => {
+                    succession.owner_claimable = true;
+                    succession.owner_inactive_days = Some(inactive_days);
+                }
+                MemberRole::Maintainer => {
+                    has_maintainers = true;
+                    if inactive_days < MAINTAINER_INACTIVITY_DAYS {
+                        all_maintainers_inactive = false;
+                    } else {
+                        succession.maintainer_inactive_days = Some(inactive_days);
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        succession.maintainer_claimable = has_maintainers && all_maintainers_inactive;
+        succession
+    }
+
+    /// The role a member holding `role` may claim, if any.
+    ///
+    /// Ownership goes to maintainers or contributors; a maintainer role only
+    /// to contributors. Non-members can claim nothing.
+    pub fn claimable_by(&self, role: Option<MemberRole>) -> Option<MemberRole> {
+        match role {
+            Some(MemberRole::Maintainer | MemberRole::Contributor) if self.owner_claimable => {
+                Some(MemberRole::Owner)
+            }
+            Some(MemberRole::Contributor) if self.maintainer_claimable => {
+                Some(MemberRole::Maintainer)
+            }
+            _ => None,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -206,6 +501,17 @@ mod tests {
             build_status: BuildStatus::Passing,
             created_by: None,
             created_at: Utc::now(),
+            mirror: None,
+            allow_alternate_bases: false,
+            require_green_ci: false,
+            required_approvals: 0,
+            featured: false,
+            featured_rank: 0,
+            elo_multiplier: 1.0,
+            visibility: ProjectVisibility::Public,
+            review_eligibility: ReviewEligibility::Open,
+            protect_default_branch: false,
+            star_count: 0,
         }
     }
 
@@ -305,6 +611,47 @@ mod tests {
         assert!("invalid".parse::<MemberRole>().is_err());
     }
 
+    #[test]
+    fn succession_requires_every_maintainer_inactive() {
+        let now = Utc::now();
+        let stale = now - chrono::Duration::days(MAINTAINER_INACTIVITY_DAYS);
+        let fresh = now - chrono::Duration::days(1);
+
+        let succession = Succession::assess(
+            [
+                (MemberRole::Owner, fresh),
+                (MemberRole::Maintainer, stale),
+                (MemberRole::Maintainer, fresh),
+            ],
+            now,
+        );
+        assert!(!succession.owner_claimable);
+        assert!(!succession.maintainer_claimable);
+
+        let succession = Succession::assess([(MemberRole::Maintainer, stale)], now);
+        assert!(succession.maintainer_claimable);
+        assert_eq!(
+            succession.claimable_by(Some(MemberRole::Contributor)),
+            Some(MemberRole::Maintainer)
+        );
+        assert_eq!(succession.claimable_by(Some(MemberRole::Maintainer)), None);
+        assert_eq!(succession.claimable_by(None), None);
+    }
+
+    #[test]
+    fn language_aliases_normalize_and_expand() {
+        let aliases = LanguageAliases::default();
+        assert_eq!(aliases.canonical("  Rust "), Some("rust".to_string()));
+        assert_eq!(aliases.canonical("Golang"), Some("go".to_string()));
+        assert_eq!(aliases.canonical("   "), None);
+        assert_eq!(aliases.spellings("GO"), vec!["go", "golang"]);
+
+        let custom: LanguageAliases = "C++=cpp, cxx=cpp".parse().unwrap();
+        assert_eq!(custom.canonical("c++"), Some("cpp".to_string()));
+        assert_eq!(custom.canonical("golang"), Some("golang".to_string()));
+        assert!("cpp".parse::<LanguageAliases>().is_err());
+    }
+
     #[test]
     fn project_id_display() {
         let id = ProjectId(Uuid::nil());
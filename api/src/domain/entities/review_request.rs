@@ -0,0 +1,190 @@
+//! Review request domain entity
+//!
+//! A review asked of a specific agent on a PR. Requests that go unanswered
+//! past their deadline are either handed to another member or cost the
+//! reviewer a little ELO, so a no-show doesn't leave the PR author blocked.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use super::agent::AgentId;
+use super::project::ProjectId;
+
+/// Unique identifier for a review request
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct ReviewRequestId(pub Uuid);
+
+impl ReviewRequestId {
+    pub fn new() -> Self {
+        Self(Uuid::new_v4())
+    }
+}
+
+impl Default for ReviewRequestId {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl From<Uuid> for ReviewRequestId {
+    fn from(id: Uuid) -> Self {
+        Self(id)
+    }
+}
+
+impl std::fmt::Display for ReviewRequestId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// Lifecycle of a review request
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ReviewRequestStatus {
+    /// Waiting for the reviewer to submit a review
+    Pending,
+    /// The reviewer submitted a review
+    Completed,
+    /// The deadline passed and the review was handed to another member
+    Reassigned,
+    /// The deadline passed and the reviewer lost ELO
+    Penalized,
+    /// The PR was merged or closed before the deadline
+    Canceled,
+}
+
+impl std::fmt::Display for ReviewRequestStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ReviewRequestStatus::Pending => write!(f, "pending"),
+            ReviewRequestStatus::Completed => write!(f, "completed"),
+            ReviewRequestStatus::Reassigned => write!(f, "reassigned"),
+            ReviewRequestStatus::Penalized => write!(f, "penalized"),
+            ReviewRequestStatus::Canceled => write!(f, "canceled"),
+        }
+    }
+}
+
+impl std::str::FromStr for ReviewRequestStatus {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "pending" => Ok(ReviewRequestStatus::Pending),
+            "completed" => Ok(ReviewRequestStatus::Completed),
+            "reassigned" => Ok(ReviewRequestStatus::Reassigned),
+            "penalized" => Ok(ReviewRequestStatus::Penalized),
+            "canceled" => Ok(ReviewRequestStatus::Canceled),
+            _ => Err(format!("Unknown review request status: {}", s)),
+        }
+    }
+}
+
+/// What happens to a review request nobody answered in time
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AbandonedReviewPolicy {
+    /// Deduct a small amount of ELO from the reviewer
+    #[default]
+    Penalize,
+    /// Request the review from another project member instead (falls back
+    /// to the penalty when no other member can take it)
+    Reassign,
+}
+
+impl std::fmt::Display for AbandonedReviewPolicy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AbandonedReviewPolicy::Penalize => write!(f, "penalize"),
+            AbandonedReviewPolicy::Reassign => write!(f, "reassign"),
+        }
+    }
+}
+
+impl std::str::FromStr for AbandonedReviewPolicy {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "penalize" => Ok(AbandonedReviewPolicy::Penalize),
+            "reassign" => Ok(AbandonedReviewPolicy::Reassign),
+            _ => Err(format!("Unknown abandoned review policy: {}", s)),
+        }
+    }
+}
+
+/// An outstanding (or resolved) request for an agent to review a PR
+#[derive(Debug, Clone, Serialize)]
+pub struct ReviewRequest {
+    pub id: ReviewRequestId,
+    pub project_id: ProjectId,
+    /// Gitea org of the PR's repository (used to re-check the PR)
+    pub gitea_org: String,
+    /// Gitea repo of the PR's repository
+    pub gitea_repo: String,
+    pub pr_number: i64,
+    pub reviewer_agent_id: AgentId,
+    /// Agent who asked for the review, if it came through the API
+    pub requested_by: Option<AgentId>,
+    pub status: ReviewRequestStatus,
+    /// When the review is considered abandoned
+    pub due_at: DateTime<Utc>,
+    pub resolved_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl ReviewRequest {
+    /// Check if a still-pending request has passed its deadline
+    pub fn is_overdue(&self, now: DateTime<Utc>) -> bool {
+        self.status == ReviewRequestStatus::Pending && self.due_at <= now
+    }
+}
+
+/// Data needed to record a new review request
+#[derive(Debug, Clone)]
+pub struct NewReviewRequest {
+    pub project_id: ProjectId,
+    pub gitea_org: String,
+    pub gitea_repo: String,
+    pub pr_number: i64,
+    pub reviewer_agent_id: AgentId,
+    pub requested_by: Option<AgentId>,
+    pub due_at: DateTime<Utc>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn review_request_status_round_trips() {
+        for status in [
+            ReviewRequestStatus::Pending,
+            ReviewRequestStatus::Completed,
+            ReviewRequestStatus::Reassigned,
+            ReviewRequestStatus::Penalized,
+            ReviewRequestStatus::Canceled,
+        ] {
+            assert_eq!(
+                status.to_string().parse::<ReviewRequestStatus>().unwrap(),
+                status
+            );
+        }
+        assert!("invalid".parse::<ReviewRequestStatus>().is_err());
+    }
+
+    #[test]
+    fn abandoned_review_policy_parses() {
+        assert_eq!(
+            "reassign".parse::<AbandonedReviewPolicy>().unwrap(),
+            AbandonedReviewPolicy::Reassign
+        );
+        assert_eq!(
+            AbandonedReviewPolicy::default(),
+            AbandonedReviewPolicy::Penalize
+        );
+        assert!("ignore".parse::<AbandonedReviewPolicy>().is_err());
+    }
+}
@@ -3,6 +3,7 @@
 //! Represents a project ticket/issue that agents can work on in Ant Farm mode.
 
 use chrono::{DateTime, Utc};
+use regex::Regex;
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
@@ -43,14 +44,24 @@ impl std::fmt::Display for TicketId {
 pub enum TicketStatus {
     Open,
     InProgress,
+    /// Waiting on something outside the ticket (see `Ticket::blocked_reason`)
+    Blocked,
     Closed,
 }
 
+impl TicketStatus {
+    /// Whether an agent is working the ticket (and so can abandon it)
+    pub fn is_claimed(self) -> bool {
+        matches!(self, TicketStatus::InProgress | TicketStatus::Blocked)
+    }
+}
+
 impl std::fmt::Display for TicketStatus {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             TicketStatus::Open => write!(f, "open"),
             TicketStatus::InProgress => write!(f, "in_progress"),
+            TicketStatus::Blocked => write!(f, "blocked"),
             TicketStatus::Closed => write!(f, "closed"),
         }
     }
@@ -63,6 +74,7 @@ impl std::str::FromStr for TicketStatus {
         match s.to_lowercase().as_str() {
             "open" => Ok(TicketStatus::Open),
             "in_progress" | "inprogress" => Ok(TicketStatus::InProgress),
+            "blocked" => Ok(TicketStatus::Blocked),
             "closed" => Ok(TicketStatus::Closed),
             _ => Err(format!("Unknown ticket status: {}", s)),
         }
@@ -104,6 +116,120 @@ impl std::str::FromStr for TicketPriority {
     }
 }
 
+/// How hard a ticket is expected to be
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Difficulty {
+    Easy,
+    #[default]
+    Medium,
+    Hard,
+}
+
+impl Difficulty {
+    /// Infer difficulty from a ticket's text. Recognizes explicit markers like
+    /// "difficulty: hard" and the "good first issue" convention; anything else
+    /// is Medium.
+    pub fn infer(title: &str, body: Option<&str>) -> Self {
+        let text = format!("{}\n{}", title, body.unwrap_or_default());
+        let marker = Regex::new(r"(?i)\bdifficulty\s*[:=/]\s*(easy|medium|hard)\b")
+            .expect("difficulty marker regex is valid");
+
+        if let Some(level) = marker.captures(&text).and_then(|c| c[1].parse().ok()) {
+            return level;
+        }
+        if text.to_lowercase().contains("good first issue") {
+            return Difficulty::Easy;
+        }
+        Difficulty::Medium
+    }
+}
+
+impl std::fmt::Display for Difficulty {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Difficulty::Easy => write!(f, "easy"),
+            Difficulty::Medium => write!(f, "medium"),
+            Difficulty::Hard => write!(f, "hard"),
+        }
+    }
+}
+
+impl std::str::FromStr for Difficulty {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "easy" => Ok(Difficulty::Easy),
+            "medium" => Ok(Difficulty::Medium),
+            "hard" => Ok(Difficulty::Hard),
+            _ => Err(format!("Unknown difficulty: {}", s)),
+        }
+    }
+}
+
+/// Percentage weights applied per difficulty to the merge ELO award and
+/// to feed ordering (100 = unweighted)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DifficultyWeights {
+    pub easy: i32,
+    pub medium: i32,
+    pub hard: i32,
+}
+
+impl Default for DifficultyWeights {
+    fn default() -> Self {
+        Self {
+            easy: 75,
+            medium: 100,
+            hard: 150,
+        }
+    }
+}
+
+impl DifficultyWeights {
+    /// Weight for a difficulty, in percent
+    pub fn weight(&self, difficulty: Difficulty) -> i32 {
+        match difficulty {
+            Difficulty::Easy => self.easy,
+            Difficulty::Medium => self.medium,
+            Difficulty::Hard => self.hard,
+        }
+    }
+
+    /// Scale an ELO award by the weight for `difficulty`
+    pub fn scale(&self, award: i32, difficulty: Difficulty) -> i32 {
+        award * self.weight(difficulty) / 100
+    }
+}
+
+impl std::str::FromStr for DifficultyWeights {
+    type Err = String;
+
+    /// Parse overrides like "easy=50,hard=200"; unspecified levels keep their default
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut weights = Self::default();
+
+        for pair in s.split(',').map(str::trim).filter(|p| !p.is_empty()) {
+            let (key, value) = pair.split_once('=').ok_or_else(|| {
+                format!("Invalid weight '{}', expected <difficulty>=<percent>", pair)
+            })?;
+            let value: i32 = value
+                .trim()
+                .parse()
+                .map_err(|_| format!("Invalid weight value for '{}': {}", key, value))?;
+
+            match key.trim().parse::<Difficulty>()? {
+                Difficulty::Easy => weights.easy = value,
+                Difficulty::Medium => weights.medium = value,
+                Difficulty::Hard => weights.hard = value,
+            }
+        }
+
+        Ok(weights)
+    }
+}
+
 /// A project ticket that agents can work on
 #[derive(Debug, Clone, Serialize)]
 pub struct Ticket {
@@ -116,14 +242,22 @@ pub struct Ticket {
     pub gitea_issue_url: Option<String>,
     pub status: TicketStatus,
     pub priority: TicketPriority,
+    pub difficulty: Difficulty,
     /// Agent currently working on this ticket
     pub assigned_to: Option<AgentId>,
     pub created_by: Option<AgentId>,
     pub created_at: DateTime<Utc>,
     pub closed_at: Option<DateTime<Utc>>,
+    /// Why the ticket is blocked (set while status is `Blocked`)
+    pub blocked_reason: Option<String>,
 }
 
 impl Ticket {
+    /// Check if the ticket is blocked and should be left out of actionable lists
+    pub fn is_blocked(&self) -> bool {
+        self.status == TicketStatus::Blocked
+    }
+
     /// Check if the ticket is available for assignment
     pub fn is_available(&self) -> bool {
         self.status == TicketStatus::Open && self.assigned_to.is_none()
@@ -146,9 +280,19 @@ pub struct NewTicket {
     pub gitea_issue_number: Option<i32>,
     pub gitea_issue_url: Option<String>,
     pub priority: TicketPriority,
+    /// Explicit difficulty; inferred from the title and body when unset
+    pub difficulty: Option<Difficulty>,
     pub created_by: Option<AgentId>,
 }
 
+impl NewTicket {
+    /// The difficulty to store: the explicit one, or one inferred from the text
+    pub fn resolved_difficulty(&self) -> Difficulty {
+        self.difficulty
+            .unwrap_or_else(|| Difficulty::infer(&self.title, self.body.as_deref()))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -163,10 +307,12 @@ mod tests {
             gitea_issue_url: Some("https://gitea.test/issue/1".to_string()),
             status,
             priority: TicketPriority::Medium,
+            difficulty: Difficulty::Medium,
             assigned_to,
             created_by: None,
             created_at: Utc::now(),
             closed_at: None,
+            blocked_reason: None,
         }
     }
 
@@ -194,6 +340,13 @@ mod tests {
         assert!(!ticket.is_available());
     }
 
+    #[test]
+    fn ticket_is_not_available_when_blocked() {
+        let ticket = make_ticket(TicketStatus::Blocked, None);
+        assert!(ticket.is_blocked());
+        assert!(!ticket.is_available());
+    }
+
     #[test]
     fn can_assign_to_agent_when_open_and_unassigned() {
         let ticket = make_ticket(TicketStatus::Open, None);
@@ -220,6 +373,7 @@ mod tests {
     fn ticket_status_display() {
         assert_eq!(TicketStatus::Open.to_string(), "open");
         assert_eq!(TicketStatus::InProgress.to_string(), "in_progress");
+        assert_eq!(TicketStatus::Blocked.to_string(), "blocked");
         assert_eq!(TicketStatus::Closed.to_string(), "closed");
     }
 
@@ -238,6 +392,10 @@ mod tests {
             "closed".parse::<TicketStatus>().unwrap(),
             TicketStatus::Closed
         );
+        assert_eq!(
+            "blocked".parse::<TicketStatus>().unwrap(),
+            TicketStatus::Blocked
+        );
         assert!("invalid".parse::<TicketStatus>().is_err());
     }
 
@@ -270,6 +428,58 @@ mod tests {
         assert!("invalid".parse::<TicketPriority>().is_err());
     }
 
+    #[test]
+    fn difficulty_from_str() {
+        assert_eq!("easy".parse::<Difficulty>().unwrap(), Difficulty::Easy);
+        assert_eq!("HARD".parse::<Difficulty>().unwrap(), Difficulty::Hard);
+        assert!("extreme".parse::<Difficulty>().is_err());
+    }
+
+    #[test]
+    fn difficulty_inferred_from_markers() {
+        assert_eq!(
+            Difficulty::infer("Rewrite scheduler", Some("Difficulty: hard")),
+            Difficulty::Hard
+        );
+        assert_eq!(
+            Difficulty::infer("Fix typo (good first issue)", None),
+            Difficulty::Easy
+        );
+        assert_eq!(Difficulty::infer("Add logging", None), Difficulty::Medium);
+    }
+
+    #[test]
+    fn explicit_difficulty_wins_over_inference() {
+        let ticket = NewTicket {
+            project_id: ProjectId::new(),
+            title: "difficulty: easy".to_string(),
+            body: None,
+            gitea_issue_number: None,
+            gitea_issue_url: None,
+            priority: TicketPriority::Medium,
+            difficulty: Some(Difficulty::Hard),
+            created_by: None,
+        };
+        assert_eq!(ticket.resolved_difficulty(), Difficulty::Hard);
+    }
+
+    #[test]
+    fn difficulty_weights_favor_hard_tickets() {
+        let weights = DifficultyWeights::default();
+        assert!(weights.scale(15, Difficulty::Hard) > weights.scale(15, Difficulty::Easy));
+        assert_eq!(weights.scale(15, Difficulty::Medium), 15);
+    }
+
+    #[test]
+    fn difficulty_weights_parse_overrides() {
+        let weights: DifficultyWeights = "easy=50, hard=200".parse().unwrap();
+        assert_eq!(weights.easy, 50);
+        assert_eq!(weights.medium, 100);
+        assert_eq!(weights.hard, 200);
+        assert!("insane=300".parse::<DifficultyWeights>().is_err());
+        assert!("hard".parse::<DifficultyWeights>().is_err());
+    }
+
     #[test]
     fn ticket_id_display() {
         let id = TicketId(uuid::Uuid::nil());
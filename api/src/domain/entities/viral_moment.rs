@@ -7,7 +7,10 @@ use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
-use super::AgentId;
+use super::{AgentId, EngagementCounts};
+
+/// Longest text field kept in a compacted snapshot
+const SNAPSHOT_SUMMARY_MAX_CHARS: usize = 200;
 
 /// Unique identifier for a viral moment
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
@@ -98,6 +101,46 @@ impl std::str::FromStr for MomentType {
     }
 }
 
+/// Ordering for filtered moment lists
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MomentSort {
+    /// Highest score first, newest breaking ties
+    #[default]
+    Score,
+    /// Newest first
+    Recent,
+}
+
+impl std::str::FromStr for MomentSort {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "score" => Ok(MomentSort::Score),
+            "recent" => Ok(MomentSort::Recent),
+            _ => Err(format!("Unknown moment sort: {}", s)),
+        }
+    }
+}
+
+/// Filter for listing moments; `None` fields match any value
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MomentFilter {
+    pub moment_type: Option<MomentType>,
+    pub promoted: Option<bool>,
+    pub hidden: Option<bool>,
+    pub sort: MomentSort,
+}
+
+impl MomentFilter {
+    /// Whether `moment` passes the filter (ignores `sort`)
+    pub fn matches(&self, moment: &ViralMoment) -> bool {
+        self.moment_type.is_none_or(|t| moment.moment_type == t)
+            && self.promoted.is_none_or(|p| moment.promoted == p)
+            && self.hidden.is_none_or(|h| moment.hidden == h)
+    }
+}
+
 /// Reference type for what triggered the moment
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
@@ -130,6 +173,10 @@ impl std::str::FromStr for ReferenceType {
     }
 }
 
+// Snapshots are captured when a moment is created so it stays self-contained
+// even if the agents, PRs, or issues it refers to change later. Fields added
+// after the first release are `#[serde(default)]` so older rows still parse.
+
 /// Snapshot data for Hall of Shame moments
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ShameSnapshot {
@@ -140,25 +187,32 @@ pub struct ShameSnapshot {
     pub exit_code: Option<i32>,
     pub issue_title: String,
     pub issue_difficulty: String,
+    #[serde(default)]
+    pub pr_number: Option<i64>,
+    #[serde(default)]
+    pub project: Option<String>,
+    /// Engagement on the source at creation time, if it has an engagement target
+    #[serde(default)]
+    pub engagement: Option<EngagementCounts>,
 }
 
 /// Snapshot data for Agent Drama moments
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DramaSnapshot {
+    pub pr_number: i64,
     pub pr_title: String,
-    pub pr_url: String,
-    pub author_name: String,
-    pub author_elo: i32,
-    pub reviewers: Vec<DramaReviewer>,
-    pub conflict_summary: String,
+    pub project: String,
+    pub approvers: Vec<DramaReviewer>,
+    pub rejectors: Vec<DramaReviewer>,
+    #[serde(default)]
+    pub engagement: Option<EngagementCounts>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DramaReviewer {
     pub name: String,
     pub elo: i32,
-    pub verdict: String, // "approved" or "rejected"
-    pub comment: Option<String>,
+    pub tier: String,
 }
 
 /// Snapshot data for David vs Goliath moments
@@ -171,6 +225,10 @@ pub struct UpsetSnapshot {
     pub issue_title: String,
     pub issue_difficulty: String,
     pub elo_differential: i32,
+    #[serde(default)]
+    pub issue_id: Option<Uuid>,
+    #[serde(default)]
+    pub engagement: Option<EngagementCounts>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -189,6 +247,8 @@ pub struct BattleSnapshot {
     pub started_at: DateTime<Utc>,
     pub ended_at: Option<DateTime<Utc>>,
     pub winner_id: Option<Uuid>,
+    #[serde(default)]
+    pub engagement: Option<EngagementCounts>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -228,9 +288,35 @@ pub struct ViralMoment {
     pub llm_classified: bool,
     pub llm_classification: Option<LlmClassification>,
     pub created_at: DateTime<Utc>,
+    /// When the snapshot was reduced to a summary by retention
+    pub snapshot_compacted_at: Option<DateTime<Utc>>,
 }
 
 impl ViralMoment {
+    /// Minimal summary of the snapshot kept once the moment ages out: short
+    /// scalar fields survive, lists shrink to a `<field>_count`, and nested
+    /// objects and long text (stderr, diffs) are dropped
+    pub fn compacted_snapshot(&self) -> serde_json::Value {
+        let mut summary = serde_json::Map::new();
+        if let serde_json::Value::Object(fields) = &self.snapshot {
+            for (key, value) in fields {
+                match value {
+                    serde_json::Value::String(s)
+                        if s.chars().count() > SNAPSHOT_SUMMARY_MAX_CHARS => {}
+                    serde_json::Value::Array(items) => {
+                        summary.insert(format!("{}_count", key), items.len().into());
+                    }
+                    serde_json::Value::Object(_) => {}
+                    scalar => {
+                        summary.insert(key.clone(), scalar.clone());
+                    }
+                }
+            }
+        }
+        summary.insert("compacted".to_string(), true.into());
+        serde_json::Value::Object(summary)
+    }
+
     /// Parse snapshot as ShameSnapshot
     pub fn as_shame_snapshot(&self) -> Option<ShameSnapshot> {
         if self.moment_type == MomentType::HallOfShame {
@@ -0,0 +1,164 @@
+//! Webhook delivery domain entity
+//!
+//! A log entry for one received Gitea webhook and how its processing ended.
+//! Used to debug why an expected ELO change never happened.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// Unique identifier for a logged webhook delivery
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct WebhookDeliveryId(pub Uuid);
+
+impl WebhookDeliveryId {
+    pub fn new() -> Self {
+        Self(Uuid::new_v4())
+    }
+}
+
+impl Default for WebhookDeliveryId {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl From<Uuid> for WebhookDeliveryId {
+    fn from(id: Uuid) -> Self {
+        Self(id)
+    }
+}
+
+impl std::fmt::Display for WebhookDeliveryId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// How processing of a webhook ended
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DeliveryOutcome {
+    /// Accepted and waiting for a worker
+    Queued,
+    /// Handled without error
+    Success,
+    /// Skipped because the same delivery was already processed
+    Duplicate,
+    /// Rejected because the signature didn't verify
+    SignatureFailed,
+    /// Rejected because the body wasn't a valid payload
+    InvalidPayload,
+    /// The event handler returned an error
+    Failed,
+}
+
+impl std::fmt::Display for DeliveryOutcome {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DeliveryOutcome::Queued => write!(f, "queued"),
+            DeliveryOutcome::Success => write!(f, "success"),
+            DeliveryOutcome::Duplicate => write!(f, "duplicate"),
+            DeliveryOutcome::SignatureFailed => write!(f, "signature_failed"),
+            DeliveryOutcome::InvalidPayload => write!(f, "invalid_payload"),
+            DeliveryOutcome::Failed => write!(f, "failed"),
+        }
+    }
+}
+
+impl std::str::FromStr for DeliveryOutcome {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "queued" => Ok(DeliveryOutcome::Queued),
+            "success" => Ok(DeliveryOutcome::Success),
+            "duplicate" => Ok(DeliveryOutcome::Duplicate),
+            "signature_failed" => Ok(DeliveryOutcome::SignatureFailed),
+            "invalid_payload" => Ok(DeliveryOutcome::InvalidPayload),
+            "failed" => Ok(DeliveryOutcome::Failed),
+            _ => Err(format!("Unknown delivery outcome: {}", s)),
+        }
+    }
+}
+
+/// A received webhook and its processing result
+#[derive(Debug, Clone, Serialize)]
+pub struct WebhookDelivery {
+    pub id: WebhookDeliveryId,
+    /// Gitea's `X-Gitea-Delivery` id (kept across redeliveries)
+    pub delivery_id: Option<String>,
+    pub event_type: String,
+    /// Full name of the repository the event came from, when the payload parsed
+    pub repo: Option<String>,
+    pub action: Option<String>,
+    pub outcome: DeliveryOutcome,
+    pub error: Option<String>,
+    pub received_at: DateTime<Utc>,
+    /// Raw body, kept while the delivery is queued so it survives a restart
+    #[serde(skip)]
+    pub payload: Option<String>,
+}
+
+/// Data needed to log a webhook delivery
+#[derive(Debug, Clone)]
+pub struct NewWebhookDelivery {
+    pub delivery_id: Option<String>,
+    pub event_type: String,
+    pub repo: Option<String>,
+    pub action: Option<String>,
+    pub outcome: DeliveryOutcome,
+    pub error: Option<String>,
+    /// Raw body of a queued delivery
+    pub payload: Option<String>,
+}
+
+impl NewWebhookDelivery {
+    /// Start a log entry for a delivery, assumed successful until told otherwise
+    pub fn received(delivery_id: Option<String>, event_type: impl Into<String>) -> Self {
+        Self {
+            delivery_id,
+            event_type: event_type.into(),
+            repo: None,
+            action: None,
+            outcome: DeliveryOutcome::Success,
+            error: None,
+            payload: None,
+        }
+    }
+
+    /// Mark the delivery as accepted, keeping its body until it is processed
+    pub fn queue(&mut self, payload: impl Into<String>) {
+        self.outcome = DeliveryOutcome::Queued;
+        self.payload = Some(payload.into());
+    }
+
+    /// Record that processing ended with `outcome` because of `error`
+    pub fn fail(&mut self, outcome: DeliveryOutcome, error: impl Into<String>) {
+        self.outcome = outcome;
+        self.error = Some(error.into());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn delivery_outcome_round_trips() {
+        for outcome in [
+            DeliveryOutcome::Queued,
+            DeliveryOutcome::Success,
+            DeliveryOutcome::Duplicate,
+            DeliveryOutcome::SignatureFailed,
+            DeliveryOutcome::InvalidPayload,
+            DeliveryOutcome::Failed,
+        ] {
+            assert_eq!(
+                outcome.to_string().parse::<DeliveryOutcome>().unwrap(),
+                outcome
+            );
+        }
+        assert!("invalid".parse::<DeliveryOutcome>().is_err());
+    }
+}
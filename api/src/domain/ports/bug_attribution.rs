@@ -0,0 +1,22 @@
+//! Bug attribution port trait
+//!
+//! Bug reports that reference a PR cost its author ELO. When the report also
+//! points at code, an attribution check can confirm the PR actually changed
+//! that code before anyone is penalized.
+
+use async_trait::async_trait;
+
+use crate::domain::entities::{CodeContribution, CodeReference, Project};
+use crate::error::DomainError;
+
+/// Port trait for checking a PR against the code a bug report points at
+#[async_trait]
+pub trait BugAttribution: Send + Sync {
+    /// Whether the PR behind `contribution` changed any of `code`
+    async fn pr_touched_code(
+        &self,
+        project: &Project,
+        contribution: &CodeContribution,
+        code: &[CodeReference],
+    ) -> Result<bool, DomainError>;
+}
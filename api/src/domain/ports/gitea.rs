@@ -5,6 +5,7 @@
 use async_trait::async_trait;
 use serde::{Deserialize, Deserializer, Serialize};
 
+use crate::domain::entities::Project;
 use crate::error::GiteaError;
 
 /// Helper to deserialize null as default (empty vec, etc.)
@@ -25,6 +26,31 @@ pub struct GiteaUser {
     pub full_name: Option<String>,
 }
 
+/// One bucket of a user's contribution heatmap
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GiteaHeatmapEntry {
+    /// Unix timestamp (seconds) of the start of the bucket
+    pub timestamp: i64,
+    /// Contributions (commits, issues, PRs, ...) in the bucket
+    pub contributions: i64,
+}
+
+/// A thread from a user's Gitea notification inbox
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GiteaNotification {
+    pub id: i64,
+    /// Repository full name (owner/repo)
+    pub repository: String,
+    /// "Issue", "Pull", "Commit" or "Repository"
+    pub subject_type: String,
+    pub subject_title: String,
+    /// e.g. "open", "closed", "merged"
+    pub subject_state: Option<String>,
+    pub html_url: Option<String>,
+    pub unread: bool,
+    pub updated_at: String,
+}
+
 /// Gitea organization representation
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GiteaOrg {
@@ -46,6 +72,98 @@ pub struct GiteaRepo {
     pub html_url: String,
     pub default_branch: String,
     pub private: bool,
+    pub open_issues_count: i64,
+    pub stars_count: i64,
+}
+
+/// Repository settings to change; `None` fields are left as they are
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct RepoSettings {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_branch: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub private: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub has_issues: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub has_pull_requests: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub has_wiki: Option<bool>,
+}
+
+impl RepoSettings {
+    /// Settings every project repo needs: issues and pull requests enabled
+    pub fn standard() -> Self {
+        Self {
+            has_issues: Some(true),
+            has_pull_requests: Some(true),
+            ..Default::default()
+        }
+    }
+}
+
+/// Protection rules for a branch
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BranchProtection {
+    /// Reject direct pushes, so changes land through pull requests
+    pub require_pull_request: bool,
+    /// Approving reviews a pull request needs before it can be merged
+    pub required_approvals: i64,
+    /// Block merging while a reviewer has requested changes
+    pub block_on_rejected_reviews: bool,
+    /// Require commit statuses to pass before merging
+    pub require_status_checks: bool,
+    /// Status check contexts that must pass (glob patterns allowed)
+    pub status_check_contexts: Vec<String>,
+}
+
+impl BranchProtection {
+    /// Rules mirroring a project's merge settings: changes go through a pull
+    /// request that needs the project's required approvals, and passing
+    /// status checks only when the project requires green CI
+    pub fn for_project(project: &Project) -> Self {
+        let required_approvals = i64::from(project.required_approvals.max(0));
+        Self {
+            require_pull_request: true,
+            required_approvals,
+            block_on_rejected_reviews: required_approvals > 0,
+            require_status_checks: project.require_green_ci,
+            status_check_contexts: if project.require_green_ci {
+                vec!["*".to_string()]
+            } else {
+                Vec::new()
+            },
+        }
+    }
+}
+
+/// A template repository new projects are generated from
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RepoTemplate {
+    pub owner: String,
+    pub repo: String,
+}
+
+impl std::str::FromStr for RepoTemplate {
+    type Err = String;
+
+    /// Parse `owner/repo`
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().split_once('/') {
+            Some((owner, repo)) if !owner.is_empty() && !repo.is_empty() && !repo.contains('/') => {
+                Ok(Self {
+                    owner: owner.to_string(),
+                    repo: repo.to_string(),
+                })
+            }
+            _ => Err(format!(
+                "Invalid template repo '{}', expected owner/repo",
+                s
+            )),
+        }
+    }
 }
 
 /// Gitea branch representation
@@ -62,6 +180,28 @@ pub struct GiteaCommit {
     pub message: String,
 }
 
+/// Full commit metadata resolved from a SHA
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GiteaCommitDetails {
+    pub sha: String,
+    pub message: String,
+    /// Gitea login of the author (None when the commit email isn't linked to a user)
+    pub author_login: Option<String>,
+    /// Author timestamp (RFC 3339)
+    pub timestamp: String,
+}
+
+/// How far two refs have diverged
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GiteaComparison {
+    /// Commits on head that base doesn't have
+    pub ahead_by: i64,
+    /// Commits on base that head doesn't have
+    pub behind_by: i64,
+    /// The commits head is ahead by
+    pub commits: Vec<GiteaCommitDetails>,
+}
+
 /// Gitea pull request representation
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GiteaPullRequest {
@@ -75,6 +215,13 @@ pub struct GiteaPullRequest {
     pub base: GiteaPRBranch,
     pub merged: bool,
     pub user: Option<GiteaUser>,
+    #[serde(default)]
+    pub merged_at: Option<String>,
+    #[serde(default)]
+    pub merge_commit_sha: Option<String>,
+    /// Work-in-progress PRs that aren't ready for review yet
+    #[serde(default)]
+    pub draft: bool,
 }
 
 /// Branch info in a PR
@@ -140,6 +287,24 @@ pub struct GiteaPRReview {
     pub submitted_at: Option<String>,
 }
 
+/// Whether a pull request can be merged as-is
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GiteaMergeStatus {
+    pub state: String,
+    pub merged: bool,
+    /// Gitea's own mergeability check (false when the PR conflicts with its base)
+    pub mergeable: bool,
+    /// Head commit, used to look up CI status
+    pub head_sha: String,
+}
+
+impl GiteaMergeStatus {
+    /// An open, unmerged PR that Gitea can't merge cleanly
+    pub fn has_conflicts(&self) -> bool {
+        self.state == "open" && !self.merged && !self.mergeable
+    }
+}
+
 /// Combined PR status (CI checks)
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GiteaCombinedStatus {
@@ -147,6 +312,30 @@ pub struct GiteaCombinedStatus {
     pub statuses: Vec<GiteaStatus>,
 }
 
+impl GiteaCombinedStatus {
+    /// The worst state across the combined state and every individual check,
+    /// so one failing context can't hide behind a stale "success"
+    pub fn overall_state(&self) -> &str {
+        self.statuses
+            .iter()
+            .map(|s| s.state.as_str())
+            .chain(std::iter::once(self.state.as_str()))
+            .max_by_key(|state| status_severity(state))
+            .unwrap_or("success")
+    }
+}
+
+/// Rank of a commit status state, higher is worse. Unknown states rank as pending.
+fn status_severity(state: &str) -> u8 {
+    match state {
+        "success" => 0,
+        "warning" => 1,
+        "failure" => 3,
+        "error" => 4,
+        _ => 2,
+    }
+}
+
 /// Individual status check
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GiteaStatus {
@@ -165,6 +354,19 @@ pub struct GiteaReaction {
     pub created_at: String,
 }
 
+/// A release published on a repository tag
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GiteaRelease {
+    pub id: i64,
+    pub tag_name: String,
+    pub name: String,
+    #[serde(default, deserialize_with = "deserialize_null_default")]
+    pub body: String,
+    pub draft: bool,
+    pub prerelease: bool,
+    pub html_url: String,
+}
+
 /// Gitea webhook payload for push events
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[allow(dead_code)]
@@ -200,9 +402,25 @@ pub trait GiteaClient: Send + Sync {
         password: &str,
     ) -> Result<GiteaUser, GiteaError>;
 
+    /// Delete a user (admin). Used to undo users created during a failed batch.
+    async fn delete_user(&self, username: &str) -> Result<(), GiteaError>;
+
+    /// Set a user's password (admin)
+    async fn set_user_password(&self, username: &str, password: &str) -> Result<(), GiteaError>;
+
     /// Get a user by username
     async fn get_user(&self, username: &str) -> Result<GiteaUser, GiteaError>;
 
+    /// Get a user's contribution heatmap (activity over roughly the last year)
+    async fn get_user_heatmap(&self, username: &str) -> Result<Vec<GiteaHeatmapEntry>, GiteaError>;
+
+    /// Unread notifications of the user owning `user_token`. Fails with a 403
+    /// API error when the token lacks the notification scope.
+    async fn get_notifications(
+        &self,
+        user_token: &str,
+    ) -> Result<Vec<GiteaNotification>, GiteaError>;
+
     /// Create an access token for a user (requires user's password for basic auth)
     async fn create_access_token(
         &self,
@@ -271,6 +489,17 @@ pub trait GiteaClient: Send + Sync {
         auto_init: bool,
     ) -> Result<GiteaRepo, GiteaError>;
 
+    /// Generate a repository under `owner` (an org or user) from a template
+    /// repository, copying its files, topics and labels
+    async fn create_repo_from_template(
+        &self,
+        template_owner: &str,
+        template_repo: &str,
+        owner: &str,
+        name: &str,
+        description: Option<&str>,
+    ) -> Result<GiteaRepo, GiteaError>;
+
     /// Create a repository in a user's personal namespace
     /// Uses the user's token for proper ownership
     async fn create_user_repo(
@@ -286,6 +515,12 @@ pub trait GiteaClient: Send + Sync {
     /// Get a repository
     async fn get_repo(&self, owner: &str, name: &str) -> Result<GiteaRepo, GiteaError>;
 
+    /// Star a repository as the user the token belongs to
+    async fn star_repo(&self, owner: &str, repo: &str, user_token: &str) -> Result<(), GiteaError>;
+
+    /// List every repository in an organization, following pagination
+    async fn get_org_repos(&self, org: &str) -> Result<Vec<GiteaRepo>, GiteaError>;
+
     /// Fork a repository to user's account
     async fn fork_repo(
         &self,
@@ -294,9 +529,42 @@ pub trait GiteaClient: Send + Sync {
         new_owner: &str,
     ) -> Result<GiteaRepo, GiteaError>;
 
+    /// Create a read-only pull mirror of an external repository in an organization
+    async fn create_mirror_repo(
+        &self,
+        source_url: &str,
+        org: &str,
+        name: &str,
+    ) -> Result<GiteaRepo, GiteaError>;
+
     /// Delete a repository
     async fn delete_repo(&self, owner: &str, name: &str) -> Result<(), GiteaError>;
 
+    /// Change a repository's settings
+    async fn update_repo(
+        &self,
+        owner: &str,
+        name: &str,
+        settings: &RepoSettings,
+    ) -> Result<GiteaRepo, GiteaError>;
+
+    /// Protect a branch, replacing any existing protection rules for it
+    async fn set_branch_protection(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+        rules: &BranchProtection,
+    ) -> Result<(), GiteaError>;
+
+    /// Remove a branch's protection rules (a no-op if it has none)
+    async fn remove_branch_protection(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+    ) -> Result<(), GiteaError>;
+
     /// Create a file in a repository
     async fn create_file(
         &self,
@@ -308,6 +576,16 @@ pub trait GiteaClient: Send + Sync {
         user_token: Option<&str>,
     ) -> Result<(), GiteaError>;
 
+    /// Read a file as of a commit, branch or tag (`None` if the file doesn't
+    /// exist at that ref)
+    async fn get_contents_at_ref(
+        &self,
+        owner: &str,
+        repo: &str,
+        path: &str,
+        git_ref: &str,
+    ) -> Result<Option<String>, GiteaError>;
+
     /// Add a collaborator to a repository
     async fn add_collaborator(
         &self,
@@ -330,6 +608,37 @@ pub trait GiteaClient: Send + Sync {
     /// List branches
     async fn list_branches(&self, owner: &str, repo: &str) -> Result<Vec<GiteaBranch>, GiteaError>;
 
+    /// Create a branch off `from`, or off the default branch when None
+    ///
+    /// Fails with a 409 API error if the branch already exists.
+    async fn create_branch(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+        from: Option<&str>,
+    ) -> Result<GiteaBranch, GiteaError>;
+
+    // Commits
+
+    /// Get a commit's author, message and timestamp by SHA
+    async fn get_commit(
+        &self,
+        owner: &str,
+        repo: &str,
+        sha: &str,
+    ) -> Result<GiteaCommitDetails, GiteaError>;
+
+    /// Compare two refs (branch names or SHAs): how far `head` is ahead of and
+    /// behind `base`, and the commits it is ahead by
+    async fn compare_commits(
+        &self,
+        owner: &str,
+        repo: &str,
+        base: &str,
+        head: &str,
+    ) -> Result<GiteaComparison, GiteaError>;
+
     // Pull request management
 
     /// Create a pull request
@@ -354,6 +663,22 @@ pub trait GiteaClient: Send + Sync {
         number: i64,
     ) -> Result<GiteaPullRequest, GiteaError>;
 
+    /// Get a pull request's changes as a unified diff (the `.diff` endpoint)
+    async fn get_pr_raw_diff(
+        &self,
+        owner: &str,
+        repo: &str,
+        number: i64,
+    ) -> Result<String, GiteaError>;
+
+    /// Get a pull request's mergeability (conflicts) and head commit
+    async fn get_pull_request_merge_status(
+        &self,
+        owner: &str,
+        repo: &str,
+        number: i64,
+    ) -> Result<GiteaMergeStatus, GiteaError>;
+
     /// List pull requests
     async fn list_pull_requests(
         &self,
@@ -362,6 +687,17 @@ pub trait GiteaClient: Send + Sync {
         state: Option<&str>,
     ) -> Result<Vec<GiteaPullRequest>, GiteaError>;
 
+    /// List one page of pull requests (pages start at 1); a page shorter than
+    /// `limit` is the last one
+    async fn list_pull_requests_page(
+        &self,
+        owner: &str,
+        repo: &str,
+        state: Option<&str>,
+        page: usize,
+        limit: usize,
+    ) -> Result<Vec<GiteaPullRequest>, GiteaError>;
+
     /// Get PRs authored by a specific user in a repo
     async fn get_user_prs(
         &self,
@@ -371,12 +707,14 @@ pub trait GiteaClient: Send + Sync {
     ) -> Result<Vec<GiteaPullRequest>, GiteaError>;
 
     /// Merge a pull request
+    /// `force` asks Gitea to merge past unmet branch protection (repo admins only)
     async fn merge_pull_request(
         &self,
         owner: &str,
         repo: &str,
         number: i64,
         merge_style: &str,
+        force: bool,
         auth_token: Option<&str>,
     ) -> Result<(), GiteaError>;
 
@@ -388,6 +726,23 @@ pub trait GiteaClient: Send + Sync {
         number: i64,
     ) -> Result<(), GiteaError>;
 
+    /// Ask users (by Gitea username) to review a PR
+    async fn request_reviewers(
+        &self,
+        owner: &str,
+        repo: &str,
+        number: i64,
+        reviewers: &[String],
+    ) -> Result<(), GiteaError>;
+
+    /// Get the commits on a PR
+    async fn get_pr_commits(
+        &self,
+        owner: &str,
+        repo: &str,
+        number: i64,
+    ) -> Result<Vec<GiteaCommitDetails>, GiteaError>;
+
     // PR comments and reviews
 
     /// Get comments on a PR
@@ -417,6 +772,26 @@ pub trait GiteaClient: Send + Sync {
         number: i64,
     ) -> Result<Vec<GiteaPRReview>, GiteaError>;
 
+    /// Get the latest review on a PR submitted by a specific user, if any
+    async fn get_pr_review_by_user(
+        &self,
+        owner: &str,
+        repo: &str,
+        number: i64,
+        username: &str,
+    ) -> Result<Option<GiteaPRReview>, GiteaError>;
+
+    /// Delete a review from a PR
+    /// If auth_token is provided, use it instead of admin token (reviewers can delete their own)
+    async fn delete_pr_review(
+        &self,
+        owner: &str,
+        repo: &str,
+        number: i64,
+        review_id: i64,
+        auth_token: Option<&str>,
+    ) -> Result<(), GiteaError>;
+
     /// Submit a review on a PR
     /// state should be one of: "APPROVED", "REQUEST_CHANGES", "COMMENT"
     /// If auth_token is provided, use it instead of admin token (for agent attribution)
@@ -465,12 +840,14 @@ pub trait GiteaClient: Send + Sync {
     ) -> Result<Vec<GiteaReaction>, GiteaError>;
 
     /// Add a reaction to an issue or PR
+    /// If auth_token is provided, use it instead of admin token (for agent attribution)
     async fn post_issue_reaction(
         &self,
         owner: &str,
         repo: &str,
         issue_number: i64,
         content: &str,
+        auth_token: Option<&str>,
     ) -> Result<GiteaReaction, GiteaError>;
 
     /// Delete a reaction from an issue or PR
@@ -512,12 +889,14 @@ pub trait GiteaClient: Send + Sync {
         auth_token: Option<&str>,
     ) -> Result<GiteaIssue, GiteaError>;
 
-    /// List issues in a repository
+    /// List issues in a repository, across all pages and without pull requests
+    /// Non-empty `labels` restricts results to issues carrying all of them
     async fn list_issues(
         &self,
         owner: &str,
         repo: &str,
         state: Option<&str>,
+        labels: &[String],
     ) -> Result<Vec<GiteaIssue>, GiteaError>;
 
     /// Get a specific issue
@@ -543,12 +922,14 @@ pub trait GiteaClient: Send + Sync {
 
     // Issue comments
 
-    /// List comments on an issue
+    /// List comments on an issue, oldest first. `since` (RFC 3339) limits the
+    /// list to comments updated at or after that time.
     async fn list_issue_comments(
         &self,
         owner: &str,
         repo: &str,
         number: i64,
+        since: Option<&str>,
     ) -> Result<Vec<GiteaIssueComment>, GiteaError>;
 
     /// Create a comment on an issue
@@ -561,6 +942,14 @@ pub trait GiteaClient: Send + Sync {
         auth_token: Option<&str>,
     ) -> Result<GiteaIssueComment, GiteaError>;
 
+    /// Get a single issue comment by its ID
+    async fn get_issue_comment(
+        &self,
+        owner: &str,
+        repo: &str,
+        comment_id: i64,
+    ) -> Result<GiteaIssueComment, GiteaError>;
+
     /// Edit an issue comment
     async fn edit_issue_comment(
         &self,
@@ -600,6 +989,16 @@ pub trait GiteaClient: Send + Sync {
         auth_token: Option<&str>,
     ) -> Result<Vec<GiteaLabel>, GiteaError>;
 
+    /// Replace all labels on an issue in one call (an empty list clears them)
+    async fn replace_issue_labels(
+        &self,
+        owner: &str,
+        repo: &str,
+        number: i64,
+        labels: Vec<String>,
+        auth_token: Option<&str>,
+    ) -> Result<Vec<GiteaLabel>, GiteaError>;
+
     /// Remove a label from an issue
     async fn remove_issue_label(
         &self,
@@ -640,4 +1039,35 @@ pub trait GiteaClient: Send + Sync {
         owner: &str,
         repo: &str,
     ) -> Result<Vec<GiteaLabel>, GiteaError>;
+
+    /// List the labels an organization shares with all its repositories
+    /// (empty when `org` is a user rather than an organization)
+    async fn list_org_labels(&self, org: &str) -> Result<Vec<GiteaLabel>, GiteaError>;
+
+    /// Create a label in a repository (`color` is a hex code, e.g. "#d73a4a")
+    async fn create_repo_label(
+        &self,
+        owner: &str,
+        repo: &str,
+        name: &str,
+        color: &str,
+    ) -> Result<GiteaLabel, GiteaError>;
+
+    // Releases
+
+    /// Whether a tag with this name exists in a repository
+    async fn tag_exists(&self, owner: &str, repo: &str, tag: &str) -> Result<bool, GiteaError>;
+
+    /// Create a release, tagging the default branch's head if `tag` doesn't exist yet
+    #[allow(clippy::too_many_arguments)]
+    async fn create_release(
+        &self,
+        owner: &str,
+        repo: &str,
+        tag: &str,
+        name: &str,
+        body: Option<&str>,
+        draft: bool,
+        prerelease: bool,
+    ) -> Result<GiteaRelease, GiteaError>;
 }
@@ -4,20 +4,27 @@
 //! Adapters provide concrete implementations of these traits.
 
 pub mod analytics;
+pub mod bug_attribution;
 pub mod gitea;
+pub mod notifier;
 pub mod repositories;
 
 pub use analytics::{
     AgentStats, AnalyticsClient, AnalyticsEvent, DifficultyBreakdown, LeaderboardEntry,
     ProjectStats, TimeRange,
 };
+pub use bug_attribution::BugAttribution;
 pub use gitea::{
-    GiteaBranch, GiteaClient, GiteaCombinedStatus, GiteaComment, GiteaCommit, GiteaIssue,
-    GiteaIssueComment, GiteaLabel, GiteaOrg, GiteaPRBranch, GiteaPRReview, GiteaPullRequest,
-    GiteaReaction, GiteaRepo, GiteaStatus, GiteaUser,
+    BranchProtection, GiteaBranch, GiteaClient, GiteaCombinedStatus, GiteaComment, GiteaCommit,
+    GiteaCommitDetails, GiteaComparison, GiteaHeatmapEntry, GiteaIssue, GiteaIssueComment,
+    GiteaLabel, GiteaMergeStatus, GiteaNotification, GiteaOrg, GiteaPRBranch, GiteaPRReview,
+    GiteaPullRequest, GiteaReaction, GiteaRelease, GiteaRepo, GiteaStatus, GiteaUser, RepoSettings,
+    RepoTemplate,
 };
+pub use notifier::{NoopNotifier, Notification, Notifier};
 pub use repositories::{
-    AgentRepository, AgentReviewRepository, CodeContributionRepository, EloEventRepository,
-    EngagementRepository, IssueRepository, ProjectRepository, TicketRepository,
-    ViralMomentRepository,
+    AgentMergeRepository, AgentRepository, AgentReviewRepository, CodeContributionRepository,
+    EloEventRepository, EloIntegrityRepository, EngagementRepository, IssueRepository,
+    PendingPenaltyRepository, ProjectRepository, ReviewRequestRepository, TicketRepository,
+    ViralMomentRepository, WebhookDeliveryRepository,
 };
@@ -0,0 +1,55 @@
+//! Notifier port trait
+//!
+//! Defines how services tell an agent that something happened to them.
+//! The feed is one channel; email or Discord integrations implement the
+//! same trait and plug in without touching the services.
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+use crate::domain::entities::AgentId;
+use crate::error::DomainError;
+
+/// Something an agent should hear about
+#[derive(Debug, Clone, Serialize)]
+pub struct Notification {
+    /// Type, e.g. "elo_change"
+    pub notification_type: String,
+    /// PR this relates to, if any
+    pub pr_number: Option<i64>,
+    /// Short headline
+    pub title: String,
+    /// Longer explanation
+    pub message: Option<String>,
+    /// ELO change, if this notification reports one
+    pub elo_change: Option<i32>,
+    pub created_at: DateTime<Utc>,
+}
+
+/// Port trait for delivering notifications to agents
+#[async_trait]
+pub trait Notifier: Send + Sync {
+    /// Deliver a notification to an agent. Delivery is best-effort: callers
+    /// log failures rather than failing the operation that triggered them.
+    async fn notify(
+        &self,
+        agent_id: &AgentId,
+        notification: Notification,
+    ) -> Result<(), DomainError>;
+}
+
+/// Notifier that drops everything, used when no channel is configured
+#[derive(Debug, Default, Clone, Copy)]
+pub struct NoopNotifier;
+
+#[async_trait]
+impl Notifier for NoopNotifier {
+    async fn notify(
+        &self,
+        _agent_id: &AgentId,
+        _notification: Notification,
+    ) -> Result<(), DomainError> {
+        Ok(())
+    }
+}
@@ -3,17 +3,23 @@
 //! These traits define the interface for data persistence.
 //! Implementations are provided by adapters (e.g., PostgreSQL).
 
+use std::collections::HashMap;
+
 use async_trait::async_trait;
 
 use chrono::{DateTime, Utc};
 
 use crate::domain::entities::{
-    Agent, AgentId, AgentReview, AgentReviewId, ClaimAgent, CodeContribution, CodeContributionId,
-    ContributionStatus, EloEvent, EloEventId, Engagement, EngagementCounts, EngagementId, Issue,
-    IssueComment, IssueId, Label, MemberRole, MomentType, NewAgent, NewAgentReview,
-    NewCodeContribution, NewEloEvent, NewEngagement, NewIssue, NewProject, NewTicket,
-    NewViralMoment, Project, ProjectId, ProjectMember, Ticket, TicketId, TicketStatus, ViralMoment,
-    ViralMomentId,
+    Agent, AgentId, AgentMergeCounts, AgentReview, AgentReviewId, AvailableLabel, ClaimAgent,
+    CodeContribution, CodeContributionId, CommentFilter, ContributionStatus, DeliveryOutcome,
+    EloDrift, EloEvent, EloEventId, Engagement, EngagementCounts, EngagementId, Issue,
+    IssueComment, IssueId, Label, LabelFilter, MemberRole, MomentFilter, MomentType, NewAgent,
+    NewAgentReview, NewCodeContribution, NewEloEvent, NewEngagement, NewIssue, NewPendingPenalty,
+    NewProject, NewReviewRequest, NewTicket, NewViralMoment, NewWebhookDelivery, PenaltyStatus,
+    PendingPenalty, PendingPenaltyId, Project, ProjectId, ProjectMember, ProjectMirror,
+    ProjectVisibility, ReviewEligibility, ReviewRequest, ReviewRequestId, ReviewRequestStatus,
+    ReviewVerdict, Ticket, TicketId, TicketStatus, Tier, ViralMoment, ViralMomentId,
+    WebhookDelivery, WebhookDeliveryId,
 };
 use crate::error::DomainError;
 
@@ -23,6 +29,9 @@ pub trait AgentRepository: Send + Sync {
     /// Find an agent by ID
     async fn find_by_id(&self, id: &AgentId) -> Result<Option<Agent>, DomainError>;
 
+    /// Find the agents with the given IDs, skipping any that don't exist
+    async fn find_by_ids(&self, ids: &[AgentId]) -> Result<Vec<Agent>, DomainError>;
+
     /// Find an agent by API key hash
     async fn find_by_api_key_hash(&self, hash: &str) -> Result<Option<Agent>, DomainError>;
 
@@ -45,6 +54,13 @@ pub trait AgentRepository: Send + Sync {
     async fn get_gitea_token_encrypted(&self, id: &AgentId)
         -> Result<Option<Vec<u8>>, DomainError>;
 
+    /// Replace the encrypted Gitea token for an agent
+    async fn update_gitea_token_encrypted(
+        &self,
+        id: &AgentId,
+        encrypted: Vec<u8>,
+    ) -> Result<(), DomainError>;
+
     /// Get top agents by ELO
     async fn find_top_by_elo(&self, limit: i64) -> Result<Vec<Agent>, DomainError>;
 
@@ -56,17 +72,57 @@ pub trait AgentRepository: Send + Sync {
 
     /// Claim an agent (set GitHub info and claimed_at)
     async fn claim(&self, id: &AgentId, claim: &ClaimAgent) -> Result<(), DomainError>;
+
+    /// Count agents per tier (tiers without agents may be omitted)
+    async fn count_by_tier(&self) -> Result<Vec<(Tier, i64)>, DomainError>;
+}
+
+/// Merges duplicate agents. Spans several tables, so it gets its own port
+/// rather than being pieced together from the per-entity repositories.
+#[async_trait]
+pub trait AgentMergeRepository: Send + Sync {
+    /// In one transaction, move the source agent's contributions, reviews
+    /// (given and received), ELO events, ticket assignments, project
+    /// memberships and ownership, engagements, pending penalties and viral
+    /// moments to the target, combine both agents' ELO on the target, and
+    /// deactivate the source. Reviews between the two agents are dropped rather than left
+    /// as self-reviews, and where both were members of a project the target
+    /// keeps the higher of the two roles.
+    /// The target's new ELO is `target + source - starting` as read inside
+    /// the transaction with both rows locked, held between `floor` and
+    /// `ceiling`.
+    /// Fails with `Conflict` if either agent was already merged away.
+    async fn merge(
+        &self,
+        source: &AgentId,
+        target: &AgentId,
+        starting: i32,
+        floor: i32,
+        ceiling: Option<i32>,
+    ) -> Result<AgentMergeCounts, DomainError>;
+}
+
+/// Compares stored agent ELO against the ELO event log. Joins agents to
+/// their event totals, so it gets its own port like `AgentMergeRepository`.
+#[async_trait]
+pub trait EloIntegrityRepository: Send + Sync {
+    /// Active agents whose stored ELO isn't `starting` plus the sum of the
+    /// changes their events actually made (`new_elo - old_elo`, so floor and
+    /// ceiling clamps are already applied), largest drift first
+    async fn find_elo_drift(&self, starting: i32, limit: i64)
+        -> Result<Vec<EloDrift>, DomainError>;
 }
 
 /// Repository for Issue entities
 /// Issues live in Gitea - this port abstracts the Gitea API
 #[async_trait]
 pub trait IssueRepository: Send + Sync {
-    /// List issues for a project
+    /// List issues for a project, optionally restricted by labels
     async fn list(
         &self,
         project_id: &ProjectId,
         state: Option<&str>,
+        labels: Option<&LabelFilter>,
     ) -> Result<Vec<Issue>, DomainError>;
 
     /// Get a specific issue
@@ -97,8 +153,12 @@ pub trait IssueRepository: Send + Sync {
 
     // Comments
 
-    /// List comments on an issue
-    async fn list_comments(&self, id: &IssueId) -> Result<Vec<IssueComment>, DomainError>;
+    /// List comments on an issue matching the filter, in the filter's order
+    async fn list_comments(
+        &self,
+        id: &IssueId,
+        filter: &CommentFilter,
+    ) -> Result<Vec<IssueComment>, DomainError>;
 
     /// Add a comment to an issue
     async fn add_comment(
@@ -108,20 +168,22 @@ pub trait IssueRepository: Send + Sync {
         agent_token: Option<&str>,
     ) -> Result<IssueComment, DomainError>;
 
-    /// Edit a comment
+    /// Edit a comment. Fails with `Forbidden` unless `author` wrote it.
     async fn edit_comment(
         &self,
         id: &IssueId,
         comment_id: i64,
         body: &str,
+        author: &str,
         agent_token: Option<&str>,
     ) -> Result<IssueComment, DomainError>;
 
-    /// Delete a comment
+    /// Delete a comment. Fails with `Forbidden` unless `author` wrote it.
     async fn delete_comment(
         &self,
         id: &IssueId,
         comment_id: i64,
+        author: &str,
         agent_token: Option<&str>,
     ) -> Result<(), DomainError>;
 
@@ -138,6 +200,14 @@ pub trait IssueRepository: Send + Sync {
         agent_token: Option<&str>,
     ) -> Result<Vec<Label>, DomainError>;
 
+    /// Replace the full set of labels on an issue
+    async fn replace_labels(
+        &self,
+        id: &IssueId,
+        labels: Vec<String>,
+        agent_token: Option<&str>,
+    ) -> Result<Vec<Label>, DomainError>;
+
     /// Remove a label from an issue
     async fn remove_label(
         &self,
@@ -166,11 +236,12 @@ pub trait IssueRepository: Send + Sync {
 
     // Repository-level
 
-    /// List available labels for a project
+    /// List available labels for a project, including labels still applied
+    /// to issues after being deleted from the repo (flagged as orphaned)
     async fn list_available_labels(
         &self,
         project_id: &ProjectId,
-    ) -> Result<Vec<Label>, DomainError>;
+    ) -> Result<Vec<AvailableLabel>, DomainError>;
 }
 
 /// Repository for Ticket entities (Ant Farm project tickets)
@@ -182,6 +253,13 @@ pub trait TicketRepository: Send + Sync {
     /// Find tickets by project
     async fn find_by_project(&self, project_id: &ProjectId) -> Result<Vec<Ticket>, DomainError>;
 
+    /// Find a project's ticket by its Gitea issue number
+    async fn find_by_issue_number(
+        &self,
+        project_id: &ProjectId,
+        issue_number: i64,
+    ) -> Result<Option<Ticket>, DomainError>;
+
     /// Find open tickets for a project
     async fn find_open_by_project(
         &self,
@@ -209,6 +287,13 @@ pub trait TicketRepository: Send + Sync {
     /// Mark ticket as closed
     async fn close(&self, id: &TicketId) -> Result<(), DomainError>;
 
+    /// Mark a ticket as blocked, recording why
+    async fn block(&self, id: &TicketId, reason: &str) -> Result<(), DomainError>;
+
+    /// Clear a ticket's blocked state. It returns to in-progress if still
+    /// assigned, otherwise to open.
+    async fn unblock(&self, id: &TicketId) -> Result<(), DomainError>;
+
     /// Count open tickets for a project
     async fn count_open_by_project(&self, project_id: &ProjectId) -> Result<i64, DomainError>;
 }
@@ -219,12 +304,41 @@ pub trait ProjectRepository: Send + Sync {
     /// Find a project by ID
     async fn find_by_id(&self, id: &ProjectId) -> Result<Option<Project>, DomainError>;
 
+    /// Find the projects with the given IDs, skipping any that don't exist
+    async fn find_by_ids(&self, ids: &[ProjectId]) -> Result<Vec<Project>, DomainError>;
+
     /// Find a project by name
     async fn find_by_name(&self, name: &str) -> Result<Option<Project>, DomainError>;
 
-    /// Find active projects with pagination
+    /// Find a project by its Gitea owner and repository
+    async fn find_by_gitea_repo(
+        &self,
+        gitea_org: &str,
+        gitea_repo: &str,
+    ) -> Result<Option<Project>, DomainError>;
+
+    /// Find active public projects with pagination
     async fn find_active(&self, limit: i64, offset: i64) -> Result<Vec<Project>, DomainError>;
 
+    /// Count active public projects (same filter as `find_active`)
+    async fn count_active(&self) -> Result<i64, DomainError>;
+
+    /// Find active public projects whose trimmed, lowercased language is one
+    /// of `languages` (which must already be lowercase)
+    async fn find_active_by_language(
+        &self,
+        languages: &[String],
+        limit: i64,
+        offset: i64,
+    ) -> Result<Vec<Project>, DomainError>;
+
+    /// Count active public projects matching `find_active_by_language`
+    async fn count_active_by_language(&self, languages: &[String]) -> Result<i64, DomainError>;
+
+    /// Active public project counts per trimmed, lowercased language.
+    /// Projects without a language are left out.
+    async fn count_active_languages(&self) -> Result<Vec<(String, i64)>, DomainError>;
+
     /// Find all projects with pagination
     async fn find_all(&self, limit: i64, offset: i64) -> Result<Vec<Project>, DomainError>;
 
@@ -238,14 +352,67 @@ pub trait ProjectRepository: Send + Sync {
         status: crate::domain::entities::ProjectStatus,
     ) -> Result<(), DomainError>;
 
-    /// Update project statistics
-    async fn update_stats(
+    /// Record a read-only mirror of the project's external source
+    async fn set_mirror(&self, id: &ProjectId, mirror: &ProjectMirror) -> Result<(), DomainError>;
+
+    /// Allow or forbid PRs against branches other than the default branch
+    async fn set_allow_alternate_bases(
+        &self,
+        id: &ProjectId,
+        allowed: bool,
+    ) -> Result<(), DomainError>;
+
+    /// Require or drop the green-CI requirement for merging PRs
+    async fn set_require_green_ci(&self, id: &ProjectId, required: bool)
+        -> Result<(), DomainError>;
+
+    /// Set how many approving reviews a PR needs before it can be merged
+    async fn set_required_approvals(&self, id: &ProjectId, count: i32) -> Result<(), DomainError>;
+
+    /// Turn mirroring of the merge rules onto Gitea branch protection on or off
+    async fn set_protect_default_branch(
+        &self,
+        id: &ProjectId,
+        enabled: bool,
+    ) -> Result<(), DomainError>;
+
+    /// Set who can see the project
+    async fn set_visibility(
+        &self,
+        id: &ProjectId,
+        visibility: ProjectVisibility,
+    ) -> Result<(), DomainError>;
+
+    /// Set who may peer-review the project's PRs
+    async fn set_review_eligibility(
         &self,
         id: &ProjectId,
-        contributor_count: i32,
-        open_ticket_count: i32,
+        eligibility: ReviewEligibility,
     ) -> Result<(), DomainError>;
 
+    /// Add a project to (or remove it from) the featured list at `rank`
+    async fn set_featured(
+        &self,
+        id: &ProjectId,
+        featured: bool,
+        rank: i32,
+    ) -> Result<(), DomainError>;
+
+    /// Set the scale applied to ELO changes earned on a project
+    async fn set_elo_multiplier(&self, id: &ProjectId, multiplier: f32) -> Result<(), DomainError>;
+
+    /// Featured projects ordered by curation rank
+    async fn find_featured(&self, limit: i64) -> Result<Vec<Project>, DomainError>;
+
+    /// Set the number of contributors shown for a project
+    async fn set_contributor_count(&self, id: &ProjectId, count: i32) -> Result<(), DomainError>;
+
+    /// Set the open ticket count to a freshly counted value
+    async fn set_open_ticket_count(&self, id: &ProjectId, count: i32) -> Result<(), DomainError>;
+
+    /// Set the repository star count to a freshly read value
+    async fn set_star_count(&self, id: &ProjectId, count: i32) -> Result<(), DomainError>;
+
     /// Increment open ticket count by delta (can be negative)
     async fn adjust_ticket_count(&self, id: &ProjectId, delta: i32) -> Result<(), DomainError>;
 
@@ -267,9 +434,52 @@ pub trait ProjectRepository: Send + Sync {
         agent_id: &AgentId,
     ) -> Result<bool, DomainError>;
 
+    /// Invite an agent to a project (no-op if already invited)
+    async fn add_invite(
+        &self,
+        project_id: &ProjectId,
+        agent_id: &AgentId,
+        invited_by: &AgentId,
+    ) -> Result<(), DomainError>;
+
+    /// Check if an agent has been invited to a project
+    async fn is_invited(
+        &self,
+        project_id: &ProjectId,
+        agent_id: &AgentId,
+    ) -> Result<bool, DomainError>;
+
+    /// Record that an agent force-merged a PR, waiving `waived_approvals`
+    /// required approvals
+    async fn record_force_merge(
+        &self,
+        project_id: &ProjectId,
+        agent_id: &AgentId,
+        pr_number: i64,
+        waived_approvals: i32,
+    ) -> Result<(), DomainError>;
+
+    /// PR numbers the stale PR sweep has already nudged in a project
+    async fn find_nudged_prs(&self, project_id: &ProjectId) -> Result<Vec<i64>, DomainError>;
+
+    /// Record that the stale PR sweep nudged a PR
+    async fn record_pr_nudge(
+        &self,
+        project_id: &ProjectId,
+        pr_number: i64,
+        nudged_at: DateTime<Utc>,
+    ) -> Result<(), DomainError>;
+
     /// Get projects an agent is a member of
     async fn find_by_agent(&self, agent_id: &AgentId) -> Result<Vec<Project>, DomainError>;
 
+    /// Get projects where an agent holds exactly the given role
+    async fn find_by_agent_with_role(
+        &self,
+        agent_id: &AgentId,
+        role: MemberRole,
+    ) -> Result<Vec<Project>, DomainError>;
+
     /// Get an agent's role in a project (None if not a member)
     async fn get_member_role(
         &self,
@@ -316,12 +526,74 @@ pub trait CodeContributionRepository: Send + Sync {
     async fn find_by_agent(&self, agent_id: &AgentId)
         -> Result<Vec<CodeContribution>, DomainError>;
 
+    /// Find a page of an agent's contributions, newest first
+    async fn find_by_agent_paginated(
+        &self,
+        agent_id: &AgentId,
+        limit: i64,
+        offset: i64,
+    ) -> Result<Vec<CodeContribution>, DomainError>;
+
     /// Find contributions by project
     async fn find_by_project(
         &self,
         project_id: &ProjectId,
     ) -> Result<Vec<CodeContribution>, DomainError>;
 
+    /// Find an agent's contributions with the given status, newest first
+    async fn find_by_agent_and_status(
+        &self,
+        agent_id: &AgentId,
+        status: ContributionStatus,
+    ) -> Result<Vec<CodeContribution>, DomainError>;
+
+    /// Find a project's contributions with the given status, newest first
+    async fn find_by_project_and_status(
+        &self,
+        project_id: &ProjectId,
+        status: ContributionStatus,
+    ) -> Result<Vec<CodeContribution>, DomainError>;
+
+    /// Count an agent's contributions to a project merged at or after `since`
+    async fn count_by_agent_and_project_since(
+        &self,
+        agent_id: &AgentId,
+        project_id: &ProjectId,
+        since: DateTime<Utc>,
+    ) -> Result<i64, DomainError>;
+
+    /// When each of an agent's reverted contributions was reverted (oldest first)
+    async fn find_revert_times(
+        &self,
+        agent_id: &AgentId,
+    ) -> Result<Vec<DateTime<Utc>>, DomainError>;
+
+    /// Count an agent's contributions merged after `since` (if given) and no
+    /// later than `merged_by` that were neither reverted nor replaced by `at`
+    async fn count_healthy_between(
+        &self,
+        agent_id: &AgentId,
+        since: Option<DateTime<Utc>>,
+        merged_by: DateTime<Utc>,
+        at: DateTime<Utc>,
+    ) -> Result<i64, DomainError>;
+
+    /// Projects both agents have contributions in
+    async fn find_shared_projects(
+        &self,
+        agent_id: &AgentId,
+        other_agent_id: &AgentId,
+    ) -> Result<Vec<ProjectId>, DomainError>;
+
+    /// Count an agent's contributions to `projects`, only those with
+    /// `status` when given
+    async fn count_in_projects(
+        &self,
+        agent_id: &AgentId,
+        projects: &[ProjectId],
+        status: Option<ContributionStatus>,
+    ) -> Result<i64, DomainError>;
+
     /// Find healthy contributions eligible for longevity bonus
     /// (status = healthy, longevity_bonus_paid = false, merged_at < threshold)
     async fn find_eligible_for_longevity_bonus(
@@ -343,8 +615,10 @@ pub trait CodeContributionRepository: Send + Sync {
         timestamp: DateTime<Utc>,
     ) -> Result<(), DomainError>;
 
-    /// Mark longevity bonus as paid
-    async fn mark_longevity_bonus_paid(&self, id: &CodeContributionId) -> Result<(), DomainError>;
+    /// Mark longevity bonus as paid. Returns false if it was already paid, so
+    /// concurrent or repeated sweeps award each bonus at most once.
+    async fn mark_longevity_bonus_paid(&self, id: &CodeContributionId)
+        -> Result<bool, DomainError>;
 
     /// Increment bug count
     async fn increment_bug_count(&self, id: &CodeContributionId) -> Result<(), DomainError>;
@@ -372,6 +646,28 @@ pub trait AgentReviewRepository: Send + Sync {
     /// Find reviews of a reviewed agent
     async fn find_by_reviewed(&self, agent_id: &AgentId) -> Result<Vec<AgentReview>, DomainError>;
 
+    /// Find a page of reviews by a reviewer agent, newest first
+    async fn find_by_reviewer_paginated(
+        &self,
+        agent_id: &AgentId,
+        limit: i64,
+        offset: i64,
+    ) -> Result<Vec<AgentReview>, DomainError>;
+
+    /// Find a page of reviews of a reviewed agent, newest first
+    async fn find_by_reviewed_paginated(
+        &self,
+        agent_id: &AgentId,
+        limit: i64,
+        offset: i64,
+    ) -> Result<Vec<AgentReview>, DomainError>;
+
+    /// Count reviews by a reviewer agent
+    async fn count_by_reviewer(&self, agent_id: &AgentId) -> Result<i64, DomainError>;
+
+    /// Count reviews of a reviewed agent
+    async fn count_by_reviewed(&self, agent_id: &AgentId) -> Result<i64, DomainError>;
+
     /// Count reviews by an agent in a time window (for rate limiting)
     async fn count_by_reviewer_since(
         &self,
@@ -379,6 +675,14 @@ pub trait AgentReviewRepository: Send + Sync {
         since: DateTime<Utc>,
     ) -> Result<i64, DomainError>;
 
+    /// Count the reviews with `verdict` one agent gave another
+    async fn count_between(
+        &self,
+        reviewer_agent_id: &AgentId,
+        reviewed_agent_id: &AgentId,
+        verdict: ReviewVerdict,
+    ) -> Result<i64, DomainError>;
+
     /// Check if a reviewer has already reviewed a specific PR
     async fn exists_for_pr_and_reviewer(
         &self,
@@ -419,6 +723,126 @@ pub trait EloEventRepository: Send + Sync {
 
     /// Get total ELO delta for an agent (useful for auditing)
     async fn sum_delta_by_agent(&self, agent_id: &AgentId) -> Result<i64, DomainError>;
+
+    /// Get total ELO delta for an agent from events created at or after `since`
+    async fn sum_delta_by_agent_since(
+        &self,
+        agent_id: &AgentId,
+        since: DateTime<Utc>,
+    ) -> Result<i64, DomainError>;
+
+    /// Net ELO delta per agent over events created at or after `since`
+    /// (agents without events in the window are omitted)
+    async fn net_deltas_since(
+        &self,
+        since: DateTime<Utc>,
+    ) -> Result<Vec<(AgentId, i64)>, DomainError>;
+}
+
+/// Repository for deferred PR-rejection penalties
+#[async_trait]
+pub trait PendingPenaltyRepository: Send + Sync {
+    /// Record a new pending penalty
+    async fn create(&self, penalty: &NewPendingPenalty) -> Result<PendingPenalty, DomainError>;
+
+    /// Find pending penalties whose grace period has elapsed by `now` (oldest first)
+    async fn find_due(
+        &self,
+        now: DateTime<Utc>,
+        limit: i64,
+    ) -> Result<Vec<PendingPenalty>, DomainError>;
+
+    /// Find pending penalties for a PR
+    async fn find_pending_by_pr(
+        &self,
+        project_id: &ProjectId,
+        pr_number: i64,
+    ) -> Result<Vec<PendingPenalty>, DomainError>;
+
+    /// Find an agent's penalties that are still pending (soonest to apply first)
+    async fn find_pending_by_agent(
+        &self,
+        agent_id: &AgentId,
+    ) -> Result<Vec<PendingPenalty>, DomainError>;
+
+    /// Push back when a still-pending penalty is next considered
+    async fn reschedule(
+        &self,
+        id: &PendingPenaltyId,
+        apply_at: DateTime<Utc>,
+    ) -> Result<(), DomainError>;
+
+    /// Mark a penalty as applied or canceled
+    async fn resolve(
+        &self,
+        id: &PendingPenaltyId,
+        status: PenaltyStatus,
+        resolved_at: DateTime<Utc>,
+    ) -> Result<(), DomainError>;
+}
+
+/// Repository for reviews requested of specific agents
+#[async_trait]
+pub trait ReviewRequestRepository: Send + Sync {
+    /// Record a new pending review request
+    async fn create(&self, request: &NewReviewRequest) -> Result<ReviewRequest, DomainError>;
+
+    /// Find pending requests whose deadline has passed by `now` (oldest first)
+    async fn find_overdue(
+        &self,
+        now: DateTime<Utc>,
+        limit: i64,
+    ) -> Result<Vec<ReviewRequest>, DomainError>;
+
+    /// Find every request made on a PR, resolved or not (oldest first)
+    async fn find_by_pr(
+        &self,
+        project_id: &ProjectId,
+        pr_number: i64,
+    ) -> Result<Vec<ReviewRequest>, DomainError>;
+
+    /// Push back when a still-pending request is next considered overdue
+    async fn reschedule(
+        &self,
+        id: &ReviewRequestId,
+        due_at: DateTime<Utc>,
+    ) -> Result<(), DomainError>;
+
+    /// Mark a request as completed, reassigned, penalized or canceled
+    async fn resolve(
+        &self,
+        id: &ReviewRequestId,
+        status: ReviewRequestStatus,
+        resolved_at: DateTime<Utc>,
+    ) -> Result<(), DomainError>;
+}
+
+/// Repository for the webhook delivery log
+#[async_trait]
+pub trait WebhookDeliveryRepository: Send + Sync {
+    /// Log a received webhook
+    async fn create(&self, delivery: &NewWebhookDelivery) -> Result<WebhookDelivery, DomainError>;
+
+    /// Most recently received deliveries (newest first)
+    async fn find_recent(&self, limit: i64) -> Result<Vec<WebhookDelivery>, DomainError>;
+
+    /// Whether a delivery with this Gitea delivery id was already processed successfully
+    async fn is_processed(&self, delivery_id: &str) -> Result<bool, DomainError>;
+
+    /// Record how processing of a queued delivery ended (drops its stored body)
+    async fn complete(
+        &self,
+        id: &WebhookDeliveryId,
+        outcome: DeliveryOutcome,
+        error: Option<String>,
+    ) -> Result<(), DomainError>;
+
+    /// Deliveries still waiting to be processed (oldest first)
+    async fn find_queued(&self, limit: i64) -> Result<Vec<WebhookDelivery>, DomainError>;
+
+    /// Delete deliveries received before `before`, except ones still queued.
+    /// Returns the number deleted.
+    async fn delete_received_before(&self, before: DateTime<Utc>) -> Result<u64, DomainError>;
 }
 
 /// Repository for Engagement entities
@@ -436,6 +860,22 @@ pub trait EngagementRepository: Send + Sync {
         offset: i64,
     ) -> Result<Vec<Engagement>, DomainError>;
 
+    /// Most recent engagements on each of several targets, at most
+    /// `per_target` each, keyed by target ID (targets without any are absent)
+    async fn find_recent_by_targets(
+        &self,
+        target_type: &str,
+        target_ids: &[uuid::Uuid],
+        per_target: i64,
+    ) -> Result<HashMap<uuid::Uuid, Vec<Engagement>>, DomainError>;
+
+    /// Count engagements on a target (same filter as `find_by_target`)
+    async fn count_by_target(
+        &self,
+        target_type: &str,
+        target_id: uuid::Uuid,
+    ) -> Result<i64, DomainError>;
+
     /// Find engagements by agent
     async fn find_by_agent(
         &self,
@@ -444,19 +884,57 @@ pub trait EngagementRepository: Send + Sync {
         offset: i64,
     ) -> Result<Vec<Engagement>, DomainError>;
 
-    /// Get engagement counts for a target
+    /// Get engagement counts for a target, including reactions made directly on Gitea
     async fn get_counts(
         &self,
         target_type: &str,
         target_id: uuid::Uuid,
     ) -> Result<EngagementCounts, DomainError>;
 
+    /// Get engagement counts for several targets in one query.
+    /// Targets without any engagement map to zeroed counts.
+    async fn get_counts_batch(
+        &self,
+        targets: &[(String, uuid::Uuid)],
+    ) -> Result<HashMap<(String, uuid::Uuid), EngagementCounts>, DomainError>;
+
+    /// Replace the tally of reactions made on Gitea by users who aren't agents
+    async fn set_external_counts(
+        &self,
+        target_type: &str,
+        target_id: uuid::Uuid,
+        counts: &EngagementCounts,
+    ) -> Result<(), DomainError>;
+
     /// Create a new engagement
     async fn create(&self, engagement: &NewEngagement) -> Result<Engagement, DomainError>;
 
     /// Update Gitea sync status
     async fn mark_synced(&self, id: &EngagementId, gitea_id: i64) -> Result<(), DomainError>;
 
+    /// Find engagements meant for Gitea, recorded before `created_before`,
+    /// that haven't been synced yet and haven't been given up on, oldest first
+    async fn find_unsynced(
+        &self,
+        created_before: DateTime<Utc>,
+        limit: i64,
+    ) -> Result<Vec<Engagement>, DomainError>;
+
+    /// Count a failed sync attempt, flagging the engagement as permanently
+    /// failed when `give_up` is set
+    async fn record_sync_failure(
+        &self,
+        id: &EngagementId,
+        give_up: bool,
+    ) -> Result<(), DomainError>;
+
+    /// Gitea IDs of a target's engagements that have been mirrored to Gitea
+    async fn find_synced_gitea_ids(
+        &self,
+        target_type: &str,
+        target_id: uuid::Uuid,
+    ) -> Result<Vec<i64>, DomainError>;
+
     /// Check if agent already has this reaction on target
     async fn has_reaction(
         &self,
@@ -465,6 +943,15 @@ pub trait EngagementRepository: Send + Sync {
         target_id: uuid::Uuid,
         reaction: &str,
     ) -> Result<bool, DomainError>;
+
+    /// When the agent last engaged with a target in this way (None if never)
+    async fn last_engagement_at(
+        &self,
+        agent_id: &AgentId,
+        target_type: &str,
+        target_id: uuid::Uuid,
+        engagement_type: &str,
+    ) -> Result<Option<DateTime<Utc>>, DomainError>;
 }
 
 /// Repository for ViralMoment entities
@@ -487,6 +974,21 @@ pub trait ViralMomentRepository: Send + Sync {
     /// Find promoted moments (staff picks)
     async fn find_promoted(&self, limit: i64) -> Result<Vec<ViralMoment>, DomainError>;
 
+    /// Count visible promoted moments
+    async fn count_promoted(&self) -> Result<i64, DomainError>;
+
+    /// Find moments matching `filter` (hidden ones included unless filtered out),
+    /// ordered by `filter.sort`
+    async fn find_filtered(
+        &self,
+        filter: &MomentFilter,
+        limit: i64,
+        offset: i64,
+    ) -> Result<Vec<ViralMoment>, DomainError>;
+
+    /// Count moments matching `filter`
+    async fn count_filtered(&self, filter: &MomentFilter) -> Result<i64, DomainError>;
+
     /// Check if a moment already exists for this reference
     async fn exists_for_reference(
         &self,
@@ -520,4 +1022,21 @@ pub trait ViralMomentRepository: Send + Sync {
         limit: i64,
         offset: i64,
     ) -> Result<Vec<ViralMoment>, DomainError>;
+
+    /// Find unpromoted moments created before `before` whose snapshot hasn't
+    /// been compacted yet, oldest first
+    async fn find_compactable(
+        &self,
+        before: DateTime<Utc>,
+        limit: i64,
+    ) -> Result<Vec<ViralMoment>, DomainError>;
+
+    /// Replace a moment's snapshot with its compacted summary. A moment
+    /// promoted in the meantime keeps its full snapshot; returns whether the
+    /// snapshot was replaced.
+    async fn compact_snapshot(
+        &self,
+        id: &ViralMomentId,
+        summary: &serde_json::Value,
+    ) -> Result<bool, DomainError>;
 }
@@ -26,6 +26,7 @@ pub struct Model {
     pub github_username: Option<String>,
     #[sea_orm(column_type = "Text", nullable)]
     pub github_avatar_url: Option<String>,
+    pub merged_into: Option<Uuid>,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
@@ -38,6 +39,8 @@ pub enum Relation {
     Engagements,
     #[sea_orm(has_many = "super::issues::Entity")]
     Issues,
+    #[sea_orm(has_many = "super::pending_penalties::Entity")]
+    PendingPenalties,
     #[sea_orm(has_many = "super::pr_reviews::Entity")]
     PrReviews,
     #[sea_orm(has_many = "super::project_members::Entity")]
@@ -74,6 +77,12 @@ impl Related<super::issues::Entity> for Entity {
     }
 }
 
+impl Related<super::pending_penalties::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::PendingPenalties.def()
+    }
+}
+
 impl Related<super::pr_reviews::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::PrReviews.def()
@@ -15,6 +15,9 @@ pub struct Model {
     pub skull_count: i32,
     pub comment_count: i32,
     pub total_score: i32,
+    pub heart_count: i32,
+    pub eyes_count: i32,
+    pub review_count: i32,
     pub updated_at: DateTimeWithTimeZone,
 }
 
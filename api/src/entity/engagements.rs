@@ -18,6 +18,10 @@ pub struct Model {
     pub gitea_synced: bool,
     pub gitea_id: Option<i64>,
     pub created_at: DateTimeWithTimeZone,
+    pub gitea_org: Option<String>,
+    pub gitea_repo: Option<String>,
+    pub sync_attempts: i32,
+    pub sync_failed: bool,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
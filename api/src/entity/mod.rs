@@ -7,12 +7,19 @@ pub mod agents;
 pub mod code_contributions;
 pub mod elo_events;
 pub mod engagement_counts;
+pub mod engagement_external_counts;
 pub mod engagements;
+pub mod force_merges;
 pub mod issues;
+pub mod pending_penalties;
 pub mod pr_reviews;
+pub mod project_invites;
 pub mod project_members;
 pub mod projects;
 pub mod pull_requests;
+pub mod review_requests;
+pub mod stale_pr_nudges;
 pub mod tickets;
 pub mod viral_moments;
 pub mod votes;
+pub mod webhook_deliveries;
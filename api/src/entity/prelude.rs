@@ -7,12 +7,19 @@ pub use super::agents::Entity as Agents;
 pub use super::code_contributions::Entity as CodeContributions;
 pub use super::elo_events::Entity as EloEvents;
 pub use super::engagement_counts::Entity as EngagementCounts;
+pub use super::engagement_external_counts::Entity as EngagementExternalCounts;
 pub use super::engagements::Entity as Engagements;
+pub use super::force_merges::Entity as ForceMerges;
 pub use super::issues::Entity as Issues;
+pub use super::pending_penalties::Entity as PendingPenalties;
 pub use super::pr_reviews::Entity as PrReviews;
+pub use super::project_invites::Entity as ProjectInvites;
 pub use super::project_members::Entity as ProjectMembers;
 pub use super::projects::Entity as Projects;
 pub use super::pull_requests::Entity as PullRequests;
+pub use super::review_requests::Entity as ReviewRequests;
+pub use super::stale_pr_nudges::Entity as StalePrNudges;
 pub use super::tickets::Entity as Tickets;
 pub use super::viral_moments::Entity as ViralMoments;
 pub use super::votes::Entity as Votes;
+pub use super::webhook_deliveries::Entity as WebhookDeliveries;
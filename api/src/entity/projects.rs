@@ -3,7 +3,7 @@
 use sea_orm::entity::prelude::*;
 use serde::{Deserialize, Serialize};
 
-#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Eq, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
 #[sea_orm(table_name = "projects")]
 pub struct Model {
     #[sea_orm(primary_key, auto_increment = false)]
@@ -21,6 +21,21 @@ pub struct Model {
     pub build_status: Option<String>,
     pub created_by: Option<Uuid>,
     pub created_at: Option<DateTimeWithTimeZone>,
+    #[sea_orm(column_type = "Text", nullable)]
+    pub mirror_source_url: Option<String>,
+    pub mirror_repo: Option<String>,
+    pub mirrored_at: Option<DateTimeWithTimeZone>,
+    pub allow_alternate_bases: bool,
+    pub require_green_ci: bool,
+    pub required_approvals: i32,
+    pub featured: bool,
+    pub featured_rank: i32,
+    #[sea_orm(column_type = "Float")]
+    pub elo_multiplier: f32,
+    pub visibility: String,
+    pub review_eligibility: String,
+    pub protect_default_branch: bool,
+    pub star_count: i32,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
@@ -39,6 +54,8 @@ pub enum Relation {
     CodeContributions,
     #[sea_orm(has_many = "super::issues::Entity")]
     Issues,
+    #[sea_orm(has_many = "super::pending_penalties::Entity")]
+    PendingPenalties,
     #[sea_orm(has_many = "super::project_members::Entity")]
     ProjectMembers,
     #[sea_orm(has_many = "super::pull_requests::Entity")]
@@ -65,6 +82,12 @@ impl Related<super::issues::Entity> for Entity {
     }
 }
 
+impl Related<super::pending_penalties::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::PendingPenalties.def()
+    }
+}
+
 impl Related<super::project_members::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::ProjectMembers.def()
@@ -21,6 +21,9 @@ pub struct Model {
     pub created_by: Option<Uuid>,
     pub created_at: Option<DateTimeWithTimeZone>,
     pub closed_at: Option<DateTimeWithTimeZone>,
+    #[sea_orm(column_type = "Text", nullable)]
+    pub blocked_reason: Option<String>,
+    pub difficulty: String,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
@@ -24,6 +24,7 @@ pub struct Model {
     #[sea_orm(column_type = "JsonBinary", nullable)]
     pub llm_classification: Option<Json>,
     pub created_at: DateTimeWithTimeZone,
+    pub snapshot_compacted_at: Option<DateTimeWithTimeZone>,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
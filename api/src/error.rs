@@ -6,7 +6,7 @@
 //! - `AppError`: Application layer errors (wraps domain errors for HTTP responses)
 
 use axum::{
-    http::StatusCode,
+    http::{header, StatusCode},
     response::{IntoResponse, Response},
     Json,
 };
@@ -104,6 +104,9 @@ pub enum AppError {
     #[error("Invalid request: {0}")]
     BadRequest(String),
 
+    #[error("Unprocessable: {0}")]
+    Unprocessable(String),
+
     #[error("Unauthorized")]
     Unauthorized,
 
@@ -118,6 +121,18 @@ pub enum AppError {
 
     #[error("Parse error: {0}")]
     Parse(String),
+
+    #[error("Invalid action: {0}")]
+    ActionParse(#[from] ActionParseError),
+
+    #[error("Payload too large: {0}")]
+    PayloadTooLarge(String),
+
+    #[error("Service unavailable: {0}")]
+    Unavailable(String),
+
+    #[error("Too many requests: {0}")]
+    TooManyRequests(String),
 }
 
 /// Error response body for JSON responses
@@ -126,6 +141,9 @@ struct ErrorResponse {
     error: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     details: Option<String>,
+    /// Structured detail for a rejected action command
+    #[serde(skip_serializing_if = "Option::is_none")]
+    parse_error: Option<ActionParseError>,
 }
 
 impl IntoResponse for AppError {
@@ -208,6 +226,11 @@ impl IntoResponse for AppError {
             AppError::BadRequest(msg) => {
                 (StatusCode::BAD_REQUEST, "Bad request", Some(msg.clone()))
             }
+            AppError::Unprocessable(msg) => (
+                StatusCode::UNPROCESSABLE_ENTITY,
+                "Unprocessable entity",
+                Some(msg.clone()),
+            ),
             AppError::Unauthorized => (StatusCode::UNAUTHORIZED, "Unauthorized", None),
             AppError::Forbidden => (StatusCode::FORBIDDEN, "Forbidden", None),
             AppError::NotFound(msg) => (StatusCode::NOT_FOUND, "Not found", Some(msg.clone())),
@@ -220,17 +243,62 @@ impl IntoResponse for AppError {
                 )
             }
             AppError::Parse(msg) => (StatusCode::BAD_REQUEST, "Parse error", Some(msg.clone())),
+            AppError::ActionParse(e) => (
+                StatusCode::UNPROCESSABLE_ENTITY,
+                "Invalid action",
+                Some(e.to_string()),
+            ),
+            AppError::PayloadTooLarge(msg) => (
+                StatusCode::PAYLOAD_TOO_LARGE,
+                "Payload too large",
+                Some(msg.clone()),
+            ),
+            AppError::Unavailable(msg) => (
+                StatusCode::SERVICE_UNAVAILABLE,
+                "Service unavailable",
+                Some(msg.clone()),
+            ),
+            AppError::TooManyRequests(msg) => (
+                StatusCode::TOO_MANY_REQUESTS,
+                "Too many requests",
+                Some(msg.clone()),
+            ),
+        };
+
+        let parse_error = match self {
+            AppError::ActionParse(e) => Some(e),
+            _ => None,
         };
 
         let body = Json(ErrorResponse {
             error: error.to_string(),
             details,
+            parse_error,
         });
 
         (status, body).into_response()
     }
 }
 
+/// Response mapper that turns axum's plain-text body-limit rejections into
+/// the standard JSON error body. Applied as a router-wide middleware.
+pub async fn json_payload_too_large(response: Response) -> Response {
+    let is_json = response
+        .headers()
+        .get(header::CONTENT_TYPE)
+        .and_then(|v| v.to_str().ok())
+        .is_some_and(|v| v.starts_with("application/json"));
+
+    if response.status() == StatusCode::PAYLOAD_TOO_LARGE && !is_json {
+        return AppError::PayloadTooLarge(
+            "Request body exceeds the maximum allowed size".to_string(),
+        )
+        .into_response();
+    }
+
+    response
+}
+
 /// Parse error for action parsing
 #[derive(Debug, Error)]
 pub enum ParseError {
@@ -247,6 +315,33 @@ pub enum ParseError {
     InvalidNumber(#[from] std::num::ParseIntError),
 }
 
+/// Why an agent's action command couldn't be parsed.
+///
+/// Serialized into the error response so agents can correct the command.
+#[derive(Debug, Clone, PartialEq, Eq, Error, Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum ActionParseError {
+    #[error("No action given. Send `help` to list available actions")]
+    Empty,
+
+    #[error("Unknown action '{action}'. Send `help` to list available actions")]
+    UnknownAction { action: String },
+
+    #[error("Missing argument <{name}> for {action} (usage: {usage})")]
+    MissingArgument {
+        action: String,
+        name: String,
+        usage: String,
+    },
+
+    #[error("Invalid argument <{name}> '{value}': {reason}")]
+    InvalidArgument {
+        name: String,
+        value: String,
+        reason: String,
+    },
+}
+
 impl From<ParseError> for AppError {
     fn from(e: ParseError) -> Self {
         AppError::Parse(e.to_string())
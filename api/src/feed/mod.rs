@@ -5,5 +5,6 @@
 pub mod renderer;
 
 pub use renderer::{
-    render_feed, render_leaderboard, render_profile, render_project_details, render_work_status,
+    render_feed, render_leaderboard_with_flair, render_profile_with_flair, render_project_details,
+    render_work_status,
 };
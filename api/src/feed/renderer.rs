@@ -3,6 +3,7 @@
 //! Renders feeds to LLM-readable markdown format.
 
 use crate::app::{Feed, FeedNotification, FeedPR, FeedProject, FeedTicket};
+use crate::domain::entities::{EloEventType, TierFlair};
 
 /// Render a feed to markdown format
 pub fn render_feed(feed: &Feed) -> String {
@@ -81,13 +82,27 @@ fn render_notification(notification: &FeedNotification) -> String {
         "approved" => "[APPROVED]",
         "changes_requested" => "[CHANGES REQUESTED]",
         "ci_failed" => "[CI FAILED]",
+        "longevity_bonus" => "[LONGEVITY]",
+        "review_requested" => "[REVIEW REQUESTED]",
+        "gitea_notification" => "[GITEA]",
+        elo_event if elo_event.parse::<EloEventType>().is_ok() => "[ELO]",
         _ => "[INFO]",
     };
 
-    let mut line = format!(
-        "{} PR #{}: {}",
-        icon, notification.pr_number, notification.pr_title
-    );
+    if notification.notification_type == "welcome" {
+        let message = notification.message.as_deref().unwrap_or_default();
+        return format!("[WELCOME] {}\n    {}\n", notification.pr_title, message);
+    }
+
+    if notification.is_summary() {
+        let summary = notification.message.as_deref().unwrap_or_default();
+        return format!("{} {}\n", icon, summary);
+    }
+
+    let mut line = match notification.pr_number {
+        Some(number) => format!("{} PR #{}: {}", icon, number, notification.pr_title),
+        None => format!("{} {}", icon, notification.pr_title),
+    };
 
     if let Some(msg) = &notification.message {
         line.push_str(&format!("\n    {}", truncate(msg, 80)));
@@ -212,15 +227,18 @@ pub fn render_project_details(project: &crate::domain::entities::Project) -> Str
     buf
 }
 
-/// Render agent profile
-pub fn render_profile(agent: &crate::domain::entities::Agent) -> String {
+/// Render agent profile with a badge next to the tier
+pub fn render_profile_with_flair(
+    agent: &crate::domain::entities::Agent,
+    flair: &TierFlair,
+) -> String {
     let mut buf = String::new();
 
     buf.push_str(&format!("# Agent: {}\n\n", agent.name));
 
     buf.push_str("## Stats\n\n");
     buf.push_str(&format!("- **ELO:** {}\n", agent.elo));
-    buf.push_str(&format!("- **Tier:** {}\n", agent.tier));
+    buf.push_str(&format!("- **Tier:** {}\n", flair.label(agent.tier)));
 
     buf.push_str("\n## Account\n\n");
     buf.push_str(&format!(
@@ -238,10 +256,11 @@ pub fn render_profile(agent: &crate::domain::entities::Agent) -> String {
     buf
 }
 
-/// Render leaderboard
-pub fn render_leaderboard(
+/// Render leaderboard with a badge next to each tier
+pub fn render_leaderboard_with_flair(
     agents: &[crate::domain::entities::Agent],
     current_agent: &crate::domain::entities::Agent,
+    flair: &TierFlair,
 ) -> String {
     let mut buf = String::new();
 
@@ -262,7 +281,11 @@ pub fn render_leaderboard(
             };
             buf.push_str(&format!(
                 "| {} | {}{} | {} | {} |\n",
-                rank, agent.name, marker, agent.elo, agent.tier
+                rank,
+                agent.name,
+                marker,
+                agent.elo,
+                flair.label(agent.tier)
             ));
         }
         buf.push('\n');
@@ -273,7 +296,8 @@ pub fn render_leaderboard(
     if !current_in_list {
         buf.push_str(&format!(
             "**Your position:** ELO {} ({})\n\n",
-            current_agent.elo, current_agent.tier
+            current_agent.elo,
+            flair.label(current_agent.tier)
         ));
     }
 
@@ -344,23 +368,54 @@ mod tests {
         assert!(!result.contains("## My Pull Requests"));
     }
 
+    #[test]
+    fn render_notification_without_pr() {
+        let notification = FeedNotification {
+            notification_type: "ticket_abandoned".to_string(),
+            pr_number: None,
+            pr_title: "ELO -2 (1000 -> 998)".to_string(),
+            message: None,
+            elo_change: Some(-2),
+            created_at: chrono::Utc::now(),
+            count: 1,
+        };
+
+        let line = render_notification(&notification);
+
+        assert!(line.starts_with("[ELO] ELO -2 (1000 -> 998)"));
+        assert!(!line.contains("PR #"));
+    }
+
     #[test]
     fn render_feed_with_notifications() {
         let feed = Feed {
             notifications: vec![
                 FeedNotification {
                     notification_type: "changes_requested".to_string(),
-                    pr_number: 42,
+                    pr_number: Some(42),
                     pr_title: "Fix the bug".to_string(),
                     message: Some("Please add tests".to_string()),
                     elo_change: None,
+                    created_at: chrono::Utc::now(),
+                    count: 1,
                 },
                 FeedNotification {
                     notification_type: "merged".to_string(),
-                    pr_number: 41,
+                    pr_number: Some(41),
                     pr_title: "Add feature".to_string(),
                     message: Some("Great work!".to_string()),
                     elo_change: Some(25),
+                    created_at: chrono::Utc::now(),
+                    count: 1,
+                },
+                FeedNotification {
+                    notification_type: "longevity_bonus".to_string(),
+                    pr_number: Some(40),
+                    pr_title: "Old feature".to_string(),
+                    message: Some("3 longevity bonuses: +30".to_string()),
+                    elo_change: Some(30),
+                    created_at: chrono::Utc::now(),
+                    count: 3,
                 },
             ],
             my_tickets: vec![],
@@ -375,6 +430,7 @@ mod tests {
         assert!(result.contains("Please add tests"));
         assert!(result.contains("[MERGED] PR #41: Add feature"));
         assert!(result.contains("ELO: +25"));
+        assert!(result.contains("[LONGEVITY] 3 longevity bonuses: +30"));
     }
 
     #[test]
@@ -533,7 +589,7 @@ mod tests {
     fn render_profile_basic() {
         let agent = test_agent();
 
-        let result = render_profile(&agent);
+        let result = render_profile_with_flair(&agent, &TierFlair::default());
 
         assert!(result.contains(&format!("# Agent: {}", agent.name)));
         assert!(result.contains("## Stats"));
@@ -549,7 +605,7 @@ mod tests {
         let mut agent = test_agent();
         agent.last_seen_at = Some(chrono::Utc::now());
 
-        let result = render_profile(&agent);
+        let result = render_profile_with_flair(&agent, &TierFlair::default());
 
         assert!(result.contains("**Last Active:**"));
     }
@@ -561,20 +617,37 @@ mod tests {
         agent.elo = 1800;
         agent.tier = Tier::Gold;
 
-        let result = render_profile(&agent);
+        let result = render_profile_with_flair(&agent, &TierFlair::default());
 
         assert!(result.contains("# Agent: pro-agent"));
         assert!(result.contains("**ELO:** 1800"));
         assert!(result.contains("**Tier:** gold"));
     }
 
+    #[test]
+    fn render_profile_shows_configured_tier_flair() {
+        let flair: TierFlair = "gold=[GOLD],bronze=[BRONZE]".parse().unwrap();
+        let mut gold = test_agent();
+        gold.elo = 1800;
+        gold.tier = Tier::Gold;
+        let bronze = test_agent();
+
+        let gold_profile = render_profile_with_flair(&gold, &flair);
+        let bronze_profile = render_profile_with_flair(&bronze, &flair);
+
+        assert!(gold_profile.contains("**Tier:** gold [GOLD]"));
+        assert!(!gold_profile.contains("[BRONZE]"));
+        assert!(bronze_profile.contains("**Tier:** bronze [BRONZE]"));
+        assert!(!bronze_profile.contains("[GOLD]"));
+    }
+
     // ===== render_leaderboard tests =====
 
     #[test]
     fn render_leaderboard_empty() {
         let current_agent = test_agent();
 
-        let result = render_leaderboard(&[], &current_agent);
+        let result = render_leaderboard_with_flair(&[], &current_agent, &TierFlair::default());
 
         assert!(result.contains("# Leaderboard"));
         assert!(result.contains("_No agents ranked yet._"));
@@ -593,7 +666,8 @@ mod tests {
 
         let current_agent = test_agent_named("Viewer");
 
-        let result = render_leaderboard(&[agent1, agent2], &current_agent);
+        let result =
+            render_leaderboard_with_flair(&[agent1, agent2], &current_agent, &TierFlair::default());
 
         assert!(result.contains("# Leaderboard"));
         assert!(result.contains("| Rank | Agent | ELO | Tier |"));
@@ -613,7 +687,7 @@ mod tests {
         // Include current agent in list
         let agents = vec![agent1, current_agent.clone()];
 
-        let result = render_leaderboard(&agents, &current_agent);
+        let result = render_leaderboard_with_flair(&agents, &current_agent, &TierFlair::default());
 
         assert!(result.contains("| 2 | Me <- you | 1500 |"));
         assert!(!result.contains("**Your position:**"));
@@ -628,7 +702,8 @@ mod tests {
         current_agent.elo = 900;
         current_agent.tier = Tier::Bronze;
 
-        let result = render_leaderboard(&[agent1], &current_agent);
+        let result =
+            render_leaderboard_with_flair(&[agent1], &current_agent, &TierFlair::default());
 
         assert!(!result.contains("NotInList"));
         assert!(result.contains("**Your position:** ELO 900 (bronze)"));
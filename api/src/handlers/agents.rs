@@ -2,10 +2,32 @@
 //!
 //! Endpoints for agent registration and management.
 
-use axum::{extract::State, Json};
+use axum::{
+    body::Body,
+    extract::{Path, Query, State},
+    http::header,
+    response::{IntoResponse, Response},
+    Extension, Json,
+};
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use uuid::Uuid;
 
+use crate::app::elo_config::ELO_PR_REJECTED;
+use crate::app::pending_penalty_service::CANCEL_CONDITIONS;
+use crate::app::reactive_elo_service::{EloMover, HeadToHead, MoversRange, RivalRecord};
+use crate::app::{
+    ActivityDay, BatchFailurePolicy, BatchUserResult, EloSimulation, ExportRecords,
+    ReviewDirection, ReviewRecord, UserCreationOutcome,
+};
+use crate::auth::AdminToken;
+use crate::domain::entities::{
+    Agent, AgentId, CodeContribution, ContributionStatus, EloEventType, OnboardingStatus,
+    PendingPenalty, ProjectId, Tier,
+};
+use crate::domain::ports::ProjectRepository;
 use crate::error::AppError;
+use crate::handlers::pagination::Page;
 use crate::AppState;
 
 /// Request body for agent registration
@@ -37,6 +59,770 @@ pub struct RegisterResponse {
     pub message: String,
 }
 
+/// Number of agents in a single tier
+#[derive(Debug, Serialize)]
+pub struct TierCount {
+    pub tier: Tier,
+    pub count: i64,
+}
+
+/// Response body for tier distribution statistics
+#[derive(Debug, Serialize)]
+pub struct TierStatsResponse {
+    pub tiers: Vec<TierCount>,
+    pub total: i64,
+}
+
+/// GET /stats/tiers
+///
+/// Histogram of how many agents sit in each tier.
+pub async fn get_tier_stats(
+    State(state): State<AppState>,
+) -> Result<Json<TierStatsResponse>, AppError> {
+    let distribution = state.agent_service.get_tier_distribution().await?;
+    let total = distribution.iter().map(|(_, count)| count).sum();
+
+    Ok(Json(TierStatsResponse {
+        tiers: distribution
+            .into_iter()
+            .map(|(tier, count)| TierCount { tier, count })
+            .collect(),
+        total,
+    }))
+}
+
+/// Most movers returned in each direction
+const MAX_MOVERS: usize = 50;
+
+/// Query parameters for the leaderboard movers
+#[derive(Debug, Deserialize)]
+pub struct MoversQuery {
+    /// Window to measure over: day, week (default), or month
+    pub range: Option<String>,
+    /// Agents returned in each direction (default 10, max 50)
+    pub limit: Option<usize>,
+}
+
+/// An agent's net ELO change over the window
+#[derive(Debug, Serialize)]
+pub struct MoverResponse {
+    pub agent_id: String,
+    pub name: String,
+    pub elo: i32,
+    pub tier: Tier,
+    pub delta: i64,
+}
+
+impl From<EloMover> for MoverResponse {
+    fn from(mover: EloMover) -> Self {
+        Self {
+            agent_id: mover.agent.id.to_string(),
+            name: mover.agent.name,
+            elo: mover.agent.elo,
+            tier: mover.agent.tier,
+            delta: mover.delta,
+        }
+    }
+}
+
+/// Response body for the leaderboard movers
+#[derive(Debug, Serialize)]
+pub struct MoversResponse {
+    pub range: String,
+    pub since: String,
+    pub gainers: Vec<MoverResponse>,
+    pub losers: Vec<MoverResponse>,
+}
+
+/// GET /leaderboard/movers
+///
+/// Agents with the biggest net ELO gains and losses over a recent window.
+pub async fn get_leaderboard_movers(
+    State(state): State<AppState>,
+    Query(query): Query<MoversQuery>,
+) -> Result<Json<MoversResponse>, AppError> {
+    let range: MoversRange = match query.range.as_deref() {
+        Some(r) => r.parse().map_err(AppError::BadRequest)?,
+        None => MoversRange::default(),
+    };
+    let limit = query.limit.unwrap_or(10).clamp(1, MAX_MOVERS);
+
+    let movers = state
+        .reactive_elo_service
+        .leaderboard_movers(range, limit)
+        .await?;
+
+    Ok(Json(MoversResponse {
+        range: range.to_string(),
+        since: movers.since.to_rfc3339(),
+        gainers: movers.gainers.into_iter().map(Into::into).collect(),
+        losers: movers.losers.into_iter().map(Into::into).collect(),
+    }))
+}
+
+/// One agent's side of a head-to-head record
+#[derive(Debug, Serialize)]
+pub struct RivalRecordResponse {
+    pub agent_id: String,
+    pub name: String,
+    pub elo: i32,
+    /// Approvals this agent gave the rival
+    pub approvals_given: usize,
+    /// Change requests this agent gave the rival
+    pub changes_requested_given: usize,
+    /// Contributions merged into projects both agents contributed to
+    pub contested_contributions: usize,
+    /// Contested contributions still healthy
+    pub contested_surviving: usize,
+}
+
+impl From<RivalRecord> for RivalRecordResponse {
+    fn from(record: RivalRecord) -> Self {
+        Self {
+            agent_id: record.agent.id.to_string(),
+            name: record.agent.name,
+            elo: record.agent.elo,
+            approvals_given: record.reviews_given.approved,
+            changes_requested_given: record.reviews_given.changes_requested,
+            contested_contributions: record.contested_contributions,
+            contested_surviving: record.contested_surviving,
+        }
+    }
+}
+
+/// Response body for a head-to-head record
+#[derive(Debug, Serialize)]
+pub struct HeadToHeadResponse {
+    pub a: RivalRecordResponse,
+    pub b: RivalRecordResponse,
+    pub shared_projects: Vec<String>,
+    /// Agent ID of whoever leads on surviving contested contributions (null when tied)
+    pub leader: Option<String>,
+}
+
+impl From<HeadToHead> for HeadToHeadResponse {
+    fn from(h2h: HeadToHead) -> Self {
+        Self {
+            a: h2h.a.into(),
+            b: h2h.b.into(),
+            shared_projects: h2h.shared_projects.iter().map(|p| p.to_string()).collect(),
+            leader: h2h.leader.map(|id| id.to_string()),
+        }
+    }
+}
+
+/// GET /agents/:id/vs/:other
+///
+/// Head-to-head record between two agents: who reviewed whom, and whose
+/// contributions to shared projects held up better.
+pub async fn get_head_to_head(
+    State(state): State<AppState>,
+    Path((a, b)): Path<(Uuid, Uuid)>,
+) -> Result<Json<HeadToHeadResponse>, AppError> {
+    let record = state
+        .reactive_elo_service
+        .get_head_to_head(&AgentId(a), &AgentId(b))
+        .await?;
+
+    Ok(Json(record.into()))
+}
+
+/// Query parameters for listing an agent's contributions
+#[derive(Debug, Deserialize)]
+pub struct ListContributionsQuery {
+    /// Only return contributions with this status (healthy, reverted, replaced)
+    pub status: Option<String>,
+}
+
+/// A merged contribution and how it has held up
+#[derive(Debug, Serialize)]
+pub struct ContributionResponse {
+    pub id: String,
+    pub agent_id: String,
+    pub project_id: String,
+    pub pr_number: i64,
+    pub commit_sha: String,
+    pub status: String,
+    pub bug_count: i32,
+    pub dependent_prs_count: i32,
+    pub longevity_bonus_paid: bool,
+    pub merged_at: String,
+    pub reverted_at: Option<String>,
+    pub replaced_at: Option<String>,
+    pub created_at: String,
+}
+
+impl From<CodeContribution> for ContributionResponse {
+    fn from(c: CodeContribution) -> Self {
+        Self {
+            id: c.id.to_string(),
+            agent_id: c.agent_id.to_string(),
+            project_id: c.project_id.to_string(),
+            pr_number: c.pr_number,
+            commit_sha: c.commit_sha,
+            status: c.status.to_string(),
+            bug_count: c.bug_count,
+            dependent_prs_count: c.dependent_prs_count,
+            longevity_bonus_paid: c.longevity_bonus_paid,
+            merged_at: c.merged_at.to_rfc3339(),
+            reverted_at: c.reverted_at.map(|t| t.to_rfc3339()),
+            replaced_at: c.replaced_at.map(|t| t.to_rfc3339()),
+            created_at: c.created_at.to_rfc3339(),
+        }
+    }
+}
+
+/// GET /agents/:id/contributions
+///
+/// List an agent's merged contributions, newest first.
+/// Use `?status=healthy|reverted|replaced` to see one outcome at a time.
+pub async fn list_agent_contributions(
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+    Query(query): Query<ListContributionsQuery>,
+) -> Result<Json<Vec<ContributionResponse>>, AppError> {
+    let status = query
+        .status
+        .as_deref()
+        .map(|s| s.parse::<ContributionStatus>())
+        .transpose()
+        .map_err(AppError::BadRequest)?;
+
+    let agent_id = AgentId(id);
+    state
+        .agent_service
+        .find_by_id(&agent_id)
+        .await?
+        .ok_or_else(|| AppError::NotFound(format!("Agent {} not found", id)))?;
+
+    let contributions = state
+        .reactive_elo_service
+        .list_contributions(&agent_id, status)
+        .await?;
+
+    Ok(Json(
+        contributions
+            .into_iter()
+            .map(ContributionResponse::from)
+            .collect(),
+    ))
+}
+
+/// Largest page accepted by GET /agents/:id/reviews
+const MAX_REVIEWS_PAGE: i64 = 100;
+
+/// Query parameters for listing an agent's reviews
+#[derive(Debug, Deserialize)]
+pub struct ListReviewsQuery {
+    /// `given` (default) or `received`
+    pub direction: Option<String>,
+    #[serde(default = "default_reviews_limit")]
+    pub limit: i64,
+    #[serde(default)]
+    pub offset: i64,
+}
+
+fn default_reviews_limit() -> i64 {
+    20
+}
+
+/// The other agent on a review
+#[derive(Debug, Serialize)]
+pub struct ReviewCounterpart {
+    pub id: String,
+    /// None if the agent no longer exists
+    pub name: Option<String>,
+}
+
+/// A peer review given or received by an agent
+#[derive(Debug, Serialize)]
+pub struct AgentReviewResponse {
+    pub id: String,
+    pub verdict: String,
+    pub project_id: String,
+    pub project_name: Option<String>,
+    pub pr_number: i64,
+    /// Reviewed agent for given reviews, reviewer for received ones
+    pub counterpart: ReviewCounterpart,
+    pub reviewer_elo_at_time: i32,
+    pub created_at: String,
+}
+
+impl AgentReviewResponse {
+    fn new(record: ReviewRecord, direction: ReviewDirection) -> Self {
+        let review = record.review;
+        let counterpart_id = match direction {
+            ReviewDirection::Given => review.reviewed_agent_id,
+            ReviewDirection::Received => review.reviewer_agent_id,
+        };
+        Self {
+            id: review.id.to_string(),
+            verdict: review.verdict.to_string(),
+            project_id: review.project_id.to_string(),
+            project_name: record.project_name,
+            pr_number: review.pr_id,
+            counterpart: ReviewCounterpart {
+                id: counterpart_id.to_string(),
+                name: record.counterpart.map(|a| a.name),
+            },
+            reviewer_elo_at_time: review.reviewer_elo_at_time,
+            created_at: review.created_at.to_rfc3339(),
+        }
+    }
+}
+
+/// GET /agents/:id/reviews
+///
+/// Peer reviews the agent gave (`?direction=given`, the default) or received
+/// (`?direction=received`), newest first, one page at a time.
+pub async fn list_agent_reviews(
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+    Query(query): Query<ListReviewsQuery>,
+) -> Result<Json<Page<AgentReviewResponse>>, AppError> {
+    let direction = query
+        .direction
+        .as_deref()
+        .map(|d| d.parse::<ReviewDirection>())
+        .transpose()
+        .map_err(AppError::BadRequest)?
+        .unwrap_or_default();
+    let limit = query.limit.clamp(1, MAX_REVIEWS_PAGE);
+    let offset = query.offset.max(0);
+
+    let agent_id = AgentId(id);
+    state
+        .agent_service
+        .find_by_id(&agent_id)
+        .await?
+        .ok_or_else(|| AppError::NotFound(format!("Agent {} not found", id)))?;
+
+    let (records, total) = state
+        .reactive_elo_service
+        .list_reviews(&agent_id, direction, limit as usize, offset as usize)
+        .await?;
+
+    let items = records
+        .into_iter()
+        .map(|r| AgentReviewResponse::new(r, direction))
+        .collect();
+    Ok(Json(Page::new(items, total as i64, limit, offset)))
+}
+
+/// Contributions on one day of an agent's activity calendar
+#[derive(Debug, Serialize)]
+pub struct ActivityDayResponse {
+    /// UTC date (YYYY-MM-DD)
+    pub date: String,
+    pub count: i64,
+}
+
+/// An agent's activity calendar
+#[derive(Debug, Serialize)]
+pub struct ActivityResponse {
+    pub agent_id: String,
+    pub total: i64,
+    /// Days with at least one contribution, oldest first
+    pub days: Vec<ActivityDayResponse>,
+}
+
+impl ActivityResponse {
+    fn new(agent_id: AgentId, days: Vec<ActivityDay>) -> Self {
+        Self {
+            agent_id: agent_id.to_string(),
+            total: days.iter().map(|d| d.count).sum(),
+            days: days
+                .into_iter()
+                .map(|d| ActivityDayResponse {
+                    date: d.date.format("%Y-%m-%d").to_string(),
+                    count: d.count,
+                })
+                .collect(),
+        }
+    }
+}
+
+/// GET /agents/:id/activity
+///
+/// Daily contribution counts from the agent's Gitea activity heatmap
+/// (roughly the last year), for rendering an activity calendar.
+pub async fn get_agent_activity(
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<ActivityResponse>, AppError> {
+    let agent = state
+        .agent_service
+        .find_by_id(&AgentId(id))
+        .await?
+        .ok_or_else(|| AppError::NotFound(format!("Agent {} not found", id)))?;
+
+    let days = state.agent_service.get_activity(&agent).await?;
+    Ok(Json(ActivityResponse::new(agent.id, days)))
+}
+
+/// Query parameters for exporting an agent's history
+#[derive(Debug, Deserialize)]
+pub struct ExportHistoryQuery {
+    /// Export format (only `csv` is supported)
+    #[serde(default = "default_export_format")]
+    pub format: String,
+    /// Which records to export: contributions (default) or elo_events
+    pub records: Option<String>,
+}
+
+fn default_export_format() -> String {
+    "csv".to_string()
+}
+
+/// GET /agents/:id/export?format=csv
+///
+/// Download an agent's contribution history (or ELO events with
+/// `records=elo_events`) as CSV. The body is streamed as rows are read.
+pub async fn export_agent_history(
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+    Query(query): Query<ExportHistoryQuery>,
+) -> Result<Response, AppError> {
+    if !query.format.eq_ignore_ascii_case("csv") {
+        return Err(AppError::BadRequest(format!(
+            "Unsupported export format '{}'. Use: csv",
+            query.format
+        )));
+    }
+    let records = query
+        .records
+        .as_deref()
+        .map(|r| r.parse::<ExportRecords>())
+        .transpose()
+        .map_err(AppError::BadRequest)?
+        .unwrap_or_default();
+
+    let agent = state
+        .agent_service
+        .find_by_id(&AgentId(id))
+        .await?
+        .ok_or_else(|| AppError::NotFound(format!("Agent {} not found", id)))?;
+
+    let body = Body::from_stream(state.history_export_service.csv_stream(agent.id, records));
+    let disposition = format!(
+        "attachment; filename=\"{}-{}.csv\"",
+        agent.gitea_username,
+        records.file_stem()
+    );
+
+    Ok((
+        [
+            (header::CONTENT_TYPE, "text/csv; charset=utf-8".to_string()),
+            (header::CONTENT_DISPOSITION, disposition),
+        ],
+        body,
+    )
+        .into_response())
+}
+
+/// GET /agents/me/onboarding
+///
+/// Checklist of the setup steps the authenticated agent has completed.
+pub async fn get_onboarding_status(
+    State(state): State<AppState>,
+    Extension(agent): Extension<Agent>,
+) -> Result<Json<OnboardingStatus>, AppError> {
+    let projects = state.project_repo.find_by_agent(&agent.id).await?;
+    let contributions = state
+        .reactive_elo_service
+        .list_contributions(&agent.id, None)
+        .await?;
+
+    Ok(Json(OnboardingStatus::for_agent(
+        &agent,
+        !projects.is_empty(),
+        !contributions.is_empty(),
+    )))
+}
+
+/// A penalty scheduled against the agent that hasn't been applied yet
+#[derive(Debug, Serialize)]
+pub struct PendingPenaltyResponse {
+    pub id: Uuid,
+    pub project_id: Uuid,
+    pub gitea_org: String,
+    pub gitea_repo: String,
+    pub pr_number: i64,
+    /// ELO change once the penalty applies
+    pub elo_delta: i32,
+    pub apply_at: DateTime<Utc>,
+    pub created_at: DateTime<Utc>,
+    /// Events that cancel the penalty before it applies
+    pub cancel_conditions: Vec<&'static str>,
+}
+
+impl From<PendingPenalty> for PendingPenaltyResponse {
+    fn from(penalty: PendingPenalty) -> Self {
+        Self {
+            id: penalty.id.0,
+            project_id: penalty.project_id.0,
+            gitea_org: penalty.gitea_org,
+            gitea_repo: penalty.gitea_repo,
+            pr_number: penalty.pr_number,
+            elo_delta: ELO_PR_REJECTED,
+            apply_at: penalty.apply_at,
+            created_at: penalty.created_at,
+            cancel_conditions: CANCEL_CONDITIONS.to_vec(),
+        }
+    }
+}
+
+/// GET /agents/me/pending-penalties
+///
+/// Rejection penalties waiting out their grace period, soonest first.
+pub async fn get_pending_penalties(
+    State(state): State<AppState>,
+    Extension(agent): Extension<Agent>,
+) -> Result<Json<Vec<PendingPenaltyResponse>>, AppError> {
+    let penalties = state
+        .pending_penalty_service
+        .list_pending_for_agent(&agent.id)
+        .await?;
+
+    Ok(Json(penalties.into_iter().map(Into::into).collect()))
+}
+
+/// A newly issued Gitea token
+#[derive(Debug, Serialize)]
+pub struct RotateGiteaTokenResponse {
+    pub gitea_token: String,
+}
+
+/// POST /agents/me/gitea-token
+///
+/// Replace your Gitea access token. The old token stops working; the new
+/// one is only shown in this response.
+pub async fn rotate_gitea_token(
+    State(state): State<AppState>,
+    Extension(agent): Extension<Agent>,
+) -> Result<Json<RotateGiteaTokenResponse>, AppError> {
+    let gitea_token = state.agent_service.rotate_gitea_token(&agent).await?;
+    Ok(Json(RotateGiteaTokenResponse { gitea_token }))
+}
+
+/// Request body for merging a duplicate agent into another
+#[derive(Debug, Deserialize)]
+pub struct MergeAgentsRequest {
+    /// Duplicate agent; deactivated by the merge
+    pub source_agent_id: Uuid,
+    /// Agent that keeps the combined history
+    pub target_agent_id: Uuid,
+}
+
+/// Result of an agent merge
+#[derive(Debug, Serialize)]
+pub struct MergeAgentsResponse {
+    pub source_agent_id: Uuid,
+    pub target_agent_id: Uuid,
+    /// Target's ELO after the merge
+    pub elo: i32,
+    pub tier: Tier,
+    /// Number of records moved from the source to the target
+    pub contributions: u64,
+    pub reviews: u64,
+    pub elo_events: u64,
+    pub tickets: u64,
+    pub memberships: u64,
+    pub engagements: u64,
+    pub pending_penalties: u64,
+    pub viral_moments: u64,
+}
+
+/// POST /admin/agents/merge
+///
+/// Merge a duplicate agent into another (admin only). The source's
+/// contributions, reviews, ELO events, tickets, project memberships,
+/// engagements, pending penalties and viral moments move to the target,
+/// which keeps the ELO both earned; the source can no longer authenticate.
+pub async fn merge_agents(
+    State(state): State<AppState>,
+    _admin: AdminToken,
+    Json(request): Json<MergeAgentsRequest>,
+) -> Result<Json<MergeAgentsResponse>, AppError> {
+    let result = state
+        .agent_service
+        .merge_agents(
+            state.agent_merge_repo.as_ref(),
+            &AgentId(request.source_agent_id),
+            &AgentId(request.target_agent_id),
+        )
+        .await?;
+
+    Ok(Json(MergeAgentsResponse {
+        source_agent_id: result.source.id.0,
+        target_agent_id: result.target.id.0,
+        elo: result.target.elo,
+        tier: result.target.tier,
+        contributions: result.moved.contributions,
+        reviews: result.moved.reviews,
+        elo_events: result.moved.elo_events,
+        tickets: result.moved.tickets,
+        memberships: result.moved.memberships,
+        engagements: result.moved.engagements,
+        pending_penalties: result.moved.pending_penalties,
+        viral_moments: result.moved.viral_moments,
+    }))
+}
+
+/// Request body for creating Gitea users for existing agents
+#[derive(Debug, Deserialize)]
+pub struct ProvisionGiteaUsersRequest {
+    pub agent_ids: Vec<Uuid>,
+    /// Delete the users created so far if any creation fails
+    #[serde(default)]
+    pub rollback: bool,
+}
+
+/// Per-agent result of Gitea user provisioning
+#[derive(Debug, Serialize)]
+pub struct ProvisionedGiteaUser {
+    pub agent_id: Uuid,
+    pub gitea_username: String,
+    /// `created`, `failed` or `rolled_back`
+    pub outcome: &'static str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+impl From<BatchUserResult> for ProvisionedGiteaUser {
+    fn from(result: BatchUserResult) -> Self {
+        let (outcome, error) = match result.outcome {
+            UserCreationOutcome::Created => ("created", None),
+            UserCreationOutcome::Failed(e) => ("failed", Some(e)),
+            UserCreationOutcome::RolledBack => ("rolled_back", None),
+        };
+        Self {
+            agent_id: result.agent_id.0,
+            gitea_username: result.gitea_username,
+            outcome,
+            error,
+        }
+    }
+}
+
+/// POST /admin/agents/gitea-users
+///
+/// Create Gitea users for existing agents (admin only), e.g. after the
+/// Gitea instance was reset. Users are created concurrently; with
+/// `rollback` set, any failure deletes the users this request created.
+pub async fn provision_gitea_users(
+    State(state): State<AppState>,
+    _admin: AdminToken,
+    Json(request): Json<ProvisionGiteaUsersRequest>,
+) -> Result<Json<Vec<ProvisionedGiteaUser>>, AppError> {
+    let ids: Vec<AgentId> = request.agent_ids.into_iter().map(AgentId).collect();
+    let policy = if request.rollback {
+        BatchFailurePolicy::Rollback
+    } else {
+        BatchFailurePolicy::Report
+    };
+
+    let results = state
+        .agent_service
+        .provision_gitea_users(&ids, policy)
+        .await?;
+
+    Ok(Json(results.into_iter().map(Into::into).collect()))
+}
+
+/// Request body for simulating an ELO change
+#[derive(Debug, Deserialize)]
+pub struct SimulateEloRequest {
+    pub agent_id: Uuid,
+    pub event_type: EloEventType,
+    /// Delta to simulate (defaults to the event type's standard delta)
+    pub delta: Option<i32>,
+    /// Project the event happens on, whose ELO multiplier scales the delta
+    pub project_id: Option<Uuid>,
+}
+
+/// POST /admin/elo/simulate
+///
+/// Compute what an ELO event would do to an agent, applying the same project
+/// multiplier, daily cap and ELO bounds as a real change, without writing
+/// anything (admin only).
+pub async fn simulate_elo(
+    State(state): State<AppState>,
+    _admin: AdminToken,
+    Json(request): Json<SimulateEloRequest>,
+) -> Result<Json<EloSimulation>, AppError> {
+    let simulation = state
+        .reactive_elo_service
+        .simulate_elo_change(
+            &AgentId(request.agent_id),
+            request.event_type,
+            request.delta,
+            request.project_id.map(ProjectId).as_ref(),
+        )
+        .await?;
+
+    Ok(Json(simulation))
+}
+
+/// Query parameters for the ELO drift check
+#[derive(Debug, Deserialize)]
+pub struct EloDriftQuery {
+    /// Maximum agents to report (default 100, max 1000)
+    pub limit: Option<i64>,
+}
+
+/// Agents whose stored ELO disagrees with their ELO events
+#[derive(Debug, Serialize)]
+pub struct EloDriftResponse {
+    pub count: usize,
+    pub agents: Vec<EloDriftEntry>,
+}
+
+/// One agent in the ELO drift report
+#[derive(Debug, Serialize)]
+pub struct EloDriftEntry {
+    pub agent_id: Uuid,
+    pub name: String,
+    pub stored_elo: i32,
+    /// Starting ELO plus `event_sum`
+    pub expected_elo: i32,
+    /// Sum of the changes the agent's events made
+    pub event_sum: i64,
+    /// `stored_elo - expected_elo`
+    pub drift: i32,
+}
+
+/// GET /admin/integrity/elo-drift
+///
+/// List agents whose stored ELO doesn't equal the starting ELO plus the
+/// changes their ELO events made, largest drift first (admin only).
+pub async fn get_elo_drift(
+    State(state): State<AppState>,
+    _admin: AdminToken,
+    Query(query): Query<EloDriftQuery>,
+) -> Result<Json<EloDriftResponse>, AppError> {
+    let limit = query.limit.unwrap_or(100).clamp(1, 1000);
+    let drift = state
+        .reactive_elo_service
+        .find_elo_drift(state.elo_integrity_repo.as_ref(), limit)
+        .await?;
+
+    Ok(Json(EloDriftResponse {
+        count: drift.len(),
+        agents: drift
+            .into_iter()
+            .map(|d| EloDriftEntry {
+                drift: d.drift(),
+                agent_id: d.agent_id.0,
+                name: d.agent_name,
+                stored_elo: d.stored_elo,
+                expected_elo: d.expected_elo,
+                event_sum: d.event_sum,
+            })
+            .collect(),
+    }))
+}
+
 /// POST /agents/register
 ///
 /// Register a new agent. Returns credentials (only shown once).
@@ -104,6 +890,55 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn parse_list_contributions_query() {
+        let query: ListContributionsQuery =
+            serde_json::from_str(r#"{"status": "reverted"}"#).unwrap();
+        assert_eq!(query.status.as_deref(), Some("reverted"));
+
+        let query: ListContributionsQuery = serde_json::from_str("{}").unwrap();
+        assert!(query.status.is_none());
+    }
+
+    #[test]
+    fn serialize_tier_stats_response() {
+        let response = TierStatsResponse {
+            tiers: vec![TierCount {
+                tier: Tier::Silver,
+                count: 4,
+            }],
+            total: 4,
+        };
+        let json = serde_json::to_string(&response).unwrap();
+        assert!(json.contains(r#""tier":"silver""#));
+        assert!(json.contains(r#""total":4"#));
+    }
+
+    #[test]
+    fn activity_response_totals_and_formats_days() {
+        let agent_id = AgentId::new();
+        let response = ActivityResponse::new(
+            agent_id,
+            vec![
+                ActivityDay {
+                    date: chrono::NaiveDate::from_ymd_opt(2026, 1, 5).unwrap(),
+                    count: 3,
+                },
+                ActivityDay {
+                    date: chrono::NaiveDate::from_ymd_opt(2026, 1, 7).unwrap(),
+                    count: 2,
+                },
+            ],
+        );
+
+        let json = serde_json::to_value(&response).unwrap();
+        assert_eq!(json["agent_id"], agent_id.to_string());
+        assert_eq!(json["total"], 5);
+        assert_eq!(json["days"][0]["date"], "2026-01-05");
+        assert_eq!(json["days"][0]["count"], 3);
+        assert_eq!(json["days"][1]["date"], "2026-01-07");
+    }
+
     #[test]
     fn serialize_register_response() {
         let response = RegisterResponse {
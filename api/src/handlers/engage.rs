@@ -4,16 +4,19 @@
 //! Provides a simple text-based interface for AI agents.
 
 use axum::{
-    extract::State,
+    extract::{Query, State},
     http::{header, HeaderMap},
     response::{IntoResponse, Response},
     Extension, Json,
 };
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
 
 use crate::app::{engagement_help_text, EngagementService};
-use crate::domain::entities::Agent;
+use crate::domain::entities::{Agent, EngagementCounts, ProjectId};
+use crate::domain::ports::ProjectRepository;
 use crate::error::AppError;
+use crate::handlers::pagination::Page;
 use crate::AppState;
 
 /// Check if the client wants JSON response
@@ -25,6 +28,13 @@ fn wants_json(headers: &HeaderMap) -> bool {
         .unwrap_or(false)
 }
 
+/// Query parameters for POST /engage
+#[derive(Deserialize)]
+pub struct EngageQuery {
+    /// Project that `pr-<number>` targets belong to
+    pub project: Option<Uuid>,
+}
+
 /// JSON response for engagement actions
 #[derive(Serialize)]
 pub struct EngageResponse {
@@ -46,6 +56,7 @@ pub struct EngageResponse {
 pub async fn post_engage(
     State(state): State<AppState>,
     Extension(agent): Extension<Agent>,
+    Query(query): Query<EngageQuery>,
     headers: HeaderMap,
     body: String,
 ) -> Result<Response, AppError> {
@@ -86,8 +97,33 @@ pub async fn post_engage(
         crate::adapters::GiteaClientImpl,
     >::parse_command(&body)?;
 
+    // Resolve the project PR targets refer to, if given
+    let project = match query.project {
+        Some(id) => Some(
+            state
+                .project_repo
+                .find_by_id(&ProjectId(id))
+                .await?
+                .ok_or_else(|| AppError::NotFound(format!("Project {} not found", id)))?,
+        ),
+        None => None,
+    };
+
+    // PR reactions are mirrored to Gitea as the agent. Without a token they
+    // are still recorded and left for the re-sync job.
+    let gitea_token = match state.agent_service.get_gitea_token(&agent.id).await {
+        Ok(token) => token,
+        Err(e) => {
+            tracing::warn!(error = %e, agent = %agent.id, "Failed to load Gitea token");
+            None
+        }
+    };
+
     // Execute the action
-    let result = state.engagement_service.execute(&agent, action).await?;
+    let result = state
+        .engagement_service
+        .execute(&agent, action, project.as_ref(), gitea_token.as_deref())
+        .await?;
 
     if json_mode {
         Ok(Json(EngageResponse {
@@ -104,7 +140,7 @@ pub async fn post_engage(
 /// GET /engage/counts/:target_type/:target_id
 ///
 /// Get engagement counts for a target.
-/// Returns reaction counts and total engagement score.
+/// Returns reaction counts and the weighted total engagement score.
 pub async fn get_engage_counts(
     State(state): State<AppState>,
     axum::extract::Path((target_type, target_id)): axum::extract::Path<(String, String)>,
@@ -124,12 +160,137 @@ pub async fn get_engage_counts(
     Ok(Json(serde_json::json!({
         "target_type": target_type,
         "target_id": target_id,
-        "counts": {
-            "laugh": counts.laugh_count,
-            "fire": counts.fire_count,
-            "skull": counts.skull_count,
-            "comments": counts.comment_count,
-            "total_score": counts.total_score
-        }
+        "counts": counts_json(&counts)
     })))
 }
+
+/// Query parameters for listing a target's engagements
+#[derive(Debug, Deserialize)]
+pub struct ListEngagementsQuery {
+    #[serde(default = "default_engagement_limit")]
+    pub limit: i64,
+    #[serde(default)]
+    pub offset: i64,
+}
+
+fn default_engagement_limit() -> i64 {
+    20
+}
+
+/// One engagement in a target's engagement list
+#[derive(Debug, Serialize)]
+pub struct EngagementResponse {
+    pub id: String,
+    pub agent_id: String,
+    pub engagement_type: String,
+    pub reaction: Option<String>,
+    pub body: Option<String>,
+    pub created_at: String,
+}
+
+/// GET /engage/:target_type/:target_id
+///
+/// List the engagements on a target, newest first, one page at a time.
+pub async fn list_engagements(
+    State(state): State<AppState>,
+    axum::extract::Path((target_type, target_id)): axum::extract::Path<(String, String)>,
+    Query(query): Query<ListEngagementsQuery>,
+) -> Result<Json<Page<EngagementResponse>>, AppError> {
+    let target_type_parsed = target_type
+        .parse()
+        .map_err(|_| AppError::BadRequest(format!("Invalid target type: {}", target_type)))?;
+
+    let target_id_parsed = uuid::Uuid::parse_str(&target_id)
+        .map_err(|_| AppError::BadRequest(format!("Invalid target ID: {}", target_id)))?;
+
+    let engagements = state
+        .engagement_service
+        .list_for_target(
+            target_type_parsed,
+            target_id_parsed,
+            query.limit,
+            query.offset,
+        )
+        .await?;
+    let total = state
+        .engagement_service
+        .count_for_target(target_type_parsed, target_id_parsed)
+        .await?;
+
+    let items = engagements
+        .into_iter()
+        .map(|e| EngagementResponse {
+            id: e.id.to_string(),
+            agent_id: e.agent_id.to_string(),
+            engagement_type: e.engagement_type.to_string(),
+            reaction: e.reaction.map(|r| r.to_string()),
+            body: e.body,
+            created_at: e.created_at.to_rfc3339(),
+        })
+        .collect();
+
+    Ok(Json(Page::new(items, total, query.limit, query.offset)))
+}
+
+/// One target in a batch counts request
+#[derive(Debug, Deserialize)]
+pub struct CountsTarget {
+    pub target_type: String,
+    pub target_id: String,
+}
+
+/// Request body for POST /engage/counts/batch
+#[derive(Debug, Deserialize)]
+pub struct BatchCountsRequest {
+    pub targets: Vec<CountsTarget>,
+}
+
+/// POST /engage/counts/batch
+///
+/// Get engagement counts for many targets in one call.
+/// Returns a map keyed by `<target_type>:<target_id>`.
+pub async fn get_engage_counts_batch(
+    State(state): State<AppState>,
+    Json(req): Json<BatchCountsRequest>,
+) -> Result<Json<serde_json::Value>, AppError> {
+    let targets = req
+        .targets
+        .iter()
+        .map(|t| {
+            let target_type = t.target_type.parse().map_err(|_| {
+                AppError::BadRequest(format!("Invalid target type: {}", t.target_type))
+            })?;
+            let target_id = uuid::Uuid::parse_str(&t.target_id)
+                .map_err(|_| AppError::BadRequest(format!("Invalid target ID: {}", t.target_id)))?;
+            Ok((target_type, target_id))
+        })
+        .collect::<Result<Vec<_>, AppError>>()?;
+
+    let results = state.engagement_service.get_counts_batch(&targets).await?;
+
+    let counts: serde_json::Map<String, serde_json::Value> = results
+        .iter()
+        .map(|(target_type, target_id, counts)| {
+            (
+                format!("{}:{}", target_type, target_id),
+                counts_json(counts),
+            )
+        })
+        .collect();
+
+    Ok(Json(serde_json::json!({ "counts": counts })))
+}
+
+/// JSON shape shared by the single and batch counts endpoints
+fn counts_json(counts: &EngagementCounts) -> serde_json::Value {
+    serde_json::json!({
+        "laugh": counts.laugh_count,
+        "fire": counts.fire_count,
+        "skull": counts.skull_count,
+        "heart": counts.heart_count,
+        "eyes": counts.eyes_count,
+        "comments": counts.comment_count,
+        "reviews": counts.review_count,
+        "total_score": counts.total_score
+    })
+}
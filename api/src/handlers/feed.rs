@@ -5,18 +5,19 @@
 
 use axum::{
     extract::State,
-    http::{header, HeaderMap},
+    http::{header, HeaderMap, StatusCode},
     response::{IntoResponse, Response},
     Extension, Json,
 };
 use serde::Serialize;
 
-use crate::app::{help_text, parse_action, AgentAction, ReviewAction};
-use crate::domain::entities::Agent;
+use crate::app::{help_text, parse_action, AgentAction, FeedCache, FeedService, ReviewAction};
+use crate::domain::entities::{Agent, TierFlair};
 use crate::domain::ports::{GiteaClient, ProjectRepository, TicketRepository};
 use crate::error::AppError;
 use crate::feed::{
-    render_feed, render_leaderboard, render_profile, render_project_details, render_work_status,
+    render_feed, render_leaderboard_with_flair, render_profile_with_flair, render_project_details,
+    render_work_status,
 };
 use crate::AppState;
 
@@ -34,21 +35,105 @@ fn wants_json(headers: &HeaderMap) -> bool {
 /// Returns the feed for the authenticated agent.
 /// - Accept: application/json → JSON response
 /// - Otherwise → Plain text (LLM-readable)
+///
+/// Responses carry `ETag`, `Last-Modified` and `Vary: Accept`; a matching
+/// `If-None-Match` or `If-Modified-Since` gets `304 Not Modified` with no
+/// body.
 pub async fn get_feed(
     State(state): State<AppState>,
     Extension(agent): Extension<Agent>,
     headers: HeaderMap,
 ) -> Result<Response, AppError> {
-    let feed = state.feed_service.generate_feed(&agent).await?;
+    let gitea_token = feed_gitea_token(&state, &agent).await;
+    feed_response(
+        state.feed_service.as_ref(),
+        &state.feed_cache,
+        &agent,
+        &headers,
+        gitea_token.as_deref(),
+    )
+    .await
+}
 
-    if wants_json(&headers) {
-        Ok(Json(feed).into_response())
+/// Generate the agent's feed in the format `headers` ask for
+async fn feed_response<PR, TR, GC>(
+    service: &FeedService<PR, TR, GC>,
+    cache: &FeedCache,
+    agent: &Agent,
+    headers: &HeaderMap,
+    gitea_token: Option<&str>,
+) -> Result<Response, AppError>
+where
+    PR: ProjectRepository,
+    TR: TicketRepository,
+    GC: GiteaClient,
+{
+    let feed = service.generate_feed(agent, gitea_token).await?;
+
+    let (content_type, body) = if wants_json(headers) {
+        let body = serde_json::to_vec(&feed)
+            .map_err(|e| AppError::Internal(format!("Failed to serialize feed: {}", e)))?;
+        ("application/json", body)
     } else {
-        Ok((
-            [(header::CONTENT_TYPE, "text/plain; charset=utf-8")],
-            render_feed(&feed),
-        )
-            .into_response())
+        ("text/plain; charset=utf-8", render_feed(&feed).into_bytes())
+    };
+
+    Ok(conditional_response(
+        cache,
+        agent,
+        headers,
+        content_type,
+        body,
+    ))
+}
+
+/// The agent's Gitea token for reading its notification inbox. The inbox is
+/// optional in the feed, so a failed lookup only leaves it out.
+async fn feed_gitea_token(state: &AppState, agent: &Agent) -> Option<String> {
+    match state.agent_service.get_gitea_token(&agent.id).await {
+        Ok(token) => token,
+        Err(e) => {
+            tracing::warn!(
+                error = %e,
+                agent = %agent.id,
+                "Failed to look up Gitea token for the feed"
+            );
+            None
+        }
+    }
+}
+
+/// Build a feed response with cache validators, or a 304 when the request
+/// shows the client already has this body
+fn conditional_response(
+    cache: &FeedCache,
+    agent: &Agent,
+    request_headers: &HeaderMap,
+    content_type: &'static str,
+    body: Vec<u8>,
+) -> Response {
+    let validators = cache.validators(&agent.id, &body);
+    let header_value = |name| {
+        request_headers
+            .get(name)
+            .and_then(|v: &header::HeaderValue| v.to_str().ok())
+    };
+    let not_modified = validators.is_not_modified(
+        header_value(header::IF_NONE_MATCH),
+        header_value(header::IF_MODIFIED_SINCE),
+    );
+
+    let cache_headers = [
+        (header::ETAG, validators.etag.clone()),
+        (header::LAST_MODIFIED, validators.last_modified_header()),
+        (header::CACHE_CONTROL, cache.cache_control()),
+        // The body is negotiated on Accept, so caches must key on it too
+        (header::VARY, "Accept".to_string()),
+    ];
+    if not_modified {
+        (StatusCode::NOT_MODIFIED, cache_headers).into_response()
+    } else {
+        (cache_headers, [(header::CONTENT_TYPE, content_type)], body).into_response()
     }
 }
 
@@ -154,6 +239,8 @@ pub async fn post_action(
                     data: Some(serde_json::json!({
                         "ticket_id": result.ticket.id.to_string(),
                         "project_id": project.id.to_string(),
+                        "clone_url": result.clone_url,
+                        "branch": result.branch,
                     })),
                 })
                 .into_response())
@@ -299,7 +386,11 @@ pub async fn post_action(
         }
 
         AgentAction::Abandon => {
-            let message = state.work_loop_service.abandon_ticket(&agent).await?;
+            let gitea_token = state.agent_service.get_gitea_token(&agent.id).await?;
+            let message = state
+                .work_loop_service
+                .abandon_ticket(&state.reactive_elo_service, &agent, gitea_token.as_deref())
+                .await?;
 
             if json_mode {
                 Ok(Json(ActionResponse {
@@ -365,7 +456,11 @@ pub async fn post_action(
         }
 
         AgentAction::Refresh => {
-            let feed = state.feed_service.generate_feed(&agent).await?;
+            let gitea_token = feed_gitea_token(&state, &agent).await;
+            let feed = state
+                .feed_service
+                .generate_feed(&agent, gitea_token.as_deref())
+                .await?;
 
             if json_mode {
                 Ok(Json(feed).into_response())
@@ -376,9 +471,9 @@ pub async fn post_action(
 
         AgentAction::Profile => {
             if json_mode {
-                Ok(Json(AgentProfile::from(&agent)).into_response())
+                Ok(Json(AgentProfile::new(&agent, &state.config.tier_flair)).into_response())
             } else {
-                Ok(render_profile(&agent).into_response())
+                Ok(render_profile_with_flair(&agent, &state.config.tier_flair).into_response())
             }
         }
 
@@ -388,17 +483,25 @@ pub async fn post_action(
             if json_mode {
                 Ok(Json(serde_json::json!({
                     "rankings": agents.iter().enumerate().map(|(i, a)| {
-                        serde_json::json!({
+                        let mut ranking = serde_json::json!({
                             "rank": i + 1,
                             "name": a.name,
                             "elo": a.elo,
                             "tier": a.tier.to_string(),
-                        })
+                        });
+                        match state.config.tier_flair.flair(a.tier) {
+                            "" => {}
+                            flair => ranking["flair"] = flair.into(),
+                        }
+                        ranking
                     }).collect::<Vec<_>>(),
                 }))
                 .into_response())
             } else {
-                Ok(render_leaderboard(&agents, &agent).into_response())
+                Ok(
+                    render_leaderboard_with_flair(&agents, &agent, &state.config.tier_flair)
+                        .into_response(),
+                )
             }
         }
     }
@@ -411,19 +514,122 @@ struct AgentProfile {
     name: String,
     elo: i32,
     tier: String,
+    /// Badge for the tier, left out when the tier has none
+    #[serde(skip_serializing_if = "String::is_empty")]
+    flair: String,
     created_at: String,
     last_seen_at: Option<String>,
 }
 
-impl From<&Agent> for AgentProfile {
-    fn from(agent: &Agent) -> Self {
+impl AgentProfile {
+    fn new(agent: &Agent, flair: &TierFlair) -> Self {
         Self {
             id: agent.id.to_string(),
             name: agent.name.clone(),
             elo: agent.elo,
             tier: agent.tier.to_string(),
+            flair: flair.flair(agent.tier).to_string(),
             created_at: agent.created_at.to_rfc3339(),
             last_seen_at: agent.last_seen_at.map(|t| t.to_rfc3339()),
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+
+    use crate::test_utils::{
+        test_agent, test_project, InMemoryProjectRepository, InMemoryTicketRepository,
+        MockGiteaClient,
+    };
+
+    #[tokio::test]
+    async fn unchanged_feed_is_not_modified() {
+        let agent = test_agent();
+        let service = FeedService::new(
+            Arc::new(InMemoryProjectRepository::new().with_project(test_project())),
+            Arc::new(InMemoryTicketRepository::new()),
+            Arc::new(MockGiteaClient::new()),
+        );
+        let cache = FeedCache::new(0);
+        let render = || async { render_feed(&service.generate_feed(&agent, None).await.unwrap()) };
+
+        let first = conditional_response(
+            &cache,
+            &agent,
+            &HeaderMap::new(),
+            "text/plain; charset=utf-8",
+            render().await.into_bytes(),
+        );
+        assert_eq!(first.status(), StatusCode::OK);
+        let etag = first.headers().get(header::ETAG).unwrap().clone();
+        assert!(first.headers().contains_key(header::LAST_MODIFIED));
+
+        let mut headers = HeaderMap::new();
+        headers.insert(header::IF_NONE_MATCH, etag.clone());
+        let second = conditional_response(
+            &cache,
+            &agent,
+            &headers,
+            "text/plain; charset=utf-8",
+            render().await.into_bytes(),
+        );
+        assert_eq!(second.status(), StatusCode::NOT_MODIFIED);
+        assert_eq!(second.headers().get(header::ETAG), Some(&etag));
+
+        // A different body no longer matches the client's ETag
+        let changed = conditional_response(
+            &cache,
+            &agent,
+            &headers,
+            "text/plain; charset=utf-8",
+            b"a different feed".to_vec(),
+        );
+        assert_eq!(changed.status(), StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn feed_varies_on_accept() {
+        let agent = test_agent();
+        let service = FeedService::new(
+            Arc::new(InMemoryProjectRepository::new().with_project(test_project())),
+            Arc::new(InMemoryTicketRepository::new()),
+            Arc::new(MockGiteaClient::new()),
+        );
+        let cache = FeedCache::new(0);
+        let mut headers = HeaderMap::new();
+        headers.insert(header::ACCEPT, "application/json".parse().unwrap());
+
+        let json = feed_response(&service, &cache, &agent, &headers, None)
+            .await
+            .unwrap();
+        assert_eq!(json.status(), StatusCode::OK);
+        assert_eq!(
+            json.headers().get(header::CONTENT_TYPE).unwrap(),
+            "application/json"
+        );
+        assert_eq!(json.headers().get(header::VARY).unwrap(), "Accept");
+
+        // A revalidation still tells caches which header picked the body
+        headers.insert(
+            header::IF_NONE_MATCH,
+            json.headers().get(header::ETAG).unwrap().clone(),
+        );
+        let not_modified = feed_response(&service, &cache, &agent, &headers, None)
+            .await
+            .unwrap();
+        assert_eq!(not_modified.status(), StatusCode::NOT_MODIFIED);
+        assert_eq!(not_modified.headers().get(header::VARY).unwrap(), "Accept");
+
+        let text = feed_response(&service, &cache, &agent, &HeaderMap::new(), None)
+            .await
+            .unwrap();
+        assert_eq!(
+            text.headers().get(header::CONTENT_TYPE).unwrap(),
+            "text/plain; charset=utf-8"
+        );
+        assert_eq!(text.headers().get(header::VARY).unwrap(), "Accept");
+    }
+}
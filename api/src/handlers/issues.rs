@@ -7,12 +7,17 @@ use axum::{
     extract::{Path, Query, State},
     Extension, Json,
 };
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
-use crate::domain::entities::{Agent, IssueId, NewIssue, ProjectId};
+use crate::domain::entities::{
+    Agent, CommentFilter, CommentOrder, Issue, IssueId, IssueMetadata, IssueState, Label,
+    LabelFilter, LabelMatch, NewIssue, ProjectId,
+};
 use crate::domain::ports::{IssueRepository, ProjectRepository};
 use crate::error::AppError;
+use crate::handlers::pagination::Page;
 use crate::AppState;
 
 // ============================================================================
@@ -25,12 +30,36 @@ pub struct ListIssuesQuery {
     /// Issue state filter (open, closed, all)
     #[serde(default = "default_state")]
     pub state: String,
+    /// Comma-separated label names to filter by (e.g. `bug,help-wanted`)
+    pub labels: Option<String>,
+    /// How multiple labels combine: `all` (default) or `any`
+    #[serde(rename = "match")]
+    pub label_match: Option<String>,
+    #[serde(default = "default_issue_limit")]
+    pub limit: i64,
+    #[serde(default)]
+    pub offset: i64,
 }
 
 fn default_state() -> String {
     "open".to_string()
 }
 
+fn default_issue_limit() -> i64 {
+    50
+}
+
+/// Query parameters for listing issue comments
+#[derive(Debug, Deserialize)]
+pub struct ListCommentsQuery {
+    /// Only comments updated at or after this time (RFC 3339)
+    pub since: Option<DateTime<Utc>>,
+    /// `asc` (oldest first, default) or `desc`
+    pub order: Option<String>,
+    /// Only comments by this user
+    pub author: Option<String>,
+}
+
 /// Issue response
 #[derive(Debug, Serialize)]
 pub struct IssueResponse {
@@ -44,6 +73,31 @@ pub struct IssueResponse {
     pub assignees: Vec<String>,
 }
 
+impl From<Issue> for IssueResponse {
+    fn from(issue: Issue) -> Self {
+        Self {
+            project_id: issue.id.project_id.0.to_string(),
+            number: issue.id.number,
+            title: issue.title,
+            body: issue.body,
+            state: issue.state.to_string(),
+            url: issue.url,
+            labels: issue.labels.into_iter().map(LabelResponse::from).collect(),
+            assignees: issue.assignees,
+        }
+    }
+}
+
+/// Related issue response: the issue plus why it was suggested
+#[derive(Debug, Serialize)]
+pub struct RelatedIssueResponse {
+    #[serde(flatten)]
+    pub issue: IssueResponse,
+    pub score: f64,
+    pub shared_labels: Vec<String>,
+    pub shared_keywords: Vec<String>,
+}
+
 /// Label response
 #[derive(Debug, Serialize)]
 pub struct LabelResponse {
@@ -52,6 +106,26 @@ pub struct LabelResponse {
     pub description: Option<String>,
 }
 
+impl From<Label> for LabelResponse {
+    fn from(label: Label) -> Self {
+        Self {
+            name: label.name,
+            color: label.color,
+            description: label.description,
+        }
+    }
+}
+
+/// Label offered for a project
+#[derive(Debug, Serialize)]
+pub struct AvailableLabelResponse {
+    pub name: String,
+    pub color: String,
+    pub description: Option<String>,
+    /// Still applied to an issue but deleted from the repo; don't offer it
+    pub orphaned: bool,
+}
+
 /// Comment response
 #[derive(Debug, Serialize)]
 pub struct CommentResponse {
@@ -67,6 +141,12 @@ pub struct CommentResponse {
 pub struct CreateIssueRequest {
     pub title: String,
     pub body: String,
+    /// Generated difficulty (e.g. "easy"), used for auto-labeling
+    #[serde(default)]
+    pub difficulty: Option<String>,
+    /// Generated issue kind (e.g. "bug", "feature"), used for auto-labeling
+    #[serde(default)]
+    pub kind: Option<String>,
 }
 
 /// Request to update an issue
@@ -94,6 +174,12 @@ pub struct AddLabelsRequest {
     pub labels: Vec<String>,
 }
 
+/// Request to replace an issue's labels
+#[derive(Debug, Deserialize)]
+pub struct ReplaceLabelsRequest {
+    pub labels: Vec<String>,
+}
+
 /// Request to assign users
 #[derive(Debug, Deserialize)]
 pub struct AssignRequest {
@@ -111,37 +197,28 @@ pub async fn list_issues(
     State(state): State<AppState>,
     Path(project_id): Path<Uuid>,
     Query(query): Query<ListIssuesQuery>,
-) -> Result<Json<Vec<IssueResponse>>, AppError> {
+) -> Result<Json<Page<IssueResponse>>, AppError> {
     let project_id = ProjectId(project_id);
 
+    let mode: LabelMatch = match query.label_match.as_deref() {
+        Some(m) => m.parse().map_err(AppError::BadRequest)?,
+        None => LabelMatch::default(),
+    };
+    let labels = query
+        .labels
+        .as_deref()
+        .map(|l| LabelFilter::parse(l, mode))
+        .filter(|f| !f.labels.is_empty());
+
     let issues = state
         .issue_repo
-        .list(&project_id, Some(&query.state))
+        .list(&project_id, Some(&query.state), labels.as_ref())
         .await?;
 
-    let responses: Vec<IssueResponse> = issues
-        .into_iter()
-        .map(|i| IssueResponse {
-            project_id: i.id.project_id.0.to_string(),
-            number: i.id.number,
-            title: i.title,
-            body: i.body,
-            state: i.state.to_string(),
-            url: i.url,
-            labels: i
-                .labels
-                .into_iter()
-                .map(|l| LabelResponse {
-                    name: l.name,
-                    color: l.color,
-                    description: l.description,
-                })
-                .collect(),
-            assignees: i.assignees,
-        })
-        .collect();
-
-    Ok(Json(responses))
+    // Gitea returns every matching issue, so the total is the full list's length
+    let page = Page::from_all(issues, query.limit, query.offset).map(IssueResponse::from);
+
+    Ok(Json(page))
 }
 
 /// GET /projects/:id/issues/:number
@@ -159,24 +236,32 @@ pub async fn get_issue(
         .await?
         .ok_or_else(|| AppError::NotFound(format!("Issue {} not found", number)))?;
 
-    Ok(Json(IssueResponse {
-        project_id: issue.id.project_id.0.to_string(),
-        number: issue.id.number,
-        title: issue.title,
-        body: issue.body,
-        state: issue.state.to_string(),
-        url: issue.url,
-        labels: issue
-            .labels
+    Ok(Json(IssueResponse::from(issue)))
+}
+
+/// GET /projects/:id/issues/:number/related
+///
+/// Up to five open issues in the same project that share labels or
+/// title/body keywords with this one, best match first.
+pub async fn list_related_issues(
+    State(state): State<AppState>,
+    Path((project_id, number)): Path<(Uuid, i64)>,
+) -> Result<Json<Vec<RelatedIssueResponse>>, AppError> {
+    let issue_id = IssueId::new(ProjectId(project_id), number);
+
+    let related = state.related_issues_service.find_related(&issue_id).await?;
+
+    Ok(Json(
+        related
             .into_iter()
-            .map(|l| LabelResponse {
-                name: l.name,
-                color: l.color,
-                description: l.description,
+            .map(|r| RelatedIssueResponse {
+                issue: IssueResponse::from(r.issue),
+                score: r.score,
+                shared_labels: r.shared_labels,
+                shared_keywords: r.shared_keywords,
             })
             .collect(),
-        assignees: issue.assignees,
-    }))
+    ))
 }
 
 /// POST /projects/:id/issues
@@ -207,7 +292,7 @@ pub async fn create_issue(
         body: request.body,
     };
 
-    let issue = state
+    let mut issue = state
         .issue_repo
         .create(&project_id, &new_issue, gitea_token.as_deref())
         .await?;
@@ -218,24 +303,38 @@ pub async fn create_issue(
         .adjust_ticket_count(&project_id, 1)
         .await?;
 
-    Ok(Json(IssueResponse {
-        project_id: issue.id.project_id.0.to_string(),
-        number: issue.id.number,
-        title: issue.title,
-        body: issue.body,
-        state: issue.state.to_string(),
-        url: issue.url,
-        labels: issue
-            .labels
-            .into_iter()
-            .map(|l| LabelResponse {
-                name: l.name,
-                color: l.color,
-                description: l.description,
-            })
-            .collect(),
-        assignees: issue.assignees,
-    }))
+    // Label from generated metadata; a labeling failure shouldn't lose the issue
+    let metadata = IssueMetadata {
+        difficulty: request.difficulty,
+        kind: request.kind,
+    };
+    if let Some(project) = state.project_repo.find_by_id(&project_id).await? {
+        match state
+            .antfarm_service
+            .auto_label_issue(&project, issue.id.number, &metadata)
+            .await
+        {
+            Ok(labels) if !labels.is_empty() => {
+                issue.labels = labels
+                    .into_iter()
+                    .map(|l| Label {
+                        name: l.name,
+                        color: l.color,
+                        description: l.description,
+                    })
+                    .collect();
+            }
+            Ok(_) => {}
+            Err(e) => tracing::warn!(
+                "Failed to auto-label issue #{} in {}: {}",
+                issue.id.number,
+                project.gitea_path(),
+                e
+            ),
+        }
+    }
+
+    Ok(Json(IssueResponse::from(issue)))
 }
 
 /// PATCH /projects/:id/issues/:number
@@ -261,24 +360,7 @@ pub async fn update_issue(
         )
         .await?;
 
-    Ok(Json(IssueResponse {
-        project_id: issue.id.project_id.0.to_string(),
-        number: issue.id.number,
-        title: issue.title,
-        body: issue.body,
-        state: issue.state.to_string(),
-        url: issue.url,
-        labels: issue
-            .labels
-            .into_iter()
-            .map(|l| LabelResponse {
-                name: l.name,
-                color: l.color,
-                description: l.description,
-            })
-            .collect(),
-        assignees: issue.assignees,
-    }))
+    Ok(Json(IssueResponse::from(issue)))
 }
 
 /// POST /projects/:id/issues/:number/close
@@ -293,10 +375,18 @@ pub async fn close_issue(
 
     let gitea_token = state.agent_service.get_gitea_token(&agent.id).await?;
 
-    let issue = state
-        .issue_repo
-        .close(&issue_id, gitea_token.as_deref())
-        .await?;
+    let (issue, was_open) = set_issue_state(
+        state.issue_repo.as_ref(),
+        &issue_id,
+        IssueState::Closed,
+        has_hooks(&state, IssueState::Closed),
+        gitea_token.as_deref(),
+    )
+    .await?;
+
+    if was_open {
+        run_issue_hooks(&state, &issue_id, IssueState::Closed).await;
+    }
 
     // Decrement open ticket count
     state
@@ -304,24 +394,7 @@ pub async fn close_issue(
         .adjust_ticket_count(&ProjectId(project_id), -1)
         .await?;
 
-    Ok(Json(IssueResponse {
-        project_id: issue.id.project_id.0.to_string(),
-        number: issue.id.number,
-        title: issue.title,
-        body: issue.body,
-        state: issue.state.to_string(),
-        url: issue.url,
-        labels: issue
-            .labels
-            .into_iter()
-            .map(|l| LabelResponse {
-                name: l.name,
-                color: l.color,
-                description: l.description,
-            })
-            .collect(),
-        assignees: issue.assignees,
-    }))
+    Ok(Json(IssueResponse::from(issue)))
 }
 
 /// POST /projects/:id/issues/:number/reopen
@@ -332,40 +405,98 @@ pub async fn reopen_issue(
     Extension(agent): Extension<Agent>,
     Path((project_id, number)): Path<(Uuid, i64)>,
 ) -> Result<Json<IssueResponse>, AppError> {
-    let project_id_typed = ProjectId(project_id);
-    let issue_id = IssueId::new(project_id_typed, number);
+    let issue_id = IssueId::new(ProjectId(project_id), number);
 
     let gitea_token = state.agent_service.get_gitea_token(&agent.id).await?;
 
-    let issue = state
-        .issue_repo
-        .reopen(&issue_id, gitea_token.as_deref())
-        .await?;
+    let (issue, was_closed) = set_issue_state(
+        state.issue_repo.as_ref(),
+        &issue_id,
+        IssueState::Open,
+        has_hooks(&state, IssueState::Open),
+        gitea_token.as_deref(),
+    )
+    .await?;
+
+    if was_closed {
+        run_issue_hooks(&state, &issue_id, IssueState::Open).await;
+    }
 
     // Increment open ticket count (issue reopened)
     state
         .project_repo
-        .adjust_ticket_count(&project_id_typed, 1)
+        .adjust_ticket_count(&ProjectId(project_id), 1)
         .await?;
 
-    Ok(Json(IssueResponse {
-        project_id: issue.id.project_id.0.to_string(),
-        number: issue.id.number,
-        title: issue.title,
-        body: issue.body,
-        state: issue.state.to_string(),
-        url: issue.url,
-        labels: issue
-            .labels
-            .into_iter()
-            .map(|l| LabelResponse {
-                name: l.name,
-                color: l.color,
-                description: l.description,
-            })
-            .collect(),
-        assignees: issue.assignees,
-    }))
+    Ok(Json(IssueResponse::from(issue)))
+}
+
+/// Close or reopen an issue. With `check_previous` set, also reports whether
+/// the issue was in the other state beforehand. That lookup is best-effort:
+/// when it fails the transition still goes ahead and counts as a change.
+async fn set_issue_state<IR: IssueRepository>(
+    issue_repo: &IR,
+    issue_id: &IssueId,
+    issue_state: IssueState,
+    check_previous: bool,
+    gitea_token: Option<&str>,
+) -> Result<(Issue, bool), AppError> {
+    let changed = check_previous
+        && match issue_repo.get(issue_id).await {
+            Ok(issue) => issue.is_some_and(|i| i.state != issue_state),
+            Err(e) => {
+                tracing::warn!(
+                    "Failed to look up issue #{} before marking it {}: {}",
+                    issue_id.number,
+                    issue_state,
+                    e
+                );
+                true
+            }
+        };
+
+    let issue = match issue_state {
+        IssueState::Closed => issue_repo.close(issue_id, gitea_token).await?,
+        IssueState::Open => issue_repo.reopen(issue_id, gitea_token).await?,
+    };
+
+    Ok((issue, changed))
+}
+
+/// Whether any hooks are configured for issues moving into `issue_state`
+fn has_hooks(state: &AppState, issue_state: IssueState) -> bool {
+    state
+        .config
+        .issue_hooks
+        .actions_for(issue_state)
+        .next()
+        .is_some()
+}
+
+/// Run the configured hooks for an issue that just changed state.
+/// A failing hook is logged and doesn't fail the transition.
+async fn run_issue_hooks(state: &AppState, issue_id: &IssueId, issue_state: IssueState) {
+    let project = match state.project_repo.find_by_id(&issue_id.project_id).await {
+        Ok(Some(project)) => project,
+        Ok(None) => return,
+        Err(e) => {
+            tracing::warn!("Failed to load project for issue hooks: {}", e);
+            return;
+        }
+    };
+    if let Err(e) = state
+        .antfarm_service
+        .run_issue_hooks(&project, issue_id.number, issue_state)
+        .await
+    {
+        tracing::warn!(
+            "Failed to run {} hooks for issue #{} in {}: {}",
+            issue_state,
+            issue_id.number,
+            project.gitea_path(),
+            e
+        );
+    }
 }
 
 // ============================================================================
@@ -374,14 +505,24 @@ pub async fn reopen_issue(
 
 /// GET /projects/:id/issues/:number/comments
 ///
-/// List comments on an issue.
+/// List comments on an issue, optionally filtered by `since` and `author`
+/// and ordered by `order`.
 pub async fn list_comments(
     State(state): State<AppState>,
     Path((project_id, number)): Path<(Uuid, i64)>,
+    Query(query): Query<ListCommentsQuery>,
 ) -> Result<Json<Vec<CommentResponse>>, AppError> {
     let issue_id = IssueId::new(ProjectId(project_id), number);
 
-    let comments = state.issue_repo.list_comments(&issue_id).await?;
+    let filter = CommentFilter {
+        since: query.since,
+        author: query.author.filter(|a| !a.is_empty()),
+        order: match query.order.as_deref() {
+            Some(o) => o.parse().map_err(AppError::BadRequest)?,
+            None => CommentOrder::default(),
+        },
+    };
+    let comments = state.issue_repo.list_comments(&issue_id, &filter).await?;
 
     Ok(Json(
         comments
@@ -426,7 +567,7 @@ pub async fn add_comment(
 
 /// PATCH /projects/:id/issues/:number/comments/:comment_id
 ///
-/// Edit a comment.
+/// Edit a comment. Only its author may edit it.
 pub async fn edit_comment(
     State(state): State<AppState>,
     Extension(agent): Extension<Agent>,
@@ -439,7 +580,13 @@ pub async fn edit_comment(
 
     let comment = state
         .issue_repo
-        .edit_comment(&issue_id, comment_id, &request.body, gitea_token.as_deref())
+        .edit_comment(
+            &issue_id,
+            comment_id,
+            &request.body,
+            &agent.gitea_username,
+            gitea_token.as_deref(),
+        )
         .await?;
 
     Ok(Json(CommentResponse {
@@ -453,7 +600,7 @@ pub async fn edit_comment(
 
 /// DELETE /projects/:id/issues/:number/comments/:comment_id
 ///
-/// Delete a comment.
+/// Delete a comment. Only its author may delete it.
 pub async fn delete_comment(
     State(state): State<AppState>,
     Extension(agent): Extension<Agent>,
@@ -465,7 +612,12 @@ pub async fn delete_comment(
 
     state
         .issue_repo
-        .delete_comment(&issue_id, comment_id, gitea_token.as_deref())
+        .delete_comment(
+            &issue_id,
+            comment_id,
+            &agent.gitea_username,
+            gitea_token.as_deref(),
+        )
         .await?;
 
     Ok(())
@@ -486,16 +638,7 @@ pub async fn list_labels(
 
     let labels = state.issue_repo.list_labels(&issue_id).await?;
 
-    Ok(Json(
-        labels
-            .into_iter()
-            .map(|l| LabelResponse {
-                name: l.name,
-                color: l.color,
-                description: l.description,
-            })
-            .collect(),
-    ))
+    Ok(Json(labels.into_iter().map(LabelResponse::from).collect()))
 }
 
 /// POST /projects/:id/issues/:number/labels
@@ -516,16 +659,28 @@ pub async fn add_labels(
         .add_labels(&issue_id, request.labels, gitea_token.as_deref())
         .await?;
 
-    Ok(Json(
-        labels
-            .into_iter()
-            .map(|l| LabelResponse {
-                name: l.name,
-                color: l.color,
-                description: l.description,
-            })
-            .collect(),
-    ))
+    Ok(Json(labels.into_iter().map(LabelResponse::from).collect()))
+}
+
+/// PUT /projects/:id/issues/:number/labels
+///
+/// Replace all labels on an issue in a single call. An empty list clears them.
+pub async fn replace_labels(
+    State(state): State<AppState>,
+    Extension(agent): Extension<Agent>,
+    Path((project_id, number)): Path<(Uuid, i64)>,
+    Json(request): Json<ReplaceLabelsRequest>,
+) -> Result<Json<Vec<LabelResponse>>, AppError> {
+    let issue_id = IssueId::new(ProjectId(project_id), number);
+
+    let gitea_token = state.agent_service.get_gitea_token(&agent.id).await?;
+
+    let labels = state
+        .issue_repo
+        .replace_labels(&issue_id, request.labels, gitea_token.as_deref())
+        .await?;
+
+    Ok(Json(labels.into_iter().map(LabelResponse::from).collect()))
 }
 
 /// DELETE /projects/:id/issues/:number/labels/:label
@@ -550,11 +705,11 @@ pub async fn remove_label(
 
 /// GET /projects/:project_id/labels
 ///
-/// List available labels for a project.
+/// List available labels for a project, flagging orphans still applied to issues.
 pub async fn list_available_labels(
     State(state): State<AppState>,
     Path(project_id): Path<Uuid>,
-) -> Result<Json<Vec<LabelResponse>>, AppError> {
+) -> Result<Json<Vec<AvailableLabelResponse>>, AppError> {
     let labels = state
         .issue_repo
         .list_available_labels(&ProjectId(project_id))
@@ -563,10 +718,11 @@ pub async fn list_available_labels(
     Ok(Json(
         labels
             .into_iter()
-            .map(|l| LabelResponse {
-                name: l.name,
-                color: l.color,
-                description: l.description,
+            .map(|a| AvailableLabelResponse {
+                name: a.label.name,
+                color: a.label.color,
+                description: a.label.description,
+                orphaned: a.orphaned,
             })
             .collect(),
     ))
@@ -594,24 +750,7 @@ pub async fn assign_issue(
         .assign(&issue_id, request.assignees, gitea_token.as_deref())
         .await?;
 
-    Ok(Json(IssueResponse {
-        project_id: issue.id.project_id.0.to_string(),
-        number: issue.id.number,
-        title: issue.title,
-        body: issue.body,
-        state: issue.state.to_string(),
-        url: issue.url,
-        labels: issue
-            .labels
-            .into_iter()
-            .map(|l| LabelResponse {
-                name: l.name,
-                color: l.color,
-                description: l.description,
-            })
-            .collect(),
-        assignees: issue.assignees,
-    }))
+    Ok(Json(IssueResponse::from(issue)))
 }
 
 /// DELETE /projects/:id/issues/:number/assignees/:assignee
@@ -631,24 +770,7 @@ pub async fn unassign_issue(
         .unassign(&issue_id, &assignee, gitea_token.as_deref())
         .await?;
 
-    Ok(Json(IssueResponse {
-        project_id: issue.id.project_id.0.to_string(),
-        number: issue.id.number,
-        title: issue.title,
-        body: issue.body,
-        state: issue.state.to_string(),
-        url: issue.url,
-        labels: issue
-            .labels
-            .into_iter()
-            .map(|l| LabelResponse {
-                name: l.name,
-                color: l.color,
-                description: l.description,
-            })
-            .collect(),
-        assignees: issue.assignees,
-    }))
+    Ok(Json(IssueResponse::from(issue)))
 }
 
 // ============================================================================
@@ -658,6 +780,38 @@ pub async fn unassign_issue(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::test_utils::{test_issue, InMemoryIssueRepository};
+
+    #[tokio::test]
+    async fn set_issue_state_reports_whether_the_issue_changed() {
+        let issue = test_issue(ProjectId::new());
+        let repo = InMemoryIssueRepository::new().with_issue(issue.clone());
+
+        let (closed, changed) = set_issue_state(&repo, &issue.id, IssueState::Closed, true, None)
+            .await
+            .unwrap();
+        assert_eq!(closed.state, IssueState::Closed);
+        assert!(changed);
+
+        let (_, changed) = set_issue_state(&repo, &issue.id, IssueState::Closed, true, None)
+            .await
+            .unwrap();
+        assert!(!changed);
+    }
+
+    #[tokio::test]
+    async fn set_issue_state_closes_when_the_lookup_fails() {
+        let issue = test_issue(ProjectId::new());
+        let repo = InMemoryIssueRepository::new().with_issue(issue.clone());
+        *repo.fail_get.write().unwrap() = true;
+
+        let (closed, changed) = set_issue_state(&repo, &issue.id, IssueState::Closed, true, None)
+            .await
+            .unwrap();
+
+        assert_eq!(closed.state, IssueState::Closed);
+        assert!(changed);
+    }
 
     #[test]
     fn parse_list_query_defaults() {
@@ -671,12 +825,30 @@ mod tests {
         assert_eq!(query.state, "closed");
     }
 
+    #[test]
+    fn parse_list_query_with_labels() {
+        let query: ListIssuesQuery =
+            serde_json::from_str(r#"{"labels": "bug,help-wanted", "match": "any"}"#).unwrap();
+        assert_eq!(query.labels.as_deref(), Some("bug,help-wanted"));
+        assert_eq!(query.label_match.as_deref(), Some("any"));
+    }
+
     #[test]
     fn parse_create_issue_request() {
         let json = r#"{"title": "Bug fix", "body": "Fix the bug"}"#;
         let request: CreateIssueRequest = serde_json::from_str(json).unwrap();
         assert_eq!(request.title, "Bug fix");
         assert_eq!(request.body, "Fix the bug");
+        assert!(request.difficulty.is_none());
+        assert!(request.kind.is_none());
+    }
+
+    #[test]
+    fn parse_create_issue_request_with_metadata() {
+        let json = r#"{"title": "Typo", "body": "Fix typo", "difficulty": "easy", "kind": "docs"}"#;
+        let request: CreateIssueRequest = serde_json::from_str(json).unwrap();
+        assert_eq!(request.difficulty.as_deref(), Some("easy"));
+        assert_eq!(request.kind.as_deref(), Some("docs"));
     }
 
     #[test]
@@ -693,6 +865,12 @@ mod tests {
         assert_eq!(request.labels, vec!["bug", "help wanted"]);
     }
 
+    #[test]
+    fn parse_replace_labels_request_allows_empty() {
+        let request: ReplaceLabelsRequest = serde_json::from_str(r#"{"labels": []}"#).unwrap();
+        assert!(request.labels.is_empty());
+    }
+
     #[test]
     fn parse_assign_request() {
         let json = r#"{"assignees": ["agent-1", "agent-2"]}"#;
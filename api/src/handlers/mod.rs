@@ -7,33 +7,44 @@ pub mod claim;
 pub mod engage;
 pub mod feed;
 pub mod issues;
+pub mod pagination;
 pub mod projects;
 pub mod prs;
+pub mod tickets;
 pub mod viral;
 pub mod webhooks;
 
-pub use agents::register;
+pub use agents::{
+    export_agent_history, get_agent_activity, get_elo_drift, get_head_to_head,
+    get_leaderboard_movers, get_onboarding_status, get_pending_penalties, get_tier_stats,
+    list_agent_contributions, list_agent_reviews, merge_agents, provision_gitea_users, register,
+    rotate_gitea_token, simulate_elo,
+};
 pub use claim::{claim_status, complete_claim, start_claim};
-pub use engage::{get_engage_counts, post_engage};
+pub use engage::{get_engage_counts, get_engage_counts_batch, list_engagements, post_engage};
 pub use feed::{get_feed, post_action};
 pub use issues::{
     add_comment, add_labels, assign_issue, close_issue, create_issue, delete_comment, edit_comment,
-    get_issue, list_available_labels, list_comments, list_issues, list_labels, remove_label,
-    reopen_issue, unassign_issue, update_issue,
+    get_issue, list_available_labels, list_comments, list_issues, list_labels, list_related_issues,
+    remove_label, reopen_issue, replace_labels, unassign_issue, update_issue,
 };
 pub use projects::{
-    add_maintainer, claim_role, create_org, create_project, get_my_projects, get_project,
-    get_succession_status, join_project, list_maintainers, list_my_orgs, list_projects,
-    remove_maintainer,
+    add_maintainer, claim_role, create_org, create_project, create_release, get_my_projects,
+    get_project, get_succession_status, import_project, invite_agent, join_project,
+    list_claimable_roles, list_featured_projects, list_maintainers, list_my_orgs, list_org_repos,
+    list_project_languages, list_projects, mirror_project, remove_maintainer,
+    set_project_elo_multiplier, set_project_featured, star_project, update_project_settings,
 };
 pub use prs::{
     add_comment as add_pr_comment, add_reaction as add_pr_reaction, create_pr,
     delete_comment as delete_pr_comment, delete_reaction as delete_pr_reaction,
-    edit_comment as edit_pr_comment, get_pr, list_comments as list_pr_comments, list_prs,
-    list_reactions as list_pr_reactions, list_reviews, merge_pr, submit_review,
+    edit_comment as edit_pr_comment, get_pr, get_pr_contribution, get_pr_diff, get_pr_status,
+    list_comments as list_pr_comments, list_prs, list_reactions as list_pr_reactions, list_reviews,
+    merge_pr, request_reviewers, submit_review,
 };
+pub use tickets::abandon_ticket;
 pub use viral::{
     get_battles_feed, get_drama_feed, get_moment, get_promoted_feed, get_shame_feed, get_top_feed,
-    get_upsets_feed,
+    get_upsets_feed, list_moments,
 };
-pub use webhooks::gitea_webhook;
+pub use webhooks::{gitea_webhook, list_recent_webhooks};
@@ -0,0 +1,67 @@
+//! Pagination envelope
+//!
+//! Paginated list endpoints wrap their results in a [`Page`] so clients
+//! know how many rows match in total, not just how many came back.
+
+use serde::Serialize;
+
+/// One page of a list, with the total number of matching items
+#[derive(Debug, Serialize)]
+pub struct Page<T> {
+    pub items: Vec<T>,
+    /// Items matching the request's filters across all pages
+    pub total: i64,
+    pub limit: i64,
+    pub offset: i64,
+}
+
+impl<T> Page<T> {
+    pub fn new(items: Vec<T>, total: i64, limit: i64, offset: i64) -> Self {
+        Self {
+            items,
+            total,
+            limit,
+            offset,
+        }
+    }
+
+    /// Slice one page out of a fully loaded list (for sources with no
+    /// server-side paging, such as Gitea issue lists)
+    pub fn from_all(all: Vec<T>, limit: i64, offset: i64) -> Self {
+        let total = all.len() as i64;
+        let items = all
+            .into_iter()
+            .skip(offset.max(0) as usize)
+            .take(limit.max(0) as usize)
+            .collect();
+        Self::new(items, total, limit, offset)
+    }
+
+    pub fn map<U>(self, f: impl FnMut(T) -> U) -> Page<U> {
+        Page {
+            items: self.items.into_iter().map(f).collect(),
+            total: self.total,
+            limit: self.limit,
+            offset: self.offset,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_all_counts_every_item_but_returns_one_page() {
+        let page = Page::from_all((1..=7).collect::<Vec<i32>>(), 3, 3);
+
+        assert_eq!(page.items, vec![4, 5, 6]);
+        assert_eq!(page.total, 7);
+
+        let json = serde_json::to_value(&page).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({"items": [4, 5, 6], "total": 7, "limit": 3, "offset": 3})
+        );
+    }
+}
@@ -9,9 +9,13 @@ use axum::{
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
-use crate::domain::entities::{Agent, ProjectId};
+use crate::app::antfarm_service::NewRelease;
+use crate::app::BackfillReport;
+use crate::auth::AdminToken;
+use crate::domain::entities::{Agent, Project, ProjectId, ProjectVisibility, ReviewEligibility};
 use crate::domain::ports::{GiteaClient, ProjectRepository};
 use crate::error::AppError;
+use crate::handlers::pagination::Page;
 use crate::AppState;
 
 /// Query parameters for listing projects
@@ -21,6 +25,8 @@ pub struct ListProjectsQuery {
     pub limit: i64,
     #[serde(default)]
     pub offset: i64,
+    /// Only list projects written in this language (case-insensitive)
+    pub language: Option<String>,
 }
 
 fn default_limit() -> i64 {
@@ -41,6 +47,27 @@ pub struct ProjectResponse {
     pub gitea_org: String,
     pub gitea_repo: String,
     pub created_at: String,
+    /// Stars on the project's repository
+    pub stars: i32,
+}
+
+impl From<Project> for ProjectResponse {
+    fn from(p: Project) -> Self {
+        Self {
+            id: p.id.to_string(),
+            name: p.name,
+            description: p.description,
+            language: p.language,
+            status: p.status.to_string(),
+            contributor_count: p.contributor_count,
+            open_ticket_count: p.open_ticket_count,
+            build_status: p.build_status.to_string(),
+            gitea_org: p.gitea_org,
+            gitea_repo: p.gitea_repo,
+            created_at: p.created_at.to_rfc3339(),
+            stars: p.star_count,
+        }
+    }
 }
 
 /// Request to create a new project
@@ -61,64 +88,174 @@ pub struct CreateProjectRequest {
 
 /// GET /projects
 ///
-/// List active projects.
+/// List active projects, optionally only those in one language.
 pub async fn list_projects(
     State(state): State<AppState>,
     Query(query): Query<ListProjectsQuery>,
+) -> Result<Json<Page<ProjectResponse>>, AppError> {
+    let service = &state.antfarm_service;
+    // A blank `?language=` filters nothing
+    let language = query.language.as_deref().filter(|l| !l.trim().is_empty());
+    let (projects, total) = match language {
+        Some(language) => (
+            service
+                .list_active_projects_by_language(language, query.limit, query.offset)
+                .await?,
+            service.count_active_projects_by_language(language).await?,
+        ),
+        None => (
+            service
+                .list_active_projects(query.limit, query.offset)
+                .await?,
+            service.count_active_projects().await?,
+        ),
+    };
+
+    let responses: Vec<ProjectResponse> = projects.into_iter().map(ProjectResponse::from).collect();
+
+    Ok(Json(Page::new(responses, total, query.limit, query.offset)))
+}
+
+/// A language and how many active projects use it
+#[derive(Debug, Serialize)]
+pub struct LanguageFacetResponse {
+    pub language: String,
+    pub count: i64,
+}
+
+/// GET /projects/languages
+///
+/// Distinct languages of active projects with their counts, most common first.
+pub async fn list_project_languages(
+    State(state): State<AppState>,
+) -> Result<Json<Vec<LanguageFacetResponse>>, AppError> {
+    let facets = state.antfarm_service.language_facets().await?;
+
+    Ok(Json(
+        facets
+            .into_iter()
+            .map(|(language, count)| LanguageFacetResponse { language, count })
+            .collect(),
+    ))
+}
+
+/// Query parameters for the featured project list
+#[derive(Debug, Deserialize)]
+pub struct FeaturedProjectsQuery {
+    #[serde(default = "default_limit")]
+    pub limit: i64,
+}
+
+/// GET /projects/featured
+///
+/// List operator-curated featured projects, ordered by curation rank.
+pub async fn list_featured_projects(
+    State(state): State<AppState>,
+    Query(query): Query<FeaturedProjectsQuery>,
 ) -> Result<Json<Vec<ProjectResponse>>, AppError> {
     let projects = state
         .antfarm_service
-        .list_active_projects(query.limit, query.offset)
+        .list_featured_projects(query.limit)
         .await?;
 
-    let responses: Vec<ProjectResponse> = projects
-        .into_iter()
-        .map(|p| ProjectResponse {
-            id: p.id.to_string(),
-            name: p.name,
-            description: p.description,
-            language: p.language,
-            status: p.status.to_string(),
-            contributor_count: p.contributor_count,
-            open_ticket_count: p.open_ticket_count,
-            build_status: p.build_status.to_string(),
-            gitea_org: p.gitea_org,
-            gitea_repo: p.gitea_repo,
-            created_at: p.created_at.to_rfc3339(),
-        })
-        .collect();
+    let responses: Vec<ProjectResponse> = projects.into_iter().map(ProjectResponse::from).collect();
 
     Ok(Json(responses))
 }
 
-/// GET /projects/:id
+/// Request to feature or unfeature a project
+#[derive(Debug, Deserialize)]
+pub struct SetFeaturedRequest {
+    pub featured: bool,
+    /// Position on the featured list (lower comes first)
+    #[serde(default)]
+    pub rank: i32,
+}
+
+/// Response after changing a project's featured state
+#[derive(Debug, Serialize)]
+pub struct SetFeaturedResponse {
+    pub project_id: String,
+    pub featured: bool,
+    pub rank: i32,
+}
+
+/// PUT /projects/:id/featured
 ///
-/// Get project details.
-pub async fn get_project(
+/// Feature or unfeature a project. Requires the operator's `X-Admin-Token`.
+pub async fn set_project_featured(
     State(state): State<AppState>,
+    _admin: AdminToken,
     Path(id): Path<Uuid>,
-) -> Result<Json<ProjectResponse>, AppError> {
+    Json(request): Json<SetFeaturedRequest>,
+) -> Result<Json<SetFeaturedResponse>, AppError> {
     let project = state
         .antfarm_service
         .get_project(&ProjectId(id))
         .await?
         .ok_or_else(|| AppError::NotFound(format!("Project {} not found", id)))?;
 
-    Ok(Json(ProjectResponse {
-        id: project.id.to_string(),
-        name: project.name,
-        description: project.description,
-        language: project.language,
-        status: project.status.to_string(),
-        contributor_count: project.contributor_count,
-        open_ticket_count: project.open_ticket_count,
-        build_status: project.build_status.to_string(),
-        gitea_org: project.gitea_org,
-        gitea_repo: project.gitea_repo,
-        created_at: project.created_at.to_rfc3339(),
+    let project = state
+        .antfarm_service
+        .set_featured(&project, request.featured, request.rank)
+        .await?;
+
+    Ok(Json(SetFeaturedResponse {
+        project_id: project.id.to_string(),
+        featured: project.featured,
+        rank: project.featured_rank,
+    }))
+}
+
+/// Request to set a project's ELO multiplier
+#[derive(Debug, Deserialize)]
+pub struct SetEloMultiplierRequest {
+    /// Clamped to 0.5–3.0
+    pub multiplier: f32,
+}
+
+/// Response after changing a project's ELO multiplier
+#[derive(Debug, Serialize)]
+pub struct SetEloMultiplierResponse {
+    pub project_id: String,
+    pub elo_multiplier: f32,
+}
+
+/// PUT /projects/:id/elo-multiplier
+///
+/// Weight ELO earned on a project. Requires the operator's `X-Admin-Token`.
+pub async fn set_project_elo_multiplier(
+    State(state): State<AppState>,
+    _admin: AdminToken,
+    Path(id): Path<Uuid>,
+    Json(request): Json<SetEloMultiplierRequest>,
+) -> Result<Json<SetEloMultiplierResponse>, AppError> {
+    let project = state
+        .antfarm_service
+        .get_project(&ProjectId(id))
+        .await?
+        .ok_or_else(|| AppError::NotFound(format!("Project {} not found", id)))?;
+
+    let project = state
+        .antfarm_service
+        .set_elo_multiplier(&project, request.multiplier)
+        .await?;
+
+    Ok(Json(SetEloMultiplierResponse {
+        project_id: project.id.to_string(),
+        elo_multiplier: project.elo_multiplier,
     }))
 }
 
+/// GET /projects/:id
+///
+/// Get project details. Private projects are only visible to their members.
+pub async fn get_project(
+    Extension(project): Extension<Project>,
+) -> Result<Json<ProjectResponse>, AppError> {
+    Ok(Json(project.into()))
+}
+
 /// POST /projects
 ///
 /// Create a new project.
@@ -146,46 +283,120 @@ pub async fn create_project(
 
     let project = result.project;
 
-    Ok(Json(ProjectResponse {
-        id: project.id.to_string(),
-        name: project.name,
-        description: project.description,
-        language: project.language,
-        status: project.status.to_string(),
-        contributor_count: project.contributor_count,
-        open_ticket_count: project.open_ticket_count,
-        build_status: project.build_status.to_string(),
-        gitea_org: project.gitea_org,
-        gitea_repo: project.gitea_repo,
-        created_at: project.created_at.to_rfc3339(),
+    Ok(Json(project.into()))
+}
+
+/// Request to import an existing Gitea repository as a project
+#[derive(Debug, Deserialize)]
+pub struct ImportProjectRequest {
+    /// Gitea owner (org or username) of the existing repository
+    pub gitea_org: String,
+    /// Existing repository name
+    pub gitea_repo: String,
+    /// Display name for the project (defaults to the repository name)
+    pub name: Option<String>,
+    pub language: Option<String>,
+    /// Mirror the project's merge settings onto the default branch's protection
+    #[serde(default)]
+    pub protect_default_branch: bool,
+}
+
+/// Response for importing a project
+#[derive(Debug, Serialize)]
+pub struct ImportProjectResponse {
+    pub project: ProjectResponse,
+    pub webhook_registered: bool,
+    pub contributions_backfilled: usize,
+    /// Merged PRs whose contribution couldn't be recorded
+    pub contributions_failed: usize,
+    /// Why the backfill stopped early, if it did
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub backfill_error: Option<String>,
+    /// Other repositories in the same organization
+    pub org_repos: Vec<String>,
+    pub message: String,
+}
+
+/// POST /projects/import
+///
+/// Import an existing Gitea repository as a project.
+/// The importing agent becomes the project owner.
+pub async fn import_project(
+    State(state): State<AppState>,
+    Extension(agent): Extension<Agent>,
+    Json(request): Json<ImportProjectRequest>,
+) -> Result<Json<ImportProjectResponse>, AppError> {
+    let webhook_url = format!("{}/webhooks/gitea", state.api_base_url);
+
+    let result = state
+        .antfarm_service
+        .import_project(
+            &agent,
+            &request.gitea_org,
+            &request.gitea_repo,
+            request.name.as_deref(),
+            request.language.as_deref(),
+            &webhook_url,
+            state.config.webhook_secret.as_deref(),
+            request.protect_default_branch,
+        )
+        .await?;
+
+    let project = result.project;
+
+    // Backfill contributions from already-merged PRs. The import itself has
+    // succeeded by now, so a failure is reported rather than returned.
+    let (backfill, backfill_error) = match state
+        .reactive_elo_service
+        .backfill_contributions(state.gitea.as_ref(), &project)
+        .await
+    {
+        Ok(report) => (report, None),
+        Err(e) => {
+            tracing::warn!(error = %e, project = %project.name, "Failed to backfill contributions");
+            (BackfillReport::default(), Some(e.to_string()))
+        }
+    };
+
+    Ok(Json(ImportProjectResponse {
+        project: project.into(),
+        webhook_registered: result.webhook_id.is_some(),
+        contributions_backfilled: backfill.created,
+        contributions_failed: backfill.failed,
+        backfill_error,
+        org_repos: result.sibling_repos,
+        message: result.message,
     }))
 }
 
+/// Query parameters for listing the agent's own projects
+#[derive(Debug, Deserialize)]
+pub struct MyProjectsQuery {
+    /// Only projects where the agent holds this role (owner, maintainer, contributor)
+    pub role: Option<String>,
+}
+
 /// GET /projects/my
 ///
 /// Get projects the authenticated agent is a member of.
+/// Use `?role=maintainer` to list only projects the agent maintains or owns.
 pub async fn get_my_projects(
     State(state): State<AppState>,
     Extension(agent): Extension<Agent>,
+    Query(query): Query<MyProjectsQuery>,
 ) -> Result<Json<Vec<ProjectResponse>>, AppError> {
-    let projects = state.antfarm_service.get_my_projects(&agent).await?;
+    let projects = match query.role.as_deref() {
+        Some(role) => {
+            let role = role.parse::<MemberRole>().map_err(AppError::BadRequest)?;
+            state
+                .antfarm_service
+                .get_my_projects_with_role(&agent, role)
+                .await?
+        }
+        None => state.antfarm_service.get_my_projects(&agent).await?,
+    };
 
-    let responses: Vec<ProjectResponse> = projects
-        .into_iter()
-        .map(|p| ProjectResponse {
-            id: p.id.to_string(),
-            name: p.name,
-            description: p.description,
-            language: p.language,
-            status: p.status.to_string(),
-            contributor_count: p.contributor_count,
-            open_ticket_count: p.open_ticket_count,
-            build_status: p.build_status.to_string(),
-            gitea_org: p.gitea_org,
-            gitea_repo: p.gitea_repo,
-            created_at: p.created_at.to_rfc3339(),
-        })
-        .collect();
+    let responses: Vec<ProjectResponse> = projects.into_iter().map(ProjectResponse::from).collect();
 
     Ok(Json(responses))
 }
@@ -215,6 +426,274 @@ pub async fn join_project(
     }))
 }
 
+/// Response for starring a project
+#[derive(Debug, Serialize)]
+pub struct StarProjectResponse {
+    pub project_id: String,
+    pub stars: i64,
+}
+
+/// POST /projects/:id/star
+///
+/// Star the project's repository on Gitea as the calling agent.
+pub async fn star_project(
+    State(state): State<AppState>,
+    Extension(agent): Extension<Agent>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<StarProjectResponse>, AppError> {
+    let project = state
+        .antfarm_service
+        .get_visible_project(&ProjectId(id), Some(&agent))
+        .await?
+        .ok_or_else(|| AppError::NotFound(format!("Project {} not found", id)))?;
+
+    let token = state
+        .agent_service
+        .get_gitea_token(&agent.id)
+        .await?
+        .ok_or_else(|| {
+            AppError::BadRequest("Your agent has no Gitea account to star with".to_string())
+        })?;
+
+    let stars = state.antfarm_service.star_project(&project, &token).await?;
+
+    Ok(Json(StarProjectResponse {
+        project_id: project.id.to_string(),
+        stars,
+    }))
+}
+
+/// Request to mirror a project's external source repository
+#[derive(Debug, Deserialize)]
+pub struct MirrorProjectRequest {
+    /// HTTP(S) clone URL of the external repository
+    pub source_url: String,
+    /// Mirror repo name (defaults to `<repo>-mirror`)
+    pub name: Option<String>,
+}
+
+/// Response for creating a project mirror
+#[derive(Debug, Serialize)]
+pub struct MirrorProjectResponse {
+    pub project_id: String,
+    pub source_url: String,
+    pub mirror_repo: String,
+    pub clone_url: String,
+    pub mirrored_at: String,
+    pub message: String,
+}
+
+/// POST /projects/:id/mirror
+///
+/// Create a read-only Gitea mirror of the project's external source.
+/// Requires owner or maintainer role.
+pub async fn mirror_project(
+    State(state): State<AppState>,
+    Extension(agent): Extension<Agent>,
+    Path(id): Path<Uuid>,
+    Json(request): Json<MirrorProjectRequest>,
+) -> Result<Json<MirrorProjectResponse>, AppError> {
+    let project = state
+        .antfarm_service
+        .get_project(&ProjectId(id))
+        .await?
+        .ok_or_else(|| AppError::NotFound(format!("Project {} not found", id)))?;
+
+    let result = state
+        .antfarm_service
+        .mirror_project(
+            &agent,
+            &project,
+            &request.source_url,
+            request.name.as_deref(),
+        )
+        .await?;
+
+    let mirror = result
+        .project
+        .mirror
+        .ok_or_else(|| AppError::Internal("Mirror was not recorded".to_string()))?;
+
+    Ok(Json(MirrorProjectResponse {
+        project_id: result.project.id.to_string(),
+        source_url: mirror.source_url,
+        mirror_repo: result.repo.full_name,
+        clone_url: result.repo.clone_url,
+        mirrored_at: mirror.mirrored_at.to_rfc3339(),
+        message: result.message,
+    }))
+}
+
+/// Request to cut a release
+#[derive(Debug, Deserialize)]
+pub struct CreateReleaseRequest {
+    /// Tag to create, e.g. `v1.0.0` (must not exist yet)
+    pub tag: String,
+    /// Release title (defaults to the tag)
+    pub name: Option<String>,
+    pub body: Option<String>,
+    #[serde(default)]
+    pub draft: bool,
+    #[serde(default)]
+    pub prerelease: bool,
+}
+
+/// Response for a created release
+#[derive(Debug, Serialize)]
+pub struct ReleaseResponse {
+    pub id: i64,
+    pub tag: String,
+    pub name: String,
+    pub draft: bool,
+    pub prerelease: bool,
+    pub url: String,
+}
+
+/// POST /projects/:id/releases
+///
+/// Cut a release on a new tag. Owners and maintainers only.
+pub async fn create_release(
+    State(state): State<AppState>,
+    Extension(agent): Extension<Agent>,
+    Path(id): Path<Uuid>,
+    Json(request): Json<CreateReleaseRequest>,
+) -> Result<Json<ReleaseResponse>, AppError> {
+    let project = state
+        .antfarm_service
+        .get_project(&ProjectId(id))
+        .await?
+        .ok_or_else(|| AppError::NotFound(format!("Project {} not found", id)))?;
+
+    let release = state
+        .antfarm_service
+        .create_release(
+            &agent,
+            &project,
+            &NewRelease {
+                tag: request.tag,
+                name: request.name,
+                body: request.body,
+                draft: request.draft,
+                prerelease: request.prerelease,
+            },
+        )
+        .await?;
+
+    Ok(Json(ReleaseResponse {
+        id: release.id,
+        tag: release.tag_name,
+        name: release.name,
+        draft: release.draft,
+        prerelease: release.prerelease,
+        url: release.html_url,
+    }))
+}
+
+/// Request to update project settings
+#[derive(Debug, Deserialize)]
+pub struct ProjectSettingsRequest {
+    /// Allow PRs against branches other than the default branch
+    pub allow_alternate_bases: Option<bool>,
+    /// Require a green combined CI status before PRs can be merged
+    pub require_green_ci: Option<bool>,
+    /// Distinct approving reviews a PR needs before it can be merged
+    pub required_approvals: Option<i32>,
+    /// Who can see the project: public, unlisted or private
+    pub visibility: Option<ProjectVisibility>,
+    /// Who may peer-review PRs: open or members
+    pub review_eligibility: Option<ReviewEligibility>,
+    /// Mirror the merge settings onto the default branch's Gitea protection
+    pub protect_default_branch: Option<bool>,
+}
+
+/// Current project settings
+#[derive(Debug, Serialize)]
+pub struct ProjectSettingsResponse {
+    pub project_id: String,
+    pub allow_alternate_bases: bool,
+    pub require_green_ci: bool,
+    pub required_approvals: i32,
+    pub visibility: ProjectVisibility,
+    pub review_eligibility: ReviewEligibility,
+    pub protect_default_branch: bool,
+}
+
+/// PUT /projects/:id/settings
+///
+/// Update project settings. Requires owner or maintainer role.
+pub async fn update_project_settings(
+    State(state): State<AppState>,
+    Extension(agent): Extension<Agent>,
+    Path(id): Path<Uuid>,
+    Json(request): Json<ProjectSettingsRequest>,
+) -> Result<Json<ProjectSettingsResponse>, AppError> {
+    let project = state
+        .antfarm_service
+        .get_project(&ProjectId(id))
+        .await?
+        .ok_or_else(|| AppError::NotFound(format!("Project {} not found", id)))?;
+
+    if request.allow_alternate_bases.is_none()
+        && request.require_green_ci.is_none()
+        && request.required_approvals.is_none()
+        && request.visibility.is_none()
+        && request.review_eligibility.is_none()
+        && request.protect_default_branch.is_none()
+    {
+        return Err(AppError::BadRequest(
+            "No project settings provided".to_string(),
+        ));
+    }
+
+    let mut project = project;
+    if let Some(allowed) = request.allow_alternate_bases {
+        project = state
+            .antfarm_service
+            .set_allow_alternate_bases(&agent, &project, allowed)
+            .await?;
+    }
+    if let Some(required) = request.require_green_ci {
+        project = state
+            .antfarm_service
+            .set_require_green_ci(&agent, &project, required)
+            .await?;
+    }
+    if let Some(count) = request.required_approvals {
+        project = state
+            .antfarm_service
+            .set_required_approvals(&agent, &project, count)
+            .await?;
+    }
+    if let Some(visibility) = request.visibility {
+        project = state
+            .antfarm_service
+            .set_visibility(&agent, &project, visibility)
+            .await?;
+    }
+    if let Some(eligibility) = request.review_eligibility {
+        project = state
+            .antfarm_service
+            .set_review_eligibility(&agent, &project, eligibility)
+            .await?;
+    }
+    if let Some(enabled) = request.protect_default_branch {
+        project = state
+            .antfarm_service
+            .set_protect_default_branch(&agent, &project, enabled)
+            .await?;
+    }
+
+    Ok(Json(ProjectSettingsResponse {
+        project_id: project.id.to_string(),
+        allow_alternate_bases: project.allow_alternate_bases,
+        require_green_ci: project.require_green_ci,
+        required_approvals: project.required_approvals,
+        visibility: project.visibility,
+        review_eligibility: project.review_eligibility,
+        protect_default_branch: project.protect_default_branch,
+    }))
+}
+
 /// Response for joining a project
 #[derive(Debug, Serialize)]
 pub struct JoinProjectResponse {
@@ -272,6 +751,42 @@ pub async fn list_my_orgs(
     Ok(Json(orgs))
 }
 
+/// Repository in an organization
+#[derive(Debug, Serialize)]
+pub struct OrgRepoResponse {
+    pub name: String,
+    pub full_name: String,
+    pub description: Option<String>,
+    pub clone_url: String,
+    pub default_branch: String,
+    pub private: bool,
+}
+
+/// GET /orgs/:org/repos
+///
+/// List every repository in an organization. Requires owner or maintainer role.
+pub async fn list_org_repos(
+    State(state): State<AppState>,
+    Extension(agent): Extension<Agent>,
+    Path(org): Path<String>,
+) -> Result<Json<Vec<OrgRepoResponse>>, AppError> {
+    let repos = state.antfarm_service.list_org_repos(&agent, &org).await?;
+
+    Ok(Json(
+        repos
+            .into_iter()
+            .map(|r| OrgRepoResponse {
+                name: r.name,
+                full_name: r.full_name,
+                description: r.description,
+                clone_url: r.clone_url,
+                default_branch: r.default_branch,
+                private: r.private,
+            })
+            .collect(),
+    ))
+}
+
 // ============================================================================
 // Maintainer Management
 // ============================================================================
@@ -445,16 +960,48 @@ pub async fn remove_maintainer(
     })))
 }
 
+/// Request to invite an agent to a project
+#[derive(Debug, Deserialize)]
+pub struct InviteAgentRequest {
+    /// Gitea username of the agent to invite
+    pub username: String,
+}
+
+/// POST /projects/:id/invites
+///
+/// Invite an agent to a private project. Requires owner or maintainer role.
+pub async fn invite_agent(
+    State(state): State<AppState>,
+    Extension(agent): Extension<Agent>,
+    Extension(project): Extension<Project>,
+    Json(request): Json<InviteAgentRequest>,
+) -> Result<Json<serde_json::Value>, AppError> {
+    let invitee = state
+        .agent_service
+        .find_by_gitea_username(&request.username)
+        .await?
+        .ok_or_else(|| {
+            AppError::NotFound(format!(
+                "Agent with username '{}' not found",
+                request.username
+            ))
+        })?;
+
+    state
+        .antfarm_service
+        .invite_member(&agent, &project, &invitee)
+        .await?;
+
+    Ok(Json(serde_json::json!({
+        "success": true,
+        "message": format!("{} can now join {}", request.username, project.name)
+    })))
+}
+
 // ============================================================================
 // Project Succession / Claim System
 // ============================================================================
 
-use chrono::Utc;
-
-/// Inactivity thresholds (in days)
-const OWNER_INACTIVITY_DAYS: i64 = 30;
-const MAINTAINER_INACTIVITY_DAYS: i64 = 14;
-
 /// Response for succession status check
 #[derive(Debug, Serialize)]
 pub struct SuccessionStatusResponse {
@@ -472,6 +1019,8 @@ pub struct SuccessionStatusResponse {
     pub you_can_claim: bool,
     /// What role the agent can claim (if any)
     pub claimable_role: Option<String>,
+    /// Repositories in the project's organization (what a claimed role covers)
+    pub org_repos: Vec<String>,
     /// Message explaining the situation
     pub message: String,
 }
@@ -497,74 +1046,33 @@ pub async fn get_succession_status(
         .await?
         .ok_or_else(|| AppError::NotFound(format!("Project {} not found", project_id)))?;
 
-    let members = state.project_repo.get_members(&project.id).await?;
-    let now = Utc::now();
+    let succession = state
+        .antfarm_service
+        .get_succession(&project, state.agent_repo.as_ref())
+        .await?;
 
-    // Find owner and check activity
-    let mut owner_claimable = false;
-    let mut owner_inactive_days = None;
     let mut current_owner = None;
-
-    for member in &members {
+    for member in state.project_repo.get_members(&project.id).await? {
         if member.role == MemberRole::Owner {
             if let Ok(Some(owner_agent)) = state.agent_service.find_by_id(&member.agent_id).await {
-                current_owner = Some(owner_agent.gitea_username.clone());
-                let last_active = owner_agent.last_seen_at.unwrap_or(owner_agent.created_at);
-                let inactive_days = (now - last_active).num_days();
-                if inactive_days >= OWNER_INACTIVITY_DAYS {
-                    owner_claimable = true;
-                    owner_inactive_days = Some(inactive_days);
-                }
-            }
-        }
-    }
-
-    // Find maintainers and check activity
-    let mut maintainer_claimable = false;
-    let mut maintainer_inactive_days = None;
-    let mut all_maintainers_inactive = true;
-    let mut has_maintainers = false;
-
-    for member in &members {
-        if member.role == MemberRole::Maintainer {
-            has_maintainers = true;
-            if let Ok(Some(maint_agent)) = state.agent_service.find_by_id(&member.agent_id).await {
-                let last_active = maint_agent.last_seen_at.unwrap_or(maint_agent.created_at);
-                let inactive_days = (now - last_active).num_days();
-                if inactive_days < MAINTAINER_INACTIVITY_DAYS {
-                    all_maintainers_inactive = false;
-                } else {
-                    maintainer_inactive_days = Some(inactive_days);
-                }
+                current_owner = Some(owner_agent.gitea_username);
             }
         }
     }
 
-    if has_maintainers && all_maintainers_inactive {
-        maintainer_claimable = true;
-    }
-
     // Check if requesting agent is eligible to claim
-    // Must be a contributor with at least some activity
     let agent_role = state
         .project_repo
         .get_member_role(&project.id, &agent.id)
         .await?;
 
     let is_member = agent_role.is_some();
-    let is_contributor = agent_role == Some(MemberRole::Contributor);
-    let is_maintainer = agent_role == Some(MemberRole::Maintainer);
-
-    let mut you_can_claim = false;
-    let mut claimable_role = None;
-
-    if owner_claimable && (is_maintainer || is_contributor) {
-        you_can_claim = true;
-        claimable_role = Some("owner".to_string());
-    } else if maintainer_claimable && is_contributor {
-        you_can_claim = true;
-        claimable_role = Some("maintainer".to_string());
-    }
+    let claimable_role = succession
+        .claimable_by(agent_role)
+        .map(|role| role.to_string());
+    let you_can_claim = claimable_role.is_some();
+    let owner_claimable = succession.owner_claimable;
+    let maintainer_claimable = succession.maintainer_claimable;
 
     let message = if !is_member {
         "You must be a project member to claim roles".to_string()
@@ -580,14 +1088,23 @@ pub async fn get_succession_status(
         "No roles are currently claimable - project leadership is active".to_string()
     };
 
+    // Org-level roles span every repo in the org, not just this project
+    let org_repos = state
+        .gitea
+        .get_org_repos(&project.gitea_org)
+        .await
+        .map(|repos| repos.into_iter().map(|r| r.name).collect())
+        .unwrap_or_default();
+
     Ok(Json(SuccessionStatusResponse {
         owner_claimable,
-        owner_inactive_days,
+        owner_inactive_days: succession.owner_inactive_days,
         current_owner,
         maintainer_claimable,
-        maintainer_inactive_days,
+        maintainer_inactive_days: succession.maintainer_inactive_days,
         you_can_claim,
         claimable_role,
+        org_repos,
         message,
     }))
 }
@@ -607,6 +1124,17 @@ pub async fn claim_role(
         .await?
         .ok_or_else(|| AppError::NotFound(format!("Project {} not found", project_id)))?;
 
+    let requested = match request.role.to_lowercase().as_str() {
+        "owner" => MemberRole::Owner,
+        "maintainer" => MemberRole::Maintainer,
+        _ => {
+            return Err(AppError::BadRequest(format!(
+                "Invalid role '{}'. Use 'owner' or 'maintainer'",
+                request.role
+            )))
+        }
+    };
+
     // Check agent's current role
     let agent_role = state
         .project_repo
@@ -619,45 +1147,37 @@ pub async fn claim_role(
         )));
     }
 
-    let members = state.project_repo.get_members(&project.id).await?;
-    let now = Utc::now();
-
-    match request.role.to_lowercase().as_str() {
-        "owner" => {
-            // Verify owner is actually inactive
-            let mut owner_inactive = false;
-            let mut current_owner_id = None;
-
-            for member in &members {
-                if member.role == MemberRole::Owner {
-                    current_owner_id = Some(member.agent_id);
-                    if let Ok(Some(owner_agent)) =
-                        state.agent_service.find_by_id(&member.agent_id).await
-                    {
-                        let last_active =
-                            owner_agent.last_seen_at.unwrap_or(owner_agent.created_at);
-                        let inactive_days = (now - last_active).num_days();
-                        if inactive_days >= OWNER_INACTIVITY_DAYS {
-                            owner_inactive = true;
-                        }
-                    }
-                }
+    let succession = state
+        .antfarm_service
+        .get_succession(&project, state.agent_repo.as_ref())
+        .await?;
+    let claimable = succession.claimable_by(agent_role);
+    if claimable != Some(requested) {
+        let reason = match (requested, claimable) {
+            (_, Some(role)) => format!("You can only claim the {} role", role),
+            (MemberRole::Owner, None) if !succession.owner_claimable => {
+                "Owner is still active. Cannot claim ownership.".to_string()
             }
-
-            if !owner_inactive {
-                return Err(AppError::BadRequest(
-                    "Owner is still active. Cannot claim ownership.".to_string(),
-                ));
+            (MemberRole::Owner, None) => {
+                "Only contributors or maintainers can claim ownership".to_string()
             }
-
-            // Must be at least a contributor or maintainer
-            if agent_role != Some(MemberRole::Contributor)
-                && agent_role != Some(MemberRole::Maintainer)
-            {
-                return Err(AppError::BadRequest(
-                    "Only contributors or maintainers can claim ownership".to_string(),
-                ));
+            (_, None) if !succession.maintainer_claimable => {
+                "Maintainers are still active. Cannot claim maintainer role.".to_string()
             }
+            (_, None) => "Only contributors can claim maintainer role".to_string(),
+        };
+        return Err(AppError::BadRequest(reason));
+    }
+
+    match requested {
+        MemberRole::Owner => {
+            let current_owner_id = state
+                .project_repo
+                .get_members(&project.id)
+                .await?
+                .into_iter()
+                .find(|m| m.role == MemberRole::Owner)
+                .map(|m| m.agent_id);
 
             // Demote old owner to maintainer (if they exist)
             if let Some(old_owner_id) = current_owner_id {
@@ -688,41 +1208,7 @@ pub async fn claim_role(
                 "new_role": "owner"
             })))
         }
-        "maintainer" => {
-            // Verify all maintainers are inactive
-            let mut all_maintainers_inactive = true;
-            let mut has_maintainers = false;
-
-            for member in &members {
-                if member.role == MemberRole::Maintainer {
-                    has_maintainers = true;
-                    if let Ok(Some(maint_agent)) =
-                        state.agent_service.find_by_id(&member.agent_id).await
-                    {
-                        let last_active =
-                            maint_agent.last_seen_at.unwrap_or(maint_agent.created_at);
-                        let inactive_days = (now - last_active).num_days();
-                        if inactive_days < MAINTAINER_INACTIVITY_DAYS {
-                            all_maintainers_inactive = false;
-                            break;
-                        }
-                    }
-                }
-            }
-
-            if has_maintainers && !all_maintainers_inactive {
-                return Err(AppError::BadRequest(
-                    "Some maintainers are still active. Cannot claim maintainer role.".to_string(),
-                ));
-            }
-
-            // Must be a contributor
-            if agent_role != Some(MemberRole::Contributor) {
-                return Err(AppError::BadRequest(
-                    "Only contributors can claim maintainer role".to_string(),
-                ));
-            }
-
+        _ => {
             // Promote agent to maintainer
             state
                 .project_repo
@@ -744,19 +1230,80 @@ pub async fn claim_role(
                 "new_role": "maintainer"
             })))
         }
-        _ => Err(AppError::BadRequest(format!(
-            "Invalid role '{}'. Use 'owner' or 'maintainer'",
-            request.role
-        ))),
     }
 }
 
+/// A role the agent can claim on one of its projects
+#[derive(Debug, Serialize)]
+pub struct ClaimableRoleResponse {
+    pub project_id: Uuid,
+    pub project_name: String,
+    /// Role that can be claimed: "owner" or "maintainer"
+    pub role: String,
+    /// Days since the role's current holder was last active
+    pub inactive_days: Option<i64>,
+}
+
+/// GET /agents/me/claimable-roles
+///
+/// List abandoned roles the agent is eligible to claim across all of its projects.
+pub async fn list_claimable_roles(
+    State(state): State<AppState>,
+    Extension(agent): Extension<Agent>,
+) -> Result<Json<Vec<ClaimableRoleResponse>>, AppError> {
+    let claimable = state
+        .antfarm_service
+        .list_claimable_roles(&agent, state.agent_repo.as_ref())
+        .await?;
+
+    Ok(Json(
+        claimable
+            .into_iter()
+            .map(|c| ClaimableRoleResponse {
+                project_id: c.project.id.0,
+                project_name: c.project.name,
+                role: c.role.to_string(),
+                inactive_days: c.inactive_days,
+            })
+            .collect(),
+    ))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn parse_my_projects_query() {
+        let query: MyProjectsQuery = serde_json::from_str(r#"{"role": "maintainer"}"#).unwrap();
+        assert_eq!(query.role.as_deref(), Some("maintainer"));
+
+        let query: MyProjectsQuery = serde_json::from_str("{}").unwrap();
+        assert!(query.role.is_none());
+    }
+
     // ===== ListProjectsQuery tests =====
 
+    #[test]
+    fn parse_mirror_project_request() {
+        let json = r#"{"source_url": "https://github.com/example/upstream.git"}"#;
+        let request: MirrorProjectRequest = serde_json::from_str(json).unwrap();
+        assert_eq!(
+            request.source_url,
+            "https://github.com/example/upstream.git"
+        );
+        assert!(request.name.is_none());
+    }
+
+    #[test]
+    fn parse_project_settings_request() {
+        let json = r#"{"allow_alternate_bases": true}"#;
+        let request: ProjectSettingsRequest = serde_json::from_str(json).unwrap();
+        assert_eq!(request.allow_alternate_bases, Some(true));
+        assert!(request.require_green_ci.is_none());
+        assert!(request.review_eligibility.is_none());
+    }
+
     #[test]
     fn parse_list_query_defaults() {
         let query: ListProjectsQuery = serde_json::from_str("{}").unwrap();
@@ -812,6 +1359,16 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn parse_import_project_minimal() {
+        let json = r#"{"gitea_org": "my-org", "gitea_repo": "backend"}"#;
+        let request: ImportProjectRequest = serde_json::from_str(json).unwrap();
+        assert_eq!(request.gitea_org, "my-org");
+        assert_eq!(request.gitea_repo, "backend");
+        assert!(request.name.is_none());
+        assert!(request.language.is_none());
+    }
+
     #[test]
     fn parse_create_project_missing_name() {
         let json = r#"{"repo": "test"}"#;
@@ -835,6 +1392,7 @@ mod tests {
             gitea_org: "antfarm-test".to_string(),
             gitea_repo: "main".to_string(),
             created_at: "2025-01-01T00:00:00Z".to_string(),
+            stars: 0,
         };
 
         let json = serde_json::to_string(&response).unwrap();
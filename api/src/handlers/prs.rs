@@ -8,12 +8,18 @@ use axum::{
     extract::{Path, Query, State},
     Extension, Json,
 };
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
-use crate::domain::entities::{Agent, MemberRole, ProjectId};
-use crate::domain::ports::{GiteaClient, ProjectRepository};
-use crate::error::AppError;
+use crate::app::pr_target_id;
+use crate::domain::entities::{
+    ActionEloThresholds, Agent, EngagementCounts, GatedAction, IssueId, MemberRole, ProjectId,
+    TargetType,
+};
+use crate::domain::ports::{AgentRepository, GiteaClient, IssueRepository, ProjectRepository};
+use crate::error::{AppError, GiteaError};
+use crate::handlers::agents::ContributionResponse;
 use crate::AppState;
 
 // ============================================================================
@@ -60,6 +66,43 @@ pub struct PrDetailResponse {
     pub merged: bool,
     pub reviews: Vec<ReviewResponse>,
     pub ci_status: Option<String>,
+    /// Engagement counts, including reactions left directly on Gitea
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub engagement: Option<EngagementCounts>,
+}
+
+/// A PR's changed files and unified diff
+#[derive(Debug, Serialize)]
+pub struct PrDiffResponse {
+    pub number: i64,
+    pub files: Vec<String>,
+    pub diff: String,
+}
+
+/// Combined CI status of a PR's head commit
+#[derive(Debug, Serialize)]
+pub struct PrStatusResponse {
+    pub number: i64,
+    pub sha: String,
+    /// Worst state across all checks: success, warning, pending, failure or error
+    pub state: String,
+    pub statuses: Vec<StatusCheckResponse>,
+    /// Commits on the PR branch that base doesn't have
+    pub ahead_by: Option<i64>,
+    /// Commits on base that the PR branch doesn't have
+    pub behind_by: Option<i64>,
+    /// Present when the branch is far behind base and should be rebased
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub warning: Option<String>,
+}
+
+/// A single CI check on a commit
+#[derive(Debug, Serialize)]
+pub struct StatusCheckResponse {
+    pub context: String,
+    pub state: String,
+    pub description: Option<String>,
+    pub target_url: Option<String>,
 }
 
 /// Review response
@@ -113,6 +156,9 @@ pub struct MergePrRequest {
     /// Merge style: merge, rebase, squash (default: merge)
     #[serde(default = "default_merge_style")]
     pub style: String,
+    /// Merge without the project's required approvals
+    #[serde(default)]
+    pub force: bool,
 }
 
 fn default_merge_style() -> String {
@@ -128,6 +174,23 @@ pub struct SubmitReviewRequest {
     pub body: Option<String>,
 }
 
+/// Request to ask agents for a review
+#[derive(Debug, Deserialize)]
+pub struct RequestReviewersRequest {
+    /// Names of the agents to ask (must be project members)
+    pub reviewers: Vec<String>,
+}
+
+/// A review asked of an agent
+#[derive(Debug, Serialize)]
+pub struct ReviewRequestResponse {
+    pub id: Uuid,
+    pub reviewer: String,
+    pub status: String,
+    /// When the request counts as abandoned if no review was submitted
+    pub due_at: DateTime<Utc>,
+}
+
 /// Request to add a comment
 #[derive(Debug, Deserialize)]
 pub struct AddCommentRequest {
@@ -156,14 +219,14 @@ async fn check_merge_permission(
     state: &AppState,
     project_id: &ProjectId,
     agent_id: &crate::domain::entities::AgentId,
-) -> Result<(), AppError> {
+) -> Result<MemberRole, AppError> {
     let role = state
         .project_repo
         .get_member_role(project_id, agent_id)
         .await?;
 
     match role {
-        Some(MemberRole::Owner) | Some(MemberRole::Maintainer) => Ok(()),
+        Some(role @ (MemberRole::Owner | MemberRole::Maintainer)) => Ok(role),
         Some(MemberRole::Contributor) => {
             Err(AppError::Domain(crate::error::DomainError::Forbidden(
                 "Only maintainers and owners can merge PRs".to_string(),
@@ -175,6 +238,24 @@ async fn check_merge_permission(
     }
 }
 
+/// Enforce the configured minimum ELO for a sensitive action
+fn check_min_elo(
+    thresholds: &ActionEloThresholds,
+    agent: &Agent,
+    action: GatedAction,
+    role: Option<MemberRole>,
+) -> Result<(), AppError> {
+    match thresholds.unmet_threshold(action, agent.elo, role) {
+        Some(required) => Err(AppError::Domain(crate::error::DomainError::Forbidden(
+            format!(
+                "{} requires at least {} ELO (you have {})",
+                action, required, agent.elo
+            ),
+        ))),
+        None => Ok(()),
+    }
+}
+
 /// Get project and verify it exists
 async fn get_project(
     state: &AppState,
@@ -260,6 +341,13 @@ pub async fn get_pr(
         .ok()
         .map(|s| s.state);
 
+    // Reactions left on Gitea are folded in by the background reconcile job
+    let engagement = state
+        .engagement_service
+        .get_counts(TargetType::Pr, pr_target_id(&project.id, number))
+        .await
+        .ok();
+
     Ok(Json(PrDetailResponse {
         number: pr.number,
         title: pr.title,
@@ -281,9 +369,96 @@ pub async fn get_pr(
             })
             .collect(),
         ci_status,
+        engagement,
+    }))
+}
+
+/// GET /projects/:id/prs/:number/diff
+///
+/// Get the files a PR changes and its unified diff, for reviewing.
+pub async fn get_pr_diff(
+    State(state): State<AppState>,
+    Path((project_id, number)): Path<(Uuid, i64)>,
+) -> Result<Json<PrDiffResponse>, AppError> {
+    let project = get_project(&state, project_id).await?;
+
+    let diff = state
+        .gitea
+        .get_pr_raw_diff(&project.gitea_org, &project.gitea_repo, number)
+        .await
+        .map_err(|e| match e {
+            GiteaError::Api { status: 404, .. } => {
+                AppError::NotFound(format!("PR #{} not found", number))
+            }
+            e => e.into(),
+        })?;
+
+    Ok(Json(PrDiffResponse {
+        number,
+        files: diff_files(&diff),
+        diff,
     }))
 }
 
+/// GET /projects/:id/prs/:number/contribution
+///
+/// Get the contribution recorded for a merged PR and how it has held up.
+pub async fn get_pr_contribution(
+    State(state): State<AppState>,
+    Path((project_id, number)): Path<(Uuid, i64)>,
+) -> Result<Json<ContributionResponse>, AppError> {
+    let project = get_project(&state, project_id).await?;
+
+    let contribution = state
+        .reactive_elo_service
+        .get_pr_contribution(&project.id, number)
+        .await?;
+
+    Ok(Json(ContributionResponse::from(contribution)))
+}
+
+/// GET /projects/:id/prs/:number/status
+///
+/// Get the combined CI status of a PR's head commit, per check and overall.
+pub async fn get_pr_status(
+    State(state): State<AppState>,
+    Path((project_id, number)): Path<(Uuid, i64)>,
+) -> Result<Json<PrStatusResponse>, AppError> {
+    let project = get_project(&state, project_id).await?;
+
+    let status = state
+        .work_loop_service
+        .get_pr_status(&project, number)
+        .await?;
+
+    Ok(Json(PrStatusResponse {
+        number,
+        sha: status.sha,
+        state: status.state,
+        statuses: status
+            .statuses
+            .into_iter()
+            .map(|s| StatusCheckResponse {
+                context: s.context,
+                state: s.state,
+                description: s.description,
+                target_url: s.target_url,
+            })
+            .collect(),
+        ahead_by: status.ahead_by,
+        behind_by: status.behind_by,
+        warning: status.warning,
+    }))
+}
+
+/// Paths changed by a unified diff, in order, from its `diff --git a/.. b/..` headers
+fn diff_files(diff: &str) -> Vec<String> {
+    diff.lines()
+        .filter_map(|line| line.strip_prefix("diff --git "))
+        .filter_map(|paths| paths.rsplit_once(" b/").map(|(_, b)| b.to_string()))
+        .collect()
+}
+
 /// POST /projects/:id/prs
 ///
 /// Create a new pull request.
@@ -315,6 +490,12 @@ pub async fn create_pr(
             ))
         })?;
 
+    // Only the default branch counts toward contributions
+    state
+        .work_loop_service
+        .validate_pr_base(&project, &request.base)
+        .await?;
+
     // Get agent's token for attribution
     let gitea_token = state.agent_service.get_gitea_token(&agent.id).await?;
 
@@ -360,8 +541,14 @@ pub async fn merge_pr(
 ) -> Result<Json<serde_json::Value>, AppError> {
     let project = get_project(&state, project_id).await?;
 
-    // Check merge permission
-    check_merge_permission(&state, &project.id, &agent.id).await?;
+    // Check merge permission and ELO floor
+    let role = check_merge_permission(&state, &project.id, &agent.id).await?;
+    check_min_elo(
+        &state.config.action_elo_thresholds,
+        &agent,
+        GatedAction::Merge,
+        Some(role),
+    )?;
 
     // Validate merge style
     let merge_style = match request.style.as_str() {
@@ -374,21 +561,32 @@ pub async fn merge_pr(
         }
     };
 
+    // Refuse to merge PRs that target a non-default branch
+    let pr = state
+        .gitea
+        .get_pull_request(&project.gitea_org, &project.gitea_repo, number)
+        .await
+        .map_err(|_| AppError::NotFound(format!("PR #{} not found", number)))?;
+    state
+        .work_loop_service
+        .validate_pr_base(&project, &pr.base.ref_name)
+        .await?;
+
     // Get agent's Gitea token for proper attribution
     let gitea_token = state.agent_service.get_gitea_token(&agent.id).await?;
 
-    // Merge the PR
+    // Merge the PR, refusing conflicting PRs and (if required) failing CI
     state
-        .gitea
+        .work_loop_service
         .merge_pull_request(
-            &project.gitea_org,
-            &project.gitea_repo,
+            &project,
+            &agent.id,
             number,
             merge_style,
+            request.force,
             gitea_token.as_deref(),
         )
-        .await
-        .map_err(|e| AppError::Internal(format!("Failed to merge PR: {}", e)))?;
+        .await?;
 
     Ok(Json(serde_json::json!({
         "success": true,
@@ -441,13 +639,18 @@ pub async fn submit_review(
 ) -> Result<Json<ReviewResponse>, AppError> {
     let project = get_project(&state, project_id).await?;
 
-    // Check membership
-    let is_member = state.project_repo.is_member(&project.id, &agent.id).await?;
-    if !is_member {
-        return Err(AppError::Domain(crate::error::DomainError::Forbidden(
-            "You must be a project member to review PRs".to_string(),
-        )));
-    }
+    // Who may review at all is the project's review eligibility, checked
+    // before the review reaches Gitea
+    let role = state
+        .project_repo
+        .get_member_role(&project.id, &agent.id)
+        .await?;
+    check_min_elo(
+        &state.config.action_elo_thresholds,
+        &agent,
+        GatedAction::Review,
+        role,
+    )?;
 
     // Map action to Gitea review state
     let review_state = match request.action.to_lowercase().as_str() {
@@ -465,18 +668,18 @@ pub async fn submit_review(
     // Get agent's token for attribution
     let gitea_token = state.agent_service.get_gitea_token(&agent.id).await?;
 
+    // Replace any earlier review by this agent instead of stacking a new one
     let review = state
-        .gitea
-        .submit_pr_review(
-            &project.gitea_org,
-            &project.gitea_repo,
+        .work_loop_service
+        .submit_or_replace_review(
+            &agent,
+            &project,
             number,
             review_state,
             request.body.as_deref(),
             gitea_token.as_deref(),
         )
-        .await
-        .map_err(|e| AppError::Internal(format!("Failed to submit review: {}", e)))?;
+        .await?;
 
     Ok(Json(ReviewResponse {
         id: review.id,
@@ -487,6 +690,87 @@ pub async fn submit_review(
     }))
 }
 
+/// POST /projects/:id/prs/:number/reviewers
+///
+/// Ask project members to review a PR. Requests left unanswered past the
+/// review window are reassigned or cost the reviewer a little ELO.
+pub async fn request_reviewers(
+    State(state): State<AppState>,
+    Extension(agent): Extension<Agent>,
+    Path((project_id, number)): Path<(Uuid, i64)>,
+    Json(request): Json<RequestReviewersRequest>,
+) -> Result<Json<Vec<ReviewRequestResponse>>, AppError> {
+    let project = get_project(&state, project_id).await?;
+
+    let is_member = state.project_repo.is_member(&project.id, &agent.id).await?;
+    if !is_member {
+        return Err(AppError::Domain(crate::error::DomainError::Forbidden(
+            "You must be a project member to request reviews".to_string(),
+        )));
+    }
+    if request.reviewers.is_empty() {
+        return Err(AppError::BadRequest(
+            "Name at least one reviewer".to_string(),
+        ));
+    }
+
+    let mut reviewers = Vec::with_capacity(request.reviewers.len());
+    for name in &request.reviewers {
+        let reviewer = state
+            .agent_repo
+            .find_by_name(name)
+            .await?
+            .ok_or_else(|| AppError::NotFound(format!("Agent '{}' not found", name)))?;
+        if reviewer.id == agent.id {
+            return Err(AppError::BadRequest(
+                "You can't request a review from yourself".to_string(),
+            ));
+        }
+        if !state
+            .project_repo
+            .is_member(&project.id, &reviewer.id)
+            .await?
+        {
+            return Err(AppError::BadRequest(format!(
+                "'{}' is not a member of this project",
+                name
+            )));
+        }
+        reviewers.push(reviewer);
+    }
+
+    // Make sure the PR exists and is still open
+    let pr = state
+        .gitea
+        .get_pull_request(&project.gitea_org, &project.gitea_repo, number)
+        .await
+        .map_err(|_| AppError::NotFound(format!("PR #{} not found", number)))?;
+    if pr.merged || pr.state != "open" {
+        return Err(AppError::BadRequest(format!("PR #{} is not open", number)));
+    }
+
+    let created = state
+        .review_request_service
+        .request_reviews(&project, number, &reviewers, Some(&agent.id))
+        .await?;
+
+    Ok(Json(
+        created
+            .into_iter()
+            .map(|r| ReviewRequestResponse {
+                id: r.id.0,
+                reviewer: reviewers
+                    .iter()
+                    .find(|a| a.id == r.reviewer_agent_id)
+                    .map(|a| a.name.clone())
+                    .unwrap_or_default(),
+                status: r.status.to_string(),
+                due_at: r.due_at,
+            })
+            .collect(),
+    ))
+}
+
 // ============================================================================
 // Comment Handlers
 // ============================================================================
@@ -568,35 +852,32 @@ pub async fn add_comment(
 
 /// PATCH /projects/:id/prs/:number/comments/:comment_id
 ///
-/// Edit a comment on a PR.
+/// Edit a comment on a PR. Only its author may edit it.
 pub async fn edit_comment(
     State(state): State<AppState>,
     Extension(agent): Extension<Agent>,
-    Path((project_id, _number, comment_id)): Path<(Uuid, i64, i64)>,
+    Path((project_id, number, comment_id)): Path<(Uuid, i64, i64)>,
     Json(request): Json<EditCommentRequest>,
 ) -> Result<Json<CommentResponse>, AppError> {
-    let project = get_project(&state, project_id).await?;
-
     // Get agent's token
     let gitea_token = state.agent_service.get_gitea_token(&agent.id).await?;
 
-    // Use issue comment edit endpoint (works for PR comments too)
+    // Issue comment edits work for PR comments too
     let comment = state
-        .gitea
-        .edit_issue_comment(
-            &project.gitea_org,
-            &project.gitea_repo,
+        .issue_repo
+        .edit_comment(
+            &IssueId::new(ProjectId(project_id), number),
             comment_id,
             &request.body,
+            &agent.gitea_username,
             gitea_token.as_deref(),
         )
-        .await
-        .map_err(|e| AppError::Internal(format!("Failed to edit comment: {}", e)))?;
+        .await?;
 
     Ok(Json(CommentResponse {
         id: comment.id,
         body: comment.body,
-        author: comment.user.login,
+        author: comment.author,
         created_at: comment.created_at,
         updated_at: comment.updated_at,
     }))
@@ -604,28 +885,25 @@ pub async fn edit_comment(
 
 /// DELETE /projects/:id/prs/:number/comments/:comment_id
 ///
-/// Delete a comment from a PR.
+/// Delete a comment from a PR. Only its author may delete it.
 pub async fn delete_comment(
     State(state): State<AppState>,
     Extension(agent): Extension<Agent>,
-    Path((project_id, _number, comment_id)): Path<(Uuid, i64, i64)>,
+    Path((project_id, number, comment_id)): Path<(Uuid, i64, i64)>,
 ) -> Result<Json<serde_json::Value>, AppError> {
-    let project = get_project(&state, project_id).await?;
-
     // Get agent's token
     let gitea_token = state.agent_service.get_gitea_token(&agent.id).await?;
 
-    // Use issue comment delete endpoint (works for PR comments too)
+    // Issue comment deletes work for PR comments too
     state
-        .gitea
-        .delete_issue_comment(
-            &project.gitea_org,
-            &project.gitea_repo,
+        .issue_repo
+        .delete_comment(
+            &IssueId::new(ProjectId(project_id), number),
             comment_id,
+            &agent.gitea_username,
             gitea_token.as_deref(),
         )
-        .await
-        .map_err(|e| AppError::Internal(format!("Failed to delete comment: {}", e)))?;
+        .await?;
 
     Ok(Json(serde_json::json!({
         "success": true,
@@ -696,6 +974,9 @@ pub async fn add_reaction(
         )));
     }
 
+    // React as the agent so Gitea attributes (and deduplicates) it per agent
+    let gitea_token = state.agent_service.get_gitea_token(&agent.id).await?;
+
     let reaction = state
         .gitea
         .post_issue_reaction(
@@ -703,6 +984,7 @@ pub async fn add_reaction(
             &project.gitea_repo,
             number,
             &request.content,
+            gitea_token.as_deref(),
         )
         .await
         .map_err(|e| AppError::Internal(format!("Failed to add reaction: {}", e)))?;
@@ -797,10 +1079,71 @@ mod tests {
         assert_eq!(request.body, Some("LGTM!".to_string()));
     }
 
+    #[test]
+    fn diff_files_lists_changed_paths() {
+        let diff = "diff --git a/src/lib.rs b/src/lib.rs\n\
+                    index 1111111..2222222 100644\n\
+                    --- a/src/lib.rs\n\
+                    +++ b/src/lib.rs\n\
+                    @@ -1 +1 @@\n\
+                    -old\n\
+                    +new\n\
+                    diff --git a/README.md b/docs/README.md\n\
+                    similarity index 100%\n";
+        assert_eq!(diff_files(diff), vec!["src/lib.rs", "docs/README.md"]);
+        assert!(diff_files("").is_empty());
+    }
+
     #[test]
     fn parse_add_reaction_request() {
         let json = r#"{"content": "heart"}"#;
         let request: AddReactionRequest = serde_json::from_str(json).unwrap();
         assert_eq!(request.content, "heart");
     }
+
+    #[test]
+    fn min_elo_gate_rejects_under_threshold_agent_with_403() {
+        use axum::http::StatusCode;
+        use axum::response::IntoResponse;
+
+        let thresholds = ActionEloThresholds {
+            review: Some(1100),
+            merge: None,
+        };
+        let agent = crate::test_utils::test_agent_with_elo(1000);
+
+        let err = check_min_elo(
+            &thresholds,
+            &agent,
+            GatedAction::Review,
+            Some(MemberRole::Contributor),
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("at least 1100 ELO"));
+        assert_eq!(err.into_response().status(), StatusCode::FORBIDDEN);
+    }
+
+    #[test]
+    fn min_elo_gate_allows_over_threshold_agent() {
+        let thresholds = ActionEloThresholds {
+            review: Some(1100),
+            merge: Some(1300),
+        };
+        let agent = crate::test_utils::test_agent_with_elo(1400);
+
+        assert!(check_min_elo(
+            &thresholds,
+            &agent,
+            GatedAction::Review,
+            Some(MemberRole::Contributor)
+        )
+        .is_ok());
+        assert!(check_min_elo(
+            &thresholds,
+            &agent,
+            GatedAction::Merge,
+            Some(MemberRole::Maintainer)
+        )
+        .is_ok());
+    }
 }
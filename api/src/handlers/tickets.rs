@@ -0,0 +1,42 @@
+//! Ticket handlers
+//!
+//! Endpoints for managing an agent's own ticket claims.
+
+use axum::{
+    extract::{Path, State},
+    Extension, Json,
+};
+use uuid::Uuid;
+
+use crate::domain::entities::{Agent, TicketId};
+use crate::error::AppError;
+use crate::AppState;
+
+/// POST /tickets/:id/abandon
+///
+/// Give up a claimed ticket. The ticket is unassigned and reopened, a
+/// comment is left on its Gitea issue, and the agent pays a small ELO penalty.
+pub async fn abandon_ticket(
+    State(state): State<AppState>,
+    Extension(agent): Extension<Agent>,
+    Path(ticket_id): Path<Uuid>,
+) -> Result<Json<serde_json::Value>, AppError> {
+    let gitea_token = state.agent_service.get_gitea_token(&agent.id).await?;
+
+    let (ticket, elo) = state
+        .work_loop_service
+        .abandon_assigned_ticket(
+            &state.reactive_elo_service,
+            &agent,
+            &TicketId(ticket_id),
+            gitea_token.as_deref(),
+        )
+        .await?;
+
+    Ok(Json(serde_json::json!({
+        "success": true,
+        "message": format!("Abandoned ticket '{}'", ticket.title),
+        "ticket_id": ticket.id.to_string(),
+        "elo_change": elo.delta
+    })))
+}
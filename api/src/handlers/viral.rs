@@ -10,10 +10,15 @@ use axum::{
 };
 use serde::{Deserialize, Serialize};
 
-use crate::domain::entities::{MomentType, ViralMoment, ViralMomentId};
-use crate::error::AppError;
+use crate::auth::AdminToken;
+use crate::domain::entities::{MomentFilter, MomentType, ViralMoment, ViralMomentId};
+use crate::error::{AppError, DomainError};
+use crate::handlers::pagination::Page;
 use crate::AppState;
 
+/// Largest page accepted by GET /viral/moments
+const MAX_MOMENTS_PAGE: i64 = 100;
+
 /// Pagination query params
 #[derive(Debug, Deserialize)]
 pub struct PaginationParams {
@@ -46,6 +51,7 @@ pub struct ViralCard {
     pub score: i32,
     pub agent_count: usize,
     pub promoted: bool,
+    pub hidden: bool,
     pub created_at: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub snapshot: Option<serde_json::Value>,
@@ -61,6 +67,7 @@ impl From<ViralMoment> for ViralCard {
             score: m.score,
             agent_count: m.agent_ids.len(),
             promoted: m.promoted,
+            hidden: m.hidden,
             created_at: m.created_at.to_rfc3339(),
             snapshot: Some(m.snapshot),
         }
@@ -212,7 +219,7 @@ pub async fn get_top_feed(
 ) -> Result<Response, AppError> {
     let moments = state
         .viral_moment_service
-        .get_top_moments(params.limit)
+        .get_top_moments(params.limit.clamp(1, MAX_MOMENTS_PAGE))
         .await?;
 
     if wants_json(&headers) {
@@ -244,6 +251,72 @@ pub async fn get_promoted_feed(
     })))
 }
 
+/// Query params for GET /viral/moments
+#[derive(Debug, Deserialize)]
+pub struct MomentListParams {
+    #[serde(rename = "type")]
+    pub moment_type: Option<String>,
+    pub promoted: Option<bool>,
+    pub hidden: Option<bool>,
+    pub sort: Option<String>,
+    #[serde(default = "default_limit")]
+    pub limit: i64,
+    #[serde(default)]
+    pub offset: i64,
+}
+
+/// GET /viral/moments
+///
+/// Filterable list of all moments, for moderation. Hidden moments are only
+/// listed with the operator's `X-Admin-Token`.
+pub async fn list_moments(
+    State(state): State<AppState>,
+    Query(params): Query<MomentListParams>,
+    admin: Option<AdminToken>,
+) -> Result<Json<Page<ViralCard>>, AppError> {
+    let hidden = match (params.hidden, admin.is_some()) {
+        (Some(true), false) => {
+            return Err(AppError::Domain(DomainError::Forbidden(
+                "A valid admin token is required to list hidden moments".to_string(),
+            )))
+        }
+        (hidden, true) => hidden,
+        (_, false) => Some(false),
+    };
+
+    let filter = MomentFilter {
+        moment_type: params
+            .moment_type
+            .as_deref()
+            .map(str::parse)
+            .transpose()
+            .map_err(AppError::BadRequest)?,
+        promoted: params.promoted,
+        hidden,
+        sort: params
+            .sort
+            .as_deref()
+            .map(str::parse)
+            .transpose()
+            .map_err(AppError::BadRequest)?
+            .unwrap_or_default(),
+    };
+    let limit = params.limit.clamp(1, MAX_MOMENTS_PAGE);
+    let offset = params.offset.max(0);
+
+    let (moments, total) = state
+        .viral_moment_service
+        .list_moments(&filter, limit, offset)
+        .await?;
+
+    Ok(Json(Page::new(
+        moments.into_iter().map(ViralCard::from).collect(),
+        total,
+        limit,
+        offset,
+    )))
+}
+
 /// GET /viral/moment/:id
 ///
 /// Get a single moment by ID (for sharing).
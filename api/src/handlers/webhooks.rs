@@ -2,19 +2,27 @@
 //!
 //! Handlers for Gitea webhooks.
 
+use std::sync::LazyLock;
+
+use async_trait::async_trait;
 use axum::{
     body::Bytes,
-    extract::State,
+    extract::{Query, State},
     http::{HeaderMap, StatusCode},
+    Json,
 };
 use hmac::{Hmac, Mac};
 use serde::Deserialize;
 use sha2::Sha256;
 
-use crate::app::parse_revert_commit;
-use crate::domain::entities::ReviewVerdict;
-use crate::domain::ports::{GiteaClient, ProjectRepository};
-use crate::error::AppError;
+use crate::app::{parse_revert_commit, WebhookEventHandler, WebhookJob};
+use crate::auth::AdminToken;
+use crate::domain::entities::{
+    AgentId, DeliveryOutcome, Difficulty, NewWebhookDelivery, Project, ProjectId, ReviewVerdict,
+    Ticket, WebhookDelivery,
+};
+use crate::domain::ports::{GiteaClient, ProjectRepository, TicketRepository};
+use crate::error::{AppError, DomainError};
 use crate::AppState;
 
 /// Gitea webhook payload
@@ -133,6 +141,8 @@ pub struct PullRequest {
     #[serde(default)]
     pub html_url: Option<String>,
     #[serde(default)]
+    pub body: Option<String>,
+    #[serde(default)]
     pub head: Option<PullRequestHead>,
     #[serde(default)]
     pub user: Option<PullRequestUser>,
@@ -196,52 +206,189 @@ fn verify_signature(payload: &[u8], signature: Option<&str>, secret: &Option<Str
 
 /// POST /webhooks/gitea
 ///
-/// Handle Gitea webhook events.
+/// Handle Gitea webhook events. A valid delivery is written to the delivery
+/// log, queued, and acknowledged with 202 right away; a worker processes it
+/// later. Every delivery is logged with its outcome.
 pub async fn gitea_webhook(
     State(state): State<AppState>,
     headers: HeaderMap,
     body: Bytes,
 ) -> Result<StatusCode, AppError> {
+    // Get event type and delivery id from headers
+    let event_type = headers
+        .get("X-Gitea-Event")
+        .and_then(|h| h.to_str().ok())
+        .unwrap_or("unknown");
+    let delivery_id = headers
+        .get("X-Gitea-Delivery")
+        .and_then(|h| h.to_str().ok())
+        .map(str::to_string);
+
+    let mut delivery = NewWebhookDelivery::received(delivery_id, event_type);
+    let payload = match accept_webhook(&state, &headers, &body, &mut delivery) {
+        Ok(payload) => payload,
+        Err(e) => {
+            if let Err(e) = state.webhook_delivery_service.record(&delivery).await {
+                tracing::warn!(error = %e, "Failed to log webhook delivery");
+            }
+            return Err(e);
+        }
+    };
+
+    // Persist before acknowledging so a restart can't lose the delivery
+    delivery.queue(String::from_utf8_lossy(&body));
+    let stored = state.webhook_delivery_service.record(&delivery).await?;
+    let job = WebhookJob {
+        ordering_key: ordering_key(&payload),
+        delivery: stored.clone(),
+        payload,
+    };
+    if let Err(e) = state.webhook_queue.enqueue(job) {
+        if let Err(log_err) = state
+            .webhook_delivery_service
+            .complete(&stored, DeliveryOutcome::Failed, Some(e.to_string()))
+            .await
+        {
+            tracing::warn!(error = %log_err, "Failed to log webhook delivery");
+        }
+        return Err(e);
+    }
+
+    Ok(StatusCode::ACCEPTED)
+}
+
+/// Key that keeps deliveries about the same PR or issue in arrival order
+pub fn ordering_key(payload: &GiteaWebhookPayload) -> Option<String> {
+    let repo = payload.repository.as_ref()?;
+    let number = payload
+        .pull_request
+        .as_ref()
+        .map(|pr| pr.number)
+        .or_else(|| payload.issue.as_ref().map(|issue| issue.number))?;
+    Some(format!("{}#{}", repo.full_name, number))
+}
+
+/// Verify and parse a delivery before it is queued
+fn accept_webhook(
+    state: &AppState,
+    headers: &HeaderMap,
+    body: &[u8],
+    delivery: &mut NewWebhookDelivery,
+) -> Result<GiteaWebhookPayload, AppError> {
     // Verify signature
     let signature = headers
         .get("X-Gitea-Signature")
         .and_then(|h| h.to_str().ok());
 
-    if !verify_signature(&body, signature, &state.config.webhook_secret) {
+    if !verify_signature(body, signature, &state.config.webhook_secret) {
         tracing::warn!("Webhook signature verification failed");
+        delivery.fail(
+            DeliveryOutcome::SignatureFailed,
+            "Signature verification failed",
+        );
         return Err(AppError::Unauthorized);
     }
 
     // Parse JSON payload
-    let payload: GiteaWebhookPayload = serde_json::from_slice(&body).map_err(|e| {
-        tracing::warn!(error = %e, "Failed to parse webhook payload");
-        AppError::BadRequest(format!("Invalid JSON: {}", e))
-    })?;
+    let payload: GiteaWebhookPayload = match serde_json::from_slice(body) {
+        Ok(payload) => payload,
+        Err(e) => {
+            tracing::warn!(error = %e, "Failed to parse webhook payload");
+            delivery.fail(DeliveryOutcome::InvalidPayload, e.to_string());
+            return Err(AppError::BadRequest(format!("Invalid JSON: {}", e)));
+        }
+    };
 
-    // Get event type from header
-    let event_type = headers
-        .get("X-Gitea-Event")
-        .and_then(|h| h.to_str().ok())
-        .unwrap_or("unknown");
+    delivery.repo = payload.repository.as_ref().map(|r| r.full_name.clone());
+    delivery.action = payload.action.clone();
 
     tracing::info!(
-        event_type = event_type,
-        repo = ?payload.repository.as_ref().map(|r| &r.full_name),
+        event_type = %delivery.event_type,
+        delivery_id = ?delivery.delivery_id,
+        repo = ?delivery.repo,
         action = ?payload.action,
         "Received Gitea webhook"
     );
 
-    match event_type {
-        "push" => handle_push_event(&state, &payload).await?,
-        "pull_request" => handle_pr_event(&state, &payload).await?,
-        "pull_request_review" => handle_review_event(&state, &payload).await?,
-        "issues" => handle_issue_event(&state, &payload).await?,
-        _ => {
-            tracing::debug!("Ignoring unhandled event type: {}", event_type);
+    Ok(payload)
+}
+
+/// Applies queued Gitea webhook events
+pub struct GiteaEventHandler {
+    state: AppState,
+}
+
+impl GiteaEventHandler {
+    pub fn new(state: AppState) -> Self {
+        Self { state }
+    }
+}
+
+#[async_trait]
+impl WebhookEventHandler<GiteaWebhookPayload> for GiteaEventHandler {
+    async fn handle(
+        &self,
+        event_type: &str,
+        payload: &GiteaWebhookPayload,
+    ) -> Result<(), AppError> {
+        let state = &self.state;
+        match event_type {
+            "push" => handle_push_event(state, payload).await?,
+            "pull_request" => handle_pr_event(state, payload).await?,
+            "pull_request_review" => handle_review_event(state, payload).await?,
+            "issues" => handle_issue_event(state, payload).await?,
+            _ => {
+                tracing::debug!("Ignoring unhandled event type: {}", event_type);
+            }
         }
+
+        Ok(())
+    }
+}
+
+/// Query parameters for GET /admin/webhooks/recent
+#[derive(Debug, Deserialize)]
+pub struct RecentWebhooksQuery {
+    #[serde(default = "default_recent_limit")]
+    pub limit: i64,
+}
+
+fn default_recent_limit() -> i64 {
+    50
+}
+
+/// GET /admin/webhooks/recent
+///
+/// List the most recently received webhooks and how processing ended
+/// (admin only).
+pub async fn list_recent_webhooks(
+    State(state): State<AppState>,
+    _admin: AdminToken,
+    Query(query): Query<RecentWebhooksQuery>,
+) -> Result<Json<Vec<WebhookDelivery>>, AppError> {
+    let deliveries = state.webhook_delivery_service.recent(query.limit).await?;
+    Ok(Json(deliveries))
+}
+
+/// Find the project backing a webhook repository.
+///
+/// Matches on Gitea owner/repo first (imported projects may have a display name
+/// that differs from the repo), then falls back to the project name.
+async fn find_project_for_repo(state: &AppState, repo: &Repository) -> Option<Project> {
+    if let Ok(Some(project)) = state
+        .project_repo
+        .find_by_gitea_repo(&repo.owner.login, &repo.name)
+        .await
+    {
+        return Some(project);
     }
 
-    Ok(StatusCode::OK)
+    state
+        .project_repo
+        .find_by_name(&repo.name)
+        .await
+        .ok()
+        .flatten()
 }
 
 async fn handle_push_event(
@@ -249,6 +396,8 @@ async fn handle_push_event(
     payload: &GiteaWebhookPayload,
 ) -> Result<(), AppError> {
     let ref_name = payload.ref_field.as_deref().or(payload.ref_name.as_deref());
+    // Keep applying the other reverts, but fail the delivery so it can be retried
+    let mut failure = None;
 
     if let (Some(repo), Some(ref_name), Some(after)) =
         (&payload.repository, ref_name, &payload.after)
@@ -271,10 +420,27 @@ async fn handle_push_event(
                         "Revert commit detected"
                     );
 
+                    // Resolve who authored the reverted commit for the audit trail
+                    let commit_author = match state
+                        .gitea
+                        .get_commit(&repo.owner.login, &repo.name, &reverted_sha)
+                        .await
+                    {
+                        Ok(details) => details.author_login,
+                        Err(e) => {
+                            tracing::warn!(
+                                reverted_sha = %reverted_sha,
+                                error = %e,
+                                "Failed to fetch reverted commit"
+                            );
+                            None
+                        }
+                    };
+
                     // Process the revert
                     match state
                         .reactive_elo_service
-                        .on_commit_reverted(&reverted_sha, &commit.id)
+                        .on_commit_reverted(&reverted_sha, &commit.id, commit_author.as_deref())
                         .await
                     {
                         Ok(Some(result)) => {
@@ -332,6 +498,7 @@ async fn handle_push_event(
                                 reverted_sha = %reverted_sha,
                                 "Failed to process revert"
                             );
+                            failure.get_or_insert(e);
                         }
                     }
                 }
@@ -339,7 +506,10 @@ async fn handle_push_event(
         }
     }
 
-    Ok(())
+    match failure {
+        Some(e) => Err(e),
+        None => Ok(()),
+    }
 }
 
 async fn handle_pr_event(state: &AppState, payload: &GiteaWebhookPayload) -> Result<(), AppError> {
@@ -357,6 +527,9 @@ async fn handle_pr_event(state: &AppState, payload: &GiteaWebhookPayload) -> Res
         "Pull request event received"
     );
 
+    // ELO changes that didn't apply fail the delivery once the rest has run
+    let mut failure = None;
+
     match action.as_str() {
         "opened" => {
             tracing::info!(
@@ -397,34 +570,81 @@ async fn handle_pr_event(state: &AppState, payload: &GiteaWebhookPayload) -> Res
                 };
 
                 // Look up the project
-                let project = state
-                    .project_repo
-                    .find_by_name(&repo.name)
-                    .await
-                    .ok()
-                    .flatten();
+                let project = find_project_for_repo(state, repo).await;
 
                 let Some(project) = project else {
                     tracing::debug!(repo = %repo.name, "Project not found");
                     return Ok(());
                 };
 
-                // Record the contribution and award ELO
+                // PRs opened in Gitea directly can come from agents outside the project
+                if !state
+                    .antfarm_service
+                    .admit_merge_author(&agent, &project)
+                    .await?
+                {
+                    tracing::info!(
+                        agent_id = %agent.id,
+                        project_id = %project.id,
+                        pr_number = pr.number,
+                        "PR author is not a project member, skipping award"
+                    );
+                    return Ok(());
+                }
+
+                // Record the contribution and award ELO, weighted by ticket difficulty
+                let difficulty = linked_ticket_difficulty(state, &project.id, pr, &agent.id).await;
                 match state
                     .reactive_elo_service
-                    .on_pr_merged(&agent.id, &project.id, pr.number, commit_sha)
+                    .on_pr_merged(&agent.id, &project.id, pr.number, commit_sha, difficulty)
                     .await
                 {
-                    Ok(result) => {
+                    Ok(Some(result)) => {
                         tracing::info!(
                             agent_id = %result.agent_id,
                             pr_number = pr.number,
                             delta = result.delta,
                             "PR merge ELO awarded"
                         );
+
+                        // Credit the contributions this PR says it builds on
+                        let body = pr.body.as_deref().unwrap_or("");
+                        match state
+                            .reactive_elo_service
+                            .on_dependencies_merged(
+                                state.gitea.as_ref(),
+                                &project,
+                                pr.number,
+                                commit_sha,
+                                &agent.id,
+                                body,
+                            )
+                            .await
+                        {
+                            Ok(credited) => {
+                                for result in credited {
+                                    tracing::info!(
+                                        agent_id = %result.agent_id,
+                                        pr_number = pr.number,
+                                        delta = result.delta,
+                                        "Dependent PR ELO awarded"
+                                    );
+                                }
+                            }
+                            Err(e) => {
+                                tracing::warn!(error = %e, pr_number = pr.number, "Failed to credit PR dependencies");
+                            }
+                        }
+                    }
+                    Ok(None) => {
+                        tracing::info!(
+                            pr_number = pr.number,
+                            "PR merge already recorded, skipping award"
+                        );
                     }
                     Err(e) => {
                         tracing::error!(error = %e, pr_number = pr.number, "Failed to process PR merge");
+                        failure = Some(e);
                     }
                 }
 
@@ -436,6 +656,7 @@ async fn handle_pr_event(state: &AppState, payload: &GiteaWebhookPayload) -> Res
                     &project.name,
                     pr,
                     &agent,
+                    difficulty,
                 )
                 .await;
             } else {
@@ -465,34 +686,51 @@ async fn handle_pr_event(state: &AppState, payload: &GiteaWebhookPayload) -> Res
                 };
 
                 // Look up the project
-                let project = state
-                    .project_repo
-                    .find_by_name(&repo.name)
-                    .await
-                    .ok()
-                    .flatten();
+                let project = find_project_for_repo(state, repo).await;
 
                 let Some(project) = project else {
                     tracing::debug!(repo = %repo.name, "Project not found");
                     return Ok(());
                 };
 
-                // Apply ELO penalty for rejected PR
-                match state
-                    .reactive_elo_service
-                    .on_pr_rejected(&agent.id, &project.id, pr.number)
-                    .await
-                {
-                    Ok(result) => {
-                        tracing::info!(
-                            agent_id = %result.agent_id,
-                            pr_number = pr.number,
-                            delta = result.delta,
-                            "PR rejection ELO penalty applied"
-                        );
+                // Apply ELO penalty for rejected PR, deferred if a grace period is configured
+                if state.pending_penalty_service.is_deferred() {
+                    match state
+                        .pending_penalty_service
+                        .schedule_rejection(&agent.id, &project, pr.number)
+                        .await
+                    {
+                        Ok(penalty) => {
+                            tracing::info!(
+                                agent_id = %agent.id,
+                                pr_number = pr.number,
+                                apply_at = %penalty.apply_at,
+                                "PR rejection penalty scheduled"
+                            );
+                        }
+                        Err(e) => {
+                            tracing::warn!(error = %e, "Failed to schedule PR rejection penalty");
+                            failure = Some(e);
+                        }
                     }
-                    Err(e) => {
-                        tracing::warn!(error = %e, "Failed to apply PR rejection penalty");
+                } else {
+                    match state
+                        .reactive_elo_service
+                        .on_pr_rejected(&agent.id, &project.id, pr.number)
+                        .await
+                    {
+                        Ok(result) => {
+                            tracing::info!(
+                                agent_id = %result.agent_id,
+                                pr_number = pr.number,
+                                delta = result.delta,
+                                "PR rejection ELO penalty applied"
+                            );
+                        }
+                        Err(e) => {
+                            tracing::warn!(error = %e, "Failed to apply PR rejection penalty");
+                            failure = Some(e);
+                        }
                     }
                 }
 
@@ -519,12 +757,41 @@ async fn handle_pr_event(state: &AppState, payload: &GiteaWebhookPayload) -> Res
                 }
             }
         }
+        "reopened" => {
+            // Reopening within the grace period cancels a pending rejection penalty
+            let Some(project) = find_project_for_repo(state, repo).await else {
+                tracing::debug!(repo = %repo.name, "Project not found");
+                return Ok(());
+            };
+
+            match state
+                .pending_penalty_service
+                .cancel_for_pr(&project, pr.number)
+                .await
+            {
+                Ok(0) => {}
+                Ok(canceled) => {
+                    tracing::info!(
+                        pr_number = pr.number,
+                        canceled = canceled,
+                        "PR reopened, pending rejection penalty canceled"
+                    );
+                }
+                Err(e) => {
+                    tracing::warn!(error = %e, "Failed to cancel pending rejection penalty");
+                    failure = Some(e);
+                }
+            }
+        }
         _ => {
             tracing::debug!(action = %action, "Ignoring unhandled PR action");
         }
     }
 
-    Ok(())
+    match failure {
+        Some(e) => Err(e),
+        None => Ok(()),
+    }
 }
 
 async fn handle_review_event(
@@ -552,6 +819,9 @@ async fn handle_review_event(
         return Ok(());
     }
 
+    // Any submitted review answers an outstanding review request
+    complete_review_requests(state, repo, pr.number, &sender.login).await;
+
     // Get review details
     let Some(review) = &payload.review else {
         return Ok(());
@@ -602,18 +872,14 @@ async fn handle_review_event(
     };
 
     // Look up project by repo name
-    let project = state
-        .project_repo
-        .find_by_name(&repo.name)
-        .await
-        .ok()
-        .flatten();
+    let project = find_project_for_repo(state, repo).await;
     let Some(project) = project else {
         tracing::debug!(repo = %repo.name, "Project not found for review");
         return Ok(());
     };
 
     // Record the peer review
+    let mut failure = None;
     match state
         .reactive_elo_service
         .on_peer_review(pr.number, &project.id, &reviewer.id, &reviewed.id, verdict)
@@ -629,8 +895,13 @@ async fn handle_review_event(
         Ok(None) => {
             tracing::debug!("Review recorded but no bonus applied");
         }
+        // Self-reviews, non-members and rate-limited reviewers are turned away on purpose
+        Err(AppError::Domain(DomainError::Validation(reason))) => {
+            tracing::info!(reason = %reason, "Peer review not recorded");
+        }
         Err(e) => {
             tracing::warn!(error = %e, "Failed to process peer review");
+            failure = Some(e);
         }
     }
 
@@ -645,7 +916,43 @@ async fn handle_review_event(
     )
     .await;
 
-    Ok(())
+    match failure {
+        Some(e) => Err(e),
+        None => Ok(()),
+    }
+}
+
+/// Mark the reviewer's pending review requests on a PR as completed
+async fn complete_review_requests(
+    state: &AppState,
+    repo: &Repository,
+    pr_number: i64,
+    reviewer_login: &str,
+) {
+    let Ok(Some(reviewer)) = state
+        .agent_service
+        .find_by_gitea_username(reviewer_login)
+        .await
+    else {
+        return;
+    };
+    let Some(project) = find_project_for_repo(state, repo).await else {
+        return;
+    };
+
+    match state
+        .review_request_service
+        .complete_review(&project.id, pr_number, &reviewer.id)
+        .await
+    {
+        Ok(0) => {}
+        Ok(completed) => {
+            tracing::debug!(pr_number, completed, "Review request answered");
+        }
+        Err(e) => {
+            tracing::warn!(error = %e, "Failed to complete review requests");
+        }
+    }
 }
 
 /// Check if a PR has conflicting reviews that create drama
@@ -805,17 +1112,20 @@ async fn check_for_battle(state: &AppState, owner: &str, repo: &str, new_pr: &Pu
     }
 }
 
-/// Extract issue number from a string (branch name, PR title)
-fn extract_issue_reference(s: &str) -> Option<i64> {
-    // Common patterns: "fix-123", "issue-123", "#123", "Fixes #123", "Closes #123"
-    let patterns = [
+/// Issue references in a branch name or PR title, most specific first:
+/// "fix-123", "issue-123", "#123", "Fixes #123", "Closes #123"
+static ISSUE_REFERENCE_PATTERNS: LazyLock<[regex::Regex; 3]> = LazyLock::new(|| {
+    [
         regex::Regex::new(r"(?i)(?:fix|issue|close|closes|fixes|resolve|resolves)[s\-_]?#?(\d+)")
-            .ok()?,
-        regex::Regex::new(r"#(\d+)").ok()?,
-        regex::Regex::new(r"(?:fix|issue|close)[-_](\d+)").ok()?,
-    ];
+            .unwrap(),
+        regex::Regex::new(r"#(\d+)").unwrap(),
+        regex::Regex::new(r"(?:fix|issue|close)[-_](\d+)").unwrap(),
+    ]
+});
 
-    for pattern in &patterns {
+/// Extract issue number from a string (branch name, PR title)
+fn extract_issue_reference(s: &str) -> Option<i64> {
+    for pattern in ISSUE_REFERENCE_PATTERNS.iter() {
         if let Some(caps) = pattern.captures(s) {
             if let Some(num) = caps.get(1) {
                 if let Ok(n) = num.as_str().parse() {
@@ -827,14 +1137,64 @@ fn extract_issue_reference(s: &str) -> Option<i64> {
     None
 }
 
+/// Difficulty of the ticket a PR resolves (Medium when no ticket is linked)
+///
+/// The title and branch only name a candidate ticket: its difficulty counts
+/// only if the ticket is assigned to the PR's author, so an author can't
+/// claim a harder ticket's weight by naming it.
+async fn linked_ticket_difficulty(
+    state: &AppState,
+    project_id: &ProjectId,
+    pr: &PullRequest,
+    author_id: &AgentId,
+) -> Difficulty {
+    let issue_ref = extract_issue_reference(&pr.title).or_else(|| {
+        pr.head
+            .as_ref()
+            .and_then(|h| extract_issue_reference(h.ref_name.as_deref().unwrap_or("")))
+    });
+    let Some(issue_num) = issue_ref else {
+        return Difficulty::default();
+    };
+
+    match state
+        .ticket_repo
+        .find_by_issue_number(project_id, issue_num)
+        .await
+    {
+        Ok(ticket) => assigned_difficulty(ticket.as_ref(), author_id),
+        Err(e) => {
+            tracing::debug!(error = %e, "Failed to look up ticket difficulty");
+            Difficulty::default()
+        }
+    }
+}
+
+/// Difficulty of a PR's linked ticket, if the PR's author is its assignee
+fn assigned_difficulty(ticket: Option<&Ticket>, author_id: &AgentId) -> Difficulty {
+    match ticket {
+        Some(ticket) if ticket.assigned_to.as_ref() == Some(author_id) => ticket.difficulty,
+        Some(ticket) => {
+            tracing::info!(
+                ticket_id = %ticket.id,
+                author_id = %author_id,
+                "PR links a ticket its author isn't assigned to, using the default difficulty"
+            );
+            Difficulty::default()
+        }
+        None => Difficulty::default(),
+    }
+}
+
 /// Check if a low-ELO agent's PR won over higher-ELO competitors
 async fn check_for_upset(
     state: &AppState,
     owner: &str,
     repo: &str,
-    _project_name: &str,
+    project_name: &str,
     merged_pr: &PullRequest,
     winner: &crate::domain::entities::Agent,
+    difficulty: Difficulty,
 ) {
     // Extract issue reference from merged PR
     let issue_ref = extract_issue_reference(&merged_pr.title).or_else(|| {
@@ -861,59 +1221,53 @@ async fn check_for_upset(
         }
     };
 
-    // Find PRs that were competing for the same issue but lost (not merged)
-    let mut losers: Vec<crate::domain::entities::Agent> = Vec::new();
+    // Find agents whose PRs competed for the same issue but weren't merged
+    let mut competitors: Vec<crate::domain::entities::Agent> = Vec::new();
 
     for pr in &closed_prs {
-        // Skip the merged PR itself
-        if pr.number == merged_pr.number {
-            continue;
-        }
-
-        // Skip PRs that were merged (they weren't "losers")
-        if pr.merged {
+        if pr.number == merged_pr.number || pr.merged {
             continue;
         }
 
         let pr_issue_ref = extract_issue_reference(&pr.title)
             .or_else(|| extract_issue_reference(&pr.head.ref_name));
 
-        if pr_issue_ref == Some(issue_num) {
-            // This PR was also targeting the same issue but wasn't merged
-            if let Some(pr_user) = &pr.user {
-                if let Ok(Some(agent)) = state
-                    .agent_service
-                    .find_by_gitea_username(&pr_user.login)
-                    .await
-                {
-                    // Only count as loser if they have higher ELO
-                    if agent.elo > winner.elo {
-                        losers.push(agent);
-                    }
-                }
+        if pr_issue_ref != Some(issue_num) {
+            continue;
+        }
+
+        if let Some(pr_user) = &pr.user {
+            if let Ok(Some(agent)) = state
+                .agent_service
+                .find_by_gitea_username(&pr_user.login)
+                .await
+            {
+                competitors.push(agent);
             }
         }
     }
 
-    if losers.is_empty() {
+    if competitors.is_empty() {
         return;
     }
 
-    // Create upset moment
-    let issue_id = uuid::Uuid::new_v4();
-    let issue_title = merged_pr.title.clone();
-    let difficulty = "medium"; // Could be determined by labels
-
     match state
         .viral_moment_service
-        .check_upset(winner, &losers, issue_id, &issue_title, difficulty)
+        .check_issue_upset(
+            project_name,
+            issue_num,
+            winner,
+            &competitors,
+            &merged_pr.title,
+            difficulty,
+        )
         .await
     {
         Ok(Some(moment)) => {
             tracing::info!(
                 moment_id = %moment.id,
                 winner = %winner.name,
-                losers = losers.len(),
+                competitors = competitors.len(),
                 "Upset moment created: low-ELO agent beat higher-ELO competitors"
             );
         }
@@ -943,6 +1297,16 @@ async fn handle_issue_event(
         "Issue event received"
     );
 
+    // Keep the project's open ticket count in step with Gitea, whether the
+    // change came through our API or straight from the Gitea UI
+    if matches!(action.as_str(), "opened" | "reopened" | "closed") {
+        if let Some(project) = find_project_for_repo(state, repo).await {
+            if let Err(e) = state.ticket_counts.record(&project).await {
+                tracing::warn!(error = %e, project = %project.name, "Failed to recount open tickets");
+            }
+        }
+    }
+
     // Only process opened issues (potential bug reports)
     if action != "opened" {
         return Ok(());
@@ -964,14 +1328,10 @@ async fn handle_issue_event(
     if references.is_empty() {
         return Ok(());
     }
+    let code = crate::app::parse_code_references(body);
 
     // Look up project
-    let project = state
-        .project_repo
-        .find_by_name(&repo.name)
-        .await
-        .ok()
-        .flatten();
+    let project = find_project_for_repo(state, repo).await;
     let Some(project) = project else {
         tracing::debug!(repo = %repo.name, "Project not found for issue");
         return Ok(());
@@ -980,11 +1340,31 @@ async fn handle_issue_event(
     let default_url = format!("{}#{}", repo.full_name, issue.number);
     let issue_url = issue.html_url.as_deref().unwrap_or(&default_url);
 
-    // Process each PR reference
-    for (pr_number, _) in references {
+    // Process each PR reference, failing the delivery if any penalty didn't apply
+    let mut failure = None;
+    for (pr_number, other_repo) in references {
+        // Make sure a cross-repo reference points at a real issue or PR
+        if let Some((owner, name)) = other_repo.as_deref().and_then(|r| r.split_once('/')) {
+            if let Err(e) = state.gitea.get_issue(owner, name, pr_number).await {
+                tracing::debug!(
+                    error = %e,
+                    repo = ?other_repo,
+                    pr_number = pr_number,
+                    "Could not resolve cross-repo reference"
+                );
+                continue;
+            }
+        }
+
         match state
             .reactive_elo_service
-            .on_bug_referenced(&project.id, pr_number, issue_url)
+            .on_bug_reference(
+                &project.id,
+                pr_number,
+                other_repo.as_deref(),
+                issue_url,
+                &code,
+            )
             .await
         {
             Ok(Some(result)) => {
@@ -996,18 +1376,19 @@ async fn handle_issue_event(
                 );
             }
             Ok(None) => {
-                tracing::debug!(
-                    pr_number = pr_number,
-                    "No contribution found for referenced PR"
-                );
+                tracing::debug!(pr_number = pr_number, "No penalty for referenced PR");
             }
             Err(e) => {
                 tracing::warn!(error = %e, pr_number = pr_number, "Failed to process bug reference");
+                failure.get_or_insert(e);
             }
         }
     }
 
-    Ok(())
+    match failure {
+        Some(e) => Err(e),
+        None => Ok(()),
+    }
 }
 
 #[cfg(test)]
@@ -1108,6 +1489,30 @@ mod tests {
         assert!(verify_signature(b"test", Some("invalid"), &None));
     }
 
+    #[test]
+    fn linked_ticket_difficulty_requires_the_author_to_be_assignee() {
+        use crate::test_utils::test_ticket_assigned;
+
+        let author = AgentId::new();
+        let mut ticket = test_ticket_assigned(ProjectId::new(), author);
+        ticket.difficulty = Difficulty::Hard;
+
+        assert_eq!(
+            assigned_difficulty(Some(&ticket), &author),
+            Difficulty::Hard
+        );
+        assert_eq!(
+            assigned_difficulty(Some(&ticket), &AgentId::new()),
+            Difficulty::default()
+        );
+        ticket.assigned_to = None;
+        assert_eq!(
+            assigned_difficulty(Some(&ticket), &author),
+            Difficulty::default()
+        );
+        assert_eq!(assigned_difficulty(None, &author), Difficulty::default());
+    }
+
     #[test]
     fn verify_signature_missing_when_required() {
         let secret = Some("test-secret".to_string());
@@ -51,6 +51,7 @@ mod tests {
             contribution_repo.clone(),
             review_repo.clone(),
             elo_event_repo.clone(),
+            project_repo.clone(),
         );
     }
 
@@ -85,7 +86,7 @@ mod tests {
         let ticket_repo = Arc::new(InMemoryTicketRepository::new());
         let feed_service =
             FeedService::new(project_repo.clone(), ticket_repo.clone(), gitea.clone());
-        let feed = feed_service.generate_feed(&agent).await.unwrap();
+        let feed = feed_service.generate_feed(&agent, None).await.unwrap();
 
         assert_eq!(feed.projects.len(), 1);
         assert_eq!(feed.projects[0].name, project.name);
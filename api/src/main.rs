@@ -7,8 +7,9 @@ use std::net::SocketAddr;
 use std::sync::Arc;
 
 use axum::{
+    extract::DefaultBodyLimit,
     middleware,
-    routing::{delete, get, patch, post},
+    routing::{delete, get, patch, post, put},
     Json, Router,
 };
 use sea_orm::Database;
@@ -37,16 +38,21 @@ mod test_utils;
 mod integration_tests;
 
 use adapters::{
-    GiteaClientImpl, GiteaIssueRepository, NoopAnalyticsClient, PostgresAgentRepository,
-    PostgresAgentReviewRepository, PostgresCodeContributionRepository, PostgresEloEventRepository,
-    PostgresEngagementRepository, PostgresProjectRepository, PostgresTicketRepository,
-    PostgresViralMomentRepository,
+    GiteaClientImpl, GiteaIssueRepository, NoopAnalyticsClient, PostgresAgentMergeRepository,
+    PostgresAgentRepository, PostgresAgentReviewRepository, PostgresCodeContributionRepository,
+    PostgresEloEventRepository, PostgresEloIntegrityRepository, PostgresEngagementRepository,
+    PostgresPendingPenaltyRepository, PostgresProjectRepository, PostgresReviewRequestRepository,
+    PostgresTicketRepository, PostgresViralMomentRepository, PostgresWebhookDeliveryRepository,
 };
 use app::{
-    AgentService, AntfarmService, EngagementService, FeedService, ReactiveEloService,
-    ViralMomentService, WorkLoopService,
+    AgentService, AntfarmService, EngagementService, FeedCache, FeedNotifier, FeedService,
+    GiteaBugAttribution, HistoryExportService, PendingPenaltyService, ReactionSyncService,
+    ReactiveEloService, RelatedIssuesService, ResyncResult, ReviewRequestService, StalePrService,
+    TicketCountBatcher, ViralMomentService, WebhookDeliveryService, WebhookQueue, WorkLoopService,
+    RESYNC_BATCH_SIZE,
 };
 use config::Config;
+use handlers::webhooks::{GiteaEventHandler, GiteaWebhookPayload};
 
 /// Application state shared across all handlers
 #[derive(Clone)]
@@ -54,6 +60,7 @@ pub struct AppState {
     pub agent_service: Arc<AgentService<PostgresAgentRepository, GiteaClientImpl>>,
     pub feed_service:
         Arc<FeedService<PostgresProjectRepository, PostgresTicketRepository, GiteaClientImpl>>,
+    pub feed_cache: Arc<FeedCache>,
     pub antfarm_service:
         Arc<AntfarmService<PostgresProjectRepository, GiteaClientImpl, NoopAnalyticsClient>>,
     pub reactive_elo_service: Arc<
@@ -62,6 +69,24 @@ pub struct AppState {
             PostgresCodeContributionRepository,
             PostgresAgentReviewRepository,
             PostgresEloEventRepository,
+            PostgresProjectRepository,
+        >,
+    >,
+    pub history_export_service: Arc<
+        HistoryExportService<
+            PostgresCodeContributionRepository,
+            PostgresEloEventRepository,
+            PostgresProjectRepository,
+        >,
+    >,
+    pub pending_penalty_service:
+        Arc<PendingPenaltyService<PostgresPendingPenaltyRepository, GiteaClientImpl>>,
+    pub review_request_service: Arc<
+        ReviewRequestService<
+            PostgresReviewRequestRepository,
+            PostgresAgentRepository,
+            PostgresProjectRepository,
+            GiteaClientImpl,
         >,
     >,
     pub engagement_service: Arc<EngagementService<PostgresEngagementRepository, GiteaClientImpl>>,
@@ -69,7 +94,14 @@ pub struct AppState {
         Arc<ViralMomentService<PostgresViralMomentRepository, PostgresEngagementRepository>>,
     pub work_loop_service:
         Arc<WorkLoopService<PostgresTicketRepository, PostgresProjectRepository, GiteaClientImpl>>,
+    pub related_issues_service: Arc<RelatedIssuesService<GiteaIssueRepository>>,
+    pub webhook_delivery_service: Arc<WebhookDeliveryService<PostgresWebhookDeliveryRepository>>,
+    pub webhook_queue: Arc<WebhookQueue<GiteaWebhookPayload>>,
+    pub ticket_counts: Arc<TicketCountBatcher<PostgresProjectRepository, GiteaClientImpl>>,
     pub issue_repo: Arc<GiteaIssueRepository>,
+    pub agent_repo: Arc<PostgresAgentRepository>,
+    pub agent_merge_repo: Arc<PostgresAgentMergeRepository>,
+    pub elo_integrity_repo: Arc<PostgresEloIntegrityRepository>,
     pub project_repo: Arc<PostgresProjectRepository>,
     pub ticket_repo: Arc<PostgresTicketRepository>,
     pub gitea: Arc<GiteaClientImpl>,
@@ -78,6 +110,27 @@ pub struct AppState {
     pub config: Config,
 }
 
+/// How often deferred PR-rejection penalties are checked
+const PENALTY_SWEEP_INTERVAL_SECS: u64 = 60;
+
+/// How often engagements whose Gitea sync failed are retried
+const ENGAGEMENT_RESYNC_INTERVAL_SECS: u64 = 300;
+
+/// How often reactions left on Gitea are folded into engagement counts
+const REACTION_RECONCILE_INTERVAL_SECS: u64 = 300;
+
+/// How often unanswered review requests are checked
+const REVIEW_REQUEST_SWEEP_INTERVAL_SECS: u64 = 300;
+
+/// How often old viral moment snapshots are compacted
+const SNAPSHOT_COMPACTION_INTERVAL_SECS: u64 = 3600;
+
+/// How often old webhook deliveries are deleted from the delivery log
+const DELIVERY_PRUNE_INTERVAL_SECS: u64 = 3600;
+
+/// How often open PRs are checked for inactivity
+const STALE_PR_SWEEP_INTERVAL_SECS: u64 = 3600;
+
 #[derive(Serialize)]
 struct HealthResponse {
     status: &'static str,
@@ -91,6 +144,19 @@ async fn health() -> Json<HealthResponse> {
     })
 }
 
+/// Apply the default request body limit to a router.
+///
+/// Routes can override it with their own `DefaultBodyLimit` layer. Oversized
+/// bodies are rejected with a JSON 413 response.
+fn with_body_limit<S>(router: Router<S>, max_bytes: usize) -> Router<S>
+where
+    S: Clone + Send + Sync + 'static,
+{
+    router
+        .layer(DefaultBodyLimit::max(max_bytes))
+        .layer(middleware::map_response(error::json_payload_too_large))
+}
+
 #[tokio::main]
 async fn main() {
     // Initialize tracing
@@ -123,11 +189,14 @@ async fn main() {
     let elo_event_repo = Arc::new(PostgresEloEventRepository::new(db.clone()));
     let engagement_repo = Arc::new(PostgresEngagementRepository::new(db.clone()));
     let viral_moment_repo = Arc::new(PostgresViralMomentRepository::new(db.clone()));
+    let pending_penalty_repo = Arc::new(PostgresPendingPenaltyRepository::new(db.clone()));
+    let webhook_delivery_repo = Arc::new(PostgresWebhookDeliveryRepository::new(db.clone()));
+    let review_request_repo = Arc::new(PostgresReviewRequestRepository::new(db.clone()));
 
-    let gitea_client = Arc::new(GiteaClientImpl::new(
-        config.gitea_url.clone(),
-        config.gitea_admin_token.clone(),
-    ));
+    let gitea_client = Arc::new(
+        GiteaClientImpl::new(config.gitea_url.clone(), config.gitea_admin_token.clone())
+            .with_max_concurrent_requests(config.gitea_max_concurrent_requests),
+    );
 
     // Issue repository uses Gitea (source of truth for issues)
     let issue_repo = Arc::new(GiteaIssueRepository::new(
@@ -139,57 +208,364 @@ async fn main() {
     let analytics_client = Arc::new(NoopAnalyticsClient);
 
     // Create application services
-    let agent_service = Arc::new(AgentService::new(
-        agent_repo.clone(),
-        gitea_client.clone(),
-        config.encryption_key.clone(),
-    ));
+    let agent_service = Arc::new(
+        AgentService::new(
+            agent_repo.clone(),
+            gitea_client.clone(),
+            config.encryption_key.clone(),
+        )
+        .with_name_policy(config.agent_name_policy.clone())
+        .with_elo_bounds(config.elo_bounds)
+        .with_token_cache(
+            std::time::Duration::from_secs(config.gitea_token_cache_ttl_secs),
+            config.gitea_token_cache_max,
+        ),
+    );
 
-    let feed_service = Arc::new(FeedService::new(
-        project_repo.clone(),
-        ticket_repo.clone(),
-        gitea_client.clone(),
-    ));
+    // Notifications reach agents through their feed
+    let feed_notifier = Arc::new(FeedNotifier::new());
 
-    let antfarm_service = Arc::new(AntfarmService::new(
-        project_repo.clone(),
-        gitea_client.clone(),
-        analytics_client.clone(),
-    ));
+    let feed_service = Arc::new(
+        FeedService::new(
+            project_repo.clone(),
+            ticket_repo.clone(),
+            gitea_client.clone(),
+        )
+        .with_inbox(feed_notifier.clone())
+        .with_notification_batch_window(chrono::Duration::seconds(
+            config.notification_batch_window_secs,
+        ))
+        .with_difficulty_weights(config.difficulty_weights)
+        .with_welcome_message(config.feed_welcome_message.clone()),
+    );
+
+    let antfarm_service = Arc::new(
+        AntfarmService::new(
+            project_repo.clone(),
+            gitea_client.clone(),
+            analytics_client.clone(),
+        )
+        .with_auto_labels(config.auto_label_rules.clone())
+        .with_issue_hooks(config.issue_hooks.clone())
+        .with_language_aliases(config.language_aliases.clone())
+        .with_repo_template(config.project_template_repo.clone())
+        .with_non_member_merges(config.non_member_merges),
+    );
+
+    let reactive_elo_service = Arc::new(
+        ReactiveEloService::new(
+            agent_repo.clone(),
+            contribution_repo.clone(),
+            review_repo.clone(),
+            elo_event_repo.clone(),
+            project_repo.clone(),
+        )
+        .with_merge_cooldown(chrono::Duration::seconds(config.merge_cooldown_secs))
+        .with_daily_cap(config.elo_daily_cap)
+        .with_probation(config.probation)
+        .with_elo_bounds(config.elo_bounds)
+        .with_longevity_concurrency(config.longevity_batch_concurrency)
+        .with_self_review_policy(config.self_review_policy)
+        .with_notifier(feed_notifier.clone())
+        .with_ticket_abandon_penalty(config.ticket_abandon_elo)
+        .with_review_abandon_penalty(config.review_abandon_elo)
+        .with_difficulty_weights(config.difficulty_weights)
+        .with_bug_attribution(config.strict_bug_attribution.then(|| {
+            Arc::new(GiteaBugAttribution::new(gitea_client.clone()))
+                as Arc<dyn domain::ports::BugAttribution>
+        })),
+    );
+
+    let pending_penalty_service = Arc::new(
+        PendingPenaltyService::new(pending_penalty_repo.clone(), gitea_client.clone())
+            .with_grace_period(chrono::Duration::seconds(config.pr_rejection_grace_secs)),
+    );
+
+    // Periodically apply rejection penalties whose grace period has elapsed
+    if pending_penalty_service.is_deferred() {
+        let penalties = pending_penalty_service.clone();
+        let elo = reactive_elo_service.clone();
+        tokio::spawn(async move {
+            let mut interval =
+                tokio::time::interval(std::time::Duration::from_secs(PENALTY_SWEEP_INTERVAL_SECS));
+            loop {
+                interval.tick().await;
+                match penalties.process_due(&elo, chrono::Utc::now()).await {
+                    Ok(applied) if !applied.is_empty() => {
+                        tracing::info!(
+                            count = applied.len(),
+                            "Applied deferred PR rejection penalties"
+                        );
+                    }
+                    Ok(_) => {}
+                    Err(e) => {
+                        tracing::error!(error = %e, "Failed to process pending rejection penalties");
+                    }
+                }
+            }
+        });
+    }
+
+    let review_request_service = Arc::new(
+        ReviewRequestService::new(
+            review_request_repo.clone(),
+            agent_repo.clone(),
+            project_repo.clone(),
+            gitea_client.clone(),
+        )
+        .with_window(chrono::Duration::hours(config.review_request_window_hours))
+        .with_policy(config.abandoned_review_policy)
+        .with_notifier(feed_notifier.clone()),
+    );
 
-    let reactive_elo_service = Arc::new(ReactiveEloService::new(
-        agent_repo.clone(),
+    // Periodically reassign or penalize review requests nobody answered
+    if config.review_request_window_hours > 0 {
+        let review_requests = review_request_service.clone();
+        let elo = reactive_elo_service.clone();
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(std::time::Duration::from_secs(
+                REVIEW_REQUEST_SWEEP_INTERVAL_SECS,
+            ));
+            loop {
+                interval.tick().await;
+                match review_requests
+                    .process_overdue(&elo, chrono::Utc::now())
+                    .await
+                {
+                    Ok(0) => {}
+                    Ok(count) => {
+                        tracing::info!(count, "Followed up on abandoned review requests");
+                    }
+                    Err(e) => {
+                        tracing::error!(error = %e, "Failed to process overdue review requests");
+                    }
+                }
+            }
+        });
+    }
+
+    let engagement_service = Arc::new(
+        EngagementService::new(engagement_repo.clone(), gitea_client.clone())
+            .with_weights(config.engagement_weights)
+            .with_cooldowns(config.engagement_cooldowns)
+            .with_gitea_sync(
+                config.engagement_gitea_sync,
+                config.engagement_sync_reactions.clone(),
+            )
+            .with_sync_max_attempts(config.engagement_sync_max_attempts),
+    );
+
+    // Periodically retry engagements whose Gitea sync failed
+    if config.engagement_gitea_sync {
+        let engagements = engagement_service.clone();
+        let agents = agent_service.clone();
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(std::time::Duration::from_secs(
+                ENGAGEMENT_RESYNC_INTERVAL_SECS,
+            ));
+            loop {
+                interval.tick().await;
+                match engagements
+                    .resync_unsynced(RESYNC_BATCH_SIZE, chrono::Utc::now(), agents.as_ref())
+                    .await
+                {
+                    Ok(result) if result == ResyncResult::default() => {}
+                    Ok(result) => {
+                        tracing::info!(
+                            synced = result.synced,
+                            failed = result.failed,
+                            given_up = result.given_up,
+                            "Retried unsynced engagements"
+                        );
+                    }
+                    Err(e) => {
+                        tracing::error!(error = %e, "Failed to retry unsynced engagements");
+                    }
+                }
+            }
+        });
+    }
+
+    // Periodically fold reactions left directly on Gitea into engagement counts
+    {
+        let reaction_sync = ReactionSyncService::new(
+            engagement_repo.clone(),
+            agent_repo.clone(),
+            gitea_client.clone(),
+        );
+        let projects = project_repo.clone();
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(std::time::Duration::from_secs(
+                REACTION_RECONCILE_INTERVAL_SECS,
+            ));
+            loop {
+                interval.tick().await;
+                match reaction_sync.reconcile_open_prs(projects.as_ref()).await {
+                    Ok(0) => {}
+                    Ok(count) => {
+                        tracing::debug!(count, "Reconciled PR reactions");
+                    }
+                    Err(e) => {
+                        tracing::error!(error = %e, "Failed to reconcile PR reactions");
+                    }
+                }
+            }
+        });
+    }
+
+    let history_export_service = Arc::new(HistoryExportService::new(
         contribution_repo.clone(),
-        review_repo.clone(),
         elo_event_repo.clone(),
+        project_repo.clone(),
     ));
 
-    let engagement_service = Arc::new(EngagementService::new(
-        engagement_repo.clone(),
-        gitea_client.clone(),
-    ));
+    let viral_moment_service = Arc::new(
+        ViralMomentService::new(viral_moment_repo.clone(), engagement_repo.clone())
+            .with_engagement_weights(config.engagement_weights)
+            .with_engagement_decay(config.engagement_decay)
+            .with_promotion_quota(config.promotion_quota)
+            .with_quiet_hours(config.viral_quiet_hours)
+            .with_snapshot_retention(
+                (config.viral_snapshot_retention_days > 0)
+                    .then(|| chrono::Duration::days(config.viral_snapshot_retention_days)),
+            ),
+    );
 
-    let viral_moment_service = Arc::new(ViralMomentService::new(
-        viral_moment_repo.clone(),
-        engagement_repo.clone(),
-    ));
+    // Periodically compact the snapshots of old, unpromoted viral moments
+    if viral_moment_service.compacts_snapshots() {
+        let moments = viral_moment_service.clone();
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(std::time::Duration::from_secs(
+                SNAPSHOT_COMPACTION_INTERVAL_SECS,
+            ));
+            loop {
+                interval.tick().await;
+                match moments.compact_old_snapshots(chrono::Utc::now()).await {
+                    Ok(0) => {}
+                    Ok(count) => {
+                        tracing::info!(count, "Compacted old viral moment snapshots");
+                    }
+                    Err(e) => {
+                        tracing::error!(error = %e, "Failed to compact viral moment snapshots");
+                    }
+                }
+            }
+        });
+    }
 
-    let work_loop_service = Arc::new(WorkLoopService::new(
-        ticket_repo.clone(),
-        project_repo.clone(),
-        gitea_client.clone(),
-    ));
+    // Periodically nudge open PRs that have gone quiet
+    if config.stale_pr_nudge_hours > 0 {
+        let stale_prs = StalePrService::new(project_repo.clone(), gitea_client.clone())
+            .with_window(chrono::Duration::hours(config.stale_pr_nudge_hours))
+            .with_message(config.stale_pr_nudge_message.clone());
+        tokio::spawn(async move {
+            let mut interval =
+                tokio::time::interval(std::time::Duration::from_secs(STALE_PR_SWEEP_INTERVAL_SECS));
+            loop {
+                interval.tick().await;
+                match stale_prs.nudge_stale_prs(chrono::Utc::now()).await {
+                    Ok(0) => {}
+                    Ok(count) => {
+                        tracing::info!(count, "Nudged stale PRs");
+                    }
+                    Err(e) => {
+                        tracing::error!(error = %e, "Failed to sweep for stale PRs");
+                    }
+                }
+            }
+        });
+    }
+
+    let related_issues_service = Arc::new(RelatedIssuesService::new(issue_repo.clone()));
+
+    let webhook_delivery_service = Arc::new(
+        WebhookDeliveryService::new(webhook_delivery_repo).with_retention(
+            (config.webhook_delivery_retention_days > 0)
+                .then(|| chrono::Duration::days(config.webhook_delivery_retention_days)),
+        ),
+    );
+
+    // Periodically delete old entries from the webhook delivery log
+    if webhook_delivery_service.prunes() {
+        let deliveries = webhook_delivery_service.clone();
+        tokio::spawn(async move {
+            let mut interval =
+                tokio::time::interval(std::time::Duration::from_secs(DELIVERY_PRUNE_INTERVAL_SECS));
+            loop {
+                interval.tick().await;
+                match deliveries.prune(chrono::Utc::now()).await {
+                    Ok(0) => {}
+                    Ok(count) => {
+                        tracing::info!(count, "Pruned old webhook deliveries");
+                    }
+                    Err(e) => {
+                        tracing::error!(error = %e, "Failed to prune webhook deliveries");
+                    }
+                }
+            }
+        });
+    }
+    let (webhook_queue, webhook_workers) = WebhookQueue::new(
+        config.webhook_queue_capacity,
+        config.webhook_workers,
+        config.webhook_batch_size,
+    );
+
+    let ticket_counts = {
+        let batcher = TicketCountBatcher::new(project_repo.clone(), gitea_client.clone());
+        Arc::new(if config.ticket_count_flush_ms == 0 {
+            batcher.immediate()
+        } else {
+            batcher
+        })
+    };
+
+    // Recount open tickets for projects that saw issue events, once per project per interval
+    if ticket_counts.is_batched() {
+        let counts = ticket_counts.clone();
+        let flush_every = std::time::Duration::from_millis(config.ticket_count_flush_ms);
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(flush_every);
+            loop {
+                interval.tick().await;
+                if let Err(e) = counts.flush().await {
+                    tracing::error!(error = %e, "Failed to recount open tickets");
+                }
+            }
+        });
+    }
+
+    let work_loop_service = Arc::new(
+        WorkLoopService::new(
+            ticket_repo.clone(),
+            project_repo.clone(),
+            gitea_client.clone(),
+        )
+        .with_max_open_claims(config.max_open_claims_per_agent)
+        .with_required_approvals(config.required_approvals),
+    );
 
     // Create app state
     let state = AppState {
         agent_service,
         feed_service,
+        feed_cache: Arc::new(FeedCache::new(config.feed_cache_max_age_secs)),
         antfarm_service,
         reactive_elo_service,
+        pending_penalty_service,
+        review_request_service,
         engagement_service,
+        history_export_service,
         viral_moment_service,
         work_loop_service,
+        related_issues_service,
+        webhook_delivery_service: webhook_delivery_service.clone(),
+        webhook_queue: Arc::new(webhook_queue),
+        ticket_counts,
         issue_repo,
+        agent_repo,
+        agent_merge_repo: Arc::new(PostgresAgentMergeRepository::new(db.clone())),
+        elo_integrity_repo: Arc::new(PostgresEloIntegrityRepository::new(db.clone())),
         project_repo,
         ticket_repo,
         gitea: gitea_client.clone(),
@@ -198,6 +574,29 @@ async fn main() {
         config: config.clone(),
     };
 
+    // Process queued webhook deliveries in the background
+    webhook_workers.spawn(
+        Arc::new(GiteaEventHandler::new(state.clone())),
+        webhook_delivery_service.clone(),
+    );
+
+    // Pick up deliveries that were accepted but not processed before the last shutdown
+    {
+        let queue = state.webhook_queue.clone();
+        tokio::spawn(async move {
+            match queue
+                .requeue_stored(&webhook_delivery_service, handlers::webhooks::ordering_key)
+                .await
+            {
+                Ok(0) => {}
+                Ok(count) => tracing::info!(count, "Re-queued stored webhook deliveries"),
+                Err(e) => {
+                    tracing::error!(error = %e, "Failed to re-queue stored webhook deliveries")
+                }
+            }
+        });
+    }
+
     // Rate limiting config: 2 req/sec sustained, burst of 5
     // Uses PeerIpKeyExtractor to get client IP from socket connection
     // (SmartIpKeyExtractor requires X-Forwarded-For headers from reverse proxy)
@@ -220,18 +619,31 @@ async fn main() {
             config: governor_config,
         });
 
-    // Build router
-    let app = Router::new()
-        // Health check (no auth)
-        .route("/health", get(health))
-        // Webhooks (no auth, uses signature verification)
-        .route("/webhooks/gitea", post(handlers::gitea_webhook))
-        // Public endpoints (optional auth)
-        .route("/projects", get(handlers::list_projects))
+    // Head-to-head comparisons scan both agents' history, so they get their own limiter
+    let comparison_governor_config = Arc::new(
+        GovernorConfigBuilder::default()
+            .key_extractor(PeerIpKeyExtractor)
+            .per_second(2)
+            .burst_size(5)
+            .finish()
+            .expect("Failed to build governor config"),
+    );
+    let comparison_routes = Router::new()
+        .route("/agents/:id/vs/:other", get(handlers::get_head_to_head))
+        .layer(GovernorLayer {
+            config: comparison_governor_config,
+        });
+
+    // Project-scoped reads (optional auth, private projects need membership)
+    let public_project_routes = Router::new()
         .route("/projects/:id", get(handlers::get_project))
         .route("/projects/:id/labels", get(handlers::list_available_labels))
         .route("/projects/:id/issues", get(handlers::list_issues))
         .route("/projects/:id/issues/:number", get(handlers::get_issue))
+        .route(
+            "/projects/:id/issues/:number/related",
+            get(handlers::list_related_issues),
+        )
         .route(
             "/projects/:id/issues/:number/comments",
             get(handlers::list_comments),
@@ -243,6 +655,15 @@ async fn main() {
         // PR endpoints (public read-only)
         .route("/projects/:id/prs", get(handlers::list_prs))
         .route("/projects/:id/prs/:number", get(handlers::get_pr))
+        .route("/projects/:id/prs/:number/diff", get(handlers::get_pr_diff))
+        .route(
+            "/projects/:id/prs/:number/status",
+            get(handlers::get_pr_status),
+        )
+        .route(
+            "/projects/:id/prs/:number/contribution",
+            get(handlers::get_pr_contribution),
+        )
         .route(
             "/projects/:id/prs/:number/reviews",
             get(handlers::list_reviews),
@@ -257,6 +678,143 @@ async fn main() {
         )
         // Maintainers (public read)
         .route("/projects/:id/maintainers", get(handlers::list_maintainers))
+        .route_layer(middleware::from_fn_with_state(
+            state.clone(),
+            auth::project_visibility_middleware,
+        ))
+        .route_layer(middleware::from_fn_with_state(
+            state.clone(),
+            auth::optional_auth_middleware,
+        ));
+
+    // Project-scoped writes (private projects need membership or an invite)
+    let member_project_routes = Router::new()
+        // Issue management (nested under projects)
+        .route("/projects/:id/issues", post(handlers::create_issue))
+        .route(
+            "/projects/:id/issues/:number",
+            patch(handlers::update_issue),
+        )
+        .route(
+            "/projects/:id/issues/:number/close",
+            post(handlers::close_issue),
+        )
+        .route(
+            "/projects/:id/issues/:number/reopen",
+            post(handlers::reopen_issue),
+        )
+        .route(
+            "/projects/:id/issues/:number/comments",
+            post(handlers::add_comment),
+        )
+        .route(
+            "/projects/:id/issues/:number/comments/:comment_id",
+            patch(handlers::edit_comment).delete(handlers::delete_comment),
+        )
+        .route(
+            "/projects/:id/issues/:number/labels",
+            post(handlers::add_labels).put(handlers::replace_labels),
+        )
+        .route(
+            "/projects/:id/issues/:number/labels/:label",
+            delete(handlers::remove_label),
+        )
+        .route(
+            "/projects/:id/issues/:number/assignees",
+            post(handlers::assign_issue),
+        )
+        .route(
+            "/projects/:id/issues/:number/assignees/:assignee",
+            delete(handlers::unassign_issue),
+        )
+        .route("/projects/:id/join", post(handlers::join_project))
+        .route("/projects/:id/star", post(handlers::star_project))
+        .route("/projects/:id/mirror", post(handlers::mirror_project))
+        .route("/projects/:id/releases", post(handlers::create_release))
+        .route(
+            "/projects/:id/settings",
+            put(handlers::update_project_settings),
+        )
+        // Maintainer management
+        .route("/projects/:id/maintainers", post(handlers::add_maintainer))
+        .route(
+            "/projects/:id/maintainers/:username",
+            delete(handlers::remove_maintainer),
+        )
+        .route("/projects/:id/invites", post(handlers::invite_agent))
+        // Project succession (abandoned project revival)
+        .route(
+            "/projects/:id/succession",
+            get(handlers::get_succession_status),
+        )
+        .route("/projects/:id/claim", post(handlers::claim_role))
+        // Pull request management (nested under projects)
+        .route("/projects/:id/prs", post(handlers::create_pr))
+        .route("/projects/:id/prs/:number/merge", post(handlers::merge_pr))
+        .route(
+            "/projects/:id/prs/:number/reviews",
+            post(handlers::submit_review),
+        )
+        .route(
+            "/projects/:id/prs/:number/reviewers",
+            post(handlers::request_reviewers),
+        )
+        .route(
+            "/projects/:id/prs/:number/comments",
+            post(handlers::add_pr_comment),
+        )
+        .route(
+            "/projects/:id/prs/:number/comments/:comment_id",
+            patch(handlers::edit_pr_comment).delete(handlers::delete_pr_comment),
+        )
+        .route(
+            "/projects/:id/prs/:number/reactions",
+            post(handlers::add_pr_reaction),
+        )
+        .route(
+            "/projects/:id/prs/:number/reactions/:reaction_id",
+            delete(handlers::delete_pr_reaction),
+        )
+        .route_layer(middleware::from_fn_with_state(
+            state.clone(),
+            auth::project_visibility_middleware,
+        ));
+
+    // Build router
+    let app = Router::new()
+        // Health check (no auth)
+        .route("/health", get(health))
+        // Webhooks (no auth, uses signature verification)
+        // Gitea push payloads can be large, so this route gets its own limit
+        .route(
+            "/webhooks/gitea",
+            post(handlers::gitea_webhook)
+                .layer(DefaultBodyLimit::max(config.webhook_max_body_bytes)),
+        )
+        .route(
+            "/admin/webhooks/recent",
+            get(handlers::list_recent_webhooks),
+        )
+        .route("/admin/agents/merge", post(handlers::merge_agents))
+        .route(
+            "/admin/agents/gitea-users",
+            post(handlers::provision_gitea_users),
+        )
+        .route("/admin/elo/simulate", post(handlers::simulate_elo))
+        .route("/admin/integrity/elo-drift", get(handlers::get_elo_drift))
+        // Public endpoints (optional auth)
+        .route("/projects", get(handlers::list_projects))
+        .route("/projects/featured", get(handlers::list_featured_projects))
+        .route("/projects/languages", get(handlers::list_project_languages))
+        .route(
+            "/projects/:id/featured",
+            put(handlers::set_project_featured),
+        )
+        .route(
+            "/projects/:id/elo-multiplier",
+            put(handlers::set_project_elo_multiplier),
+        )
+        .merge(public_project_routes)
         // Viral feeds (public, no auth)
         .route("/viral/shame", get(handlers::get_shame_feed))
         .route("/viral/drama", get(handlers::get_drama_feed))
@@ -264,13 +822,36 @@ async fn main() {
         .route("/viral/battles", get(handlers::get_battles_feed))
         .route("/viral/top", get(handlers::get_top_feed))
         .route("/viral/promoted", get(handlers::get_promoted_feed))
+        .route("/viral/moments", get(handlers::list_moments))
         .route("/viral/moment/:id", get(handlers::get_moment))
+        // Agent contribution history (public, no auth)
+        .route(
+            "/agents/:id/contributions",
+            get(handlers::list_agent_contributions),
+        )
+        .route("/agents/:id/reviews", get(handlers::list_agent_reviews))
+        .route("/agents/:id/export", get(handlers::export_agent_history))
+        .route("/agents/:id/activity", get(handlers::get_agent_activity))
+        .merge(comparison_routes)
+        // Platform statistics (public, no auth)
+        .route("/stats/tiers", get(handlers::get_tier_stats))
+        .route("/leaderboard/movers", get(handlers::get_leaderboard_movers))
         // Merge rate-limited routes
         .merge(rate_limited_routes)
         // Protected routes
         .nest(
             "/",
             Router::new()
+                // Agent onboarding checklist
+                .route(
+                    "/agents/me/onboarding",
+                    get(handlers::get_onboarding_status),
+                )
+                .route(
+                    "/agents/me/pending-penalties",
+                    get(handlers::get_pending_penalties),
+                )
+                .route("/agents/me/gitea-token", post(handlers::rotate_gitea_token))
                 // Feed endpoints
                 .route("/feed", get(handlers::get_feed))
                 .route("/action", post(handlers::post_action))
@@ -280,91 +861,36 @@ async fn main() {
                     "/engage/counts/:target_type/:target_id",
                     get(handlers::get_engage_counts),
                 )
-                // Issue management (nested under projects)
-                .route("/projects/:id/issues", post(handlers::create_issue))
-                .route(
-                    "/projects/:id/issues/:number",
-                    patch(handlers::update_issue),
-                )
                 .route(
-                    "/projects/:id/issues/:number/close",
-                    post(handlers::close_issue),
+                    "/engage/:target_type/:target_id",
+                    get(handlers::list_engagements),
                 )
                 .route(
-                    "/projects/:id/issues/:number/reopen",
-                    post(handlers::reopen_issue),
-                )
-                .route(
-                    "/projects/:id/issues/:number/comments",
-                    post(handlers::add_comment),
-                )
-                .route(
-                    "/projects/:id/issues/:number/comments/:comment_id",
-                    patch(handlers::edit_comment).delete(handlers::delete_comment),
-                )
-                .route(
-                    "/projects/:id/issues/:number/labels",
-                    post(handlers::add_labels),
-                )
-                .route(
-                    "/projects/:id/issues/:number/labels/:label",
-                    delete(handlers::remove_label),
-                )
-                .route(
-                    "/projects/:id/issues/:number/assignees",
-                    post(handlers::assign_issue),
-                )
-                .route(
-                    "/projects/:id/issues/:number/assignees/:assignee",
-                    delete(handlers::unassign_issue),
+                    "/engage/counts/batch",
+                    post(handlers::get_engage_counts_batch),
                 )
                 // Project management
                 .route("/projects", post(handlers::create_project))
+                .route("/projects/import", post(handlers::import_project))
                 .route("/projects/my", get(handlers::get_my_projects))
-                .route("/projects/:id/join", post(handlers::join_project))
-                // Maintainer management
-                .route("/projects/:id/maintainers", post(handlers::add_maintainer))
                 .route(
-                    "/projects/:id/maintainers/:username",
-                    delete(handlers::remove_maintainer),
+                    "/agents/me/claimable-roles",
+                    get(handlers::list_claimable_roles),
                 )
-                // Project succession (abandoned project revival)
-                .route(
-                    "/projects/:id/succession",
-                    get(handlers::get_succession_status),
-                )
-                .route("/projects/:id/claim", post(handlers::claim_role))
+                // Tickets
+                .route("/tickets/:id/abandon", post(handlers::abandon_ticket))
                 // Organization management
                 .route("/orgs", post(handlers::create_org))
                 .route("/orgs/my", get(handlers::list_my_orgs))
-                // Pull request management (nested under projects)
-                .route("/projects/:id/prs", post(handlers::create_pr))
-                .route("/projects/:id/prs/:number/merge", post(handlers::merge_pr))
-                .route(
-                    "/projects/:id/prs/:number/reviews",
-                    post(handlers::submit_review),
-                )
-                .route(
-                    "/projects/:id/prs/:number/comments",
-                    post(handlers::add_pr_comment),
-                )
-                .route(
-                    "/projects/:id/prs/:number/comments/:comment_id",
-                    patch(handlers::edit_pr_comment).delete(handlers::delete_pr_comment),
-                )
-                .route(
-                    "/projects/:id/prs/:number/reactions",
-                    post(handlers::add_pr_reaction),
-                )
-                .route(
-                    "/projects/:id/prs/:number/reactions/:reaction_id",
-                    delete(handlers::delete_pr_reaction),
-                )
+                .route("/orgs/:org/repos", get(handlers::list_org_repos))
+                .merge(member_project_routes)
                 .layer(middleware::from_fn_with_state(
                     state.clone(),
                     auth::auth_middleware,
                 )),
-        )
+        );
+
+    let app = with_body_limit(app, config.max_body_bytes)
         // Middleware
         .layer(
             CorsLayer::new()
@@ -391,3 +917,71 @@ async fn main() {
     .await
     .unwrap();
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use axum::body::{Body, Bytes};
+    use axum::http::{Request, StatusCode};
+    use tower::ServiceExt;
+
+    async fn echo_len(body: Bytes) -> String {
+        body.len().to_string()
+    }
+
+    fn limited_router() -> Router {
+        let router = Router::new()
+            .route("/small", post(echo_len))
+            .route("/large", post(echo_len).layer(DefaultBodyLimit::max(4096)));
+        with_body_limit(router, 64)
+    }
+
+    fn post_body(uri: &str, size: usize) -> Request<Body> {
+        Request::builder()
+            .method("POST")
+            .uri(uri)
+            .header("content-type", "application/json")
+            .body(Body::from(vec![b'a'; size]))
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn body_within_limit_is_accepted() {
+        let response = limited_router()
+            .oneshot(post_body("/small", 32))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn oversized_body_returns_json_413() {
+        let response = limited_router()
+            .oneshot(post_body("/small", 1024))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::PAYLOAD_TOO_LARGE);
+
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(json["error"], "Payload too large");
+    }
+
+    #[tokio::test]
+    async fn route_override_allows_larger_body() {
+        let response = limited_router()
+            .oneshot(post_body("/large", 1024))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let response = limited_router()
+            .oneshot(post_body("/large", 8192))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::PAYLOAD_TOO_LARGE);
+    }
+}
@@ -9,7 +9,8 @@ use uuid::Uuid;
 use crate::domain::entities::{
     Agent, AgentId, AgentReview, AgentReviewId, BuildStatus, CodeContribution, CodeContributionId,
     ContributionStatus, EloEvent, EloEventId, EloEventType, Issue, IssueId, IssueState, MemberRole,
-    Project, ProjectId, ProjectMember, ProjectStatus, ReviewVerdict, Tier,
+    Project, ProjectId, ProjectMember, ProjectStatus, ProjectVisibility, ReviewEligibility,
+    ReviewVerdict, Tier,
 };
 
 /// Create a test agent with default values
@@ -134,6 +135,17 @@ pub fn test_project() -> Project {
         build_status: BuildStatus::Unknown,
         created_by: None,
         created_at: Utc::now(),
+        mirror: None,
+        allow_alternate_bases: false,
+        require_green_ci: false,
+        required_approvals: 0,
+        featured: false,
+        featured_rank: 0,
+        elo_multiplier: 1.0,
+        visibility: ProjectVisibility::Public,
+        review_eligibility: ReviewEligibility::Open,
+        protect_default_branch: false,
+        star_count: 0,
     }
 }
 
@@ -152,6 +164,17 @@ pub fn test_project_with_status(status: ProjectStatus) -> Project {
         build_status: BuildStatus::Unknown,
         created_by: None,
         created_at: Utc::now(),
+        mirror: None,
+        allow_alternate_bases: false,
+        require_green_ci: false,
+        required_approvals: 0,
+        featured: false,
+        featured_rank: 0,
+        elo_multiplier: 1.0,
+        visibility: ProjectVisibility::Public,
+        review_eligibility: ReviewEligibility::Open,
+        protect_default_branch: false,
+        star_count: 0,
     }
 }
 
@@ -294,10 +317,12 @@ pub fn test_ticket(project_id: ProjectId) -> crate::domain::entities::Ticket {
         gitea_issue_url: Some("https://gitea.test/org/repo/issues/1".to_string()),
         status: crate::domain::entities::TicketStatus::Open,
         priority: crate::domain::entities::TicketPriority::Medium,
+        difficulty: crate::domain::entities::Difficulty::Medium,
         assigned_to: None,
         created_by: None,
         created_at: Utc::now(),
         closed_at: None,
+        blocked_reason: None,
     }
 }
 
@@ -315,9 +340,11 @@ pub fn test_ticket_assigned(
         gitea_issue_url: Some("https://gitea.test/org/repo/issues/2".to_string()),
         status: crate::domain::entities::TicketStatus::InProgress,
         priority: crate::domain::entities::TicketPriority::Medium,
+        difficulty: crate::domain::entities::Difficulty::Medium,
         assigned_to: Some(agent_id),
         created_by: None,
         created_at: Utc::now(),
         closed_at: None,
+        blocked_reason: None,
     }
 }
@@ -5,23 +5,33 @@
 
 use async_trait::async_trait;
 use chrono::{DateTime, Utc};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::sync::{Arc, RwLock};
 
 use crate::domain::entities::{
-    Agent, AgentId, AgentReview, AgentReviewId, BuildStatus, ClaimAgent, CodeContribution,
-    CodeContributionId, ContributionStatus, EloEvent, EloEventId, Issue, IssueComment, IssueId,
-    IssueState, Label, MemberRole, NewAgent, NewAgentReview, NewCodeContribution, NewEloEvent,
-    NewIssue, NewProject, NewTicket, Project, ProjectId, ProjectMember, ProjectStatus, Ticket,
-    TicketId, TicketPriority, TicketStatus, Tier,
+    Agent, AgentId, AgentMergeCounts, AgentReview, AgentReviewId, AvailableLabel, BuildStatus,
+    ClaimAgent, CodeContribution, CodeContributionId, CommentFilter, ContributionStatus,
+    DeliveryOutcome, EloDrift, EloEvent, EloEventId, Engagement, EngagementCounts, EngagementId,
+    EngagementType, EngagementWeights, Issue, IssueComment, IssueId, IssueState, Label,
+    LabelFilter, MemberRole, MomentFilter, MomentSort, MomentType, NewAgent, NewAgentReview,
+    NewCodeContribution, NewEloEvent, NewEngagement, NewIssue, NewPendingPenalty, NewProject,
+    NewReviewRequest, NewTicket, NewViralMoment, NewWebhookDelivery, PenaltyStatus, PendingPenalty,
+    PendingPenaltyId, Project, ProjectId, ProjectMember, ProjectMirror, ProjectStatus,
+    ProjectVisibility, ReactionType, ReviewEligibility, ReviewRequest, ReviewRequestId,
+    ReviewRequestStatus, ReviewVerdict, Ticket, TicketId, TicketPriority, TicketStatus, Tier,
+    ViralMoment, ViralMomentId, WebhookDelivery, WebhookDeliveryId,
 };
 use crate::domain::ports::{
-    AgentRepository, AgentReviewRepository, AgentStats, AnalyticsClient, AnalyticsEvent,
-    CodeContributionRepository, DifficultyBreakdown, EloEventRepository, GiteaBranch, GiteaClient,
-    GiteaCombinedStatus, GiteaComment, GiteaCommit, GiteaIssue, GiteaIssueComment, GiteaLabel,
-    GiteaOrg, GiteaPRBranch, GiteaPRReview, GiteaPullRequest, GiteaReaction, GiteaRepo, GiteaUser,
-    IssueRepository, LeaderboardEntry, ProjectRepository, ProjectStats, TicketRepository,
-    TimeRange,
+    AgentMergeRepository, AgentRepository, AgentReviewRepository, AgentStats, AnalyticsClient,
+    AnalyticsEvent, BranchProtection, CodeContributionRepository, DifficultyBreakdown,
+    EloEventRepository, EloIntegrityRepository, EngagementRepository, GiteaBranch, GiteaClient,
+    GiteaCombinedStatus, GiteaComment, GiteaCommit, GiteaCommitDetails, GiteaComparison,
+    GiteaHeatmapEntry, GiteaIssue, GiteaIssueComment, GiteaLabel, GiteaMergeStatus,
+    GiteaNotification, GiteaOrg, GiteaPRBranch, GiteaPRReview, GiteaPullRequest, GiteaReaction,
+    GiteaRelease, GiteaRepo, GiteaStatus, GiteaUser, IssueRepository, LeaderboardEntry,
+    Notification, Notifier, PendingPenaltyRepository, ProjectRepository, ProjectStats,
+    RepoSettings, ReviewRequestRepository, TicketRepository, TimeRange, ViralMomentRepository,
+    WebhookDeliveryRepository,
 };
 use crate::error::{AnalyticsError, DomainError, GiteaError};
 
@@ -37,6 +47,10 @@ pub struct InMemoryAgentRepository {
     by_claim_code: Arc<RwLock<HashMap<String, AgentId>>>,
     by_github_id: Arc<RwLock<HashMap<i64, AgentId>>>,
     tokens: Arc<RwLock<HashMap<AgentId, Vec<u8>>>>,
+    /// Number of encrypted Gitea token lookups served
+    pub token_reads: Arc<RwLock<usize>>,
+    /// Fail `update_elo`, as if the database went away mid-award
+    pub fail_update_elo: Arc<RwLock<bool>>,
 }
 
 impl InMemoryAgentRepository {
@@ -74,6 +88,14 @@ impl AgentRepository for InMemoryAgentRepository {
         Ok(agents.get(id).cloned())
     }
 
+    async fn find_by_ids(&self, ids: &[AgentId]) -> Result<Vec<Agent>, DomainError> {
+        let agents = self.agents.read().unwrap();
+        Ok(ids
+            .iter()
+            .filter_map(|id| agents.get(id).cloned())
+            .collect())
+    }
+
     async fn find_by_api_key_hash(&self, hash: &str) -> Result<Option<Agent>, DomainError> {
         let by_api_key = self.by_api_key.read().unwrap();
         let agents = self.agents.read().unwrap();
@@ -147,6 +169,9 @@ impl AgentRepository for InMemoryAgentRepository {
     }
 
     async fn update_elo(&self, id: &AgentId, elo: i32) -> Result<(), DomainError> {
+        if *self.fail_update_elo.read().unwrap() {
+            return Err(DomainError::Database("ELO update failed".to_string()));
+        }
         let mut agents = self.agents.write().unwrap();
         if let Some(agent) = agents.get_mut(id) {
             agent.elo = elo;
@@ -161,10 +186,23 @@ impl AgentRepository for InMemoryAgentRepository {
         &self,
         id: &AgentId,
     ) -> Result<Option<Vec<u8>>, DomainError> {
+        *self.token_reads.write().unwrap() += 1;
         let tokens = self.tokens.read().unwrap();
         Ok(tokens.get(id).cloned())
     }
 
+    async fn update_gitea_token_encrypted(
+        &self,
+        id: &AgentId,
+        encrypted: Vec<u8>,
+    ) -> Result<(), DomainError> {
+        if !self.agents.read().unwrap().contains_key(id) {
+            return Err(DomainError::NotFound(format!("Agent {} not found", id)));
+        }
+        self.tokens.write().unwrap().insert(*id, encrypted);
+        Ok(())
+    }
+
     async fn find_top_by_elo(&self, limit: i64) -> Result<Vec<Agent>, DomainError> {
         let agents = self.agents.read().unwrap();
         let mut sorted: Vec<_> = agents.values().cloned().collect();
@@ -220,6 +258,15 @@ impl AgentRepository for InMemoryAgentRepository {
             Err(DomainError::NotFound(format!("Agent {} not found", id)))
         }
     }
+
+    async fn count_by_tier(&self) -> Result<Vec<(Tier, i64)>, DomainError> {
+        let agents = self.agents.read().unwrap();
+        let mut counts: HashMap<Tier, i64> = HashMap::new();
+        for agent in agents.values() {
+            *counts.entry(Tier::from_elo(agent.elo)).or_default() += 1;
+        }
+        Ok(counts.into_iter().collect())
+    }
 }
 
 // ============================================================================
@@ -235,6 +282,8 @@ pub struct InMemoryIssueRepository {
     next_number: Arc<RwLock<i64>>,
     next_comment_id: Arc<RwLock<i64>>,
     available_labels: Arc<RwLock<Vec<Label>>>,
+    /// Fail `get`, as if Gitea went away for the lookup
+    pub fail_get: Arc<RwLock<bool>>,
 }
 
 impl InMemoryIssueRepository {
@@ -256,6 +305,7 @@ impl InMemoryIssueRepository {
                     description: Some("New feature or request".to_string()),
                 },
             ])),
+            fail_get: Arc::new(RwLock::new(false)),
         }
     }
 
@@ -274,6 +324,7 @@ impl IssueRepository for InMemoryIssueRepository {
         &self,
         project_id: &ProjectId,
         state: Option<&str>,
+        labels: Option<&LabelFilter>,
     ) -> Result<Vec<Issue>, DomainError> {
         let issues = self.issues.read().unwrap();
         let result: Vec<Issue> = issues
@@ -284,12 +335,16 @@ impl IssueRepository for InMemoryIssueRepository {
                 Some("closed") => i.state == IssueState::Closed,
                 _ => true,
             })
+            .filter(|i| labels.is_none_or(|f| f.matches(&i.labels)))
             .cloned()
             .collect();
         Ok(result)
     }
 
     async fn get(&self, id: &IssueId) -> Result<Option<Issue>, DomainError> {
+        if *self.fail_get.read().unwrap() {
+            return Err(DomainError::Database("issue lookup failed".to_string()));
+        }
         let issues = self.issues.read().unwrap();
         Ok(issues.get(id).cloned())
     }
@@ -361,9 +416,13 @@ impl IssueRepository for InMemoryIssueRepository {
         Ok(issue.clone())
     }
 
-    async fn list_comments(&self, id: &IssueId) -> Result<Vec<IssueComment>, DomainError> {
+    async fn list_comments(
+        &self,
+        id: &IssueId,
+        filter: &CommentFilter,
+    ) -> Result<Vec<IssueComment>, DomainError> {
         let comments = self.comments.read().unwrap();
-        Ok(comments.get(id).cloned().unwrap_or_default())
+        Ok(filter.apply(comments.get(id).cloned().unwrap_or_default()))
     }
 
     async fn add_comment(
@@ -397,6 +456,7 @@ impl IssueRepository for InMemoryIssueRepository {
         id: &IssueId,
         comment_id: i64,
         body: &str,
+        author: &str,
         _agent_token: Option<&str>,
     ) -> Result<IssueComment, DomainError> {
         let mut comments = self.comments.write().unwrap();
@@ -408,6 +468,12 @@ impl IssueRepository for InMemoryIssueRepository {
             .iter_mut()
             .find(|c| c.id == comment_id)
             .ok_or_else(|| DomainError::NotFound(format!("Comment {} not found", comment_id)))?;
+        if comment.author != author {
+            return Err(DomainError::Forbidden(format!(
+                "Comment {} belongs to {}",
+                comment_id, comment.author
+            )));
+        }
 
         comment.body = body.to_string();
         comment.updated_at = "2026-01-02T00:00:00Z".to_string();
@@ -418,10 +484,20 @@ impl IssueRepository for InMemoryIssueRepository {
         &self,
         id: &IssueId,
         comment_id: i64,
+        author: &str,
         _agent_token: Option<&str>,
     ) -> Result<(), DomainError> {
         let mut comments = self.comments.write().unwrap();
         if let Some(issue_comments) = comments.get_mut(id) {
+            if issue_comments
+                .iter()
+                .any(|c| c.id == comment_id && c.author != author)
+            {
+                return Err(DomainError::Forbidden(format!(
+                    "Comment {} belongs to another agent",
+                    comment_id
+                )));
+            }
             issue_comments.retain(|c| c.id != comment_id);
         }
         Ok(())
@@ -458,6 +534,28 @@ impl IssueRepository for InMemoryIssueRepository {
         Ok(issue.labels.clone())
     }
 
+    async fn replace_labels(
+        &self,
+        id: &IssueId,
+        labels: Vec<String>,
+        _agent_token: Option<&str>,
+    ) -> Result<Vec<Label>, DomainError> {
+        let mut issues = self.issues.write().unwrap();
+        let issue = issues
+            .get_mut(id)
+            .ok_or_else(|| DomainError::NotFound(format!("Issue #{} not found", id.number)))?;
+
+        issue.labels = labels
+            .into_iter()
+            .map(|name| Label {
+                name,
+                color: "cccccc".to_string(),
+                description: None,
+            })
+            .collect();
+        Ok(issue.labels.clone())
+    }
+
     async fn remove_label(
         &self,
         id: &IssueId,
@@ -508,9 +606,16 @@ impl IssueRepository for InMemoryIssueRepository {
     async fn list_available_labels(
         &self,
         _project_id: &ProjectId,
-    ) -> Result<Vec<Label>, DomainError> {
-        let labels = self.available_labels.read().unwrap();
-        Ok(labels.clone())
+    ) -> Result<Vec<AvailableLabel>, DomainError> {
+        let labels = self.available_labels.read().unwrap().clone();
+        let applied: Vec<Label> = self
+            .issues
+            .read()
+            .unwrap()
+            .values()
+            .flat_map(|i| i.labels.clone())
+            .collect();
+        Ok(AvailableLabel::reconcile(labels, applied))
     }
 }
 
@@ -518,10 +623,26 @@ impl IssueRepository for InMemoryIssueRepository {
 // In-Memory Project Repository
 // ============================================================================
 
+/// A force merge recorded via record_force_merge
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ForceMergeRecord {
+    pub project_id: ProjectId,
+    pub agent_id: AgentId,
+    pub pr_number: i64,
+    pub waived_approvals: i32,
+}
+
 #[derive(Default)]
 pub struct InMemoryProjectRepository {
     projects: Arc<RwLock<HashMap<ProjectId, Project>>>,
     members: Arc<RwLock<Vec<ProjectMember>>>,
+    invites: Arc<RwLock<Vec<(ProjectId, AgentId)>>>,
+    /// Force merges recorded via record_force_merge
+    pub force_merges: Arc<RwLock<Vec<ForceMergeRecord>>>,
+    /// PRs recorded via record_pr_nudge (project, PR)
+    pub pr_nudges: Arc<RwLock<Vec<(ProjectId, i64)>>>,
+    /// Calls made to set_open_ticket_count (project, count)
+    pub ticket_count_updates: Arc<RwLock<Vec<(ProjectId, i32)>>>,
 }
 
 impl InMemoryProjectRepository {
@@ -539,6 +660,16 @@ impl InMemoryProjectRepository {
 
     /// Add a project and an agent as a member (convenience for tests)
     pub fn with_project_and_member(self, project: Project, agent_id: AgentId) -> Self {
+        self.with_project_and_role(project, agent_id, MemberRole::Contributor)
+    }
+
+    /// Add a project and an agent as a member with the given role
+    pub fn with_project_and_role(
+        self,
+        project: Project,
+        agent_id: AgentId,
+        role: MemberRole,
+    ) -> Self {
         {
             let mut projects = self.projects.write().unwrap();
             projects.insert(project.id, project.clone());
@@ -548,7 +679,7 @@ impl InMemoryProjectRepository {
             members.push(ProjectMember {
                 project_id: project.id,
                 agent_id,
-                role: MemberRole::Contributor,
+                role,
                 joined_at: Utc::now(),
             });
         }
@@ -563,22 +694,100 @@ impl ProjectRepository for InMemoryProjectRepository {
         Ok(projects.get(id).cloned())
     }
 
+    async fn find_by_ids(&self, ids: &[ProjectId]) -> Result<Vec<Project>, DomainError> {
+        let projects = self.projects.read().unwrap();
+        Ok(ids
+            .iter()
+            .filter_map(|id| projects.get(id).cloned())
+            .collect())
+    }
+
     async fn find_by_name(&self, name: &str) -> Result<Option<Project>, DomainError> {
         let projects = self.projects.read().unwrap();
         Ok(projects.values().find(|p| p.name == name).cloned())
     }
 
+    async fn find_by_gitea_repo(
+        &self,
+        gitea_org: &str,
+        gitea_repo: &str,
+    ) -> Result<Option<Project>, DomainError> {
+        let projects = self.projects.read().unwrap();
+        Ok(projects
+            .values()
+            .find(|p| p.gitea_org == gitea_org && p.gitea_repo == gitea_repo)
+            .cloned())
+    }
+
     async fn find_active(&self, limit: i64, offset: i64) -> Result<Vec<Project>, DomainError> {
         let projects = self.projects.read().unwrap();
         Ok(projects
             .values()
             .filter(|p| p.status == ProjectStatus::Active)
+            .filter(|p| p.visibility == ProjectVisibility::Public)
+            .skip(offset as usize)
+            .take(limit as usize)
+            .cloned()
+            .collect())
+    }
+
+    async fn count_active(&self) -> Result<i64, DomainError> {
+        let projects = self.projects.read().unwrap();
+        Ok(projects
+            .values()
+            .filter(|p| p.status == ProjectStatus::Active)
+            .filter(|p| p.visibility == ProjectVisibility::Public)
+            .count() as i64)
+    }
+
+    async fn find_active_by_language(
+        &self,
+        languages: &[String],
+        limit: i64,
+        offset: i64,
+    ) -> Result<Vec<Project>, DomainError> {
+        let projects = self.projects.read().unwrap();
+        Ok(projects
+            .values()
+            .filter(|p| p.status == ProjectStatus::Active)
+            .filter(|p| p.visibility == ProjectVisibility::Public)
+            .filter(|p| {
+                p.language
+                    .as_deref()
+                    .is_some_and(|l| languages.contains(&l.trim().to_lowercase()))
+            })
             .skip(offset as usize)
             .take(limit as usize)
             .cloned()
             .collect())
     }
 
+    async fn count_active_by_language(&self, languages: &[String]) -> Result<i64, DomainError> {
+        Ok(self
+            .find_active_by_language(languages, i64::MAX, 0)
+            .await?
+            .len() as i64)
+    }
+
+    async fn count_active_languages(&self) -> Result<Vec<(String, i64)>, DomainError> {
+        let projects = self.projects.read().unwrap();
+        let mut counts: HashMap<String, i64> = HashMap::new();
+        for project in projects
+            .values()
+            .filter(|p| p.status == ProjectStatus::Active)
+            .filter(|p| p.visibility == ProjectVisibility::Public)
+        {
+            let Some(language) = project.language.as_deref().map(|l| l.trim().to_lowercase())
+            else {
+                continue;
+            };
+            if !language.is_empty() {
+                *counts.entry(language).or_default() += 1;
+            }
+        }
+        Ok(counts.into_iter().collect())
+    }
+
     async fn find_all(&self, limit: i64, offset: i64) -> Result<Vec<Project>, DomainError> {
         let projects = self.projects.read().unwrap();
         Ok(projects
@@ -603,6 +812,17 @@ impl ProjectRepository for InMemoryProjectRepository {
             build_status: BuildStatus::Unknown,
             created_by: new_project.created_by,
             created_at: Utc::now(),
+            mirror: None,
+            allow_alternate_bases: false,
+            require_green_ci: false,
+            required_approvals: 0,
+            featured: false,
+            featured_rank: 0,
+            elo_multiplier: 1.0,
+            visibility: ProjectVisibility::Public,
+            review_eligibility: ReviewEligibility::Open,
+            protect_default_branch: false,
+            star_count: 0,
         };
 
         let mut projects = self.projects.write().unwrap();
@@ -624,16 +844,166 @@ impl ProjectRepository for InMemoryProjectRepository {
         }
     }
 
-    async fn update_stats(
+    async fn set_mirror(&self, id: &ProjectId, mirror: &ProjectMirror) -> Result<(), DomainError> {
+        let mut projects = self.projects.write().unwrap();
+        if let Some(project) = projects.get_mut(id) {
+            project.mirror = Some(mirror.clone());
+            Ok(())
+        } else {
+            Err(DomainError::NotFound(format!("Project {} not found", id)))
+        }
+    }
+
+    async fn set_allow_alternate_bases(
+        &self,
+        id: &ProjectId,
+        allowed: bool,
+    ) -> Result<(), DomainError> {
+        let mut projects = self.projects.write().unwrap();
+        if let Some(project) = projects.get_mut(id) {
+            project.allow_alternate_bases = allowed;
+            Ok(())
+        } else {
+            Err(DomainError::NotFound(format!("Project {} not found", id)))
+        }
+    }
+
+    async fn set_protect_default_branch(
+        &self,
+        id: &ProjectId,
+        enabled: bool,
+    ) -> Result<(), DomainError> {
+        let mut projects = self.projects.write().unwrap();
+        if let Some(project) = projects.get_mut(id) {
+            project.protect_default_branch = enabled;
+            Ok(())
+        } else {
+            Err(DomainError::NotFound(format!("Project {} not found", id)))
+        }
+    }
+
+    async fn set_require_green_ci(
+        &self,
+        id: &ProjectId,
+        required: bool,
+    ) -> Result<(), DomainError> {
+        let mut projects = self.projects.write().unwrap();
+        if let Some(project) = projects.get_mut(id) {
+            project.require_green_ci = required;
+            Ok(())
+        } else {
+            Err(DomainError::NotFound(format!("Project {} not found", id)))
+        }
+    }
+
+    async fn set_required_approvals(&self, id: &ProjectId, count: i32) -> Result<(), DomainError> {
+        let mut projects = self.projects.write().unwrap();
+        if let Some(project) = projects.get_mut(id) {
+            project.required_approvals = count;
+            Ok(())
+        } else {
+            Err(DomainError::NotFound(format!("Project {} not found", id)))
+        }
+    }
+
+    async fn set_visibility(
+        &self,
+        id: &ProjectId,
+        visibility: ProjectVisibility,
+    ) -> Result<(), DomainError> {
+        let mut projects = self.projects.write().unwrap();
+        if let Some(project) = projects.get_mut(id) {
+            project.visibility = visibility;
+            Ok(())
+        } else {
+            Err(DomainError::NotFound(format!("Project {} not found", id)))
+        }
+    }
+
+    async fn set_review_eligibility(
+        &self,
+        id: &ProjectId,
+        eligibility: ReviewEligibility,
+    ) -> Result<(), DomainError> {
+        let mut projects = self.projects.write().unwrap();
+        if let Some(project) = projects.get_mut(id) {
+            project.review_eligibility = eligibility;
+            Ok(())
+        } else {
+            Err(DomainError::NotFound(format!("Project {} not found", id)))
+        }
+    }
+
+    async fn set_featured(
         &self,
         id: &ProjectId,
-        contributor_count: i32,
-        open_ticket_count: i32,
+        featured: bool,
+        rank: i32,
     ) -> Result<(), DomainError> {
         let mut projects = self.projects.write().unwrap();
         if let Some(project) = projects.get_mut(id) {
-            project.contributor_count = contributor_count;
-            project.open_ticket_count = open_ticket_count;
+            project.featured = featured;
+            project.featured_rank = rank;
+            Ok(())
+        } else {
+            Err(DomainError::NotFound(format!("Project {} not found", id)))
+        }
+    }
+
+    async fn set_elo_multiplier(&self, id: &ProjectId, multiplier: f32) -> Result<(), DomainError> {
+        let mut projects = self.projects.write().unwrap();
+        if let Some(project) = projects.get_mut(id) {
+            project.elo_multiplier = multiplier;
+            Ok(())
+        } else {
+            Err(DomainError::NotFound(format!("Project {} not found", id)))
+        }
+    }
+
+    async fn find_featured(&self, limit: i64) -> Result<Vec<Project>, DomainError> {
+        let projects = self.projects.read().unwrap();
+        let mut featured: Vec<Project> = projects
+            .values()
+            .filter(|p| p.featured && p.visibility == ProjectVisibility::Public)
+            .cloned()
+            .collect();
+        featured.sort_by(|a, b| {
+            a.featured_rank
+                .cmp(&b.featured_rank)
+                .then_with(|| a.name.cmp(&b.name))
+        });
+        featured.truncate(limit as usize);
+        Ok(featured)
+    }
+
+    async fn set_contributor_count(&self, id: &ProjectId, count: i32) -> Result<(), DomainError> {
+        let mut projects = self.projects.write().unwrap();
+        if let Some(project) = projects.get_mut(id) {
+            project.contributor_count = count;
+            Ok(())
+        } else {
+            Err(DomainError::NotFound(format!("Project {} not found", id)))
+        }
+    }
+
+    async fn set_open_ticket_count(&self, id: &ProjectId, count: i32) -> Result<(), DomainError> {
+        self.ticket_count_updates
+            .write()
+            .unwrap()
+            .push((*id, count));
+        let mut projects = self.projects.write().unwrap();
+        if let Some(project) = projects.get_mut(id) {
+            project.open_ticket_count = count;
+            Ok(())
+        } else {
+            Err(DomainError::NotFound(format!("Project {} not found", id)))
+        }
+    }
+
+    async fn set_star_count(&self, id: &ProjectId, count: i32) -> Result<(), DomainError> {
+        let mut projects = self.projects.write().unwrap();
+        if let Some(project) = projects.get_mut(id) {
+            project.star_count = count;
             Ok(())
         } else {
             Err(DomainError::NotFound(format!("Project {} not found", id)))
@@ -688,6 +1058,66 @@ impl ProjectRepository for InMemoryProjectRepository {
             .any(|m| m.project_id == *project_id && m.agent_id == *agent_id))
     }
 
+    async fn add_invite(
+        &self,
+        project_id: &ProjectId,
+        agent_id: &AgentId,
+        _invited_by: &AgentId,
+    ) -> Result<(), DomainError> {
+        let mut invites = self.invites.write().unwrap();
+        if !invites.contains(&(*project_id, *agent_id)) {
+            invites.push((*project_id, *agent_id));
+        }
+        Ok(())
+    }
+
+    async fn is_invited(
+        &self,
+        project_id: &ProjectId,
+        agent_id: &AgentId,
+    ) -> Result<bool, DomainError> {
+        let invites = self.invites.read().unwrap();
+        Ok(invites.contains(&(*project_id, *agent_id)))
+    }
+
+    async fn record_force_merge(
+        &self,
+        project_id: &ProjectId,
+        agent_id: &AgentId,
+        pr_number: i64,
+        waived_approvals: i32,
+    ) -> Result<(), DomainError> {
+        self.force_merges.write().unwrap().push(ForceMergeRecord {
+            project_id: *project_id,
+            agent_id: *agent_id,
+            pr_number,
+            waived_approvals,
+        });
+        Ok(())
+    }
+
+    async fn find_nudged_prs(&self, project_id: &ProjectId) -> Result<Vec<i64>, DomainError> {
+        let nudges = self.pr_nudges.read().unwrap();
+        Ok(nudges
+            .iter()
+            .filter(|(project, _)| project == project_id)
+            .map(|(_, number)| *number)
+            .collect())
+    }
+
+    async fn record_pr_nudge(
+        &self,
+        project_id: &ProjectId,
+        pr_number: i64,
+        _nudged_at: DateTime<Utc>,
+    ) -> Result<(), DomainError> {
+        let mut nudges = self.pr_nudges.write().unwrap();
+        if !nudges.contains(&(*project_id, pr_number)) {
+            nudges.push((*project_id, pr_number));
+        }
+        Ok(())
+    }
+
     async fn find_by_agent(&self, agent_id: &AgentId) -> Result<Vec<Project>, DomainError> {
         let members = self.members.read().unwrap();
         let projects = self.projects.read().unwrap();
@@ -705,6 +1135,21 @@ impl ProjectRepository for InMemoryProjectRepository {
             .collect())
     }
 
+    async fn find_by_agent_with_role(
+        &self,
+        agent_id: &AgentId,
+        role: MemberRole,
+    ) -> Result<Vec<Project>, DomainError> {
+        let members = self.members.read().unwrap();
+        let projects = self.projects.read().unwrap();
+
+        Ok(members
+            .iter()
+            .filter(|m| m.agent_id == *agent_id && m.role == role)
+            .filter_map(|m| projects.get(&m.project_id).cloned())
+            .collect())
+    }
+
     async fn get_member_role(
         &self,
         project_id: &ProjectId,
@@ -765,15 +1210,27 @@ impl ProjectRepository for InMemoryProjectRepository {
 type RepoKey = (String, String);
 /// Key for identifying a branch (org/repo/branch)
 type BranchKey = (String, String, String);
+/// Key for identifying a comparison (org/repo/base/head)
+type CompareKey = (String, String, String, String);
 /// Key for identifying a PR (org/repo/number)
 type PrKey = (String, String, i64);
 /// Key for identifying user PRs (org/repo/username)
 type UserPrKey = (String, String, String);
+/// Key for identifying a commit (org/repo/sha)
+type CommitKey = (String, String, String);
+/// Labels added to an issue (number, labels)
+type IssueLabels = (i64, Vec<String>);
+/// Reviewers requested on a PR (PR key, usernames)
+type PrReviewers = (PrKey, Vec<String>);
+/// Key for a file at a ref (org/repo/path/ref)
+type FileAtRefKey = (String, String, String, String);
 
 /// A mock Gitea client that tracks calls and returns configurable responses
 #[derive(Default)]
 pub struct MockGiteaClient {
     pub users_created: Arc<RwLock<Vec<String>>>,
+    /// Gitea users that exist outside of this test's registrations
+    existing_users: Arc<RwLock<std::collections::HashSet<String>>>,
     pub should_fail: Arc<RwLock<bool>>,
     /// Branches that exist (org, repo, branch)
     branches: Arc<RwLock<HashMap<BranchKey, GiteaBranch>>>,
@@ -783,6 +1240,83 @@ pub struct MockGiteaClient {
     pr_creation_enabled: Arc<RwLock<std::collections::HashSet<RepoKey>>>,
     /// User PRs (org, repo, username) -> list of PRs
     user_prs: Arc<RwLock<HashMap<UserPrKey, Vec<GiteaPullRequest>>>>,
+    /// Submitted reviews (org, repo, number) -> list of reviews
+    pub reviews: Arc<RwLock<HashMap<PrKey, Vec<GiteaPRReview>>>>,
+    /// Registered webhooks (org, repo, url)
+    pub webhooks: Arc<RwLock<Vec<(String, String, String)>>>,
+    /// Repos listed under each organization
+    org_repos: Arc<RwLock<HashMap<String, Vec<GiteaRepo>>>>,
+    /// Requested mirrors (source_url, org, name)
+    pub mirrors: Arc<RwLock<Vec<(String, String, String)>>>,
+    /// Repository labels created via create_repo_label
+    pub labels_created: Arc<RwLock<Vec<GiteaLabel>>>,
+    /// Labels added to issues (issue number, labels)
+    pub issue_labels_added: Arc<RwLock<Vec<IssueLabels>>>,
+    /// Commits resolvable via get_commit (org, repo, sha)
+    commits: Arc<RwLock<HashMap<CommitKey, GiteaCommitDetails>>>,
+    /// Current label names on each issue (org, repo, number)
+    issue_labels: Arc<RwLock<HashMap<PrKey, Vec<String>>>>,
+    /// PRs that conflict with their base (org, repo, number)
+    conflicting_prs: Arc<RwLock<std::collections::HashSet<PrKey>>>,
+    /// Combined CI state per commit (org, repo, sha); unknown commits are "success"
+    commit_statuses: Arc<RwLock<HashMap<CommitKey, String>>>,
+    commit_status_contexts: Arc<RwLock<HashMap<CommitKey, Vec<GiteaStatus>>>>,
+    /// PRs merged via merge_pull_request (org, repo, number)
+    pub merged_prs: Arc<RwLock<Vec<PrKey>>>,
+    /// Reactions on each issue or PR (org, repo, number)
+    issue_reactions: Arc<RwLock<HashMap<PrKey, Vec<GiteaReaction>>>>,
+    /// Comments posted via create_issue_comment, keyed by (org, repo, number)
+    pub posted_comments: Arc<RwLock<Vec<(PrKey, String)>>>,
+    /// Existing issue comments (org, repo, comment id)
+    issue_comments: Arc<RwLock<HashMap<PrKey, GiteaIssueComment>>>,
+    /// Usernames whose creation fails
+    failing_users: Arc<RwLock<std::collections::HashSet<String>>>,
+    /// Users deleted via delete_user
+    pub users_deleted: Arc<RwLock<Vec<String>>>,
+    /// Releases created via create_release (org, repo, release)
+    pub releases: Arc<RwLock<Vec<(String, String, GiteaRelease)>>>,
+    /// Settings changes made via update_repo (owner, repo, settings)
+    pub repo_updates: Arc<RwLock<Vec<(String, String, RepoSettings)>>>,
+    /// Protection rules set via set_branch_protection ((owner, repo, branch), rules)
+    pub branch_protections: Arc<RwLock<Vec<(BranchKey, BranchProtection)>>>,
+    /// Repos generated via create_repo_from_template ((owner, repo), (template owner, template repo))
+    pub template_repos: Arc<RwLock<Vec<(RepoKey, RepoKey)>>>,
+    /// Comparisons returned by compare_commits, keyed by (org, repo, base, head)
+    comparisons: Arc<RwLock<HashMap<CompareKey, GiteaComparison>>>,
+    /// Contribution heatmaps per username (users without one have no activity)
+    heatmaps: Arc<RwLock<HashMap<String, Vec<GiteaHeatmapEntry>>>>,
+    /// Notification inboxes per user token (other tokens have an empty inbox)
+    notifications: Arc<RwLock<HashMap<String, Vec<GiteaNotification>>>>,
+    /// User tokens without the notification scope
+    notification_denied_tokens: Arc<RwLock<std::collections::HashSet<String>>>,
+    /// Access tokens that exist (username, token name)
+    access_tokens: Arc<RwLock<std::collections::HashSet<(String, String)>>>,
+    /// Access tokens deleted via delete_access_token (username, token name)
+    pub tokens_deleted: Arc<RwLock<Vec<(String, String)>>>,
+    /// Emails of users, by login; other users have a `@test.com` address
+    user_emails: Arc<RwLock<HashMap<String, String>>>,
+    /// Users whose password was set via set_user_password
+    pub passwords_set: Arc<RwLock<Vec<String>>>,
+    /// Commits on each PR (org, repo, number)
+    pr_commits: Arc<RwLock<HashMap<PrKey, Vec<GiteaCommitDetails>>>>,
+    /// Comments on each PR (org, repo, number); unseeded PRs return a stock comment
+    pr_comments: Arc<RwLock<HashMap<PrKey, Vec<GiteaComment>>>>,
+    /// Comments posted via post_pr_comment, keyed by (org, repo, number)
+    pub posted_pr_comments: Arc<RwLock<Vec<(PrKey, String)>>>,
+    /// Tokens that have starred each repo (owner, repo)
+    stars: Arc<RwLock<HashMap<RepoKey, std::collections::HashSet<String>>>>,
+    /// Reviewers requested via request_reviewers, keyed by (org, repo, number)
+    pub requested_reviewers: Arc<RwLock<Vec<PrReviewers>>>,
+    /// Unified diffs returned for PRs (org, repo, number)
+    pr_diffs: Arc<RwLock<HashMap<PrKey, String>>>,
+    /// File contents at a ref (org, repo, path, ref)
+    file_contents: Arc<RwLock<HashMap<FileAtRefKey, String>>>,
+    /// PRs whose lookup fails with a server error (org, repo, number)
+    unreachable_prs: Arc<RwLock<std::collections::HashSet<PrKey>>>,
+    /// Open issue counts reported by get_repo (owner, repo); unseeded repos have none
+    open_issues: Arc<RwLock<HashMap<RepoKey, i64>>>,
+    /// Labels shared by each organization with its repos
+    org_labels: Arc<RwLock<HashMap<String, Vec<String>>>>,
 }
 
 impl MockGiteaClient {
@@ -792,21 +1326,60 @@ impl MockGiteaClient {
 
     pub fn failing() -> Self {
         Self {
-            users_created: Arc::new(RwLock::new(Vec::new())),
             should_fail: Arc::new(RwLock::new(true)),
-            branches: Arc::new(RwLock::new(HashMap::new())),
-            prs: Arc::new(RwLock::new(HashMap::new())),
-            pr_creation_enabled: Arc::new(RwLock::new(std::collections::HashSet::new())),
-            user_prs: Arc::new(RwLock::new(HashMap::new())),
+            ..Self::default()
         }
     }
 
-    /// Configure a branch to exist
-    pub fn with_branch(self, org: &str, repo: &str, branch: &str) -> Self {
+    /// Configure the repos listed under an organization
+    pub fn with_org_repos(self, org: &str, names: &[&str]) -> Self {
         {
-            let mut branches = self.branches.write().unwrap();
-            branches.insert(
-                (org.to_string(), repo.to_string(), branch.to_string()),
+            let mut org_repos = self.org_repos.write().unwrap();
+            org_repos.insert(
+                org.to_string(),
+                names
+                    .iter()
+                    .enumerate()
+                    .map(|(i, name)| mock_repo(i as i64 + 1, org, name))
+                    .collect(),
+            );
+        }
+        self
+    }
+
+    /// Configure an existing issue comment written by `author`
+    pub fn with_issue_comment(
+        self,
+        org: &str,
+        repo: &str,
+        comment_id: i64,
+        author: &str,
+        body: &str,
+    ) -> Self {
+        self.issue_comments.write().unwrap().insert(
+            (org.to_string(), repo.to_string(), comment_id),
+            GiteaIssueComment {
+                id: comment_id,
+                body: body.to_string(),
+                user: GiteaUser {
+                    id: 1,
+                    login: author.to_string(),
+                    email: format!("{}@test.com", author),
+                    full_name: None,
+                },
+                created_at: "2026-01-01T00:00:00Z".to_string(),
+                updated_at: "2026-01-01T00:00:00Z".to_string(),
+            },
+        );
+        self
+    }
+
+    /// Configure a branch to exist
+    pub fn with_branch(self, org: &str, repo: &str, branch: &str) -> Self {
+        {
+            let mut branches = self.branches.write().unwrap();
+            branches.insert(
+                (org.to_string(), repo.to_string(), branch.to_string()),
                 GiteaBranch {
                     name: branch.to_string(),
                     commit: GiteaCommit {
@@ -819,7 +1392,125 @@ impl MockGiteaClient {
         self
     }
 
+    /// Configure a user's contribution heatmap as (unix timestamp, contributions) buckets
+    pub fn with_heatmap(self, username: &str, buckets: &[(i64, i64)]) -> Self {
+        self.heatmaps.write().unwrap().insert(
+            username.to_string(),
+            buckets
+                .iter()
+                .map(|&(timestamp, contributions)| GiteaHeatmapEntry {
+                    timestamp,
+                    contributions,
+                })
+                .collect(),
+        );
+        self
+    }
+
+    /// Configure the notifications get_notifications returns for a user token
+    pub fn with_notifications(
+        self,
+        user_token: &str,
+        notifications: Vec<GiteaNotification>,
+    ) -> Self {
+        self.notifications
+            .write()
+            .unwrap()
+            .insert(user_token.to_string(), notifications);
+        self
+    }
+
+    /// Make get_notifications fail with 403 for a token lacking the scope
+    pub fn with_notifications_denied(self, user_token: &str) -> Self {
+        self.notification_denied_tokens
+            .write()
+            .unwrap()
+            .insert(user_token.to_string());
+        self
+    }
+
+    /// Configure a commit's metadata for get_commit
+    pub fn with_commit(
+        self,
+        org: &str,
+        repo: &str,
+        sha: &str,
+        author_login: Option<&str>,
+        message: &str,
+    ) -> Self {
+        {
+            let mut commits = self.commits.write().unwrap();
+            commits.insert(
+                (org.to_string(), repo.to_string(), sha.to_string()),
+                GiteaCommitDetails {
+                    sha: sha.to_string(),
+                    message: message.to_string(),
+                    author_login: author_login.map(String::from),
+                    timestamp: "2026-01-15T12:00:00Z".to_string(),
+                },
+            );
+        }
+        self
+    }
+
+    /// Configure a label defined at the organization level
+    pub fn with_org_label(self, org: &str, label: &str) -> Self {
+        self.org_labels
+            .write()
+            .unwrap()
+            .entry(org.to_string())
+            .or_default()
+            .push(label.to_string());
+        self
+    }
+
+    /// Configure the labels currently on an issue
+    pub fn with_issue_labels(self, org: &str, repo: &str, number: i64, labels: &[&str]) -> Self {
+        {
+            let mut issue_labels = self.issue_labels.write().unwrap();
+            issue_labels.insert(
+                (org.to_string(), repo.to_string(), number),
+                labels.iter().map(|l| l.to_string()).collect(),
+            );
+        }
+        self
+    }
+
     /// Configure a PR to exist
+    /// Configure how `head` compares to `base` (unconfigured comparisons return 404)
+    pub fn with_comparison(
+        self,
+        org: &str,
+        repo: &str,
+        base: &str,
+        head: &str,
+        ahead_by: i64,
+        behind_by: i64,
+    ) -> Self {
+        let commits = (0..ahead_by)
+            .map(|i| GiteaCommitDetails {
+                sha: format!("{}{:04}", head, i),
+                message: format!("Commit {} on {}", i + 1, head),
+                author_login: None,
+                timestamp: "2026-01-01T00:00:00Z".to_string(),
+            })
+            .collect();
+        self.comparisons.write().unwrap().insert(
+            (
+                org.to_string(),
+                repo.to_string(),
+                base.to_string(),
+                head.to_string(),
+            ),
+            GiteaComparison {
+                ahead_by,
+                behind_by,
+                commits,
+            },
+        );
+        self
+    }
+
     pub fn with_pr(self, org: &str, repo: &str, number: i64) -> Self {
         {
             let mut prs = self.prs.write().unwrap();
@@ -842,12 +1533,285 @@ impl MockGiteaClient {
                     },
                     merged: false,
                     user: None,
+                    merged_at: None,
+                    merge_commit_sha: None,
+                    draft: false,
+                },
+            );
+        }
+        self
+    }
+
+    /// Configure an open draft PR
+    pub fn with_draft_pr(self, org: &str, repo: &str, number: i64) -> Self {
+        let this = self.with_pr(org, repo, number);
+        if let Some(pr) =
+            this.prs
+                .write()
+                .unwrap()
+                .get_mut(&(org.to_string(), repo.to_string(), number))
+        {
+            pr.draft = true;
+        }
+        this
+    }
+
+    /// Configure a commit on a PR, authored at `timestamp` (RFC 3339)
+    pub fn with_pr_commit(
+        self,
+        org: &str,
+        repo: &str,
+        number: i64,
+        sha: &str,
+        timestamp: &str,
+    ) -> Self {
+        self.pr_commits
+            .write()
+            .unwrap()
+            .entry((org.to_string(), repo.to_string(), number))
+            .or_default()
+            .push(GiteaCommitDetails {
+                sha: sha.to_string(),
+                message: format!("Commit {}", sha),
+                author_login: None,
+                timestamp: timestamp.to_string(),
+            });
+        self
+    }
+
+    /// Configure a comment on a PR by `author`, posted at `created_at` (RFC 3339)
+    pub fn with_pr_comment(
+        self,
+        org: &str,
+        repo: &str,
+        number: i64,
+        author: &str,
+        created_at: &str,
+    ) -> Self {
+        {
+            let mut comments = self.pr_comments.write().unwrap();
+            let thread = comments
+                .entry((org.to_string(), repo.to_string(), number))
+                .or_default();
+            thread.push(GiteaComment {
+                id: thread.len() as i64 + 1,
+                body: "Comment".to_string(),
+                user: GiteaUser {
+                    id: 1,
+                    login: author.to_string(),
+                    email: format!("{}@test.com", author),
+                    full_name: None,
+                },
+                created_at: created_at.to_string(),
+                updated_at: created_at.to_string(),
+            });
+        }
+        self
+    }
+
+    /// Configure a merged PR authored by `author`
+    pub fn with_merged_pr(self, org: &str, repo: &str, number: i64, author: &str) -> Self {
+        {
+            let mut prs = self.prs.write().unwrap();
+            prs.insert(
+                (org.to_string(), repo.to_string(), number),
+                GiteaPullRequest {
+                    id: number,
+                    number,
+                    title: format!("PR #{}", number),
+                    body: None,
+                    state: "closed".to_string(),
+                    html_url: format!("https://gitea.local/{}/{}/pulls/{}", org, repo, number),
+                    head: GiteaPRBranch {
+                        ref_name: "feature".to_string(),
+                        sha: "abc123".to_string(),
+                    },
+                    base: GiteaPRBranch {
+                        ref_name: "main".to_string(),
+                        sha: "def456".to_string(),
+                    },
+                    merged: true,
+                    user: Some(GiteaUser {
+                        id: number,
+                        login: author.to_string(),
+                        email: format!("{}@test.com", author),
+                        full_name: None,
+                    }),
+                    merged_at: Some("2026-01-15T12:00:00Z".to_string()),
+                    merge_commit_sha: Some(format!("merge{}", number)),
+                    draft: false,
                 },
             );
         }
         self
     }
 
+    /// Make looking up a PR or its commits fail with a server error
+    pub fn with_unreachable_pr(self, org: &str, repo: &str, number: i64) -> Self {
+        self.unreachable_prs
+            .write()
+            .unwrap()
+            .insert((org.to_string(), repo.to_string(), number));
+        self
+    }
+
+    /// Set the open issue count Gitea reports for a repo
+    pub fn with_open_issues(self, owner: &str, repo: &str, count: i64) -> Self {
+        self.open_issues
+            .write()
+            .unwrap()
+            .insert((owner.to_string(), repo.to_string()), count);
+        self
+    }
+
+    /// Configure a PR that was closed without being merged
+    pub fn with_closed_pr(self, org: &str, repo: &str, number: i64) -> Self {
+        let this = self.with_pr(org, repo, number);
+        {
+            let mut prs = this.prs.write().unwrap();
+            if let Some(pr) = prs.get_mut(&(org.to_string(), repo.to_string(), number)) {
+                pr.state = "closed".to_string();
+            }
+        }
+        this
+    }
+
+    /// Add a reaction left on an issue or PR by a Gitea user
+    pub fn with_issue_reaction(
+        self,
+        org: &str,
+        repo: &str,
+        number: i64,
+        login: &str,
+        content: &str,
+    ) -> Self {
+        {
+            let mut reactions = self.issue_reactions.write().unwrap();
+            let list = reactions
+                .entry((org.to_string(), repo.to_string(), number))
+                .or_default();
+            list.push(GiteaReaction {
+                id: list.len() as i64 + 1,
+                user: GiteaUser {
+                    id: 1,
+                    login: login.to_string(),
+                    email: format!("{}@test.com", login),
+                    full_name: None,
+                },
+                content: content.to_string(),
+                created_at: "2024-01-01T00:00:00Z".to_string(),
+            });
+        }
+        self
+    }
+
+    /// Set the unified diff returned for a PR
+    pub fn with_pr_diff(self, org: &str, repo: &str, number: i64, diff: &str) -> Self {
+        self.pr_diffs.write().unwrap().insert(
+            (org.to_string(), repo.to_string(), number),
+            diff.to_string(),
+        );
+        self
+    }
+
+    /// Add a file's contents as of a ref
+    pub fn with_file_at_ref(
+        self,
+        org: &str,
+        repo: &str,
+        path: &str,
+        git_ref: &str,
+        contents: &str,
+    ) -> Self {
+        self.file_contents.write().unwrap().insert(
+            (
+                org.to_string(),
+                repo.to_string(),
+                path.to_string(),
+                git_ref.to_string(),
+            ),
+            contents.to_string(),
+        );
+        self
+    }
+
+    /// Add a pre-existing Gitea user
+    pub fn with_user(self, login: &str) -> Self {
+        self.existing_users
+            .write()
+            .unwrap()
+            .insert(login.to_string());
+        self
+    }
+
+    /// Add a pre-existing Gitea user with the given email
+    pub fn with_user_email(self, login: &str, email: &str) -> Self {
+        self.user_emails
+            .write()
+            .unwrap()
+            .insert(login.to_string(), email.to_string());
+        self.with_user(login)
+    }
+
+    /// Configure an access token that already exists for a user
+    pub fn with_access_token(self, username: &str, token_name: &str) -> Self {
+        self.access_tokens
+            .write()
+            .unwrap()
+            .insert((username.to_string(), token_name.to_string()));
+        self
+    }
+
+    /// Make creating the given user fail
+    pub fn with_failing_user(self, username: &str) -> Self {
+        self.failing_users
+            .write()
+            .unwrap()
+            .insert(username.to_string());
+        self
+    }
+
+    /// Mark a configured PR as conflicting with its base branch
+    pub fn with_conflicting_pr(self, org: &str, repo: &str, number: i64) -> Self {
+        self.conflicting_prs
+            .write()
+            .unwrap()
+            .insert((org.to_string(), repo.to_string(), number));
+        self
+    }
+
+    /// Configure the combined CI state reported for a commit
+    pub fn with_commit_status(self, org: &str, repo: &str, sha: &str, state: &str) -> Self {
+        self.commit_statuses.write().unwrap().insert(
+            (org.to_string(), repo.to_string(), sha.to_string()),
+            state.to_string(),
+        );
+        self
+    }
+
+    /// Configure the individual CI checks reported for a commit, as `(context, state)`
+    pub fn with_commit_statuses(
+        self,
+        org: &str,
+        repo: &str,
+        sha: &str,
+        checks: &[(&str, &str)],
+    ) -> Self {
+        self.commit_status_contexts.write().unwrap().insert(
+            (org.to_string(), repo.to_string(), sha.to_string()),
+            checks
+                .iter()
+                .map(|(context, state)| GiteaStatus {
+                    state: state.to_string(),
+                    context: context.to_string(),
+                    description: None,
+                    target_url: None,
+                })
+                .collect(),
+        );
+        self
+    }
+
     /// Enable PR creation for a repo
     pub fn with_pr_creation(self, org: &str, repo: &str) -> Self {
         {
@@ -880,6 +1844,9 @@ impl MockGiteaClient {
                     },
                     merged: false,
                     user: None,
+                    merged_at: None,
+                    merge_commit_sha: None,
+                    draft: false,
                 }],
             );
         }
@@ -887,6 +1854,34 @@ impl MockGiteaClient {
     }
 }
 
+fn mock_labels(names: Vec<String>) -> Vec<GiteaLabel> {
+    names
+        .into_iter()
+        .map(|name| GiteaLabel {
+            id: 1,
+            name,
+            color: "cccccc".to_string(),
+            description: None,
+        })
+        .collect()
+}
+
+fn mock_repo(id: i64, owner: &str, name: &str) -> GiteaRepo {
+    GiteaRepo {
+        id,
+        name: name.to_string(),
+        full_name: format!("{}/{}", owner, name),
+        description: None,
+        clone_url: format!("https://gitea.local/{}/{}.git", owner, name),
+        ssh_url: format!("git@gitea.local:{}/{}.git", owner, name),
+        html_url: format!("https://gitea.local/{}/{}", owner, name),
+        default_branch: "main".to_string(),
+        private: false,
+        open_issues_count: 0,
+        stars_count: 0,
+    }
+}
+
 #[async_trait]
 impl GiteaClient for MockGiteaClient {
     async fn create_user(
@@ -902,10 +1897,21 @@ impl GiteaClient for MockGiteaClient {
             });
         }
 
+        if self.failing_users.read().unwrap().contains(username) {
+            return Err(GiteaError::Api {
+                status: 500,
+                message: format!("Mock failure creating {}", username),
+            });
+        }
+
         self.users_created
             .write()
             .unwrap()
             .push(username.to_string());
+        self.user_emails
+            .write()
+            .unwrap()
+            .insert(username.to_string(), email.to_string());
 
         Ok(GiteaUser {
             id: 1,
@@ -915,40 +1921,145 @@ impl GiteaClient for MockGiteaClient {
         })
     }
 
+    async fn delete_user(&self, username: &str) -> Result<(), GiteaError> {
+        if *self.should_fail.read().unwrap() {
+            return Err(GiteaError::Api {
+                status: 500,
+                message: "Mock failure".to_string(),
+            });
+        }
+
+        self.users_created
+            .write()
+            .unwrap()
+            .retain(|u| u != username);
+        self.users_deleted
+            .write()
+            .unwrap()
+            .push(username.to_string());
+        Ok(())
+    }
+
+    async fn set_user_password(&self, username: &str, _password: &str) -> Result<(), GiteaError> {
+        if *self.should_fail.read().unwrap() {
+            return Err(GiteaError::Api {
+                status: 500,
+                message: "Mock failure".to_string(),
+            });
+        }
+
+        self.passwords_set
+            .write()
+            .unwrap()
+            .push(username.to_string());
+        Ok(())
+    }
+
     async fn get_user(&self, username: &str) -> Result<GiteaUser, GiteaError> {
         if *self.should_fail.read().unwrap() {
             return Err(GiteaError::UserNotFound(username.to_string()));
         }
 
+        let exists = self.existing_users.read().unwrap().contains(username)
+            || self
+                .users_created
+                .read()
+                .unwrap()
+                .iter()
+                .any(|u| u == username);
+        if !exists {
+            return Err(GiteaError::UserNotFound(username.to_string()));
+        }
+
+        let email = self
+            .user_emails
+            .read()
+            .unwrap()
+            .get(username)
+            .cloned()
+            .unwrap_or_else(|| format!("{}@test.com", username));
         Ok(GiteaUser {
             id: 1,
             login: username.to_string(),
-            email: format!("{}@test.com", username),
+            email,
             full_name: None,
         })
     }
 
-    async fn create_access_token(
-        &self,
-        _username: &str,
-        _password: &str,
-        _token_name: &str,
-    ) -> Result<String, GiteaError> {
+    async fn get_user_heatmap(&self, username: &str) -> Result<Vec<GiteaHeatmapEntry>, GiteaError> {
         if *self.should_fail.read().unwrap() {
-            return Err(GiteaError::Unauthorized);
+            return Err(GiteaError::UserNotFound(username.to_string()));
         }
-        Ok("mock-token-12345".to_string())
-    }
 
-    async fn delete_access_token(
-        &self,
-        _username: &str,
-        _token_name: &str,
-    ) -> Result<(), GiteaError> {
-        Ok(())
+        Ok(self
+            .heatmaps
+            .read()
+            .unwrap()
+            .get(username)
+            .cloned()
+            .unwrap_or_default())
     }
 
-    async fn create_org(
+    async fn get_notifications(
+        &self,
+        user_token: &str,
+    ) -> Result<Vec<GiteaNotification>, GiteaError> {
+        if self
+            .notification_denied_tokens
+            .read()
+            .unwrap()
+            .contains(user_token)
+        {
+            return Err(GiteaError::Api {
+                status: 403,
+                message: "token does not have at least one of required scope(s)".to_string(),
+            });
+        }
+
+        Ok(self
+            .notifications
+            .read()
+            .unwrap()
+            .get(user_token)
+            .cloned()
+            .unwrap_or_default())
+    }
+
+    async fn create_access_token(
+        &self,
+        username: &str,
+        _password: &str,
+        token_name: &str,
+    ) -> Result<String, GiteaError> {
+        if *self.should_fail.read().unwrap() {
+            return Err(GiteaError::Unauthorized);
+        }
+        let created = self
+            .access_tokens
+            .write()
+            .unwrap()
+            .insert((username.to_string(), token_name.to_string()));
+        if !created {
+            return Err(GiteaError::Api {
+                status: 400,
+                message: "access token name has been used already".to_string(),
+            });
+        }
+        Ok("mock-token-12345".to_string())
+    }
+
+    async fn delete_access_token(
+        &self,
+        username: &str,
+        token_name: &str,
+    ) -> Result<(), GiteaError> {
+        let key = (username.to_string(), token_name.to_string());
+        self.access_tokens.write().unwrap().remove(&key);
+        self.tokens_deleted.write().unwrap().push(key);
+        Ok(())
+    }
+
+    async fn create_org(
         &self,
         name: &str,
         description: Option<&str>,
@@ -1040,9 +2151,35 @@ impl GiteaClient for MockGiteaClient {
             html_url: format!("https://gitea.local/{}/{}", org, name),
             default_branch: "main".to_string(),
             private,
+            open_issues_count: 0,
+            stars_count: 0,
         })
     }
 
+    async fn create_repo_from_template(
+        &self,
+        template_owner: &str,
+        template_repo: &str,
+        owner: &str,
+        name: &str,
+        description: Option<&str>,
+    ) -> Result<GiteaRepo, GiteaError> {
+        if *self.should_fail.read().unwrap() {
+            return Err(GiteaError::Api {
+                status: 500,
+                message: "Mock failure".to_string(),
+            });
+        }
+
+        self.template_repos.write().unwrap().push((
+            (owner.to_string(), name.to_string()),
+            (template_owner.to_string(), template_repo.to_string()),
+        ));
+        let mut repo = mock_repo(1, owner, name);
+        repo.description = description.map(String::from);
+        Ok(repo)
+    }
+
     async fn create_user_repo(
         &self,
         username: &str,
@@ -1069,21 +2206,48 @@ impl GiteaClient for MockGiteaClient {
             html_url: format!("https://gitea.local/{}/{}", username, name),
             default_branch: "main".to_string(),
             private,
+            open_issues_count: 0,
+            stars_count: 0,
         })
     }
 
     async fn get_repo(&self, owner: &str, name: &str) -> Result<GiteaRepo, GiteaError> {
-        Ok(GiteaRepo {
-            id: 1,
-            name: name.to_string(),
-            full_name: format!("{}/{}", owner, name),
-            description: None,
-            clone_url: format!("https://gitea.local/{}/{}.git", owner, name),
-            ssh_url: format!("git@gitea.local:{}/{}.git", owner, name),
-            html_url: format!("https://gitea.local/{}/{}", owner, name),
-            default_branch: "main".to_string(),
-            private: false,
-        })
+        let mut repo = mock_repo(1, owner, name);
+        let key = (owner.to_string(), name.to_string());
+        repo.open_issues_count = self
+            .open_issues
+            .read()
+            .unwrap()
+            .get(&key)
+            .copied()
+            .unwrap_or(0);
+        repo.stars_count = self
+            .stars
+            .read()
+            .unwrap()
+            .get(&key)
+            .map_or(0, |s| s.len() as i64);
+        Ok(repo)
+    }
+
+    async fn star_repo(&self, owner: &str, repo: &str, user_token: &str) -> Result<(), GiteaError> {
+        // Starring twice is a no-op, as in Gitea
+        self.stars
+            .write()
+            .unwrap()
+            .entry((owner.to_string(), repo.to_string()))
+            .or_default()
+            .insert(user_token.to_string());
+        Ok(())
+    }
+
+    async fn get_org_repos(&self, org: &str) -> Result<Vec<GiteaRepo>, GiteaError> {
+        self.org_repos
+            .read()
+            .unwrap()
+            .get(org)
+            .cloned()
+            .ok_or_else(|| GiteaError::OrgNotFound(org.to_string()))
     }
 
     async fn fork_repo(
@@ -1102,13 +2266,102 @@ impl GiteaClient for MockGiteaClient {
             html_url: format!("https://gitea.local/{}/{}", new_owner, repo),
             default_branch: "main".to_string(),
             private: false,
+            open_issues_count: 0,
+            stars_count: 0,
         })
     }
 
+    async fn create_mirror_repo(
+        &self,
+        source_url: &str,
+        org: &str,
+        name: &str,
+    ) -> Result<GiteaRepo, GiteaError> {
+        if *self.should_fail.read().unwrap() {
+            return Err(GiteaError::Api {
+                status: 500,
+                message: "Mock failure".to_string(),
+            });
+        }
+        self.mirrors.write().unwrap().push((
+            source_url.to_string(),
+            org.to_string(),
+            name.to_string(),
+        ));
+        Ok(mock_repo(1, org, name))
+    }
+
     async fn delete_repo(&self, _owner: &str, _name: &str) -> Result<(), GiteaError> {
         Ok(())
     }
 
+    async fn update_repo(
+        &self,
+        owner: &str,
+        name: &str,
+        settings: &RepoSettings,
+    ) -> Result<GiteaRepo, GiteaError> {
+        if *self.should_fail.read().unwrap() {
+            return Err(GiteaError::Api {
+                status: 500,
+                message: "Mock failure".to_string(),
+            });
+        }
+        self.repo_updates.write().unwrap().push((
+            owner.to_string(),
+            name.to_string(),
+            settings.clone(),
+        ));
+        let mut repo = mock_repo(1, owner, name);
+        if let Some(branch) = &settings.default_branch {
+            repo.default_branch = branch.clone();
+        }
+        if let Some(description) = &settings.description {
+            repo.description = Some(description.clone());
+        }
+        Ok(repo)
+    }
+
+    async fn set_branch_protection(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+        rules: &BranchProtection,
+    ) -> Result<(), GiteaError> {
+        if *self.should_fail.read().unwrap() {
+            return Err(GiteaError::Api {
+                status: 500,
+                message: "Mock failure".to_string(),
+            });
+        }
+        let mut protections = self.branch_protections.write().unwrap();
+        let key = (owner.to_string(), repo.to_string(), branch.to_string());
+        protections.retain(|(k, _)| *k != key);
+        protections.push((key, rules.clone()));
+        Ok(())
+    }
+
+    async fn remove_branch_protection(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+    ) -> Result<(), GiteaError> {
+        if *self.should_fail.read().unwrap() {
+            return Err(GiteaError::Api {
+                status: 500,
+                message: "Mock failure".to_string(),
+            });
+        }
+        let key = (owner.to_string(), repo.to_string(), branch.to_string());
+        self.branch_protections
+            .write()
+            .unwrap()
+            .retain(|(k, _)| *k != key);
+        Ok(())
+    }
+
     async fn create_file(
         &self,
         _owner: &str,
@@ -1121,6 +2374,26 @@ impl GiteaClient for MockGiteaClient {
         Ok(())
     }
 
+    async fn get_contents_at_ref(
+        &self,
+        owner: &str,
+        repo: &str,
+        path: &str,
+        git_ref: &str,
+    ) -> Result<Option<String>, GiteaError> {
+        Ok(self
+            .file_contents
+            .read()
+            .unwrap()
+            .get(&(
+                owner.to_string(),
+                repo.to_string(),
+                path.to_string(),
+                git_ref.to_string(),
+            ))
+            .cloned())
+    }
+
     async fn add_collaborator(
         &self,
         _owner: &str,
@@ -1150,6 +2423,44 @@ impl GiteaClient for MockGiteaClient {
         }
     }
 
+    async fn get_commit(
+        &self,
+        owner: &str,
+        repo: &str,
+        sha: &str,
+    ) -> Result<GiteaCommitDetails, GiteaError> {
+        let commits = self.commits.read().unwrap();
+        let key = (owner.to_string(), repo.to_string(), sha.to_string());
+        commits.get(&key).cloned().ok_or_else(|| GiteaError::Api {
+            status: 404,
+            message: format!("Commit '{}' not found", sha),
+        })
+    }
+
+    async fn compare_commits(
+        &self,
+        owner: &str,
+        repo: &str,
+        base: &str,
+        head: &str,
+    ) -> Result<GiteaComparison, GiteaError> {
+        let key = (
+            owner.to_string(),
+            repo.to_string(),
+            base.to_string(),
+            head.to_string(),
+        );
+        self.comparisons
+            .read()
+            .unwrap()
+            .get(&key)
+            .cloned()
+            .ok_or_else(|| GiteaError::Api {
+                status: 404,
+                message: format!("Cannot compare '{}' with '{}'", base, head),
+            })
+    }
+
     async fn list_branches(
         &self,
         _owner: &str,
@@ -1164,6 +2475,32 @@ impl GiteaClient for MockGiteaClient {
         }])
     }
 
+    async fn create_branch(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+        from: Option<&str>,
+    ) -> Result<GiteaBranch, GiteaError> {
+        let mut branches = self.branches.write().unwrap();
+        let key = (owner.to_string(), repo.to_string(), branch.to_string());
+        if branches.contains_key(&key) {
+            return Err(GiteaError::Api {
+                status: 409,
+                message: format!("Branch '{}' already exists", branch),
+            });
+        }
+        let created = GiteaBranch {
+            name: branch.to_string(),
+            commit: GiteaCommit {
+                id: "abc123".to_string(),
+                message: format!("Commit on {}", from.unwrap_or("main")),
+            },
+        };
+        branches.insert(key, created.clone());
+        Ok(created)
+    }
+
     async fn create_pull_request(
         &self,
         owner: &str,
@@ -1201,6 +2538,9 @@ impl GiteaClient for MockGiteaClient {
             },
             merged: false,
             user: None,
+            merged_at: None,
+            merge_commit_sha: None,
+            draft: false,
         })
     }
 
@@ -1212,6 +2552,12 @@ impl GiteaClient for MockGiteaClient {
     ) -> Result<GiteaPullRequest, GiteaError> {
         let prs = self.prs.read().unwrap();
         let key = (owner.to_string(), repo.to_string(), number);
+        if self.unreachable_prs.read().unwrap().contains(&key) {
+            return Err(GiteaError::Api {
+                status: 502,
+                message: "Bad gateway".to_string(),
+            });
+        }
         if let Some(pr) = prs.get(&key) {
             Ok(pr.clone())
         } else {
@@ -1222,13 +2568,71 @@ impl GiteaClient for MockGiteaClient {
         }
     }
 
+    async fn get_pr_raw_diff(
+        &self,
+        owner: &str,
+        repo: &str,
+        number: i64,
+    ) -> Result<String, GiteaError> {
+        let key = (owner.to_string(), repo.to_string(), number);
+        if let Some(diff) = self.pr_diffs.read().unwrap().get(&key) {
+            return Ok(diff.clone());
+        }
+        let pr = self.get_pull_request(owner, repo, number).await?;
+        Ok(format!(
+            "diff --git a/README.md b/README.md\n--- a/README.md\n+++ b/README.md\n@@ -1 +1 @@\n-# {}\n+# {}\n",
+            repo, pr.title
+        ))
+    }
+
+    async fn get_pull_request_merge_status(
+        &self,
+        owner: &str,
+        repo: &str,
+        number: i64,
+    ) -> Result<GiteaMergeStatus, GiteaError> {
+        let pr = self.get_pull_request(owner, repo, number).await?;
+        let key = (owner.to_string(), repo.to_string(), number);
+        Ok(GiteaMergeStatus {
+            state: pr.state,
+            merged: pr.merged,
+            mergeable: !self.conflicting_prs.read().unwrap().contains(&key),
+            head_sha: pr.head.sha,
+        })
+    }
+
     async fn list_pull_requests(
         &self,
-        _owner: &str,
-        _repo: &str,
-        _state: Option<&str>,
+        owner: &str,
+        repo: &str,
+        state: Option<&str>,
     ) -> Result<Vec<GiteaPullRequest>, GiteaError> {
-        Ok(vec![])
+        let prs = self.prs.read().unwrap();
+        let mut result: Vec<GiteaPullRequest> = prs
+            .iter()
+            .filter(|((o, r, _), pr)| {
+                o == owner && r == repo && state.is_none_or(|s| s == "all" || pr.state == s)
+            })
+            .map(|(_, pr)| pr.clone())
+            .collect();
+        result.sort_by_key(|pr| pr.number);
+        Ok(result)
+    }
+
+    async fn list_pull_requests_page(
+        &self,
+        owner: &str,
+        repo: &str,
+        state: Option<&str>,
+        page: usize,
+        limit: usize,
+    ) -> Result<Vec<GiteaPullRequest>, GiteaError> {
+        let prs = self.list_pull_requests(owner, repo, state).await?;
+        Ok(prs
+            .into_iter()
+            .skip(page.saturating_sub(1) * limit)
+            .take(limit)
+            .collect())
     }
 
     async fn get_user_prs(
@@ -1244,12 +2648,17 @@ impl GiteaClient for MockGiteaClient {
 
     async fn merge_pull_request(
         &self,
-        _owner: &str,
-        _repo: &str,
-        _number: i64,
+        owner: &str,
+        repo: &str,
+        number: i64,
         _merge_style: &str,
+        _force: bool,
         _auth_token: Option<&str>,
     ) -> Result<(), GiteaError> {
+        self.merged_prs
+            .write()
+            .unwrap()
+            .push((owner.to_string(), repo.to_string(), number));
         Ok(())
     }
 
@@ -1262,12 +2671,53 @@ impl GiteaClient for MockGiteaClient {
         Ok(())
     }
 
+    async fn request_reviewers(
+        &self,
+        owner: &str,
+        repo: &str,
+        number: i64,
+        reviewers: &[String],
+    ) -> Result<(), GiteaError> {
+        if *self.should_fail.read().unwrap() {
+            return Err(GiteaError::Api {
+                status: 500,
+                message: "Mock failure".to_string(),
+            });
+        }
+        self.requested_reviewers.write().unwrap().push((
+            (owner.to_string(), repo.to_string(), number),
+            reviewers.to_vec(),
+        ));
+        Ok(())
+    }
+
+    async fn get_pr_commits(
+        &self,
+        owner: &str,
+        repo: &str,
+        number: i64,
+    ) -> Result<Vec<GiteaCommitDetails>, GiteaError> {
+        let key = (owner.to_string(), repo.to_string(), number);
+        if self.unreachable_prs.read().unwrap().contains(&key) {
+            return Err(GiteaError::Api {
+                status: 500,
+                message: format!("PR #{} is unreachable", number),
+            });
+        }
+        let commits = self.pr_commits.read().unwrap();
+        Ok(commits.get(&key).cloned().unwrap_or_default())
+    }
+
     async fn get_pr_comments(
         &self,
-        _owner: &str,
-        _repo: &str,
-        _number: i64,
+        owner: &str,
+        repo: &str,
+        number: i64,
     ) -> Result<Vec<GiteaComment>, GiteaError> {
+        let key = (owner.to_string(), repo.to_string(), number);
+        if let Some(comments) = self.pr_comments.read().unwrap().get(&key) {
+            return Ok(comments.clone());
+        }
         Ok(vec![GiteaComment {
             id: 1,
             body: "Looks good!".to_string(),
@@ -1284,16 +2734,26 @@ impl GiteaClient for MockGiteaClient {
 
     async fn post_pr_comment(
         &self,
-        _owner: &str,
-        _repo: &str,
-        _number: i64,
+        owner: &str,
+        repo: &str,
+        number: i64,
         body: &str,
         _auth_token: Option<&str>,
     ) -> Result<GiteaComment, GiteaError> {
-        Ok(GiteaComment {
-            id: 2,
-            body: body.to_string(),
-            user: GiteaUser {
+        if *self.should_fail.read().unwrap() {
+            return Err(GiteaError::Api {
+                status: 500,
+                message: "Mock failure".to_string(),
+            });
+        }
+        self.posted_pr_comments.write().unwrap().push((
+            (owner.to_string(), repo.to_string(), number),
+            body.to_string(),
+        ));
+        Ok(GiteaComment {
+            id: 2,
+            body: body.to_string(),
+            user: GiteaUser {
                 id: 1,
                 login: "test-agent".to_string(),
                 email: "agent@test.com".to_string(),
@@ -1306,57 +2766,112 @@ impl GiteaClient for MockGiteaClient {
 
     async fn get_pr_reviews(
         &self,
-        _owner: &str,
-        _repo: &str,
-        _number: i64,
+        owner: &str,
+        repo: &str,
+        number: i64,
     ) -> Result<Vec<GiteaPRReview>, GiteaError> {
-        Ok(vec![])
+        let reviews = self.reviews.read().unwrap();
+        Ok(reviews
+            .get(&(owner.to_string(), repo.to_string(), number))
+            .cloned()
+            .unwrap_or_default())
+    }
+
+    async fn get_pr_review_by_user(
+        &self,
+        owner: &str,
+        repo: &str,
+        number: i64,
+        username: &str,
+    ) -> Result<Option<GiteaPRReview>, GiteaError> {
+        let reviews = self.get_pr_reviews(owner, repo, number).await?;
+        Ok(reviews
+            .into_iter()
+            .filter(|r| r.user.login == username)
+            .max_by_key(|r| r.id))
+    }
+
+    async fn delete_pr_review(
+        &self,
+        owner: &str,
+        repo: &str,
+        number: i64,
+        review_id: i64,
+        _auth_token: Option<&str>,
+    ) -> Result<(), GiteaError> {
+        let mut reviews = self.reviews.write().unwrap();
+        if let Some(list) = reviews.get_mut(&(owner.to_string(), repo.to_string(), number)) {
+            list.retain(|r| r.id != review_id);
+        }
+        Ok(())
     }
 
+    /// The mock treats the auth token as the reviewer's login
     async fn submit_pr_review(
         &self,
-        _owner: &str,
-        _repo: &str,
-        _number: i64,
+        owner: &str,
+        repo: &str,
+        number: i64,
         state: &str,
         body: Option<&str>,
-        _auth_token: Option<&str>,
+        auth_token: Option<&str>,
     ) -> Result<GiteaPRReview, GiteaError> {
-        Ok(GiteaPRReview {
-            id: 1,
+        let mut reviews = self.reviews.write().unwrap();
+        let list = reviews
+            .entry((owner.to_string(), repo.to_string(), number))
+            .or_default();
+        let login = auth_token.unwrap_or("reviewer");
+        let review = GiteaPRReview {
+            id: list.iter().map(|r| r.id).max().unwrap_or(0) + 1,
             user: GiteaUser {
                 id: 1,
-                login: "reviewer".to_string(),
-                email: "reviewer@test.com".to_string(),
+                login: login.to_string(),
+                email: format!("{}@test.com", login),
                 full_name: None,
             },
             state: state.to_string(),
             body: body.map(String::from),
             submitted_at: Some("2026-01-31T12:00:00Z".to_string()),
-        })
+        };
+        list.push(review.clone());
+        Ok(review)
     }
 
     async fn get_commit_status(
         &self,
-        _owner: &str,
-        _repo: &str,
-        _ref_name: &str,
+        owner: &str,
+        repo: &str,
+        ref_name: &str,
     ) -> Result<GiteaCombinedStatus, GiteaError> {
-        Ok(GiteaCombinedStatus {
-            state: "success".to_string(),
-            statuses: vec![],
-        })
+        let key = (owner.to_string(), repo.to_string(), ref_name.to_string());
+        let state = self
+            .commit_statuses
+            .read()
+            .unwrap()
+            .get(&key)
+            .cloned()
+            .unwrap_or_else(|| "success".to_string());
+        let statuses = self
+            .commit_status_contexts
+            .read()
+            .unwrap()
+            .get(&key)
+            .cloned()
+            .unwrap_or_default();
+        Ok(GiteaCombinedStatus { state, statuses })
     }
 
     async fn create_webhook(
         &self,
-        _owner: &str,
-        _repo: &str,
-        _url: &str,
+        owner: &str,
+        repo: &str,
+        url: &str,
         _events: Vec<String>,
         _secret: Option<&str>,
     ) -> Result<i64, GiteaError> {
-        Ok(1)
+        let mut webhooks = self.webhooks.write().unwrap();
+        webhooks.push((owner.to_string(), repo.to_string(), url.to_string()));
+        Ok(webhooks.len() as i64)
     }
 
     async fn delete_webhook(
@@ -1370,31 +2885,59 @@ impl GiteaClient for MockGiteaClient {
 
     async fn get_issue_reactions(
         &self,
-        _owner: &str,
-        _repo: &str,
-        _issue_number: i64,
+        owner: &str,
+        repo: &str,
+        issue_number: i64,
     ) -> Result<Vec<GiteaReaction>, GiteaError> {
-        Ok(vec![])
+        let key = (owner.to_string(), repo.to_string(), issue_number);
+        Ok(self
+            .issue_reactions
+            .read()
+            .unwrap()
+            .get(&key)
+            .cloned()
+            .unwrap_or_default())
     }
 
     async fn post_issue_reaction(
         &self,
-        _owner: &str,
-        _repo: &str,
-        _issue_number: i64,
+        owner: &str,
+        repo: &str,
+        issue_number: i64,
         content: &str,
+        auth_token: Option<&str>,
     ) -> Result<GiteaReaction, GiteaError> {
-        Ok(GiteaReaction {
-            id: 1,
+        if *self.should_fail.read().unwrap() {
+            return Err(GiteaError::Api {
+                status: 500,
+                message: "Mock failure".to_string(),
+            });
+        }
+        let mut reactions = self.issue_reactions.write().unwrap();
+        let list = reactions
+            .entry((owner.to_string(), repo.to_string(), issue_number))
+            .or_default();
+        let login = auth_token.unwrap_or("admin");
+        // Like Gitea, reacting again answers with the existing reaction
+        if let Some(existing) = list
+            .iter()
+            .find(|r| r.user.login == login && r.content == content)
+        {
+            return Ok(existing.clone());
+        }
+        let reaction = GiteaReaction {
+            id: list.len() as i64 + 1,
             user: GiteaUser {
                 id: 1,
-                login: "test-agent".to_string(),
-                email: "agent@test.com".to_string(),
+                login: login.to_string(),
+                email: format!("{}@test.com", login),
                 full_name: None,
             },
             content: content.to_string(),
             created_at: "2026-01-31T12:00:00Z".to_string(),
-        })
+        };
+        list.push(reaction.clone());
+        Ok(reaction)
     }
 
     async fn delete_issue_reaction(
@@ -1462,8 +3005,37 @@ impl GiteaClient for MockGiteaClient {
         owner: &str,
         repo: &str,
         _state: Option<&str>,
+        labels: &[String],
     ) -> Result<Vec<GiteaIssue>, GiteaError> {
-        Ok(vec![GiteaIssue {
+        let labeled = self
+            .issue_labels
+            .read()
+            .unwrap()
+            .iter()
+            .filter(|((o, r, _), names)| {
+                o == owner && r == repo && labels.iter().all(|l| names.contains(l))
+            })
+            .map(|((_, _, number), names)| GiteaIssue {
+                id: *number,
+                number: *number,
+                title: format!("Issue #{}", number),
+                body: None,
+                state: "open".to_string(),
+                html_url: format!(
+                    "https://gitea.example.com/{}/{}/issues/{}",
+                    owner, repo, number
+                ),
+                labels: mock_labels(names.clone()),
+                assignee: None,
+                assignees: vec![],
+            })
+            .collect::<Vec<_>>();
+
+        // The stock issue carries no labels
+        if !labels.is_empty() {
+            return Ok(labeled);
+        }
+        let mut issues = vec![GiteaIssue {
             id: 1,
             number: 1,
             title: "Test Issue".to_string(),
@@ -1473,7 +3045,9 @@ impl GiteaClient for MockGiteaClient {
             labels: vec![],
             assignee: None,
             assignees: vec![],
-        }])
+        }];
+        issues.extend(labeled);
+        Ok(issues)
     }
 
     async fn get_issue(
@@ -1526,21 +3100,42 @@ impl GiteaClient for MockGiteaClient {
 
     async fn list_issue_comments(
         &self,
-        _owner: &str,
-        _repo: &str,
+        owner: &str,
+        repo: &str,
         _number: i64,
+        since: Option<&str>,
     ) -> Result<Vec<GiteaIssueComment>, GiteaError> {
-        Ok(vec![])
+        // Configured comments aren't tied to an issue; list the repo's, oldest first
+        let since = since.and_then(|s| DateTime::parse_from_rfc3339(s).ok());
+        let mut comments: Vec<_> = self
+            .issue_comments
+            .read()
+            .unwrap()
+            .iter()
+            .filter(|((o, r, _), _)| o == owner && r == repo)
+            .map(|(_, c)| c.clone())
+            .filter(|c| {
+                since.is_none_or(|since| {
+                    DateTime::parse_from_rfc3339(&c.updated_at).is_ok_and(|u| u >= since)
+                })
+            })
+            .collect();
+        comments.sort_by_key(|c| c.id);
+        Ok(comments)
     }
 
     async fn create_issue_comment(
         &self,
-        _owner: &str,
-        _repo: &str,
-        _number: i64,
+        owner: &str,
+        repo: &str,
+        number: i64,
         body: &str,
         _auth_token: Option<&str>,
     ) -> Result<GiteaIssueComment, GiteaError> {
+        self.posted_comments.write().unwrap().push((
+            (owner.to_string(), repo.to_string(), number),
+            body.to_string(),
+        ));
         Ok(GiteaIssueComment {
             id: 1,
             body: body.to_string(),
@@ -1555,14 +3150,37 @@ impl GiteaClient for MockGiteaClient {
         })
     }
 
+    async fn get_issue_comment(
+        &self,
+        owner: &str,
+        repo: &str,
+        comment_id: i64,
+    ) -> Result<GiteaIssueComment, GiteaError> {
+        self.issue_comments
+            .read()
+            .unwrap()
+            .get(&(owner.to_string(), repo.to_string(), comment_id))
+            .cloned()
+            .ok_or_else(|| GiteaError::Api {
+                status: 404,
+                message: format!("Comment {} not found", comment_id),
+            })
+    }
+
     async fn edit_issue_comment(
         &self,
-        _owner: &str,
-        _repo: &str,
+        owner: &str,
+        repo: &str,
         comment_id: i64,
         body: &str,
         _auth_token: Option<&str>,
     ) -> Result<GiteaIssueComment, GiteaError> {
+        let key = (owner.to_string(), repo.to_string(), comment_id);
+        if let Some(comment) = self.issue_comments.write().unwrap().get_mut(&key) {
+            comment.body = body.to_string();
+            comment.updated_at = "2026-01-02T00:00:00Z".to_string();
+            return Ok(comment.clone());
+        }
         Ok(GiteaIssueComment {
             id: comment_id,
             body: body.to_string(),
@@ -1589,30 +3207,47 @@ impl GiteaClient for MockGiteaClient {
 
     async fn list_issue_labels(
         &self,
-        _owner: &str,
-        _repo: &str,
-        _number: i64,
+        owner: &str,
+        repo: &str,
+        number: i64,
     ) -> Result<Vec<GiteaLabel>, GiteaError> {
-        Ok(vec![])
+        let issue_labels = self.issue_labels.read().unwrap();
+        let key = (owner.to_string(), repo.to_string(), number);
+        Ok(issue_labels
+            .get(&key)
+            .map(|names| mock_labels(names.clone()))
+            .unwrap_or_default())
     }
 
     async fn add_issue_labels(
         &self,
         _owner: &str,
         _repo: &str,
-        _number: i64,
+        number: i64,
         labels: Vec<String>,
         _auth_token: Option<&str>,
     ) -> Result<Vec<GiteaLabel>, GiteaError> {
-        Ok(labels
-            .into_iter()
-            .map(|name| GiteaLabel {
-                id: 1,
-                name,
-                color: "cccccc".to_string(),
-                description: None,
-            })
-            .collect())
+        self.issue_labels_added
+            .write()
+            .unwrap()
+            .push((number, labels.clone()));
+        Ok(mock_labels(labels))
+    }
+
+    async fn replace_issue_labels(
+        &self,
+        owner: &str,
+        repo: &str,
+        number: i64,
+        labels: Vec<String>,
+        _auth_token: Option<&str>,
+    ) -> Result<Vec<GiteaLabel>, GiteaError> {
+        let mut issue_labels = self.issue_labels.write().unwrap();
+        issue_labels.insert(
+            (owner.to_string(), repo.to_string(), number),
+            labels.clone(),
+        );
+        Ok(mock_labels(labels))
     }
 
     async fn remove_issue_label(
@@ -1692,7 +3327,7 @@ impl GiteaClient for MockGiteaClient {
         _owner: &str,
         _repo: &str,
     ) -> Result<Vec<GiteaLabel>, GiteaError> {
-        Ok(vec![
+        let mut labels = vec![
             GiteaLabel {
                 id: 1,
                 name: "bug".to_string(),
@@ -1705,7 +3340,75 @@ impl GiteaClient for MockGiteaClient {
                 color: "00ff00".to_string(),
                 description: Some("New feature or request".to_string()),
             },
-        ])
+        ];
+        labels.extend(self.labels_created.read().unwrap().iter().cloned());
+        Ok(labels)
+    }
+
+    async fn list_org_labels(&self, org: &str) -> Result<Vec<GiteaLabel>, GiteaError> {
+        let names = self
+            .org_labels
+            .read()
+            .unwrap()
+            .get(org)
+            .cloned()
+            .unwrap_or_default();
+        Ok(mock_labels(names))
+    }
+
+    async fn create_repo_label(
+        &self,
+        _owner: &str,
+        _repo: &str,
+        name: &str,
+        color: &str,
+    ) -> Result<GiteaLabel, GiteaError> {
+        let mut created = self.labels_created.write().unwrap();
+        let label = GiteaLabel {
+            id: 100 + created.len() as i64,
+            name: name.to_string(),
+            color: color.trim_start_matches('#').to_string(),
+            description: None,
+        };
+        created.push(label.clone());
+        Ok(label)
+    }
+
+    async fn tag_exists(&self, owner: &str, repo: &str, tag: &str) -> Result<bool, GiteaError> {
+        let releases = self.releases.read().unwrap();
+        Ok(releases
+            .iter()
+            .any(|(o, r, release)| o == owner && r == repo && release.tag_name == tag))
+    }
+
+    async fn create_release(
+        &self,
+        owner: &str,
+        repo: &str,
+        tag: &str,
+        name: &str,
+        body: Option<&str>,
+        draft: bool,
+        prerelease: bool,
+    ) -> Result<GiteaRelease, GiteaError> {
+        if *self.should_fail.read().unwrap() {
+            return Err(GiteaError::Api {
+                status: 500,
+                message: "Mock failure".to_string(),
+            });
+        }
+        let mut releases = self.releases.write().unwrap();
+        let release = GiteaRelease {
+            id: releases.len() as i64 + 1,
+            tag_name: tag.to_string(),
+            name: name.to_string(),
+            body: body.unwrap_or_default().to_string(),
+            draft,
+            prerelease,
+            html_url: format!("https://gitea.test/{}/{}/releases/tag/{}", owner, repo, tag),
+        };
+        releases.push((owner.to_string(), repo.to_string(), release.clone()));
+        Ok(release)
     }
 }
 
@@ -1781,6 +3484,38 @@ impl AnalyticsClient for MockAnalyticsClient {
     }
 }
 
+// ============================================================================
+// Mock Notifier
+// ============================================================================
+
+/// A mock notifier that records every notification it is asked to send
+#[derive(Default)]
+pub struct MockNotifier {
+    pub sent: Arc<RwLock<Vec<(AgentId, Notification)>>>,
+}
+
+impl MockNotifier {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn get_sent(&self) -> Vec<(AgentId, Notification)> {
+        self.sent.read().unwrap().clone()
+    }
+}
+
+#[async_trait]
+impl Notifier for MockNotifier {
+    async fn notify(
+        &self,
+        agent_id: &AgentId,
+        notification: Notification,
+    ) -> Result<(), DomainError> {
+        self.sent.write().unwrap().push((*agent_id, notification));
+        Ok(())
+    }
+}
+
 // ============================================================================
 // In-Memory Code Contribution Repository
 // ============================================================================
@@ -1846,6 +3581,26 @@ impl CodeContributionRepository for InMemoryCodeContributionRepository {
             .collect())
     }
 
+    async fn find_by_agent_paginated(
+        &self,
+        agent_id: &AgentId,
+        limit: i64,
+        offset: i64,
+    ) -> Result<Vec<CodeContribution>, DomainError> {
+        let contributions = self.contributions.read().unwrap();
+        let mut result: Vec<_> = contributions
+            .values()
+            .filter(|c| c.agent_id == *agent_id)
+            .cloned()
+            .collect();
+        result.sort_by_key(|c| (std::cmp::Reverse(c.merged_at), c.id.0));
+        Ok(result
+            .into_iter()
+            .skip(offset as usize)
+            .take(limit as usize)
+            .collect())
+    }
+
     async fn find_by_project(
         &self,
         project_id: &ProjectId,
@@ -1858,35 +3613,146 @@ impl CodeContributionRepository for InMemoryCodeContributionRepository {
             .collect())
     }
 
-    async fn find_eligible_for_longevity_bonus(
+    async fn find_by_agent_and_status(
         &self,
-        threshold: DateTime<Utc>,
+        agent_id: &AgentId,
+        status: ContributionStatus,
     ) -> Result<Vec<CodeContribution>, DomainError> {
         let contributions = self.contributions.read().unwrap();
-        Ok(contributions
+        let mut matching: Vec<_> = contributions
             .values()
-            .filter(|c| {
-                c.status == ContributionStatus::Healthy
-                    && !c.longevity_bonus_paid
-                    && c.merged_at <= threshold
-            })
+            .filter(|c| c.agent_id == *agent_id && c.status == status)
             .cloned()
-            .collect())
+            .collect();
+        matching.sort_by_key(|c| std::cmp::Reverse(c.merged_at));
+        Ok(matching)
     }
 
-    async fn create(
+    async fn find_by_project_and_status(
         &self,
-        contribution: &NewCodeContribution,
-    ) -> Result<CodeContribution, DomainError> {
-        let new_contribution = CodeContribution {
-            id: CodeContributionId::new(),
-            agent_id: contribution.agent_id,
-            project_id: contribution.project_id,
-            pr_number: contribution.pr_number,
-            commit_sha: contribution.commit_sha.clone(),
-            status: ContributionStatus::Healthy,
-            bug_count: 0,
-            longevity_bonus_paid: false,
+        project_id: &ProjectId,
+        status: ContributionStatus,
+    ) -> Result<Vec<CodeContribution>, DomainError> {
+        let contributions = self.contributions.read().unwrap();
+        let mut matching: Vec<_> = contributions
+            .values()
+            .filter(|c| c.project_id == *project_id && c.status == status)
+            .cloned()
+            .collect();
+        matching.sort_by_key(|c| std::cmp::Reverse(c.merged_at));
+        Ok(matching)
+    }
+
+    async fn count_by_agent_and_project_since(
+        &self,
+        agent_id: &AgentId,
+        project_id: &ProjectId,
+        since: DateTime<Utc>,
+    ) -> Result<i64, DomainError> {
+        let contributions = self.contributions.read().unwrap();
+        Ok(contributions
+            .values()
+            .filter(|c| {
+                c.agent_id == *agent_id && c.project_id == *project_id && c.merged_at >= since
+            })
+            .count() as i64)
+    }
+
+    async fn find_revert_times(
+        &self,
+        agent_id: &AgentId,
+    ) -> Result<Vec<DateTime<Utc>>, DomainError> {
+        let contributions = self.contributions.read().unwrap();
+        let mut times: Vec<_> = contributions
+            .values()
+            .filter(|c| c.agent_id == *agent_id)
+            .filter_map(|c| c.reverted_at)
+            .collect();
+        times.sort();
+        Ok(times)
+    }
+
+    async fn count_healthy_between(
+        &self,
+        agent_id: &AgentId,
+        since: Option<DateTime<Utc>>,
+        merged_by: DateTime<Utc>,
+        at: DateTime<Utc>,
+    ) -> Result<i64, DomainError> {
+        let contributions = self.contributions.read().unwrap();
+        Ok(contributions
+            .values()
+            .filter(|c| c.agent_id == *agent_id && c.merged_at <= merged_by)
+            .filter(|c| since.is_none_or(|since| c.merged_at > since))
+            .filter(|c| c.reverted_at.is_none_or(|t| t > at))
+            .filter(|c| c.replaced_at.is_none_or(|t| t > at))
+            .count() as i64)
+    }
+
+    async fn find_shared_projects(
+        &self,
+        agent_id: &AgentId,
+        other_agent_id: &AgentId,
+    ) -> Result<Vec<ProjectId>, DomainError> {
+        let contributions = self.contributions.read().unwrap();
+        let mut projects: Vec<ProjectId> = contributions
+            .values()
+            .filter(|c| c.agent_id == *agent_id)
+            .map(|c| c.project_id)
+            .filter(|p| {
+                contributions
+                    .values()
+                    .any(|c| c.agent_id == *other_agent_id && c.project_id == *p)
+            })
+            .collect();
+        projects.sort_by_key(|p| p.0);
+        projects.dedup();
+        Ok(projects)
+    }
+
+    async fn count_in_projects(
+        &self,
+        agent_id: &AgentId,
+        projects: &[ProjectId],
+        status: Option<ContributionStatus>,
+    ) -> Result<i64, DomainError> {
+        let contributions = self.contributions.read().unwrap();
+        Ok(contributions
+            .values()
+            .filter(|c| c.agent_id == *agent_id && projects.contains(&c.project_id))
+            .filter(|c| status.is_none_or(|status| c.status == status))
+            .count() as i64)
+    }
+
+    async fn find_eligible_for_longevity_bonus(
+        &self,
+        threshold: DateTime<Utc>,
+    ) -> Result<Vec<CodeContribution>, DomainError> {
+        let contributions = self.contributions.read().unwrap();
+        Ok(contributions
+            .values()
+            .filter(|c| {
+                c.status == ContributionStatus::Healthy
+                    && !c.longevity_bonus_paid
+                    && c.merged_at <= threshold
+            })
+            .cloned()
+            .collect())
+    }
+
+    async fn create(
+        &self,
+        contribution: &NewCodeContribution,
+    ) -> Result<CodeContribution, DomainError> {
+        let new_contribution = CodeContribution {
+            id: CodeContributionId::new(),
+            agent_id: contribution.agent_id,
+            project_id: contribution.project_id,
+            pr_number: contribution.pr_number,
+            commit_sha: contribution.commit_sha.clone(),
+            status: ContributionStatus::Healthy,
+            bug_count: 0,
+            longevity_bonus_paid: false,
             dependent_prs_count: 0,
             merged_at: contribution.merged_at,
             reverted_at: None,
@@ -1922,11 +3788,15 @@ impl CodeContributionRepository for InMemoryCodeContributionRepository {
         }
     }
 
-    async fn mark_longevity_bonus_paid(&self, id: &CodeContributionId) -> Result<(), DomainError> {
+    async fn mark_longevity_bonus_paid(
+        &self,
+        id: &CodeContributionId,
+    ) -> Result<bool, DomainError> {
         let mut contributions = self.contributions.write().unwrap();
         if let Some(contribution) = contributions.get_mut(id) {
+            let claimed = !contribution.longevity_bonus_paid;
             contribution.longevity_bonus_paid = true;
-            Ok(())
+            Ok(claimed)
         } else {
             Err(DomainError::NotFound(format!(
                 "Contribution {} not found",
@@ -1983,6 +3853,23 @@ impl InMemoryAgentReviewRepository {
         }
         self
     }
+
+    /// Reviews matching `filter`, newest first, `limit` from `offset`
+    fn page(
+        &self,
+        filter: impl Fn(&AgentReview) -> bool,
+        limit: i64,
+        offset: i64,
+    ) -> Vec<AgentReview> {
+        let reviews = self.reviews.read().unwrap();
+        let mut matching: Vec<_> = reviews.values().filter(|r| filter(r)).cloned().collect();
+        matching.sort_by_key(|r| (std::cmp::Reverse(r.created_at), r.id.0));
+        matching
+            .into_iter()
+            .skip(offset as usize)
+            .take(limit as usize)
+            .collect()
+    }
 }
 
 #[async_trait]
@@ -2023,6 +3910,40 @@ impl AgentReviewRepository for InMemoryAgentReviewRepository {
             .collect())
     }
 
+    async fn find_by_reviewer_paginated(
+        &self,
+        agent_id: &AgentId,
+        limit: i64,
+        offset: i64,
+    ) -> Result<Vec<AgentReview>, DomainError> {
+        Ok(self.page(|r| r.reviewer_agent_id == *agent_id, limit, offset))
+    }
+
+    async fn find_by_reviewed_paginated(
+        &self,
+        agent_id: &AgentId,
+        limit: i64,
+        offset: i64,
+    ) -> Result<Vec<AgentReview>, DomainError> {
+        Ok(self.page(|r| r.reviewed_agent_id == *agent_id, limit, offset))
+    }
+
+    async fn count_by_reviewer(&self, agent_id: &AgentId) -> Result<i64, DomainError> {
+        let reviews = self.reviews.read().unwrap();
+        Ok(reviews
+            .values()
+            .filter(|r| r.reviewer_agent_id == *agent_id)
+            .count() as i64)
+    }
+
+    async fn count_by_reviewed(&self, agent_id: &AgentId) -> Result<i64, DomainError> {
+        let reviews = self.reviews.read().unwrap();
+        Ok(reviews
+            .values()
+            .filter(|r| r.reviewed_agent_id == *agent_id)
+            .count() as i64)
+    }
+
     async fn count_by_reviewer_since(
         &self,
         agent_id: &AgentId,
@@ -2036,6 +3957,24 @@ impl AgentReviewRepository for InMemoryAgentReviewRepository {
         Ok(count as i64)
     }
 
+    async fn count_between(
+        &self,
+        reviewer_agent_id: &AgentId,
+        reviewed_agent_id: &AgentId,
+        verdict: ReviewVerdict,
+    ) -> Result<i64, DomainError> {
+        let reviews = self.reviews.read().unwrap();
+        let count = reviews
+            .values()
+            .filter(|r| {
+                r.reviewer_agent_id == *reviewer_agent_id
+                    && r.reviewed_agent_id == *reviewed_agent_id
+                    && r.verdict == verdict
+            })
+            .count();
+        Ok(count as i64)
+    }
+
     async fn exists_for_pr_and_reviewer(
         &self,
         project_id: &ProjectId,
@@ -2181,118 +4120,465 @@ impl EloEventRepository for InMemoryEloEventRepository {
             .sum();
         Ok(sum)
     }
+
+    async fn sum_delta_by_agent_since(
+        &self,
+        agent_id: &AgentId,
+        since: DateTime<Utc>,
+    ) -> Result<i64, DomainError> {
+        let events = self.events.read().unwrap();
+        let sum: i64 = events
+            .values()
+            .filter(|e| e.agent_id == *agent_id && e.created_at >= since)
+            .map(|e| e.delta as i64)
+            .sum();
+        Ok(sum)
+    }
+
+    async fn net_deltas_since(
+        &self,
+        since: DateTime<Utc>,
+    ) -> Result<Vec<(AgentId, i64)>, DomainError> {
+        let events = self.events.read().unwrap();
+        let mut sums: HashMap<AgentId, i64> = HashMap::new();
+        for event in events.values().filter(|e| e.created_at >= since) {
+            *sums.entry(event.agent_id).or_default() += event.delta as i64;
+        }
+        Ok(sums.into_iter().collect())
+    }
 }
 
 // ============================================================================
-// In-Memory Ticket Repository
+// In-Memory Pending Penalty Repository
 // ============================================================================
 
 #[derive(Default)]
-pub struct InMemoryTicketRepository {
-    tickets: Arc<RwLock<HashMap<TicketId, Ticket>>>,
+pub struct InMemoryPendingPenaltyRepository {
+    penalties: Arc<RwLock<HashMap<PendingPenaltyId, PendingPenalty>>>,
 }
 
-impl InMemoryTicketRepository {
+impl InMemoryPendingPenaltyRepository {
     pub fn new() -> Self {
         Self::default()
     }
 
-    /// Pre-populate with a ticket for testing
-    pub fn with_ticket(self, ticket: Ticket) -> Self {
-        {
-            let mut tickets = self.tickets.write().unwrap();
-            tickets.insert(ticket.id, ticket);
-        }
-        self
+    /// Get all penalties for inspection in tests
+    pub fn get_all_penalties(&self) -> Vec<PendingPenalty> {
+        self.penalties.read().unwrap().values().cloned().collect()
     }
 }
 
 #[async_trait]
-impl TicketRepository for InMemoryTicketRepository {
-    async fn find_by_id(&self, id: &TicketId) -> Result<Option<Ticket>, DomainError> {
-        let tickets = self.tickets.read().unwrap();
-        Ok(tickets.get(id).cloned())
+impl PendingPenaltyRepository for InMemoryPendingPenaltyRepository {
+    async fn create(&self, penalty: &NewPendingPenalty) -> Result<PendingPenalty, DomainError> {
+        let new_penalty = PendingPenalty {
+            id: PendingPenaltyId::new(),
+            agent_id: penalty.agent_id,
+            project_id: penalty.project_id,
+            gitea_org: penalty.gitea_org.clone(),
+            gitea_repo: penalty.gitea_repo.clone(),
+            pr_number: penalty.pr_number,
+            status: PenaltyStatus::Pending,
+            apply_at: penalty.apply_at,
+            resolved_at: None,
+            created_at: Utc::now(),
+        };
+        self.penalties
+            .write()
+            .unwrap()
+            .insert(new_penalty.id, new_penalty.clone());
+        Ok(new_penalty)
     }
 
-    async fn find_by_project(&self, project_id: &ProjectId) -> Result<Vec<Ticket>, DomainError> {
-        let tickets = self.tickets.read().unwrap();
-        Ok(tickets
+    async fn find_due(
+        &self,
+        now: DateTime<Utc>,
+        limit: i64,
+    ) -> Result<Vec<PendingPenalty>, DomainError> {
+        let penalties = self.penalties.read().unwrap();
+        let mut due: Vec<_> = penalties
             .values()
-            .filter(|t| t.project_id == *project_id)
+            .filter(|p| p.is_due(now))
             .cloned()
-            .collect())
+            .collect();
+        due.sort_by_key(|p| p.apply_at);
+        Ok(due.into_iter().take(limit as usize).collect())
     }
 
-    async fn find_open_by_project(
+    async fn find_pending_by_pr(
         &self,
         project_id: &ProjectId,
-    ) -> Result<Vec<Ticket>, DomainError> {
-        let tickets = self.tickets.read().unwrap();
-        Ok(tickets
+        pr_number: i64,
+    ) -> Result<Vec<PendingPenalty>, DomainError> {
+        let penalties = self.penalties.read().unwrap();
+        Ok(penalties
             .values()
-            .filter(|t| t.project_id == *project_id && t.status == TicketStatus::Open)
+            .filter(|p| {
+                p.project_id == *project_id
+                    && p.pr_number == pr_number
+                    && p.status == PenaltyStatus::Pending
+            })
             .cloned()
             .collect())
     }
 
-    async fn find_by_agent(&self, agent_id: &AgentId) -> Result<Vec<Ticket>, DomainError> {
-        let tickets = self.tickets.read().unwrap();
-        Ok(tickets
+    async fn find_pending_by_agent(
+        &self,
+        agent_id: &AgentId,
+    ) -> Result<Vec<PendingPenalty>, DomainError> {
+        let penalties = self.penalties.read().unwrap();
+        let mut pending: Vec<_> = penalties
             .values()
-            .filter(|t| t.assigned_to == Some(*agent_id))
+            .filter(|p| p.agent_id == *agent_id && p.status == PenaltyStatus::Pending)
             .cloned()
-            .collect())
+            .collect();
+        pending.sort_by_key(|p| p.apply_at);
+        Ok(pending)
     }
 
-    async fn find_open_by_agent(&self, agent_id: &AgentId) -> Result<Vec<Ticket>, DomainError> {
-        let tickets = self.tickets.read().unwrap();
-        Ok(tickets
-            .values()
-            .filter(|t| {
-                t.assigned_to == Some(*agent_id)
-                    && (t.status == TicketStatus::Open || t.status == TicketStatus::InProgress)
-            })
-            .cloned()
-            .collect())
+    async fn reschedule(
+        &self,
+        id: &PendingPenaltyId,
+        apply_at: DateTime<Utc>,
+    ) -> Result<(), DomainError> {
+        let mut penalties = self.penalties.write().unwrap();
+        let penalty = penalties
+            .get_mut(id)
+            .ok_or_else(|| DomainError::NotFound(format!("Pending penalty not found: {}", id)))?;
+        penalty.apply_at = apply_at;
+        Ok(())
     }
 
-    async fn create(&self, ticket: &NewTicket) -> Result<Ticket, DomainError> {
-        let new_ticket = Ticket {
-            id: TicketId::new(),
-            project_id: ticket.project_id,
-            title: ticket.title.clone(),
-            body: ticket.body.clone(),
-            gitea_issue_number: ticket.gitea_issue_number,
-            gitea_issue_url: ticket.gitea_issue_url.clone(),
-            status: TicketStatus::Open,
-            priority: ticket.priority,
-            assigned_to: None,
-            created_by: ticket.created_by,
-            created_at: Utc::now(),
-            closed_at: None,
-        };
+    async fn resolve(
+        &self,
+        id: &PendingPenaltyId,
+        status: PenaltyStatus,
+        resolved_at: DateTime<Utc>,
+    ) -> Result<(), DomainError> {
+        let mut penalties = self.penalties.write().unwrap();
+        let penalty = penalties
+            .get_mut(id)
+            .ok_or_else(|| DomainError::NotFound(format!("Pending penalty not found: {}", id)))?;
+        penalty.status = status;
+        penalty.resolved_at = Some(resolved_at);
+        Ok(())
+    }
+}
 
-        let mut tickets = self.tickets.write().unwrap();
-        tickets.insert(new_ticket.id, new_ticket.clone());
-        Ok(new_ticket)
+// ============================================================================
+// In-Memory Review Request Repository
+// ============================================================================
+
+#[derive(Default)]
+pub struct InMemoryReviewRequestRepository {
+    requests: Arc<RwLock<HashMap<ReviewRequestId, ReviewRequest>>>,
+}
+
+impl InMemoryReviewRequestRepository {
+    pub fn new() -> Self {
+        Self::default()
     }
 
-    async fn assign(&self, id: &TicketId, agent_id: &AgentId) -> Result<(), DomainError> {
-        let mut tickets = self.tickets.write().unwrap();
-        if let Some(ticket) = tickets.get_mut(id) {
-            ticket.assigned_to = Some(*agent_id);
-            Ok(())
-        } else {
-            Err(DomainError::NotFound(format!("Ticket {} not found", id)))
-        }
+    /// Get all review requests for inspection in tests
+    pub fn get_all_requests(&self) -> Vec<ReviewRequest> {
+        let mut all: Vec<_> = self.requests.read().unwrap().values().cloned().collect();
+        all.sort_by_key(|r| r.created_at);
+        all
     }
+}
 
-    async fn unassign(&self, id: &TicketId) -> Result<(), DomainError> {
-        let mut tickets = self.tickets.write().unwrap();
-        if let Some(ticket) = tickets.get_mut(id) {
-            ticket.assigned_to = None;
-            Ok(())
-        } else {
+#[async_trait]
+impl ReviewRequestRepository for InMemoryReviewRequestRepository {
+    async fn create(&self, request: &NewReviewRequest) -> Result<ReviewRequest, DomainError> {
+        let new_request = ReviewRequest {
+            id: ReviewRequestId::new(),
+            project_id: request.project_id,
+            gitea_org: request.gitea_org.clone(),
+            gitea_repo: request.gitea_repo.clone(),
+            pr_number: request.pr_number,
+            reviewer_agent_id: request.reviewer_agent_id,
+            requested_by: request.requested_by,
+            status: ReviewRequestStatus::Pending,
+            due_at: request.due_at,
+            resolved_at: None,
+            created_at: Utc::now(),
+        };
+        self.requests
+            .write()
+            .unwrap()
+            .insert(new_request.id, new_request.clone());
+        Ok(new_request)
+    }
+
+    async fn find_overdue(
+        &self,
+        now: DateTime<Utc>,
+        limit: i64,
+    ) -> Result<Vec<ReviewRequest>, DomainError> {
+        let requests = self.requests.read().unwrap();
+        let mut overdue: Vec<_> = requests
+            .values()
+            .filter(|r| r.is_overdue(now))
+            .cloned()
+            .collect();
+        overdue.sort_by_key(|r| r.due_at);
+        Ok(overdue.into_iter().take(limit as usize).collect())
+    }
+
+    async fn find_by_pr(
+        &self,
+        project_id: &ProjectId,
+        pr_number: i64,
+    ) -> Result<Vec<ReviewRequest>, DomainError> {
+        let requests = self.requests.read().unwrap();
+        let mut found: Vec<_> = requests
+            .values()
+            .filter(|r| r.project_id == *project_id && r.pr_number == pr_number)
+            .cloned()
+            .collect();
+        found.sort_by_key(|r| r.created_at);
+        Ok(found)
+    }
+
+    async fn reschedule(
+        &self,
+        id: &ReviewRequestId,
+        due_at: DateTime<Utc>,
+    ) -> Result<(), DomainError> {
+        let mut requests = self.requests.write().unwrap();
+        let request = requests
+            .get_mut(id)
+            .ok_or_else(|| DomainError::NotFound(format!("Review request not found: {}", id)))?;
+        request.due_at = due_at;
+        Ok(())
+    }
+
+    async fn resolve(
+        &self,
+        id: &ReviewRequestId,
+        status: ReviewRequestStatus,
+        resolved_at: DateTime<Utc>,
+    ) -> Result<(), DomainError> {
+        let mut requests = self.requests.write().unwrap();
+        let request = requests
+            .get_mut(id)
+            .ok_or_else(|| DomainError::NotFound(format!("Review request not found: {}", id)))?;
+        request.status = status;
+        request.resolved_at = Some(resolved_at);
+        Ok(())
+    }
+}
+
+// ============================================================================
+// In-Memory Webhook Delivery Repository
+// ============================================================================
+
+#[derive(Default)]
+pub struct InMemoryWebhookDeliveryRepository {
+    deliveries: Arc<RwLock<Vec<WebhookDelivery>>>,
+}
+
+impl InMemoryWebhookDeliveryRepository {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl WebhookDeliveryRepository for InMemoryWebhookDeliveryRepository {
+    async fn create(&self, delivery: &NewWebhookDelivery) -> Result<WebhookDelivery, DomainError> {
+        let logged = WebhookDelivery {
+            id: WebhookDeliveryId::new(),
+            delivery_id: delivery.delivery_id.clone(),
+            event_type: delivery.event_type.clone(),
+            repo: delivery.repo.clone(),
+            action: delivery.action.clone(),
+            outcome: delivery.outcome,
+            error: delivery.error.clone(),
+            received_at: Utc::now(),
+            payload: delivery.payload.clone(),
+        };
+        self.deliveries.write().unwrap().push(logged.clone());
+        Ok(logged)
+    }
+
+    async fn find_recent(&self, limit: i64) -> Result<Vec<WebhookDelivery>, DomainError> {
+        let deliveries = self.deliveries.read().unwrap();
+        // Insertion order is arrival order, so newest first is a reverse walk
+        Ok(deliveries
+            .iter()
+            .rev()
+            .take(limit as usize)
+            .cloned()
+            .collect())
+    }
+
+    async fn is_processed(&self, delivery_id: &str) -> Result<bool, DomainError> {
+        let deliveries = self.deliveries.read().unwrap();
+        Ok(deliveries.iter().any(|d| {
+            d.delivery_id.as_deref() == Some(delivery_id) && d.outcome == DeliveryOutcome::Success
+        }))
+    }
+
+    async fn complete(
+        &self,
+        id: &WebhookDeliveryId,
+        outcome: DeliveryOutcome,
+        error: Option<String>,
+    ) -> Result<(), DomainError> {
+        let mut deliveries = self.deliveries.write().unwrap();
+        let delivery = deliveries
+            .iter_mut()
+            .find(|d| d.id == *id)
+            .ok_or_else(|| DomainError::NotFound(format!("Webhook delivery not found: {}", id)))?;
+        delivery.outcome = outcome;
+        delivery.error = error;
+        delivery.payload = None;
+        Ok(())
+    }
+
+    async fn find_queued(&self, limit: i64) -> Result<Vec<WebhookDelivery>, DomainError> {
+        let deliveries = self.deliveries.read().unwrap();
+        Ok(deliveries
+            .iter()
+            .filter(|d| d.outcome == DeliveryOutcome::Queued)
+            .take(limit as usize)
+            .cloned()
+            .collect())
+    }
+
+    async fn delete_received_before(&self, before: DateTime<Utc>) -> Result<u64, DomainError> {
+        let mut deliveries = self.deliveries.write().unwrap();
+        let count = deliveries.len();
+        deliveries.retain(|d| d.received_at >= before || d.outcome == DeliveryOutcome::Queued);
+        Ok((count - deliveries.len()) as u64)
+    }
+}
+
+// ============================================================================
+// In-Memory Ticket Repository
+// ============================================================================
+
+#[derive(Default)]
+pub struct InMemoryTicketRepository {
+    tickets: Arc<RwLock<HashMap<TicketId, Ticket>>>,
+}
+
+impl InMemoryTicketRepository {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Pre-populate with a ticket for testing
+    pub fn with_ticket(self, ticket: Ticket) -> Self {
+        {
+            let mut tickets = self.tickets.write().unwrap();
+            tickets.insert(ticket.id, ticket);
+        }
+        self
+    }
+}
+
+#[async_trait]
+impl TicketRepository for InMemoryTicketRepository {
+    async fn find_by_id(&self, id: &TicketId) -> Result<Option<Ticket>, DomainError> {
+        let tickets = self.tickets.read().unwrap();
+        Ok(tickets.get(id).cloned())
+    }
+
+    async fn find_by_project(&self, project_id: &ProjectId) -> Result<Vec<Ticket>, DomainError> {
+        let tickets = self.tickets.read().unwrap();
+        Ok(tickets
+            .values()
+            .filter(|t| t.project_id == *project_id)
+            .cloned()
+            .collect())
+    }
+
+    async fn find_by_issue_number(
+        &self,
+        project_id: &ProjectId,
+        issue_number: i64,
+    ) -> Result<Option<Ticket>, DomainError> {
+        let tickets = self.tickets.read().unwrap();
+        Ok(tickets
+            .values()
+            .find(|t| {
+                t.project_id == *project_id
+                    && t.gitea_issue_number.map(i64::from) == Some(issue_number)
+            })
+            .cloned())
+    }
+
+    async fn find_open_by_project(
+        &self,
+        project_id: &ProjectId,
+    ) -> Result<Vec<Ticket>, DomainError> {
+        let tickets = self.tickets.read().unwrap();
+        Ok(tickets
+            .values()
+            .filter(|t| t.project_id == *project_id && t.status == TicketStatus::Open)
+            .cloned()
+            .collect())
+    }
+
+    async fn find_by_agent(&self, agent_id: &AgentId) -> Result<Vec<Ticket>, DomainError> {
+        let tickets = self.tickets.read().unwrap();
+        Ok(tickets
+            .values()
+            .filter(|t| t.assigned_to == Some(*agent_id))
+            .cloned()
+            .collect())
+    }
+
+    async fn find_open_by_agent(&self, agent_id: &AgentId) -> Result<Vec<Ticket>, DomainError> {
+        let tickets = self.tickets.read().unwrap();
+        Ok(tickets
+            .values()
+            .filter(|t| t.assigned_to == Some(*agent_id) && t.status != TicketStatus::Closed)
+            .cloned()
+            .collect())
+    }
+
+    async fn create(&self, ticket: &NewTicket) -> Result<Ticket, DomainError> {
+        let new_ticket = Ticket {
+            id: TicketId::new(),
+            project_id: ticket.project_id,
+            title: ticket.title.clone(),
+            body: ticket.body.clone(),
+            gitea_issue_number: ticket.gitea_issue_number,
+            gitea_issue_url: ticket.gitea_issue_url.clone(),
+            status: TicketStatus::Open,
+            priority: ticket.priority,
+            difficulty: ticket.resolved_difficulty(),
+            assigned_to: None,
+            created_by: ticket.created_by,
+            created_at: Utc::now(),
+            closed_at: None,
+            blocked_reason: None,
+        };
+
+        let mut tickets = self.tickets.write().unwrap();
+        tickets.insert(new_ticket.id, new_ticket.clone());
+        Ok(new_ticket)
+    }
+
+    async fn assign(&self, id: &TicketId, agent_id: &AgentId) -> Result<(), DomainError> {
+        let mut tickets = self.tickets.write().unwrap();
+        if let Some(ticket) = tickets.get_mut(id) {
+            ticket.assigned_to = Some(*agent_id);
+            Ok(())
+        } else {
+            Err(DomainError::NotFound(format!("Ticket {} not found", id)))
+        }
+    }
+
+    async fn unassign(&self, id: &TicketId) -> Result<(), DomainError> {
+        let mut tickets = self.tickets.write().unwrap();
+        if let Some(ticket) = tickets.get_mut(id) {
+            ticket.assigned_to = None;
+            Ok(())
+        } else {
             Err(DomainError::NotFound(format!("Ticket {} not found", id)))
         }
     }
@@ -2314,6 +4600,32 @@ impl TicketRepository for InMemoryTicketRepository {
         self.update_status(id, TicketStatus::Closed).await
     }
 
+    async fn block(&self, id: &TicketId, reason: &str) -> Result<(), DomainError> {
+        let mut tickets = self.tickets.write().unwrap();
+        if let Some(ticket) = tickets.get_mut(id) {
+            ticket.status = TicketStatus::Blocked;
+            ticket.blocked_reason = Some(reason.to_string());
+            Ok(())
+        } else {
+            Err(DomainError::NotFound(format!("Ticket {} not found", id)))
+        }
+    }
+
+    async fn unblock(&self, id: &TicketId) -> Result<(), DomainError> {
+        let mut tickets = self.tickets.write().unwrap();
+        if let Some(ticket) = tickets.get_mut(id) {
+            ticket.status = if ticket.assigned_to.is_some() {
+                TicketStatus::InProgress
+            } else {
+                TicketStatus::Open
+            };
+            ticket.blocked_reason = None;
+            Ok(())
+        } else {
+            Err(DomainError::NotFound(format!("Ticket {} not found", id)))
+        }
+    }
+
     async fn count_open_by_project(&self, project_id: &ProjectId) -> Result<i64, DomainError> {
         let tickets = self.tickets.read().unwrap();
         Ok(tickets
@@ -2322,3 +4634,781 @@ impl TicketRepository for InMemoryTicketRepository {
             .count() as i64)
     }
 }
+
+// ============================================================================
+// In-Memory Engagement Repository
+// ============================================================================
+
+#[derive(Default)]
+pub struct InMemoryEngagementRepository {
+    engagements: Arc<RwLock<HashMap<EngagementId, Engagement>>>,
+    external_counts: Arc<RwLock<HashMap<(String, uuid::Uuid), EngagementCounts>>>,
+    /// Fail `mark_synced`, as if the database went away mid-sync
+    pub fail_mark_synced: Arc<RwLock<bool>>,
+}
+
+impl InMemoryEngagementRepository {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Seed an engagement as-is, e.g. one made in the past
+    pub fn with_engagement(self, engagement: Engagement) -> Self {
+        self.engagements
+            .write()
+            .unwrap()
+            .insert(engagement.id, engagement);
+        self
+    }
+}
+
+#[async_trait]
+impl EngagementRepository for InMemoryEngagementRepository {
+    async fn find_by_id(&self, id: &EngagementId) -> Result<Option<Engagement>, DomainError> {
+        let engagements = self.engagements.read().unwrap();
+        Ok(engagements.get(id).cloned())
+    }
+
+    async fn find_by_target(
+        &self,
+        target_type: &str,
+        target_id: uuid::Uuid,
+        limit: i64,
+        offset: i64,
+    ) -> Result<Vec<Engagement>, DomainError> {
+        let engagements = self.engagements.read().unwrap();
+        let mut result: Vec<_> = engagements
+            .values()
+            .filter(|e| e.target_type.to_string() == target_type && e.target_id == target_id)
+            .cloned()
+            .collect();
+        result.sort_by_key(|m| std::cmp::Reverse(m.created_at));
+        Ok(result
+            .into_iter()
+            .skip(offset as usize)
+            .take(limit as usize)
+            .collect())
+    }
+
+    async fn find_recent_by_targets(
+        &self,
+        target_type: &str,
+        target_ids: &[uuid::Uuid],
+        per_target: i64,
+    ) -> Result<HashMap<uuid::Uuid, Vec<Engagement>>, DomainError> {
+        let mut by_target = HashMap::new();
+        for target_id in target_ids {
+            let recent = self
+                .find_by_target(target_type, *target_id, per_target, 0)
+                .await?;
+            if !recent.is_empty() {
+                by_target.insert(*target_id, recent);
+            }
+        }
+        Ok(by_target)
+    }
+
+    async fn count_by_target(
+        &self,
+        target_type: &str,
+        target_id: uuid::Uuid,
+    ) -> Result<i64, DomainError> {
+        let engagements = self.engagements.read().unwrap();
+        Ok(engagements
+            .values()
+            .filter(|e| e.target_type.to_string() == target_type && e.target_id == target_id)
+            .count() as i64)
+    }
+
+    async fn find_by_agent(
+        &self,
+        agent_id: &AgentId,
+        limit: i64,
+        offset: i64,
+    ) -> Result<Vec<Engagement>, DomainError> {
+        let engagements = self.engagements.read().unwrap();
+        let mut result: Vec<_> = engagements
+            .values()
+            .filter(|e| e.agent_id == *agent_id)
+            .cloned()
+            .collect();
+        result.sort_by_key(|m| std::cmp::Reverse(m.created_at));
+        Ok(result
+            .into_iter()
+            .skip(offset as usize)
+            .take(limit as usize)
+            .collect())
+    }
+
+    async fn get_counts(
+        &self,
+        target_type: &str,
+        target_id: uuid::Uuid,
+    ) -> Result<EngagementCounts, DomainError> {
+        let mut counts = self
+            .get_counts_batch(&[(target_type.to_string(), target_id)])
+            .await?;
+        Ok(counts
+            .remove(&(target_type.to_string(), target_id))
+            .unwrap_or_default())
+    }
+
+    async fn get_counts_batch(
+        &self,
+        targets: &[(String, uuid::Uuid)],
+    ) -> Result<HashMap<(String, uuid::Uuid), EngagementCounts>, DomainError> {
+        let mut counts: HashMap<(String, uuid::Uuid), EngagementCounts> = targets
+            .iter()
+            .map(|key| (key.clone(), EngagementCounts::default()))
+            .collect();
+        let engagements = self.engagements.read().unwrap();
+        for e in engagements.values() {
+            let Some(c) = counts.get_mut(&(e.target_type.to_string(), e.target_id)) else {
+                continue;
+            };
+            match (e.engagement_type, e.reaction) {
+                (EngagementType::Reaction, Some(ReactionType::Laugh)) => c.laugh_count += 1,
+                (EngagementType::Reaction, Some(ReactionType::Fire)) => c.fire_count += 1,
+                (EngagementType::Reaction, Some(ReactionType::Skull)) => c.skull_count += 1,
+                (EngagementType::Reaction, Some(ReactionType::Heart)) => c.heart_count += 1,
+                (EngagementType::Reaction, Some(ReactionType::Eyes)) => c.eyes_count += 1,
+                (EngagementType::Comment, _) => c.comment_count += 1,
+                (EngagementType::Review, _) => c.review_count += 1,
+                (EngagementType::Reaction, None) => {}
+            }
+        }
+        let external = self.external_counts.read().unwrap();
+        for (key, c) in counts.iter_mut() {
+            if let Some(ext) = external.get(key) {
+                c.add_reactions(ext);
+            }
+        }
+        for c in counts.values_mut() {
+            c.total_score = EngagementWeights::default().score(c);
+        }
+        Ok(counts)
+    }
+
+    async fn set_external_counts(
+        &self,
+        target_type: &str,
+        target_id: uuid::Uuid,
+        counts: &EngagementCounts,
+    ) -> Result<(), DomainError> {
+        self.external_counts
+            .write()
+            .unwrap()
+            .insert((target_type.to_string(), target_id), counts.clone());
+        Ok(())
+    }
+
+    async fn create(&self, engagement: &NewEngagement) -> Result<Engagement, DomainError> {
+        let created = Engagement {
+            id: EngagementId::new(),
+            agent_id: engagement.agent_id,
+            target_type: engagement.target_type,
+            target_id: engagement.target_id,
+            engagement_type: engagement.engagement_type,
+            reaction: engagement.reaction,
+            body: engagement.body.clone(),
+            gitea_synced: false,
+            gitea_id: None,
+            created_at: Utc::now(),
+            gitea_org: engagement.gitea_org.clone(),
+            gitea_repo: engagement.gitea_repo.clone(),
+            sync_attempts: 0,
+            sync_failed: false,
+        };
+
+        let mut engagements = self.engagements.write().unwrap();
+        engagements.insert(created.id, created.clone());
+        Ok(created)
+    }
+
+    async fn mark_synced(&self, id: &EngagementId, gitea_id: i64) -> Result<(), DomainError> {
+        if *self.fail_mark_synced.read().unwrap() {
+            return Err(DomainError::Database("Mock failure".to_string()));
+        }
+        let mut engagements = self.engagements.write().unwrap();
+        let engagement = engagements
+            .get_mut(id)
+            .ok_or_else(|| DomainError::NotFound(format!("Engagement {}", id)))?;
+        engagement.gitea_synced = true;
+        engagement.gitea_id = Some(gitea_id);
+        Ok(())
+    }
+
+    async fn find_unsynced(
+        &self,
+        created_before: DateTime<Utc>,
+        limit: i64,
+    ) -> Result<Vec<Engagement>, DomainError> {
+        let engagements = self.engagements.read().unwrap();
+        let mut unsynced: Vec<Engagement> = engagements
+            .values()
+            .filter(|e| !e.gitea_synced && !e.sync_failed)
+            .filter(|e| e.created_at < created_before)
+            .filter(|e| e.gitea_org.is_some() && e.gitea_repo.is_some())
+            .cloned()
+            .collect();
+        unsynced.sort_by_key(|e| e.created_at);
+        unsynced.truncate(limit as usize);
+        Ok(unsynced)
+    }
+
+    async fn record_sync_failure(
+        &self,
+        id: &EngagementId,
+        give_up: bool,
+    ) -> Result<(), DomainError> {
+        let mut engagements = self.engagements.write().unwrap();
+        let engagement = engagements
+            .get_mut(id)
+            .ok_or_else(|| DomainError::NotFound(format!("Engagement {}", id)))?;
+        engagement.sync_attempts += 1;
+        engagement.sync_failed |= give_up;
+        Ok(())
+    }
+
+    async fn find_synced_gitea_ids(
+        &self,
+        target_type: &str,
+        target_id: uuid::Uuid,
+    ) -> Result<Vec<i64>, DomainError> {
+        let engagements = self.engagements.read().unwrap();
+        Ok(engagements
+            .values()
+            .filter(|e| e.target_type.to_string() == target_type && e.target_id == target_id)
+            .filter_map(|e| e.gitea_id)
+            .collect())
+    }
+
+    async fn has_reaction(
+        &self,
+        agent_id: &AgentId,
+        target_type: &str,
+        target_id: uuid::Uuid,
+        reaction: &str,
+    ) -> Result<bool, DomainError> {
+        let engagements = self.engagements.read().unwrap();
+        Ok(engagements.values().any(|e| {
+            e.agent_id == *agent_id
+                && e.target_type.to_string() == target_type
+                && e.target_id == target_id
+                && e.reaction.map(|r| r.to_string()).as_deref() == Some(reaction)
+        }))
+    }
+
+    async fn last_engagement_at(
+        &self,
+        agent_id: &AgentId,
+        target_type: &str,
+        target_id: uuid::Uuid,
+        engagement_type: &str,
+    ) -> Result<Option<DateTime<Utc>>, DomainError> {
+        let engagements = self.engagements.read().unwrap();
+        Ok(engagements
+            .values()
+            .filter(|e| {
+                e.agent_id == *agent_id
+                    && e.target_type.to_string() == target_type
+                    && e.target_id == target_id
+                    && e.engagement_type.to_string() == engagement_type
+            })
+            .map(|e| e.created_at)
+            .max())
+    }
+}
+
+// ============================================================================
+// In-Memory Viral Moment Repository
+// ============================================================================
+
+#[derive(Default)]
+pub struct InMemoryViralMomentRepository {
+    moments: Arc<RwLock<HashMap<ViralMomentId, ViralMoment>>>,
+}
+
+impl InMemoryViralMomentRepository {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Pre-populate with a moment for testing
+    pub fn with_moment(self, moment: ViralMoment) -> Self {
+        {
+            let mut moments = self.moments.write().unwrap();
+            moments.insert(moment.id, moment);
+        }
+        self
+    }
+
+    /// Visible moments matching `filter`, ordered by score then recency
+    fn ranked(&self, filter: impl Fn(&ViralMoment) -> bool) -> Vec<ViralMoment> {
+        let moments = self.moments.read().unwrap();
+        let mut result: Vec<_> = moments
+            .values()
+            .filter(|m| !m.hidden && filter(m))
+            .cloned()
+            .collect();
+        result.sort_by(|a, b| {
+            b.score
+                .cmp(&a.score)
+                .then_with(|| b.created_at.cmp(&a.created_at))
+        });
+        result
+    }
+
+    fn update(
+        &self,
+        id: &ViralMomentId,
+        f: impl FnOnce(&mut ViralMoment),
+    ) -> Result<(), DomainError> {
+        let mut moments = self.moments.write().unwrap();
+        let moment = moments
+            .get_mut(id)
+            .ok_or_else(|| DomainError::NotFound(format!("Viral moment {}", id)))?;
+        f(moment);
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl ViralMomentRepository for InMemoryViralMomentRepository {
+    async fn find_by_id(&self, id: &ViralMomentId) -> Result<Option<ViralMoment>, DomainError> {
+        let moments = self.moments.read().unwrap();
+        Ok(moments.get(id).cloned())
+    }
+
+    async fn find_by_type(
+        &self,
+        moment_type: MomentType,
+        limit: i64,
+        offset: i64,
+    ) -> Result<Vec<ViralMoment>, DomainError> {
+        Ok(self
+            .ranked(|m| m.moment_type == moment_type)
+            .into_iter()
+            .skip(offset as usize)
+            .take(limit as usize)
+            .collect())
+    }
+
+    async fn find_top(&self, limit: i64) -> Result<Vec<ViralMoment>, DomainError> {
+        Ok(self
+            .ranked(|_| true)
+            .into_iter()
+            .take(limit as usize)
+            .collect())
+    }
+
+    async fn find_promoted(&self, limit: i64) -> Result<Vec<ViralMoment>, DomainError> {
+        Ok(self
+            .ranked(|m| m.promoted)
+            .into_iter()
+            .take(limit as usize)
+            .collect())
+    }
+
+    async fn count_promoted(&self) -> Result<i64, DomainError> {
+        Ok(self.ranked(|m| m.promoted).len() as i64)
+    }
+
+    async fn find_filtered(
+        &self,
+        filter: &MomentFilter,
+        limit: i64,
+        offset: i64,
+    ) -> Result<Vec<ViralMoment>, DomainError> {
+        let mut result: Vec<_> = self
+            .moments
+            .read()
+            .unwrap()
+            .values()
+            .filter(|m| filter.matches(m))
+            .cloned()
+            .collect();
+        match filter.sort {
+            MomentSort::Score => result.sort_by(|a, b| {
+                b.score
+                    .cmp(&a.score)
+                    .then_with(|| b.created_at.cmp(&a.created_at))
+            }),
+            MomentSort::Recent => result.sort_by_key(|m| std::cmp::Reverse(m.created_at)),
+        }
+        Ok(result
+            .into_iter()
+            .skip(offset as usize)
+            .take(limit as usize)
+            .collect())
+    }
+
+    async fn count_filtered(&self, filter: &MomentFilter) -> Result<i64, DomainError> {
+        let moments = self.moments.read().unwrap();
+        Ok(moments.values().filter(|m| filter.matches(m)).count() as i64)
+    }
+
+    async fn exists_for_reference(
+        &self,
+        reference_type: &str,
+        reference_id: uuid::Uuid,
+    ) -> Result<bool, DomainError> {
+        let moments = self.moments.read().unwrap();
+        Ok(moments.values().any(|m| {
+            m.reference_type.to_string() == reference_type && m.reference_id == reference_id
+        }))
+    }
+
+    async fn create(&self, moment: &NewViralMoment) -> Result<ViralMoment, DomainError> {
+        let created = ViralMoment {
+            id: ViralMomentId::new(),
+            moment_type: moment.moment_type,
+            title: moment.title.clone(),
+            subtitle: moment.subtitle.clone(),
+            score: moment.score,
+            agent_ids: moment.agent_ids.clone(),
+            reference_type: moment.reference_type,
+            reference_id: moment.reference_id,
+            snapshot: moment.snapshot.clone(),
+            promoted: false,
+            hidden: false,
+            llm_classified: false,
+            llm_classification: None,
+            created_at: Utc::now(),
+            snapshot_compacted_at: None,
+        };
+
+        let mut moments = self.moments.write().unwrap();
+        moments.insert(created.id, created.clone());
+        Ok(created)
+    }
+
+    async fn update_score(&self, id: &ViralMomentId, score: i32) -> Result<(), DomainError> {
+        self.update(id, |m| m.score = score)
+    }
+
+    async fn set_promoted(&self, id: &ViralMomentId, promoted: bool) -> Result<(), DomainError> {
+        self.update(id, |m| m.promoted = promoted)
+    }
+
+    async fn set_hidden(&self, id: &ViralMomentId, hidden: bool) -> Result<(), DomainError> {
+        self.update(id, |m| m.hidden = hidden)
+    }
+
+    async fn find_compactable(
+        &self,
+        before: DateTime<Utc>,
+        limit: i64,
+    ) -> Result<Vec<ViralMoment>, DomainError> {
+        let moments = self.moments.read().unwrap();
+        let mut result: Vec<_> = moments
+            .values()
+            .filter(|m| !m.promoted && m.snapshot_compacted_at.is_none() && m.created_at < before)
+            .cloned()
+            .collect();
+        result.sort_by_key(|m| m.created_at);
+        result.truncate(limit.max(0) as usize);
+        Ok(result)
+    }
+
+    async fn compact_snapshot(
+        &self,
+        id: &ViralMomentId,
+        summary: &serde_json::Value,
+    ) -> Result<bool, DomainError> {
+        let mut compacted = false;
+        self.update(id, |m| {
+            if !m.promoted {
+                m.snapshot = summary.clone();
+                m.snapshot_compacted_at = Some(Utc::now());
+                compacted = true;
+            }
+        })?;
+        Ok(compacted)
+    }
+
+    async fn update_llm_classification(
+        &self,
+        id: &ViralMomentId,
+        classification: serde_json::Value,
+    ) -> Result<(), DomainError> {
+        self.update(id, |m| {
+            m.llm_classified = true;
+            m.llm_classification = serde_json::from_value(classification).ok();
+        })
+    }
+
+    async fn find_by_agent(
+        &self,
+        agent_id: &AgentId,
+        limit: i64,
+        offset: i64,
+    ) -> Result<Vec<ViralMoment>, DomainError> {
+        let mut result = self.ranked(|m| m.agent_ids.contains(agent_id));
+        result.sort_by_key(|m| std::cmp::Reverse(m.created_at));
+        Ok(result
+            .into_iter()
+            .skip(offset as usize)
+            .take(limit as usize)
+            .collect())
+    }
+}
+
+// ============================================================================
+// In-Memory Agent Merge Repository
+// ============================================================================
+
+/// Compares agents in an in-memory agent repository against an in-memory event log
+pub struct InMemoryEloIntegrityRepository {
+    agents: Arc<InMemoryAgentRepository>,
+    elo_events: Arc<InMemoryEloEventRepository>,
+}
+
+impl InMemoryEloIntegrityRepository {
+    pub fn new(
+        agents: Arc<InMemoryAgentRepository>,
+        elo_events: Arc<InMemoryEloEventRepository>,
+    ) -> Self {
+        Self { agents, elo_events }
+    }
+}
+
+#[async_trait]
+impl EloIntegrityRepository for InMemoryEloIntegrityRepository {
+    async fn find_elo_drift(
+        &self,
+        starting: i32,
+        limit: i64,
+    ) -> Result<Vec<EloDrift>, DomainError> {
+        let mut sums: HashMap<AgentId, i64> = HashMap::new();
+        for event in self.elo_events.events.read().unwrap().values() {
+            *sums.entry(event.agent_id).or_default() += (event.new_elo - event.old_elo) as i64;
+        }
+
+        let mut drift: Vec<_> = self
+            .agents
+            .agents
+            .read()
+            .unwrap()
+            .values()
+            .filter_map(|agent| {
+                let event_sum = sums.get(&agent.id).copied().unwrap_or(0);
+                let expected = (starting as i64 + event_sum) as i32;
+                (agent.elo != expected).then(|| EloDrift {
+                    agent_id: agent.id,
+                    agent_name: agent.name.clone(),
+                    stored_elo: agent.elo,
+                    expected_elo: expected,
+                    event_sum,
+                })
+            })
+            .collect();
+        drift.sort_by(|a, b| {
+            b.drift()
+                .abs()
+                .cmp(&a.drift().abs())
+                .then_with(|| a.agent_name.cmp(&b.agent_name))
+        });
+        drift.truncate(limit as usize);
+        Ok(drift)
+    }
+}
+
+/// Merges agents across the in-memory repositories it was built from
+pub struct InMemoryAgentMergeRepository {
+    agents: Arc<InMemoryAgentRepository>,
+    contributions: Arc<InMemoryCodeContributionRepository>,
+    reviews: Arc<InMemoryAgentReviewRepository>,
+    elo_events: Arc<InMemoryEloEventRepository>,
+    tickets: Arc<InMemoryTicketRepository>,
+    projects: Arc<InMemoryProjectRepository>,
+    engagements: Arc<InMemoryEngagementRepository>,
+    penalties: Arc<InMemoryPendingPenaltyRepository>,
+    moments: Arc<InMemoryViralMomentRepository>,
+    merged: RwLock<HashSet<AgentId>>,
+}
+
+impl InMemoryAgentMergeRepository {
+    pub fn new(
+        agents: Arc<InMemoryAgentRepository>,
+        contributions: Arc<InMemoryCodeContributionRepository>,
+        reviews: Arc<InMemoryAgentReviewRepository>,
+        elo_events: Arc<InMemoryEloEventRepository>,
+        tickets: Arc<InMemoryTicketRepository>,
+    ) -> Self {
+        Self {
+            agents,
+            contributions,
+            reviews,
+            elo_events,
+            tickets,
+            projects: Arc::new(InMemoryProjectRepository::new()),
+            engagements: Arc::new(InMemoryEngagementRepository::new()),
+            penalties: Arc::new(InMemoryPendingPenaltyRepository::new()),
+            moments: Arc::new(InMemoryViralMomentRepository::new()),
+            merged: RwLock::new(HashSet::new()),
+        }
+    }
+
+    /// Also move project memberships and ownership held in `projects`
+    pub fn with_projects(mut self, projects: Arc<InMemoryProjectRepository>) -> Self {
+        self.projects = projects;
+        self
+    }
+
+    pub fn with_engagements(mut self, engagements: Arc<InMemoryEngagementRepository>) -> Self {
+        self.engagements = engagements;
+        self
+    }
+
+    pub fn with_penalties(mut self, penalties: Arc<InMemoryPendingPenaltyRepository>) -> Self {
+        self.penalties = penalties;
+        self
+    }
+
+    pub fn with_moments(mut self, moments: Arc<InMemoryViralMomentRepository>) -> Self {
+        self.moments = moments;
+        self
+    }
+}
+
+#[async_trait]
+impl AgentMergeRepository for InMemoryAgentMergeRepository {
+    async fn merge(
+        &self,
+        source: &AgentId,
+        target: &AgentId,
+        starting: i32,
+        floor: i32,
+        ceiling: Option<i32>,
+    ) -> Result<AgentMergeCounts, DomainError> {
+        let mut merged = self.merged.write().unwrap();
+        if let Some(id) = [source, target].into_iter().find(|id| merged.contains(id)) {
+            return Err(DomainError::Conflict(format!(
+                "Agent {} was already merged",
+                id
+            )));
+        }
+
+        let mut counts = AgentMergeCounts::default();
+        // Reviews between the two agents would become self-reviews
+        self.reviews.reviews.write().unwrap().retain(|_, r| {
+            let between = [r.reviewer_agent_id, r.reviewed_agent_id];
+            !(between == [*source, *target] || between == [*target, *source])
+        });
+        for c in self
+            .contributions
+            .contributions
+            .write()
+            .unwrap()
+            .values_mut()
+        {
+            if c.agent_id == *source {
+                c.agent_id = *target;
+                counts.contributions += 1;
+            }
+        }
+        for r in self.reviews.reviews.write().unwrap().values_mut() {
+            if r.reviewer_agent_id == *source {
+                r.reviewer_agent_id = *target;
+                counts.reviews += 1;
+            }
+            if r.reviewed_agent_id == *source {
+                r.reviewed_agent_id = *target;
+                counts.reviews += 1;
+            }
+        }
+        for e in self.elo_events.events.write().unwrap().values_mut() {
+            if e.agent_id == *source {
+                e.agent_id = *target;
+                counts.elo_events += 1;
+            }
+        }
+        for t in self.tickets.tickets.write().unwrap().values_mut() {
+            if t.assigned_to == Some(*source) {
+                t.assigned_to = Some(*target);
+                counts.tickets += 1;
+            }
+        }
+
+        {
+            let mut members = self.projects.members.write().unwrap();
+            let rank = |role: MemberRole| match role {
+                MemberRole::Owner => 3,
+                MemberRole::Maintainer => 2,
+                MemberRole::Contributor => 1,
+            };
+            let held: Vec<ProjectMember> = members
+                .iter()
+                .filter(|m| m.agent_id == *source)
+                .cloned()
+                .collect();
+            for member in held {
+                counts.memberships += 1;
+                match members
+                    .iter_mut()
+                    .find(|m| m.agent_id == *target && m.project_id == member.project_id)
+                {
+                    Some(existing) => {
+                        if rank(member.role) > rank(existing.role) {
+                            existing.role = member.role;
+                        }
+                    }
+                    None => members.push(ProjectMember {
+                        agent_id: *target,
+                        ..member
+                    }),
+                }
+            }
+            members.retain(|m| m.agent_id != *source);
+        }
+        for p in self.projects.projects.write().unwrap().values_mut() {
+            if p.created_by == Some(*source) {
+                p.created_by = Some(*target);
+            }
+        }
+        for e in self.engagements.engagements.write().unwrap().values_mut() {
+            if e.agent_id == *source {
+                e.agent_id = *target;
+                counts.engagements += 1;
+            }
+        }
+        for p in self.penalties.penalties.write().unwrap().values_mut() {
+            if p.agent_id == *source {
+                p.agent_id = *target;
+                counts.pending_penalties += 1;
+            }
+        }
+        for m in self.moments.moments.write().unwrap().values_mut() {
+            if m.agent_ids.contains(source) {
+                if m.agent_ids.contains(target) {
+                    m.agent_ids.retain(|id| id != source);
+                } else {
+                    for id in m.agent_ids.iter_mut().filter(|id| **id == *source) {
+                        *id = *target;
+                    }
+                }
+                counts.viral_moments += 1;
+            }
+        }
+
+        // The source can no longer authenticate
+        self.agents
+            .by_api_key
+            .write()
+            .unwrap()
+            .retain(|_, id| id != source);
+        {
+            let mut agents = self.agents.agents.write().unwrap();
+            let source_elo = agents.get(source).map_or(starting, |a| a.elo);
+            if let Some(agent) = agents.get_mut(target) {
+                let combined = (agent.elo + source_elo - starting).max(floor);
+                agent.elo = ceiling.map_or(combined, |c| combined.min(c.max(floor)));
+                agent.update_tier();
+            }
+        }
+        merged.insert(*source);
+
+        Ok(counts)
+    }
+}
@@ -368,7 +368,7 @@ def test_list_projects(ctx: TestContext):
         print_error(f"List projects failed: {status}")
         return False
 
-    projects = json.loads(body)
+    projects = json.loads(body)["items"]
     print_success(f"Found {len(projects)} projects")
 
     # Verify our project is in the list
@@ -608,14 +608,14 @@ def test_list_issues(ctx: TestContext):
         print_error(f"List issues failed: {status}")
         return False
 
-    issues = json.loads(body)
+    issues = json.loads(body)["items"]
     print_success(f"Found {len(issues)} open issues")
 
     # With state filter
     status, body = http_request(f"{API_URL}/projects/{ctx.project_id}/issues?state=all")
     if status == 200:
         all_issues = json.loads(body)
-        print_success(f"Found {len(all_issues)} total issues (all states)")
+        print_success(f"Found {all_issues['total']} total issues (all states)")
 
     return True
 
@@ -4,15 +4,86 @@
 //! Registration is human-gated via the web UI or direct API call.
 //! This ensures accountability and prevents spam.
 
+use std::time::Duration;
+
 use anyhow::{Context, Result};
-use reqwest::header::{HeaderMap, HeaderValue, AUTHORIZATION, CONTENT_TYPE};
-use serde::Serialize;
+use reqwest::header::{HeaderMap, HeaderValue, AUTHORIZATION, CONTENT_TYPE, RETRY_AFTER};
+use reqwest::StatusCode;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+
+/// Non-success response from the SynStack API
+#[derive(Debug, thiserror::Error)]
+#[error("API error ({status}): {body}")]
+pub struct ApiError {
+    pub status: StatusCode,
+    pub body: String,
+}
+
+impl ApiError {
+    /// The human-readable detail from a JSON error body, falling back to the raw body
+    pub fn detail(&self) -> String {
+        serde_json::from_str::<ErrorBody>(&self.body)
+            .ok()
+            .and_then(|e| e.details.or(Some(e.error)))
+            .unwrap_or_else(|| self.body.clone())
+    }
+}
+
+/// How the client backs off when the API is rate limiting or failing
+///
+/// A 429 waits for the server's `Retry-After` (or the backoff delay if the
+/// header is missing); a 5xx on a GET waits `base_delay * 2^attempt`. Every
+/// wait is capped at `max_delay`, and a `Retry-After` beyond the cap fails
+/// fast. Writes are not retried on 5xx since the server may have applied them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryPolicy {
+    /// Retries after the first attempt (0 disables retrying)
+    pub max_retries: u32,
+    pub base_delay: Duration,
+    pub max_delay: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_retries: 3,
+            base_delay: Duration::from_millis(500),
+            max_delay: Duration::from_secs(30),
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Exponential backoff delay before retry number `attempt` (0-based)
+    fn backoff(&self, attempt: u32) -> Duration {
+        self.base_delay
+            .saturating_mul(2u32.saturating_pow(attempt))
+            .min(self.max_delay)
+    }
+}
+
+/// Result of claiming a ticket and resolving where to work on it
+#[derive(Debug, Clone, PartialEq)]
+pub struct ClaimedTicket {
+    pub ticket_id: String,
+    pub project_id: String,
+    pub clone_url: String,
+    pub branch: String,
+}
+
+/// A PR's changed files and unified diff
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct PrDiff {
+    pub files: Vec<String>,
+    pub diff: String,
+}
 
 /// HTTP client for communicating with the SynStack API
 #[derive(Clone)]
 pub struct SynStackClient {
     client: reqwest::Client,
     base_url: String,
+    retry: RetryPolicy,
 }
 
 impl SynStackClient {
@@ -22,6 +93,9 @@ impl SynStackClient {
     /// - SYNSTACK_API_KEY: The agent's API key (sk-...)
     /// - SYNSTACK_API_URL: Base URL of the API (e.g., https://api.synstack.org)
     ///
+    /// Optional env vars:
+    /// - SYNSTACK_MAX_RETRIES: Retries for rate-limited or failing requests (default 3)
+    ///
     /// NOTE: Get your API key by registering at https://synstack.org
     /// Registration requires human verification via GitHub OAuth.
     pub fn from_env() -> Result<Self> {
@@ -31,7 +105,15 @@ impl SynStackClient {
         let base_url = std::env::var("SYNSTACK_API_URL")
             .unwrap_or_else(|_| "https://api.synstack.org".to_string());
 
-        Self::new(&base_url, &api_key)
+        let mut retry = RetryPolicy::default();
+        if let Some(max_retries) = std::env::var("SYNSTACK_MAX_RETRIES")
+            .ok()
+            .and_then(|v| v.parse().ok())
+        {
+            retry.max_retries = max_retries;
+        }
+
+        Ok(Self::new(&base_url, &api_key)?.with_retry_policy(retry))
     }
 
     /// Create a new client with explicit configuration
@@ -52,9 +134,16 @@ impl SynStackClient {
         Ok(Self {
             client,
             base_url: base_url.trim_end_matches('/').to_string(),
+            retry: RetryPolicy::default(),
         })
     }
 
+    /// Override how rate-limited and failing requests are retried
+    pub fn with_retry_policy(mut self, retry: RetryPolicy) -> Self {
+        self.retry = retry;
+        self
+    }
+
     #[cfg(test)]
     pub fn base_url(&self) -> &str {
         &self.base_url
@@ -90,12 +179,7 @@ impl SynStackClient {
     }
 
     /// Create a new issue in a project
-    pub async fn create_issue(
-        &self,
-        title: &str,
-        body: &str,
-        project_id: &str,
-    ) -> Result<String> {
+    pub async fn create_issue(&self, title: &str, body: &str, project_id: &str) -> Result<String> {
         self.post_text(
             &format!("/projects/{}/issues", project_id),
             &CreateIssueRequest {
@@ -154,6 +238,31 @@ impl SynStackClient {
         self.get_text(&format!("/viral/{}", feed_type)).await
     }
 
+    /// Claim the ticket at a feed index and get its clone URL and working branch
+    ///
+    /// The `work-on` action creates the branch in Gitea, so callers get
+    /// everything needed to start coding in one step.
+    pub async fn claim_and_open(&self, index: u32) -> Result<ClaimedTicket> {
+        let claimed: ActionResponse = self
+            .post_json(
+                "/action",
+                &ActionRequest {
+                    action: format!("work-on {}", index),
+                },
+            )
+            .await?;
+        let data = claimed
+            .data
+            .context("Claim response did not include ticket details")?;
+
+        Ok(ClaimedTicket {
+            ticket_id: data.ticket_id,
+            project_id: data.project_id,
+            clone_url: data.clone_url,
+            branch: data.branch,
+        })
+    }
+
     /// Merge a PR
     pub async fn merge_pr(&self, project_id: &str, pr_number: i64) -> Result<String> {
         self.post_text(
@@ -163,50 +272,152 @@ impl SynStackClient {
         .await
     }
 
+    /// Get the files a PR changes and its unified diff
+    pub async fn get_pr_diff(&self, project_id: &str, pr_number: i64) -> Result<PrDiff> {
+        self.get_json(&format!("/projects/{}/prs/{}/diff", project_id, pr_number))
+            .await
+    }
+
     // --- Internal helpers ---
 
+    /// Send a request, retrying 429s and 5xx responses per the retry policy
+    ///
+    /// Returns the final response; if retries run out while the API is still
+    /// failing, the error says so instead of looking like a one-off failure.
+    async fn send(
+        &self,
+        request: reqwest::RequestBuilder,
+        method: &str,
+        path: &str,
+    ) -> Result<reqwest::Response> {
+        let mut attempt = 0;
+        loop {
+            let response = request
+                .try_clone()
+                .context("Request body cannot be retried")?
+                .send()
+                .await
+                .with_context(|| format!("Failed to {} {}", method, path))?;
+
+            let status = response.status();
+            let retryable = status == StatusCode::TOO_MANY_REQUESTS
+                || (status.is_server_error() && method == "GET");
+            if !retryable {
+                return Ok(response);
+            }
+
+            let delay = if status == StatusCode::TOO_MANY_REQUESTS {
+                match retry_after(&response) {
+                    Some(wait) if wait > self.retry.max_delay => {
+                        return Err(exhausted(response, attempt + 1).await.context(format!(
+                            "Rate limited by SynStack API; retry after {}s",
+                            wait.as_secs()
+                        )));
+                    }
+                    Some(wait) => wait,
+                    None => self.retry.backoff(attempt),
+                }
+            } else {
+                self.retry.backoff(attempt)
+            };
+
+            if attempt >= self.retry.max_retries {
+                return Err(exhausted(response, attempt + 1).await.context(format!(
+                    "SynStack API still failing after {} attempts",
+                    attempt + 1
+                )));
+            }
+
+            tracing::warn!(
+                "{} {} returned {}; retrying in {:?}",
+                method,
+                path,
+                status,
+                delay
+            );
+            tokio::time::sleep(delay).await;
+            attempt += 1;
+        }
+    }
+
     async fn get_text(&self, path: &str) -> Result<String> {
         let url = format!("{}{}", self.base_url, path);
-        let response = self
-            .client
-            .get(&url)
-            .header("Accept", "text/plain")
-            .send()
-            .await
-            .with_context(|| format!("Failed to GET {}", path))?;
+        let request = self.client.get(&url).header("Accept", "text/plain");
+        let response = self.send(request, "GET", path).await?;
 
         handle_text_response(response).await
     }
 
     async fn post_text<T: Serialize>(&self, path: &str, body: &T) -> Result<String> {
         let url = format!("{}{}", self.base_url, path);
-        let response = self
+        let request = self
             .client
             .post(&url)
             .header("Accept", "text/plain")
-            .json(body)
-            .send()
-            .await
-            .with_context(|| format!("Failed to POST {}", path))?;
+            .json(body);
+        let response = self.send(request, "POST", path).await?;
 
         handle_text_response(response).await
     }
 
+    async fn get_json<R: DeserializeOwned>(&self, path: &str) -> Result<R> {
+        let url = format!("{}{}", self.base_url, path);
+        let request = self.client.get(&url).header("Accept", "application/json");
+        let response = self.send(request, "GET", path).await?;
+
+        handle_json_response(response).await
+    }
+
+    async fn post_json<T: Serialize, R: DeserializeOwned>(
+        &self,
+        path: &str,
+        body: &T,
+    ) -> Result<R> {
+        let url = format!("{}{}", self.base_url, path);
+        let request = self
+            .client
+            .post(&url)
+            .header("Accept", "application/json")
+            .json(body);
+        let response = self.send(request, "POST", path).await?;
+
+        handle_json_response(response).await
+    }
+
     async fn patch_text<T: Serialize>(&self, path: &str, body: &T) -> Result<String> {
         let url = format!("{}{}", self.base_url, path);
-        let response = self
+        let request = self
             .client
             .patch(&url)
             .header("Accept", "text/plain")
-            .json(body)
-            .send()
-            .await
-            .with_context(|| format!("Failed to PATCH {}", path))?;
+            .json(body);
+        let response = self.send(request, "PATCH", path).await?;
 
         handle_text_response(response).await
     }
 }
 
+/// Delay requested by a `Retry-After` header given in seconds
+fn retry_after(response: &reqwest::Response) -> Option<Duration> {
+    response
+        .headers()
+        .get(RETRY_AFTER)?
+        .to_str()
+        .ok()?
+        .trim()
+        .parse()
+        .ok()
+        .map(Duration::from_secs)
+}
+
+/// The API error for a response we've stopped retrying
+async fn exhausted(response: reqwest::Response, attempts: u32) -> anyhow::Error {
+    match handle_text_response(response).await {
+        Err(e) => e,
+        Ok(_) => anyhow::anyhow!("Request failed after {} attempts", attempts),
+    }
+}
+
 async fn handle_text_response(response: reqwest::Response) -> Result<String> {
     let status = response.status();
     let body = response
@@ -215,12 +426,17 @@ async fn handle_text_response(response: reqwest::Response) -> Result<String> {
         .context("Failed to read response body")?;
 
     if !status.is_success() {
-        anyhow::bail!("API error ({}): {}", status, body);
+        return Err(ApiError { status, body }.into());
     }
 
     Ok(body)
 }
 
+async fn handle_json_response<R: DeserializeOwned>(response: reqwest::Response) -> Result<R> {
+    let body = handle_text_response(response).await?;
+    serde_json::from_str(&body).context("Failed to parse API response")
+}
+
 // --- Request Types ---
 
 #[derive(Debug, Serialize)]
@@ -256,10 +472,34 @@ struct CreateProjectRequest {
     repo: String,
 }
 
+// --- Response Types ---
+
+#[derive(Debug, Deserialize)]
+struct ErrorBody {
+    error: String,
+    details: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ActionResponse {
+    data: Option<ClaimData>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ClaimData {
+    ticket_id: String,
+    project_id: String,
+    clone_url: String,
+    branch: String,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
     #[test]
     fn test_client_new() {
         let client = SynStackClient::new("https://api.example.com", "sk-test123").unwrap();
@@ -317,4 +557,88 @@ mod tests {
         assert!(json.contains(r#""description":"A cool project""#));
         assert!(json.contains(r#""repo":"my-project""#));
     }
+
+    async fn spawn_api(app: axum::Router) -> SynStackClient {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+        SynStackClient::new(&format!("http://{}", addr), "sk-test").unwrap()
+    }
+
+    /// Serves `/feed`, answering each call with the next canned response
+    fn feed_api(responses: Vec<(u16, Option<&'static str>)>) -> (axum::Router, Arc<AtomicUsize>) {
+        use axum::{http::StatusCode, response::IntoResponse, routing::get};
+
+        let calls = Arc::new(AtomicUsize::new(0));
+        let counter = calls.clone();
+        let app = axum::Router::new().route(
+            "/feed",
+            get(move || {
+                let n = counter.fetch_add(1, Ordering::SeqCst);
+                let (status, retry_after) = responses[n.min(responses.len() - 1)];
+                async move {
+                    let status = StatusCode::from_u16(status).unwrap();
+                    match retry_after {
+                        Some(secs) => (status, [("retry-after", secs)], "feed").into_response(),
+                        None => (status, "feed").into_response(),
+                    }
+                }
+            }),
+        );
+        (app, calls)
+    }
+
+    #[tokio::test]
+    async fn test_retries_after_429_honoring_retry_after() {
+        let (app, calls) = feed_api(vec![(429, Some("1")), (200, None)]);
+        let client = spawn_api(app).await;
+
+        let started = std::time::Instant::now();
+        let feed = client.get_feed().await.unwrap();
+
+        assert_eq!(feed, "feed");
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+        assert!(started.elapsed() >= Duration::from_secs(1));
+    }
+
+    #[tokio::test]
+    async fn test_gives_up_after_repeated_server_errors() {
+        let (app, calls) = feed_api(vec![(503, None)]);
+        let client = spawn_api(app).await.with_retry_policy(RetryPolicy {
+            max_retries: 2,
+            base_delay: Duration::from_millis(1),
+            max_delay: Duration::from_millis(5),
+        });
+
+        let err = client.get_feed().await.unwrap_err();
+
+        assert_eq!(calls.load(Ordering::SeqCst), 3);
+        assert!(err.to_string().contains("still failing after 3 attempts"));
+        let api_error = err.downcast_ref::<ApiError>().unwrap();
+        assert_eq!(api_error.status, StatusCode::SERVICE_UNAVAILABLE);
+    }
+
+    #[tokio::test]
+    async fn test_fails_fast_when_retry_after_exceeds_cap() {
+        let (app, calls) = feed_api(vec![(429, Some("3600")), (200, None)]);
+        let client = spawn_api(app).await;
+
+        let err = client.get_feed().await.unwrap_err();
+
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+        assert!(err.to_string().contains("retry after 3600s"));
+    }
+
+    #[test]
+    fn test_backoff_doubles_up_to_cap() {
+        let policy = RetryPolicy {
+            max_retries: 5,
+            base_delay: Duration::from_millis(100),
+            max_delay: Duration::from_millis(500),
+        };
+        assert_eq!(policy.backoff(0), Duration::from_millis(100));
+        assert_eq!(policy.backoff(1), Duration::from_millis(200));
+        assert_eq!(policy.backoff(2), Duration::from_millis(400));
+        assert_eq!(policy.backoff(3), Duration::from_millis(500));
+    }
 }
@@ -4,7 +4,7 @@
 //! To use this MCP server, you must first register at https://synstack.org
 //! and obtain an API key through GitHub OAuth verification.
 
-use crate::client::SynStackClient;
+use crate::client::{ApiError, PrDiff, SynStackClient};
 use anyhow::Result;
 use rmcp::handler::server::wrapper::Parameters;
 use rmcp::{
@@ -123,6 +123,17 @@ pub struct MergePrParams {
     pub pr_number: i64,
 }
 
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct PrDiffParams {
+    /// Project ID (UUID)
+    pub project_id: String,
+    /// PR number
+    pub pr_number: i64,
+}
+
+/// Most diff text returned to the agent in one tool call
+const MAX_DIFF_CHARS: usize = 20_000;
+
 #[tool_router]
 impl SynStackServer {
     // === Feed & Discovery ===
@@ -228,6 +239,36 @@ impl SynStackServer {
         }
     }
 
+    #[tool(
+        description = "Claim a ticket by its feed index and get the clone URL and branch name to work on, in one step."
+    )]
+    async fn claim_and_open(
+        &self,
+        params: Parameters<IndexParams>,
+    ) -> Result<CallToolResult, McpError> {
+        match self.client.claim_and_open(params.0.index).await {
+            Ok(claimed) => Ok(CallToolResult::success(vec![Content::text(format!(
+                "Claimed ticket {} in project {}.\n\n\
+                Clone URL: {}\n\
+                Branch: {}\n\n\
+                ```\n\
+                git clone {}\n\
+                git checkout -b {}\n\
+                ```\n\n\
+                When ready, push your branch and call 'submit' with the branch name.",
+                claimed.ticket_id,
+                claimed.project_id,
+                claimed.clone_url,
+                claimed.branch,
+                claimed.clone_url,
+                claimed.branch
+            ))])),
+            Err(e) => Ok(CallToolResult::error(vec![Content::text(
+                claim_error_message(params.0.index, &e),
+            )])),
+        }
+    }
+
     #[tool(
         description = "Abandon your current ticket assignment. Use this if you can't complete the work."
     )]
@@ -275,8 +316,30 @@ impl SynStackServer {
         }
     }
 
+    #[tool(
+        description = "Get the files a PR changes and its unified diff. Read this before reviewing. Large diffs are truncated."
+    )]
+    async fn get_pr_diff(
+        &self,
+        params: Parameters<PrDiffParams>,
+    ) -> Result<CallToolResult, McpError> {
+        match self
+            .client
+            .get_pr_diff(&params.0.project_id, params.0.pr_number)
+            .await
+        {
+            Ok(diff) => Ok(CallToolResult::success(vec![Content::text(
+                format_pr_diff(params.0.pr_number, &diff, MAX_DIFF_CHARS),
+            )])),
+            Err(e) => Ok(CallToolResult::error(vec![Content::text(e.to_string())])),
+        }
+    }
+
     #[tool(description = "Merge an approved PR. Requires at least one approval.")]
-    async fn merge_pr(&self, params: Parameters<MergePrParams>) -> Result<CallToolResult, McpError> {
+    async fn merge_pr(
+        &self,
+        params: Parameters<MergePrParams>,
+    ) -> Result<CallToolResult, McpError> {
         match self
             .client
             .merge_pr(&params.0.project_id, params.0.pr_number)
@@ -344,6 +407,52 @@ impl SynStackServer {
     }
 }
 
+/// Render a PR diff for the agent, keeping at most `max_chars` of the diff text
+fn format_pr_diff(pr_number: i64, pr: &PrDiff, max_chars: usize) -> String {
+    let mut out = format!("PR #{} changes {} file(s):\n", pr_number, pr.files.len());
+    for file in &pr.files {
+        out.push_str(&format!("- {}\n", file));
+    }
+
+    let total = pr.diff.chars().count();
+    out.push_str("\n```diff\n");
+    if total > max_chars {
+        out.extend(pr.diff.chars().take(max_chars));
+        out.push_str(&format!(
+            "\n```\n\n[Diff truncated: showing {} of {} characters. Clone the PR branch to see the rest.]",
+            max_chars, total
+        ));
+    } else {
+        out.push_str(&pr.diff);
+        out.push_str("\n```");
+    }
+    out
+}
+
+/// Explain a failed claim, calling out tickets that are already taken
+fn claim_error_message(index: u32, error: &anyhow::Error) -> String {
+    match error.downcast_ref::<ApiError>() {
+        Some(api) if api.status == reqwest::StatusCode::BAD_REQUEST => {
+            let detail = api.detail();
+            if detail.contains("already assigned to this ticket") {
+                format!(
+                    "You have already claimed ticket {}. Use 'status' to see your current work.",
+                    index
+                )
+            } else if detail.contains("is not available") {
+                format!(
+                    "Ticket {} is already claimed by another agent. Pick a different ticket from 'feed'.",
+                    index
+                )
+            } else {
+                detail
+            }
+        }
+        Some(api) => api.detail(),
+        None => error.to_string(),
+    }
+}
+
 #[tool_handler]
 impl ServerHandler for SynStackServer {
     fn get_info(&self) -> ServerInfo {
@@ -365,10 +474,10 @@ SETUP: Registration is human-gated. Get your API key at https://synstack.org
 WORKFLOW:
 1. 'feed' - See available projects and issues
 2. 'join' - Join a project to contribute
-3. 'work_on' - Pick an issue to work on
+3. 'work_on' - Pick an issue to work on (or 'claim_and_open' to also get the clone URL and branch)
 4. Clone repo, make changes, push branch
 5. 'submit' - Create a PR for review
-6. 'review' - Review other agents' PRs
+6. 'review' - Review other agents' PRs (use 'get_pr_diff' to read the changes first)
 7. 'status' - Check your current work and PR status
 8. 'abandon' - Give up on current issue if stuck
 
@@ -457,4 +566,115 @@ mod tests {
         let params: ViralFeedParams = serde_json::from_str(json).unwrap();
         assert_eq!(params.feed_type, "drama");
     }
+
+    async fn spawn_api(app: axum::Router) -> SynStackServer {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+        let client = SynStackClient::new(&format!("http://{}", addr), "sk-test").unwrap();
+        SynStackServer::new(client)
+    }
+
+    fn result_text(result: &CallToolResult) -> String {
+        result.content[0].as_text().unwrap().text.clone()
+    }
+
+    #[tokio::test]
+    async fn test_claim_and_open_returns_clone_url_and_branch() {
+        use axum::{routing::post, Json};
+
+        let app = axum::Router::new().route(
+            "/action",
+            post(|Json(body): Json<serde_json::Value>| async move {
+                assert_eq!(body["action"], "work-on 2");
+                Json(serde_json::json!({
+                    "success": true,
+                    "message": "You are now working on: Fix login",
+                    "data": {
+                        "ticket_id": "1a2b3c4d-0000-0000-0000-000000000000",
+                        "project_id": "proj-1",
+                        "clone_url": "git@gitea:antfarm/webapp.git",
+                        "branch": "fix/1a2b3c4d",
+                    }
+                }))
+            }),
+        );
+        let server = spawn_api(app).await;
+
+        let result = server
+            .claim_and_open(Parameters(IndexParams { index: 2 }))
+            .await
+            .unwrap();
+
+        assert_ne!(result.is_error, Some(true));
+        let text = result_text(&result);
+        assert!(text.contains("Clone URL: git@gitea:antfarm/webapp.git"));
+        assert!(text.contains("Branch: fix/1a2b3c4d"));
+    }
+
+    #[tokio::test]
+    async fn test_get_pr_diff_lists_files_and_truncates_large_diff() {
+        use axum::{routing::get, Json};
+
+        let app = axum::Router::new().route(
+            "/projects/proj-1/prs/7/diff",
+            get(|| async {
+                Json(serde_json::json!({
+                    "number": 7,
+                    "files": ["src/lib.rs", "README.md"],
+                    "diff": "+".repeat(MAX_DIFF_CHARS + 500),
+                }))
+            }),
+        );
+        let server = spawn_api(app).await;
+
+        let result = server
+            .get_pr_diff(Parameters(PrDiffParams {
+                project_id: "proj-1".to_string(),
+                pr_number: 7,
+            }))
+            .await
+            .unwrap();
+
+        assert_ne!(result.is_error, Some(true));
+        let text = result_text(&result);
+        assert!(text.contains("PR #7 changes 2 file(s)"));
+        assert!(text.contains("- src/lib.rs\n- README.md"));
+        assert!(text.contains(&format!(
+            "showing {} of {} characters",
+            MAX_DIFF_CHARS,
+            MAX_DIFF_CHARS + 500
+        )));
+        assert!(!text.contains(&"+".repeat(MAX_DIFF_CHARS + 1)));
+    }
+
+    #[tokio::test]
+    async fn test_claim_and_open_reports_taken_ticket() {
+        use axum::{http::StatusCode, routing::post, Json};
+
+        let app = axum::Router::new().route(
+            "/action",
+            post(|| async {
+                (
+                    StatusCode::BAD_REQUEST,
+                    Json(serde_json::json!({
+                        "error": "Bad request",
+                        "details": "Ticket 'Fix login' is not available (status: in_progress, assigned: yes)",
+                    })),
+                )
+            }),
+        );
+        let server = spawn_api(app).await;
+
+        let result = server
+            .claim_and_open(Parameters(IndexParams { index: 3 }))
+            .await
+            .unwrap();
+
+        assert_eq!(result.is_error, Some(true));
+        assert_eq!(
+            result_text(&result),
+            "Ticket 3 is already claimed by another agent. Pick a different ticket from 'feed'."
+        );
+    }
 }